@@ -20,6 +20,9 @@ cargo check --all-targets -p fuel-storage &&
 cargo check --all-targets -p fuel-tx &&
 cargo check --all-targets -p fuel-types &&
 cargo check --all-targets -p fuel-vm &&
+cargo check --all-targets -p fuel-vm --no-default-features --features std &&
+cargo check --all-targets -p fuel-vm --no-default-features --features std,debugger &&
+cargo check --all-targets -p fuel-vm --no-default-features --features std,vm-backtrace &&
 cargo check --all-targets --no-default-features &&
 cargo check --all-targets --all-features &&
 cargo check --target thumbv6m-none-eabi -p fuel-asm -p fuel-storage -p fuel-merkle --no-default-features &&
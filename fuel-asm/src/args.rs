@@ -32,6 +32,14 @@ crate::enum_try_from! {
 
         /// Get memory address of base asset ID
         BaseAssetId = 0x06,
+
+        /// Get the version of the consensus parameters used to execute the
+        /// transaction.
+        GetConsensusParametersVersion = 0x07,
+
+        /// Get the version of the state transition function used to execute the
+        /// transaction.
+        GetStateTransitionVersion = 0x08,
     },
     Immediate18
 }
@@ -46,6 +54,10 @@ crate::enum_try_from! {
     /// Argument list for GTF (get tx fields) instruction
     /// The VM is the only who should match this struct, and it *MUST* always perform
     /// exhaustive match so all offered variants are covered.
+    ///
+    /// The inputs, outputs, witnesses and policies selectors are valid for every
+    /// transaction type. The selectors of the fields specific to a transaction type
+    /// panic with `InvalidMetadataIdentifier` for other transaction types.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumIter)]
     #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
     #[repr(u16)]
@@ -248,6 +260,36 @@ crate::enum_try_from! {
 
         /// Set `$rA` to `tx.policies[count_ones(0b11111 & tx.policyTypes) - 1].expiration`
         PolicyExpiration = 0x505,
+
+        /// Set `$rA` to `tx.policies[count_ones(0b111111 & tx.policyTypes) - 1].flagDefaults`
+        PolicyFlagDefaults = 0x506,
+
+        /// Set `$rA` to `Memory address of tx.purpose`
+        UpgradePurpose = 0x600,
+
+        /// Set `$rA` to `Memory address of tx.root`
+        UploadRoot = 0x700,
+
+        /// Set `$rA` to `tx.witnessIndex`
+        UploadWitnessIndex = 0x701,
+
+        /// Set `$rA` to `tx.subsectionIndex`
+        UploadSubsectionIndex = 0x702,
+
+        /// Set `$rA` to `tx.subsectionsNumber`
+        UploadSubsectionsNumber = 0x703,
+
+        /// Set `$rA` to `tx.proofSetCount`
+        UploadProofSetCount = 0x704,
+
+        /// Set `$rA` to `Memory address of tx.proofSet[$rB]`
+        UploadProofSetAtIndex = 0x705,
+
+        /// Set `$rA` to `Memory address of tx.id`
+        BlobId = 0x800,
+
+        /// Set `$rA` to `tx.witnessIndex`
+        BlobWitnessIndex = 0x801,
     },
     Immediate12
 }
@@ -268,6 +310,8 @@ fn encode_gm_args() {
         GMArgs::GetChainId,
         GMArgs::TxStart,
         GMArgs::BaseAssetId,
+        GMArgs::GetConsensusParametersVersion,
+        GMArgs::GetStateTransitionVersion,
     ];
 
     args.into_iter().for_each(|a| {
@@ -345,6 +389,16 @@ fn encode_gtf_args() {
         GTFArgs::PolicyMaturity,
         GTFArgs::PolicyExpiration,
         GTFArgs::PolicyMaxFee,
+        GTFArgs::PolicyFlagDefaults,
+        GTFArgs::UpgradePurpose,
+        GTFArgs::UploadRoot,
+        GTFArgs::UploadWitnessIndex,
+        GTFArgs::UploadSubsectionIndex,
+        GTFArgs::UploadSubsectionsNumber,
+        GTFArgs::UploadProofSetCount,
+        GTFArgs::UploadProofSetAtIndex,
+        GTFArgs::BlobId,
+        GTFArgs::BlobWitnessIndex,
     ];
 
     args.into_iter().for_each(|a| {
@@ -82,3 +82,93 @@ fn panic_reason_description() {
         }
     }
 }
+
+#[test]
+fn reserved_bits_are_rejected_for_every_opcode() {
+    for opcode_int in 0..=u8::MAX {
+        let Ok(op) = Opcode::try_from(opcode_int) else {
+            continue
+        };
+
+        for bit in 0..24 {
+            let [_, a, b, c] = (1u32 << bit).to_be_bytes();
+            let bytes = [opcode_int, a, b, c];
+
+            // A bit is either a part of some argument and survives the roundtrip,
+            // or it is reserved and the instruction is rejected.
+            if let Ok(instruction) = Instruction::try_from(bytes) {
+                assert_eq!(
+                    instruction.to_bytes(),
+                    bytes,
+                    "{op:?} silently dropped bit {bit}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn add_with_junk_in_reserved_bits_is_rejected() {
+    let raw = u32::from(op::add(0x10, 0x11, 0x12)) | 0x3f;
+
+    assert_eq!(
+        Instruction::try_from(raw.to_be_bytes()),
+        Err(InvalidOpcode::new(raw))
+    );
+}
+
+#[test]
+fn from_bytes_with_offsets_resyncs_after_invalid_word() {
+    let invalid: RawInstruction = 0xff00_0000;
+    let mut bytes = op::add(0x10, 0x11, 0x12).to_bytes().to_vec();
+    bytes.extend(invalid.to_be_bytes());
+    bytes.extend(op::movi(0x10, 0x20).to_bytes());
+    bytes.extend(op::ret(RegId::ONE).to_bytes());
+
+    let decoded: Vec<_> = from_bytes_with_offsets(bytes).collect();
+
+    assert_eq!(
+        decoded,
+        vec![
+            (0, Ok(op::add(0x10, 0x11, 0x12))),
+            (4, Err(InvalidOpcode::new(invalid))),
+            (8, Ok(op::movi(0x10, 0x20))),
+            (12, Ok(op::ret(RegId::ONE))),
+        ]
+    );
+}
+
+#[test]
+fn from_bytes_with_offsets_ignores_truncated_instruction() {
+    let mut bytes = op::noop().to_bytes().to_vec();
+    bytes.extend([0x10, 0x20]);
+
+    let decoded: Vec<_> = from_bytes_with_offsets(bytes).collect();
+
+    assert_eq!(decoded, vec![(0, Ok(op::noop()))]);
+}
+
+#[test]
+fn try_decode_at_decodes_at_any_offset() {
+    let mut bytes = vec![0xff, 0xff];
+    bytes.extend(op::movi(0x10, 0x20).to_bytes());
+    bytes.extend(op::ret(RegId::ONE).to_bytes());
+
+    let unaligned = Instruction::try_decode_at(&bytes, 2);
+    let misaligned = Instruction::try_decode_at(&bytes, 0);
+    let last = Instruction::try_decode_at(&bytes, 6);
+    let out_of_bounds = Instruction::try_decode_at(&bytes, 7);
+
+    assert_eq!(unaligned, Some(Ok(op::movi(0x10, 0x20))));
+    let raw = RawInstruction::from_be_bytes([0xff, 0xff, bytes[2], bytes[3]]);
+    assert_eq!(misaligned, Some(Err(InvalidOpcode::new(raw))));
+    assert_eq!(last, Some(Ok(op::ret(RegId::ONE))));
+    assert_eq!(out_of_bounds, None);
+    assert_eq!(Instruction::try_decode_at(&bytes, usize::MAX), None);
+}
+
+#[test]
+fn unknown_opcode_byte_has_no_raw_instruction() {
+    assert_eq!(Opcode::try_from(0), Err(InvalidOpcode::UNKNOWN_OPCODE));
+    assert_eq!(Opcode::try_from(0).map_err(|e| e.raw()), Err(None));
+}
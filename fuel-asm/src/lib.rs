@@ -26,6 +26,8 @@ pub mod macros;
 pub mod op;
 mod pack;
 mod panic_reason;
+#[cfg(feature = "alloc")]
+pub mod seq;
 mod unpack;
 
 #[cfg(test)]
@@ -50,6 +52,11 @@ pub use panic_reason::PanicReason;
 /// Represents a 6-bit register ID, guaranteed to be masked by construction.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "u8", into = "u8")
+)]
 pub struct RegId(u8);
 
 /// Represents a 6-bit immediate value, guaranteed to be masked by construction.
@@ -77,8 +84,27 @@ pub type RawInstruction = u32;
 
 /// Given opcode doesn't exist, or is the reserved part of
 /// the instruction (i.e. space outside arguments) is non-zero.
-#[derive(Debug, Eq, PartialEq)]
-pub struct InvalidOpcode;
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct InvalidOpcode {
+    raw: Option<RawInstruction>,
+}
+
+impl InvalidOpcode {
+    /// The error of the opcode byte that doesn't represent any opcode, without the
+    /// raw instruction.
+    pub const UNKNOWN_OPCODE: Self = Self { raw: None };
+
+    /// Creates the error of the `raw` instruction that failed to decode.
+    pub const fn new(raw: RawInstruction) -> Self {
+        Self { raw: Some(raw) }
+    }
+
+    /// The raw instruction that failed to decode, or `None` if only the opcode byte
+    /// was decoded, e.g. by the `Opcode::try_from`.
+    pub const fn raw(&self) -> Option<RawInstruction> {
+        self.raw
+    }
+}
 
 bitflags::bitflags! {
     /// Possible values for the FLAG instruction.
@@ -268,6 +294,14 @@ impl_instructions! {
     0x60 MCPI mcpi [dst_addr: RegId src_addr: RegId len: Imm12]
     "Get transaction fields."
     0x61 GTF gtf [dst: RegId arg: RegId selector: Imm12]
+    "A half word (4 bytes) is loaded from the specified address offset by an immediate number of half words, zero-extended."
+    0x62 LHW lhw [dst: RegId addr: RegId offset: Imm12]
+    "A quarter word (2 bytes) is loaded from the specified address offset by an immediate number of quarter words, zero-extended."
+    0x63 LQW lqw [dst: RegId addr: RegId offset: Imm12]
+    "Write the least significant half word (4 bytes) of a register to memory."
+    0x64 SHW shw [addr: RegId value: RegId offset: Imm12]
+    "Write the least significant quarter word (2 bytes) of a register to memory."
+    0x65 SQW sqw [addr: RegId value: RegId offset: Imm12]
 
     "Clear an immediate number of bytes in memory."
     0x70 MCLI mcli [addr: RegId count: Imm18]
@@ -359,6 +393,19 @@ impl Instruction {
     pub fn to_bytes(self) -> [u8; 4] {
         self.into()
     }
+
+    /// Decodes the instruction from the 4 bytes starting at the byte `offset` of the
+    /// `bytes`, which isn't required to be aligned to the instruction size.
+    ///
+    /// Returns `None` if the `bytes` have less than 4 bytes starting at the `offset`.
+    pub fn try_decode_at(
+        bytes: &[u8],
+        offset: usize,
+    ) -> Option<Result<Self, InvalidOpcode>> {
+        let end = offset.checked_add(Self::SIZE)?;
+        let raw: [u8; 4] = bytes.get(offset..end)?.try_into().ok()?;
+        Some(Self::try_from(raw))
+    }
 }
 
 #[cfg(feature = "typescript")]
@@ -703,10 +750,10 @@ impl Opcode {
             | WQML | WDDV | WQDV | WDMD | WQMD | WDAM | WQAM | WDMM | WQMM | PSHH
             | PSHL | POPH | POPL | RET | ALOC | MCL | MCP | MEQ | ECK1 | ECR1 | ED19
             | K256 | S256 | NOOP | FLAG | ADDI | ANDI | DIVI | EXPI | MODI | MULI
-            | MLDV | ORI | SLLI | SRLI | SUBI | XORI | JNEI | LB | LW | SB | SW
-            | MCPI | MCLI | GM | MOVI | JNZI | JI | JMP | JNE | JMPF | JMPB | JNZF
-            | JNZB | JNEF | JNEB | CFEI | CFSI | CFE | CFS | GTF | LDC | BSIZ | BLDD
-            | ECOP | EPAR => true,
+            | MLDV | ORI | SLLI | SRLI | SUBI | XORI | JNEI | LB | LW | SB | SW | LHW
+            | LQW | SHW | SQW | MCPI | MCLI | GM | MOVI | JNZI | JI | JMP | JNE
+            | JMPF | JMPB | JNZF | JNZB | JNEF | JNEB | CFEI | CFSI | CFE | CFS | GTF
+            | LDC | BSIZ | BLDD | ECOP | EPAR => true,
             _ => false,
         }
     }
@@ -926,6 +973,26 @@ where
     })
 }
 
+/// Given an iterator yielding bytes, produces an iterator yielding `Instruction`s along
+/// with the byte offsets they were decoded from.
+///
+/// This function assumes each consecutive 4 bytes aligns with an instruction, so an
+/// invalid instruction doesn't affect the decoding of the following ones. The
+/// trailing bytes that don't form a full instruction are ignored, as by [`from_bytes`].
+///
+/// The produced iterator yields an `Err` with the raw instruction in the case that an
+/// instruction fails to parse from 4 consecutive bytes.
+pub fn from_bytes_with_offsets<I>(
+    bs: I,
+) -> impl Iterator<Item = (usize, Result<Instruction, InvalidOpcode>)>
+where
+    I: IntoIterator<Item = u8>,
+{
+    from_bytes(bs)
+        .enumerate()
+        .map(|(index, instruction)| (index.saturating_mul(Instruction::SIZE), instruction))
+}
+
 /// Given an iterator yielding u32s (i.e. "half words" or "raw instructions"), produces an
 /// iterator yielding `Instruction`s.
 ///
@@ -942,6 +1009,14 @@ where
 // Short-hand, `panic!`ing constructors for the short-hand instruction construtors (e.g
 // op::add).
 
+// Short-hand, `panic!`ing conversion for the deprecated `compat` constructors.
+fn check_register_id(id: RegisterId) -> RegId {
+    u8::try_from(id)
+        .ok()
+        .and_then(RegId::new_checked)
+        .unwrap_or_else(|| panic!("Value `{id}` out of range for register id"))
+}
+
 fn check_imm06(u: u8) -> Imm06 {
     Imm06::new_checked(u)
         .unwrap_or_else(|| panic!("Value `{u}` out of range for 6-bit immediate"))
@@ -52,10 +52,10 @@
 //! the case that the byte represents a reserved or undefined value.
 //!
 //! ```rust
-//! # use fuel_asm::{InvalidOpcode, Opcode};
+//! # use fuel_asm::Opcode;
 //! assert_eq!(Opcode::try_from(0x10), Ok(Opcode::ADD));
 //! assert_eq!(Opcode::try_from(0x11), Ok(Opcode::AND));
-//! assert_eq!(Opcode::try_from(0), Err(InvalidOpcode));
+//! assert_eq!(Opcode::try_from(0).map_err(|e| e.raw()), Err(None));
 //! ```
 //!
 //! ## The `Instruction` enum
@@ -191,6 +191,23 @@
 //!     op::ret(0x01),             // return `1`
 //! ];
 //! ```
+//!
+//! ## The `compat` module
+//!
+//! For codebases written against the older API, where instructions were built with
+//! `Opcode` variant constructors like `Opcode::ADDI(ra, rb, imm)`, a deprecated
+//! constructor named after the uppercase identifier is generated for each operation in
+//! the `compat` module, along with a table mapping it to its shorthand constructor.
+//!
+//! ```rust
+//! # #![allow(deprecated)]
+//! use fuel_asm::{compat, op, RawInstruction};
+//!
+//! assert_eq!(
+//!     RawInstruction::from(compat::ADDI(0x10, 0x11, 1)),
+//!     RawInstruction::from(op::addi(0x10, 0x11, 1)),
+//! );
+//! ```
 
 // Generate a shorthand free function named after the $op for constructing an
 // `Instruction`.
@@ -384,6 +401,104 @@ macro_rules! op_constructor {
     };
 }
 
+// Generate a deprecated constructor mirroring the pre-`op` `Opcode` variant constructors,
+// which took `RegisterId` and `Immediate*` values.
+macro_rules! op_compat_fn {
+    ($Op:ident $op:ident[$ra:ident : RegId]) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op($ra: RegisterId) -> Instruction {
+            op::$op(check_register_id($ra))
+        }
+    };
+    ($Op:ident $op:ident[$ra:ident : RegId $rb:ident : RegId]) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op($ra: RegisterId, $rb: RegisterId) -> Instruction {
+            op::$op(check_register_id($ra), check_register_id($rb))
+        }
+    };
+    ($Op:ident $op:ident[$ra:ident : RegId $rb:ident : RegId $rc:ident : RegId]) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op($ra: RegisterId, $rb: RegisterId, $rc: RegisterId) -> Instruction {
+            op::$op(
+                check_register_id($ra),
+                check_register_id($rb),
+                check_register_id($rc),
+            )
+        }
+    };
+    (
+        $Op:ident
+        $op:ident[$ra:ident : RegId $rb:ident : RegId $rc:ident : RegId $rd:ident : RegId]
+    ) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op(
+            $ra: RegisterId,
+            $rb: RegisterId,
+            $rc: RegisterId,
+            $rd: RegisterId,
+        ) -> Instruction {
+            op::$op(
+                check_register_id($ra),
+                check_register_id($rb),
+                check_register_id($rc),
+                check_register_id($rd),
+            )
+        }
+    };
+    (
+        $Op:ident
+        $op:ident[$ra:ident : RegId $rb:ident : RegId $rc:ident : RegId $imm:ident : Imm06]
+    ) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op(
+            $ra: RegisterId,
+            $rb: RegisterId,
+            $rc: RegisterId,
+            $imm: Immediate06,
+        ) -> Instruction {
+            op::$op(
+                check_register_id($ra),
+                check_register_id($rb),
+                check_register_id($rc),
+                $imm,
+            )
+        }
+    };
+    ($Op:ident $op:ident[$ra:ident : RegId $rb:ident : RegId $imm:ident : Imm12]) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op($ra: RegisterId, $rb: RegisterId, $imm: Immediate12) -> Instruction {
+            op::$op(check_register_id($ra), check_register_id($rb), $imm)
+        }
+    };
+    ($Op:ident $op:ident[$ra:ident : RegId $imm:ident : Imm18]) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op($ra: RegisterId, $imm: Immediate18) -> Instruction {
+            op::$op(check_register_id($ra), $imm)
+        }
+    };
+    ($Op:ident $op:ident[$imm:ident : Imm24]) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op($imm: Immediate24) -> Instruction {
+            op::$op($imm)
+        }
+    };
+    ($Op:ident $op:ident[]) => {
+        #[doc = concat!("Use [`op::", stringify!($op), "`](crate::op::", stringify!($op), ") instead.")]
+        #[deprecated(note = "use the constructors of the `op` module instead")]
+        pub fn $Op() -> Instruction {
+            op::$op()
+        }
+    };
+}
+
 // Generate approriate `new` constructor for the instruction
 macro_rules! op_new {
     // Generate a constructor based on the field layout.
@@ -1089,6 +1204,50 @@ macro_rules! impl_instructions {
         }
     };
 
+    // Define the deprecated `compat` module with the conversion guide.
+    (decl_compat_module $($doc:literal $ix:literal $Op:ident $op:ident [$($fname:ident: $field:ident)*])*) => {
+        pub mod compat {
+            #![doc = concat!(
+                "Constructors mirroring the `Opcode` variant constructors of the pre-`op` ",
+                "API, e.g. `Opcode::ADDI(ra, rb, imm)`.\n\n",
+                "They accept `RegisterId` and `Immediate*` values like the old API did and ",
+                "panic if a value is out of range. Every constructor is deprecated in favor ",
+                "of its shorthand constructor in [`op`](crate::op), which produces the same ",
+                "instruction.\n\n",
+                "| Old API | New API |\n",
+                "|---------|---------|\n",
+                $(
+                    "| `Opcode::", stringify!($Op), "(", stringify!($($fname),*), ")` ",
+                    "| [`op::", stringify!($op), "(", stringify!($($fname),*), ")`]",
+                    "(crate::op::", stringify!($op), ") |\n",
+                )*
+            )]
+            #![allow(non_snake_case)]
+
+            use super::*;
+            use fuel_types::{
+                Immediate06,
+                Immediate12,
+                Immediate18,
+                Immediate24,
+            };
+
+            $(
+                op_compat_fn!($Op $op [$($fname: $field)*]);
+            )*
+
+            #[cfg(test)]
+            #[allow(deprecated)]
+            mod tests {
+                use super::*;
+
+                $(
+                    op_compat_test!($Op $op [$($field)*]);
+                )*
+            }
+        }
+    };
+
     // Recursively generate a test constructor for each opcode
     (impl_opcode_test_construct $doc:literal $ix:literal $Op:ident $op:ident [$($fname:ident: $field:ident)*] $($rest:tt)*) => {
         #[cfg(test)]
@@ -1173,7 +1332,7 @@ macro_rules! impl_instructions {
                     $(
                         $ix => Ok(Opcode::$Op),
                     )*
-                    _ => Err(InvalidOpcode),
+                    _ => Err(InvalidOpcode::UNKNOWN_OPCODE),
                 }
             }
         }
@@ -1233,12 +1392,13 @@ macro_rules! impl_instructions {
         impl core::convert::TryFrom<[u8; 4]> for Instruction {
             type Error = InvalidOpcode;
             fn try_from([op, a, b, c]: [u8; 4]) -> Result<Self, Self::Error> {
-                match Opcode::try_from(op)? {
+                let invalid = || InvalidOpcode::new(RawInstruction::from_be_bytes([op, a, b, c]));
+                match Opcode::try_from(op).map_err(|_| invalid())? {
                     $(
                         Opcode::$Op => Ok(Self::$Op({
                             let op = op::$Op([a, b, c]);
                             if !op.reserved_part_is_zero() {
-                                return Err(InvalidOpcode);
+                                return Err(invalid());
                             }
                             op
                         })),
@@ -1271,7 +1431,7 @@ macro_rules! impl_instructions {
         impl_instructions!(impl_opcode $($tts)*);
         impl_instructions!(impl_instruction $($tts)*);
         impl_instructions!(impl_opcode_test_construct $($tts)*);
-
+        impl_instructions!(decl_compat_module $($tts)*);
 
         #[cfg(test)]
         mod opcode_tests {
@@ -1367,6 +1527,92 @@ macro_rules! op_test {
     };
 }
 
+#[cfg(test)]
+// Generate a test checking that the compat constructor matches the `op` constructor.
+macro_rules! op_compat_test {
+    ($Op:ident $op:ident[RegId]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(0x10)),
+                RawInstruction::from(op::$op(0x10)),
+            );
+        }
+    };
+    ($Op:ident $op:ident[RegId RegId]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(0x10, 0x3f)),
+                RawInstruction::from(op::$op(0x10, 0x3f)),
+            );
+        }
+    };
+    ($Op:ident $op:ident[RegId RegId RegId]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(0x10, 0x3f, 0x01)),
+                RawInstruction::from(op::$op(0x10, 0x3f, 0x01)),
+            );
+        }
+    };
+    ($Op:ident $op:ident[RegId RegId RegId RegId]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(0x10, 0x3f, 0x01, 0x2a)),
+                RawInstruction::from(op::$op(0x10, 0x3f, 0x01, 0x2a)),
+            );
+        }
+    };
+    ($Op:ident $op:ident[RegId RegId RegId Imm06]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(0x10, 0x3f, 0x01, Imm06::MAX.into())),
+                RawInstruction::from(op::$op(0x10, 0x3f, 0x01, Imm06::MAX.into())),
+            );
+        }
+    };
+    ($Op:ident $op:ident[RegId RegId Imm12]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(0x10, 0x3f, Imm12::MAX.into())),
+                RawInstruction::from(op::$op(0x10, 0x3f, Imm12::MAX.into())),
+            );
+        }
+    };
+    ($Op:ident $op:ident[RegId Imm18]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(0x10, Imm18::MAX.into())),
+                RawInstruction::from(op::$op(0x10, Imm18::MAX.into())),
+            );
+        }
+    };
+    ($Op:ident $op:ident[Imm24]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op(Imm24::MAX.into())),
+                RawInstruction::from(op::$op(Imm24::MAX.into())),
+            );
+        }
+    };
+    ($Op:ident $op:ident[]) => {
+        #[test]
+        fn $op() {
+            assert_eq!(
+                RawInstruction::from(super::$Op()),
+                RawInstruction::from(op::$op()),
+            );
+        }
+    };
+}
+
 #[cfg(test)]
 fn bytes(a: u8, b: u8, c: u8, d: u8) -> [u8; 3] {
     use crate::RegId;
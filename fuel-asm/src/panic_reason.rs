@@ -160,6 +160,17 @@ enum_from! {
         InvalidEllipticCurvePoint = 0x3b,
         /// Given input contract does not exist.
         InputContractDoesNotExist = 0x3c,
+        /// The variable output was already filled by a previous transfer.
+        DoubleSpendOutput = 0x3d,
+        /// The gas forwarded by `CALL` exceeds the remaining context gas while the
+        /// strict call gas is enabled.
+        InsufficientGasForCall = 0x3e,
+        /// The storage was written while the transaction is executed in the
+        /// read-only mode.
+        WriteInReadOnlyMode = 0x3f,
+        /// The transaction wrote more bytes to the storage than allowed by the
+        /// interpreter parameters.
+        StorageWriteLimitExceeded = 0x40,
     }
 }
 
@@ -0,0 +1,137 @@
+//! Commonly used sequences of instructions.
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    op,
+    CheckRegId,
+    Imm12,
+    Imm18,
+    Instruction,
+};
+
+/// The number of bits set by one `ORI` instruction.
+const ORI_BITS: u16 = 12;
+
+/// Returns the shortest sequence of instructions setting the register `reg` to the
+/// `value`.
+///
+/// Values fitting into [`Imm18`] are set with a single `MOVI`. Larger values are built
+/// from the most significant 18 bits set by `MOVI`, followed by `SLLI`+`ORI` pairs
+/// for each remaining 12-bit chunk. `ORI` is skipped for zero chunks, and consecutive
+/// shifts are merged into one `SLLI`.
+pub fn set_reg_to<A: CheckRegId>(reg: A, value: u64) -> Vec<Instruction> {
+    let reg = reg.check();
+
+    // The number of 12-bit chunks following the most significant chunk set by `MOVI`.
+    let mut chunks = 0u32;
+    while shr(value, chunks.saturating_mul(u32::from(ORI_BITS)))
+        > u64::from(Imm18::MAX.to_u32())
+    {
+        chunks = chunks.saturating_add(1);
+    }
+
+    let head = shr(value, chunks.saturating_mul(u32::from(ORI_BITS)));
+    let head = u32::try_from(head).expect("The head fits into `Imm18` by construction");
+    let mut ops = vec![op::movi(reg, head)];
+
+    let mut pending_shift = 0u16;
+    for chunk_index in (0..chunks).rev() {
+        let chunk = shr(value, chunk_index.saturating_mul(u32::from(ORI_BITS)))
+            & u64::from(Imm12::MAX.to_u16());
+        let chunk = u16::try_from(chunk).expect("The chunk fits into `Imm12` by mask");
+
+        pending_shift = pending_shift.saturating_add(ORI_BITS);
+
+        if chunk != 0 {
+            ops.push(op::slli(reg, reg, pending_shift));
+            ops.push(op::ori(reg, reg, chunk));
+            pending_shift = 0;
+        }
+    }
+
+    if pending_shift != 0 {
+        ops.push(op::slli(reg, reg, pending_shift));
+    }
+
+    ops
+}
+
+fn shr(value: u64, bits: u32) -> u64 {
+    value.checked_shr(bits).unwrap_or_default()
+}
+
+#[allow(non_snake_case)]
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        RegId,
+        Word,
+    };
+    use rstest::rstest;
+
+    /// Interprets the subset of instructions used by [`set_reg_to`].
+    fn evaluate(reg: RegId, ops: &[Instruction]) -> Word {
+        let mut result: Word = 0;
+        for instruction in ops {
+            match instruction {
+                Instruction::MOVI(movi) => {
+                    let (dst, imm) = movi.unpack();
+                    assert_eq!(dst, reg);
+                    result = Word::from(imm.to_u32());
+                }
+                Instruction::SLLI(slli) => {
+                    let (dst, lhs, imm) = slli.unpack();
+                    assert_eq!((dst, lhs), (reg, reg));
+                    result = result
+                        .checked_shl(u32::from(imm.to_u16()))
+                        .expect("Shift is less than word size");
+                }
+                Instruction::ORI(ori) => {
+                    let (dst, lhs, imm) = ori.unpack();
+                    assert_eq!((dst, lhs), (reg, reg));
+                    result |= Word::from(imm.to_u16());
+                }
+                _ => panic!("Unexpected instruction {instruction:?}"),
+            }
+        }
+        result
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 1)]
+    #[case(u64::from(Imm18::MAX.to_u32()), 1)]
+    #[case(u64::from(Imm18::MAX.to_u32()) + 1, 2)]
+    #[case((1 << 30) - 1, 3)]
+    #[case(1 << 30, 2)]
+    #[case(0x1234_5678_9abc, 7)]
+    #[case(1 << 63, 2)]
+    #[case(u64::MAX, 9)]
+    fn set_reg_to__produces_expected_value_and_length(
+        #[case] value: u64,
+        #[case] expected_len: usize,
+    ) {
+        let reg = RegId::new(0x10);
+
+        let ops = set_reg_to(reg, value);
+
+        assert_eq!(evaluate(reg, &ops), value);
+        assert_eq!(ops.len(), expected_len);
+    }
+
+    #[test]
+    fn set_reg_to__works_for_all_bit_positions() {
+        let reg = RegId::new(0x20);
+        for bit in 0..u64::BITS {
+            for value in [1u64 << bit, (1u64 << bit) - 1, !(1u64 << bit)] {
+                assert_eq!(evaluate(reg, &set_reg_to(reg, value)), value);
+            }
+        }
+    }
+}
@@ -0,0 +1,458 @@
+//! Compression of all transactions of a block with a registry shared between them.
+//!
+//! Values substituted by a [`RegistryKey`] are registered once per registry, so the
+//! same address, asset id or contract id used by several transactions of a block (or of
+//! previous blocks) is stored only once. The [`CompressedBlockPayload`] carries the
+//! registrations made while compressing the block, which allows the decompressor to
+//! bring its own registry up to date without access to the compressor's state.
+
+use crate::{
+    CompressibleBy,
+    ContextError,
+    DecompressibleBy,
+    RegistryKey,
+};
+use core::{
+    future::Future,
+    pin::pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+use fuel_types::{
+    Address,
+    AssetId,
+    ContractId,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    task::{
+        Wake,
+        Waker,
+    },
+};
+
+/// Identifies the registry table of a substituted value.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum RegistryKeyspace {
+    /// [`Address`] values.
+    Address,
+    /// [`AssetId`] values.
+    AssetId,
+    /// [`ContractId`] values.
+    ContractId,
+    /// Script bytecode.
+    ScriptCode,
+    /// Predicate bytecode.
+    PredicateCode,
+}
+
+/// Storage of the registry tables used by the block compression.
+pub trait RegistryStore {
+    /// Returns the key of the `value` if it is already registered.
+    fn get_key(&self, keyspace: RegistryKeyspace, value: &[u8]) -> Option<RegistryKey>;
+
+    /// Returns the value registered under the `key`.
+    fn get_value(&self, keyspace: RegistryKeyspace, key: RegistryKey) -> Option<Vec<u8>>;
+
+    /// Allocates the key for the next registration in the `keyspace`.
+    fn allocate_key(&mut self, keyspace: RegistryKeyspace) -> RegistryKey;
+
+    /// Registers the `value` under the `key`, replacing the previous value if any.
+    fn insert(&mut self, keyspace: RegistryKeyspace, key: RegistryKey, value: Vec<u8>);
+}
+
+/// Registry tables kept in memory.
+///
+/// Keys are allocated sequentially per keyspace and wrap around once the keyspace is
+/// exhausted, overwriting the oldest entries.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InMemoryRegistry {
+    tables: HashMap<RegistryKeyspace, Table>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Table {
+    next_key: Option<RegistryKey>,
+    values: HashMap<RegistryKey, Vec<u8>>,
+    keys: HashMap<Vec<u8>, RegistryKey>,
+}
+
+impl InMemoryRegistry {
+    /// Returns the number of values registered in the `keyspace`.
+    pub fn len(&self, keyspace: RegistryKeyspace) -> usize {
+        self.tables
+            .get(&keyspace)
+            .map(|table| table.values.len())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if nothing is registered in any keyspace.
+    pub fn is_empty(&self) -> bool {
+        self.tables.values().all(|table| table.values.is_empty())
+    }
+}
+
+impl RegistryStore for InMemoryRegistry {
+    fn get_key(&self, keyspace: RegistryKeyspace, value: &[u8]) -> Option<RegistryKey> {
+        self.tables.get(&keyspace)?.keys.get(value).copied()
+    }
+
+    fn get_value(&self, keyspace: RegistryKeyspace, key: RegistryKey) -> Option<Vec<u8>> {
+        self.tables.get(&keyspace)?.values.get(&key).cloned()
+    }
+
+    fn allocate_key(&mut self, keyspace: RegistryKeyspace) -> RegistryKey {
+        let table = self.tables.entry(keyspace).or_default();
+        let key = table.next_key.unwrap_or(RegistryKey::ZERO);
+        table.next_key = Some(key.next());
+        key
+    }
+
+    fn insert(&mut self, keyspace: RegistryKeyspace, key: RegistryKey, value: Vec<u8>) {
+        let table = self.tables.entry(keyspace).or_default();
+        if let Some(previous) = table.values.insert(key, value.clone()) {
+            table.keys.remove(&previous);
+        }
+        table.keys.insert(value, key);
+    }
+}
+
+/// A value added to the registry while compressing a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Registration {
+    /// The registry table of the value.
+    pub keyspace: RegistryKeyspace,
+    /// The key assigned to the value.
+    pub key: RegistryKey,
+    /// The value itself.
+    pub value: Vec<u8>,
+}
+
+/// Compressed transactions of a block, together with the registrations they rely on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedBlockPayload<Tx> {
+    /// Values registered while compressing the block, in registration order.
+    pub registrations: Vec<Registration>,
+    /// The compressed transactions, in block order.
+    pub transactions: Vec<Tx>,
+}
+
+/// Error of the block compression and decompression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockCompressionError {
+    /// The registry has no value under the key.
+    UnknownKey {
+        /// The registry table of the key.
+        keyspace: RegistryKeyspace,
+        /// The key that was not found.
+        key: RegistryKey,
+    },
+    /// The value registered under the key can't be decoded.
+    InvalidValue {
+        /// The registry table of the value.
+        keyspace: RegistryKeyspace,
+        /// The key of the value.
+        key: RegistryKey,
+    },
+    /// The chain state required to compress or decompress the block is missing.
+    MissingState(&'static str),
+    /// The block has more transactions than can be addressed by a transaction index.
+    TooManyTransactions,
+}
+
+/// A type substituted by a [`RegistryKey`] in the compressed block.
+pub trait Substitutable: Default + PartialEq + Sized {
+    /// The registry table of the type.
+    const KEYSPACE: RegistryKeyspace;
+
+    /// Encodes the value as stored in the registry.
+    fn to_registry_value(&self) -> Vec<u8>;
+
+    /// Decodes the value stored in the registry.
+    fn from_registry_value(value: &[u8]) -> Option<Self>;
+}
+
+/// Context used to compress the transactions of a block.
+pub struct BlockCompressionCtx<'a, R> {
+    registry: &'a mut R,
+    registrations: Vec<Registration>,
+}
+
+impl<R> ContextError for BlockCompressionCtx<'_, R> {
+    type Error = BlockCompressionError;
+}
+
+impl<'a, R> BlockCompressionCtx<'a, R>
+where
+    R: RegistryStore,
+{
+    /// Returns the underlying registry store.
+    pub fn registry(&self) -> &R {
+        self.registry
+    }
+
+    /// Returns the key of the `value`, registering it if it is seen for the first time.
+    /// The default value is always mapped to [`RegistryKey::DEFAULT_VALUE`].
+    pub fn substitute<T>(&mut self, value: &T) -> RegistryKey
+    where
+        T: Substitutable,
+    {
+        if *value == T::default() {
+            return RegistryKey::DEFAULT_VALUE;
+        }
+
+        let value = value.to_registry_value();
+        if let Some(key) = self.registry.get_key(T::KEYSPACE, &value) {
+            return key;
+        }
+
+        let key = self.registry.allocate_key(T::KEYSPACE);
+        self.registry.insert(T::KEYSPACE, key, value.clone());
+        self.registrations.push(Registration {
+            keyspace: T::KEYSPACE,
+            key,
+            value,
+        });
+        key
+    }
+}
+
+/// Context used to decompress the transactions of a block.
+pub struct BlockDecompressionCtx<'a, R> {
+    registry: &'a R,
+    tx_index: u16,
+}
+
+impl<R> ContextError for BlockDecompressionCtx<'_, R> {
+    type Error = BlockCompressionError;
+}
+
+impl<'a, R> BlockDecompressionCtx<'a, R>
+where
+    R: RegistryStore,
+{
+    /// Returns the underlying registry store.
+    pub fn registry(&self) -> &R {
+        self.registry
+    }
+
+    /// Returns the index in the block of the transaction being decompressed.
+    pub fn tx_index(&self) -> u16 {
+        self.tx_index
+    }
+
+    /// Returns the value registered under the `key`.
+    pub fn resolve<T>(&self, key: RegistryKey) -> Result<T, BlockCompressionError>
+    where
+        T: Substitutable,
+    {
+        if key == RegistryKey::DEFAULT_VALUE {
+            return Ok(T::default());
+        }
+
+        let keyspace = T::KEYSPACE;
+        let value = self
+            .registry
+            .get_value(keyspace, key)
+            .ok_or(BlockCompressionError::UnknownKey { keyspace, key })?;
+        T::from_registry_value(&value)
+            .ok_or(BlockCompressionError::InvalidValue { keyspace, key })
+    }
+}
+
+macro_rules! substitutable_id {
+    ($t:ident) => {
+        impl Substitutable for $t {
+            const KEYSPACE: RegistryKeyspace = RegistryKeyspace::$t;
+
+            fn to_registry_value(&self) -> Vec<u8> {
+                self.as_ref().to_vec()
+            }
+
+            fn from_registry_value(value: &[u8]) -> Option<Self> {
+                $t::try_from(value).ok()
+            }
+        }
+
+        impl<R> CompressibleBy<BlockCompressionCtx<'_, R>> for $t
+        where
+            R: RegistryStore,
+        {
+            async fn compress_with(
+                &self,
+                ctx: &mut BlockCompressionCtx<'_, R>,
+            ) -> Result<RegistryKey, BlockCompressionError> {
+                Ok(ctx.substitute(self))
+            }
+        }
+
+        impl<R> DecompressibleBy<BlockDecompressionCtx<'_, R>> for $t
+        where
+            R: RegistryStore,
+        {
+            async fn decompress_with(
+                key: RegistryKey,
+                ctx: &BlockDecompressionCtx<'_, R>,
+            ) -> Result<Self, BlockCompressionError> {
+                ctx.resolve(key)
+            }
+        }
+    };
+}
+
+substitutable_id!(Address);
+substitutable_id!(AssetId);
+substitutable_id!(ContractId);
+
+/// Compresses the transactions of a block, registering new values in the `registry`.
+pub fn compress_transactions<'a, Tx, R>(
+    txs: &[Tx],
+    registry: &'a mut R,
+) -> Result<CompressedBlockPayload<Tx::Compressed>, BlockCompressionError>
+where
+    Tx: CompressibleBy<BlockCompressionCtx<'a, R>>,
+    R: RegistryStore,
+{
+    let mut ctx = BlockCompressionCtx {
+        registry,
+        registrations: Vec::new(),
+    };
+    let transactions = txs
+        .iter()
+        .map(|tx| block_on(tx.compress_with(&mut ctx)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(CompressedBlockPayload {
+        registrations: ctx.registrations,
+        transactions,
+    })
+}
+
+/// Decompresses the transactions of a block. The registrations of the `payload` are
+/// applied to the `registry` first, so it stays in sync with the compressor's registry.
+pub fn decompress_transactions<'a, Tx, R>(
+    payload: CompressedBlockPayload<Tx::Compressed>,
+    registry: &'a mut R,
+) -> Result<Vec<Tx>, BlockCompressionError>
+where
+    Tx: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+    R: RegistryStore,
+{
+    for registration in payload.registrations {
+        registry.insert(registration.keyspace, registration.key, registration.value);
+    }
+
+    let registry: &'a R = registry;
+    let mut ctx = BlockDecompressionCtx {
+        registry,
+        tx_index: 0,
+    };
+    let mut transactions = Vec::with_capacity(payload.transactions.len());
+    for (index, tx) in payload.transactions.into_iter().enumerate() {
+        ctx.tx_index = u16::try_from(index)
+            .map_err(|_| BlockCompressionError::TooManyTransactions)?;
+        transactions.push(block_on(Tx::decompress_with(tx, &ctx))?);
+    }
+
+    Ok(transactions)
+}
+
+/// Drives the future of a block context to completion. The block contexts only access
+/// the registry synchronously, so the future is ready on the first poll.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    match pin!(future).poll(&mut cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("Block compression contexts never suspend"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_memory_registry_allocates_sequential_keys_per_keyspace() {
+        let mut registry = InMemoryRegistry::default();
+
+        assert_eq!(
+            registry.allocate_key(RegistryKeyspace::Address),
+            RegistryKey::ZERO
+        );
+        assert_eq!(
+            registry.allocate_key(RegistryKeyspace::Address),
+            RegistryKey::ZERO.next()
+        );
+        assert_eq!(
+            registry.allocate_key(RegistryKeyspace::AssetId),
+            RegistryKey::ZERO
+        );
+    }
+
+    #[test]
+    fn in_memory_registry_insert_replaces_previous_value() {
+        // Given
+        let mut registry = InMemoryRegistry::default();
+        let keyspace = RegistryKeyspace::ScriptCode;
+        registry.insert(keyspace, RegistryKey::ZERO, vec![1]);
+
+        // When
+        registry.insert(keyspace, RegistryKey::ZERO, vec![2]);
+
+        // Then
+        assert_eq!(registry.get_key(keyspace, &[1]), None);
+        assert_eq!(registry.get_key(keyspace, &[2]), Some(RegistryKey::ZERO));
+        assert_eq!(
+            registry.get_value(keyspace, RegistryKey::ZERO),
+            Some(vec![2])
+        );
+        assert_eq!(registry.len(keyspace), 1);
+    }
+
+    #[test]
+    fn substitute_registers_each_value_once() {
+        // Given
+        let mut registry = InMemoryRegistry::default();
+        let mut ctx = BlockCompressionCtx {
+            registry: &mut registry,
+            registrations: Vec::new(),
+        };
+        let address = Address::new([1; 32]);
+
+        // When
+        let first = ctx.substitute(&address);
+        let second = ctx.substitute(&address);
+        let default = ctx.substitute(&Address::default());
+
+        // Then
+        assert_eq!(first, second);
+        assert_eq!(default, RegistryKey::DEFAULT_VALUE);
+        assert_eq!(
+            ctx.registrations,
+            vec![Registration {
+                keyspace: RegistryKeyspace::Address,
+                key: first,
+                value: address.to_vec(),
+            }]
+        );
+    }
+}
@@ -6,6 +6,7 @@
 #![deny(unused_crate_dependencies)]
 #![deny(clippy::cast_possible_truncation)]
 
+pub mod block;
 mod impls;
 mod key;
 mod traits;
@@ -1,19 +1,27 @@
 //! secp256r1 (P-256) functions
 
-#[cfg(feature = "test-helpers")]
-use crate::secp256::signature_format::encode_signature;
 use crate::{
     message::Message,
-    secp256::signature_format::decode_signature,
+    secp256::signature_format::{
+        decode_signature,
+        encode_signature,
+    },
     Error,
 };
-#[cfg(feature = "test-helpers")]
 use ecdsa::RecoveryId;
 use fuel_types::Bytes64;
-use p256::ecdsa::VerifyingKey;
+use p256::{
+    ecdsa::VerifyingKey,
+    EncodedPoint,
+};
 
-/// Sign a prehashed message. With the given key.
-#[cfg(feature = "test-helpers")]
+/// Sign a prehashed message with the given key.
+///
+/// The signature is normalized to the low `s` form and the recovery id is compressed
+/// into it, the same way as for the secp256k1 signatures, so the result can be
+/// passed to the `ECR1` instruction and to [`recover`] as is. The compression scheme
+/// is described in
+/// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/cryptographic-primitives.md>
 pub fn sign_prehashed(
     signing_key: &p256::ecdsa::SigningKey,
     message: &Message,
@@ -57,7 +65,6 @@ pub fn sign_prehashed(
 
 /// Convert the public key point to its uncompressed non-prefixed representation,
 /// i.e. 32 bytes of x coordinate and 32 bytes of y coordinate.
-#[cfg(feature = "test-helpers")]
 pub fn encode_pubkey(key: VerifyingKey) -> [u8; 64] {
     let point = key.to_encoded_point(false);
     let mut result = [0u8; 64];
@@ -81,10 +88,34 @@ pub fn recover(signature: &Bytes64, message: &Message) -> Result<Bytes64, Error>
     Ok(raw)
 }
 
+/// Verify that a signature produced by [`sign_prehashed`] matches the public key in
+/// the format returned by [`encode_pubkey`] and [`recover`].
+pub fn verify(
+    signature: &Bytes64,
+    public_key: &Bytes64,
+    message: &Message,
+) -> Result<(), Error> {
+    use ecdsa::signature::hazmat::PrehashVerifier;
+
+    let vk = VerifyingKey::from_encoded_point(&EncodedPoint::from_untagged_bytes(
+        &(**public_key).into(),
+    ))
+    .map_err(|_| Error::InvalidPublicKey)?;
+
+    let (sig, _) = decode_signature(**signature);
+    let sig =
+        p256::ecdsa::Signature::from_slice(&sig).map_err(|_| Error::InvalidSignature)?;
+
+    vk.verify_prehash(&**message, &sig)
+        .map_err(|_| Error::InvalidSignature)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use core::str::FromStr;
     use p256::ecdsa::SigningKey;
     use rand::{
         rngs::StdRng,
@@ -92,6 +123,13 @@ mod tests {
         SeedableRng,
     };
 
+    /// The key of the RFC 6979 test vectors for P-256 with SHA-256.
+    /// <https://www.rfc-editor.org/rfc/rfc6979#appendix-A.2.5>
+    const RFC6979_SECRET: &str =
+        "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const RFC6979_PUBLIC: &str = "60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6\
+                                  7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+
     #[test]
     fn test_raw_recover() {
         let mut rng = &mut StdRng::seed_from_u64(1234);
@@ -157,4 +195,62 @@ mod tests {
             assert_eq!(recovery_id, de_recid);
         }
     }
+
+    #[test]
+    fn sign_prehashed_matches_rfc6979_vectors() {
+        let secret = fuel_types::Bytes32::from_str(RFC6979_SECRET).unwrap();
+        let signing_key = SigningKey::from_bytes(&(*secret).into()).unwrap();
+        let public_key = Bytes64::from_str(RFC6979_PUBLIC).unwrap();
+        assert_eq!(encode_pubkey(*signing_key.verifying_key()), *public_key);
+
+        let vectors = [
+            (
+                "sample",
+                // The `s` of the RFC signature is normalized, and `y` is odd
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+                 8834e36ad29a83bf2bc9385e491d6099c8fdf9d1ed67aa7ea5f51f93782857a9",
+            ),
+            (
+                "test",
+                "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+                 019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+            ),
+        ];
+
+        for (message, expected) in vectors {
+            let message = Message::new(message);
+            let expected = Bytes64::from_str(expected).unwrap();
+
+            let signature =
+                sign_prehashed(&signing_key, &message).expect("Couldn't sign");
+
+            assert_eq!(signature, expected);
+            assert_eq!(recover(&signature, &message), Ok(public_key));
+            assert_eq!(verify(&signature, &public_key, &message), Ok(()));
+        }
+    }
+
+    #[test]
+    fn verify_rejects_other_message_and_key() {
+        let mut rng = &mut StdRng::seed_from_u64(1234);
+
+        let signing_key = SigningKey::random(&mut rng);
+        let public_key = Bytes64::from(encode_pubkey(*signing_key.verifying_key()));
+        let other_key = SigningKey::random(&mut rng);
+        let other_public_key = Bytes64::from(encode_pubkey(*other_key.verifying_key()));
+        let message = Message::new([rng.gen(); 100]);
+        let other_message = Message::new([rng.gen(); 100]);
+
+        let signature = sign_prehashed(&signing_key, &message).expect("Couldn't sign");
+
+        assert_eq!(verify(&signature, &public_key, &message), Ok(()));
+        assert_eq!(
+            verify(&signature, &public_key, &other_message),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            verify(&signature, &other_public_key, &message),
+            Err(Error::InvalidSignature)
+        );
+    }
 }
@@ -24,6 +24,13 @@ use core::{
     fmt::Debug,
 };
 
+mod encoding;
+
+pub use encoding::{
+    verify,
+    ProofDecodingError,
+};
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Proof {
     Inclusion(InclusionProof),
@@ -0,0 +1,419 @@
+//! The wire format of the [`Proof`], shared with the verifiers implemented outside
+//! of this crate.
+//!
+//! All integers are big-endian. Version 1 of the layout is:
+//!
+//! | Offset | Size     | Field                                                      |
+//! |--------|----------|------------------------------------------------------------|
+//! | 0      | 1        | Version, `0x01`                                            |
+//! | 1      | 1        | Kind: `0x00` for inclusion, `0x01` for exclusion           |
+//! | 2      | 2        | Number `n` of side hashes, at most 256                     |
+//! | 4      | `32 * n` | Side hashes, ordered from the leaf to the root             |
+//!
+//! The exclusion proof is followed by the closest leaf found on the path of the key:
+//!
+//! | Size | Field                                                      |
+//! |------|------------------------------------------------------------|
+//! | 1    | Leaf kind: `0x00` for placeholder, `0x01` for leaf         |
+//! | 32   | Leaf key, only for the leaf kind `0x01`                    |
+//! | 32   | Hash of the leaf value, only for the leaf kind `0x01`      |
+//!
+//! Trailing bytes are rejected, so every proof has exactly one encoding.
+
+use crate::{
+    common::{
+        Bytes32,
+        ProofSet,
+    },
+    sparse::{
+        proof::{
+            ExclusionLeaf,
+            ExclusionLeafData,
+            ExclusionProof,
+            InclusionProof,
+            Proof,
+        },
+        MerkleTreeKey,
+    },
+};
+
+use alloc::vec::Vec;
+
+const VERSION: u8 = 0x01;
+
+const INCLUSION: u8 = 0x00;
+const EXCLUSION: u8 = 0x01;
+
+const PLACEHOLDER: u8 = 0x00;
+const LEAF: u8 = 0x01;
+
+/// The maximum number of side hashes, equal to the height of the tree.
+const MAX_PROOF_SET_LEN: u16 = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum ProofDecodingError {
+    #[display(fmt = "unsupported proof version {}", _0)]
+    UnsupportedVersion(u8),
+
+    #[display(fmt = "unknown proof kind {}", _0)]
+    UnknownProofKind(u8),
+
+    #[display(fmt = "unknown exclusion leaf kind {}", _0)]
+    UnknownLeafKind(u8),
+
+    #[display(fmt = "proof set of {} hashes exceeds the height of the tree", _0)]
+    ProofSetTooLong(u16),
+
+    #[display(fmt = "unexpected end of the proof")]
+    UnexpectedEnd,
+
+    #[display(fmt = "{} trailing bytes after the proof", _0)]
+    TrailingBytes(usize),
+}
+
+impl Proof {
+    /// Encodes the proof in the versioned wire format shared with the verifiers
+    /// outside of this crate.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (kind, proof_set) = match self {
+            Proof::Inclusion(proof) => (INCLUSION, &proof.proof_set),
+            Proof::Exclusion(proof) => (EXCLUSION, &proof.proof_set),
+        };
+        let len = u16::try_from(proof_set.len())
+            .expect("The proof set is not longer than the height of the tree");
+
+        let mut bytes = Vec::new();
+        bytes.push(VERSION);
+        bytes.push(kind);
+        bytes.extend_from_slice(&len.to_be_bytes());
+        for side_hash in proof_set {
+            bytes.extend_from_slice(side_hash);
+        }
+
+        if let Proof::Exclusion(proof) = self {
+            match &proof.leaf {
+                ExclusionLeaf::Placeholder => bytes.push(PLACEHOLDER),
+                ExclusionLeaf::Leaf(data) => {
+                    bytes.push(LEAF);
+                    bytes.extend_from_slice(&data.leaf_key);
+                    bytes.extend_from_slice(&data.leaf_value);
+                }
+            }
+        }
+
+        bytes
+    }
+
+    /// Decodes the proof from the versioned wire format produced by
+    /// [`Proof::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProofDecodingError> {
+        let mut reader = Reader(bytes);
+
+        let [version] = reader.read()?;
+        if version != VERSION {
+            return Err(ProofDecodingError::UnsupportedVersion(version));
+        }
+
+        let [kind] = reader.read()?;
+        let len = u16::from_be_bytes(reader.read()?);
+        if len > MAX_PROOF_SET_LEN {
+            return Err(ProofDecodingError::ProofSetTooLong(len));
+        }
+        let proof_set = (0..len)
+            .map(|_| reader.read())
+            .collect::<Result<ProofSet, _>>()?;
+
+        let proof = match kind {
+            INCLUSION => Proof::Inclusion(InclusionProof { proof_set }),
+            EXCLUSION => {
+                let [leaf_kind] = reader.read()?;
+                let leaf = match leaf_kind {
+                    PLACEHOLDER => ExclusionLeaf::Placeholder,
+                    LEAF => ExclusionLeaf::Leaf(ExclusionLeafData {
+                        leaf_key: reader.read()?,
+                        leaf_value: reader.read()?,
+                    }),
+                    _ => return Err(ProofDecodingError::UnknownLeafKind(leaf_kind)),
+                };
+                Proof::Exclusion(ExclusionProof { proof_set, leaf })
+            }
+            _ => return Err(ProofDecodingError::UnknownProofKind(kind)),
+        };
+
+        if !reader.0.is_empty() {
+            return Err(ProofDecodingError::TrailingBytes(reader.0.len()));
+        }
+
+        Ok(proof)
+    }
+}
+
+/// Decodes the `proof` and verifies it against the `root` in one call.
+///
+/// The `value` is `Some` to verify the inclusion of the `key` with this value, and
+/// `None` to verify the exclusion of the `key`. The proof of the other kind doesn't
+/// verify.
+pub fn verify(
+    root: &Bytes32,
+    key: &MerkleTreeKey,
+    value: Option<&[u8]>,
+    proof: &[u8],
+) -> Result<bool, ProofDecodingError> {
+    let verified = match (Proof::from_bytes(proof)?, value) {
+        (Proof::Inclusion(proof), Some(value)) => proof.verify(root, key, value),
+        (Proof::Exclusion(proof), None) => proof.verify(root, key),
+        _ => false,
+    };
+    Ok(verified)
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn read<const N: usize>(&mut self) -> Result<[u8; N], ProofDecodingError> {
+        let bytes = self.0.get(..N).ok_or(ProofDecodingError::UnexpectedEnd)?;
+        let rest = self.0.get(N..).ok_or(ProofDecodingError::UnexpectedEnd)?;
+        self.0 = rest;
+        Ok(bytes.try_into().expect("The slice has the length `N`"))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::*;
+    use core::fmt;
+    use serde::{
+        de::{
+            Error,
+            Visitor,
+        },
+        Deserializer,
+        Serializer,
+    };
+
+    /// The proof is serialized in the wire format: as a hex string for the
+    /// human-readable formats and as bytes otherwise.
+    impl serde::Serialize for Proof {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let bytes = self.to_bytes();
+            if serializer.is_human_readable() {
+                serializer.serialize_str(&hex::encode(bytes))
+            } else {
+                serializer.serialize_bytes(&bytes)
+            }
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Proof {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(ProofVisitor)
+            } else {
+                deserializer.deserialize_bytes(ProofVisitor)
+            }
+        }
+    }
+
+    struct ProofVisitor;
+
+    impl<'de> Visitor<'de> for ProofVisitor {
+        type Value = Proof;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an encoded sparse Merkle proof")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            let bytes = hex::decode(value).map_err(E::custom)?;
+            self.visit_bytes(&bytes)
+        }
+
+        fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Proof::from_bytes(value).map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::sparse::in_memory::MerkleTree;
+    use serde_json::Value;
+
+    /// The golden vectors of the wire format for the cross-checks with the
+    /// implementations outside of this crate. Every tree lists its leaves, its root
+    /// and the proofs of the keys, with `value` being `null` for the exclusion.
+    const PROOF_VECTORS: &str = include_str!("proof_vectors.json");
+
+    fn bytes32(value: &Value) -> Bytes32 {
+        hex::decode(value.as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    fn bytes(value: &Value) -> Vec<u8> {
+        hex::decode(value.as_str().unwrap()).unwrap()
+    }
+
+    fn trees() -> Vec<Value> {
+        let trees: Value = serde_json::from_str(PROOF_VECTORS).unwrap();
+        trees.as_array().unwrap().clone()
+    }
+
+    /// The proof of the `key` in the tree with the `root`, with `value` being `None`
+    /// for the exclusion.
+    struct ProofVector {
+        root: Bytes32,
+        key: MerkleTreeKey,
+        value: Option<Vec<u8>>,
+        proof: Vec<u8>,
+    }
+
+    fn proofs() -> Vec<ProofVector> {
+        trees()
+            .iter()
+            .flat_map(|tree| {
+                let root = bytes32(&tree["root"]);
+                tree["vectors"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|vector| {
+                        let key =
+                            MerkleTreeKey::new_without_hash(bytes32(&vector["key"]));
+                        let value =
+                            (!vector["value"].is_null()).then(|| bytes(&vector["value"]));
+                        ProofVector {
+                            root,
+                            key,
+                            value,
+                            proof: bytes(&vector["proof"]),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn verify__accepts_golden_vectors() {
+        for ProofVector {
+            root,
+            key,
+            value,
+            proof,
+        } in proofs()
+        {
+            assert_eq!(verify(&root, &key, value.as_deref(), &proof), Ok(true));
+        }
+    }
+
+    #[test]
+    fn verify__rejects_golden_vectors_with_other_value() {
+        for ProofVector {
+            root,
+            key,
+            value,
+            proof,
+        } in proofs()
+        {
+            let other_value = match value {
+                Some(_) => None,
+                None => Some(b"OTHER".to_vec()),
+            };
+            assert_eq!(
+                verify(&root, &key, other_value.as_deref(), &proof),
+                Ok(false)
+            );
+        }
+    }
+
+    #[test]
+    fn from_bytes__round_trips_golden_vectors() {
+        for ProofVector { proof, .. } in proofs() {
+            assert_eq!(Proof::from_bytes(&proof).unwrap().to_bytes(), proof);
+        }
+    }
+
+    #[test]
+    fn to_bytes__matches_golden_vectors_generated_by_tree() {
+        for tree in trees() {
+            let mut merkle_tree = MerkleTree::new();
+            for leaf in tree["leaves"].as_array().unwrap() {
+                merkle_tree.update(
+                    MerkleTreeKey::new_without_hash(bytes32(&leaf["key"])),
+                    &bytes(&leaf["value"]),
+                );
+            }
+            assert_eq!(merkle_tree.root(), bytes32(&tree["root"]));
+
+            for vector in tree["vectors"].as_array().unwrap() {
+                let key = MerkleTreeKey::new_without_hash(bytes32(&vector["key"]));
+                let proof = merkle_tree.generate_proof(&key).unwrap();
+                assert_eq!(proof.to_bytes(), bytes(&vector["proof"]));
+            }
+        }
+    }
+
+    #[test]
+    fn from_bytes__rejects_malformed_proofs() {
+        let ProofVector { proof, .. } = proofs().pop().unwrap();
+
+        let mut unsupported_version = proof.clone();
+        unsupported_version[0] = 0x02;
+        let mut unknown_kind = proof.clone();
+        unknown_kind[1] = 0x02;
+        let mut too_long = proof.clone();
+        too_long[2..4].copy_from_slice(&257u16.to_be_bytes());
+        let mut trailing = proof.clone();
+        trailing.push(0);
+
+        assert_eq!(
+            Proof::from_bytes(&unsupported_version),
+            Err(ProofDecodingError::UnsupportedVersion(0x02))
+        );
+        assert_eq!(
+            Proof::from_bytes(&unknown_kind),
+            Err(ProofDecodingError::UnknownProofKind(0x02))
+        );
+        assert_eq!(
+            Proof::from_bytes(&too_long),
+            Err(ProofDecodingError::ProofSetTooLong(257))
+        );
+        assert_eq!(
+            Proof::from_bytes(proof.split_last().unwrap().1),
+            Err(ProofDecodingError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Proof::from_bytes(&trailing),
+            Err(ProofDecodingError::TrailingBytes(1))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde__encodes_proof_as_hex_string() {
+        for ProofVector { proof, .. } in proofs() {
+            let json =
+                serde_json::to_string(&Proof::from_bytes(&proof).unwrap()).unwrap();
+            assert_eq!(json, format!("\"{}\"", hex::encode(&proof)));
+
+            let decoded: Proof = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded.to_bytes(), proof);
+        }
+    }
+}
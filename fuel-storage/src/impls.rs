@@ -2,6 +2,7 @@ use crate::{
     Mappable,
     MerkleRoot,
     MerkleRootStorage,
+    StorageBatchMutate,
     StorageInspect,
     StorageMut,
     StorageMutate,
@@ -73,6 +74,36 @@ impl<T: StorageMutate<Type> + ?Sized, Type: Mappable> StorageMutate<Type> for &'
     }
 }
 
+impl<T: StorageBatchMutate<Type> + ?Sized, Type: Mappable> StorageBatchMutate<Type>
+    for &'_ mut T
+{
+    fn init_storage<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a Type::Key, &'a Type::Value)>,
+        Type::Key: 'a,
+        Type::Value: 'a,
+    {
+        <T as StorageBatchMutate<Type>>::init_storage(self, set)
+    }
+
+    fn insert_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a Type::Key, &'a Type::Value)>,
+        Type::Key: 'a,
+        Type::Value: 'a,
+    {
+        <T as StorageBatchMutate<Type>>::insert_batch(self, set)
+    }
+
+    fn remove_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = &'a Type::Key>,
+        Type::Key: 'a,
+    {
+        <T as StorageBatchMutate<Type>>::remove_batch(self, set)
+    }
+}
+
 impl<T: StorageSize<Type> + ?Sized, Type: Mappable> StorageSize<Type> for &'_ T {
     fn size_of_value(
         &self,
@@ -257,6 +288,41 @@ where
     }
 }
 
+impl<T, Type> StorageMut<'_, T, Type>
+where
+    T: StorageBatchMutate<Type>,
+    Type: Mappable,
+{
+    #[inline(always)]
+    pub fn init_storage<'a, Iter>(self, set: Iter) -> Result<(), T::Error>
+    where
+        Iter: Iterator<Item = (&'a Type::Key, &'a Type::Value)>,
+        Type::Key: 'a,
+        Type::Value: 'a,
+    {
+        StorageBatchMutate::init_storage(self.0, set)
+    }
+
+    #[inline(always)]
+    pub fn insert_batch<'a, Iter>(self, set: Iter) -> Result<(), T::Error>
+    where
+        Iter: Iterator<Item = (&'a Type::Key, &'a Type::Value)>,
+        Type::Key: 'a,
+        Type::Value: 'a,
+    {
+        StorageBatchMutate::insert_batch(self.0, set)
+    }
+
+    #[inline(always)]
+    pub fn remove_batch<'a, Iter>(self, set: Iter) -> Result<(), T::Error>
+    where
+        Iter: Iterator<Item = &'a Type::Key>,
+        Type::Key: 'a,
+    {
+        StorageBatchMutate::remove_batch(self.0, set)
+    }
+}
+
 impl<T, Type: Mappable> StorageMut<'_, T, Type> {
     #[inline(always)]
     pub fn root<Key>(self, key: &Key) -> Result<MerkleRoot, T::Error>
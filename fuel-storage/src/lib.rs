@@ -171,6 +171,53 @@ pub trait StorageWrite<Type: Mappable>: StorageMutate<Type> {
     fn take_bytes(&mut self, key: &Type::Key) -> Result<Option<Vec<u8>>, Self::Error>;
 }
 
+/// Base storage trait for Fuel infrastructure.
+///
+/// Allows inserting and removing many entries at once, so the backend can apply them
+/// as a single write batch. The default implementation falls back to the per-key
+/// methods of [`StorageMutate`].
+pub trait StorageBatchMutate<Type: Mappable>: StorageMutate<Type> {
+    /// Initializes the storage with `Key->Value` mappings that are expected to be
+    /// absent from it, e.g. the initial state of a newly created contract.
+    ///
+    /// Implementations may rely on that to skip the lookup of the previous values,
+    /// so it can be more performant than [`Self::insert_batch`].
+    fn init_storage<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a Type::Key, &'a Type::Value)>,
+        Type::Key: 'a,
+        Type::Value: 'a,
+    {
+        self.insert_batch(set)
+    }
+
+    /// Append all `Key->Value` mappings of the `set` to the storage, overriding the
+    /// existing values.
+    fn insert_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a Type::Key, &'a Type::Value)>,
+        Type::Key: 'a,
+        Type::Value: 'a,
+    {
+        for (key, value) in set {
+            self.insert(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Remove the `Key->Value` mappings of all keys in the `set` from the storage.
+    fn remove_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = &'a Type::Key>,
+        Type::Key: 'a,
+    {
+        for key in set {
+            self.remove(key)?;
+        }
+        Ok(())
+    }
+}
+
 /// Returns the merkle root for the `StorageType` per merkle `Key`. Per one storage, it is
 /// possible to have several merkle trees under different `Key`.
 pub trait MerkleRootStorage<Key, StorageType>: StorageInspect<StorageType>
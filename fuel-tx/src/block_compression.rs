@@ -0,0 +1,261 @@
+//! Transaction support for [`fuel_compression::block`].
+//!
+//! The compressed transactions omit the data that the chain already knows about, like the
+//! owner and the amount of a spent coin. The [`ChainState`] provides this data to the
+//! block contexts, so the registry store passed to
+//! [`fuel_compression::block::compress_transactions`] and
+//! [`fuel_compression::block::decompress_transactions`] should also implement it.
+
+use crate::{
+    input::{
+        coin::{
+            Coin,
+            CoinSpecification,
+        },
+        message::{
+            Message,
+            MessageSpecification,
+        },
+        AsField,
+        PredicateCode,
+    },
+    CompressedUtxoId,
+    Mint,
+    ScriptCode,
+    Transaction,
+    TxPointer,
+    UtxoId,
+};
+use alloc::vec::Vec;
+use fuel_compression::{
+    block::{
+        BlockCompressionCtx,
+        BlockCompressionError,
+        BlockDecompressionCtx,
+        RegistryKeyspace,
+        RegistryStore,
+        Substitutable,
+    },
+    Compressible,
+    CompressibleBy,
+    Decompress,
+    DecompressibleBy,
+    RegistryKey,
+};
+use fuel_types::{
+    Address,
+    AssetId,
+    BlockHeight,
+    Nonce,
+    Word,
+};
+
+/// The data of an unspent coin known by the chain.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CoinInfo {
+    /// The owner of the coin.
+    pub owner: Address,
+    /// The amount of the coin.
+    pub amount: Word,
+    /// The asset id of the coin.
+    pub asset_id: AssetId,
+    /// The location of the transaction that created the coin.
+    pub tx_pointer: TxPointer,
+}
+
+/// The data of an unspent message known by the chain.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MessageInfo {
+    /// The sender from the L1 chain.
+    pub sender: Address,
+    /// The receiver on the `Fuel` chain.
+    pub recipient: Address,
+    /// The amount of the base asset carried by the message.
+    pub amount: Word,
+    /// The data of the message.
+    pub data: Vec<u8>,
+}
+
+/// The chain data required by the block compression of transactions.
+pub trait ChainState {
+    /// Returns the location of the output identified by the `utxo_id`.
+    fn utxo_pointer(&self, utxo_id: &UtxoId) -> Option<CompressedUtxoId>;
+
+    /// Returns the id of the output at the `pointer`.
+    fn utxo_id(&self, pointer: &CompressedUtxoId) -> Option<UtxoId>;
+
+    /// Returns the unspent coin identified by the `utxo_id`.
+    fn coin(&self, utxo_id: &UtxoId) -> Option<CoinInfo>;
+
+    /// Returns the unspent message identified by the `nonce`.
+    fn message(&self, nonce: &Nonce) -> Option<MessageInfo>;
+
+    /// Returns the height of the block being decompressed.
+    fn block_height(&self) -> BlockHeight;
+}
+
+macro_rules! substitutable_code {
+    ($t:ident) => {
+        impl Substitutable for $t {
+            const KEYSPACE: RegistryKeyspace = RegistryKeyspace::$t;
+
+            fn to_registry_value(&self) -> Vec<u8> {
+                self.bytes.clone()
+            }
+
+            fn from_registry_value(value: &[u8]) -> Option<Self> {
+                Some(value.into())
+            }
+        }
+
+        impl<R> CompressibleBy<BlockCompressionCtx<'_, R>> for $t
+        where
+            R: RegistryStore,
+        {
+            async fn compress_with(
+                &self,
+                ctx: &mut BlockCompressionCtx<'_, R>,
+            ) -> Result<RegistryKey, BlockCompressionError> {
+                Ok(ctx.substitute(self))
+            }
+        }
+
+        impl<R> DecompressibleBy<BlockDecompressionCtx<'_, R>> for $t
+        where
+            R: RegistryStore,
+        {
+            async fn decompress_with(
+                key: RegistryKey,
+                ctx: &BlockDecompressionCtx<'_, R>,
+            ) -> Result<Self, BlockCompressionError> {
+                ctx.resolve(key)
+            }
+        }
+    };
+}
+
+substitutable_code!(ScriptCode);
+substitutable_code!(PredicateCode);
+
+impl<R> CompressibleBy<BlockCompressionCtx<'_, R>> for UtxoId
+where
+    R: RegistryStore + ChainState,
+{
+    async fn compress_with(
+        &self,
+        ctx: &mut BlockCompressionCtx<'_, R>,
+    ) -> Result<CompressedUtxoId, BlockCompressionError> {
+        ctx.registry()
+            .utxo_pointer(self)
+            .ok_or(BlockCompressionError::MissingState("UTXO pointer"))
+    }
+}
+
+impl<R> DecompressibleBy<BlockDecompressionCtx<'_, R>> for UtxoId
+where
+    R: RegistryStore + ChainState,
+{
+    async fn decompress_with(
+        pointer: CompressedUtxoId,
+        ctx: &BlockDecompressionCtx<'_, R>,
+    ) -> Result<Self, BlockCompressionError> {
+        ctx.registry()
+            .utxo_id(&pointer)
+            .ok_or(BlockCompressionError::MissingState("UTXO id"))
+    }
+}
+
+impl<'a, R, Specification> DecompressibleBy<BlockDecompressionCtx<'a, R>>
+    for Coin<Specification>
+where
+    R: RegistryStore + ChainState,
+    Specification: CoinSpecification,
+    Specification::Predicate: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+    Specification::PredicateData: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+    Specification::PredicateGasUsed: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+    Specification::Witness: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+{
+    async fn decompress_with(
+        c: <Coin<Specification> as Compressible>::Compressed,
+        ctx: &BlockDecompressionCtx<'a, R>,
+    ) -> Result<Self, BlockCompressionError> {
+        let utxo_id = UtxoId::decompress_with(c.utxo_id, ctx).await?;
+        let coin = ctx
+            .registry()
+            .coin(&utxo_id)
+            .ok_or(BlockCompressionError::MissingState("coin"))?;
+
+        Ok(Self {
+            utxo_id,
+            owner: coin.owner,
+            amount: coin.amount,
+            asset_id: coin.asset_id,
+            tx_pointer: coin.tx_pointer,
+            witness_index: c.witness_index.decompress(ctx).await?,
+            predicate_gas_used: c.predicate_gas_used.decompress(ctx).await?,
+            predicate: c.predicate.decompress(ctx).await?,
+            predicate_data: c.predicate_data.decompress(ctx).await?,
+        })
+    }
+}
+
+impl<'a, R, Specification> DecompressibleBy<BlockDecompressionCtx<'a, R>>
+    for Message<Specification>
+where
+    R: RegistryStore + ChainState,
+    Specification: MessageSpecification,
+    Specification::Data: Default,
+    Specification::Predicate: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+    Specification::PredicateData: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+    Specification::PredicateGasUsed: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+    Specification::Witness: DecompressibleBy<BlockDecompressionCtx<'a, R>>,
+{
+    async fn decompress_with(
+        c: <Message<Specification> as Compressible>::Compressed,
+        ctx: &BlockDecompressionCtx<'a, R>,
+    ) -> Result<Self, BlockCompressionError> {
+        let info = ctx
+            .registry()
+            .message(&c.nonce)
+            .ok_or(BlockCompressionError::MissingState("message"))?;
+
+        let mut message: Message<Specification> = Message {
+            sender: info.sender,
+            recipient: info.recipient,
+            amount: info.amount,
+            nonce: c.nonce,
+            witness_index: c.witness_index.decompress(ctx).await?,
+            predicate_gas_used: c.predicate_gas_used.decompress(ctx).await?,
+            data: Default::default(),
+            predicate: c.predicate.decompress(ctx).await?,
+            predicate_data: c.predicate_data.decompress(ctx).await?,
+        };
+
+        if let Some(data) = message.data.as_mut_field() {
+            *data = info.data;
+        }
+
+        Ok(message)
+    }
+}
+
+impl<R> DecompressibleBy<BlockDecompressionCtx<'_, R>> for Mint
+where
+    R: RegistryStore + ChainState,
+{
+    async fn decompress_with(
+        c: Self::Compressed,
+        ctx: &BlockDecompressionCtx<'_, R>,
+    ) -> Result<Self, BlockCompressionError> {
+        let tx_pointer = TxPointer::new(ctx.registry().block_height(), ctx.tx_index());
+
+        Ok(Transaction::mint(
+            tx_pointer,
+            c.input_contract.decompress(ctx).await?,
+            c.output_contract.decompress(ctx).await?,
+            c.mint_amount.decompress(ctx).await?,
+            c.mint_asset_id.decompress(ctx).await?,
+            c.gas_price.decompress(ctx).await?,
+        ))
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
             self,
             BytecodeWitnessIndex,
             Expiration,
+            FlagDefaults,
             Maturity,
             Tip,
             Witnesses,
@@ -35,6 +36,7 @@ use crate::{
     UpgradePurpose,
     Upload,
     UploadBody,
+    ValidityError,
     Witness,
 };
 
@@ -51,6 +53,7 @@ use crate::{
     },
     policies::Policies,
     transaction::{
+        size_with_witness_sizes,
         CreateBody,
         ScriptBody,
         UpgradeBody,
@@ -60,8 +63,12 @@ use alloc::{
     collections::BTreeMap,
     vec::Vec,
 };
-use fuel_crypto::SecretKey;
+use fuel_crypto::{
+    SecretKey,
+    Signature,
+};
 use fuel_types::{
+    canonical::Serialize,
     AssetId,
     BlockHeight,
     ChainId,
@@ -202,6 +209,22 @@ impl TransactionBuilder<Upgrade> {
         };
         Self::with_tx(tx)
     }
+
+    /// Creates the builder of the [`Upgrade`] transaction changing the consensus
+    /// parameters to `consensus_parameters`. The serialized consensus parameters are
+    /// added as the first witness, and the checksum is calculated from them.
+    pub fn upgrade_consensus_parameters(
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Self, ValidityError> {
+        let tx = Transaction::upgrade_consensus_parameters(
+            consensus_parameters,
+            Policies::new().with_max_fee(0),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )?;
+        Ok(Self::with_tx(tx))
+    }
 }
 
 impl TransactionBuilder<Upload> {
@@ -230,6 +253,16 @@ impl TransactionBuilder<Blob> {
         };
         Self::with_tx(tx)
     }
+
+    /// Sets the id of the blob to the hash of its witness.
+    pub fn compute_blob_id(&mut self) -> &mut Self {
+        self.tx.body.id = self
+            .tx
+            .body
+            .compute_id_from_witnesses(&self.tx.witnesses)
+            .expect("Should have the blob witness");
+        self
+    }
 }
 
 impl TransactionBuilder<Mint> {
@@ -383,6 +416,12 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    pub fn flag_defaults(&mut self, flags: Word) -> &mut Self {
+        self.tx.set_flag_defaults(flags);
+
+        self
+    }
+
     pub fn witness_limit(&mut self, witness_limit: Word) -> &mut Self {
         self.tx.set_witness_limit(witness_limit);
 
@@ -536,6 +575,28 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
     }
 }
 
+impl<Tx> TransactionBuilder<Tx>
+where
+    Tx: Buildable + Serialize,
+{
+    /// Returns the size of the transaction after [`Finalizable::finalize`], when the
+    /// empty witnesses of the signing keys are replaced by the signatures. The
+    /// predicates and their data are already part of the inputs.
+    pub fn estimated_size(&self) -> usize {
+        let mut witness_sizes = self
+            .witnesses()
+            .iter()
+            .map(|witness| witness.as_ref().len())
+            .collect::<Vec<_>>();
+        for index in self.sign_keys.values() {
+            if let Some(size) = witness_sizes.get_mut(usize::from(*index)) {
+                *size = Signature::LEN;
+            }
+        }
+        size_with_witness_sizes(self.tx.size(), self.witnesses(), &witness_sizes)
+    }
+}
+
 impl<Tx: field::Outputs> TransactionBuilder<Tx> {
     pub fn add_output(&mut self, output: Output) -> &mut Self {
         self.tx.outputs_mut().push(output);
@@ -21,6 +21,7 @@ extern crate alloc;
 extern crate core;
 
 pub mod consts;
+pub mod serde_human;
 mod tx_pointer;
 
 pub use fuel_asm::{
@@ -40,7 +41,10 @@ pub use fuel_types::{
     Salt,
     Word,
 };
-pub use tx_pointer::TxPointer;
+pub use tx_pointer::{
+    TxPointer,
+    TxPointerParseError,
+};
 
 #[cfg(feature = "test-helpers")]
 mod builder;
@@ -54,6 +58,9 @@ mod receipt;
 #[cfg(feature = "alloc")]
 mod transaction;
 
+#[cfg(feature = "da-compression")]
+pub mod block_compression;
+
 #[cfg(test)]
 mod tests;
 
@@ -69,8 +76,12 @@ pub use builder::{
 
 #[cfg(feature = "alloc")]
 pub use receipt::{
+    FlatReceipt,
+    FlatReceiptError,
     Receipt,
+    ReceiptRepr,
     ScriptExecutionResult,
+    SequenceError,
 };
 
 #[cfg(feature = "alloc")]
@@ -80,6 +91,7 @@ pub use transaction::{
     input,
     input::Input,
     input::InputRepr,
+    input::PredicateOwnerError,
     output,
     output::Output,
     output::OutputRepr,
@@ -107,6 +119,7 @@ pub use transaction::{
     Script,
     ScriptCode,
     ScriptParameters,
+    SharedPredicateStats,
     StorageSlot,
     Transaction,
     TransactionFee,
@@ -115,6 +128,8 @@ pub use transaction::{
     TxParameters,
     Upgrade,
     UpgradeBody,
+    UpgradeChange,
+    UpgradeConstraints,
     UpgradeMetadata,
     UpgradePurpose,
     Upload,
@@ -122,6 +137,7 @@ pub use transaction::{
     UploadMetadata,
     UploadSubsection,
     UtxoId,
+    UtxoIdParseError,
     ValidityError,
     Witness,
 };
@@ -1,9 +1,14 @@
 use crate::Output;
 use alloc::vec::Vec;
 use educe::Educe;
-use fuel_asm::PanicInstruction;
+use fuel_asm::{
+    Instruction,
+    PanicInstruction,
+};
 use fuel_crypto::Hasher;
+use fuel_merkle::binary::root_calculator::MerkleRootCalculator;
 use fuel_types::{
+    bytes::WORD_SIZE,
     canonical::{
         Deserialize,
         Serialize,
@@ -18,11 +23,19 @@ use fuel_types::{
     Word,
 };
 
+mod flat;
 mod receipt_repr;
 mod script_result;
+mod sequence;
 
 use crate::input::message::compute_message_id;
+pub use flat::{
+    FlatReceipt,
+    FlatReceiptError,
+};
+pub use receipt_repr::ReceiptRepr;
 pub use script_result::ScriptExecutionResult;
+pub use sequence::SequenceError;
 
 #[derive(Clone, Educe, serde::Serialize, serde::Deserialize, Deserialize, Serialize)]
 #[educe(Eq, PartialEq, Hash, Debug)]
@@ -362,6 +375,11 @@ impl Receipt {
         Self::ScriptResult { result, gas_used }
     }
 
+    /// Creates the `MessageOut` receipt of the message sent by `SMO`.
+    ///
+    /// The nonce of the message is derived from the `txid` and the index `idx` of
+    /// the receipt in the receipts of the transaction, see [`Output::message_nonce`].
+    /// The digest is the hash of the `data`.
     pub fn message_out(
         txid: &Bytes32,
         idx: Word,
@@ -499,6 +517,17 @@ impl Receipt {
         }
     }
 
+    /// Returns the index of the instruction at `pc` in the code starting at `is`,
+    /// i.e. `(pc - is) / 4`.
+    ///
+    /// Returns `None` if the receipt carries no `pc` and `is`, or if `pc` is below
+    /// `is`. The instruction itself is recoverable from the
+    /// [`PanicInstruction::instruction`] of the panic receipts.
+    pub fn instruction_index(&self) -> Option<Word> {
+        let offset = self.pc()?.checked_sub(self.is()?)?;
+        offset.checked_div(Instruction::SIZE as Word)
+    }
+
     #[inline(always)]
     pub fn to(&self) -> Option<&ContractId> {
         trim_contract_id(match self {
@@ -607,6 +636,28 @@ impl Receipt {
         }
     }
 
+    /// Returns `len` bytes of the data starting at `offset`, or `None` if the receipt
+    /// carries no data or the range is out of its bounds.
+    pub fn data_slice(&self, offset: usize, len: usize) -> Option<&[u8]> {
+        let end = offset.checked_add(len)?;
+        self.data()?.get(offset..end)
+    }
+
+    /// Returns the big-endian word with the `index` in the data, or `None` if the
+    /// receipt carries no data or the word is out of its bounds.
+    pub fn data_word(&self, index: usize) -> Option<Word> {
+        let offset = index.checked_mul(WORD_SIZE)?;
+        let bytes = self.data_slice(offset, WORD_SIZE)?;
+        Some(Word::from_be_bytes(bytes.try_into().ok()?))
+    }
+
+    /// Returns 32 bytes of the data starting at `offset`, or `None` if the receipt
+    /// carries no data or the bytes are out of its bounds.
+    pub fn data_b256(&self, offset: usize) -> Option<Bytes32> {
+        let bytes = self.data_slice(offset, Bytes32::LEN)?;
+        Bytes32::try_from(bytes).ok()
+    }
+
     pub const fn reason(&self) -> Option<PanicInstruction> {
         match self {
             Self::Panic { reason, .. } => Some(*reason),
@@ -659,6 +710,14 @@ impl Receipt {
         }
     }
 
+    /// Returns the id of the message sent by the `MessageOut` receipt.
+    ///
+    /// The id is the hash of `sender ++ recipient ++ nonce ++ amount ++ data`, the
+    /// same as the id of the message input spending the message, see
+    /// [`compute_message_id`].
+    ///
+    /// Returns `None` for the other receipts, and if the data of the message was
+    /// stripped from the receipt, since the id can't be computed from the digest.
     pub fn message_id(&self) -> Option<MessageId> {
         match self {
             Self::MessageOut {
@@ -666,11 +725,18 @@ impl Receipt {
                 recipient,
                 amount,
                 nonce,
+                len,
                 data,
                 ..
-            } => data.as_ref().map(|data| {
-                compute_message_id(sender, recipient, nonce, *amount, data.as_slice())
-            }),
+            } => {
+                let data = match data {
+                    Some(data) => data.as_slice(),
+                    // The stripped empty data is known anyway
+                    None if *len == 0 => &[],
+                    None => return None,
+                };
+                Some(compute_message_id(sender, recipient, nonce, *amount, data))
+            }
             _ => None,
         }
     }
@@ -702,6 +768,17 @@ impl Receipt {
             _ => None,
         }
     }
+
+    /// Computes the Merkle root of the `receipts`, the same way as the VM computes
+    /// the `receipts_root` of the [`Script`](crate::Script). The leaves of the
+    /// binary Merkle tree are the canonically serialized receipts.
+    pub fn compute_root(receipts: &[Receipt]) -> Bytes32 {
+        let mut tree = MerkleRootCalculator::new();
+        for receipt in receipts {
+            tree.push(receipt.to_bytes().as_slice());
+        }
+        tree.root().into()
+    }
 }
 
 fn trim_contract_id(id: Option<&ContractId>) -> Option<&ContractId> {
@@ -716,8 +793,28 @@ fn trim_contract_id(id: Option<&ContractId>) -> Option<&ContractId> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Receipt;
-    use fuel_types::ContractId;
+    use crate::{
+        Input,
+        Output,
+        Receipt,
+        ScriptExecutionResult,
+    };
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use fuel_asm::{
+        PanicInstruction,
+        PanicReason,
+    };
+    use fuel_types::{
+        Address,
+        Bytes32,
+        ContractId,
+        MessageId,
+        Nonce,
+        Word,
+    };
 
     // TODO: Rewrite the test cases when `Receipt` will have its struct for
     //  each variant. It will allow to use `Default` trait.
@@ -815,4 +912,162 @@ mod tests {
     fn receipt_to(#[case] receipt: Receipt, #[case] expected_to: Option<ContractId>) {
         assert_eq!(receipt.to(), expected_to.as_ref());
     }
+
+    fn log_data(data: Vec<u8>) -> Receipt {
+        Receipt::log_data(Default::default(), 0, 0, 0, 0, 0, data)
+    }
+
+    #[rstest::rstest]
+    #[case(log_data(vec![]))]
+    #[case(log_data(vec![1; 7]))]
+    #[case(Receipt::return_data(Default::default(), 0, 0, 0, vec![1; 7]))]
+    #[case(Receipt::message_out(
+        &Default::default(),
+        0,
+        Default::default(),
+        Default::default(),
+        0,
+        vec![1; 7],
+    ))]
+    fn data_helpers_return_none_on_short_data(#[case] receipt: Receipt) {
+        assert_eq!(receipt.data_word(0), None);
+        assert_eq!(receipt.data_b256(0), None);
+        assert_eq!(receipt.data_slice(0, 32), None);
+    }
+
+    #[test]
+    fn data_helpers_return_values_of_exact_length_data() {
+        let data = [[1; 8], [2; 8], [3; 8], [4; 8]].concat();
+        let receipt = log_data(data.clone());
+
+        assert_eq!(receipt.data_word(0), Some(0x0101010101010101));
+        assert_eq!(receipt.data_word(3), Some(0x0404040404040404));
+        assert_eq!(
+            receipt.data_b256(0),
+            Some(Bytes32::try_from(&data[..]).unwrap())
+        );
+        assert_eq!(receipt.data_slice(0, 32), Some(&data[..]));
+        assert_eq!(receipt.data_slice(32, 0), Some(&[][..]));
+    }
+
+    #[test]
+    fn data_helpers_return_none_beyond_bounds() {
+        let receipt = log_data(vec![1; 32]);
+
+        assert_eq!(receipt.data_word(4), None);
+        assert_eq!(receipt.data_word(usize::MAX), None);
+        assert_eq!(receipt.data_b256(1), None);
+        assert_eq!(receipt.data_b256(usize::MAX), None);
+        assert_eq!(receipt.data_slice(31, 2), None);
+        assert_eq!(receipt.data_slice(33, 0), None);
+        assert_eq!(receipt.data_slice(usize::MAX, 1), None);
+    }
+
+    #[test]
+    fn data_helpers_return_none_without_data() {
+        let receipt = Receipt::ret(Default::default(), 0, 0, 0);
+        let stripped = Receipt::log_data_with_len(
+            Default::default(),
+            0,
+            0,
+            0,
+            8,
+            Default::default(),
+            0,
+            0,
+            None,
+        );
+
+        for receipt in [receipt, stripped] {
+            assert_eq!(receipt.data_word(0), None);
+            assert_eq!(receipt.data_b256(0), None);
+            assert_eq!(receipt.data_slice(0, 0), None);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(Receipt::ret(Default::default(), 0, 1000, 1000), Some(0))]
+    #[case(Receipt::ret(Default::default(), 0, 1012, 1000), Some(3))]
+    #[case(Receipt::revert(Default::default(), 0, 1012, 1000), Some(3))]
+    #[case(
+        Receipt::panic(
+            Default::default(),
+            PanicInstruction::error(PanicReason::ArithmeticError, 0),
+            0x1_0000_0010,
+            0x1_0000_0000,
+        ),
+        Some(4)
+    )]
+    #[case(Receipt::ret(Default::default(), 0, 996, 1000), None)]
+    #[case(Receipt::script_result(ScriptExecutionResult::Success, 0), None)]
+    fn instruction_index_is_relative_to_is(
+        #[case] receipt: Receipt,
+        #[case] expected: Option<Word>,
+    ) {
+        assert_eq!(receipt.instruction_index(), expected);
+    }
+
+    fn message_out(nonce: Nonce, len: Word, data: Option<Vec<u8>>) -> Receipt {
+        Receipt::message_out_with_len(
+            Address::new([1; 32]),
+            Address::new([2; 32]),
+            4,
+            nonce,
+            len,
+            Default::default(),
+            data,
+        )
+    }
+
+    #[test]
+    fn message_id_matches_known_vector() {
+        let receipt = message_out(Nonce::new([3; 32]), 3, Some(vec![5, 6, 7]));
+
+        let expected: MessageId =
+            "c2ea5ddb04288a066432b790c6efeb2e3793a40145c460fc859ada2ba5cf80f5"
+                .parse()
+                .unwrap();
+        assert_eq!(receipt.message_id(), Some(expected));
+    }
+
+    #[test]
+    fn message_id_matches_the_message_input() {
+        let txid = Bytes32::new([9; 32]);
+        let data = vec![5, 6, 7];
+        let receipt = Receipt::message_out(
+            &txid,
+            3,
+            Address::new([1; 32]),
+            Address::new([2; 32]),
+            4,
+            data.clone(),
+        );
+        let nonce = Output::message_nonce(&txid, 3);
+
+        let input = Input::message_data_signed(
+            Address::new([1; 32]),
+            Address::new([2; 32]),
+            4,
+            nonce,
+            0,
+            data,
+        );
+
+        assert_eq!(receipt.nonce(), Some(&nonce));
+        assert_eq!(receipt.message_id(), input.message_id());
+    }
+
+    #[test]
+    fn message_id_of_stripped_data() {
+        let nonce = Nonce::new([3; 32]);
+        let stripped = message_out(nonce, 3, None);
+        let stripped_empty = message_out(nonce, 0, None);
+
+        assert_eq!(stripped.message_id(), None);
+        assert_eq!(
+            stripped_empty.message_id(),
+            message_out(nonce, 0, Some(vec![])).message_id()
+        );
+        assert!(stripped_empty.message_id().is_some());
+    }
 }
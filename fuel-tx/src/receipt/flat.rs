@@ -0,0 +1,632 @@
+use super::{
+    receipt_repr::ReceiptRepr,
+    Receipt,
+    ScriptExecutionResult,
+};
+use crate::TxId;
+use alloc::vec::Vec;
+use fuel_asm::PanicInstruction;
+use fuel_types::{
+    Address,
+    AssetId,
+    Bytes32,
+    ContractId,
+    Nonce,
+    Word,
+};
+
+/// The receipt flattened into uniform nullable columns, for storing receipts of
+/// all kinds in a single table.
+///
+/// The conversion is lossless for every receipt variant: each field of a receipt
+/// has its own column, and the columns not used by the `kind` are `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct FlatReceipt {
+    /// The id of the transaction that produced the receipt.
+    pub tx_id: TxId,
+    /// The index of the receipt in the receipts of the transaction.
+    pub index: u64,
+    /// The variant of the receipt.
+    pub kind: ReceiptRepr,
+    /// The `id` of the contract receipts, or the `contract_id` of the `Mint` and
+    /// `Burn` receipts.
+    pub contract_id: Option<ContractId>,
+    /// The `to` contract of the `Call` and `Transfer` receipts, or the `to` address
+    /// of the `TransferOut` receipt.
+    pub to: Option<Bytes32>,
+    pub amount: Option<Word>,
+    pub asset_id: Option<AssetId>,
+    pub gas: Option<Word>,
+    pub param1: Option<Word>,
+    pub param2: Option<Word>,
+    pub val: Option<Word>,
+    pub ra: Option<Word>,
+    pub rb: Option<Word>,
+    pub rc: Option<Word>,
+    pub rd: Option<Word>,
+    pub ptr: Option<Word>,
+    pub len: Option<Word>,
+    pub digest: Option<Bytes32>,
+    pub data: Option<Vec<u8>>,
+    pub pc: Option<Word>,
+    pub is: Option<Word>,
+    pub reason: Option<PanicInstruction>,
+    /// The `contract_id` of the `Panic` receipt.
+    pub panic_contract_id: Option<ContractId>,
+    pub result: Option<ScriptExecutionResult>,
+    pub gas_used: Option<Word>,
+    pub sender: Option<Address>,
+    pub recipient: Option<Address>,
+    pub nonce: Option<Nonce>,
+    pub sub_id: Option<Bytes32>,
+}
+
+/// The error returned when a [`FlatReceipt`] can't be converted back into a
+/// [`Receipt`].
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[non_exhaustive]
+pub enum FlatReceiptError {
+    /// The column required by the receipt kind is `None`.
+    #[display("The `{column}` column of the `{kind:?}` receipt is missing")]
+    MissingColumn {
+        kind: ReceiptRepr,
+        column: &'static str,
+    },
+}
+
+impl FlatReceipt {
+    fn new(tx_id: TxId, index: u64, kind: ReceiptRepr) -> Self {
+        Self {
+            tx_id,
+            index,
+            kind,
+            contract_id: None,
+            to: None,
+            amount: None,
+            asset_id: None,
+            gas: None,
+            param1: None,
+            param2: None,
+            val: None,
+            ra: None,
+            rb: None,
+            rc: None,
+            rd: None,
+            ptr: None,
+            len: None,
+            digest: None,
+            data: None,
+            pc: None,
+            is: None,
+            reason: None,
+            panic_contract_id: None,
+            result: None,
+            gas_used: None,
+            sender: None,
+            recipient: None,
+            nonce: None,
+            sub_id: None,
+        }
+    }
+
+    /// Reconstructs the receipt from its columns.
+    pub fn try_into_receipt(&self) -> Result<Receipt, FlatReceiptError> {
+        let kind = self.kind;
+        let get = |value: Option<Word>, column| {
+            value.ok_or(FlatReceiptError::MissingColumn { kind, column })
+        };
+        let id = || {
+            self.contract_id.ok_or(FlatReceiptError::MissingColumn {
+                kind,
+                column: "contract_id",
+            })
+        };
+        let to = || {
+            self.to
+                .ok_or(FlatReceiptError::MissingColumn { kind, column: "to" })
+        };
+        let asset_id = || {
+            self.asset_id.ok_or(FlatReceiptError::MissingColumn {
+                kind,
+                column: "asset_id",
+            })
+        };
+        let digest = || {
+            self.digest.ok_or(FlatReceiptError::MissingColumn {
+                kind,
+                column: "digest",
+            })
+        };
+        let sub_id = || {
+            self.sub_id.ok_or(FlatReceiptError::MissingColumn {
+                kind,
+                column: "sub_id",
+            })
+        };
+        let pc = || get(self.pc, "pc");
+        let is = || get(self.is, "is");
+
+        let receipt = match kind {
+            ReceiptRepr::Call => Receipt::Call {
+                id: id()?,
+                to: ContractId::from(*to()?),
+                amount: get(self.amount, "amount")?,
+                asset_id: asset_id()?,
+                gas: get(self.gas, "gas")?,
+                param1: get(self.param1, "param1")?,
+                param2: get(self.param2, "param2")?,
+                pc: pc()?,
+                is: is()?,
+            },
+            ReceiptRepr::Return => Receipt::Return {
+                id: id()?,
+                val: get(self.val, "val")?,
+                pc: pc()?,
+                is: is()?,
+            },
+            ReceiptRepr::ReturnData => Receipt::ReturnData {
+                id: id()?,
+                ptr: get(self.ptr, "ptr")?,
+                len: get(self.len, "len")?,
+                digest: digest()?,
+                pc: pc()?,
+                is: is()?,
+                data: self.data.clone(),
+            },
+            ReceiptRepr::Panic => Receipt::Panic {
+                id: id()?,
+                reason: self.reason.ok_or(FlatReceiptError::MissingColumn {
+                    kind,
+                    column: "reason",
+                })?,
+                pc: pc()?,
+                is: is()?,
+                contract_id: self.panic_contract_id,
+            },
+            ReceiptRepr::Revert => Receipt::Revert {
+                id: id()?,
+                ra: get(self.ra, "ra")?,
+                pc: pc()?,
+                is: is()?,
+            },
+            ReceiptRepr::Log => Receipt::Log {
+                id: id()?,
+                ra: get(self.ra, "ra")?,
+                rb: get(self.rb, "rb")?,
+                rc: get(self.rc, "rc")?,
+                rd: get(self.rd, "rd")?,
+                pc: pc()?,
+                is: is()?,
+            },
+            ReceiptRepr::LogData => Receipt::LogData {
+                id: id()?,
+                ra: get(self.ra, "ra")?,
+                rb: get(self.rb, "rb")?,
+                ptr: get(self.ptr, "ptr")?,
+                len: get(self.len, "len")?,
+                digest: digest()?,
+                pc: pc()?,
+                is: is()?,
+                data: self.data.clone(),
+            },
+            ReceiptRepr::Transfer => Receipt::Transfer {
+                id: id()?,
+                to: ContractId::from(*to()?),
+                amount: get(self.amount, "amount")?,
+                asset_id: asset_id()?,
+                pc: pc()?,
+                is: is()?,
+            },
+            ReceiptRepr::TransferOut => Receipt::TransferOut {
+                id: id()?,
+                to: Address::from(*to()?),
+                amount: get(self.amount, "amount")?,
+                asset_id: asset_id()?,
+                pc: pc()?,
+                is: is()?,
+            },
+            ReceiptRepr::ScriptResult => Receipt::ScriptResult {
+                result: self.result.ok_or(FlatReceiptError::MissingColumn {
+                    kind,
+                    column: "result",
+                })?,
+                gas_used: get(self.gas_used, "gas_used")?,
+            },
+            ReceiptRepr::MessageOut => Receipt::MessageOut {
+                sender: self.sender.ok_or(FlatReceiptError::MissingColumn {
+                    kind,
+                    column: "sender",
+                })?,
+                recipient: self.recipient.ok_or(FlatReceiptError::MissingColumn {
+                    kind,
+                    column: "recipient",
+                })?,
+                amount: get(self.amount, "amount")?,
+                nonce: self.nonce.ok_or(FlatReceiptError::MissingColumn {
+                    kind,
+                    column: "nonce",
+                })?,
+                len: get(self.len, "len")?,
+                digest: digest()?,
+                data: self.data.clone(),
+            },
+            ReceiptRepr::Mint => Receipt::Mint {
+                sub_id: sub_id()?,
+                contract_id: id()?,
+                val: get(self.val, "val")?,
+                pc: pc()?,
+                is: is()?,
+            },
+            ReceiptRepr::Burn => Receipt::Burn {
+                sub_id: sub_id()?,
+                contract_id: id()?,
+                val: get(self.val, "val")?,
+                pc: pc()?,
+                is: is()?,
+            },
+        };
+
+        Ok(receipt)
+    }
+}
+
+impl Receipt {
+    /// Flattens the receipt into uniform nullable columns, see [`FlatReceipt`].
+    pub fn to_flat(&self, tx_id: &TxId, index: usize) -> FlatReceipt {
+        let mut flat = FlatReceipt::new(*tx_id, index as u64, ReceiptRepr::from(self));
+
+        match self.clone() {
+            Receipt::Call {
+                id,
+                to,
+                amount,
+                asset_id,
+                gas,
+                param1,
+                param2,
+                pc,
+                is,
+            } => {
+                flat.contract_id = Some(id);
+                flat.to = Some(Bytes32::from(*to));
+                flat.amount = Some(amount);
+                flat.asset_id = Some(asset_id);
+                flat.gas = Some(gas);
+                flat.param1 = Some(param1);
+                flat.param2 = Some(param2);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+            }
+            Receipt::Return { id, val, pc, is } => {
+                flat.contract_id = Some(id);
+                flat.val = Some(val);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+            }
+            Receipt::ReturnData {
+                id,
+                ptr,
+                len,
+                digest,
+                pc,
+                is,
+                data,
+            } => {
+                flat.contract_id = Some(id);
+                flat.ptr = Some(ptr);
+                flat.len = Some(len);
+                flat.digest = Some(digest);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+                flat.data = data;
+            }
+            Receipt::Panic {
+                id,
+                reason,
+                pc,
+                is,
+                contract_id,
+            } => {
+                flat.contract_id = Some(id);
+                flat.reason = Some(reason);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+                flat.panic_contract_id = contract_id;
+            }
+            Receipt::Revert { id, ra, pc, is } => {
+                flat.contract_id = Some(id);
+                flat.ra = Some(ra);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+            }
+            Receipt::Log {
+                id,
+                ra,
+                rb,
+                rc,
+                rd,
+                pc,
+                is,
+            } => {
+                flat.contract_id = Some(id);
+                flat.ra = Some(ra);
+                flat.rb = Some(rb);
+                flat.rc = Some(rc);
+                flat.rd = Some(rd);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+            }
+            Receipt::LogData {
+                id,
+                ra,
+                rb,
+                ptr,
+                len,
+                digest,
+                pc,
+                is,
+                data,
+            } => {
+                flat.contract_id = Some(id);
+                flat.ra = Some(ra);
+                flat.rb = Some(rb);
+                flat.ptr = Some(ptr);
+                flat.len = Some(len);
+                flat.digest = Some(digest);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+                flat.data = data;
+            }
+            Receipt::Transfer {
+                id,
+                to,
+                amount,
+                asset_id,
+                pc,
+                is,
+            } => {
+                flat.contract_id = Some(id);
+                flat.to = Some(Bytes32::from(*to));
+                flat.amount = Some(amount);
+                flat.asset_id = Some(asset_id);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+            }
+            Receipt::TransferOut {
+                id,
+                to,
+                amount,
+                asset_id,
+                pc,
+                is,
+            } => {
+                flat.contract_id = Some(id);
+                flat.to = Some(Bytes32::from(*to));
+                flat.amount = Some(amount);
+                flat.asset_id = Some(asset_id);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+            }
+            Receipt::ScriptResult { result, gas_used } => {
+                flat.result = Some(result);
+                flat.gas_used = Some(gas_used);
+            }
+            Receipt::MessageOut {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                len,
+                digest,
+                data,
+            } => {
+                flat.sender = Some(sender);
+                flat.recipient = Some(recipient);
+                flat.amount = Some(amount);
+                flat.nonce = Some(nonce);
+                flat.len = Some(len);
+                flat.digest = Some(digest);
+                flat.data = data;
+            }
+            Receipt::Mint {
+                sub_id,
+                contract_id,
+                val,
+                pc,
+                is,
+            }
+            | Receipt::Burn {
+                sub_id,
+                contract_id,
+                val,
+                pc,
+                is,
+            } => {
+                flat.sub_id = Some(sub_id);
+                flat.contract_id = Some(contract_id);
+                flat.val = Some(val);
+                flat.pc = Some(pc);
+                flat.is = Some(is);
+            }
+        }
+
+        flat
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use fuel_asm::PanicReason;
+
+    fn contract(byte: u8) -> ContractId {
+        ContractId::from([byte; 32])
+    }
+
+    #[rstest::rstest]
+    #[case(Receipt::Call {
+        id: contract(1),
+        to: contract(2),
+        amount: 3,
+        asset_id: AssetId::from([4; 32]),
+        gas: 5,
+        param1: 6,
+        param2: 7,
+        pc: 8,
+        is: 9,
+    })]
+    #[case(Receipt::Return { id: contract(1), val: 2, pc: 3, is: 4 })]
+    #[case(Receipt::ReturnData {
+        id: contract(1),
+        ptr: 2,
+        len: 3,
+        digest: Bytes32::from([4; 32]),
+        pc: 5,
+        is: 6,
+        data: Some(vec![7, 8, 9]),
+    })]
+    #[case(Receipt::ReturnData {
+        id: contract(1),
+        ptr: 2,
+        len: 3,
+        digest: Bytes32::from([4; 32]),
+        pc: 5,
+        is: 6,
+        data: None,
+    })]
+    #[case(Receipt::Panic {
+        id: contract(1),
+        reason: PanicInstruction::error(PanicReason::ContractNotInInputs, 2),
+        pc: 3,
+        is: 4,
+        contract_id: Some(contract(5)),
+    })]
+    #[case(Receipt::Revert { id: contract(1), ra: 2, pc: 3, is: 4 })]
+    #[case(Receipt::Log {
+        id: contract(1),
+        ra: 2,
+        rb: 3,
+        rc: 4,
+        rd: 5,
+        pc: 6,
+        is: 7,
+    })]
+    #[case(Receipt::LogData {
+        id: contract(1),
+        ra: 2,
+        rb: 3,
+        ptr: 4,
+        len: 5,
+        digest: Bytes32::from([6; 32]),
+        pc: 7,
+        is: 8,
+        data: Some(vec![9; 5]),
+    })]
+    #[case(Receipt::Transfer {
+        id: contract(1),
+        to: contract(2),
+        amount: 3,
+        asset_id: AssetId::from([4; 32]),
+        pc: 5,
+        is: 6,
+    })]
+    #[case(Receipt::TransferOut {
+        id: contract(1),
+        to: Address::from([2; 32]),
+        amount: 3,
+        asset_id: AssetId::from([4; 32]),
+        pc: 5,
+        is: 6,
+    })]
+    #[case(Receipt::ScriptResult {
+        result: ScriptExecutionResult::GenericFailure(1),
+        gas_used: 2,
+    })]
+    #[case(Receipt::MessageOut {
+        sender: Address::from([1; 32]),
+        recipient: Address::from([2; 32]),
+        amount: 3,
+        nonce: Nonce::from([4; 32]),
+        len: 5,
+        digest: Bytes32::from([6; 32]),
+        data: Some(vec![7; 5]),
+    })]
+    #[case(Receipt::Mint {
+        sub_id: Bytes32::from([1; 32]),
+        contract_id: contract(2),
+        val: 3,
+        pc: 4,
+        is: 5,
+    })]
+    #[case(Receipt::Burn {
+        sub_id: Bytes32::from([1; 32]),
+        contract_id: contract(2),
+        val: 3,
+        pc: 4,
+        is: 5,
+    })]
+    fn to_flat__round_trips_receipt(#[case] receipt: Receipt) {
+        // Given
+        let tx_id = TxId::from([0xaa; 32]);
+
+        // When
+        let flat = receipt.to_flat(&tx_id, 3);
+        let json = serde_json::to_string(&flat).expect("Failed to serialize");
+        let decoded: FlatReceipt =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+        let restored = decoded.try_into_receipt().expect("Lossless conversion");
+
+        // Then
+        assert_eq!(decoded, flat);
+        assert_eq!((flat.tx_id, flat.index), (tx_id, 3));
+        assert_eq!(flat.kind, ReceiptRepr::from(&receipt));
+        assert_eq!(restored, receipt);
+        assert_eq!(restored.data(), receipt.data());
+        if let (
+            Receipt::Panic { contract_id, .. },
+            Receipt::Panic {
+                contract_id: expected,
+                ..
+            },
+        ) = (&restored, &receipt)
+        {
+            assert_eq!(contract_id, expected);
+        }
+    }
+
+    #[test]
+    fn try_into_receipt__fails_on_missing_column() {
+        // Given
+        let mut flat = Receipt::Return {
+            id: contract(1),
+            val: 2,
+            pc: 3,
+            is: 4,
+        }
+        .to_flat(&TxId::zeroed(), 0);
+        flat.val = None;
+
+        // When
+        let result = flat.try_into_receipt();
+
+        // Then
+        assert_eq!(
+            result,
+            Err(FlatReceiptError::MissingColumn {
+                kind: ReceiptRepr::Return,
+                column: "val",
+            })
+        );
+    }
+}
@@ -37,7 +37,7 @@ macro_rules! enum_from {
 }
 
 enum_from! {
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
     pub enum ReceiptRepr {
         Call = 0x00,
         Return = 0x01,
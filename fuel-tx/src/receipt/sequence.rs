@@ -0,0 +1,229 @@
+use super::Receipt;
+use alloc::vec::Vec;
+use fuel_types::ContractId;
+
+/// The violation of the legal ordering of the receipts of a script, see
+/// [`Receipt::validate_sequence`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[non_exhaustive]
+pub enum SequenceError {
+    /// The `Return` or `ReturnData` receipt of a call is not produced by the called
+    /// contract.
+    #[display("The return receipt {index} doesn't belong to the call receipt {call}")]
+    MismatchedReturn { index: usize, call: usize },
+    /// A receipt other than the `ScriptResult` follows the receipt terminating the
+    /// script.
+    #[display("The receipt {index} follows the terminating receipt {terminal}")]
+    AfterTermination { index: usize, terminal: usize },
+    /// The `ScriptResult` receipt follows an unfinished call.
+    #[display("The call receipt {call} has no terminating receipt")]
+    UnterminatedCall { call: usize },
+    /// The `ScriptResult` receipt follows the unfinished script.
+    #[display("The script result {index} follows no terminating receipt")]
+    MissingTermination { index: usize },
+    /// The script result is not the last receipt.
+    #[display("The receipt {index} follows the script result")]
+    AfterScriptResult { index: usize },
+    /// The receipts don't end with the script result.
+    #[display("The receipts don't end with the script result")]
+    MissingScriptResult,
+}
+
+impl Receipt {
+    /// Validates the ordering of the `receipts` of an executed script.
+    ///
+    /// Every `Call` receipt is followed by the `Return` or `ReturnData` of the
+    /// called contract, unless the execution is terminated by a `Revert` or a
+    /// `Panic` at any depth. A `Revert` or a `Panic` of the called contract followed
+    /// by other receipts than the `ScriptResult` only finishes the reverted call.
+    /// The script is terminated by the `Return` or
+    /// `ReturnData` outside of the calls, a `Revert` or a `Panic`, and only the
+    /// `ScriptResult` follows the termination, exactly once and as the last
+    /// receipt. The empty receipts of the transactions not running a script are
+    /// valid.
+    pub fn validate_sequence(receipts: &[Receipt]) -> Result<(), SequenceError> {
+        // The index and the called contract of every unfinished call.
+        let mut calls: Vec<(usize, ContractId)> = Vec::new();
+        let mut terminal = None;
+        // The revert of a call, terminating the script only if nothing but the
+        // `ScriptResult` follows.
+        let mut reverted = None;
+        let mut has_script_result = false;
+
+        for (index, receipt) in receipts.iter().enumerate() {
+            if has_script_result {
+                return Err(SequenceError::AfterScriptResult { index });
+            }
+            if let Receipt::ScriptResult { .. } = receipt {
+                terminal = terminal.or(reverted);
+                if terminal.is_none() {
+                    return Err(match calls.last() {
+                        Some((call, _)) => {
+                            SequenceError::UnterminatedCall { call: *call }
+                        }
+                        None => SequenceError::MissingTermination { index },
+                    });
+                }
+                has_script_result = true;
+                continue;
+            }
+            if let Some(terminal) = terminal {
+                return Err(SequenceError::AfterTermination { index, terminal });
+            }
+            reverted = None;
+
+            match receipt {
+                Receipt::Call { to, .. } => calls.push((index, *to)),
+                Receipt::Return { id, .. } | Receipt::ReturnData { id, .. } => {
+                    match calls.pop() {
+                        Some((call, to)) if to != *id => {
+                            return Err(SequenceError::MismatchedReturn { index, call });
+                        }
+                        Some(_) => {}
+                        None => terminal = Some(index),
+                    }
+                }
+                Receipt::Revert { id, .. } | Receipt::Panic { id, .. } => {
+                    match calls.last() {
+                        Some((_, to)) if to == id => {
+                            calls.pop();
+                            reverted = Some(index);
+                        }
+                        _ => terminal = Some(index),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !receipts.is_empty() && !has_script_result {
+            return Err(SequenceError::MissingScriptResult);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::ScriptExecutionResult;
+    use alloc::vec;
+    use fuel_asm::{
+        PanicInstruction,
+        PanicReason,
+    };
+
+    fn call(to: u8) -> Receipt {
+        Receipt::call(
+            Default::default(),
+            ContractId::from([to; 32]),
+            0,
+            Default::default(),
+            0,
+            0,
+            0,
+            0,
+            0,
+        )
+    }
+
+    fn ret(id: u8) -> Receipt {
+        Receipt::ret(ContractId::from([id; 32]), 1, 0, 0)
+    }
+
+    fn log() -> Receipt {
+        Receipt::log(Default::default(), 0, 0, 0, 0, 0, 0)
+    }
+
+    fn panic(id: u8) -> Receipt {
+        Receipt::panic(
+            ContractId::from([id; 32]),
+            PanicInstruction::error(PanicReason::MemoryOverflow, 0),
+            0,
+            0,
+        )
+    }
+
+    fn revert(id: u8) -> Receipt {
+        Receipt::revert(ContractId::from([id; 32]), 0, 0, 0)
+    }
+
+    fn script_result() -> Receipt {
+        Receipt::script_result(ScriptExecutionResult::Success, 0)
+    }
+
+    #[rstest::rstest]
+    #[case::no_receipts(vec![])]
+    #[case::script_return(vec![log(), ret(0), script_result()])]
+    #[case::nested_calls(vec![
+        call(1), log(), call(2), ret(2), ret(1), call(3), ret(3), ret(0), script_result(),
+    ])]
+    #[case::panic_in_call(vec![call(1), call(2), panic(2), script_result()])]
+    #[case::revert_in_call(vec![call(1), revert(1), script_result()])]
+    #[case::script_panic(vec![panic(0), script_result()])]
+    #[case::reverted_call_frame(vec![
+        call(1), log(), revert(1), log(), call(2), ret(2), ret(0), script_result(),
+    ])]
+    #[case::panicked_call_frame(vec![
+        call(1), call(2), panic(2), ret(1), panic(0), script_result(),
+    ])]
+    fn validate_sequence__valid_receipts(#[case] receipts: Vec<Receipt>) {
+        assert_eq!(Receipt::validate_sequence(&receipts), Ok(()));
+    }
+
+    #[rstest::rstest]
+    #[case::call_without_terminator(
+        vec![call(1), script_result()],
+        SequenceError::UnterminatedCall { call: 0 },
+    )]
+    #[case::inner_call_without_terminator(
+        vec![call(1), call(2), ret(2), script_result()],
+        SequenceError::UnterminatedCall { call: 0 },
+    )]
+    #[case::two_script_results(
+        vec![ret(0), script_result(), script_result()],
+        SequenceError::AfterScriptResult { index: 2 },
+    )]
+    #[case::receipt_after_script_result(
+        vec![ret(0), script_result(), log()],
+        SequenceError::AfterScriptResult { index: 2 },
+    )]
+    #[case::receipt_after_panic(
+        vec![panic(0), log(), script_result()],
+        SequenceError::AfterTermination { index: 1, terminal: 0 },
+    )]
+    #[case::call_without_terminator_after_reverted_call(
+        vec![call(1), call(2), revert(2), log(), script_result()],
+        SequenceError::UnterminatedCall { call: 0 },
+    )]
+    #[case::return_of_other_contract(
+        vec![call(1), ret(2), ret(0), script_result()],
+        SequenceError::MismatchedReturn { index: 1, call: 0 },
+    )]
+    #[case::script_result_without_termination(
+        vec![log(), script_result()],
+        SequenceError::MissingTermination { index: 1 },
+    )]
+    #[case::missing_script_result(
+        vec![log(), ret(0)],
+        SequenceError::MissingScriptResult,
+    )]
+    fn validate_sequence__invalid_receipts(
+        #[case] receipts: Vec<Receipt>,
+        #[case] expected: SequenceError,
+    ) {
+        assert_eq!(Receipt::validate_sequence(&receipts), Err(expected));
+    }
+}
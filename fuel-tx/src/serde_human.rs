@@ -0,0 +1,222 @@
+//! Opt-in serde helpers representing the identifiers as their hex strings in the
+//! human-readable formats, to be used with the `serde(with = ...)` attribute:
+//!
+//! ```rust
+//! use fuel_tx::TxPointer;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Coin {
+//!     #[serde(with = "fuel_tx::serde_human::tx_pointer")]
+//!     tx_pointer: TxPointer,
+//! }
+//! ```
+//!
+//! The other formats keep the structural representation of the derived impls, so
+//! the helpers only change the fields they are applied to.
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Display + Serialize,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(value)
+    } else {
+        value.serialize(serializer)
+    }
+}
+
+fn deserialize<'de, T, D>(deserializer: D, expecting: &'static str) -> Result<T, D::Error>
+where
+    T: FromStr + Deserialize<'de>,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(Visitor {
+            expecting,
+            _value: PhantomData,
+        })
+    } else {
+        T::deserialize(deserializer)
+    }
+}
+
+struct Visitor<T> {
+    expecting: &'static str,
+    _value: PhantomData<T>,
+}
+
+impl<T> serde::de::Visitor<'_> for Visitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+}
+
+/// Represents the [`TxPointer`](crate::TxPointer) as its hex string in the
+/// human-readable formats.
+pub mod tx_pointer {
+    use crate::TxPointer;
+    use serde::{
+        Deserializer,
+        Serializer,
+    };
+
+    /// Serializes the `tx_pointer`.
+    pub fn serialize<S>(tx_pointer: &TxPointer, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize(tx_pointer, serializer)
+    }
+
+    /// Deserializes the [`TxPointer`].
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TxPointer, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(deserializer, "a hex encoded TxPointer")
+    }
+}
+
+/// Represents the [`UtxoId`](crate::UtxoId) as its hex string in the
+/// human-readable formats.
+#[cfg(feature = "alloc")]
+pub mod utxo_id {
+    use crate::UtxoId;
+    use serde::{
+        Deserializer,
+        Serializer,
+    };
+
+    /// Serializes the `utxo_id`.
+    pub fn serialize<S>(utxo_id: &UtxoId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize(utxo_id, serializer)
+    }
+
+    /// Deserializes the [`UtxoId`].
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<UtxoId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(deserializer, "a hex encoded UtxoId")
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{
+        TxPointer,
+        UtxoId,
+    };
+    use core::str::FromStr;
+    use fuel_types::Bytes32;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Human {
+        #[serde(with = "super::tx_pointer")]
+        tx_pointer: TxPointer,
+        #[serde(with = "super::utxo_id")]
+        utxo_id: UtxoId,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Structural {
+        tx_pointer: TxPointer,
+        utxo_id: UtxoId,
+    }
+
+    const TX_ID: &str =
+        "0c0000000000000000000000000000000000000000000000000000000000000b";
+
+    fn human() -> Human {
+        Human {
+            tx_pointer: TxPointer::new(83473.into(), 3829),
+            utxo_id: UtxoId::new(Bytes32::from_str(TX_ID).unwrap(), 0xabcd),
+        }
+    }
+
+    #[test]
+    fn human_readable_formats_use_hex_strings() {
+        let human = human();
+
+        let json = serde_json::to_string(&human).expect("failed to serialize");
+        assert_eq!(
+            json,
+            format!(
+                "{{\"tx_pointer\":\"{}\",\"utxo_id\":\"{TX_ID}abcd\"}}",
+                human.tx_pointer
+            )
+        );
+        let decoded: Human = serde_json::from_str(&json).expect("failed to deserialize");
+        assert_eq!(decoded, human);
+    }
+
+    #[test]
+    fn other_formats_keep_the_structural_representation() {
+        let human = human();
+        let structural = Structural {
+            tx_pointer: human.tx_pointer,
+            utxo_id: human.utxo_id,
+        };
+
+        let bytes = postcard::to_allocvec(&human).expect("failed to serialize");
+        assert_eq!(
+            bytes,
+            postcard::to_allocvec(&structural).expect("failed to serialize")
+        );
+        let decoded: Human = postcard::from_bytes(&bytes).expect("failed to deserialize");
+        assert_eq!(decoded, human);
+    }
+
+    #[test]
+    fn derived_impls_keep_the_structural_representation() {
+        let human = human();
+        let structural = Structural {
+            tx_pointer: human.tx_pointer,
+            utxo_id: human.utxo_id,
+        };
+
+        let json = serde_json::to_value(&structural).expect("failed to serialize");
+        assert!(json["tx_pointer"].is_object());
+        assert!(json["utxo_id"].is_object());
+    }
+
+    #[test]
+    fn invalid_strings_are_rejected() {
+        let json = format!("{{\"tx_pointer\":\"zz\",\"utxo_id\":\"{TX_ID}abcd\"}}");
+
+        let result = serde_json::from_str::<Human>(&json);
+
+        assert!(result.is_err());
+    }
+}
@@ -79,6 +79,7 @@ mod use_std {
         distributions::{
             Distribution,
             Uniform,
+            WeightedIndex,
         },
         rngs::StdRng,
         CryptoRng,
@@ -87,6 +88,10 @@ mod use_std {
     };
     use strum::EnumCount;
 
+    /// The relative frequencies of the `Script`, `Create`, `Mint`, `Upgrade`, `Upload`
+    /// and `Blob` transactions produced by the `TransactionFactory<_, Transaction>`.
+    const TRANSACTION_WEIGHTS: [u32; Transaction::COUNT] = [8, 4, 2, 2, 2, 2];
+
     pub struct TransactionFactory<R, Tx>
     where
         R: Rng + CryptoRng,
@@ -94,6 +99,7 @@ mod use_std {
         rng: R,
         input_sampler: Uniform<usize>,
         output_sampler: Uniform<usize>,
+        transaction_sampler: WeightedIndex<u32>,
         marker: PhantomData<Tx>,
     }
 
@@ -104,6 +110,8 @@ mod use_std {
         fn from(rng: R) -> Self {
             let input_sampler = Uniform::from(0..Input::COUNT);
             let output_sampler = Uniform::from(0..Output::COUNT);
+            let transaction_sampler = WeightedIndex::new(TRANSACTION_WEIGHTS)
+                .expect("The weights are positive");
 
             // Trick to enforce coverage of all variants in compile-time
             //
@@ -144,6 +152,13 @@ mod use_std {
                     })
                     .unwrap_or(());
 
+                UpgradePurpose::decode(&mut &empty[..])
+                    .map(|p| match p {
+                        UpgradePurpose::ConsensusParameters { .. } => (),
+                        UpgradePurpose::StateTransition { .. } => (),
+                    })
+                    .unwrap_or(());
+
                 true
             });
 
@@ -151,6 +166,7 @@ mod use_std {
                 rng,
                 input_sampler,
                 output_sampler,
+                transaction_sampler,
                 marker: Default::default(),
             }
         }
@@ -162,6 +178,16 @@ mod use_std {
         }
     }
 
+    impl<R, Tx> TransactionFactory<R, Tx>
+    where
+        R: Rng + CryptoRng,
+    {
+        /// The factory of the `T` transactions drawing from the same generator.
+        fn factory<T>(&mut self) -> TransactionFactory<&mut R, T> {
+            (&mut self.rng).into()
+        }
+    }
+
     impl<R, Tx> TransactionFactory<R, Tx>
     where
         R: Rng + CryptoRng,
@@ -404,9 +430,7 @@ mod use_std {
                     witness_index: 0,
                     checksum,
                 },
-                _ => {
-                    panic!("Not supported")
-                }
+                _ => unreachable!(),
             };
 
             let mut builder = TransactionBuilder::<Upgrade>::upgrade(purpose);
@@ -495,6 +519,47 @@ mod use_std {
         }
     }
 
+    impl<R> TransactionFactory<R, Transaction>
+    where
+        R: Rng + CryptoRng,
+    {
+        pub fn transaction(&mut self) -> Transaction {
+            self.transaction_with_keys().0
+        }
+
+        /// Samples the type of the transaction according to the
+        /// [`TRANSACTION_WEIGHTS`], so all the types are covered.
+        pub fn transaction_with_keys(&mut self) -> (Transaction, Vec<SecretKey>) {
+            let variant = self.transaction_sampler.sample(&mut self.rng);
+
+            match variant {
+                0 => {
+                    let (tx, keys) = self.factory::<Script>().transaction_with_keys();
+                    (tx.into(), keys)
+                }
+                1 => {
+                    let (tx, keys) = self.factory::<Create>().transaction_with_keys();
+                    (tx.into(), keys)
+                }
+                2 => (self.factory::<Mint>().transaction().into(), vec![]),
+                3 => {
+                    let (tx, keys) = self.factory::<Upgrade>().transaction_with_keys();
+                    (tx.into(), keys)
+                }
+                4 => {
+                    let (tx, keys) = self.factory::<Upload>().transaction_with_keys();
+                    (tx.into(), keys)
+                }
+                5 => {
+                    let (tx, keys) = self.factory::<Blob>().transaction_with_keys();
+                    (tx.into(), keys)
+                }
+
+                _ => unreachable!(),
+            }
+        }
+    }
+
     impl<R> Iterator for TransactionFactory<R, Create>
     where
         R: Rng + CryptoRng,
@@ -560,4 +625,14 @@ mod use_std {
             Some(self.transaction())
         }
     }
+    impl<R> Iterator for TransactionFactory<R, Transaction>
+    where
+        R: Rng + CryptoRng,
+    {
+        type Item = (Transaction, Vec<SecretKey>);
+
+        fn next(&mut self) -> Option<(Transaction, Vec<SecretKey>)> {
+            Some(self.transaction_with_keys())
+        }
+    }
 }
@@ -0,0 +1,365 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    block_compression::{
+        ChainState,
+        CoinInfo,
+        MessageInfo,
+    },
+    field::{
+        self,
+        Outputs,
+    },
+    test_helper::TransactionFactory,
+    Blob,
+    CompressedTransaction,
+    CompressedUtxoId,
+    Create,
+    Mint,
+    Output,
+    PrepareSign,
+    Script,
+    Transaction,
+    TxPointer,
+    Upgrade,
+    Upload,
+    UtxoId,
+};
+use bimap::BiMap;
+use fuel_compression::{
+    block::{
+        compress_transactions,
+        decompress_transactions,
+        BlockCompressionError,
+        CompressedBlockPayload,
+        InMemoryRegistry,
+        RegistryKeyspace,
+        RegistryStore,
+    },
+    RegistryKey,
+};
+use fuel_types::{
+    Address,
+    AssetId,
+    BlockHeight,
+    Nonce,
+};
+use rand::{
+    rngs::StdRng,
+    seq::SliceRandom,
+    Rng,
+    SeedableRng,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+const BLOCK_SIZE: usize = 50;
+
+/// The chain data known by both the compressor and the decompressor.
+#[derive(Debug, Default, Clone)]
+struct TestChain {
+    block_height: BlockHeight,
+    utxos: BiMap<CompressedUtxoId, UtxoId>,
+    coins: HashMap<UtxoId, CoinInfo>,
+    messages: HashMap<Nonce, MessageInfo>,
+}
+
+impl TestChain {
+    fn store_tx_info<Tx>(&mut self, tx: &Tx)
+    where
+        Tx: field::Inputs,
+    {
+        for input in tx.inputs() {
+            if input.is_coin() {
+                let utxo_id = *input.utxo_id().unwrap();
+                let pointer = CompressedUtxoId {
+                    tx_pointer: TxPointer::new((self.utxos.len() as u32).into(), 0),
+                    output_index: utxo_id.output_index(),
+                };
+                self.utxos.insert(pointer, utxo_id);
+                self.coins.insert(
+                    utxo_id,
+                    CoinInfo {
+                        owner: *input.input_owner().unwrap(),
+                        amount: input.amount().unwrap(),
+                        asset_id: *input.asset_id(&AssetId::default()).unwrap(),
+                        tx_pointer: *input.tx_pointer().unwrap(),
+                    },
+                );
+            }
+
+            if input.is_message() {
+                self.messages.insert(
+                    *input.nonce().unwrap(),
+                    MessageInfo {
+                        sender: *input.sender().unwrap(),
+                        recipient: *input.recipient().unwrap(),
+                        amount: input.amount().unwrap(),
+                        data: input.input_data().unwrap_or_default().to_vec(),
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Registry of one side of the DA layer on top of the shared chain data.
+#[derive(Debug, Default, Clone)]
+struct TestStore {
+    registry: InMemoryRegistry,
+    chain: TestChain,
+}
+
+impl TestStore {
+    fn new(chain: TestChain) -> Self {
+        Self {
+            registry: InMemoryRegistry::default(),
+            chain,
+        }
+    }
+}
+
+impl RegistryStore for TestStore {
+    fn get_key(&self, keyspace: RegistryKeyspace, value: &[u8]) -> Option<RegistryKey> {
+        self.registry.get_key(keyspace, value)
+    }
+
+    fn get_value(&self, keyspace: RegistryKeyspace, key: RegistryKey) -> Option<Vec<u8>> {
+        self.registry.get_value(keyspace, key)
+    }
+
+    fn allocate_key(&mut self, keyspace: RegistryKeyspace) -> RegistryKey {
+        self.registry.allocate_key(keyspace)
+    }
+
+    fn insert(&mut self, keyspace: RegistryKeyspace, key: RegistryKey, value: Vec<u8>) {
+        self.registry.insert(keyspace, key, value)
+    }
+}
+
+impl ChainState for TestStore {
+    fn utxo_pointer(&self, utxo_id: &UtxoId) -> Option<CompressedUtxoId> {
+        self.chain.utxos.get_by_right(utxo_id).copied()
+    }
+
+    fn utxo_id(&self, pointer: &CompressedUtxoId) -> Option<UtxoId> {
+        self.chain.utxos.get_by_left(pointer).copied()
+    }
+
+    fn coin(&self, utxo_id: &UtxoId) -> Option<CoinInfo> {
+        self.chain.coins.get(utxo_id).cloned()
+    }
+
+    fn message(&self, nonce: &Nonce) -> Option<MessageInfo> {
+        self.chain.messages.get(nonce).cloned()
+    }
+
+    fn block_height(&self) -> BlockHeight {
+        self.chain.block_height
+    }
+}
+
+/// Makes the coin and change outputs of the transaction use the shared recipients and
+/// assets.
+fn share_addresses<Tx>(
+    mut tx: Tx,
+    rng: &mut StdRng,
+    addresses: &[Address],
+    assets: &[AssetId],
+) -> Tx
+where
+    Tx: Outputs + PrepareSign,
+{
+    for output in tx.outputs_mut() {
+        match output {
+            Output::Coin { to, asset_id, .. } | Output::Change { to, asset_id, .. } => {
+                *to = *addresses.choose(rng).unwrap();
+                *asset_id = *assets.choose(rng).unwrap();
+            }
+            _ => {}
+        }
+    }
+    tx.prepare_sign();
+    tx
+}
+
+/// Generates a block of random transactions that send to a few shared addresses,
+/// together with the chain data required to decompress it.
+fn block(seed: u64) -> (Vec<Transaction>, TestChain) {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let addresses: Vec<Address> = (0..3).map(|_| rng.gen()).collect();
+    let assets: Vec<AssetId> = (0..2).map(|_| rng.gen()).collect();
+
+    let mut chain = TestChain {
+        block_height: rng.gen(),
+        ..Default::default()
+    };
+    let mut txs = Vec::with_capacity(BLOCK_SIZE);
+    for i in 0..BLOCK_SIZE - 1 {
+        let seed = rng.gen();
+        let tx: Transaction = match i % 5 {
+            0 => {
+                let tx = TransactionFactory::<_, Script>::from_seed(seed).transaction();
+                let tx = share_addresses(tx, rng, &addresses, &assets);
+                chain.store_tx_info(&tx);
+                tx.into()
+            }
+            1 => {
+                let tx = TransactionFactory::<_, Create>::from_seed(seed).transaction();
+                let tx = share_addresses(tx, rng, &addresses, &assets);
+                chain.store_tx_info(&tx);
+                tx.into()
+            }
+            2 => {
+                let tx = TransactionFactory::<_, Upgrade>::from_seed(seed).transaction();
+                let tx = share_addresses(tx, rng, &addresses, &assets);
+                chain.store_tx_info(&tx);
+                tx.into()
+            }
+            3 => {
+                let tx = TransactionFactory::<_, Upload>::from_seed(seed).transaction();
+                let tx = share_addresses(tx, rng, &addresses, &assets);
+                chain.store_tx_info(&tx);
+                tx.into()
+            }
+            _ => {
+                let tx = TransactionFactory::<_, Blob>::from_seed(seed).transaction();
+                let tx = share_addresses(tx, rng, &addresses, &assets);
+                chain.store_tx_info(&tx);
+                tx.into()
+            }
+        };
+        txs.push(tx);
+    }
+
+    let mut mint = TransactionFactory::<_, Mint>::from_seed(rng.gen()).transaction();
+    mint.prepare_sign();
+    *field::TxPointer::tx_pointer_mut(&mut mint) =
+        TxPointer::new(chain.block_height, (BLOCK_SIZE - 1) as u16);
+    txs.push(mint.into());
+
+    (txs, chain)
+}
+
+fn payload_size(payload: &CompressedBlockPayload<CompressedTransaction>) -> usize {
+    postcard::to_stdvec(payload)
+        .expect("failed to serialize")
+        .len()
+}
+
+#[test]
+fn compress_transactions__block_of_transactions__round_trip() {
+    for seed in [1234, 5678] {
+        // Given
+        let (txs, chain) = block(seed);
+        let mut compressor = TestStore::new(chain.clone());
+        let mut decompressor = TestStore::new(chain);
+
+        // When
+        let payload =
+            compress_transactions(&txs, &mut compressor).expect("compression failed");
+        let bytes = postcard::to_stdvec(&payload).expect("failed to serialize");
+        let payload = postcard::from_bytes(&bytes).expect("failed to deserialize");
+        let decompressed: Vec<Transaction> =
+            decompress_transactions(payload, &mut decompressor)
+                .expect("decompression failed");
+
+        // Then
+        pretty_assertions::assert_eq!(txs, decompressed);
+    }
+}
+
+#[test]
+fn compress_transactions__next_block__reuses_registrations_of_previous_block() {
+    // Given
+    let (txs, chain) = block(1234);
+    let mut compressor = TestStore::new(chain.clone());
+    let mut decompressor = TestStore::new(chain);
+    let payload = compress_transactions(&txs, &mut compressor).unwrap();
+    decompress_transactions::<Transaction, _>(payload, &mut decompressor).unwrap();
+
+    // When
+    let payload = compress_transactions(&txs, &mut compressor).unwrap();
+    assert!(payload.registrations.is_empty());
+    let decompressed: Vec<Transaction> =
+        decompress_transactions(payload, &mut decompressor).unwrap();
+
+    // Then
+    pretty_assertions::assert_eq!(txs, decompressed);
+}
+
+#[test]
+fn compress_transactions__shared_registry__deduplicates_across_transactions() {
+    // Given
+    let (txs, chain) = block(1234);
+    let recipients = txs
+        .iter()
+        .filter_map(|tx| match tx {
+            Transaction::Script(tx) => Some(tx.outputs()),
+            Transaction::Create(tx) => Some(tx.outputs()),
+            Transaction::Upgrade(tx) => Some(tx.outputs()),
+            Transaction::Upload(tx) => Some(tx.outputs()),
+            Transaction::Blob(tx) => Some(tx.outputs()),
+            Transaction::Mint(_) => None,
+        })
+        .flatten()
+        .filter_map(|output| match output {
+            Output::Coin { to, .. } | Output::Change { to, .. } => Some(*to),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    // When
+    let block_payload =
+        compress_transactions(&txs, &mut TestStore::new(chain.clone())).unwrap();
+    let separate_payloads = txs
+        .iter()
+        .map(|tx| {
+            compress_transactions(
+                core::slice::from_ref(tx),
+                &mut TestStore::new(chain.clone()),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Then
+    let registered_addresses = block_payload
+        .registrations
+        .iter()
+        .filter(|registration| registration.keyspace == RegistryKeyspace::Address)
+        .count();
+    assert_eq!(registered_addresses, recipients.len());
+
+    let block_size = payload_size(&block_payload);
+    let separate_size: usize = separate_payloads.iter().map(payload_size).sum();
+    assert!(
+        block_size < separate_size,
+        "block payload of {block_size} bytes is not smaller than {separate_size} bytes \
+         of separately compressed transactions"
+    );
+}
+
+#[test]
+fn decompress_transactions__missing_registrations__fails() {
+    // Given
+    let (txs, chain) = block(1234);
+    let mut payload =
+        compress_transactions(&txs, &mut TestStore::new(chain.clone())).unwrap();
+    let registration = payload.registrations.remove(0);
+
+    // When
+    let result =
+        decompress_transactions::<Transaction, _>(payload, &mut TestStore::new(chain));
+
+    // Then
+    assert_eq!(
+        result,
+        Err(BlockCompressionError::UnknownKey {
+            keyspace: registration.keyspace,
+            key: registration.key,
+        })
+    );
+}
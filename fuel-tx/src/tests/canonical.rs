@@ -0,0 +1,234 @@
+#![allow(non_snake_case)]
+
+use crate::{
+    field::{
+        Inputs,
+        Outputs,
+    },
+    policies::Policies,
+    test_helper::TransactionFactory,
+    *,
+};
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Error,
+        Serialize,
+    },
+    Bytes32,
+    Bytes64,
+};
+use proptest::prelude::*;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::fmt;
+
+/// Checks that `value -> bytes -> value` is the identity and that
+/// `bytes -> value -> bytes` is a fixed point.
+fn assert_round_trip<T>(value: &T)
+where
+    T: Serialize + Deserialize + fmt::Debug + PartialEq,
+{
+    let bytes = value.to_bytes();
+    assert_eq!(value.size(), bytes.len());
+
+    let decoded = T::from_bytes(&bytes).expect("Failed to decode the encoded value");
+    assert_eq!(&decoded, value);
+    assert_eq!(decoded.to_bytes(), bytes);
+}
+
+/// Checks that a value decoded from arbitrary bytes encodes back to the same bytes.
+fn assert_bytes_fixed_point<T>(bytes: &[u8])
+where
+    T: Serialize + Deserialize + fmt::Debug + PartialEq,
+{
+    if let Ok(value) = T::from_bytes(bytes) {
+        assert_eq!(value.to_bytes(), bytes);
+        assert_round_trip(&value);
+    }
+}
+
+fn transactions(seed: u64) -> Vec<Transaction> {
+    vec![
+        TransactionFactory::<_, Script>::from_seed(seed)
+            .transaction()
+            .into(),
+        TransactionFactory::<_, Create>::from_seed(seed)
+            .transaction()
+            .into(),
+        TransactionFactory::<_, Mint>::from_seed(seed)
+            .transaction()
+            .into(),
+        TransactionFactory::<_, Upgrade>::from_seed(seed)
+            .transaction()
+            .into(),
+        TransactionFactory::<_, Upload>::from_seed(seed)
+            .transaction()
+            .into(),
+        TransactionFactory::<_, Blob>::from_seed(seed)
+            .transaction()
+            .into(),
+    ]
+}
+
+fn inputs_and_outputs(tx: &Transaction) -> (Vec<Input>, Vec<Output>) {
+    match tx {
+        Transaction::Script(tx) => (tx.inputs().clone(), tx.outputs().clone()),
+        Transaction::Create(tx) => (tx.inputs().clone(), tx.outputs().clone()),
+        Transaction::Upgrade(tx) => (tx.inputs().clone(), tx.outputs().clone()),
+        Transaction::Upload(tx) => (tx.inputs().clone(), tx.outputs().clone()),
+        Transaction::Blob(tx) => (tx.inputs().clone(), tx.outputs().clone()),
+        Transaction::Mint(_) => (vec![], vec![]),
+    }
+}
+
+proptest! {
+    #[test]
+    fn transaction__round_trip(seed: u64) {
+        for tx in transactions(seed) {
+            assert_round_trip(&tx);
+            assert_bytes_fixed_point::<Transaction>(&tx.to_bytes());
+        }
+    }
+
+    #[test]
+    fn input_and_output__round_trip(seed: u64) {
+        for tx in transactions(seed) {
+            let (inputs, outputs) = inputs_and_outputs(&tx);
+            for input in inputs.iter() {
+                assert_round_trip(input);
+            }
+            for output in outputs.iter() {
+                assert_round_trip(output);
+            }
+        }
+    }
+
+    #[test]
+    fn policies__round_trip(seed: u64) {
+        let policies: Policies = StdRng::seed_from_u64(seed).gen();
+        assert_round_trip(&policies);
+    }
+
+    #[test]
+    fn policies__bytes_fixed_point(bits in 0u32..32, values: [u64; 5]) {
+        let mut bytes = (bits as u64).to_be_bytes().to_vec();
+        for (i, value) in values.iter().enumerate() {
+            if bits & (1 << i) != 0 {
+                bytes.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        assert_bytes_fixed_point::<Policies>(&bytes);
+    }
+
+    #[test]
+    fn storage_slot__round_trip(bytes in proptest::collection::vec(any::<u8>(), 64)) {
+        let slot = StorageSlot::from(&Bytes64::try_from(bytes.as_slice()).unwrap());
+        assert_round_trip(&slot);
+        assert_bytes_fixed_point::<StorageSlot>(&bytes);
+    }
+
+    #[test]
+    fn upgrade_purpose__round_trip(
+        witness_index: u16,
+        checksum: [u8; 32],
+        root: [u8; 32],
+    ) {
+        assert_round_trip(&UpgradePurpose::ConsensusParameters {
+            witness_index,
+            checksum: Bytes32::new(checksum),
+        });
+        assert_round_trip(&UpgradePurpose::StateTransition {
+            root: Bytes32::new(root),
+        });
+    }
+
+    #[test]
+    fn witness__round_trip(data in proptest::collection::vec(any::<u8>(), 0..1024)) {
+        let witness = Witness::from(data);
+        assert_round_trip(&witness);
+        assert_bytes_fixed_point::<Witness>(&witness.to_bytes());
+    }
+}
+
+#[test]
+fn script__max_sizes__round_trip() {
+    let params = ScriptParameters::DEFAULT;
+    let script = vec![0xAB; params.max_script_length() as usize];
+    let script_data = vec![0xCD; params.max_script_data_length() as usize];
+
+    let tx: Transaction = TransactionBuilder::script(script, script_data)
+        .add_witness(Witness::from(vec![
+            0xEF;
+            TxParameters::DEFAULT.max_size() as usize
+        ]))
+        .finalize()
+        .into();
+
+    assert_round_trip(&tx);
+}
+
+#[test]
+fn create__max_storage_slots__round_trip() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let slots = (0..ContractParameters::DEFAULT.max_storage_slots())
+        .map(|_| rng.gen())
+        .collect::<Vec<StorageSlot>>();
+
+    let tx: Transaction =
+        TransactionBuilder::create(vec![0; 64].into(), rng.gen(), slots)
+            .finalize()
+            .into();
+
+    assert_round_trip(&tx);
+}
+
+#[test]
+fn transaction__trailing_bytes__rejected() {
+    for tx in transactions(1234) {
+        // Given
+        let mut bytes = tx.to_bytes();
+        bytes.extend_from_slice(&[0; 8]);
+
+        // When
+        let result = Transaction::from_bytes(&bytes);
+
+        // Then
+        assert_eq!(result, Err(Error::TrailingBytes));
+    }
+}
+
+#[test]
+fn field_types__trailing_bytes__rejected() {
+    let rng = &mut StdRng::seed_from_u64(1234);
+
+    let mut bytes = rng.gen::<Policies>().to_bytes();
+    bytes.push(0);
+    assert_eq!(Policies::from_bytes(&bytes), Err(Error::TrailingBytes));
+
+    let mut bytes = rng.gen::<StorageSlot>().to_bytes();
+    bytes.push(0);
+    assert_eq!(StorageSlot::from_bytes(&bytes), Err(Error::TrailingBytes));
+
+    let mut bytes = rng.gen::<Witness>().to_bytes();
+    bytes.extend_from_slice(&[0; 8]);
+    assert_eq!(Witness::from_bytes(&bytes), Err(Error::TrailingBytes));
+}
+
+#[test]
+fn policies__non_zero_padding__rejected() {
+    // Given
+    let policies = Policies::new().with_tip(1);
+    let mut bytes = policies.to_bytes();
+    // The bits are a `u32` encoded into a word, so the first 4 bytes are padding.
+    bytes[0] = 1;
+
+    // When
+    let result = Policies::from_bytes(&bytes);
+
+    // Then
+    assert_eq!(result, Err(Error::NonZeroPadding));
+}
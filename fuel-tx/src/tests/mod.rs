@@ -3,7 +3,11 @@
 mod offset;
 mod valid_cases;
 
+#[cfg(feature = "da-compression")]
+mod block_compression;
 mod bytes;
+mod canonical;
 #[cfg(feature = "da-compression")]
 mod da_compression;
 mod display;
+mod transaction_factory;
@@ -85,7 +85,7 @@ where
             .inputs_offset_at(idx)
             .expect("failed to fetch input offset");
         let i_p =
-            Input::from_bytes(&bytes[input_ofs..]).expect("failed to deserialize input");
+            Input::decode(&mut &bytes[input_ofs..]).expect("failed to deserialize input");
 
         assert_eq!(i, &i_p);
 
@@ -93,8 +93,8 @@ where
             cases.utxo_id = true;
 
             let ofs = input_ofs + i.repr().utxo_id_offset().expect("input have utxo_id");
-            let utxo_id_p =
-                UtxoId::from_bytes(&bytes[ofs..]).expect("failed to deserialize utxo id");
+            let utxo_id_p = UtxoId::decode(&mut &bytes[ofs..])
+                .expect("failed to deserialize utxo id");
 
             assert_eq!(utxo_id, &utxo_id_p);
         }
@@ -263,7 +263,7 @@ fn outputs_assert<Tx: Outputs>(tx: &Tx, bytes: &[u8], cases: &mut TestedFields)
         let output_ofs = tx
             .outputs_offset_at(idx)
             .expect("failed to fetch output offset");
-        let o_p = Output::from_bytes(&bytes[output_ofs..])
+        let o_p = Output::decode(&mut &bytes[output_ofs..])
             .expect("failed to deserialize output");
 
         assert_eq!(o, &o_p);
@@ -651,7 +651,7 @@ fn iow_offset() {
                 let offset_p = tx_p.inputs_offset_at(x).unwrap();
                 assert_eq!(offset, offset_p);
 
-                let input = Input::from_bytes(&bytes[offset..])
+                let input = Input::decode(&mut &bytes[offset..])
                     .expect("Failed to deserialize input!");
 
                 assert_eq!(i, &input);
@@ -662,7 +662,7 @@ fn iow_offset() {
                 let offset_p = tx_p.outputs_offset_at(x).unwrap();
                 assert_eq!(offset, offset_p);
 
-                let output = Output::from_bytes(&bytes[offset..])
+                let output = Output::decode(&mut &bytes[offset..])
                     .expect("Failed to deserialize output!");
 
                 assert_eq!(o, &output);
@@ -673,7 +673,7 @@ fn iow_offset() {
                 let offset_p = tx_p.witnesses_offset_at(x).unwrap();
                 assert_eq!(offset, offset_p);
 
-                let witness = Witness::from_bytes(&bytes[offset..])
+                let witness = Witness::decode(&mut &bytes[offset..])
                     .expect("Failed to deserialize witness!");
 
                 assert_eq!(w, &witness);
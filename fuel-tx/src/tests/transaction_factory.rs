@@ -0,0 +1,52 @@
+use crate::{
+    test_helper::TransactionFactory,
+    Transaction,
+};
+use fuel_types::canonical::{
+    Deserialize,
+    Serialize,
+};
+use strum::EnumCount;
+
+const NUMBER_CASES: usize = 1000;
+
+fn variant_index(tx: &Transaction) -> usize {
+    match tx {
+        Transaction::Script(_) => 0,
+        Transaction::Create(_) => 1,
+        Transaction::Mint(_) => 2,
+        Transaction::Upgrade(_) => 3,
+        Transaction::Upload(_) => 4,
+        Transaction::Blob(_) => 5,
+    }
+}
+
+#[test]
+fn transaction_factory_is_deterministic() {
+    let a = TransactionFactory::<_, Transaction>::from_seed(1234).take(10);
+    let b = TransactionFactory::<_, Transaction>::from_seed(1234).take(10);
+
+    assert!(a.eq(b));
+}
+
+#[test]
+fn transaction_factory_covers_every_variant_and_serializes_canonically() {
+    let mut counts = [0usize; Transaction::COUNT];
+
+    for (tx, _) in
+        TransactionFactory::<_, Transaction>::from_seed(1234).take(NUMBER_CASES)
+    {
+        counts[variant_index(&tx)] += 1;
+
+        let bytes = tx.to_bytes();
+        assert_eq!(bytes.len(), tx.size());
+
+        let decoded = Transaction::from_bytes(&bytes).expect("Failed to decode");
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded.to_bytes(), bytes);
+    }
+
+    assert!(counts.iter().all(|count| *count > 0), "{counts:?}");
+    // Scripts are the most frequent
+    assert!(counts.iter().all(|count| *count <= counts[0]), "{counts:?}");
+}
@@ -1,10 +1,14 @@
-#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_possible_truncation, non_snake_case)]
 
 use super::PREDICATE_PARAMS;
 use crate::{
     builder::TransactionBuilder,
     field,
-    field::Witnesses,
+    field::{
+        BytecodeWitnessIndex,
+        Inputs,
+        Witnesses,
+    },
     test_helper::{
         generate_bytes,
         generate_nonempty_padded_bytes,
@@ -17,7 +21,10 @@ use fuel_crypto::{
     PublicKey,
     SecretKey,
 };
-use fuel_types::ChainId;
+use fuel_types::{
+    canonical::Serialize,
+    ChainId,
+};
 use rand::{
     rngs::StdRng,
     CryptoRng,
@@ -190,6 +197,238 @@ fn duplicate_secrets_reuse_witness() {
     )
 }
 
+#[test]
+fn sign_all_inputs_dedups_witnesses_of_the_same_key() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let first = SecretKey::random(rng);
+    let second = SecretKey::random(rng);
+
+    let mut script = TransactionBuilder::script(vec![], vec![]).finalize();
+    for (witness_index, key) in [first, second, first].iter().enumerate() {
+        script.add_unsigned_coin_input(
+            rng.gen(),
+            &key.public_key(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            witness_index as u16,
+        );
+        script.witnesses_mut().push(Witness::default());
+    }
+    let mut tx: Transaction = script.into();
+
+    tx.sign_all_inputs(&[first, second], &chain_id);
+
+    let Transaction::Script(script) = &tx else {
+        unreachable!("The transaction is a script")
+    };
+    assert_eq!(script.witnesses().len(), 2);
+    let witness_indexes: Vec<_> = script
+        .inputs()
+        .iter()
+        .map(|input| input.witness_index().expect("Signed input"))
+        .collect();
+    assert_eq!(witness_indexes, vec![0, 1, 0]);
+    tx.check_signatures(&chain_id)
+        .expect("All inputs should be signed");
+    tx.verify_input_signatures(&chain_id)
+        .expect("All inputs should be signed");
+}
+
+#[test]
+fn sign_all_inputs_keeps_the_bytecode_witness() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let key = SecretKey::random(rng);
+
+    let mut create =
+        TransactionBuilder::create(generate_bytes(rng).into(), rng.gen(), vec![])
+            .finalize();
+    let bytecode = create.witnesses()[0].clone();
+    for _ in 0..2 {
+        let witness_index = create.witnesses().len() as u16;
+        create.add_unsigned_coin_input(
+            rng.gen(),
+            &key.public_key(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            witness_index,
+        );
+        create.witnesses_mut().push(Witness::default());
+    }
+    // The bytecode is moved after the signatures, and its index has to be updated
+    create.witnesses_mut().rotate_left(1);
+    for input in create.inputs_mut() {
+        if let Input::CoinSigned(coin) = input {
+            coin.witness_index -= 1;
+        }
+    }
+    *create.bytecode_witness_index_mut() = 2;
+    let mut tx: Transaction = create.into();
+
+    tx.sign_all_inputs(&[key], &chain_id);
+
+    let Transaction::Create(create) = &tx else {
+        unreachable!("The transaction is a create")
+    };
+    assert_eq!(create.witnesses().len(), 2);
+    assert_eq!(*create.bytecode_witness_index(), 1);
+    assert_eq!(create.witnesses()[1], bytecode);
+    tx.verify_input_signatures(&chain_id)
+        .expect("All inputs should be signed");
+}
+
+#[test]
+fn verify_input_signatures_reports_index_of_the_input() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let signed = SecretKey::random(rng);
+    let unsigned = SecretKey::random(rng);
+
+    let mut script = TransactionBuilder::script(vec![], vec![]).finalize();
+    for (witness_index, key) in [signed, unsigned].iter().enumerate() {
+        script.add_unsigned_coin_input(
+            rng.gen(),
+            &key.public_key(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            witness_index as u16,
+        );
+        script.witnesses_mut().push(Witness::default());
+    }
+    let mut tx: Transaction = script.into();
+
+    tx.sign_all_inputs(&[signed], &chain_id);
+
+    let (index, _) = tx
+        .verify_input_signatures(&chain_id)
+        .expect_err("The second input isn't signed");
+    assert_eq!(index, 1);
+}
+
+#[test]
+fn dedup_witnesses_merges_only_identical_signatures() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let first = SecretKey::random(rng);
+    let second = SecretKey::random(rng);
+
+    let mut script = TransactionBuilder::script(vec![], vec![]).finalize();
+    for (witness_index, key) in [first, second, first].iter().enumerate() {
+        script.add_unsigned_coin_input(
+            rng.gen(),
+            &key.public_key(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            witness_index as u16,
+        );
+        script.witnesses_mut().push(Witness::default());
+    }
+    script.sign_inputs(&first, &chain_id);
+    script.sign_inputs(&second, &chain_id);
+    let mut tx: Transaction = script.into();
+    let size = tx.size();
+
+    let removed = tx.dedup_witnesses();
+
+    assert_eq!(removed, 1);
+    assert_eq!(size - tx.size(), Witness::from(vec![0; 64]).size());
+    let Transaction::Script(script) = &tx else {
+        unreachable!("The transaction is a script")
+    };
+    let witness_indexes: Vec<_> = script
+        .inputs()
+        .iter()
+        .map(|input| input.witness_index().expect("Signed input"))
+        .collect();
+    assert_eq!(witness_indexes, vec![0, 1, 0]);
+    assert_eq!(tx.dedup_witnesses(), 0);
+    tx.sign_all_inputs(&[first, second], &chain_id);
+    tx.verify_input_signatures(&chain_id)
+        .expect("All inputs should be signed");
+}
+
+#[test]
+fn dedup_witnesses_updates_the_bytecode_witness_index() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let key = SecretKey::random(rng);
+
+    let mut create =
+        TransactionBuilder::create(generate_bytes(rng).into(), rng.gen(), vec![])
+            .finalize();
+    let bytecode = create.witnesses()[0].clone();
+    for _ in 0..2 {
+        let witness_index = create.witnesses().len() as u16;
+        create.add_unsigned_coin_input(
+            rng.gen(),
+            &key.public_key(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            witness_index,
+        );
+        create.witnesses_mut().push(Witness::default());
+    }
+    // The bytecode is moved after the signatures, and its index has to be updated
+    create.witnesses_mut().rotate_left(1);
+    for input in create.inputs_mut() {
+        if let Input::CoinSigned(coin) = input {
+            coin.witness_index -= 1;
+        }
+    }
+    *create.bytecode_witness_index_mut() = 2;
+    let mut tx: Transaction = create.into();
+
+    let removed = tx.dedup_witnesses();
+
+    assert_eq!(removed, 1);
+    let Transaction::Create(create) = &tx else {
+        unreachable!("The transaction is a create")
+    };
+    assert_eq!(create.witnesses().len(), 2);
+    assert_eq!(*create.bytecode_witness_index(), 1);
+    assert_eq!(create.witnesses()[1], bytecode);
+    tx.sign_all_inputs(&[key], &chain_id);
+    tx.verify_input_signatures(&chain_id)
+        .expect("All inputs should be signed");
+}
+
+#[test]
+fn shared_predicate_stats_counts_repeated_predicates() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let shared = generate_nonempty_padded_bytes(rng);
+    let other = generate_nonempty_padded_bytes(rng);
+
+    let mut script = TransactionBuilder::script(vec![], vec![]).finalize();
+    for predicate in [&shared, &other, &shared, &shared] {
+        script.inputs_mut().push(Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(predicate),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            0,
+            predicate.clone(),
+            vec![],
+        ));
+    }
+    let tx: Transaction = script.into();
+
+    assert_eq!(
+        tx.shared_predicate_stats(),
+        SharedPredicateStats {
+            predicates: 4,
+            unique: 2,
+            duplicate_bytes: 2 * shared.len(),
+        }
+    );
+}
+
 #[test]
 fn coin_predicate() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -314,6 +553,125 @@ fn contract() {
     );
 }
 
+#[test]
+fn validate_predicate_owner__accepts_owners_derived_from_predicate() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let predicate = generate_nonempty_padded_bytes(rng);
+    let owner = Input::predicate_owner(&predicate);
+    let inputs = [
+        Input::coin_predicate(
+            rng.gen(),
+            owner,
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            predicate.clone(),
+            generate_bytes(rng),
+        ),
+        Input::message_coin_predicate(
+            rng.gen(),
+            owner,
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            predicate.clone(),
+            generate_bytes(rng),
+        ),
+        Input::message_data_predicate(
+            rng.gen(),
+            owner,
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            generate_bytes(rng),
+            predicate.clone(),
+            generate_bytes(rng),
+        ),
+    ];
+
+    for input in inputs {
+        // When
+        let result = input.validate_predicate_owner();
+
+        // Then
+        assert_eq!(result, Ok(()));
+        assert!(Input::is_predicate_owner_valid(&owner, &predicate));
+    }
+}
+
+#[test]
+fn validate_predicate_owner__reports_mismatched_field() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let predicate = generate_nonempty_padded_bytes(rng);
+    let expected = Input::predicate_owner(&predicate);
+    let wrong_owner: Address = rng.gen();
+    let coin = Input::coin_predicate(
+        rng.gen(),
+        wrong_owner,
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        predicate.clone(),
+        generate_bytes(rng),
+    );
+    let message_coin = Input::message_coin_predicate(
+        rng.gen(),
+        wrong_owner,
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        predicate.clone(),
+        generate_bytes(rng),
+    );
+    let message_data = Input::message_data_predicate(
+        rng.gen(),
+        wrong_owner,
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        generate_bytes(rng),
+        predicate.clone(),
+        generate_bytes(rng),
+    );
+    let signed = Input::coin_signed(
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+    );
+
+    // When
+    let coin_result = coin.validate_predicate_owner();
+    let message_coin_result = message_coin.validate_predicate_owner();
+    let message_data_result = message_data.validate_predicate_owner();
+    let signed_result = signed.validate_predicate_owner();
+
+    // Then
+    assert_eq!(
+        coin_result,
+        Err(PredicateOwnerError::OwnerMismatch {
+            expected,
+            actual: wrong_owner,
+        })
+    );
+    let recipient_mismatch = Err(PredicateOwnerError::RecipientMismatch {
+        expected,
+        actual: wrong_owner,
+    });
+    assert_eq!(message_coin_result, recipient_mismatch);
+    assert_eq!(message_data_result, recipient_mismatch);
+    assert_eq!(signed_result, Err(PredicateOwnerError::NotPredicate));
+    assert!(!Input::is_predicate_owner_valid(&wrong_owner, &predicate));
+}
+
 #[test]
 fn message_metadata() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -429,7 +787,14 @@ fn message_metadata() {
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate length error");
 
-    assert_eq!(ValidityError::InputPredicateLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::InputPredicateLength {
+            index: 1,
+            actual: PREDICATE_PARAMS.max_predicate_length() + 1,
+            limit: PREDICATE_PARAMS.max_predicate_length(),
+        },
+        err,
+    );
 
     let predicate_data =
         vec![0xff; PREDICATE_PARAMS.max_predicate_data_length() as usize + 1];
@@ -447,7 +812,14 @@ fn message_metadata() {
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate data length error");
 
-    assert_eq!(ValidityError::InputPredicateDataLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::InputPredicateDataLength {
+            index: 1,
+            actual: PREDICATE_PARAMS.max_predicate_data_length() + 1,
+            limit: PREDICATE_PARAMS.max_predicate_data_length(),
+        },
+        err,
+    );
 }
 
 #[test]
@@ -514,7 +886,14 @@ fn message_message_coin() {
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate length error");
 
-    assert_eq!(ValidityError::InputPredicateLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::InputPredicateLength {
+            index: 1,
+            actual: PREDICATE_PARAMS.max_predicate_length() + 1,
+            limit: PREDICATE_PARAMS.max_predicate_length(),
+        },
+        err,
+    );
 
     let predicate_data =
         vec![0xff; PREDICATE_PARAMS.max_predicate_data_length() as usize + 1];
@@ -531,7 +910,80 @@ fn message_message_coin() {
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate data length error");
 
-    assert_eq!(ValidityError::InputPredicateDataLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::InputPredicateDataLength {
+            index: 1,
+            actual: PREDICATE_PARAMS.max_predicate_data_length() + 1,
+            limit: PREDICATE_PARAMS.max_predicate_data_length(),
+        },
+        err,
+    );
+}
+
+#[test]
+fn transaction_with_oversized_predicate_reports_index_of_the_input() {
+    let limit = 64;
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_predicate_params(
+        PREDICATE_PARAMS
+            .with_max_predicate_length(limit)
+            .with_max_predicate_data_length(limit),
+    );
+
+    let predicate_input = |predicate: Vec<u8>, predicate_data: Vec<u8>| {
+        let owner = Input::predicate_owner(&predicate);
+        Input::coin_predicate(
+            UtxoId::new(
+                [predicate.len() as u8; 32].into(),
+                predicate_data.len() as u16,
+            ),
+            owner,
+            1_000,
+            AssetId::BASE,
+            Default::default(),
+            0,
+            predicate,
+            predicate_data,
+        )
+    };
+    let valid = || predicate_input(vec![0xff; 8], vec![]);
+    let check = |oversized: Input| {
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(valid())
+            .add_input(predicate_input(vec![0xff; 16], vec![]))
+            .add_input(oversized)
+            .add_input(predicate_input(vec![0xff; 24], vec![]))
+            .add_output(Output::change(Default::default(), 0, AssetId::BASE))
+            .finalize()
+            .check_without_signatures(Default::default(), &consensus_params)
+            .expect_err("Expected the oversized predicate to be rejected")
+    };
+
+    // Given
+    let oversized_predicate = predicate_input(vec![0xff; limit as usize + 8], vec![]);
+    let oversized_predicate_data = predicate_input(vec![0xff; 32], vec![0xff; 100]);
+
+    // When
+    let predicate_err = check(oversized_predicate);
+    let predicate_data_err = check(oversized_predicate_data);
+
+    // Then
+    assert_eq!(
+        predicate_err,
+        ValidityError::InputPredicateLength {
+            index: 2,
+            actual: limit + 8,
+            limit,
+        }
+    );
+    assert_eq!(
+        predicate_data_err,
+        ValidityError::InputPredicateDataLength {
+            index: 2,
+            actual: 100,
+            limit,
+        }
+    );
 }
 
 #[test]
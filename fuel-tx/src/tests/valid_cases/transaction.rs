@@ -106,7 +106,13 @@ fn maturity() {
     .check(block_height, &test_params())
     .expect_err("Expected erroneous transaction");
 
-    assert_eq!(ValidityError::TransactionMaturity, err);
+    assert_eq!(
+        ValidityError::TransactionMaturity {
+            policy: 1001.into(),
+            block_height,
+        },
+        err
+    );
 
     let err = Transaction::create(
         0,
@@ -120,7 +126,13 @@ fn maturity() {
     .check(block_height, &test_params())
     .expect_err("Expected erroneous transaction");
 
-    assert_eq!(ValidityError::TransactionMaturity, err);
+    assert_eq!(
+        ValidityError::TransactionMaturity {
+            policy: 1001.into(),
+            block_height,
+        },
+        err
+    );
 }
 
 #[test]
@@ -157,7 +169,13 @@ fn script__check__invalid_expiration_policy() {
         .expect_err("Expected erroneous transaction");
 
     // Then
-    assert_eq!(ValidityError::TransactionExpiration, err);
+    assert_eq!(
+        ValidityError::TransactionExpiration {
+            policy: old_block_height,
+            block_height,
+        },
+        err
+    );
 }
 
 #[test]
@@ -195,7 +213,127 @@ fn create__check__invalid_expiration_policy() {
         .expect_err("Failed to validate tx create");
 
     // Then
-    assert_eq!(ValidityError::TransactionExpiration, err);
+    assert_eq!(
+        ValidityError::TransactionExpiration {
+            policy: old_block_height,
+            block_height,
+        },
+        err
+    );
+}
+
+#[test]
+fn script__check__flag_defaults_policy_allowed() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000.into();
+    let mut params = test_params();
+    params.set_tx_params(TX_PARAMS.with_allow_flag_defaults(true));
+
+    TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+        // Given
+        .flag_defaults(fuel_asm::Flags::WRAPPING.bits())
+        .add_fee_input()
+        .finalize()
+        // When
+        .check(block_height, &params)
+        // Then
+        .expect("Failed to validate script");
+}
+
+#[test]
+fn script__check__flag_defaults_policy_not_allowed() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000.into();
+
+    // Given
+    let params = test_params();
+    assert!(!params.tx_params().allow_flag_defaults());
+    let err = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+        .flag_defaults(fuel_asm::Flags::WRAPPING.bits())
+        .add_fee_input()
+        .finalize()
+        // When
+        .check(block_height, &params)
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(ValidityError::TransactionFlagDefaultsNotAllowed, err);
+}
+
+#[test]
+fn script__check__invalid_flag_defaults_policy() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000.into();
+    let mut params = test_params();
+    params.set_tx_params(TX_PARAMS.with_allow_flag_defaults(true));
+
+    // Given
+    let unknown_flags = !fuel_asm::Flags::all().bits();
+    let err = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+        .flag_defaults(unknown_flags)
+        .add_fee_input()
+        .finalize()
+        // When
+        .check(block_height, &params)
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(ValidityError::TransactionPoliciesAreInvalid, err);
+}
+
+#[rstest::rstest]
+#[case::at_maturity_and_expiration(Some(1000), Some(1000), 1000, None)]
+#[case::one_before_maturity(Some(1001), None, 1000, Some(1001))]
+#[case::one_after_maturity(Some(999), None, 1000, None)]
+#[case::one_before_expiration(None, Some(1001), 1000, None)]
+#[case::one_after_expiration(None, Some(999), 1000, Some(999))]
+#[case::maturity_after_expiration(Some(1001), Some(999), 1000, Some(1001))]
+#[case::zero_height(Some(0), Some(0), 0, None)]
+#[case::max_height_without_policies(None, None, u32::MAX, None)]
+#[case::max_height_at_max_policies(Some(u32::MAX), Some(u32::MAX), u32::MAX, None)]
+#[case::max_maturity_one_before(Some(u32::MAX), None, u32::MAX - 1, Some(u32::MAX))]
+#[case::max_height_one_after_expiration(None, Some(u32::MAX - 1), u32::MAX, Some(u32::MAX - 1))]
+fn script__check__block_height_policies(
+    #[case] maturity: Option<u32>,
+    #[case] expiration: Option<u32>,
+    #[case] block_height: u32,
+    #[case] failing_policy: Option<u32>,
+) {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let mut builder =
+        TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng));
+    if let Some(maturity) = maturity {
+        builder.maturity(maturity.into());
+    }
+    if let Some(expiration) = expiration {
+        builder.expiration(expiration.into());
+    }
+    let tx = builder.add_fee_input().finalize();
+    let block_height = block_height.into();
+
+    // When
+    let result = tx.check(block_height, &test_params());
+
+    // Then
+    let expected = match failing_policy {
+        None => Ok(()),
+        Some(policy) if Some(policy) == maturity => {
+            Err(ValidityError::TransactionMaturity {
+                policy: policy.into(),
+                block_height,
+            })
+        }
+        Some(policy) => Err(ValidityError::TransactionExpiration {
+            policy: policy.into(),
+            block_height,
+        }),
+    };
+    assert_eq!(expected, result);
 }
 
 #[test]
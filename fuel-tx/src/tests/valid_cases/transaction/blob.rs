@@ -2,7 +2,10 @@
 #![allow(non_snake_case)]
 
 use super::*;
-use crate::field::Witnesses;
+use crate::field::{
+    BlobId as _,
+    Witnesses,
+};
 use fuel_asm::op;
 use fuel_types::{
     BlobId,
@@ -67,7 +70,13 @@ fn check__fails_if_maturity_not_met() {
     let result = tx.check(block_height, &test_params());
 
     // Then
-    assert_eq!(Err(ValidityError::TransactionMaturity), result);
+    assert_eq!(
+        Err(ValidityError::TransactionMaturity {
+            policy: failing_block_height,
+            block_height,
+        }),
+        result
+    );
 }
 
 #[test]
@@ -99,7 +108,13 @@ fn check__fails_if_expiration_not_met() {
     let result = tx.check(block_height, &test_params());
 
     // Then
-    assert_eq!(Err(ValidityError::TransactionExpiration), result);
+    assert_eq!(
+        Err(ValidityError::TransactionExpiration {
+            policy: failing_block_height,
+            block_height,
+        }),
+        result
+    );
 }
 
 #[test]
@@ -110,7 +125,7 @@ fn check__fails_if_blob_id_doesnt_match_payload() {
         id: BlobId::from_bytes(&[0xf0; 32]).unwrap(),
         witness_index: 0,
     });
-    builder.add_witness(blob_data.into());
+    builder.add_witness(blob_data.clone().into());
     builder.max_fee_limit(0);
     builder.add_input(Input::coin_predicate(
         Default::default(),
@@ -131,11 +146,78 @@ fn check__fails_if_blob_id_doesnt_match_payload() {
 
     // Then
     assert_eq!(
-        Err(ValidityError::TransactionBlobIdVerificationFailed),
+        Err(ValidityError::BlobIdMismatch {
+            declared: BlobId::from_bytes(&[0xf0; 32]).unwrap(),
+            computed: BlobId::compute(&blob_data),
+        }),
         result
     );
 }
 
+#[test]
+fn validate_id__valid_blob_transaction_passes() {
+    // Given
+    let tx = valid_blob_transaction().finalize();
+
+    // When
+    let result = tx.validate_id();
+
+    // Then
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn validate_id__fails_if_blob_id_differs_from_witness() {
+    // Given
+    let declared = BlobId::from_bytes(&[0xf0; 32]).unwrap();
+    let mut tx = valid_blob_transaction().finalize();
+    let computed = *tx.blob_id();
+    *tx.blob_id_mut() = declared;
+
+    // When
+    let result = tx.validate_id();
+
+    // Then
+    assert_eq!(
+        result,
+        Err(ValidityError::BlobIdMismatch { declared, computed })
+    );
+}
+
+#[test]
+fn validate_id__fails_if_witness_is_missing() {
+    // Given
+    let mut tx = valid_blob_transaction().finalize();
+    tx.witnesses_mut().clear();
+
+    // When
+    let result = tx.validate_id();
+
+    // Then
+    assert_eq!(
+        result,
+        Err(ValidityError::InputWitnessIndexBounds { index: 0 })
+    );
+}
+
+#[test]
+fn compute_blob_id__sets_id_of_witness() {
+    // Given
+    let blob_data = vec![2; 100];
+    let mut builder = TransactionBuilder::blob(BlobBody {
+        id: Default::default(),
+        witness_index: 0,
+    });
+    builder.add_witness(blob_data.clone().into());
+
+    // When
+    let tx = builder.compute_blob_id().finalize();
+
+    // Then
+    assert_eq!(*tx.blob_id(), BlobId::compute(&blob_data));
+    assert_eq!(tx.validate_id(), Ok(()));
+}
+
 #[test]
 fn check__not_set_witness_limit_success() {
     let block_height = 1000.into();
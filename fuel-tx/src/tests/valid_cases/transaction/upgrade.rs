@@ -90,7 +90,13 @@ fn maturity() {
     let result = tx.check(block_height, &test_params());
 
     // Then
-    assert_eq!(Err(ValidityError::TransactionMaturity), result);
+    assert_eq!(
+        Err(ValidityError::TransactionMaturity {
+            policy: failing_block_height,
+            block_height,
+        }),
+        result
+    );
 }
 
 #[test]
@@ -123,7 +129,13 @@ fn upgrade__check__valid_expiration_policy() {
     let result = tx.check(block_height, &test_params());
 
     // Then
-    assert_eq!(Err(ValidityError::TransactionExpiration), result);
+    assert_eq!(
+        Err(ValidityError::TransactionExpiration {
+            policy: failing_block_height,
+            block_height,
+        }),
+        result
+    );
 }
 
 #[test]
@@ -674,6 +686,126 @@ fn check__errors_when_consensus_parameters_different_than_calculated_metadata()
     assert_eq!(Err(ValidityError::TransactionMetadataMismatch), result);
 }
 
+fn consensus_parameters_upgrade(new_params: &ConsensusParameters) -> Upgrade {
+    let mut builder = TransactionBuilder::upgrade_consensus_parameters(new_params)
+        .expect("Failed to serialize the consensus parameters");
+    builder.add_input(Input::coin_predicate(
+        Default::default(),
+        Input::predicate_owner(predicate()),
+        Default::default(),
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+        predicate(),
+        vec![],
+    ));
+    builder.with_params(test_params());
+    builder.finalize()
+}
+
+#[test]
+fn upgrade_consensus_parameters__builds_transaction_that_passes_check() {
+    let block_height = 1000.into();
+    let mut new_params = test_params();
+    new_params.set_gas_costs(GasCosts::free());
+
+    // Given
+    let tx = consensus_parameters_upgrade(&new_params);
+
+    // When
+    let result = tx.check(block_height, &test_params());
+
+    // Then
+    assert_eq!(Ok(()), result);
+    assert_eq!(
+        UpgradeMetadata::compute(&tx),
+        Ok(UpgradeMetadata::ConsensusParameters {
+            calculated_checksum: new_params.checksum().unwrap(),
+            consensus_parameters: Box::new(new_params),
+        })
+    );
+}
+
+#[test]
+fn validate_purpose__gas_costs_only_allows_gas_costs_change() {
+    let mut new_params = test_params();
+    new_params.set_gas_costs(GasCosts::free());
+    let metadata = UpgradeMetadata::compute(&consensus_parameters_upgrade(&new_params))
+        .expect("Valid metadata");
+
+    // Given
+    let constraints = UpgradeConstraints::gas_costs_only(test_params());
+
+    // When
+    let result = metadata.validate_purpose(&constraints);
+
+    // Then
+    assert_eq!(Ok(()), result);
+}
+
+#[test]
+fn validate_purpose__gas_costs_only_rejects_fee_params_change() {
+    let mut new_params = test_params();
+    new_params.set_gas_costs(GasCosts::free());
+    new_params.set_fee_params(test_params().fee_params().with_gas_per_byte(1234));
+    let metadata = UpgradeMetadata::compute(&consensus_parameters_upgrade(&new_params))
+        .expect("Valid metadata");
+
+    // Given
+    let constraints = UpgradeConstraints::gas_costs_only(test_params());
+
+    // When
+    let result = metadata.validate_purpose(&constraints);
+
+    // Then
+    assert_eq!(
+        Err(ValidityError::TransactionUpgradeDisallowedChange(
+            UpgradeChange::FeeParams
+        )),
+        result
+    );
+}
+
+#[test]
+fn validate_purpose__allows_fee_params_change_when_allowed() {
+    let mut new_params = test_params();
+    new_params.set_fee_params(test_params().fee_params().with_gas_per_byte(1234));
+    let metadata = UpgradeMetadata::compute(&consensus_parameters_upgrade(&new_params))
+        .expect("Valid metadata");
+
+    // Given
+    let constraints = UpgradeConstraints {
+        allow_fee_params: true,
+        ..UpgradeConstraints::new(test_params())
+    };
+
+    // When
+    let result = metadata.validate_purpose(&constraints);
+
+    // Then
+    assert_eq!(Ok(()), result);
+}
+
+#[test]
+fn validate_purpose__gas_costs_only_rejects_state_transition() {
+    let metadata = UpgradeMetadata::compute(&valid_upgrade_transaction().finalize())
+        .expect("Valid metadata");
+
+    // Given
+    let constraints = UpgradeConstraints::gas_costs_only(test_params());
+
+    // When
+    let result = metadata.validate_purpose(&constraints);
+
+    // Then
+    assert_eq!(
+        Err(ValidityError::TransactionUpgradeDisallowedChange(
+            UpgradeChange::StateTransition
+        )),
+        result
+    );
+}
+
 // The module tests that `Upgrade` transaction can work with different input types.
 mod check_inputs {
     use super::*;
@@ -166,7 +166,13 @@ fn maturity() {
     let result = tx.check(block_height, &test_params());
 
     // Then
-    assert_eq!(Err(ValidityError::TransactionMaturity), result);
+    assert_eq!(
+        Err(ValidityError::TransactionMaturity {
+            policy: failing_block_height,
+            block_height,
+        }),
+        result
+    );
 }
 
 #[test]
@@ -199,7 +205,13 @@ fn upload__check__valid_expiration_policy() {
     let result = tx.check(block_height, &test_params());
 
     // Then
-    assert_eq!(Err(ValidityError::TransactionExpiration), result);
+    assert_eq!(
+        Err(ValidityError::TransactionExpiration {
+            policy: failing_block_height,
+            block_height,
+        }),
+        result
+    );
 }
 
 #[test]
@@ -46,9 +46,11 @@ use alloc::vec::{
 };
 use itertools::Itertools;
 
+mod dedup;
 mod fee;
 mod metadata;
 mod repr;
+mod signing;
 mod types;
 mod validity;
 
@@ -68,6 +70,7 @@ pub use consensus_parameters::{
     ScriptParameters,
     TxParameters,
 };
+pub use dedup::SharedPredicateStats;
 pub use fee::{
     Chargeable,
     TransactionFee,
@@ -474,6 +477,48 @@ impl Transaction {
             _ => None,
         }
     }
+
+    /// Returns the size of the transaction once its witnesses are replaced by the
+    /// witnesses of `witness_sizes` bytes, e.g. when the empty placeholders are
+    /// replaced by the 64-byte signatures. The `Mint` doesn't have witnesses, so
+    /// its size doesn't change.
+    pub fn size_with_witness_placeholders(&self, witness_sizes: &[usize]) -> usize {
+        use field::Witnesses;
+
+        let witnesses = match self {
+            Self::Script(tx) => tx.witnesses(),
+            Self::Create(tx) => tx.witnesses(),
+            Self::Mint(_) => return self.size(),
+            Self::Upgrade(tx) => tx.witnesses(),
+            Self::Upload(tx) => tx.witnesses(),
+            Self::Blob(tx) => tx.witnesses(),
+        };
+        size_with_witness_sizes(self.size(), witnesses, witness_sizes)
+    }
+}
+
+/// Replaces the encoded `witnesses` in the `size` of the transaction with the
+/// witnesses of `witness_sizes` bytes. The number of witnesses is a fixed-size
+/// field, so it doesn't affect the size.
+pub(crate) fn size_with_witness_sizes(
+    size: usize,
+    witnesses: &[Witness],
+    witness_sizes: &[usize],
+) -> usize {
+    let witnesses_size = witnesses
+        .iter()
+        .map(Serialize::size)
+        .fold(0usize, usize::saturating_add);
+    let placeholders_size = witness_sizes
+        .iter()
+        .map(|len| {
+            fuel_types::bytes::padded_len_usize(*len)
+                .unwrap_or(usize::MAX)
+                .saturating_add(Witness::default().size())
+        })
+        .fold(0usize, usize::saturating_add);
+    size.saturating_sub(witnesses_size)
+        .saturating_add(placeholders_size)
 }
 
 pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
@@ -871,6 +916,24 @@ pub mod field {
         }
     }
 
+    pub trait FlagDefaults {
+        fn flag_defaults(&self) -> Word;
+        fn set_flag_defaults(&mut self, value: Word);
+    }
+
+    impl<T: Policies + ?Sized> FlagDefaults for T {
+        #[inline(always)]
+        fn flag_defaults(&self) -> Word {
+            self.policies().get(PolicyType::FlagDefaults).unwrap_or(0)
+        }
+
+        #[inline(always)]
+        fn set_flag_defaults(&mut self, value: Word) {
+            self.policies_mut()
+                .set(PolicyType::FlagDefaults, Some(value))
+        }
+    }
+
     pub trait TxPointer {
         fn tx_pointer(&self) -> &crate::TxPointer;
         fn tx_pointer_mut(&mut self) -> &mut crate::TxPointer;
@@ -1362,6 +1425,15 @@ pub mod typescript {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        Finalizable,
+        TransactionBuilder,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
 
     #[test]
     fn script__metered_bytes_size___includes_witnesses() {
@@ -1467,4 +1539,109 @@ mod tests {
             tx_with_no_witnesses.metered_bytes_size() + witness.size()
         );
     }
+
+    fn script_builder(
+        rng: &mut StdRng,
+        signers: usize,
+        predicate_lens: &[usize],
+    ) -> TransactionBuilder<Script> {
+        let mut builder = TransactionBuilder::script(vec![0x24; 12], vec![0xaa; 5]);
+        for _ in 0..signers {
+            let secret = fuel_crypto::SecretKey::random(rng);
+            // Two inputs per key share the witness.
+            for _ in 0..2 {
+                builder.add_unsigned_coin_input(
+                    secret,
+                    rng.gen(),
+                    rng.gen(),
+                    rng.gen(),
+                    rng.gen(),
+                );
+            }
+        }
+        for len in predicate_lens {
+            builder.add_input(Input::coin_predicate(
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                vec![0x11; *len],
+                vec![0x22; len / 2],
+            ));
+        }
+        builder.add_output(Output::change(rng.gen(), 0, rng.gen()));
+        builder
+    }
+
+    #[rstest::rstest]
+    fn transaction_builder__estimated_size__matches_the_signed_size(
+        #[values(0, 1, 3, 8)] signers: usize,
+        #[values(vec![], vec![7], vec![1, 64, 1000])] predicate_lens: Vec<usize>,
+    ) {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(2322);
+        let builder = script_builder(rng, signers, &predicate_lens);
+
+        // When
+        let estimated_size = builder.estimated_size();
+
+        // Then
+        assert_eq!(estimated_size, builder.finalize().size());
+    }
+
+    #[rstest::rstest]
+    fn size_with_witness_placeholders__signatures__match_the_signed_size(
+        #[values(0, 1, 3, 8)] signers: usize,
+        #[values(vec![], vec![7], vec![1, 64, 1000])] predicate_lens: Vec<usize>,
+    ) {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(2322);
+        let mut builder = script_builder(rng, signers, &predicate_lens);
+        let unsigned = builder.finalize_without_signature_as_transaction();
+        let signed = builder.finalize_as_transaction();
+
+        // When
+        let size = unsigned.size_with_witness_placeholders(&vec![64; signers]);
+
+        // Then
+        assert_eq!(size, signed.size());
+    }
+
+    #[test]
+    fn size_with_witness_placeholders__unpadded_sizes__are_padded() {
+        // Given
+        let tx: Transaction = Transaction::script(
+            Default::default(),
+            vec![],
+            vec![],
+            Default::default(),
+            vec![],
+            vec![],
+            vec![vec![0xff; 3].into()],
+        )
+        .into();
+        let with_witnesses = |witnesses: Vec<Witness>| {
+            Transaction::script(
+                Default::default(),
+                vec![],
+                vec![],
+                Default::default(),
+                vec![],
+                vec![],
+                witnesses,
+            )
+            .size()
+        };
+
+        // When
+        let size = tx.size_with_witness_placeholders(&[9, 0, 16]);
+
+        // Then
+        assert_eq!(
+            size,
+            with_witnesses(vec![vec![0; 9].into(), vec![].into(), vec![0; 16].into()])
+        );
+    }
 }
@@ -6,6 +6,7 @@ use fuel_types::{
     ChainId,
 };
 
+mod chain_config;
 pub mod gas;
 
 pub use gas::{
@@ -15,7 +16,10 @@ pub use gas::{
     GasCostsValues,
 };
 
-use crate::consts::BALANCE_ENTRY_SIZE;
+use crate::{
+    consts::BALANCE_ENTRY_SIZE,
+    ValidityError,
+};
 
 #[cfg(feature = "test-helpers")]
 const MAX_GAS: u64 = 100_000_000;
@@ -178,6 +182,17 @@ impl ConsensusParameters {
     }
 }
 
+impl ConsensusParameters {
+    /// Returns the checksum of the serialized consensus parameters. It is the same
+    /// checksum as used by the `Upgrade` transaction to commit to the parameters.
+    pub fn checksum(&self) -> Result<Bytes32, ValidityError> {
+        let serialized = postcard::to_allocvec(self).map_err(|_| {
+            ValidityError::TransactionUpgradeConsensusParametersSerialization
+        })?;
+        Ok(fuel_crypto::Hasher::hash(serialized))
+    }
+}
+
 impl ConsensusParameters {
     /// Set the transaction parameters.
     pub fn set_tx_params(&mut self, tx_params: TxParameters) {
@@ -620,6 +635,8 @@ impl Default for PredicateParametersV1 {
 pub enum TxParameters {
     /// Version 1 of the transaction parameters.
     V1(TxParametersV1),
+    /// Version 2 of the transaction parameters.
+    V2(TxParametersV2),
 }
 
 impl TxParameters {
@@ -651,6 +668,10 @@ impl TxParameters {
                 params.max_inputs = max_inputs;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_inputs = max_inputs;
+                Self::V2(params)
+            }
         }
     }
 
@@ -661,6 +682,10 @@ impl TxParameters {
                 params.max_outputs = max_outputs;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_outputs = max_outputs;
+                Self::V2(params)
+            }
         }
     }
 
@@ -671,6 +696,10 @@ impl TxParameters {
                 params.max_witnesses = max_witnesses;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_witnesses = max_witnesses;
+                Self::V2(params)
+            }
         }
     }
 
@@ -681,6 +710,10 @@ impl TxParameters {
                 params.max_gas_per_tx = max_gas_per_tx;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_gas_per_tx = max_gas_per_tx;
+                Self::V2(params)
+            }
         }
     }
 
@@ -691,9 +724,93 @@ impl TxParameters {
                 params.max_size = max_size;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_size = max_size;
+                Self::V2(params)
+            }
         }
     }
 
+    /// Replace the strict call gas with the given argument, upgrading the
+    /// parameters to the [`TxParametersV2`]
+    pub fn with_strict_call_gas(self, strict_call_gas: bool) -> Self {
+        let mut params = match self {
+            Self::V1(params) => params.into(),
+            Self::V2(params) => params,
+        };
+        params.strict_call_gas = strict_call_gas;
+        Self::V2(params)
+    }
+
+    /// Replace the switch allowing the flag defaults policy with the given
+    /// argument, upgrading the parameters to the [`TxParametersV2`]
+    pub fn with_allow_flag_defaults(self, allow_flag_defaults: bool) -> Self {
+        let mut params = match self {
+            Self::V1(params) => params.into(),
+            Self::V2(params) => params,
+        };
+        params.allow_flag_defaults = allow_flag_defaults;
+        Self::V2(params)
+    }
+
+    /// Replace the word-aligned heap switch with the given argument, upgrading the
+    /// parameters to the [`TxParametersV2`]
+    pub fn with_word_aligned_heap(self, word_aligned_heap: bool) -> Self {
+        let mut params = match self {
+            Self::V1(params) => params.into(),
+            Self::V2(params) => params,
+        };
+        params.word_aligned_heap = word_aligned_heap;
+        Self::V2(params)
+    }
+
+    /// Replace the switch allowing the empty reads at any address with the given
+    /// argument, upgrading the parameters to the [`TxParametersV2`]
+    pub fn with_allow_empty_reads(self, allow_empty_reads: bool) -> Self {
+        let mut params = match self {
+            Self::V1(params) => params.into(),
+            Self::V2(params) => params,
+        };
+        params.allow_empty_reads = allow_empty_reads;
+        Self::V2(params)
+    }
+
+    /// Replace the switch keeping the relative jumps within the code with the given
+    /// argument, upgrading the parameters to the [`TxParametersV2`]
+    pub fn with_relative_jumps_within_code(
+        self,
+        relative_jumps_within_code: bool,
+    ) -> Self {
+        let mut params = match self {
+            Self::V1(params) => params.into(),
+            Self::V2(params) => params,
+        };
+        params.relative_jumps_within_code = relative_jumps_within_code;
+        Self::V2(params)
+    }
+
+    /// Replace the switch reverting the call frames alone with the given argument,
+    /// upgrading the parameters to the [`TxParametersV2`]
+    pub fn with_call_frame_reverts(self, call_frame_reverts: bool) -> Self {
+        let mut params = match self {
+            Self::V1(params) => params.into(),
+            Self::V2(params) => params,
+        };
+        params.call_frame_reverts = call_frame_reverts;
+        Self::V2(params)
+    }
+
+    /// Replace the switch distinguishing the panics of the transfers to the outputs
+    /// with the given argument, upgrading the parameters to the [`TxParametersV2`]
+    pub fn with_precise_output_panics(self, precise_output_panics: bool) -> Self {
+        let mut params = match self {
+            Self::V1(params) => params.into(),
+            Self::V2(params) => params,
+        };
+        params.precise_output_panics = precise_output_panics;
+        Self::V2(params)
+    }
+
     /// Replace the max bytecode subsections with the given argument
     pub const fn with_max_bytecode_subsections(
         self,
@@ -704,6 +821,10 @@ impl TxParameters {
                 params.max_bytecode_subsections = max_bytecode_subsections;
                 Self::V1(params)
             }
+            Self::V2(mut params) => {
+                params.max_bytecode_subsections = max_bytecode_subsections;
+                Self::V2(params)
+            }
         }
     }
 }
@@ -713,6 +834,7 @@ impl TxParameters {
     pub const fn max_inputs(&self) -> u16 {
         match self {
             Self::V1(params) => params.max_inputs,
+            Self::V2(params) => params.max_inputs,
         }
     }
 
@@ -720,6 +842,7 @@ impl TxParameters {
     pub const fn max_outputs(&self) -> u16 {
         match self {
             Self::V1(params) => params.max_outputs,
+            Self::V2(params) => params.max_outputs,
         }
     }
 
@@ -727,6 +850,7 @@ impl TxParameters {
     pub const fn max_witnesses(&self) -> u32 {
         match self {
             Self::V1(params) => params.max_witnesses,
+            Self::V2(params) => params.max_witnesses,
         }
     }
 
@@ -734,6 +858,7 @@ impl TxParameters {
     pub const fn max_gas_per_tx(&self) -> u64 {
         match self {
             Self::V1(params) => params.max_gas_per_tx,
+            Self::V2(params) => params.max_gas_per_tx,
         }
     }
 
@@ -741,6 +866,7 @@ impl TxParameters {
     pub const fn max_size(&self) -> u64 {
         match self {
             Self::V1(params) => params.max_size,
+            Self::V2(params) => params.max_size,
         }
     }
 
@@ -748,6 +874,68 @@ impl TxParameters {
     pub const fn max_bytecode_subsections(&self) -> u16 {
         match self {
             Self::V1(params) => params.max_bytecode_subsections,
+            Self::V2(params) => params.max_bytecode_subsections,
+        }
+    }
+
+    /// Whether `CALL` panics instead of forwarding the remaining context gas when
+    /// the requested gas exceeds it.
+    pub const fn strict_call_gas(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(params) => params.strict_call_gas,
+        }
+    }
+
+    /// Whether the transactions may set the `FlagDefaults` policy.
+    pub const fn allow_flag_defaults(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(params) => params.allow_flag_defaults,
+        }
+    }
+
+    /// Whether `ALOC` rounds the allocation sizes up to a multiple of the word size.
+    pub const fn word_aligned_heap(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(params) => params.word_aligned_heap,
+        }
+    }
+
+    /// Whether `RETD`, `LOGD`, `SMO`, `S256`, `K256` and `MEQ` may read the empty
+    /// range at any address up to the end of the memory.
+    pub const fn allow_empty_reads(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(params) => params.allow_empty_reads,
+        }
+    }
+
+    /// Whether the relative jumps panic when targeting outside of the code of the
+    /// current context.
+    pub const fn relative_jumps_within_code(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(params) => params.relative_jumps_within_code,
+        }
+    }
+
+    /// Whether `RVRT` and the panics inside a call frame revert only the frame and
+    /// return to the caller.
+    pub const fn call_frame_reverts(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(params) => params.call_frame_reverts,
+        }
+    }
+
+    /// Whether `TRO` distinguishes the non-variable and the already filled outputs
+    /// from the missing ones in its panics.
+    pub const fn precise_output_panics(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(params) => params.precise_output_panics,
         }
     }
 }
@@ -764,6 +952,7 @@ impl TxParameters {
     pub fn set_max_size(&mut self, max_size: u64) {
         match self {
             Self::V1(params) => params.max_size = max_size,
+            Self::V2(params) => params.max_size = max_size,
         }
     }
 }
@@ -812,6 +1001,110 @@ impl Default for TxParametersV1 {
     }
 }
 
+impl From<TxParametersV2> for TxParameters {
+    fn from(params: TxParametersV2) -> Self {
+        Self::V2(params)
+    }
+}
+
+/// The transaction parameters.
+/// The difference with [`TxParametersV1`]:
+/// - `strict_call_gas` has been added.
+/// - `allow_flag_defaults` has been added.
+/// - `word_aligned_heap` has been added.
+/// - `allow_empty_reads` has been added.
+/// - `relative_jumps_within_code` has been added.
+/// - `call_frame_reverts` has been added.
+/// - `precise_output_panics` has been added.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct TxParametersV2 {
+    /// Maximum number of inputs.
+    pub max_inputs: u16,
+    /// Maximum number of outputs.
+    pub max_outputs: u16,
+    /// Maximum number of witnesses.
+    pub max_witnesses: u32,
+    /// Maximum gas per transaction.
+    pub max_gas_per_tx: u64,
+    /// Maximum size in bytes
+    pub max_size: u64,
+    /// Maximum number of bytecode subsections.
+    pub max_bytecode_subsections: u16,
+    /// `CALL` panics with `InsufficientGasForCall` instead of forwarding the
+    /// remaining context gas when the requested gas exceeds it.
+    pub strict_call_gas: bool,
+    /// Transactions may set the `FlagDefaults` policy, the initial value of the
+    /// `$flag` register of the script and the called contracts.
+    pub allow_flag_defaults: bool,
+    /// `ALOC` rounds the allocation sizes up to a multiple of the word size, keeping
+    /// `$hp` word-aligned.
+    pub word_aligned_heap: bool,
+    /// `RETD`, `LOGD`, `SMO`, `S256`, `K256` and `MEQ` may read the empty range at
+    /// any address up to the end of the memory, including the unallocated memory
+    /// between the stack and the heap.
+    pub allow_empty_reads: bool,
+    /// `JMPF`, `JMPB`, `JNZF` and `JNZB` panic with `MemoryOverflow` when targeting
+    /// outside of the code of the current context.
+    pub relative_jumps_within_code: bool,
+    /// `RVRT` and the panics inside a call frame undo the storage writes, the coin
+    /// transfers and the receipts of the frame and return to the caller, instead of
+    /// terminating the transaction.
+    pub call_frame_reverts: bool,
+    /// `TRO` panics with `ExpectedOutputVariable` for the output that isn't
+    /// `Output::Variable` and with `DoubleSpendOutput` for the already filled
+    /// variable output, instead of `OutputNotFound`.
+    pub precise_output_panics: bool,
+}
+
+#[cfg(feature = "test-helpers")]
+impl TxParametersV2 {
+    /// Default parameters just for testing.
+    pub const DEFAULT: Self = Self {
+        max_inputs: 255,
+        max_outputs: 255,
+        max_witnesses: 255,
+        max_gas_per_tx: MAX_GAS,
+        max_size: MAX_SIZE,
+        max_bytecode_subsections: 255,
+        strict_call_gas: false,
+        allow_flag_defaults: false,
+        word_aligned_heap: false,
+        allow_empty_reads: false,
+        relative_jumps_within_code: false,
+        call_frame_reverts: false,
+        precise_output_panics: false,
+    };
+}
+
+#[cfg(feature = "test-helpers")]
+impl Default for TxParametersV2 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<TxParametersV1> for TxParametersV2 {
+    fn from(params: TxParametersV1) -> Self {
+        Self {
+            max_inputs: params.max_inputs,
+            max_outputs: params.max_outputs,
+            max_witnesses: params.max_witnesses,
+            max_gas_per_tx: params.max_gas_per_tx,
+            max_size: params.max_size,
+            max_bytecode_subsections: params.max_bytecode_subsections,
+            strict_call_gas: false,
+            allow_flag_defaults: false,
+            word_aligned_heap: false,
+            allow_empty_reads: false,
+            relative_jumps_within_code: false,
+            call_frame_reverts: false,
+            precise_output_panics: false,
+        }
+    }
+}
+
 /// Versioned script parameters.
 #[derive(
     Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
@@ -1042,6 +1335,8 @@ mod tests {
     use super::{
         ConsensusParameters,
         ConsensusParametersV1,
+        TxParameters,
+        TxParametersV2,
     };
 
     #[test]
@@ -1066,4 +1361,51 @@ mod tests {
 
         assert!(matches!(result, Ok(())))
     }
+
+    #[test]
+    fn strict_call_gas_is_disabled_in_tx_parameters_v1() {
+        assert!(!TxParameters::DEFAULT.strict_call_gas());
+    }
+
+    #[test]
+    fn setting_strict_call_gas_upgrades_tx_parameters_v1_to_v2() {
+        let params = TxParameters::DEFAULT.with_max_inputs(7);
+
+        let params = params.with_strict_call_gas(true);
+
+        assert!(params.strict_call_gas());
+        assert_eq!(params.max_inputs(), 7);
+        assert!(matches!(
+            params,
+            TxParameters::V2(TxParametersV2 {
+                strict_call_gas: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn word_aligned_heap_is_disabled_in_tx_parameters_v1() {
+        assert!(!TxParameters::DEFAULT.word_aligned_heap());
+    }
+
+    #[test]
+    fn allow_empty_reads_is_disabled_in_tx_parameters_v1() {
+        assert!(!TxParameters::DEFAULT.allow_empty_reads());
+    }
+
+    #[test]
+    fn relative_jumps_within_code_is_disabled_in_tx_parameters_v1() {
+        assert!(!TxParameters::DEFAULT.relative_jumps_within_code());
+    }
+
+    #[test]
+    fn call_frame_reverts_is_disabled_in_tx_parameters_v1() {
+        assert!(!TxParameters::DEFAULT.call_frame_reverts());
+    }
+
+    #[test]
+    fn precise_output_panics_is_disabled_in_tx_parameters_v1() {
+        assert!(!TxParameters::DEFAULT.precise_output_panics());
+    }
 }
@@ -0,0 +1,170 @@
+use super::ConsensusParameters;
+use alloc::string::String;
+
+/// The part of the chain config JSON used by fuel-core that holds the consensus
+/// parameters. The other sections of the chain config, like the genesis or the
+/// consensus, belong to fuel-core, so they are ignored.
+#[derive(serde::Deserialize)]
+struct ChainConfig {
+    consensus_parameters: ConsensusParameters,
+}
+
+#[derive(serde::Serialize)]
+struct ChainConfigRef<'a> {
+    consensus_parameters: &'a ConsensusParameters,
+}
+
+impl ConsensusParameters {
+    /// Parses the consensus parameters from the `consensus_parameters` section of
+    /// the chain config JSON used by fuel-core.
+    ///
+    /// The fields unknown to this version of the parameters are ignored, so the
+    /// config written by the newer node still loads. The gas costs are expected in
+    /// the versioned format of the [`GasCostsValues`](super::GasCostsValues).
+    pub fn from_chain_config_json(json: &str) -> serde_json::Result<Self> {
+        let config: ChainConfig = serde_json::from_str(json)?;
+        Ok(config.consensus_parameters)
+    }
+
+    /// Serializes the consensus parameters into the pretty chain config JSON with
+    /// only the `consensus_parameters` section.
+    pub fn to_chain_config_json(&self) -> String {
+        let config = ChainConfigRef {
+            consensus_parameters: self,
+        };
+        serde_json::to_string_pretty(&config).expect("unable to json format")
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus_parameters::DependentCost;
+    use core::str::FromStr;
+    use fuel_types::{
+        Address,
+        AssetId,
+        ChainId,
+    };
+    use serde_json::Value;
+
+    /// The chain config of the local testnet in the format of fuel-core.
+    const CHAIN_CONFIG: &str = include_str!("chain_config.json");
+
+    #[test]
+    fn from_chain_config_json__parses_fixture() {
+        let params = ConsensusParameters::from_chain_config_json(CHAIN_CONFIG).unwrap();
+
+        assert!(matches!(params, ConsensusParameters::V2(_)));
+
+        let tx_params = params.tx_params();
+        assert_eq!(tx_params.max_inputs(), 255);
+        assert_eq!(tx_params.max_outputs(), 255);
+        assert_eq!(tx_params.max_witnesses(), 255);
+        assert_eq!(tx_params.max_gas_per_tx(), 30_000_000);
+        assert_eq!(tx_params.max_size(), 112_640);
+        assert_eq!(tx_params.max_bytecode_subsections(), 256);
+
+        let predicate_params = params.predicate_params();
+        assert_eq!(predicate_params.max_predicate_length(), 24_576);
+        assert_eq!(predicate_params.max_predicate_data_length(), 24_576);
+        assert_eq!(predicate_params.max_message_data_length(), 102_400);
+        assert_eq!(predicate_params.max_gas_per_predicate(), 1_000_000);
+
+        let script_params = params.script_params();
+        assert_eq!(script_params.max_script_length(), 102_400);
+        assert_eq!(script_params.max_script_data_length(), 102_400);
+
+        let contract_params = params.contract_params();
+        assert_eq!(contract_params.contract_max_size(), 262_144);
+        assert_eq!(contract_params.max_storage_slots(), 1_760);
+
+        let fee_params = params.fee_params();
+        assert_eq!(fee_params.gas_price_factor(), 92);
+        assert_eq!(fee_params.gas_per_byte(), 63);
+
+        let gas_costs = params.gas_costs();
+        assert_eq!(gas_costs.add(), 1);
+        assert_eq!(gas_costs.ecr1(), 3_000);
+        assert_eq!(gas_costs.new_storage_per_byte(), 63);
+        assert_eq!(
+            gas_costs.call(),
+            DependentCost::LightOperation {
+                base: 144,
+                units_per_gas: 214,
+            }
+        );
+
+        assert_eq!(params.chain_id(), ChainId::new(0));
+        assert_eq!(
+            *params.base_asset_id(),
+            AssetId::from_str(
+                "f8f8b6283d7fa5b672b530cbb84fcccb4ff8dc40f8176ef4544ddb1f1952ad07"
+            )
+            .unwrap()
+        );
+        assert_eq!(params.block_gas_limit(), 30_000_000);
+        assert_eq!(params.block_transaction_size_limit(), 260_096);
+        assert_eq!(
+            *params.privileged_address(),
+            Address::from_str(
+                "9f0e19d6c2a6283a3222426ab2630d35516b1799b503f37b02105bebe1b8a3e9"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn to_chain_config_json__round_trips_fixture() {
+        let params = ConsensusParameters::from_chain_config_json(CHAIN_CONFIG).unwrap();
+
+        let json = params.to_chain_config_json();
+
+        let round_tripped = ConsensusParameters::from_chain_config_json(&json).unwrap();
+        assert_eq!(round_tripped, params);
+        let fixture: Value = serde_json::from_str(CHAIN_CONFIG).unwrap();
+        let written: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            written["consensus_parameters"],
+            fixture["consensus_parameters"]
+        );
+    }
+
+    #[test]
+    fn to_chain_config_json__round_trips_standard_parameters() {
+        let params = ConsensusParameters::standard();
+
+        let json = params.to_chain_config_json();
+
+        assert_eq!(
+            ConsensusParameters::from_chain_config_json(&json).unwrap(),
+            params
+        );
+    }
+
+    #[test]
+    fn from_chain_config_json__ignores_unknown_fields() {
+        let mut json: Value = serde_json::from_str(CHAIN_CONFIG).unwrap();
+        let params = &mut json["consensus_parameters"]["V2"];
+        params["new_limit"] = 1.into();
+        params["tx_params"]["V1"]["new_tx_limit"] = 2.into();
+        params["gas_costs"]["V6"]["new_op"] = 3.into();
+
+        let params = ConsensusParameters::from_chain_config_json(&json.to_string());
+
+        assert_eq!(
+            params.unwrap(),
+            ConsensusParameters::from_chain_config_json(CHAIN_CONFIG).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_chain_config_json__missing_section__fails() {
+        let err =
+            ConsensusParameters::from_chain_config_json(r#"{ "chain_name": "Local" }"#)
+                .unwrap_err();
+
+        assert!(err.to_string().contains("consensus_parameters"), "{err}");
+    }
+}
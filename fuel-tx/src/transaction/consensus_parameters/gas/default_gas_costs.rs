@@ -4,7 +4,7 @@ use super::*;
 pub const GIT: &str = "98341e564b75d1157e61d7d5f38612f6224a5b30";
 /// Modified manually afterwards in https://github.com/FuelLabs/fuel-vm/pull/780
 pub fn default_gas_costs() -> GasCostsValues {
-    GasCostsValuesV5 {
+    GasCostsValuesV6 {
         add: 1,
         addi: 1,
         and: 1,
@@ -89,8 +89,15 @@ pub fn default_gas_costs() -> GasCostsValues {
         wqmm: 3,
         xor: 1,
         xori: 1,
-        ecop: 3500,
-        epar: DependentCost::HeavyOperation {
+        ecop_bn254_add: 3500,
+        ecop_bn254_mul: 3500,
+        lqw: 1,
+        lhw: 1,
+        sqw: 1,
+        shw: 1,
+        gm_consensus_parameters_version: 2,
+        gm_state_transition_version: 2,
+        epar_bn254: DependentCost::HeavyOperation {
             base: 69000,
             gas_per_unit: 52000,
         },
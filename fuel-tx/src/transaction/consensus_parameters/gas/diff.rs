@@ -0,0 +1,294 @@
+use super::*;
+use alloc::{
+    collections::BTreeMap,
+    string::String,
+    vec::Vec,
+};
+use serde_json::Value;
+
+/// The change of one cost between two [`GasCosts`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GasCostChange {
+    /// The name of the opcode, as in the [`NamedGasCostsValues`] format.
+    pub opcode: String,
+    /// The parameter of the [`DependentCost`], e.g. `base` or `units_per_gas`,
+    /// or `None` for the flat cost.
+    pub parameter: Option<String>,
+    /// The old value, or `None` if the old costs don't define it.
+    pub old: Option<Word>,
+    /// The new value, or `None` if the new costs don't define it.
+    pub new: Option<Word>,
+    /// The change of the value relative to the old one, in percent. It is `None`
+    /// if any of the values is missing or the old value is zero.
+    ///
+    /// The increase of the `units_per_gas` parameter makes the operation cheaper.
+    pub percentage: Option<f64>,
+}
+
+impl GasCostChange {
+    fn new(
+        opcode: &str,
+        parameter: Option<String>,
+        old: Option<Word>,
+        new: Option<Word>,
+    ) -> Self {
+        let percentage = match (old, new) {
+            (Some(old), Some(new)) if old != 0 => {
+                Some((new as f64 - old as f64) / old as f64 * 100.0)
+            }
+            _ => None,
+        };
+        Self {
+            opcode: opcode.into(),
+            parameter,
+            old,
+            new,
+            percentage,
+        }
+    }
+}
+
+/// The report of the changed costs between two [`GasCosts`], see
+/// [`GasCosts::diff`].
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GasCostsDiff {
+    /// The changed flat costs, ordered by the opcode.
+    pub flat: Vec<GasCostChange>,
+    /// The changed parameters of the dependent costs, ordered by the opcode and
+    /// the parameter.
+    pub dependent: Vec<GasCostChange>,
+}
+
+impl GasCostsDiff {
+    /// Returns `true` if no cost is changed.
+    pub fn is_empty(&self) -> bool {
+        self.flat.is_empty() && self.dependent.is_empty()
+    }
+
+    /// Iterates over all the changes, the flat costs first.
+    pub fn changes(&self) -> impl Iterator<Item = &GasCostChange> {
+        self.flat.iter().chain(self.dependent.iter())
+    }
+
+    /// Returns the change with the largest relative increase, if any value
+    /// increased.
+    pub fn max_increase(&self) -> Option<&GasCostChange> {
+        self.changes()
+            .filter(|change| change.percentage.is_some_and(|p| p > 0.0))
+            .max_by(|a, b| a.percentage.partial_cmp(&b.percentage).expect("not NaN"))
+    }
+
+    /// Returns the change with the largest relative decrease, if any value
+    /// decreased.
+    pub fn max_decrease(&self) -> Option<&GasCostChange> {
+        self.changes()
+            .filter(|change| change.percentage.is_some_and(|p| p < 0.0))
+            .min_by(|a, b| a.percentage.partial_cmp(&b.percentage).expect("not NaN"))
+    }
+
+    /// Returns the costs defined by the old costs that are missing or set to zero
+    /// in the new ones, which is usually an accident.
+    pub fn lost_costs(&self) -> impl Iterator<Item = &GasCostChange> {
+        self.changes().filter(|change| {
+            change.old.is_some_and(|old| old != 0) && change.new.unwrap_or_default() == 0
+        })
+    }
+}
+
+impl GasCosts {
+    /// Compares the costs with the `other` ones, treating them as the new costs.
+    ///
+    /// The costs are matched by the name of the opcode, so the costs of the
+    /// different versions can be compared. The opcodes missing from one of the
+    /// versions are reported with the `None` value.
+    pub fn diff(&self, other: &GasCosts) -> GasCostsDiff {
+        let old = named_costs(self);
+        let new = named_costs(other);
+
+        let mut opcodes = old.keys().chain(new.keys()).collect::<Vec<_>>();
+        opcodes.sort();
+        opcodes.dedup();
+
+        let mut diff = GasCostsDiff::default();
+        for opcode in opcodes {
+            let old = old.get(opcode).map(parameters).unwrap_or_default();
+            let new = new.get(opcode).map(parameters).unwrap_or_default();
+
+            let mut names = old.keys().chain(new.keys()).collect::<Vec<_>>();
+            names.sort();
+            names.dedup();
+
+            for parameter in names {
+                let old = old.get(parameter).copied();
+                let new = new.get(parameter).copied();
+                if old == new {
+                    continue
+                }
+                let change = GasCostChange::new(opcode, parameter.clone(), old, new);
+                match parameter {
+                    None => diff.flat.push(change),
+                    Some(_) => diff.dependent.push(change),
+                }
+            }
+        }
+
+        diff
+    }
+}
+
+/// The costs keyed by the name of the opcode, as in the [`NamedGasCostsValues`].
+fn named_costs(costs: &GasCosts) -> BTreeMap<String, Value> {
+    // The version is a string, so it has no parameters to compare.
+    let named = NamedGasCostsValues::from(costs.0.as_ref().clone());
+    let Ok(Value::Object(costs)) = serde_json::to_value(named) else {
+        unreachable!("The named gas costs are serialized into the object")
+    };
+    costs.into_iter().collect()
+}
+
+/// The values of the cost keyed by the parameter, which is `None` for the flat cost.
+fn parameters(cost: &Value) -> BTreeMap<Option<String>, Word> {
+    match cost {
+        Value::Number(value) => value
+            .as_u64()
+            .map(|value| (None, value))
+            .into_iter()
+            .collect(),
+        // The dependent cost is serialized as `{ "Kind": { "parameter": value } }`.
+        Value::Object(kinds) => kinds
+            .values()
+            .filter_map(Value::as_object)
+            .flatten()
+            .filter_map(|(parameter, value)| {
+                Some((Some(parameter.clone()), value.as_u64()?))
+            })
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modified_costs() -> GasCosts {
+        let GasCostsValues::V6(mut values) = GasCostsValues::default() else {
+            panic!("The default gas costs are of the latest version")
+        };
+        values.add = values.add.saturating_mul(3);
+        values.mul = 0;
+        values.ldc = DependentCost::LightOperation {
+            base: 200,
+            units_per_gas: 50,
+        };
+        GasCosts::new(values.into())
+    }
+
+    #[test]
+    fn diff__same_costs__is_empty() {
+        let costs = GasCosts::default();
+
+        let diff = costs.diff(&costs.clone());
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.max_increase(), None);
+        assert_eq!(diff.max_decrease(), None);
+    }
+
+    #[test]
+    fn diff__modified_costs__reports_every_change() {
+        // Given
+        let old = GasCosts::default();
+        let new = modified_costs();
+        let (old_add, old_mul, old_ldc) = (old.add(), old.mul(), old.ldc());
+        let DependentCost::LightOperation {
+            base: old_base,
+            units_per_gas: old_units_per_gas,
+        } = old_ldc
+        else {
+            panic!("The default `ldc` is the light operation")
+        };
+
+        // When
+        let diff = old.diff(&new);
+
+        // Then
+        assert_eq!(
+            diff.flat,
+            vec![
+                GasCostChange::new(
+                    "add",
+                    None,
+                    Some(old_add),
+                    Some(old_add.saturating_mul(3))
+                ),
+                GasCostChange::new("mul", None, Some(old_mul), Some(0)),
+            ]
+        );
+        assert_eq!(
+            diff.dependent,
+            vec![
+                GasCostChange::new("ldc", Some("base".into()), Some(old_base), Some(200)),
+                GasCostChange::new(
+                    "ldc",
+                    Some("units_per_gas".into()),
+                    Some(old_units_per_gas),
+                    Some(50)
+                ),
+            ]
+        );
+        assert_eq!(diff.flat[0].percentage, Some(200.0));
+        assert_eq!(diff.flat[1].percentage, Some(-100.0));
+    }
+
+    #[test]
+    fn max_increase_and_decrease__modified_costs__are_the_extremes() {
+        let diff = GasCosts::default().diff(&modified_costs());
+
+        let max_increase = diff.max_increase().unwrap();
+        let max_decrease = diff.max_decrease().unwrap();
+
+        assert!(diff
+            .changes()
+            .all(|change| change.percentage <= max_increase.percentage));
+        assert!(diff
+            .changes()
+            .filter(|change| change.percentage.is_some())
+            .all(|change| change.percentage >= max_decrease.percentage));
+        assert_eq!(max_decrease.opcode, "mul");
+    }
+
+    #[test]
+    fn lost_costs__zeroed_cost__is_reported() {
+        let diff = GasCosts::default().diff(&modified_costs());
+
+        let lost = diff.lost_costs().map(|change| change.opcode.as_str());
+
+        assert_eq!(lost.collect::<Vec<_>>(), vec!["mul"]);
+    }
+
+    #[test]
+    fn diff__older_version__reports_added_and_lost_opcodes() {
+        // Given
+        let old: GasCosts = GasCostsValues::from(GasCostsValuesV5::unit()).into();
+        let new: GasCosts = GasCostsValues::from(GasCostsValuesV6::unit()).into();
+
+        // When
+        let diff = old.diff(&new);
+
+        // Then
+        let added = diff
+            .changes()
+            .filter(|change| change.old.is_none())
+            .map(|change| change.opcode.as_str())
+            .collect::<Vec<_>>();
+        assert!(added.contains(&"ecop_bn254_add"));
+        assert!(added.contains(&"epar_bn254"));
+        let lost = diff
+            .lost_costs()
+            .map(|change| change.opcode.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(lost, vec!["ecop", "epar"]);
+    }
+}
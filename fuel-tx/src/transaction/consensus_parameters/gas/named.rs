@@ -0,0 +1,248 @@
+use super::*;
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::String,
+    vec::Vec,
+};
+use serde_json::Value;
+
+/// The key of the version in the [`NamedGasCostsValues`] format.
+const VERSION_KEY: &str = "version";
+
+/// Gas costs of one version keyed by the name of the opcode, together with the
+/// costs unknown to this version.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NamedGasCosts<Values> {
+    /// The costs known to this version. The missing costs are set to one.
+    #[serde(flatten)]
+    pub values: Values,
+    /// The costs unknown to this version. They are preserved as is, so they
+    /// survive the serialization round trip.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl<Values> NamedGasCosts<Values> {
+    /// Creates named gas costs without unknown costs.
+    pub fn new(values: Values) -> Self {
+        Self {
+            values,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+/// The versioned gas costs keyed by the name of the opcode:
+///
+/// ```json
+/// {
+///   "version": "V6",
+///   "add": 1,
+///   "ldc": {
+///     "LightOperation": {
+///       "base": 15,
+///       "units_per_gas": 272
+///     }
+///   }
+/// }
+/// ```
+///
+/// Unlike the format of [`GasCostsValues`], the config written for the older
+/// version of the gas costs doesn't break when a new cost is added, and the config
+/// written for the newer version doesn't lose the costs unknown to this crate.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+pub enum NamedGasCostsValues {
+    /// Version 1 of the gas costs.
+    V1(NamedGasCosts<GasCostsValuesV1>),
+    /// Version 2 of the gas costs.
+    V2(NamedGasCosts<GasCostsValuesV2>),
+    /// Version 3 of the gas costs.
+    V3(NamedGasCosts<GasCostsValuesV3>),
+    /// Version 4 of the gas costs.
+    V4(NamedGasCosts<GasCostsValuesV4>),
+    /// Version 5 of the gas costs.
+    V5(NamedGasCosts<GasCostsValuesV5>),
+    /// Version 6 of the gas costs.
+    V6(NamedGasCosts<GasCostsValuesV6>),
+}
+
+impl NamedGasCostsValues {
+    /// Returns the costs unknown to the version.
+    pub fn extra(&self) -> &BTreeMap<String, Value> {
+        match self {
+            NamedGasCostsValues::V1(v1) => &v1.extra,
+            NamedGasCostsValues::V2(v2) => &v2.extra,
+            NamedGasCostsValues::V3(v3) => &v3.extra,
+            NamedGasCostsValues::V4(v4) => &v4.extra,
+            NamedGasCostsValues::V5(v5) => &v5.extra,
+            NamedGasCostsValues::V6(v6) => &v6.extra,
+        }
+    }
+
+    /// Splits into the known and unknown costs.
+    pub fn into_parts(self) -> (GasCostsValues, BTreeMap<String, Value>) {
+        match self {
+            NamedGasCostsValues::V1(v1) => (v1.values.into(), v1.extra),
+            NamedGasCostsValues::V2(v2) => (v2.values.into(), v2.extra),
+            NamedGasCostsValues::V3(v3) => (v3.values.into(), v3.extra),
+            NamedGasCostsValues::V4(v4) => (v4.values.into(), v4.extra),
+            NamedGasCostsValues::V5(v5) => (v5.values.into(), v5.extra),
+            NamedGasCostsValues::V6(v6) => (v6.values.into(), v6.extra),
+        }
+    }
+
+    /// Parses the gas costs from the JSON in the named format, preserving the
+    /// unknown costs.
+    pub fn from_json_str(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes the gas costs into the pretty JSON in the named format.
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(self).expect("unable to json format")
+    }
+}
+
+impl From<GasCostsValues> for NamedGasCostsValues {
+    fn from(values: GasCostsValues) -> Self {
+        match values {
+            GasCostsValues::V1(v1) => NamedGasCostsValues::V1(NamedGasCosts::new(v1)),
+            GasCostsValues::V2(v2) => NamedGasCostsValues::V2(NamedGasCosts::new(v2)),
+            GasCostsValues::V3(v3) => NamedGasCostsValues::V3(NamedGasCosts::new(v3)),
+            GasCostsValues::V4(v4) => NamedGasCostsValues::V4(NamedGasCosts::new(v4)),
+            GasCostsValues::V5(v5) => NamedGasCostsValues::V5(NamedGasCosts::new(v5)),
+            GasCostsValues::V6(v6) => NamedGasCostsValues::V6(NamedGasCosts::new(v6)),
+        }
+    }
+}
+
+impl GasCosts {
+    /// Parses the gas costs from the JSON either in the [`NamedGasCostsValues`]
+    /// format or in the versioned format of the [`GasCostsValues`] used by the
+    /// existing chain configs.
+    ///
+    /// Unknown costs of the named format are rejected. Use
+    /// [`NamedGasCostsValues::from_json_str`] to preserve them instead.
+    pub fn from_json_str(json: &str) -> serde_json::Result<Self> {
+        let value: Value = serde_json::from_str(json)?;
+        let is_named = value
+            .as_object()
+            .is_some_and(|object| object.contains_key(VERSION_KEY));
+
+        if !is_named {
+            let values: GasCostsValues = serde_json::from_value(value)?;
+            return Ok(values.into())
+        }
+
+        let named: NamedGasCostsValues = serde_json::from_value(value)?;
+        let (values, extra) = named.into_parts();
+        if !extra.is_empty() {
+            let unknown = extra.into_keys().collect::<Vec<_>>().join("`, `");
+            return Err(serde::de::Error::custom(format!(
+                "unknown gas costs: `{unknown}`"
+            )))
+        }
+
+        Ok(values.into())
+    }
+
+    /// Serializes the gas costs into the pretty JSON in the [`NamedGasCostsValues`]
+    /// format.
+    pub fn to_json_string(&self) -> String {
+        NamedGasCostsValues::from(self.0.as_ref().clone()).to_json_string()
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The default gas costs in the named format.
+    const NAMED_GAS_COSTS: &str = include_str!("named_gas_costs.json");
+    /// The default gas costs in the format of the existing chain configs.
+    const LEGACY_GAS_COSTS: &str = include_str!("legacy_gas_costs.json");
+
+    #[test]
+    fn to_json_string__matches_golden_file() {
+        assert_eq!(
+            GasCosts::default().to_json_string(),
+            NAMED_GAS_COSTS.trim_end()
+        );
+    }
+
+    #[test]
+    fn from_json_str__parses_golden_file() {
+        let gas_costs = GasCosts::from_json_str(NAMED_GAS_COSTS).unwrap();
+
+        assert_eq!(gas_costs, GasCosts::default());
+    }
+
+    #[test]
+    fn from_json_str__migrates_legacy_format() {
+        let gas_costs = GasCosts::from_json_str(LEGACY_GAS_COSTS).unwrap();
+
+        assert_eq!(gas_costs, GasCosts::default());
+        assert_eq!(gas_costs.to_json_string(), NAMED_GAS_COSTS.trim_end());
+    }
+
+    #[test]
+    fn from_json_str__round_trips_every_version() {
+        let versions: [GasCostsValues; 6] = [
+            GasCostsValuesV1::unit().into(),
+            GasCostsValuesV2::unit().into(),
+            GasCostsValuesV3::unit().into(),
+            GasCostsValuesV4::unit().into(),
+            GasCostsValuesV5::unit().into(),
+            GasCostsValuesV6::unit().into(),
+        ];
+
+        for values in versions {
+            let gas_costs = GasCosts::new(values);
+            let json = gas_costs.to_json_string();
+
+            assert_eq!(GasCosts::from_json_str(&json).unwrap(), gas_costs);
+        }
+    }
+
+    #[test]
+    fn from_json_str__sets_missing_costs_to_one() {
+        let gas_costs =
+            GasCosts::from_json_str(r#"{ "version": "V6", "add": 2 }"#).unwrap();
+
+        let mut expected = GasCostsValuesV6::unit();
+        expected.add = 2;
+        assert_eq!(gas_costs, GasCosts::new(expected.into()));
+    }
+
+    #[test]
+    fn from_json_str__rejects_unknown_costs() {
+        let json = r#"{ "version": "V6", "add": 2, "new_op": 3 }"#;
+
+        let err = GasCosts::from_json_str(json).unwrap_err();
+
+        assert!(err.to_string().contains("`new_op`"), "{err}");
+    }
+
+    #[test]
+    fn named_gas_costs__round_trips_unknown_costs() {
+        let mut json: Value = serde_json::from_str(NAMED_GAS_COSTS).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.insert("new_op".into(), 3.into());
+        object.insert(
+            "new_dependent_op".into(),
+            serde_json::json!({ "HeavyOperation": { "base": 4, "gas_per_unit": 5 } }),
+        );
+
+        let named = NamedGasCostsValues::from_json_str(&json.to_string()).unwrap();
+        assert_eq!(named.extra().len(), 2);
+        assert_eq!(named.extra()["new_op"], Value::from(3));
+
+        let round_tripped: Value = serde_json::from_str(&named.to_json_string()).unwrap();
+        assert_eq!(round_tripped, json);
+        let (values, _) = named.into_parts();
+        assert_eq!(values, GasCostsValues::default());
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    field::{
+        BytecodeWitnessIndex,
+        Inputs,
+        Witnesses,
+    },
+    Transaction,
+};
+use alloc::{
+    collections::BTreeSet,
+    vec::Vec,
+};
+use fuel_types::bytes::padded_len_usize;
+
+use super::signing::{
+    remove_witnesses,
+    signed_input_mut,
+};
+
+/// The potential savings of sharing the bytecode of the identical predicates.
+///
+/// The owner of the predicate input is the root of its bytecode, and the
+/// transaction format has no way to reference the bytecode of another input, so
+/// every predicate input carries the full bytecode. The stats show how much the
+/// transaction could shrink if the predicates were shared, e.g. by splitting the
+/// predicate into a small loader and a blob.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SharedPredicateStats {
+    /// The number of the predicate inputs.
+    pub predicates: usize,
+    /// The number of the distinct predicates.
+    pub unique: usize,
+    /// The serialized size of the predicates repeating an earlier identical one.
+    pub duplicate_bytes: usize,
+}
+
+impl Transaction {
+    /// Merges the byte-identical witnesses into the first of them, pointing the signed
+    /// inputs and the bytecode witness index to it. Returns the number of removed
+    /// witnesses.
+    ///
+    /// Only the exact byte equality is considered, so the signatures of different
+    /// owners are never merged. The witness indexes are a part of the transaction
+    /// id, so the transaction must be signed again after the deduplication, e.g. with
+    /// [`Transaction::sign_all_inputs`], and its predicates estimated again, since
+    /// their gas depends on the transaction size. The duplicates are removed even if
+    /// nothing references them, which shifts the indexes of the witnesses read by the
+    /// script.
+    pub fn dedup_witnesses(&mut self) -> usize {
+        match self {
+            Self::Script(tx) => dedup_identical_witnesses(tx, None).0,
+            Self::Create(tx) => {
+                let index = *tx.bytecode_witness_index();
+                let (removed, index) = dedup_identical_witnesses(tx, Some(index));
+                if let Some(index) = index {
+                    *tx.bytecode_witness_index_mut() = index;
+                }
+                removed
+            }
+            Self::Upgrade(tx) => dedup_identical_witnesses(tx, None).0,
+            Self::Upload(tx) => {
+                let index = *tx.bytecode_witness_index();
+                let (removed, index) = dedup_identical_witnesses(tx, Some(index));
+                if let Some(index) = index {
+                    *tx.bytecode_witness_index_mut() = index;
+                }
+                removed
+            }
+            Self::Blob(tx) => {
+                let index = *tx.bytecode_witness_index();
+                let (removed, index) = dedup_identical_witnesses(tx, Some(index));
+                if let Some(index) = index {
+                    *tx.bytecode_witness_index_mut() = index;
+                }
+                removed
+            }
+            Self::Mint(_) => 0,
+        }
+    }
+
+    /// Reports the predicates repeated across the inputs of the transaction.
+    ///
+    /// The predicates can't be deduplicated in place, see [`SharedPredicateStats`].
+    pub fn shared_predicate_stats(&self) -> SharedPredicateStats {
+        match self {
+            Self::Script(tx) => shared_predicate_stats(tx),
+            Self::Create(tx) => shared_predicate_stats(tx),
+            Self::Upgrade(tx) => shared_predicate_stats(tx),
+            Self::Upload(tx) => shared_predicate_stats(tx),
+            Self::Blob(tx) => shared_predicate_stats(tx),
+            Self::Mint(_) => SharedPredicateStats::default(),
+        }
+    }
+}
+
+/// Points the signed inputs and the `reserved` witness index to the first witness
+/// identical to the referenced one and removes the repeated witnesses.
+///
+/// Returns the number of the removed witnesses and the new `reserved` index.
+fn dedup_identical_witnesses<Tx>(
+    tx: &mut Tx,
+    reserved: Option<u16>,
+) -> (usize, Option<u16>)
+where
+    Tx: Inputs + Witnesses,
+{
+    let witnesses = tx.witnesses();
+    let first_identical = witnesses
+        .iter()
+        .enumerate()
+        .map(|(index, witness)| {
+            let first = witnesses
+                .iter()
+                .position(|other| other == witness)
+                .unwrap_or(index);
+            u16::try_from(first).unwrap_or(u16::MAX)
+        })
+        .collect::<Vec<_>>();
+    let canonical = |index: u16| {
+        first_identical
+            .get(usize::from(index))
+            .copied()
+            .unwrap_or(index)
+    };
+
+    let removed = first_identical
+        .iter()
+        .enumerate()
+        .filter(|(index, first)| *index != usize::from(**first))
+        .filter_map(|(index, _)| u16::try_from(index).ok())
+        .collect::<BTreeSet<_>>();
+
+    for input in tx.inputs_mut() {
+        if let Some((_, witness_index)) = signed_input_mut(input) {
+            *witness_index = canonical(*witness_index);
+        }
+    }
+
+    let reserved = remove_witnesses(tx, &removed, reserved.map(canonical));
+    (removed.len(), reserved)
+}
+
+fn shared_predicate_stats<Tx>(tx: &Tx) -> SharedPredicateStats
+where
+    Tx: Inputs,
+{
+    let mut stats = SharedPredicateStats::default();
+    let mut seen = BTreeSet::new();
+    for (predicate, _, _) in tx.inputs().iter().filter_map(|input| input.predicate()) {
+        stats.predicates = stats.predicates.saturating_add(1);
+        if seen.insert(predicate) {
+            stats.unique = stats.unique.saturating_add(1);
+        } else {
+            let size = padded_len_usize(predicate.len()).unwrap_or(usize::MAX);
+            stats.duplicate_bytes = stats.duplicate_bytes.saturating_add(size);
+        }
+    }
+    stats
+}
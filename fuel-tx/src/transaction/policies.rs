@@ -4,6 +4,7 @@ use core::{
     marker::PhantomData,
     ops::Deref,
 };
+use fuel_asm::Flags;
 use fuel_types::{
     canonical::{
         Deserialize,
@@ -41,6 +42,8 @@ bitflags::bitflags! {
         const MaxFee = 1 << 3;
         /// If set, the expiration is present in the policies.
         const Expiration = 1 << 4;
+        /// If set, the flag defaults are present in the policies.
+        const FlagDefaults = 1 << 5;
     }
 }
 
@@ -89,6 +92,7 @@ pub enum PolicyType {
     Maturity,
     MaxFee,
     Expiration,
+    FlagDefaults,
 }
 
 impl PolicyType {
@@ -99,6 +103,7 @@ impl PolicyType {
             PolicyType::Maturity => 2,
             PolicyType::MaxFee => 3,
             PolicyType::Expiration => 4,
+            PolicyType::FlagDefaults => 5,
         }
     }
 
@@ -109,6 +114,7 @@ impl PolicyType {
             PolicyType::Maturity => PoliciesBits::Maturity,
             PolicyType::MaxFee => PoliciesBits::MaxFee,
             PolicyType::Expiration => PoliciesBits::Expiration,
+            PolicyType::FlagDefaults => PoliciesBits::FlagDefaults,
         }
     }
 }
@@ -184,6 +190,12 @@ impl Policies {
         self
     }
 
+    /// Sets the `flag_defaults` policy, the initial value of the `$flag` register.
+    pub fn with_flag_defaults(mut self, flags: Word) -> Self {
+        self.set(PolicyType::FlagDefaults, Some(flags));
+        self
+    }
+
     /// Returns a policy's value if the corresponding bit is set.
     pub fn get(&self, policy_type: PolicyType) -> Option<Word> {
         if self.bits.contains(policy_type.bit()) {
@@ -238,6 +250,12 @@ impl Policies {
             }
         }
 
+        if let Some(flags) = self.get(PolicyType::FlagDefaults) {
+            if Flags::from_bits(flags).is_none() {
+                return false;
+            }
+        }
+
         true
     }
 
@@ -615,6 +633,11 @@ impl Distribution<Policies> for Standard {
             policies.set(PolicyType::Expiration, Some(expiration as u64));
         }
 
+        if policies.is_set(PolicyType::FlagDefaults) {
+            let flags = rng.gen::<Word>() & Flags::all().bits();
+            policies.set(PolicyType::FlagDefaults, Some(flags));
+        }
+
         policies
     }
 }
@@ -665,8 +688,9 @@ pub mod typescript {
 #[test]
 fn values_for_bitmask_produces_expected_values() {
     const MAX_BITMASK: u32 = 1 << POLICIES_NUMBER;
-    const VALUES: [Word; POLICIES_NUMBER] =
-        [0x1000001, 0x2000001, 0x3000001, 0x4000001, 0x5000001];
+    const VALUES: [Word; POLICIES_NUMBER] = [
+        0x1000001, 0x2000001, 0x3000001, 0x4000001, 0x5000001, 0x6000001,
+    ];
 
     // Given
     let mut set = hashbrown::HashSet::new();
@@ -685,8 +709,9 @@ fn values_for_bitmask_produces_expected_values() {
 #[test]
 fn canonical_serialization_deserialization_for_any_combination_of_values_works() {
     const MAX_BITMASK: u32 = 1 << POLICIES_NUMBER;
-    const VALUES: [Word; POLICIES_NUMBER] =
-        [0x1000001, 0x2000001, 0x3000001, 0x4000001, 0x5000001];
+    const VALUES: [Word; POLICIES_NUMBER] = [
+        0x1000001, 0x2000001, 0x3000001, 0x4000001, 0x5000001, 0x6000001,
+    ];
 
     for bitmask in 0..MAX_BITMASK {
         let bits =
@@ -737,7 +762,7 @@ fn serde_de_serialization_is_backward_compatible() {
     // Given
     let policies = Policies {
         bits: PoliciesBits::Maturity.union(PoliciesBits::MaxFee),
-        values: [0, 0, 20, 10, 0],
+        values: [0, 0, 20, 10, 0, 0],
     };
 
     assert_tokens(
@@ -814,7 +839,7 @@ fn serde_deserialization_new_format() {
     // Given
     let policies = Policies {
         bits: PoliciesBits::Maturity.union(PoliciesBits::Expiration),
-        values: [0, 0, 20, 0, 10],
+        values: [0, 0, 20, 0, 10, 0],
     };
 
     assert_tokens(
@@ -0,0 +1,275 @@
+use crate::{
+    field::{
+        BytecodeWitnessIndex,
+        Inputs,
+        Witnesses,
+    },
+    input::{
+        coin::CoinSigned,
+        message::{
+            MessageCoinSigned,
+            MessageDataSigned,
+        },
+    },
+    Cacheable,
+    Input,
+    Transaction,
+    UniqueIdentifier,
+    ValidityError,
+};
+use alloc::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    vec::Vec,
+};
+use fuel_crypto::{
+    Message,
+    PublicKey,
+    SecretKey,
+    Signature,
+};
+use fuel_types::{
+    Address,
+    ChainId,
+};
+
+impl Transaction {
+    /// Signs every signed input owned by one of the `keys`.
+    ///
+    /// The inputs sharing an owner are pointed to a single witness, and the witnesses
+    /// left unused by it are removed, fixing up the indexes of the remaining ones.
+    /// The transaction id is computed once, after the indexes are fixed up.
+    ///
+    /// Inputs owned by other keys and witnesses not referenced by the signed inputs,
+    /// like the bytecode witness, are kept, only their indexes are updated.
+    pub fn sign_all_inputs(&mut self, keys: &[SecretKey], chain_id: &ChainId) {
+        let keys = keys
+            .iter()
+            .map(|secret| (Input::owner(&PublicKey::from(secret)), secret))
+            .collect::<BTreeMap<_, _>>();
+
+        match self {
+            Self::Script(tx) => {
+                dedup_witnesses(tx, &keys, None);
+                sign_owned_inputs(tx, &keys, chain_id);
+            }
+            Self::Create(tx) => {
+                let bytecode_witness_index = *tx.bytecode_witness_index();
+                if let Some(index) =
+                    dedup_witnesses(tx, &keys, Some(bytecode_witness_index))
+                {
+                    *tx.bytecode_witness_index_mut() = index;
+                }
+                sign_owned_inputs(tx, &keys, chain_id);
+            }
+            Self::Upgrade(tx) => {
+                dedup_witnesses(tx, &keys, None);
+                sign_owned_inputs(tx, &keys, chain_id);
+            }
+            Self::Upload(tx) => {
+                let bytecode_witness_index = *tx.bytecode_witness_index();
+                if let Some(index) =
+                    dedup_witnesses(tx, &keys, Some(bytecode_witness_index))
+                {
+                    *tx.bytecode_witness_index_mut() = index;
+                }
+                sign_owned_inputs(tx, &keys, chain_id);
+            }
+            Self::Blob(tx) => {
+                let bytecode_witness_index = *tx.bytecode_witness_index();
+                if let Some(index) =
+                    dedup_witnesses(tx, &keys, Some(bytecode_witness_index))
+                {
+                    *tx.bytecode_witness_index_mut() = index;
+                }
+                sign_owned_inputs(tx, &keys, chain_id);
+            }
+            Self::Mint(_) => {}
+        }
+    }
+
+    /// Verifies the signatures of all signed inputs, returning the index of the
+    /// first input with a missing or invalid signature.
+    pub fn verify_input_signatures(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<(), (usize, ValidityError)> {
+        match self {
+            Self::Script(tx) => verify_input_signatures(tx, chain_id),
+            Self::Create(tx) => verify_input_signatures(tx, chain_id),
+            Self::Upgrade(tx) => verify_input_signatures(tx, chain_id),
+            Self::Upload(tx) => verify_input_signatures(tx, chain_id),
+            Self::Blob(tx) => verify_input_signatures(tx, chain_id),
+            Self::Mint(_) => Ok(()),
+        }
+    }
+}
+
+/// The owner and the witness index of the signed inputs.
+pub(super) fn signed_input_mut(input: &mut Input) -> Option<(&Address, &mut u16)> {
+    match input {
+        Input::CoinSigned(CoinSigned {
+            owner,
+            witness_index,
+            ..
+        })
+        | Input::MessageCoinSigned(MessageCoinSigned {
+            recipient: owner,
+            witness_index,
+            ..
+        })
+        | Input::MessageDataSigned(MessageDataSigned {
+            recipient: owner,
+            witness_index,
+            ..
+        }) => Some((owner, witness_index)),
+        _ => None,
+    }
+}
+
+/// Points the signed inputs owned by one of the `keys` to the lowest witness index
+/// used by their owner and removes the witnesses which are not referenced anymore.
+///
+/// The `reserved` witness index is never removed. Returns its index after the
+/// removal.
+fn dedup_witnesses<Tx>(
+    tx: &mut Tx,
+    keys: &BTreeMap<Address, &SecretKey>,
+    reserved: Option<u16>,
+) -> Option<u16>
+where
+    Tx: Inputs + Witnesses,
+{
+    let mut owner_witness = BTreeMap::<Address, u16>::new();
+    let mut released = BTreeSet::new();
+    for input in tx.inputs_mut() {
+        if let Some((owner, witness_index)) = signed_input_mut(input) {
+            if keys.contains_key(owner) {
+                let shared = owner_witness.entry(*owner).or_insert(*witness_index);
+                *shared = (*shared).min(*witness_index);
+                released.insert(*witness_index);
+            }
+        }
+    }
+
+    let mut referenced = reserved.into_iter().collect::<BTreeSet<_>>();
+    for input in tx.inputs_mut() {
+        if let Some((owner, witness_index)) = signed_input_mut(input) {
+            if let Some(shared) = owner_witness.get(owner) {
+                *witness_index = *shared;
+            }
+            referenced.insert(*witness_index);
+        }
+    }
+
+    // Witnesses released by the deduplication and not referenced by anything else
+    let removed = released
+        .difference(&referenced)
+        .copied()
+        .collect::<BTreeSet<_>>();
+    remove_witnesses(tx, &removed, reserved)
+}
+
+/// Removes the witnesses at the `removed` indexes, shifting the witness indexes of
+/// the signed inputs pointing after them.
+///
+/// Returns the shifted `reserved` witness index.
+pub(super) fn remove_witnesses<Tx>(
+    tx: &mut Tx,
+    removed: &BTreeSet<u16>,
+    reserved: Option<u16>,
+) -> Option<u16>
+where
+    Tx: Inputs + Witnesses,
+{
+    let new_index = |index: u16| {
+        let shift = removed.range(..index).count();
+        index.saturating_sub(u16::try_from(shift).unwrap_or(u16::MAX))
+    };
+
+    if !removed.is_empty() {
+        let witnesses = core::mem::take(tx.witnesses_mut());
+        *tx.witnesses_mut() = witnesses
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                u16::try_from(*index)
+                    .map(|index| !removed.contains(&index))
+                    .unwrap_or(true)
+            })
+            .map(|(_, witness)| witness)
+            .collect();
+
+        for input in tx.inputs_mut() {
+            if let Some((_, witness_index)) = signed_input_mut(input) {
+                *witness_index = new_index(*witness_index);
+            }
+        }
+    }
+
+    reserved.map(new_index)
+}
+
+/// Signs the signed inputs owned by one of the `keys`, computing the id only once.
+fn sign_owned_inputs<Tx>(
+    tx: &mut Tx,
+    keys: &BTreeMap<Address, &SecretKey>,
+    chain_id: &ChainId,
+) where
+    Tx: Inputs + Witnesses + UniqueIdentifier + Cacheable,
+{
+    if tx.is_computed() {
+        // The cached id is stale after the deduplication of the witnesses. An invalid
+        // transaction keeps no metadata, and its id is computed on demand instead.
+        let _ = tx.precompute(chain_id);
+    }
+
+    let id = tx.id(chain_id);
+    let message = Message::from_bytes_ref(&id);
+
+    let witnesses = tx
+        .inputs()
+        .iter()
+        .filter_map(|input| {
+            let owner = input.input_owner()?;
+            let witness_index = input.witness_index()?;
+            let secret = keys.get(owner)?;
+            Some((witness_index, *secret))
+        })
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(witness_index, secret)| (witness_index, Signature::sign(secret, message)))
+        .collect::<Vec<_>>();
+
+    for (witness_index, signature) in witnesses {
+        if let Some(witness) = tx.witnesses_mut().get_mut(usize::from(witness_index)) {
+            *witness = signature.as_ref().into();
+        }
+    }
+
+    if tx.is_computed() {
+        // The signatures change the offsets of the witnesses.
+        let _ = tx.precompute(chain_id);
+    }
+}
+
+fn verify_input_signatures<Tx>(
+    tx: &Tx,
+    chain_id: &ChainId,
+) -> Result<(), (usize, ValidityError)>
+where
+    Tx: Inputs + Witnesses + UniqueIdentifier,
+{
+    let id = tx.id(chain_id);
+
+    tx.inputs()
+        .iter()
+        .enumerate()
+        .try_for_each(|(index, input)| {
+            input
+                .check_signature(index, &id, tx.witnesses(), &mut None)
+                .map_err(|error| (index, error))
+        })
+}
@@ -36,6 +36,8 @@ pub use storage::StorageSlot;
 pub use upgrade::{
     Upgrade,
     UpgradeBody,
+    UpgradeChange,
+    UpgradeConstraints,
     UpgradeMetadata,
     UpgradePurpose,
 };
@@ -45,7 +47,10 @@ pub use upload::{
     UploadMetadata,
     UploadSubsection,
 };
-pub use utxo_id::UtxoId;
+pub use utxo_id::{
+    UtxoId,
+    UtxoIdParseError,
+};
 pub use witness::Witness;
 
 #[cfg(feature = "da-compression")]
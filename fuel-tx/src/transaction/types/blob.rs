@@ -17,6 +17,7 @@ use crate::{
     Output,
     TransactionRepr,
     ValidityError,
+    Witness,
 };
 use educe::Educe;
 use fuel_types::{
@@ -61,6 +62,34 @@ pub struct BlobBody {
     pub witness_index: u16,
 }
 
+impl BlobBody {
+    /// Computes the id of the blob from its data in the `witnesses`.
+    pub fn compute_id_from_witnesses(
+        &self,
+        witnesses: &[Witness],
+    ) -> Result<BlobId, ValidityError> {
+        let index = self.witness_index as usize;
+        let witness = witnesses
+            .get(index)
+            .ok_or(ValidityError::InputWitnessIndexBounds { index })?;
+        Ok(BlobId::compute(witness.as_ref()))
+    }
+}
+
+impl Blob {
+    /// Validates that the declared id of the blob is the hash of its data.
+    pub fn validate_id(&self) -> Result<(), ValidityError> {
+        let computed = self.body.compute_id_from_witnesses(&self.witnesses)?;
+        if computed != self.body.id {
+            return Err(ValidityError::BlobIdMismatch {
+                declared: self.body.id,
+                computed,
+            });
+        }
+        Ok(())
+    }
+}
+
 impl PrepareSign for BlobBody {
     fn prepare_sign(&mut self) {}
 }
@@ -97,16 +126,7 @@ impl UniqueFormatValidityChecks for Blob {
         &self,
         consensus_params: &ConsensusParameters,
     ) -> Result<(), ValidityError> {
-        let index = self.body.witness_index as usize;
-        let witness = self
-            .witnesses
-            .get(index)
-            .ok_or(ValidityError::InputWitnessIndexBounds { index })?;
-
-        // Verify that blob id is correct
-        if BlobId::compute(witness.as_ref()) != self.body.id {
-            return Err(ValidityError::TransactionBlobIdVerificationFailed);
-        }
+        self.validate_id()?;
 
         self.inputs
             .iter()
@@ -809,6 +809,11 @@ impl Input {
         compute_message_id(sender, recipient, nonce, amount, data)
     }
 
+    /// Returns the owner of the coins and the recipient of the messages spendable by
+    /// the `predicate`.
+    ///
+    /// The address is the hash of [`ContractId::SEED`] and the binary Merkle root of the
+    /// predicate code, computed the same way as the contract code root.
     pub fn predicate_owner<P>(predicate: P) -> Address
     where
         P: AsRef<[u8]>,
@@ -825,14 +830,96 @@ impl Input {
         (*hasher.digest()).into()
     }
 
+    /// Returns `true` if the `owner` is the [`Self::predicate_owner`] of the
+    /// `predicate`.
     pub fn is_predicate_owner_valid<P>(owner: &Address, predicate: P) -> bool
     where
         P: AsRef<[u8]>,
     {
         owner == &Self::predicate_owner(predicate)
     }
+
+    /// Checks that the owner of the coin predicate or the recipient of the message
+    /// predicate is the [`Self::predicate_owner`] of its predicate.
+    pub fn validate_predicate_owner(&self) -> Result<(), PredicateOwnerError> {
+        match self {
+            Input::CoinPredicate(CoinPredicate {
+                owner, predicate, ..
+            }) => {
+                let expected = Self::predicate_owner(&**predicate);
+                if owner != &expected {
+                    return Err(PredicateOwnerError::OwnerMismatch {
+                        expected,
+                        actual: *owner,
+                    })
+                }
+                Ok(())
+            }
+            Input::MessageCoinPredicate(MessageCoinPredicate {
+                recipient,
+                predicate,
+                ..
+            })
+            | Input::MessageDataPredicate(MessageDataPredicate {
+                recipient,
+                predicate,
+                ..
+            }) => {
+                let expected = Self::predicate_owner(&**predicate);
+                if recipient != &expected {
+                    return Err(PredicateOwnerError::RecipientMismatch {
+                        expected,
+                        actual: *recipient,
+                    })
+                }
+                Ok(())
+            }
+            _ => Err(PredicateOwnerError::NotPredicate),
+        }
+    }
+}
+
+/// The error returned by [`Input::validate_predicate_owner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PredicateOwnerError {
+    /// The input isn't a predicate input.
+    NotPredicate,
+    /// The `owner` of the coin isn't derived from its predicate.
+    OwnerMismatch {
+        /// The owner derived from the predicate.
+        expected: Address,
+        /// The owner of the coin.
+        actual: Address,
+    },
+    /// The `recipient` of the message isn't derived from its predicate.
+    RecipientMismatch {
+        /// The recipient derived from the predicate.
+        expected: Address,
+        /// The recipient of the message.
+        actual: Address,
+    },
 }
 
+impl fmt::Display for PredicateOwnerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotPredicate => write!(f, "The input isn't a predicate input"),
+            Self::OwnerMismatch { expected, actual } => write!(
+                f,
+                "The coin owner {actual} doesn't match the predicate owner {expected}"
+            ),
+            Self::RecipientMismatch { expected, actual } => write!(
+                f,
+                "The message recipient {actual} doesn't match the predicate owner \
+                 {expected}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PredicateOwnerError {}
+
 impl Serialize for Input {
     fn size_static(&self) -> usize {
         (match self {
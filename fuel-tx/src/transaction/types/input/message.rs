@@ -461,6 +461,10 @@ impl MessageDataSigned {
     }
 }
 
+/// Computes the id of the message as the hash of
+/// `sender ++ recipient ++ nonce ++ amount ++ data`, with the `amount` in big-endian.
+///
+/// The message sent by `SMO` has the same id, see [`crate::Receipt::message_id`].
 pub fn compute_message_id(
     sender: &Address,
     recipient: &Address,
@@ -199,6 +199,8 @@ impl Output {
         matches!(self, Self::ContractCreated { .. })
     }
 
+    /// The nonce of the message sent by `SMO`, i.e. the hash of the `txid` and the
+    /// index `idx` of the `MessageOut` receipt in the receipts of the transaction.
     pub fn message_nonce(txid: &Bytes32, idx: Word) -> Nonce {
         (*Hasher::default()
             .chain(txid)
@@ -207,6 +209,7 @@ impl Output {
         .into()
     }
 
+    /// The digest of the data of the message sent by `SMO`.
     pub fn message_digest(data: &[u8]) -> Bytes32 {
         Hasher::hash(data)
     }
@@ -90,6 +90,209 @@ impl UpgradeMetadata {
             }
         }
     }
+
+    /// Verifies that the upgrade only performs the changes allowed by the
+    /// `constraints`. The consensus parameters from the metadata are compared with
+    /// the [`UpgradeConstraints::current_consensus_parameters`].
+    pub fn validate_purpose(
+        &self,
+        constraints: &UpgradeConstraints,
+    ) -> Result<(), ValidityError> {
+        let new = match self {
+            Self::ConsensusParameters {
+                consensus_parameters,
+                ..
+            } => consensus_parameters.as_ref(),
+            Self::StateTransition => {
+                return constraints.check(UpgradeChange::StateTransition, true)
+            }
+        };
+        let current = &constraints.current_consensus_parameters;
+
+        constraints.check(
+            UpgradeChange::ConsensusParametersVersion,
+            core::mem::discriminant(current) != core::mem::discriminant(new),
+        )?;
+        constraints.check(
+            UpgradeChange::TxParams,
+            current.tx_params() != new.tx_params(),
+        )?;
+        constraints.check(
+            UpgradeChange::PredicateParams,
+            current.predicate_params() != new.predicate_params(),
+        )?;
+        constraints.check(
+            UpgradeChange::ScriptParams,
+            current.script_params() != new.script_params(),
+        )?;
+        constraints.check(
+            UpgradeChange::ContractParams,
+            current.contract_params() != new.contract_params(),
+        )?;
+        constraints.check(
+            UpgradeChange::FeeParams,
+            current.fee_params() != new.fee_params(),
+        )?;
+        constraints
+            .check(UpgradeChange::ChainId, current.chain_id() != new.chain_id())?;
+        constraints.check(
+            UpgradeChange::GasCosts,
+            current.gas_costs() != new.gas_costs(),
+        )?;
+        constraints.check(
+            UpgradeChange::BaseAssetId,
+            current.base_asset_id() != new.base_asset_id(),
+        )?;
+        constraints.check(
+            UpgradeChange::BlockGasLimit,
+            current.block_gas_limit() != new.block_gas_limit(),
+        )?;
+        constraints.check(
+            UpgradeChange::BlockTransactionSizeLimit,
+            current.block_transaction_size_limit() != new.block_transaction_size_limit(),
+        )?;
+        constraints.check(
+            UpgradeChange::PrivilegedAddress,
+            current.privileged_address() != new.privileged_address(),
+        )
+    }
+}
+
+/// The change performed by the [`Upgrade`] transaction.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[non_exhaustive]
+pub enum UpgradeChange {
+    /// The state transition function is replaced.
+    StateTransition,
+    /// The version of the consensus parameters is changed.
+    ConsensusParametersVersion,
+    /// The transaction parameters are changed.
+    TxParams,
+    /// The predicate parameters are changed.
+    PredicateParams,
+    /// The script parameters are changed.
+    ScriptParams,
+    /// The contract parameters are changed.
+    ContractParams,
+    /// The fee parameters are changed.
+    FeeParams,
+    /// The chain id is changed.
+    ChainId,
+    /// The gas costs are changed.
+    GasCosts,
+    /// The base asset id is changed.
+    BaseAssetId,
+    /// The block gas limit is changed.
+    BlockGasLimit,
+    /// The block transaction size limit is changed.
+    BlockTransactionSizeLimit,
+    /// The privileged address is changed.
+    PrivilegedAddress,
+}
+
+/// The policy of the changes allowed to be performed by the [`Upgrade`] transaction,
+/// verified by the [`UpgradeMetadata::validate_purpose`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpgradeConstraints {
+    /// The consensus parameters before the upgrade.
+    pub current_consensus_parameters: ConsensusParameters,
+    /// Allows replacing the state transition function.
+    pub allow_state_transition: bool,
+    /// Allows changing the version of the consensus parameters.
+    pub allow_consensus_parameters_version: bool,
+    /// Allows changing the transaction parameters.
+    pub allow_tx_params: bool,
+    /// Allows changing the predicate parameters.
+    pub allow_predicate_params: bool,
+    /// Allows changing the script parameters.
+    pub allow_script_params: bool,
+    /// Allows changing the contract parameters.
+    pub allow_contract_params: bool,
+    /// Allows changing the fee parameters.
+    pub allow_fee_params: bool,
+    /// Allows changing the chain id.
+    pub allow_chain_id: bool,
+    /// Allows changing the gas costs.
+    pub allow_gas_costs: bool,
+    /// Allows changing the base asset id.
+    pub allow_base_asset_id: bool,
+    /// Allows changing the block gas limit.
+    pub allow_block_gas_limit: bool,
+    /// Allows changing the block transaction size limit.
+    pub allow_block_transaction_size_limit: bool,
+    /// Allows changing the privileged address.
+    pub allow_privileged_address: bool,
+}
+
+impl UpgradeConstraints {
+    /// Creates the constraints that don't allow any change.
+    pub fn new(current_consensus_parameters: ConsensusParameters) -> Self {
+        Self {
+            current_consensus_parameters,
+            allow_state_transition: false,
+            allow_consensus_parameters_version: false,
+            allow_tx_params: false,
+            allow_predicate_params: false,
+            allow_script_params: false,
+            allow_contract_params: false,
+            allow_fee_params: false,
+            allow_chain_id: false,
+            allow_gas_costs: false,
+            allow_base_asset_id: false,
+            allow_block_gas_limit: false,
+            allow_block_transaction_size_limit: false,
+            allow_privileged_address: false,
+        }
+    }
+
+    /// Creates the constraints that allow changing only the gas costs.
+    pub fn gas_costs_only(current_consensus_parameters: ConsensusParameters) -> Self {
+        Self {
+            allow_gas_costs: true,
+            ..Self::new(current_consensus_parameters)
+        }
+    }
+
+    /// Returns whether the `change` is allowed.
+    pub fn allows(&self, change: UpgradeChange) -> bool {
+        match change {
+            UpgradeChange::StateTransition => self.allow_state_transition,
+            UpgradeChange::ConsensusParametersVersion => {
+                self.allow_consensus_parameters_version
+            }
+            UpgradeChange::TxParams => self.allow_tx_params,
+            UpgradeChange::PredicateParams => self.allow_predicate_params,
+            UpgradeChange::ScriptParams => self.allow_script_params,
+            UpgradeChange::ContractParams => self.allow_contract_params,
+            UpgradeChange::FeeParams => self.allow_fee_params,
+            UpgradeChange::ChainId => self.allow_chain_id,
+            UpgradeChange::GasCosts => self.allow_gas_costs,
+            UpgradeChange::BaseAssetId => self.allow_base_asset_id,
+            UpgradeChange::BlockGasLimit => self.allow_block_gas_limit,
+            UpgradeChange::BlockTransactionSizeLimit => {
+                self.allow_block_transaction_size_limit
+            }
+            UpgradeChange::PrivilegedAddress => self.allow_privileged_address,
+        }
+    }
+
+    fn check(&self, change: UpgradeChange, changed: bool) -> Result<(), ValidityError> {
+        if changed && !self.allows(change) {
+            Err(ValidityError::TransactionUpgradeDisallowedChange(change))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// The types describe the purpose of the upgrade performed by the [`Upgrade`]
@@ -66,6 +66,13 @@ impl UtxoId {
         }
     }
 
+    /// Creates the UTXO id, checking that the output index fits into `u16`.
+    pub fn try_new(tx_id: TxId, output_index: u64) -> Result<Self, UtxoIdParseError> {
+        let output_index = u16::try_from(output_index)
+            .map_err(|_| UtxoIdParseError::OutputIndexOutOfRange)?;
+        Ok(Self::new(tx_id, output_index))
+    }
+
     pub const fn tx_id(&self) -> &TxId {
         &self.tx_id
     }
@@ -118,37 +125,83 @@ impl core::fmt::Display for UtxoId {
     }
 }
 
+/// The error of parsing or creating a [`UtxoId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UtxoIdParseError {
+    /// The transaction id isn't 64 hex characters long, or the output index is
+    /// missing.
+    InvalidLength,
+    /// The string contains a non-hex character.
+    InvalidHex,
+    /// The output index doesn't fit into `u16`.
+    OutputIndexOutOfRange,
+}
+
+impl fmt::Display for UtxoIdParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(
+                f,
+                "UtxoId must be 64 hex characters of the transaction id followed by the output index"
+            ),
+            Self::InvalidHex => write!(f, "Invalid hex character in UtxoId"),
+            Self::OutputIndexOutOfRange => {
+                write!(f, "UtxoId output index doesn't fit into u16")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UtxoIdParseError {}
+
 impl str::FromStr for UtxoId {
-    type Err = &'static str;
+    type Err = UtxoIdParseError;
 
-    /// UtxoId is encoded as hex string with optional 0x prefix, where
-    /// the last two characters are the output index and the part
-    /// optionally preceeding it is the transaction id.
+    /// UtxoId is encoded as hex string with optional 0x prefix: 64 characters of the
+    /// transaction id followed by 4 characters of the output index. The output index
+    /// may also be separated by `:`, in which case its width is arbitrary.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const ERR: &str = "Invalid encoded byte in UtxoId";
+        const TX_ID_LEN: usize = TxId::LEN * 2;
+        const OUTPUT_INDEX_LEN: usize = 4;
+
         let s = s.strip_prefix("0x").unwrap_or(s);
 
-        Ok(if s.is_empty() {
-            UtxoId::new(Bytes32::default(), 0)
-        } else if s.len() <= 4 {
-            UtxoId::new(
-                TxId::default(),
-                u16::from_str_radix(s, 16).map_err(|_| ERR)?,
-            )
+        // All valid inputs are ASCII, so the indices below are char boundaries
+        if !s.is_ascii() {
+            return Err(UtxoIdParseError::InvalidHex);
+        }
+
+        let (tx_id, output_index) = match s.split_once(':') {
+            Some(parts) => parts,
+            None if s.len() == TX_ID_LEN + OUTPUT_INDEX_LEN => s.split_at(TX_ID_LEN),
+            None => return Err(UtxoIdParseError::InvalidLength),
+        };
+
+        if tx_id.len() != TX_ID_LEN || output_index.is_empty() {
+            return Err(UtxoIdParseError::InvalidLength);
+        }
+        if !tx_id
+            .bytes()
+            .chain(output_index.bytes())
+            .all(|b| b.is_ascii_hexdigit())
+        {
+            return Err(UtxoIdParseError::InvalidHex);
+        }
+
+        let tx_id = Bytes32::from_str(tx_id).map_err(|_| UtxoIdParseError::InvalidHex)?;
+        let output_index = output_index.trim_start_matches('0');
+        if output_index.len() > OUTPUT_INDEX_LEN {
+            return Err(UtxoIdParseError::OutputIndexOutOfRange);
+        }
+        let output_index = if output_index.is_empty() {
+            0
         } else {
-            #[allow(clippy::arithmetic_side_effects)] // Checked above
-            let i = s.len() - 4;
-            if !s.is_char_boundary(i) {
-                return Err(ERR)
-            }
-            let (tx_id, output_index) = s.split_at(i);
-            let tx_id = tx_id.strip_suffix(':').unwrap_or(tx_id);
-
-            UtxoId::new(
-                Bytes32::from_str(tx_id)?,
-                u16::from_str_radix(output_index, 16).map_err(|_| ERR)?,
-            )
-        })
+            u16::from_str_radix(output_index, 16)
+                .map_err(|_| UtxoIdParseError::InvalidHex)?
+        };
+
+        Ok(UtxoId::new(tx_id, output_index))
     }
 }
 
@@ -169,7 +222,7 @@ pub mod typescript {
         #[wasm_bindgen(constructor)]
         pub fn typescript_new(value: &str) -> Result<UtxoId, js_sys::Error> {
             use core::str::FromStr;
-            UtxoId::from_str(value).map_err(js_sys::Error::new)
+            UtxoId::from_str(value).map_err(|e| js_sys::Error::new(&format!("{e}")))
         }
 
         #[wasm_bindgen(js_name = toString)]
@@ -240,7 +293,7 @@ mod tests {
     }
 
     #[test]
-    fn from_str_utxo_id() -> Result<(), &'static str> {
+    fn from_str_utxo_id() -> Result<(), UtxoIdParseError> {
         let utxo_id = UtxoId::from_str(
             "0x0c0000000000000000000000000000000000000000000000000000000000000babcd",
         )?;
@@ -252,7 +305,7 @@ mod tests {
     }
 
     #[test]
-    fn from_str_utxo_id_colon_separator() -> Result<(), &'static str> {
+    fn from_str_utxo_id_colon_separator() -> Result<(), UtxoIdParseError> {
         let utxo_id = UtxoId::from_str(
             "0c0000000000000000000000000000000000000000000000000000000000000b:abcd",
         )?;
@@ -269,4 +322,84 @@ mod tests {
         UtxoId::from_str("0x00😎").expect_err("Should fail on incorrect input");
         UtxoId::from_str("0x000😎").expect_err("Should fail on incorrect input");
     }
+
+    const TX_ID: &str =
+        "0c0000000000000000000000000000000000000000000000000000000000000b";
+
+    #[test]
+    fn fmt_max_values_round_trip() {
+        let utxo_id = UtxoId::new(Bytes32::new([0xff; 32]), u16::MAX);
+
+        let lower = format!("{utxo_id}");
+        assert_eq!(lower, "f".repeat(68));
+        assert_eq!(UtxoId::from_str(&lower), Ok(utxo_id));
+        assert_eq!(UtxoId::from_str(&format!("{utxo_id:#X}")), Ok(utxo_id));
+
+        let zero = UtxoId::default();
+        assert_eq!(format!("{zero}"), "0".repeat(68));
+        assert_eq!(UtxoId::from_str(&format!("{zero:#x}")), Ok(zero));
+    }
+
+    #[test]
+    fn from_str_colon_separator_accepts_any_index_width() {
+        let expected = UtxoId::new(Bytes32::from_str(TX_ID).unwrap(), 0xab);
+
+        for index in ["ab", "00ab", "0000000000ab"] {
+            assert_eq!(UtxoId::from_str(&format!("{TX_ID}:{index}")), Ok(expected));
+        }
+        assert_eq!(
+            UtxoId::from_str(&format!("0x{TX_ID}:0")),
+            Ok(UtxoId::new(expected.tx_id, 0))
+        );
+    }
+
+    #[test]
+    fn from_str_errors() {
+        let cases = [
+            (String::new(), UtxoIdParseError::InvalidLength),
+            ("0x".to_string(), UtxoIdParseError::InvalidLength),
+            ("00ab".to_string(), UtxoIdParseError::InvalidLength),
+            (format!("{TX_ID}ab"), UtxoIdParseError::InvalidLength),
+            (format!("{TX_ID}000ab"), UtxoIdParseError::InvalidLength),
+            (format!("{TX_ID}:"), UtxoIdParseError::InvalidLength),
+            (
+                format!("{}:ab", TX_ID.get(2..).unwrap()),
+                UtxoIdParseError::InvalidLength,
+            ),
+            (format!("{TX_ID}00ag"), UtxoIdParseError::InvalidHex),
+            (format!("{TX_ID}:+ab"), UtxoIdParseError::InvalidHex),
+            (
+                format!("g{}00ab", TX_ID.get(1..).unwrap()),
+                UtxoIdParseError::InvalidHex,
+            ),
+            (format!("{TX_ID}:1ab:0"), UtxoIdParseError::InvalidHex),
+            (
+                format!("{TX_ID}:10000"),
+                UtxoIdParseError::OutputIndexOutOfRange,
+            ),
+            (
+                format!("{TX_ID}:ffffffffffffffffffff"),
+                UtxoIdParseError::OutputIndexOutOfRange,
+            ),
+            ("0x00😎".to_string(), UtxoIdParseError::InvalidHex),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(UtxoId::from_str(&input), Err(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn try_new_checks_output_index_range() {
+        let tx_id = Bytes32::from_str(TX_ID).unwrap();
+
+        assert_eq!(
+            UtxoId::try_new(tx_id, u16::MAX.into()),
+            Ok(UtxoId::new(tx_id, u16::MAX))
+        );
+        assert_eq!(
+            UtxoId::try_new(tx_id, 0x1_0000),
+            Err(UtxoIdParseError::OutputIndexOutOfRange)
+        );
+    }
 }
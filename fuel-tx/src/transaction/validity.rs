@@ -164,7 +164,11 @@ impl Input {
             | Self::MessageDataPredicate(MessageDataPredicate { predicate, .. })
                 if predicate.len() as u64 > predicate_params.max_predicate_length() =>
             {
-                Err(ValidityError::InputPredicateLength { index })
+                Err(ValidityError::InputPredicateLength {
+                    index,
+                    actual: predicate.len() as u64,
+                    limit: predicate_params.max_predicate_length(),
+                })
             }
 
             Self::CoinPredicate(CoinPredicate { predicate_data, .. })
@@ -176,7 +180,11 @@ impl Input {
             }) if predicate_data.len() as u64
                 > predicate_params.max_predicate_data_length() =>
             {
-                Err(ValidityError::InputPredicateDataLength { index })
+                Err(ValidityError::InputPredicateDataLength {
+                    index,
+                    actual: predicate_data.len() as u64,
+                    limit: predicate_params.max_predicate_data_length(),
+                })
             }
 
             Self::CoinSigned(CoinSigned { witness_index, .. })
@@ -321,6 +329,37 @@ where
     Ok(())
 }
 
+/// Checks that the transaction can be included in the block at the `block_height`
+/// according to its policies, i.e. `maturity <= block_height <= expiration`.
+///
+/// The transaction without the maturity policy is mature at any height, and the
+/// transaction without the expiration policy never expires.
+pub(crate) fn check_block_height_policies<T>(
+    tx: &T,
+    block_height: BlockHeight,
+) -> Result<(), ValidityError>
+where
+    T: Maturity + Expiration + ?Sized,
+{
+    let maturity = tx.maturity();
+    if block_height < maturity {
+        return Err(ValidityError::TransactionMaturity {
+            policy: maturity,
+            block_height,
+        });
+    }
+
+    let expiration = tx.expiration();
+    if block_height > expiration {
+        return Err(ValidityError::TransactionExpiration {
+            policy: expiration,
+            block_height,
+        });
+    }
+
+    Ok(())
+}
+
 pub(crate) fn check_common_part<T>(
     tx: &T,
     block_height: BlockHeight,
@@ -357,13 +396,12 @@ where
         Err(ValidityError::TransactionMaxFeeNotSet)?
     };
 
-    if tx.maturity() > block_height {
-        Err(ValidityError::TransactionMaturity)?;
+    if tx.policies().is_set(PolicyType::FlagDefaults) && !tx_params.allow_flag_defaults()
+    {
+        Err(ValidityError::TransactionFlagDefaultsNotAllowed)?
     }
 
-    if tx.expiration() < block_height {
-        Err(ValidityError::TransactionExpiration)?;
-    }
+    check_block_height_policies(tx, block_height)?;
 
     if tx.inputs().len() > tx_params.max_inputs() as usize {
         Err(ValidityError::TransactionInputsMax)?
@@ -1,6 +1,8 @@
 use crate::UtxoId;
 use fuel_types::{
     AssetId,
+    BlobId,
+    BlockHeight,
     ContractId,
     Nonce,
 };
@@ -28,11 +30,31 @@ pub enum ValidityError {
     InputPredicateEmpty {
         index: usize,
     },
+    #[display(
+        "Predicate of input {} is too long: {} bytes, the limit is {}",
+        index,
+        actual,
+        limit
+    )]
     InputPredicateLength {
         index: usize,
+        /// The length of the predicate bytecode.
+        actual: u64,
+        /// The maximum allowed length of the predicate bytecode.
+        limit: u64,
     },
+    #[display(
+        "Predicate data of input {} is too long: {} bytes, the limit is {}",
+        index,
+        actual,
+        limit
+    )]
     InputPredicateDataLength {
         index: usize,
+        /// The length of the predicate data.
+        actual: u64,
+        /// The maximum allowed length of the predicate data.
+        limit: u64,
     },
     InputPredicateOwner {
         index: usize,
@@ -40,6 +62,8 @@ pub enum ValidityError {
     InputInvalidSignature {
         index: usize,
     },
+    /// The `Input::Contract` at the `index` isn't referenced by exactly one
+    /// `Output::Contract`, so the VM has no output to update with its state.
     InputContractAssociatedOutputContract {
         index: usize,
     },
@@ -55,6 +79,8 @@ pub enum ValidityError {
     DuplicateInputContractId {
         contract_id: ContractId,
     },
+    /// The `Output::Contract` at the `index` references an input that is out of
+    /// bounds or isn't an `Input::Contract`.
     OutputContractInputIndex {
         index: usize,
     },
@@ -115,6 +141,9 @@ pub enum ValidityError {
     TransactionUpgradeConsensusParametersSerialization,
     /// The `Upgrade` transaction's consensus parameters deserialization failed.
     TransactionUpgradeConsensusParametersDeserialization,
+    /// The `Upgrade` transaction performs the change not allowed by the
+    /// [`UpgradeConstraints`](crate::UpgradeConstraints).
+    TransactionUpgradeDisallowedChange(crate::UpgradeChange),
     /// The verification of the bytecode root of the `Upload` transaction failed.
     TransactionUploadRootVerificationFailed,
     /// The total number of bytecode subsections in the `Upload` transaction exceeds the
@@ -127,9 +156,34 @@ pub enum ValidityError {
     TransactionWitnessLimitExceeded,
     TransactionPoliciesAreInvalid,
     TransactionNoGasPricePolicy,
-    TransactionMaturity,
-    TransactionExpiration,
+    /// The transaction isn't mature yet at the block height of the checking.
+    #[display(
+        "Transaction is mature at the block height {}, but checked at {}",
+        policy,
+        block_height
+    )]
+    TransactionMaturity {
+        /// The maturity policy of the transaction.
+        policy: BlockHeight,
+        /// The block height of the checking.
+        block_height: BlockHeight,
+    },
+    /// The transaction is expired at the block height of the checking.
+    #[display(
+        "Transaction expires after the block height {}, but checked at {}",
+        policy,
+        block_height
+    )]
+    TransactionExpiration {
+        /// The expiration policy of the transaction.
+        policy: BlockHeight,
+        /// The block height of the checking.
+        block_height: BlockHeight,
+    },
     TransactionMaxFeeNotSet,
+    /// The transaction sets the flag defaults policy, but the consensus parameters
+    /// don't allow it.
+    TransactionFlagDefaultsNotAllowed,
     TransactionInputsMax,
     TransactionOutputsMax,
     TransactionWitnessesMax,
@@ -185,6 +239,16 @@ pub enum ValidityError {
     },
     /// The `Create` transaction doesn't contain `Output::ContractCreated`.
     TransactionOutputDoesntContainContractCreated,
-    /// Blob id of the transaction differs from the data.
-    TransactionBlobIdVerificationFailed,
+    /// Blob id of the transaction differs from the hash of the blob data.
+    #[display(
+        "The blob id {} differs from the id {} computed from the blob data",
+        declared,
+        computed
+    )]
+    BlobIdMismatch {
+        /// The id declared by the transaction.
+        declared: BlobId,
+        /// The id computed from the blob data.
+        computed: BlobId,
+    },
 }
@@ -48,6 +48,18 @@ impl TxPointer {
         }
     }
 
+    /// Creates the pointer, checking that the values fit into their fields.
+    pub fn try_new(
+        block_height: u64,
+        tx_index: u64,
+    ) -> Result<Self, TxPointerParseError> {
+        let block_height = u32::try_from(block_height)
+            .map_err(|_| TxPointerParseError::BlockHeightOutOfRange)?;
+        let tx_index = u16::try_from(tx_index)
+            .map_err(|_| TxPointerParseError::TxIndexOutOfRange)?;
+        Ok(Self::new(block_height.into(), tx_index))
+    }
+
     pub const fn block_height(&self) -> BlockHeight {
         self.block_height
     }
@@ -72,33 +84,78 @@ impl fmt::Display for TxPointer {
 
 impl fmt::LowerHex for TxPointer {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
         write!(f, "{:08x}{:04x}", self.block_height, self.tx_index)
     }
 }
 
 impl fmt::UpperHex for TxPointer {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
         write!(f, "{:08X}{:04X}", self.block_height, self.tx_index)
     }
 }
 
+/// The error of parsing or creating a [`TxPointer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TxPointerParseError {
+    /// The string isn't 12 hex characters long.
+    InvalidLength,
+    /// The string contains a non-hex character.
+    InvalidHex,
+    /// The block height doesn't fit into `u32`.
+    BlockHeightOutOfRange,
+    /// The transaction index doesn't fit into `u16`.
+    TxIndexOutOfRange,
+}
+
+impl fmt::Display for TxPointerParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "TxPointer must be 12 hex characters long"),
+            Self::InvalidHex => write!(f, "Invalid hex character in TxPointer"),
+            Self::BlockHeightOutOfRange => {
+                write!(f, "TxPointer block height doesn't fit into u32")
+            }
+            Self::TxIndexOutOfRange => {
+                write!(f, "TxPointer transaction index doesn't fit into u16")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TxPointerParseError {}
+
 impl str::FromStr for TxPointer {
-    type Err = &'static str;
+    type Err = TxPointerParseError;
 
-    /// TxPointer is encoded as 12 hex characters:
+    /// TxPointer is encoded as 12 hex characters with optional 0x prefix:
     /// - 8 characters for block height
     /// - 4 characters for tx index
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const ERR: &str = "Invalid encoded byte in TxPointer";
+        let s = s.strip_prefix("0x").unwrap_or(s);
 
-        if s.len() != 12 || !s.is_char_boundary(8) {
-            return Err(ERR)
+        if !s.is_ascii() {
+            return Err(TxPointerParseError::InvalidHex);
+        }
+        if s.len() != 12 {
+            return Err(TxPointerParseError::InvalidLength);
+        }
+        if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(TxPointerParseError::InvalidHex);
         }
 
         let (block_height, tx_index) = s.split_at(8);
 
-        let block_height = u32::from_str_radix(block_height, 16).map_err(|_| ERR)?;
-        let tx_index = u16::from_str_radix(tx_index, 16).map_err(|_| ERR)?;
+        let block_height = u32::from_str_radix(block_height, 16)
+            .map_err(|_| TxPointerParseError::InvalidHex)?;
+        let tx_index = u16::from_str_radix(tx_index, 16)
+            .map_err(|_| TxPointerParseError::InvalidHex)?;
 
         Ok(Self::new(block_height.into(), tx_index))
     }
@@ -121,7 +178,7 @@ pub mod typescript {
         #[wasm_bindgen(constructor)]
         pub fn typescript_new(value: &str) -> Result<TxPointer, js_sys::Error> {
             use core::str::FromStr;
-            TxPointer::from_str(value).map_err(js_sys::Error::new)
+            TxPointer::from_str(value).map_err(|e| js_sys::Error::new(&format!("{e}")))
         }
 
         #[wasm_bindgen(js_name = toString)]
@@ -178,3 +235,56 @@ fn decode_bug() {
     use core::str::FromStr;
     TxPointer::from_str("00000😎000").expect_err("Should fail on incorrect input");
 }
+
+#[test]
+fn fmt_max_values_round_trip() {
+    use core::str::FromStr;
+
+    let tx_pointer = TxPointer::new(u32::MAX.into(), u16::MAX);
+
+    assert_eq!(format!("{tx_pointer}"), "ffffffffffff");
+    assert_eq!(format!("{tx_pointer:#x}"), "0xffffffffffff");
+    assert_eq!(format!("{tx_pointer:#X}"), "0xFFFFFFFFFFFF");
+    assert_eq!(TxPointer::from_str("ffffffffffff"), Ok(tx_pointer));
+    assert_eq!(TxPointer::from_str("0xFFFFFFFFFFFF"), Ok(tx_pointer));
+
+    let zero = TxPointer::default();
+    assert_eq!(format!("{zero}"), "000000000000");
+    assert_eq!(TxPointer::from_str(&format!("{zero:#x}")), Ok(zero));
+}
+
+#[test]
+fn from_str_errors() {
+    use core::str::FromStr;
+
+    let cases = [
+        ("", TxPointerParseError::InvalidLength),
+        ("0x", TxPointerParseError::InvalidLength),
+        ("00000000000", TxPointerParseError::InvalidLength),
+        ("0000000000000", TxPointerParseError::InvalidLength),
+        ("0x0x0000000000", TxPointerParseError::InvalidHex),
+        ("00000000000g", TxPointerParseError::InvalidHex),
+        ("+00000000000", TxPointerParseError::InvalidHex),
+        ("00000😎000", TxPointerParseError::InvalidHex),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(TxPointer::from_str(input), Err(expected), "{input}");
+    }
+}
+
+#[test]
+fn try_new_checks_ranges() {
+    assert_eq!(
+        TxPointer::try_new(u32::MAX.into(), u16::MAX.into()),
+        Ok(TxPointer::new(u32::MAX.into(), u16::MAX))
+    );
+    assert_eq!(
+        TxPointer::try_new(0x1_0000_0000, 0),
+        Err(TxPointerParseError::BlockHeightOutOfRange)
+    );
+    assert_eq!(
+        TxPointer::try_new(0, 0x1_0000),
+        Err(TxPointerParseError::TxIndexOutOfRange)
+    );
+}
@@ -354,6 +354,8 @@ key!(Bytes32, 32);
 key!(Nonce, 32);
 key!(MessageId, 32);
 key!(Salt, 32);
+key!(U128, 16);
+key!(U256, 32);
 
 key_with_big_array!(Bytes64, 64);
 
@@ -27,6 +27,10 @@ pub enum Error {
     InvalidPrefix,
     /// Allocation too large to be correct.
     AllocationLimit,
+    /// The padding bytes are not zero.
+    NonZeroPadding,
+    /// The buffer has bytes left after decoding.
+    TrailingBytes,
     /// Unknown error.
     Unknown(&'static str),
 }
@@ -40,6 +44,8 @@ impl Error {
                 "prefix set with #[canonical(prefix = ...)] was invalid"
             }
             Error::AllocationLimit => "allocation too large",
+            Error::NonZeroPadding => "padding bytes are not zero",
+            Error::TrailingBytes => "buffer has bytes left after decoding",
             Error::Unknown(str) => str,
         }
     }
@@ -140,6 +146,16 @@ pub trait Input {
 
     /// Skips next `n` bytes.
     fn skip(&mut self, n: usize) -> Result<(), Error>;
+
+    /// Skips next `n` bytes of padding, failing if any of them is not zero.
+    fn skip_padding(&mut self, n: usize) -> Result<(), Error> {
+        for _ in 0..n {
+            if self.read_byte()? != 0 {
+                return Err(Error::NonZeroPadding)
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Allows deserialize the type from the `Input`.
@@ -173,9 +189,14 @@ pub trait Deserialize: Sized {
         Ok(())
     }
 
-    /// Helper method for deserializing `Self` from bytes.
+    /// Helper method for deserializing `Self` from bytes. All bytes must be consumed,
+    /// use [`Deserialize::decode`] to decode `Self` from the start of the `buffer`.
     fn from_bytes(mut buffer: &[u8]) -> Result<Self, Error> {
-        Self::decode(&mut buffer)
+        let value = Self::decode(&mut buffer)?;
+        if !buffer.is_empty() {
+            return Err(Error::TrailingBytes)
+        }
+        Ok(value)
     }
 }
 
@@ -235,7 +256,7 @@ macro_rules! impl_for_primitives {
 
             fn decode_static<I: Input + ?Sized>(buffer: &mut I) -> Result<Self, Error> {
                 let mut asset = [0u8; ::core::mem::size_of::<$t>()];
-                buffer.skip(alignment_bytes(asset.len()))?; // Skip zero-padding
+                buffer.skip_padding(alignment_bytes(asset.len()))?;
                 buffer.read(asset.as_mut())?;
                 Ok(<$t>::from_be_bytes(asset))
             }
@@ -355,7 +376,7 @@ impl<T: Deserialize> Deserialize for Vec<T> {
         }
 
         if T::UNALIGNED_BYTES {
-            buffer.skip(alignment_bytes(self.capacity()))?;
+            buffer.skip_padding(alignment_bytes(self.capacity()))?;
         }
 
         Ok(())
@@ -423,7 +444,7 @@ impl<const N: usize, T: Deserialize> Deserialize for [T; N] {
         if T::UNALIGNED_BYTES {
             let mut bytes: [u8; N] = [0; N];
             buffer.read(bytes.as_mut())?;
-            buffer.skip(alignment_bytes(N))?;
+            buffer.skip_padding(alignment_bytes(N))?;
             let ref_typed: &[T; N] = unsafe { core::mem::transmute(&bytes) };
             let typed: [T; N] = unsafe { core::ptr::read(ref_typed) };
             Ok(typed)
@@ -690,4 +711,27 @@ mod tests {
             [0u8, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0xff, 0xff]
         );
     }
+
+    #[test]
+    fn from_bytes_rejects_non_zero_padding() {
+        let mut bytes = 0x1234u32.to_bytes();
+        bytes[0] = 1;
+        assert_eq!(u32::from_bytes(&bytes), Err(Error::NonZeroPadding));
+
+        let mut bytes = vec![1u8, 2, 3].to_bytes();
+        *bytes.last_mut().unwrap() = 1;
+        assert_eq!(Vec::<u8>::from_bytes(&bytes), Err(Error::NonZeroPadding));
+
+        let mut bytes = [1u8, 2, 3].to_bytes();
+        bytes[3] = 1;
+        assert_eq!(<[u8; 3]>::from_bytes(&bytes), Err(Error::NonZeroPadding));
+    }
+
+    #[test]
+    fn from_bytes_rejects_trailing_bytes() {
+        let mut bytes = 7u64.to_bytes();
+        bytes.push(0);
+        assert_eq!(u64::from_bytes(&bytes), Err(Error::TrailingBytes));
+        assert_eq!(u64::decode(&mut &bytes[..]), Ok(7));
+    }
 }
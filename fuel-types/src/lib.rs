@@ -26,6 +26,7 @@ mod array_types;
 #[cfg(feature = "alloc")]
 mod fmt;
 mod numeric_types;
+mod wideint;
 
 pub use array_types::*;
 #[cfg(feature = "alloc")]
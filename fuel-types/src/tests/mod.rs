@@ -2,3 +2,4 @@
 use criterion as _;
 
 mod types;
+mod wideint;
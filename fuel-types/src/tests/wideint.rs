@@ -0,0 +1,141 @@
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
+    U128,
+    U256,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// Random value with a random number of leading zero bytes, so that both the
+/// overflowing and the non-overflowing cases are exercised.
+fn random<const S: usize>(rng: &mut StdRng) -> [u8; S] {
+    let mut bytes: [u8; S] = core::array::from_fn(|_| rng.gen());
+    let leading_zeros = rng.gen_range(0..=S);
+    bytes[..leading_zeros].fill(0);
+    bytes
+}
+
+fn edge_cases_u256() -> [U256; 5] {
+    [
+        U256::zeroed(),
+        U256::from(1u64),
+        U256::from(u64::MAX),
+        U256::from(u128::MAX),
+        U256::MAX,
+    ]
+}
+
+fn reference(value: U256) -> ethnum::U256 {
+    ethnum::U256::from_be_bytes(value.into())
+}
+
+fn from_reference(value: ethnum::U256) -> U256 {
+    value.to_be_bytes().into()
+}
+
+#[test]
+fn u128_conversions_use_big_endian_bytes() {
+    let value = 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128;
+
+    let wide = U128::from(value);
+
+    assert_eq!(<[u8; 16]>::from(wide), value.to_be_bytes());
+    assert_eq!(u128::from(wide), value);
+    assert_eq!(U128::from(value.to_be_bytes()), wide);
+}
+
+#[test]
+fn u256_conversions_use_big_endian_bytes() {
+    let value = 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128;
+
+    let wide = U256::from(value);
+
+    let mut expected = [0u8; 32];
+    expected[16..].copy_from_slice(&value.to_be_bytes());
+    assert_eq!(<[u8; 32]>::from(wide), expected);
+    assert_eq!(wide, U256::from(U128::from(value)));
+    assert_eq!(reference(wide), ethnum::U256::from(value));
+}
+
+#[test]
+fn wideint_canonical_serialization_roundtrip() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for _ in 0..100 {
+        let a = U128::from(random::<16>(rng));
+        let b = U256::from(random::<32>(rng));
+
+        assert_eq!(a.to_bytes(), a.as_ref());
+        assert_eq!(b.to_bytes(), b.as_ref());
+        assert_eq!(U128::from_bytes(&a.to_bytes()).unwrap(), a);
+        assert_eq!(U256::from_bytes(&b.to_bytes()).unwrap(), b);
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn wideint_serde_uses_hex_strings() {
+    let value = U256::from(0xabu64);
+
+    let json = serde_json::to_string(&value).expect("Failed to serialize U256");
+
+    assert_eq!(json, format!("\"{}ab\"", "0".repeat(62)));
+    let decoded: U256 = serde_json::from_str(&json).expect("Failed to deserialize U256");
+    assert_eq!(decoded, value);
+
+    let binary = bincode::serialize(&value).expect("Failed to serialize U256");
+    let decoded: U256 =
+        bincode::deserialize(&binary).expect("Failed to deserialize U256");
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn u128_arithmetic_matches_reference() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for _ in 0..1000 {
+        let (a, b) = (
+            u128::from_be_bytes(random(rng)),
+            u128::from_be_bytes(random(rng)),
+        );
+        let (wa, wb) = (U128::from(a), U128::from(b));
+
+        assert_eq!(wa.checked_add(wb).map(u128::from), a.checked_add(b));
+        assert_eq!(wa.checked_sub(wb).map(u128::from), a.checked_sub(b));
+        assert_eq!(wa.checked_mul(wb).map(u128::from), a.checked_mul(b));
+        assert_eq!(wa.checked_div(wb).map(u128::from), a.checked_div(b));
+        assert_eq!(wa.checked_rem(wb).map(u128::from), a.checked_rem(b));
+    }
+}
+
+#[test]
+fn u256_arithmetic_matches_reference() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let random_pairs = (0..1000).map(|_| {
+        (
+            U256::from(random::<32>(&mut *rng)),
+            U256::from(random::<32>(&mut *rng)),
+        )
+    });
+    let edge_pairs = edge_cases_u256()
+        .into_iter()
+        .flat_map(|a| edge_cases_u256().into_iter().map(move |b| (a, b)));
+    let pairs: Vec<_> = random_pairs.chain(edge_pairs).collect();
+
+    for (a, b) in pairs {
+        let (ra, rb) = (reference(a), reference(b));
+
+        assert_eq!(a.checked_add(b), ra.checked_add(rb).map(from_reference));
+        assert_eq!(a.checked_sub(b), ra.checked_sub(rb).map(from_reference));
+        assert_eq!(a.checked_mul(b), ra.checked_mul(rb).map(from_reference));
+        assert_eq!(a.checked_div(b), ra.checked_div(rb).map(from_reference));
+        assert_eq!(a.checked_rem(b), ra.checked_rem(rb).map(from_reference));
+    }
+}
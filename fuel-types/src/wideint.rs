@@ -0,0 +1,237 @@
+//! Arithmetic over the wide integer types.
+//!
+//! [`U128`] and [`U256`] keep their value as big-endian bytes, which is the byte
+//! order the wide integer instructions of the VM use in memory.
+
+use crate::{
+    U128,
+    U256,
+};
+
+/// Little-endian 64-bit limbs of a [`U256`].
+type Limbs = [u64; 4];
+
+impl U128 {
+    /// The largest value that can be represented by this type.
+    pub const MAX: U128 = U128::new([0xff; 16]);
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        u128::from(self).checked_add(rhs.into()).map(Into::into)
+    }
+
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        u128::from(self).checked_sub(rhs.into()).map(Into::into)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        u128::from(self).checked_mul(rhs.into()).map(Into::into)
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        u128::from(self).checked_div(rhs.into()).map(Into::into)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        u128::from(self).checked_rem(rhs.into()).map(Into::into)
+    }
+}
+
+impl From<u128> for U128 {
+    fn from(value: u128) -> Self {
+        Self::new(value.to_be_bytes())
+    }
+}
+
+impl From<u64> for U128 {
+    fn from(value: u64) -> Self {
+        u128::from(value).into()
+    }
+}
+
+impl From<U128> for u128 {
+    fn from(value: U128) -> Self {
+        u128::from_be_bytes(value.into())
+    }
+}
+
+impl U256 {
+    /// The largest value that can be represented by this type.
+    pub const MAX: U256 = U256::new([0xff; 32]);
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match overflowing_add(self.limbs(), rhs.limbs()) {
+            (sum, false) => Some(Self::from_limbs(sum)),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked subtraction. Returns `None` on underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match overflowing_sub(self.limbs(), rhs.limbs()) {
+            (difference, false) => Some(Self::from_limbs(difference)),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs) = (self.limbs(), rhs.limbs());
+        let mut product = [0u64; 8];
+
+        for (i, a) in lhs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (b, p) in rhs.iter().zip(product.iter_mut().skip(i)) {
+                // `a * b + p + carry` is at most `2^128 - 1`, so it can't overflow.
+                let t = u128::from(*a)
+                    .wrapping_mul(u128::from(*b))
+                    .wrapping_add(u128::from(*p))
+                    .wrapping_add(u128::from(carry));
+                (carry, *p) = split(t);
+            }
+            if carry != 0 {
+                // The carry lands in the limb `i + 4`, which is always an overflow.
+                return None
+            }
+        }
+
+        let (low, high) = product.split_at(4);
+        if high.iter().any(|limb| *limb != 0) {
+            return None
+        }
+        let mut limbs = Limbs::default();
+        limbs.copy_from_slice(low);
+
+        Some(Self::from_limbs(limbs))
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+
+    /// Binary long division.
+    fn div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs == Self::zeroed() {
+            return None
+        }
+
+        let (dividend, divisor) = (self.limbs(), rhs.limbs());
+        let mut quotient = Limbs::default();
+        let mut remainder = Limbs::default();
+
+        for (dividend, quotient) in dividend.iter().zip(quotient.iter_mut()).rev() {
+            for bit in (0..64).rev() {
+                let (shifted, carry) = shl1(remainder, (dividend >> bit) & 1);
+                remainder = shifted;
+
+                // The remainder was below the divisor before the shift, so subtracting
+                // the divisor once is enough to bring it back below.
+                if carry || !less_than(remainder, divisor) {
+                    remainder = overflowing_sub(remainder, divisor).0;
+                    *quotient |= 1 << bit;
+                }
+            }
+        }
+
+        Some((Self::from_limbs(quotient), Self::from_limbs(remainder)))
+    }
+
+    fn limbs(self) -> Limbs {
+        let mut limbs = Limbs::default();
+        for (limb, chunk) in limbs.iter_mut().zip(self.rchunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *limb = u64::from_be_bytes(bytes);
+        }
+        limbs
+    }
+
+    fn from_limbs(limbs: Limbs) -> Self {
+        let mut value = Self::zeroed();
+        for (limb, chunk) in limbs.iter().zip(value.rchunks_exact_mut(8)) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        value
+    }
+}
+
+impl From<U128> for U256 {
+    fn from(value: U128) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(value.as_ref());
+        Self::new(bytes)
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        U128::from(value).into()
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        U128::from(value).into()
+    }
+}
+
+/// Splits the value into the high and the low 64 bits.
+fn split(value: u128) -> (u64, u64) {
+    let bytes = value.to_be_bytes();
+    let mut high = [0u8; 8];
+    let mut low = [0u8; 8];
+    high.copy_from_slice(&bytes[..8]);
+    low.copy_from_slice(&bytes[8..]);
+    (u64::from_be_bytes(high), u64::from_be_bytes(low))
+}
+
+fn overflowing_add(lhs: Limbs, rhs: Limbs) -> (Limbs, bool) {
+    let mut sum = Limbs::default();
+    let mut carry = false;
+    for ((s, a), b) in sum.iter_mut().zip(lhs).zip(rhs) {
+        let (t, c1) = a.overflowing_add(b);
+        let (t, c2) = t.overflowing_add(u64::from(carry));
+        *s = t;
+        carry = c1 || c2;
+    }
+    (sum, carry)
+}
+
+fn overflowing_sub(lhs: Limbs, rhs: Limbs) -> (Limbs, bool) {
+    let mut difference = Limbs::default();
+    let mut borrow = false;
+    for ((d, a), b) in difference.iter_mut().zip(lhs).zip(rhs) {
+        let (t, b1) = a.overflowing_sub(b);
+        let (t, b2) = t.overflowing_sub(u64::from(borrow));
+        *d = t;
+        borrow = b1 || b2;
+    }
+    (difference, borrow)
+}
+
+/// Shifts the value left by one bit, filling the lowest bit with `bit`.
+/// Returns the shifted value and whether the highest bit was shifted out.
+fn shl1(limbs: Limbs, bit: u64) -> (Limbs, bool) {
+    let mut shifted = Limbs::default();
+    let mut carry = bit;
+    for (s, limb) in shifted.iter_mut().zip(limbs) {
+        *s = (limb << 1) | carry;
+        carry = limb >> 63;
+    }
+    (shifted, carry != 0)
+}
+
+fn less_than(lhs: Limbs, rhs: Limbs) -> bool {
+    lhs.iter().rev().lt(rhs.iter().rev())
+}
@@ -0,0 +1,134 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+    Finalizable,
+    GasCosts,
+    Input,
+    Output,
+    Salt,
+    Script,
+    TransactionBuilder,
+    TxParameters,
+};
+use fuel_vm::{
+    checked_transaction::{
+        Checked,
+        IntoChecked,
+    },
+    consts::WORD_SIZE,
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::{
+        MemoryStorage,
+        Transactor,
+    },
+    script_with_data_offset,
+};
+
+const CALLS: u32 = 1000;
+/// The size of the called contract in instructions.
+const CONTRACT_LEN: usize = 4096;
+
+fn consensus_params() -> ConsensusParameters {
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_gas_costs(GasCosts::free());
+    consensus_params
+}
+
+/// Deploys the contract and returns the script calling it [`CALLS`] times.
+fn setup(
+    transactor: &mut Transactor<MemoryInstance, MemoryStorage, Script>,
+) -> Checked<Script> {
+    let consensus_params = consensus_params();
+    let program = core::iter::repeat(op::noop())
+        .take(CONTRACT_LEN - 1)
+        .chain(core::iter::once(op::ret(RegId::ONE)))
+        .collect::<Vec<u8>>();
+    let salt = Salt::zeroed();
+    let contract = Contract::from(program.as_slice());
+    let contract_id =
+        contract.id(&salt, &contract.root(), &Contract::default_state_root());
+
+    let create = TransactionBuilder::create(program.into(), salt, vec![])
+        .max_fee_limit(0)
+        .add_fee_input()
+        .add_contract_created()
+        .finalize()
+        .into_checked_basic(Default::default(), &consensus_params)
+        .expect("Valid create transaction");
+    transactor.deploy(create).expect("Failed to deploy");
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::movi(0x11, CALLS),
+            // loop_start:
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::subi(0x11, 0x11, 1),
+            op::jnzb(0x11, RegId::ZERO, 1), // if counter != 0 then goto loop_start
+            op::ret(RegId::ONE),
+        ],
+        TxParameters::DEFAULT.tx_offset()
+    );
+    let mut script_data = contract_id.to_vec();
+    script_data.extend([0u8; WORD_SIZE * 2]);
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .max_fee_limit(0)
+        .script_gas_limit(consensus_params.tx_params().max_gas_per_tx() / 2)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize()
+        .into_checked_basic(Default::default(), &consensus_params)
+        .expect("Valid script transaction")
+}
+
+fn code_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("code_cache");
+
+    for (name, capacity_bytes) in [("uncached", 0), ("cached", 1024 * 1024)] {
+        let mut transactor = Transactor::<_, _, Script>::new(
+            MemoryInstance::new(),
+            MemoryStorage::default(),
+            InterpreterParams::new(0, consensus_params()),
+        );
+        transactor.with_code_cache(capacity_bytes);
+        let script = setup(&mut transactor);
+
+        group.bench_function(format!("{CALLS} calls of one contract, {name}"), |b| {
+            b.iter(|| {
+                let receipts = transactor
+                    .transact(script.clone())
+                    .receipts()
+                    .expect("Failed to execute the script")
+                    .len();
+                black_box(receipts);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, code_cache);
+criterion_main!(benches);
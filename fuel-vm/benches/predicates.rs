@@ -0,0 +1,106 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_asm::{
+    op,
+    GMArgs,
+    GTFArgs,
+    RegId,
+};
+use fuel_crypto::{
+    Message,
+    PublicKey,
+    SecretKey,
+    Signature,
+};
+use fuel_tx::{
+    AssetId,
+    ConsensusParameters,
+    Finalizable,
+    Input,
+    TransactionBuilder,
+};
+use fuel_vm::{
+    checked_transaction::{
+        CheckPredicateParams,
+        CheckPredicates,
+        EstimatePredicates,
+        IntoChecked,
+    },
+    interpreter::MemoryInstance,
+    storage::predicate::EmptyStorage,
+};
+
+const PREDICATES: u8 = 16;
+
+/// Predicate recovering the public key from the signature of the message and comparing
+/// it with the expected one. The predicate data is `signature ++ message ++ key`.
+fn eck1_predicate() -> Vec<u8> {
+    vec![
+        op::gm_args(0x10, GMArgs::GetVerifyingPredicate),
+        op::gtf_args(0x10, 0x10, GTFArgs::InputCoinPredicateData),
+        op::movi(0x11, PublicKey::LEN as u32),
+        op::aloc(0x11),
+        op::addi(0x12, 0x10, Signature::LEN as u16),
+        op::eck1(RegId::HP, 0x10, 0x12),
+        op::addi(0x12, 0x12, Message::LEN as u16),
+        op::meq(0x13, RegId::HP, 0x12, 0x11),
+        op::ret(0x13),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn predicates(c: &mut Criterion) {
+    let predicate = eck1_predicate();
+    let owner = Input::predicate_owner(&predicate);
+    let params = CheckPredicateParams::default();
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(0);
+    for i in 1..=PREDICATES {
+        let secret = SecretKey::try_from(&[i; 32][..]).expect("Valid secret key");
+        let message = Message::new([i; 100]);
+        let signature = Signature::sign(&secret, &message);
+        let public_key = PublicKey::from(&secret);
+
+        let data = [&signature[..], &message[..], &public_key[..]].concat();
+        builder.add_input(Input::coin_predicate(
+            Default::default(),
+            owner,
+            1_000,
+            AssetId::default(),
+            Default::default(),
+            0,
+            predicate.clone(),
+            data,
+        ));
+    }
+    let mut tx = builder.finalize();
+    tx.estimate_predicates(&params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Predicates should be valid");
+    let checked = tx
+        .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+        .expect("Transaction should be valid");
+
+    let mut group = c.benchmark_group("predicates");
+
+    group.bench_function("16-predicate `eck1` multisig", |b| {
+        b.iter(|| {
+            black_box(checked.clone().check_predicates(
+                &params,
+                MemoryInstance::new(),
+                &EmptyStorage,
+            ))
+            .expect("Predicates should be valid")
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, predicates);
+criterion_main!(benches);
@@ -0,0 +1,93 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BatchSize,
+    Criterion,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Finalizable,
+    GasCosts,
+    Script,
+    TransactionBuilder,
+};
+use fuel_vm::{
+    checked_transaction::{
+        Checked,
+        IntoChecked,
+    },
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+    },
+    prelude::{
+        MemoryStorage,
+        Transactor,
+    },
+};
+
+/// The number of the `Log` receipts produced by the transaction.
+const LOGS: u32 = 10_000;
+
+fn logs_tx(params: &ConsensusParameters) -> Checked<Script> {
+    let script = vec![
+        op::movi(0x10, LOGS),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::subi(0x10, 0x10, 1),
+        op::jnzb(0x10, RegId::ZERO, 1),
+        op::ret(RegId::ONE),
+    ];
+    TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .max_fee_limit(0)
+        .script_gas_limit(10_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked_basic(Default::default(), params)
+        .expect("Failed to check the transaction")
+}
+
+fn receipts(c: &mut Criterion) {
+    let mut group = c.benchmark_group("receipts");
+
+    let consensus_params = ConsensusParameters::standard();
+    let tx = logs_tx(&consensus_params);
+    // The logs, the return and the script result
+    let receipts = LOGS as usize + 2;
+
+    for (name, receipts_capacity_hint) in
+        [("without hint", 0), ("with capacity hint", receipts)]
+    {
+        let interpreter_params = InterpreterParams {
+            gas_costs: GasCosts::free(),
+            receipts_capacity_hint,
+            ..InterpreterParams::new(0, &consensus_params)
+        };
+
+        group.bench_function(format!("{LOGS} logs, {name}"), |b| {
+            b.iter_batched(
+                || {
+                    Transactor::<_, _, Script>::new(
+                        MemoryInstance::new(),
+                        MemoryStorage::default(),
+                        interpreter_params.clone(),
+                    )
+                },
+                |mut transactor| {
+                    transactor.transact(tx.clone());
+                    black_box(transactor.receipts().map(<[_]>::len))
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, receipts);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BatchSize,
+    Criterion,
+};
+use fuel_crypto::Hasher;
+use fuel_tx::{
+    Contract,
+    StorageSlot,
+};
+use fuel_types::ContractId;
+use fuel_vm::{
+    prelude::MemoryStorage,
+    storage::InterpreterStorage,
+};
+
+/// The number of the initial storage slots of the deployed contract.
+const SLOTS: u32 = 1000;
+/// The number of slots of the contract that is already present in the storage.
+const EXISTING_SLOTS: u32 = 10_000;
+
+fn slots(seed: u8, count: u32) -> Vec<StorageSlot> {
+    (0..count)
+        .map(|i| {
+            let key = Hasher::default().chain([seed]).chain(i.to_be_bytes()).finalize();
+            let value = Hasher::hash(key);
+            StorageSlot::new(key, value)
+        })
+        .collect()
+}
+
+/// Returns the storage with one contract that has [`EXISTING_SLOTS`] slots.
+fn setup() -> MemoryStorage {
+    let mut storage = MemoryStorage::default();
+    let contract = Contract::from(vec![0u8; 32]);
+    storage
+        .deploy_contract_with_id(
+            &slots(0, EXISTING_SLOTS),
+            &contract,
+            &ContractId::from([0u8; 32]),
+        )
+        .expect("Failed to deploy");
+    storage
+}
+
+fn storage_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("storage_batch");
+
+    let storage = setup();
+    let slots = slots(1, SLOTS);
+    let contract = Contract::from(vec![1u8; 32]);
+    let id = ContractId::from([1u8; 32]);
+
+    group.bench_function(format!("deploy {SLOTS} slots, per key"), |b| {
+        b.iter_batched(
+            || storage.clone(),
+            |mut storage| {
+                storage
+                    .storage_contract_insert(&id, &contract)
+                    .expect("Failed to insert the contract");
+                for slot in &slots {
+                    storage
+                        .contract_state_insert(&id, slot.key(), slot.value().as_ref())
+                        .expect("Failed to insert the slot");
+                }
+                black_box(storage)
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function(format!("deploy {SLOTS} slots, batched"), |b| {
+        b.iter_batched(
+            || storage.clone(),
+            |mut storage| {
+                storage
+                    .deploy_contract_with_id(&slots, &contract, &id)
+                    .expect("Failed to deploy");
+                black_box(storage)
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, storage_batch);
+criterion_main!(benches);
@@ -88,6 +88,13 @@ fn main() {
                                 .unwrap_or_else(|| "???".to_owned()),
                         );
                     }
+                    DebugEval::Watchpoint(wp) => {
+                        println!(
+                            "at {:>4} wrote to {:?}",
+                            wp.location().pc(),
+                            wp.watch()
+                        );
+                    }
                     DebugEval::Continue => {}
                 }
                 t = vm.resume().expect("panicked");
@@ -6,10 +6,12 @@ use alloc::{
     borrow::ToOwned,
     vec::Vec,
 };
+use core::fmt;
 
 use crate::{
     call::CallFrame,
     consts::*,
+    context::ContextKind,
     interpreter::{
         InitialBalances,
         Interpreter,
@@ -21,7 +23,14 @@ use crate::interpreter::{
     Memory,
     MemoryInstance,
 };
-use fuel_tx::ScriptExecutionResult;
+use fuel_asm::{
+    PanicInstruction,
+    RegId,
+};
+use fuel_tx::{
+    Receipt,
+    ScriptExecutionResult,
+};
 use fuel_types::{
     ContractId,
     Word,
@@ -29,20 +38,31 @@ use fuel_types::{
 
 #[derive(Educe)]
 #[educe(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Runtime description derived from a VM error.
 pub struct Backtrace {
     call_stack: Vec<CallFrame>,
     contract: ContractId,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; VM_REGISTER_COUNT]>")
+    )]
     registers: [Word; VM_REGISTER_COUNT],
     memory: MemoryInstance,
+    panic_instruction: Option<PanicInstruction>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    panic_instruction_index: Option<Word>,
     result: ScriptExecutionResult,
     initial_balances: InitialBalances,
+    #[cfg_attr(feature = "serde", serde(default))]
+    context: ContextKind,
 }
 
 impl Backtrace {
     /// Create a backtrace from a vm instance and instruction result.
     ///
-    /// This isn't copy-free and shouldn't be provided by default.
+    /// This isn't copy-free and shouldn't be provided by default. See
+    /// [`CompactBacktrace`] for a backtrace without the full memory.
     pub fn from_vm_error<M, S, Tx, Ecal>(
         vm: &Interpreter<M, S, Tx, Ecal>,
         result: ScriptExecutionResult,
@@ -53,7 +73,10 @@ impl Backtrace {
         let call_stack = vm.call_stack().to_owned();
         let contract = vm.internal_contract().unwrap_or_default();
         let memory = vm.memory().clone();
+        let panic_instruction = panic_instruction(vm.receipts());
+        let panic_instruction_index = panic_instruction_index(vm.receipts());
         let initial_balances = vm.initial_balances().clone();
+        let context = vm.context().kind();
         let mut registers = [0; VM_REGISTER_COUNT];
 
         registers.copy_from_slice(vm.registers());
@@ -63,8 +86,11 @@ impl Backtrace {
             contract,
             registers,
             memory,
+            panic_instruction,
+            panic_instruction_index,
             result,
             initial_balances,
+            context,
         }
     }
 
@@ -88,6 +114,17 @@ impl Backtrace {
         &self.memory
     }
 
+    /// The instruction that caused the panic, if the VM panicked.
+    pub const fn panic_instruction(&self) -> Option<&PanicInstruction> {
+        self.panic_instruction.as_ref()
+    }
+
+    /// The index of the instruction that caused the panic in the code of the
+    /// panicked frame, see [`Receipt::instruction_index`].
+    pub const fn panic_instruction_index(&self) -> Option<Word> {
+        self.panic_instruction_index
+    }
+
     /// [`ScriptExecutionResult`] of the error that caused this backtrace.
     pub const fn result(&self) -> &ScriptExecutionResult {
         &self.result
@@ -98,6 +135,27 @@ impl Backtrace {
         &self.initial_balances
     }
 
+    /// The kind of the context when the error occurred, e.g. a predicate
+    /// verification for the instructions not allowed in the predicates.
+    pub const fn context(&self) -> ContextKind {
+        self.context
+    }
+
+    /// Compact form of the backtrace, keeping only the windows of
+    /// `memory_window` bytes of the memory around `$pc`, `$sp`, `$fp` and `$hp`.
+    pub fn compact(&self, memory_window: usize) -> CompactBacktrace {
+        CompactBacktrace::new(
+            self.call_stack.clone(),
+            self.contract,
+            self.registers,
+            &self.memory,
+            self.panic_instruction,
+            self.panic_instruction_index,
+            self.result,
+            memory_window,
+        )
+    }
+
     /// Expose the internal attributes of the backtrace.
     pub fn into_inner(
         self,
@@ -116,6 +174,7 @@ impl Backtrace {
             memory,
             result,
             initial_balances,
+            ..
         } = self;
 
         (
@@ -128,3 +187,263 @@ impl Backtrace {
         )
     }
 }
+
+/// The instruction of the last panic receipt, if any. The earlier ones belong to the
+/// reverted call frames.
+fn panic_instruction(receipts: &[Receipt]) -> Option<PanicInstruction> {
+    receipts.iter().rev().find_map(Receipt::reason)
+}
+
+/// The index of the instruction of the last panic receipt, if any.
+fn panic_instruction_index(receipts: &[Receipt]) -> Option<Word> {
+    receipts
+        .iter()
+        .rev()
+        .find(|receipt| matches!(receipt, Receipt::Panic { .. }))
+        .and_then(Receipt::instruction_index)
+}
+
+/// Registers around which [`CompactBacktrace`] keeps the memory.
+const WINDOW_REGISTERS: [RegId; 4] = [RegId::PC, RegId::SP, RegId::FP, RegId::HP];
+
+/// Bytes of the memory around the value of a register.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryWindow {
+    register: RegId,
+    start: Word,
+    bytes: Vec<u8>,
+}
+
+impl MemoryWindow {
+    /// Reads the accessible memory within `memory_window` bytes centered at the value
+    /// of the `register`.
+    ///
+    /// The window is clamped to the stack if the register points into the stack, or
+    /// to the heap otherwise, so the unallocated memory in between is never read.
+    fn new(
+        memory: &MemoryInstance,
+        registers: &[Word; VM_REGISTER_COUNT],
+        register: RegId,
+        memory_window: usize,
+    ) -> Self {
+        let at = registers[usize::from(register)];
+        let (sp, hp) = (
+            registers[usize::from(RegId::SP)],
+            registers[usize::from(RegId::HP)],
+        );
+        let (low, high) = if at < hp {
+            (0, sp)
+        } else {
+            (hp, MEM_SIZE as Word)
+        };
+
+        let half = memory_window.saturating_div(2) as Word;
+        let start = at.saturating_sub(half).max(low);
+        let end = at.saturating_add(half).min(high).max(start);
+        let bytes = memory
+            .read(start, end.saturating_sub(start))
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+
+        Self {
+            register,
+            start,
+            bytes,
+        }
+    }
+
+    /// The register the window is centered at.
+    pub const fn register(&self) -> RegId {
+        self.register
+    }
+
+    /// Address of the first byte of the window.
+    pub const fn start(&self) -> Word {
+        self.start
+    }
+
+    /// Bytes of the memory in the window.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Runtime description derived from a VM error, keeping only the parts of the memory
+/// around `$pc`, `$sp`, `$fp` and `$hp`.
+///
+/// Unlike [`Backtrace`], it is cheap to build and small enough to be logged.
+pub struct CompactBacktrace {
+    call_stack: Vec<CallFrame>,
+    contract: ContractId,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; VM_REGISTER_COUNT]>")
+    )]
+    registers: [Word; VM_REGISTER_COUNT],
+    panic_instruction: Option<PanicInstruction>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    panic_instruction_index: Option<Word>,
+    result: ScriptExecutionResult,
+    memory: Vec<MemoryWindow>,
+}
+
+impl CompactBacktrace {
+    /// Create a compact backtrace from a vm instance and instruction result, keeping
+    /// the windows of `memory_window` bytes of the memory.
+    ///
+    /// Unlike [`Backtrace::from_vm_error`], it doesn't clone the whole memory.
+    pub fn from_vm_error<M, S, Tx, Ecal>(
+        vm: &Interpreter<M, S, Tx, Ecal>,
+        result: ScriptExecutionResult,
+        memory_window: usize,
+    ) -> Self
+    where
+        M: Memory,
+    {
+        let mut registers = [0; VM_REGISTER_COUNT];
+        registers.copy_from_slice(vm.registers());
+
+        Self::new(
+            vm.call_stack().to_owned(),
+            vm.internal_contract().unwrap_or_default(),
+            registers,
+            vm.memory(),
+            panic_instruction(vm.receipts()),
+            panic_instruction_index(vm.receipts()),
+            result,
+            memory_window,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        call_stack: Vec<CallFrame>,
+        contract: ContractId,
+        registers: [Word; VM_REGISTER_COUNT],
+        memory: &MemoryInstance,
+        panic_instruction: Option<PanicInstruction>,
+        panic_instruction_index: Option<Word>,
+        result: ScriptExecutionResult,
+        memory_window: usize,
+    ) -> Self {
+        let memory = WINDOW_REGISTERS
+            .iter()
+            .map(|register| {
+                MemoryWindow::new(memory, &registers, *register, memory_window)
+            })
+            .collect();
+        Self {
+            call_stack,
+            contract,
+            registers,
+            panic_instruction,
+            panic_instruction_index,
+            result,
+            memory,
+        }
+    }
+
+    /// Call stack of the VM when the error occurred.
+    pub fn call_stack(&self) -> &[CallFrame] {
+        self.call_stack.as_slice()
+    }
+
+    /// Last contract of the context when the error occurred.
+    pub const fn contract(&self) -> &ContractId {
+        &self.contract
+    }
+
+    /// Register set when the error occurred.
+    pub const fn registers(&self) -> &[Word] {
+        &self.registers
+    }
+
+    /// The instruction that caused the panic, if the VM panicked.
+    pub const fn panic_instruction(&self) -> Option<&PanicInstruction> {
+        self.panic_instruction.as_ref()
+    }
+
+    /// The index of the instruction that caused the panic in the code of the
+    /// panicked frame, see [`Receipt::instruction_index`].
+    pub const fn panic_instruction_index(&self) -> Option<Word> {
+        self.panic_instruction_index
+    }
+
+    /// [`ScriptExecutionResult`] of the error that caused this backtrace.
+    pub const fn result(&self) -> &ScriptExecutionResult {
+        &self.result
+    }
+
+    /// Windows of the memory around `$pc`, `$sp`, `$fp` and `$hp`.
+    pub fn memory(&self) -> &[MemoryWindow] {
+        &self.memory
+    }
+}
+
+impl fmt::Display for CompactBacktrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Script failed with {:?} in contract {}",
+            self.result, self.contract
+        )?;
+        if let Some(panic) = &self.panic_instruction {
+            write!(
+                f,
+                "Panic: {} caused by instruction {:#010x}",
+                panic.reason(),
+                panic.instruction()
+            )?;
+            if let Some(index) = self.panic_instruction_index {
+                write!(f, " at index {index}")?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "Registers:")?;
+        for (index, chunk) in self.registers.chunks(4).enumerate() {
+            write!(f, "  ")?;
+            for (offset, value) in chunk.iter().enumerate() {
+                let register = index.saturating_mul(4).saturating_add(offset);
+                write!(f, " ${register:<2} {value:#018x}")?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "Call stack ({} frames):", self.call_stack.len())?;
+        for (depth, frame) in self.call_stack.iter().enumerate().rev() {
+            writeln!(
+                f,
+                "  #{depth} contract {} asset {} a {:#x} b {:#x}",
+                frame.to(),
+                frame.asset_id(),
+                frame.a(),
+                frame.b()
+            )?;
+        }
+
+        for window in &self.memory {
+            writeln!(
+                f,
+                "Memory around ${} at {:#x}:",
+                window.register.to_u8(),
+                window.start
+            )?;
+            for (line, chunk) in window.bytes.chunks(32).enumerate() {
+                let address = window
+                    .start
+                    .saturating_add((line as Word).saturating_mul(32));
+                write!(f, "  {address:#010x} ")?;
+                for byte in chunk {
+                    write!(f, "{byte:02x}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,11 @@
 //! Inter-contract call supporting structures
 
+use alloc::vec::Vec;
 use fuel_asm::{
     PanicReason,
     RegId,
 };
+use fuel_tx::Receipt;
 use fuel_types::{
     bytes::padded_len_usize,
     canonical::{
@@ -36,6 +38,9 @@ pub struct Call {
 impl Call {
     /// The size of the call structures in memory representation.
     pub const LEN: usize = ContractId::LEN + 8 + 8;
+    /// The size of the serialized call structure. It is the same as the memory
+    /// representation, so `CALL` reads exactly this many bytes.
+    pub const SERIALIZED_LEN: usize = Self::LEN;
 
     /// Create a new call structure representation.
     pub const fn new(to: ContractId, a: Word, b: Word) -> Self {
@@ -64,12 +69,27 @@ impl Call {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Call frame representation in the VM stack.
 ///
+/// The frame stores a snapshot of all registers of the caller. `CALL` starts the
+/// callee with `$flag` set to the `FlagDefaults` policy of the transaction, or zero
+/// without the policy, while `$of` and `$err` are inherited as-is. On `RET`/`RETD` all
+/// registers are restored from the frame except `$cgas`, `$ggas`, `$ret`, `$retl`
+/// and `$hp`, so the caller always observes its own `$flag`, `$of` and `$err`
+/// regardless of what the callee did. A panic or revert inside of the callee
+/// terminates the whole transaction, unless the `call_frame_reverts` switch of the
+/// transaction parameters is enabled. Then the writes of the callee are undone and
+/// the frame is unwound into the caller the same way, with `$err` set.
+///
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/fuel-vm/index.md#call-frames>
 pub struct CallFrame {
     to: ContractId,
     asset_id: AssetId,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; VM_REGISTER_COUNT]>")
+    )]
     registers: [Word; VM_REGISTER_COUNT],
     code_size_padded: usize,
     a: Word,
@@ -120,11 +140,37 @@ impl CallFrame {
         Self::contract_id_offset().saturating_add(ContractId::LEN)
     }
 
-    /// Start of the registers offset from the beginning of the call frame.
+    /// Start of the saved registers region from the beginning of the call frame.
+    ///
+    /// The layout of the region is described by the [`SavedRegister`].
     pub const fn registers_offset() -> usize {
         Self::asset_id_offset().saturating_add(AssetId::LEN)
     }
 
+    /// Start of the `which` saved register from the beginning of the call frame, or
+    /// `None` if the register is outside of the saved registers region.
+    pub const fn saved_register_offset(which: SavedRegister) -> Option<usize> {
+        let index = which.index();
+        if index < VM_REGISTER_COUNT {
+            Some(Self::registers_offset().saturating_add(WORD_SIZE.saturating_mul(index)))
+        } else {
+            None
+        }
+    }
+
+    /// Reads the `which` saved register from the `frame` bytes, which start at the
+    /// beginning of the call frame, e.g. the memory at `$fp` of the callee.
+    ///
+    /// Returns `None` if the `frame` is too short to contain the register.
+    pub fn read_saved_register(
+        frame: &[u8],
+        which: impl Into<SavedRegister>,
+    ) -> Option<Word> {
+        let offset = Self::saved_register_offset(which.into())?;
+        let bytes = frame.get(offset..offset.checked_add(WORD_SIZE)?)?;
+        Some(Word::from_be_bytes(bytes.try_into().ok()?))
+    }
+
     /// Start of the code size offset from the beginning of the call frame.
     pub const fn code_size_offset() -> usize {
         Self::registers_offset().saturating_add(WORD_SIZE * VM_REGISTER_COUNT)
@@ -192,11 +238,234 @@ impl CallFrame {
     }
 }
 
-impl TryFrom<&[u8]> for Call {
-    type Error = PanicReason;
+/// The register saved by `CALL` in the call frame.
+///
+/// The saved registers region starts at the [`CallFrame::registers_offset`] and holds
+/// all [`VM_REGISTER_COUNT`] registers of the caller as big-endian words, ordered by
+/// the register id: the system registers in the order of the variants below are
+/// followed by the program registers. The registers are saved after the forwarded
+/// gas is subtracted, so `$cgas` is the context gas left to the caller, while `$pc`
+/// still points to the `CALL` instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SavedRegister {
+    /// `$zero`
+    Zero,
+    /// `$one`
+    One,
+    /// `$of`
+    Of,
+    /// `$pc`
+    Pc,
+    /// `$ssp`
+    Ssp,
+    /// `$sp`
+    Sp,
+    /// `$fp`
+    Fp,
+    /// `$hp`
+    Hp,
+    /// `$err`
+    Err,
+    /// `$ggas`
+    Ggas,
+    /// `$cgas`
+    Cgas,
+    /// `$bal`
+    Bal,
+    /// `$is`
+    Is,
+    /// `$ret`
+    Ret,
+    /// `$retl`
+    Retl,
+    /// `$flag`
+    Flag,
+    /// The program register at the index, where `0` is the register `0x10`.
+    Program(u8),
+}
+
+impl SavedRegister {
+    /// The saved system registers in the order of the saved registers region.
+    pub const SYSTEM: [Self; VM_REGISTER_SYSTEM_COUNT] = [
+        Self::Zero,
+        Self::One,
+        Self::Of,
+        Self::Pc,
+        Self::Ssp,
+        Self::Sp,
+        Self::Fp,
+        Self::Hp,
+        Self::Err,
+        Self::Ggas,
+        Self::Cgas,
+        Self::Bal,
+        Self::Is,
+        Self::Ret,
+        Self::Retl,
+        Self::Flag,
+    ];
+
+    /// The index of the register in the saved registers region, which is the
+    /// register id.
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Zero => RegId::ZERO.to_u8() as usize,
+            Self::One => RegId::ONE.to_u8() as usize,
+            Self::Of => RegId::OF.to_u8() as usize,
+            Self::Pc => RegId::PC.to_u8() as usize,
+            Self::Ssp => RegId::SSP.to_u8() as usize,
+            Self::Sp => RegId::SP.to_u8() as usize,
+            Self::Fp => RegId::FP.to_u8() as usize,
+            Self::Hp => RegId::HP.to_u8() as usize,
+            Self::Err => RegId::ERR.to_u8() as usize,
+            Self::Ggas => RegId::GGAS.to_u8() as usize,
+            Self::Cgas => RegId::CGAS.to_u8() as usize,
+            Self::Bal => RegId::BAL.to_u8() as usize,
+            Self::Is => RegId::IS.to_u8() as usize,
+            Self::Ret => RegId::RET.to_u8() as usize,
+            Self::Retl => RegId::RETL.to_u8() as usize,
+            Self::Flag => RegId::FLAG.to_u8() as usize,
+            Self::Program(index) => {
+                VM_REGISTER_SYSTEM_COUNT.saturating_add(index as usize)
+            }
+        }
+    }
+}
+
+impl From<RegId> for SavedRegister {
+    fn from(reg: RegId) -> Self {
+        let index = reg.to_u8() as usize;
+        match Self::SYSTEM.get(index) {
+            Some(system) => *system,
+            None => Self::Program(reg.to_u8().saturating_sub(RegId::WRITABLE.to_u8())),
+        }
+    }
+}
 
-    fn try_from(mut value: &[u8]) -> Result<Self, Self::Error> {
-        Self::decode(&mut value).map_err(|_| PanicReason::MalformedCallStructure)
+/// Gas accounting of a single contract call of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CallGasReport {
+    /// Index of the `Call` receipt in the receipts of the transaction.
+    pub receipt_index: usize,
+    /// The called contract.
+    pub to: ContractId,
+    /// The gas forwarded to the callee, as recorded in the `Call` receipt.
+    pub forwarded: Word,
+    /// The gas consumed by the callee and all the calls made by it. It never
+    /// exceeds the forwarded gas.
+    pub consumed: Word,
+    /// The gas handed back to the caller on `RET` or `RETD`, or `None` if the call
+    /// didn't return because the execution reverted or panicked.
+    pub returned: Option<Word>,
+}
+
+impl CallGasReport {
+    /// Pairs every `Call` receipt with the gas left in its subtree.
+    ///
+    /// `returned_gas` is the context gas at each return from a call, in the order of
+    /// the returns. The calls that didn't return are still on the `open_frames` stack,
+    /// and the gas left in them is `cgas` of the innermost frame plus the context gas
+    /// saved by every nested frame.
+    pub(crate) fn from_execution(
+        receipts: &[Receipt],
+        returned_gas: &[Word],
+        open_frames: &[CallFrame],
+        cgas: Word,
+    ) -> Vec<Self> {
+        let mut reports = Vec::<Self>::new();
+        let mut stack = Vec::new();
+        let mut returned_gas = returned_gas.iter().copied();
+
+        for (receipt_index, receipt) in receipts.iter().enumerate() {
+            match receipt {
+                Receipt::Call { to, gas, .. } => {
+                    stack.push(reports.len());
+                    reports.push(Self {
+                        receipt_index,
+                        to: *to,
+                        forwarded: *gas,
+                        consumed: 0,
+                        returned: None,
+                    });
+                }
+                Receipt::Return { .. } | Receipt::ReturnData { .. } => {
+                    // The return from the script itself has no call to pair with
+                    let Some(index) = stack.pop() else { continue };
+                    let report = &mut reports[index];
+                    report.returned = returned_gas.next();
+                    report.consumed = report
+                        .forwarded
+                        .saturating_sub(report.returned.unwrap_or_default());
+                }
+                // A reverted call frame hands its gas back like a return, while the
+                // revert of the whole transaction returns no gas
+                Receipt::Revert { .. } | Receipt::Panic { .. } if !stack.is_empty() => {
+                    let Some(returned) = returned_gas.next() else {
+                        continue
+                    };
+                    let Some(index) = stack.pop() else { continue };
+                    let report = &mut reports[index];
+                    report.returned = Some(returned);
+                    report.consumed = report.forwarded.saturating_sub(returned);
+                }
+                _ => {}
+            }
+        }
+
+        let mut left = cgas;
+        for (index, frame) in stack.iter().rev().zip(open_frames.iter().rev()) {
+            let report = &mut reports[*index];
+            report.consumed = report.forwarded.saturating_sub(left);
+            left = left.saturating_add(frame.context_gas());
+        }
+
+        reports
+    }
+}
+
+/// The error of parsing the [`Call`] from bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum CallParseError {
+    /// The bytes end before the call structure is complete.
+    #[display(
+        fmt = "Call structure is {} bytes, but only {available} are provided",
+        "Call::SERIALIZED_LEN"
+    )]
+    Truncated {
+        /// The number of provided bytes
+        available: usize,
+    },
+    /// The bytes continue after the call structure.
+    #[display(fmt = "Unexpected {remaining} bytes after the call structure")]
+    TrailingBytes {
+        /// The number of bytes after the call structure
+        remaining: usize,
+    },
+}
+
+impl From<CallParseError> for PanicReason {
+    fn from(_: CallParseError) -> Self {
+        PanicReason::MalformedCallStructure
+    }
+}
+
+impl TryFrom<&[u8]> for Call {
+    type Error = CallParseError;
+
+    /// Parses the call structure as it is read by `CALL`. The `value` must be
+    /// exactly [`Call::SERIALIZED_LEN`] bytes.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let available = value.len();
+        let Some(remaining) = available.checked_sub(Self::SERIALIZED_LEN) else {
+            return Err(CallParseError::Truncated { available })
+        };
+        if remaining != 0 {
+            return Err(CallParseError::TrailingBytes { remaining })
+        }
+        let mut value = value;
+        let call = Self::decode(&mut value)
+            .map_err(|_| CallParseError::Truncated { available })?;
+        Ok(call)
     }
 }
 
@@ -22,6 +22,10 @@ use fuel_types::{
 
 use alloc::{
     boxed::Box,
+    string::{
+        String,
+        ToString,
+    },
     vec::Vec,
 };
 use core::{
@@ -37,9 +41,25 @@ use fuel_tx::{
 mod balances;
 #[cfg(feature = "test-helpers")]
 pub mod builder;
+#[cfg(any(feature = "rayon", feature = "tokio"))]
+mod executors;
+pub mod predicate_cache;
 pub mod types;
+mod views;
 
+#[cfg(feature = "rayon")]
+pub use executors::{
+    RayonExecutor,
+    RayonTask,
+};
+#[cfg(feature = "tokio")]
+pub use executors::{
+    TokioExecutor,
+    TokioTask,
+};
+pub use predicate_cache::PredicateCheckCache;
 pub use types::*;
+pub use views::TransactionFields;
 
 use crate::{
     error::PredicateVerificationFailed,
@@ -49,10 +69,14 @@ use crate::{
     },
     pool::VmMemoryPool,
     prelude::*,
-    storage::predicate::{
-        EmptyStorage,
-        PredicateStorageProvider,
-        PredicateStorageRequirements,
+    storage::{
+        predicate::{
+            EmptyStorage,
+            PredicateStorageProvider,
+            PredicateStorageRequirements,
+        },
+        ConsensusParametersAt,
+        ConsensusParametersProvider,
     },
 };
 
@@ -164,6 +188,15 @@ impl<Tx: IntoChecked> Ready<Tx> {
     }
 }
 
+impl<Tx: IntoChecked + UniqueIdentifier> Ready<Tx> {
+    /// Returns the transaction ID from the computed metadata
+    pub fn id(&self) -> TxId {
+        self.transaction
+            .cached_id()
+            .expect("Transaction metadata should be computed for checked transactions")
+    }
+}
+
 #[cfg(feature = "test-helpers")]
 impl<Tx: IntoChecked> Checked<Tx> {
     /// Convert `Checked` into `Ready` without performing final checks.
@@ -208,8 +241,12 @@ impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
         let max_fee_from_gas_price = fee.max_fee();
 
         if let Some(block_height) = block_height {
-            if block_height > transaction.expiration() {
-                return Err(CheckError::Validity(ValidityError::TransactionExpiration));
+            let expiration = transaction.expiration();
+            if block_height > expiration {
+                return Err(CheckError::Validity(ValidityError::TransactionExpiration {
+                    policy: expiration,
+                    block_height,
+                }));
             }
         }
 
@@ -297,6 +334,8 @@ pub enum CheckError {
         /// The max fee calculated from the gas price and gas used by the transaction.
         max_fee_from_gas_price: Word,
     },
+    /// The consensus parameters to check the transaction against can't be provided.
+    ConsensusParametersUnavailable(String),
 }
 
 /// Performs checks for a transaction
@@ -339,6 +378,25 @@ pub trait IntoChecked: FormatValidityChecks + Sized {
             .check_predicates(&check_predicate_params, memory, storage)
     }
 
+    /// Returns transaction that passed all `Checks` against the consensus parameters
+    /// active at the `block_height` according to the `storage`.
+    ///
+    /// Unlike [`Self::into_checked`], it takes the upgrades of the consensus parameters
+    /// into account.
+    fn into_checked_at_version(
+        self,
+        block_height: BlockHeight,
+        storage: &impl ConsensusParametersProvider,
+    ) -> Result<Checked<Self>, CheckError>
+    where
+        Checked<Self>: CheckPredicates,
+    {
+        let consensus_params = storage
+            .params_at(ConsensusParametersAt::Height(block_height))
+            .map_err(|e| CheckError::ConsensusParametersUnavailable(e.to_string()))?;
+        self.into_checked(block_height, &consensus_params)
+    }
+
     /// Returns transaction that passed only `Checks::Basic`.
     fn into_checked_basic(
         self,
@@ -364,12 +422,33 @@ pub struct CheckPredicateParams {
     pub contract_max_size: u64,
     /// Maximum length of the message data
     pub max_message_data_length: u64,
+    /// Maximum length of the predicate bytecode
+    pub max_predicate_length: u64,
+    /// Maximum length of the predicate data
+    pub max_predicate_data_length: u64,
     /// Offset of the transaction data in the memory
     pub tx_offset: usize,
     /// Fee parameters
     pub fee_params: FeeParameters,
     /// Base Asset ID
     pub base_asset_id: AssetId,
+    /// Reject the predicates failing the
+    /// [`static_check`](crate::predicate::static_check) before executing them
+    pub static_check: bool,
+    /// Panic on `CALL` forwarding more gas than the remaining context gas
+    pub strict_call_gas: bool,
+    /// Round the `ALOC` allocation sizes up to a multiple of the word size
+    pub word_aligned_heap: bool,
+    /// Allow `RETD`, `LOGD`, `SMO`, `S256`, `K256` and `MEQ` to read the empty range
+    /// at any address
+    pub allow_empty_reads: bool,
+    /// Panic on the relative jumps targeting outside of the code of the current
+    /// context
+    pub relative_jumps_within_code: bool,
+    /// Revert only the call frame on `RVRT` and the panics inside of it
+    pub call_frame_reverts: bool,
+    /// Distinguish the non-variable and the filled outputs in the panics of `TRO`
+    pub precise_output_panics: bool,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -395,9 +474,20 @@ impl From<&ConsensusParameters> for CheckPredicateParams {
             max_inputs: value.tx_params().max_inputs(),
             contract_max_size: value.contract_params().contract_max_size(),
             max_message_data_length: value.predicate_params().max_message_data_length(),
+            max_predicate_length: value.predicate_params().max_predicate_length(),
+            max_predicate_data_length: value
+                .predicate_params()
+                .max_predicate_data_length(),
             tx_offset: value.tx_params().tx_offset(),
             fee_params: *(value.fee_params()),
             base_asset_id: *value.base_asset_id(),
+            static_check: false,
+            strict_call_gas: value.tx_params().strict_call_gas(),
+            word_aligned_heap: value.tx_params().word_aligned_heap(),
+            allow_empty_reads: value.tx_params().allow_empty_reads(),
+            relative_jumps_within_code: value.tx_params().relative_jumps_within_code(),
+            call_frame_reverts: value.tx_params().call_frame_reverts(),
+            precise_output_panics: value.tx_params().precise_output_panics(),
         }
     }
 }
@@ -422,6 +512,30 @@ pub trait CheckPredicates: Sized {
     ) -> Result<Self, CheckError>;
 }
 
+impl<Tx> Checked<Tx>
+where
+    Tx: ExecutableTransaction,
+    <Tx as IntoChecked>::Metadata: crate::interpreter::CheckedMetadata,
+{
+    /// Performs predicates verification of the transaction, if not yet done.
+    ///
+    /// Predicates with a matching entry in the `cache` are not executed again.
+    /// Successfully verified predicates are recorded in the `cache`.
+    pub fn check_predicates_cached(
+        mut self,
+        params: &CheckPredicateParams,
+        cache: &mut PredicateCheckCache,
+        memory: impl Memory,
+        storage: &impl PredicateStorageRequirements,
+    ) -> Result<Self, CheckError> {
+        if !self.checks_bitmask.contains(Checks::Predicates) {
+            predicates::check_predicates_cached(&self, params, cache, memory, storage)?;
+            self.checks_bitmask.insert(Checks::Predicates);
+        }
+        Ok(self)
+    }
+}
+
 /// Provides predicate estimation functionality for the transaction.
 #[async_trait::async_trait]
 pub trait EstimatePredicates: Sized {
@@ -935,6 +1049,9 @@ mod tests {
     use fuel_crypto::SecretKey;
     use fuel_tx::{
         field::{
+            Inputs,
+            Outputs,
+            ScriptData,
             ScriptGasLimit,
             Tip,
             WitnessLimit,
@@ -1107,6 +1224,108 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn views__match_owned_fields_of_transaction_with_mixed_inputs() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // Given
+        let coin_predicate = vec![op::ret(1)].into_iter().collect::<Vec<u8>>();
+        let message_predicate = vec![op::ret(1), op::noop()]
+            .into_iter()
+            .collect::<Vec<u8>>();
+        let tx = TransactionBuilder::script(
+            vec![op::ret(1)].into_iter().collect(),
+            vec![0xcc; 7],
+        )
+        .max_fee_limit(0)
+        .script_gas_limit(1000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            10,
+            AssetId::BASE,
+            rng.gen(),
+        )
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&coin_predicate),
+            10,
+            AssetId::BASE,
+            rng.gen(),
+            0,
+            coin_predicate.clone(),
+            vec![0xaa; 3],
+        ))
+        .add_input(Input::contract(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+        ))
+        .add_input(Input::message_data_predicate(
+            rng.gen(),
+            Input::predicate_owner(&message_predicate),
+            10,
+            rng.gen(),
+            0,
+            vec![0xff; 5],
+            message_predicate.clone(),
+            vec![0xbb; 4],
+        ))
+        .add_output(Output::contract(2, rng.gen(), rng.gen()))
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+        .finalize();
+
+        // When
+        let checked = tx
+            .clone()
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .expect("Expected valid transaction");
+        let ready = checked.clone().test_into_ready();
+        let checked_tx = Transaction::from(tx.clone())
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .expect("Expected valid transaction");
+
+        // Then
+        let owned_predicates = vec![
+            (1, coin_predicate.clone(), vec![0xaa; 3]),
+            (3, message_predicate.clone(), vec![0xbb; 4]),
+        ];
+        macro_rules! assert_views {
+            ($view:expr) => {
+                let view = $view;
+                assert_eq!(view.script_data(), Some(tx.script_data().as_slice()));
+                for (index, input) in tx.inputs().iter().enumerate() {
+                    assert_eq!(
+                        view.predicate(index),
+                        input.predicate().map(|(predicate, _, _)| predicate)
+                    );
+                    assert_eq!(
+                        view.predicate_data(index),
+                        input.predicate().map(|(_, data, _)| data)
+                    );
+                }
+                assert_eq!(view.predicate(tx.inputs().len()), None);
+                assert_eq!(view.predicate_data(tx.inputs().len()), None);
+                for (index, witness) in tx.witnesses().iter().enumerate() {
+                    assert_eq!(view.witness(index), Some(witness.as_vec().as_slice()));
+                }
+                assert_eq!(view.witness(tx.witnesses().len()), None);
+                let predicates = view
+                    .iter_predicates()
+                    .map(|(index, predicate, data)| {
+                        (index, predicate.to_vec(), data.to_vec())
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(predicates, owned_predicates);
+            };
+        }
+        assert_views!(&checked);
+        assert_views!(&ready);
+        assert_views!(&checked_tx);
+    }
+
     // use quickcheck to fuzz any rounding or precision errors in the max fee w/ coin
     // input
     #[quickcheck]
@@ -1657,6 +1876,71 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn into_checked__contract_input_without_contract_output__fails() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // Given
+        let mut tx = valid_coin_tx(rng, 1000, 1000, 10, 500);
+        tx.outputs_mut()[0] = Output::coin(rng.gen(), 10, AssetId::default());
+
+        // When
+        let err = tx
+            .into_checked(Default::default(), &ConsensusParameters::standard())
+            .expect_err("Expected invalid transaction");
+
+        // Then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::InputContractAssociatedOutputContract {
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn into_checked__contract_output_referencing_missing_input__fails() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // Given
+        let mut tx = valid_coin_tx(rng, 1000, 1000, 10, 500);
+        let out_of_bounds = tx.inputs().len() as u16;
+        tx.outputs_mut()
+            .push(Output::contract(out_of_bounds, rng.gen(), rng.gen()));
+
+        // When
+        let err = tx
+            .into_checked(Default::default(), &ConsensusParameters::standard())
+            .expect_err("Expected invalid transaction");
+
+        // Then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::OutputContractInputIndex { index: 3 })
+        );
+    }
+
+    #[test]
+    fn into_checked__contract_output_referencing_coin_input__fails() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // Given
+        let mut tx = valid_coin_tx(rng, 1000, 1000, 10, 500);
+        tx.outputs_mut()
+            .push(Output::contract(0, rng.gen(), rng.gen()));
+
+        // When
+        let err = tx
+            .into_checked(Default::default(), &ConsensusParameters::standard())
+            .expect_err("Expected invalid transaction");
+
+        // Then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::OutputContractInputIndex { index: 3 })
+        );
+    }
+
     #[test]
     fn into_checked__tx_fails_when_provided_fees_dont_cover_byte_costs() {
         let rng = &mut StdRng::seed_from_u64(2322u64);
@@ -1857,6 +2141,83 @@ mod tests {
             .expect("Should be valid");
     }
 
+    fn base_asset_tx_with_block_height_policies(
+        rng: &mut StdRng,
+        maturity: BlockHeight,
+        expiration: BlockHeight,
+    ) -> Script {
+        TransactionBuilder::script(vec![], vec![])
+            .maturity(maturity)
+            .expiration(expiration)
+            .max_fee_limit(1_000)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                1_000,
+                AssetId::default(),
+                rng.gen(),
+            )
+            .finalize()
+    }
+
+    #[test]
+    fn into_checked__tx_is_valid_only_between_maturity_and_expiration() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let params = ConsensusParameters::standard();
+
+        // Given
+        let tx = base_asset_tx_with_block_height_policies(rng, 10.into(), 20.into());
+
+        // When
+        let results = [9u32, 10, 20, 21].map(|block_height| {
+            tx.clone()
+                .into_checked(block_height.into(), &params)
+                .map(|_| ())
+        });
+
+        // Then
+        assert_eq!(
+            results,
+            [
+                Err(CheckError::Validity(ValidityError::TransactionMaturity {
+                    policy: 10.into(),
+                    block_height: 9.into(),
+                })),
+                Ok(()),
+                Ok(()),
+                Err(CheckError::Validity(ValidityError::TransactionExpiration {
+                    policy: 20.into(),
+                    block_height: 21.into(),
+                })),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_ready__fails_if_expired_at_the_block_height() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let params = ConsensusParameters::standard();
+
+        // Given
+        let tx = base_asset_tx_with_block_height_policies(rng, 0.into(), 20.into())
+            .into_checked(20.into(), &params)
+            .expect("Expected valid transaction");
+
+        // When
+        let err = tx
+            .into_ready(0, params.gas_costs(), params.fee_params(), Some(21.into()))
+            .expect_err("Expected expired transaction");
+
+        // Then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::TransactionExpiration {
+                policy: 20.into(),
+                block_height: 21.into(),
+            })
+        );
+    }
+
     #[test]
     fn into_ready__return_overflow_error_if_gas_price_too_high() {
         let rng = &mut StdRng::seed_from_u64(2322u64);
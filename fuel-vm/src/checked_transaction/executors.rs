@@ -0,0 +1,142 @@
+//! Implementations of the [`ParallelExecutor`] on top of the popular runtimes.
+//!
+//! A panic inside of the predicate task is caught and reported as
+//! [`PredicateVerificationFailed::TaskPanicked`], so the check fails instead of
+//! hanging or unwinding through the caller.
+
+use alloc::vec::Vec;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+use fuel_types::Word;
+
+use super::ParallelExecutor;
+use crate::error::PredicateVerificationFailed;
+
+type TaskResult = Result<(Word, usize), PredicateVerificationFailed>;
+
+/// Runs the predicates on the global [`rayon`](https://docs.rs/rayon) thread pool.
+///
+/// The tasks are spawned when they are created, and the returned future resolves
+/// once the worker thread finishes the task. It doesn't depend on the async runtime
+/// awaiting it.
+#[cfg(feature = "rayon")]
+pub struct RayonExecutor;
+
+/// The future of the task spawned by the [`RayonExecutor`].
+#[cfg(feature = "rayon")]
+pub struct RayonTask(std::sync::Arc<std::sync::Mutex<RayonTaskState>>);
+
+#[cfg(feature = "rayon")]
+#[derive(Default)]
+struct RayonTaskState {
+    result: Option<TaskResult>,
+    waker: Option<core::task::Waker>,
+}
+
+#[cfg(feature = "rayon")]
+impl Future for RayonTask {
+    type Output = TaskResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.0.lock().expect("The task never panics with the lock");
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[async_trait::async_trait]
+impl ParallelExecutor for RayonExecutor {
+    type Task = RayonTask;
+
+    fn create_task<F>(func: F) -> Self::Task
+    where
+        F: FnOnce() -> TaskResult + Send + 'static,
+    {
+        let state = std::sync::Arc::new(std::sync::Mutex::new(RayonTaskState::default()));
+        let task_state = state.clone();
+        rayon::spawn(move || {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func))
+                .unwrap_or(Err(PredicateVerificationFailed::TaskPanicked));
+            let mut state = task_state
+                .lock()
+                .expect("The task never panics with the lock");
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        RayonTask(state)
+    }
+
+    async fn execute_tasks(futures: Vec<Self::Task>) -> Vec<TaskResult> {
+        join_in_order(futures).await
+    }
+}
+
+/// Runs the predicates on the blocking thread pool of the current
+/// [`tokio`](https://docs.rs/tokio) runtime.
+///
+/// The tasks are spawned with `spawn_blocking` when they are created, so the
+/// predicates must be checked from within the Tokio runtime.
+#[cfg(feature = "tokio")]
+pub struct TokioExecutor;
+
+/// The future of the task spawned by the [`TokioExecutor`].
+#[cfg(feature = "tokio")]
+pub struct TokioTask(tokio::task::JoinHandle<TaskResult>);
+
+#[cfg(feature = "tokio")]
+impl Future for TokioTask {
+    type Output = TaskResult;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(|result| {
+            // The blocking tasks can't be cancelled once started, so the join error
+            // means that the task panicked.
+            result.unwrap_or(Err(PredicateVerificationFailed::TaskPanicked))
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[async_trait::async_trait]
+impl ParallelExecutor for TokioExecutor {
+    type Task = TokioTask;
+
+    fn create_task<F>(func: F) -> Self::Task
+    where
+        F: FnOnce() -> TaskResult + Send + 'static,
+    {
+        TokioTask(tokio::task::spawn_blocking(func))
+    }
+
+    async fn execute_tasks(futures: Vec<Self::Task>) -> Vec<TaskResult> {
+        join_in_order(futures).await
+    }
+}
+
+/// Awaits the already running tasks, collecting the results in the order of the
+/// tasks.
+async fn join_in_order<T>(tasks: Vec<T>) -> Vec<TaskResult>
+where
+    T: Future<Output = TaskResult>,
+{
+    let mut results = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        results.push(task.await);
+    }
+    results
+}
@@ -0,0 +1,136 @@
+//! Cache of the predicate verification results.
+//!
+//! Verification of predicates is the most expensive part of the transaction checking.
+//! The cache allows skipping the verification of the predicates whose outcome can't
+//! change, e.g. when the transaction is re-inserted into the pool or when the
+//! consensus parameters are updated without touching parameters affecting
+//! the execution of predicates.
+
+use alloc::collections::BTreeMap;
+
+use fuel_crypto::Hasher;
+use fuel_types::{
+    canonical::Serialize,
+    Bytes32,
+    Word,
+};
+
+use crate::checked_transaction::CheckPredicateParams;
+
+/// The result of the successful verification of one predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredicateCacheEntry {
+    /// The digest of everything that affects the predicate execution.
+    /// See [`PredicateCheckCache::entry_digest`].
+    pub digest: Bytes32,
+    /// The gas used by the predicate during verification.
+    pub gas_used: Word,
+}
+
+/// Cache of the successful predicate verifications keyed by the input index.
+///
+/// Only successful verifications are recorded. An entry is reused only if its
+/// digest matches the digest computed for the current transaction and parameters.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredicateCheckCache {
+    entries: BTreeMap<usize, PredicateCacheEntry>,
+}
+
+impl PredicateCheckCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cache entry for the input with the `index`.
+    pub fn get(&self, index: usize) -> Option<&PredicateCacheEntry> {
+        self.entries.get(&index)
+    }
+
+    /// Returns the gas used by the predicate of the input with the `index`
+    /// if it was already verified with the same `digest`.
+    pub fn lookup(&self, index: usize, digest: &Bytes32) -> Option<Word> {
+        self.entries
+            .get(&index)
+            .filter(|entry| &entry.digest == digest)
+            .map(|entry| entry.gas_used)
+    }
+
+    /// Records the successful verification of the predicate of the input with the
+    /// `index`. Replaces the previous entry, if any.
+    pub fn insert(&mut self, index: usize, digest: Bytes32, gas_used: Word) {
+        self.entries
+            .insert(index, PredicateCacheEntry { digest, gas_used });
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Computes the digest of the parameters affecting the execution of predicates.
+    ///
+    /// Fee parameters and `max_gas_per_tx` are not included because they are only
+    /// used by the transaction-wide gas check, which is never cached.
+    pub fn params_digest(params: &CheckPredicateParams) -> Bytes32 {
+        // The canonical serialization is stable across the releases, unlike the
+        // `Hash` implementations, so the persisted digests stay valid.
+        Hasher::default()
+            .chain(params.gas_costs.to_bytes())
+            .chain(params.chain_id.to_bytes())
+            .chain(params.max_gas_per_predicate.to_bytes())
+            .chain(params.max_inputs.to_bytes())
+            .chain(params.contract_max_size.to_bytes())
+            .chain(params.max_message_data_length.to_bytes())
+            .chain(params.max_predicate_length.to_bytes())
+            .chain(params.max_predicate_data_length.to_bytes())
+            .chain((params.tx_offset as u64).to_bytes())
+            .chain(params.base_asset_id.to_bytes())
+            .chain(u8::from(params.static_check).to_bytes())
+            .chain(u8::from(params.word_aligned_heap).to_bytes())
+            .chain(u8::from(params.allow_empty_reads).to_bytes())
+            .chain(u8::from(params.relative_jumps_within_code).to_bytes())
+            // The predicates can't `CALL`, but every consensus switch is committed to,
+            // so a switch becoming visible to the predicates never reuses stale entries
+            .chain(u8::from(params.strict_call_gas).to_bytes())
+            .chain(u8::from(params.call_frame_reverts).to_bytes())
+            .chain(u8::from(params.precise_output_panics).to_bytes())
+            .finalize()
+    }
+
+    /// Computes the digest of the cache entry for the input with the `index`.
+    ///
+    /// Predicates can introspect every field of the transaction, so the digest
+    /// commits to the whole serialized transaction (including the predicate bytecode
+    /// and predicate data of the input) and the `params_digest`.
+    pub fn entry_digest<Tx>(tx: &Tx, index: usize, params_digest: &Bytes32) -> Bytes32
+    where
+        Tx: Serialize,
+    {
+        Self::entry_digest_from_bytes(&tx.to_bytes(), index, params_digest)
+    }
+
+    pub(crate) fn entry_digest_from_bytes(
+        tx_bytes: &[u8],
+        index: usize,
+        params_digest: &Bytes32,
+    ) -> Bytes32 {
+        Hasher::default()
+            .chain(params_digest)
+            .chain((index as u64).to_be_bytes())
+            .chain(tx_bytes)
+            .finalize()
+    }
+}
@@ -17,6 +17,7 @@ use fuel_types::{
 /// More information about it in the specification:
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/tx-validity.md#sufficient-balance>
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonRetryableFreeBalances(pub(crate) BTreeMap<AssetId, Word>);
 
 impl From<NonRetryableFreeBalances> for BTreeMap<AssetId, Word> {
@@ -37,6 +38,7 @@ impl core::ops::Deref for NonRetryableFreeBalances {
 /// More information about it in the specification:
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/tx-validity.md#sufficient-balance>
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RetryableAmount {
     pub(crate) amount: Word,
     pub(crate) base_asset_id: AssetId,
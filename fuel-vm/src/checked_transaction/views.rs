@@ -0,0 +1,174 @@
+//! Zero-copy views into the fields of the [`Checked`] and [`Ready`] transactions.
+
+use fuel_tx::{
+    field,
+    Blob,
+    Create,
+    Input,
+    Mint,
+    Script,
+    Transaction,
+    Upgrade,
+    Upload,
+    Witness,
+};
+
+use super::{
+    Checked,
+    IntoChecked,
+    Ready,
+};
+
+/// Borrows the fields of the transaction exposed by the views of [`Checked`] and
+/// [`Ready`].
+pub trait TransactionFields {
+    /// Returns the script data if the transaction is a script.
+    fn script_data_field(&self) -> Option<&[u8]>;
+
+    /// Returns the inputs of the transaction.
+    fn inputs_field(&self) -> &[Input];
+
+    /// Returns the witnesses of the transaction.
+    fn witnesses_field(&self) -> &[Witness];
+}
+
+impl TransactionFields for Script {
+    fn script_data_field(&self) -> Option<&[u8]> {
+        Some(field::ScriptData::script_data(self))
+    }
+
+    fn inputs_field(&self) -> &[Input] {
+        field::Inputs::inputs(self)
+    }
+
+    fn witnesses_field(&self) -> &[Witness] {
+        field::Witnesses::witnesses(self)
+    }
+}
+
+macro_rules! impl_transaction_fields_without_script {
+    ($($tx:ty),+) => {
+        $(
+            impl TransactionFields for $tx {
+                fn script_data_field(&self) -> Option<&[u8]> {
+                    None
+                }
+
+                fn inputs_field(&self) -> &[Input] {
+                    field::Inputs::inputs(self)
+                }
+
+                fn witnesses_field(&self) -> &[Witness] {
+                    field::Witnesses::witnesses(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_transaction_fields_without_script!(Create, Upgrade, Upload, Blob);
+
+impl TransactionFields for Mint {
+    fn script_data_field(&self) -> Option<&[u8]> {
+        None
+    }
+
+    fn inputs_field(&self) -> &[Input] {
+        &[]
+    }
+
+    fn witnesses_field(&self) -> &[Witness] {
+        &[]
+    }
+}
+
+impl TransactionFields for Transaction {
+    fn script_data_field(&self) -> Option<&[u8]> {
+        match self {
+            Transaction::Script(tx) => tx.script_data_field(),
+            Transaction::Create(tx) => tx.script_data_field(),
+            Transaction::Mint(tx) => tx.script_data_field(),
+            Transaction::Upgrade(tx) => tx.script_data_field(),
+            Transaction::Upload(tx) => tx.script_data_field(),
+            Transaction::Blob(tx) => tx.script_data_field(),
+        }
+    }
+
+    fn inputs_field(&self) -> &[Input] {
+        match self {
+            Transaction::Script(tx) => tx.inputs_field(),
+            Transaction::Create(tx) => tx.inputs_field(),
+            Transaction::Mint(tx) => tx.inputs_field(),
+            Transaction::Upgrade(tx) => tx.inputs_field(),
+            Transaction::Upload(tx) => tx.inputs_field(),
+            Transaction::Blob(tx) => tx.inputs_field(),
+        }
+    }
+
+    fn witnesses_field(&self) -> &[Witness] {
+        match self {
+            Transaction::Script(tx) => tx.witnesses_field(),
+            Transaction::Create(tx) => tx.witnesses_field(),
+            Transaction::Mint(tx) => tx.witnesses_field(),
+            Transaction::Upgrade(tx) => tx.witnesses_field(),
+            Transaction::Upload(tx) => tx.witnesses_field(),
+            Transaction::Blob(tx) => tx.witnesses_field(),
+        }
+    }
+}
+
+macro_rules! impl_views {
+    ($wrapper:ident) => {
+        impl<Tx: IntoChecked + TransactionFields> $wrapper<Tx> {
+            /// Returns the script data of the transaction, if it is a script.
+            pub fn script_data(&self) -> Option<&[u8]> {
+                self.transaction.script_data_field()
+            }
+
+            /// Returns the predicate of the input at `index`, if it is a predicate
+            /// input.
+            pub fn predicate(&self, index: usize) -> Option<&[u8]> {
+                self.transaction
+                    .inputs_field()
+                    .get(index)?
+                    .input_predicate()
+            }
+
+            /// Returns the predicate data of the input at `index`, if it is a
+            /// predicate input.
+            pub fn predicate_data(&self, index: usize) -> Option<&[u8]> {
+                self.transaction
+                    .inputs_field()
+                    .get(index)?
+                    .input_predicate_data()
+            }
+
+            /// Returns the witness at `index`.
+            pub fn witness(&self, index: usize) -> Option<&[u8]> {
+                self.transaction
+                    .witnesses_field()
+                    .get(index)
+                    .map(AsRef::as_ref)
+            }
+
+            /// Iterates over the predicate inputs, yielding the index of the input,
+            /// the predicate and the predicate data.
+            pub fn iter_predicates(
+                &self,
+            ) -> impl Iterator<Item = (usize, &[u8], &[u8])> + '_ {
+                self.transaction
+                    .inputs_field()
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, input)| {
+                        input
+                            .predicate()
+                            .map(|(predicate, data, _)| (index, predicate, data))
+                    })
+            }
+        }
+    };
+}
+
+impl_views!(Checked);
+impl_views!(Ready);
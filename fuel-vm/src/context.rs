@@ -39,7 +39,35 @@ impl Default for Context {
     }
 }
 
+/// The kind of the [`Context`], without its data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContextKind {
+    /// Predicate estimation.
+    PredicateEstimation,
+    /// Predicate verification.
+    PredicateVerification,
+    /// Script execution.
+    Script,
+    /// Execution under a `CALL` scope.
+    Call,
+    /// No transaction initialized/invalid context.
+    #[default]
+    NotInitialized,
+}
+
 impl Context {
+    /// The kind of the context.
+    pub const fn kind(&self) -> ContextKind {
+        match self {
+            Self::PredicateEstimation { .. } => ContextKind::PredicateEstimation,
+            Self::PredicateVerification { .. } => ContextKind::PredicateVerification,
+            Self::Script { .. } => ContextKind::Script,
+            Self::Call { .. } => ContextKind::Call,
+            Self::NotInitialized => ContextKind::NotInitialized,
+        }
+    }
+
     /// Check if the context is predicate
     pub const fn is_predicate(&self) -> bool {
         matches!(
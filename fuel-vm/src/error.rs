@@ -7,9 +7,17 @@ use fuel_asm::{
     Word,
 };
 use fuel_tx::ValidityError;
+use fuel_types::{
+    Bytes32,
+    ContractId,
+};
 
-use crate::checked_transaction::CheckError;
+use crate::{
+    checked_transaction::CheckError,
+    predicate::StaticIssue,
+};
 use alloc::{
+    boxed::Box,
     format,
     string::{
         String,
@@ -24,7 +32,7 @@ use core::{
 use crate::storage::predicate;
 
 /// Interpreter runtime error variants.
-#[derive(Debug, derive_more::Display)]
+#[derive(Debug, Clone, derive_more::Display)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InterpreterError<StorageError> {
     /// The instructions execution resulted in a well-formed panic, caused by an
@@ -48,7 +56,7 @@ pub enum InterpreterError<StorageError> {
     DebugStateNotInitialized,
     /// Storage I/O error
     #[display(fmt = "Storage error: {}", _0)]
-    Storage(StorageError),
+    Storage(StorageFailure<StorageError>),
     /// Encountered a bug
     #[display(fmt = "Bug: {_0}")]
     Bug(Bug),
@@ -112,7 +120,10 @@ where
     /// Make non-generic by converting the storage error to a string.
     pub fn erase_generics(&self) -> InterpreterError<String> {
         match self {
-            Self::Storage(e) => InterpreterError::Storage(format!("{e:?}")),
+            Self::Storage(e) => InterpreterError::Storage(StorageFailure {
+                error: format!("{:?}", e.error),
+                context: e.context,
+            }),
             Self::PanicInstruction(e) => InterpreterError::PanicInstruction(*e),
             Self::Panic(e) => InterpreterError::Panic(*e),
             Self::NoTransactionInitialized => InterpreterError::NoTransactionInitialized,
@@ -184,7 +195,7 @@ pub enum RuntimeError<StorageError> {
     /// Invalid interpreter state reached unexpectedly, this is a bug
     Bug(Bug),
     /// Storage io error
-    Storage(StorageError),
+    Storage(StorageFailure<StorageError>),
 }
 
 impl<StorageError> RuntimeError<StorageError> {
@@ -197,6 +208,33 @@ impl<StorageError> RuntimeError<StorageError> {
     pub const fn must_halt(&self) -> bool {
         !self.is_recoverable()
     }
+
+    /// Wraps the storage `error` with the `context` of the failed operation.
+    pub const fn storage(error: StorageError, context: StorageErrorContext) -> Self {
+        Self::Storage(StorageFailure::with_context(error, context))
+    }
+}
+
+impl<StorageError> RuntimeError<StorageError>
+where
+    StorageError: Into<RuntimeError<StorageError>>,
+{
+    /// Converts the storage error into the [`RuntimeError`] defined by the storage,
+    /// so the storage may turn its error into the VM panic, e.g. the write to the
+    /// [`ReadOnlyStorage`](crate::storage::ReadOnlyStorage). The storage errors
+    /// that remain unrecoverable keep the context of the failed operation.
+    pub fn resolve_storage(self) -> Self {
+        let Self::Storage(StorageFailure { error, context }) = self else {
+            return self
+        };
+        match error.into() {
+            Self::Storage(failure) => Self::Storage(StorageFailure {
+                error: failure.error,
+                context: context.or(failure.context),
+            }),
+            resolved => resolved,
+        }
+    }
 }
 
 impl<StorageError: PartialEq> PartialEq for RuntimeError<StorageError> {
@@ -215,7 +253,13 @@ impl<StorageError: core::fmt::Debug> fmt::Display for RuntimeError<StorageError>
         match self {
             Self::Recoverable(reason) => write!(f, "Recoverable error: {}", reason),
             Self::Bug(err) => write!(f, "Bug: {}", err),
-            Self::Storage(err) => write!(f, "Unrecoverable storage error: {:?}", err),
+            Self::Storage(err) => {
+                write!(f, "Unrecoverable storage error: {:?}", err.error)?;
+                if let Some(context) = &err.context {
+                    write!(f, " {context}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -244,85 +288,353 @@ impl<StorageError> From<Infallible> for RuntimeError<StorageError> {
     }
 }
 
+/// The storage operation that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageOp {
+    /// Reading the state of a contract, e.g. by `SRW` or `SRWQ`.
+    #[display(fmt = "reading the contract state")]
+    ContractStateRead,
+    /// Writing the state of a contract, e.g. by `SWW` or `SWWQ`.
+    #[display(fmt = "writing the contract state")]
+    ContractStateWrite,
+    /// Clearing the state of a contract by `SCWQ`.
+    #[display(fmt = "clearing the contract state")]
+    ContractStateClear,
+    /// Reading the balance of a contract.
+    #[display(fmt = "reading the contract balance")]
+    ContractBalanceRead,
+    /// Writing the balance of a contract.
+    #[display(fmt = "writing the contract balance")]
+    ContractBalanceWrite,
+    /// Reading the bytecode of a contract or checking its existence.
+    #[display(fmt = "reading the contract code")]
+    ContractCodeRead,
+    /// Deploying a contract by the `Create` transaction.
+    #[display(fmt = "deploying the contract")]
+    ContractDeploy,
+    /// Reading a blob.
+    #[display(fmt = "reading the blob")]
+    BlobRead,
+    /// Writing a blob by the `Blob` transaction.
+    #[display(fmt = "writing the blob")]
+    BlobWrite,
+    /// Uploading a subsection of the bytecode by the `Upload` transaction.
+    #[display(fmt = "uploading the bytecode")]
+    BytecodeUpload,
+    /// Reading the version of the consensus parameters.
+    #[display(fmt = "reading the consensus parameters version")]
+    ConsensusParametersRead,
+    /// Upgrading the consensus parameters by the `Upgrade` transaction.
+    #[display(fmt = "upgrading the consensus parameters")]
+    ConsensusParametersUpgrade,
+    /// Reading the version of the state transition bytecode.
+    #[display(fmt = "reading the state transition version")]
+    StateTransitionRead,
+    /// Upgrading the state transition bytecode by the `Upgrade` transaction.
+    #[display(fmt = "upgrading the state transition bytecode")]
+    StateTransitionUpgrade,
+    /// Reading the block height, hash, timestamp or coinbase.
+    #[display(fmt = "reading the block information")]
+    BlockInfoRead,
+}
+
+/// The context of the failed storage operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageErrorContext {
+    /// The operation that failed.
+    pub op: StorageOp,
+    /// The contract accessed by the operation, if any.
+    pub contract: Option<ContractId>,
+    /// The key accessed by the operation, if any.
+    pub key: Option<Bytes32>,
+}
+
+impl StorageErrorContext {
+    /// Creates the context of the `op` without the contract and the key.
+    pub const fn new(op: StorageOp) -> Self {
+        Self {
+            op,
+            contract: None,
+            key: None,
+        }
+    }
+
+    /// Sets the contract accessed by the operation.
+    pub const fn contract(mut self, contract: ContractId) -> Self {
+        self.contract = Some(contract);
+        self
+    }
+
+    /// Sets the key accessed by the operation.
+    pub const fn key(mut self, key: Bytes32) -> Self {
+        self.key = Some(key);
+        self
+    }
+}
+
+impl fmt::Display for StorageErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "while {}", self.op)?;
+        if let Some(contract) = &self.contract {
+            write!(f, " of the contract {contract}")?;
+        }
+        if let Some(key) = &self.key {
+            write!(f, " at the key {key}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The error of the storage, with the context of the operation if it's known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageFailure<StorageError> {
+    /// The error returned by the storage.
+    pub error: StorageError,
+    /// The context of the failed operation.
+    pub context: Option<StorageErrorContext>,
+}
+
+impl<StorageError> StorageFailure<StorageError> {
+    /// Wraps the storage `error` without the context.
+    pub const fn new(error: StorageError) -> Self {
+        Self {
+            error,
+            context: None,
+        }
+    }
+
+    /// Wraps the storage `error` with the `context` of the failed operation.
+    pub const fn with_context(error: StorageError, context: StorageErrorContext) -> Self {
+        Self {
+            error,
+            context: Some(context),
+        }
+    }
+
+    /// Maps the storage error, keeping the context.
+    pub fn map<E, F>(self, f: F) -> StorageFailure<E>
+    where
+        F: FnOnce(StorageError) -> E,
+    {
+        StorageFailure {
+            error: f(self.error),
+            context: self.context,
+        }
+    }
+}
+
+impl<StorageError> From<StorageError> for StorageFailure<StorageError> {
+    fn from(error: StorageError) -> Self {
+        Self::new(error)
+    }
+}
+
+impl<StorageError> fmt::Display for StorageFailure<StorageError>
+where
+    StorageError: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let Some(context) = &self.context {
+            write!(f, " {context}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Predicates checking failed
 #[derive(Debug, Clone, PartialEq, derive_more::Display)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PredicateVerificationFailed {
     /// The predicate did not use the amount of gas provided
-    #[display(fmt = "Predicate used less than the required amount of gas")]
-    GasMismatch,
+    #[display(
+        fmt = "Predicate at input {index} consumed {gas_used} of {available_gas} gas, but must consume all of its `predicate_gas_used`"
+    )]
+    GasMismatch {
+        /// The index of the input
+        index: usize,
+        /// The gas consumed by the predicate
+        gas_used: Word,
+        /// The gas available to the predicate
+        available_gas: Word,
+    },
     /// The transaction doesn't contain enough gas to evaluate the predicate
-    #[display(fmt = "Insufficient gas available for single predicate")]
-    OutOfGas,
+    #[display(fmt = "Predicate at input {index} ran out of {available_gas} gas")]
+    OutOfGas {
+        /// The index of the input
+        index: usize,
+        /// The gas available to the predicate
+        available_gas: Word,
+    },
+    /// The predicate bytecode of the input exceeds the `max_predicate_length`
+    #[display(
+        fmt = "Predicate at input {index} has length {actual}, exceeding the limit {limit}"
+    )]
+    PredicateLength {
+        /// The index of the input
+        index: usize,
+        /// The length of the predicate bytecode
+        actual: u64,
+        /// The maximum allowed length of the predicate bytecode
+        limit: u64,
+    },
+    /// The predicate data of the input exceeds the `max_predicate_data_length`
+    #[display(
+        fmt = "Predicate data at input {index} has length {actual}, exceeding the limit {limit}"
+    )]
+    PredicateDataLength {
+        /// The index of the input
+        index: usize,
+        /// The length of the predicate data
+        actual: u64,
+        /// The maximum allowed length of the predicate data
+        limit: u64,
+    },
     /// The predicate owner does not correspond to the predicate code
-    #[display(fmt = "Predicate owner invalid, doesn't match code root")]
-    InvalidOwner,
+    #[display(fmt = "Owner of input {index} doesn't match the root of its predicate")]
+    InvalidOwner {
+        /// The index of the input
+        index: usize,
+    },
     /// The predicate wasn't successfully evaluated to true
-    #[display(fmt = "Predicate failed to evaluate")]
-    False,
+    #[display(
+        fmt = "Predicate at input {index} returned false after consuming {gas_used} of {available_gas} gas"
+    )]
+    False {
+        /// The index of the input
+        index: usize,
+        /// The gas consumed by the predicate
+        gas_used: Word,
+        /// The gas available to the predicate
+        available_gas: Word,
+    },
     /// The predicate gas used was not specified before execution
-    #[display(fmt = "Predicate failed to evaluate")]
-    GasNotSpecified,
+    #[display(fmt = "Predicate gas of input {index} wasn't specified before execution")]
+    GasNotSpecified {
+        /// The index of the input
+        index: usize,
+    },
     /// The transaction's `max_gas` is greater than the global gas limit.
-    #[display(fmt = "Transaction exceeds total gas allowance {_0:?}")]
+    #[display(
+        fmt = "Transaction requires {_0} gas, exceeding the total gas allowance per transaction"
+    )]
     TransactionExceedsTotalGasAllowance(Word),
     /// The cumulative gas overflowed the u64 accumulator
     #[display(fmt = "Cumulative gas computation overflowed the u64 accumulator")]
     GasOverflow,
     /// Invalid interpreter state reached unexpectedly, this is a bug
-    #[display(fmt = "Invalid interpreter state reached unexpectedly")]
-    Bug(Bug),
+    #[display(fmt = "Predicate at input {index} reached an invalid state: {bug}")]
+    Bug {
+        /// The index of the input
+        index: usize,
+        /// The bug
+        bug: Bug,
+    },
     /// The VM execution resulted in a well-formed panic, caused by an instruction.
-    #[display(fmt = "Execution error: {_0:?}")]
-    PanicInstruction(PanicInstruction),
+    #[display(fmt = "Predicate at input {index} panicked: {instruction:?}")]
+    PanicInstruction {
+        /// The index of the input
+        index: usize,
+        /// The panic reason and the instruction causing it
+        instruction: PanicInstruction,
+    },
     /// The VM execution resulted in a well-formed panic not caused by an instruction.
-    #[display(fmt = "Execution error: {_0:?}")]
-    Panic(PanicReason),
-    /// Predicate verification failed since it attempted to access storage
-    #[display(
-        fmt = "Predicate verification failed since it attempted to access storage"
-    )]
-    Storage,
+    #[display(fmt = "Predicate at input {index} panicked: {reason}")]
+    Panic {
+        /// The index of the input
+        index: usize,
+        /// The panic reason
+        reason: PanicReason,
+    },
+    /// The interpreter failed to execute the predicate, e.g. because the predicate
+    /// attempted to access the storage
+    #[display(fmt = "Predicate at input {index} failed to execute: {error}")]
+    Interpreter {
+        /// The index of the input
+        index: usize,
+        /// The error of the interpreter
+        error: Box<InterpreterError<predicate::PredicateStorageError>>,
+    },
+    /// The task checking the predicate panicked
+    #[display(fmt = "The task checking the predicate panicked")]
+    TaskPanicked,
+    /// The predicate bytecode of the input failed the static check
+    #[display(fmt = "Predicate at input {index} failed the static check: {issue}")]
+    StaticCheck {
+        /// The index of the input
+        index: usize,
+        /// The first issue found in the predicate bytecode
+        issue: StaticIssue,
+    },
 }
 
-impl From<InterpreterError<predicate::PredicateStorageError>>
-    for PredicateVerificationFailed
-{
-    fn from(error: InterpreterError<predicate::PredicateStorageError>) -> Self {
+impl PredicateVerificationFailed {
+    /// Converts the `error` of the interpreter executing the predicate of the input
+    /// `index` with the `available_gas`.
+    pub fn from_interpreter_error(
+        index: usize,
+        available_gas: Word,
+        error: InterpreterError<predicate::PredicateStorageError>,
+    ) -> Self {
         match error {
             error if error.panic_reason() == Some(PanicReason::OutOfGas) => {
-                PredicateVerificationFailed::OutOfGas
+                Self::OutOfGas {
+                    index,
+                    available_gas,
+                }
             }
-            InterpreterError::Panic(reason) => PredicateVerificationFailed::Panic(reason),
-            InterpreterError::PanicInstruction(result) => {
-                PredicateVerificationFailed::PanicInstruction(result)
+            InterpreterError::Panic(reason) => Self::Panic { index, reason },
+            InterpreterError::PanicInstruction(instruction) => {
+                Self::PanicInstruction { index, instruction }
             }
-            InterpreterError::Bug(bug) => PredicateVerificationFailed::Bug(bug),
-            InterpreterError::Storage(_) => PredicateVerificationFailed::Storage,
-            _ => PredicateVerificationFailed::False,
+            InterpreterError::Bug(bug) => Self::Bug { index, bug },
+            error => Self::Interpreter {
+                index,
+                error: Box::new(error),
+            },
         }
     }
-}
 
-impl From<Bug> for PredicateVerificationFailed {
-    fn from(bug: Bug) -> Self {
-        Self::Bug(bug)
+    /// The index of the input whose predicate failed, or `None` if the failure
+    /// concerns the whole transaction.
+    pub fn input_index(&self) -> Option<usize> {
+        match self {
+            Self::GasMismatch { index, .. }
+            | Self::OutOfGas { index, .. }
+            | Self::PredicateLength { index, .. }
+            | Self::PredicateDataLength { index, .. }
+            | Self::InvalidOwner { index }
+            | Self::False { index, .. }
+            | Self::GasNotSpecified { index }
+            | Self::Bug { index, .. }
+            | Self::PanicInstruction { index, .. }
+            | Self::Panic { index, .. }
+            | Self::Interpreter { index, .. }
+            | Self::StaticCheck { index, .. } => Some(*index),
+            Self::TransactionExceedsTotalGasAllowance(_)
+            | Self::GasOverflow
+            | Self::TaskPanicked => None,
+        }
     }
 }
 
-impl From<PanicReason> for PredicateVerificationFailed {
-    fn from(reason: PanicReason) -> Self {
-        Self::Panic(reason)
+#[cfg(feature = "std")]
+impl std::error::Error for PredicateVerificationFailed {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Interpreter { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
     }
 }
 
-impl From<PanicOrBug> for PredicateVerificationFailed {
-    fn from(err: PanicOrBug) -> Self {
-        match err {
-            PanicOrBug::Panic(reason) => Self::from(reason),
-            PanicOrBug::Bug(bug) => Self::Bug(bug),
-        }
-    }
+#[cfg(feature = "std")]
+impl<StorageError> std::error::Error for InterpreterError<StorageError> where
+    StorageError: fmt::Debug + fmt::Display
+{
 }
 
 /// Traceable bug variants
@@ -377,6 +689,18 @@ pub enum BugVariant {
         message = "The witness subsection index is higher than the total number of parts."
     )]
     NextSubsectionIndexIsHigherThanTotalNumberOfParts,
+
+    /// The reverted call frame has no checkpoint in the storage journal.
+    #[strum(
+        message = "The call frames are reverted only if the journal has a checkpoint for each of them."
+    )]
+    CallFrameCheckpointMissing,
+
+    /// The value restored by reverting a call frame doesn't fit the current state.
+    #[strum(
+        message = "Reverting a call frame restores the values replaced after its checkpoint, so they always fit."
+    )]
+    CallFrameUndoMismatch,
 }
 
 impl fmt::Display for BugVariant {
@@ -555,7 +879,10 @@ pub type IoResult<T, S> = Result<T, RuntimeError<S>>;
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use super::*;
+    use test_case::test_case;
 
     #[test]
     fn bug_report_message() {
@@ -566,4 +893,166 @@ mod tests {
         assert!(text.contains("ContextGasOverflow"));
         assert!(text.contains("Test message"));
     }
+
+    #[test_case(
+        PredicateVerificationFailed::GasMismatch { index: 3, gas_used: 1200, available_gas: 5000 },
+        Some(3),
+        "Predicate at input 3 consumed 1200 of 5000 gas, but must consume all of its `predicate_gas_used`";
+        "gas mismatch"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::OutOfGas { index: 3, available_gas: 5000 },
+        Some(3),
+        "Predicate at input 3 ran out of 5000 gas";
+        "out of gas"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::PredicateLength { index: 3, actual: 20, limit: 10 },
+        Some(3),
+        "Predicate at input 3 has length 20, exceeding the limit 10";
+        "predicate length"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::PredicateDataLength { index: 3, actual: 20, limit: 10 },
+        Some(3),
+        "Predicate data at input 3 has length 20, exceeding the limit 10";
+        "predicate data length"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::InvalidOwner { index: 3 },
+        Some(3),
+        "Owner of input 3 doesn't match the root of its predicate";
+        "invalid owner"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::False { index: 3, gas_used: 1200, available_gas: 5000 },
+        Some(3),
+        "Predicate at input 3 returned false after consuming 1200 of 5000 gas";
+        "false"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::GasNotSpecified { index: 3 },
+        Some(3),
+        "Predicate gas of input 3 wasn't specified before execution";
+        "gas not specified"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::TransactionExceedsTotalGasAllowance(5000),
+        None,
+        "Transaction requires 5000 gas, exceeding the total gas allowance per transaction";
+        "exceeds total gas allowance"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::GasOverflow,
+        None,
+        "Cumulative gas computation overflowed the u64 accumulator";
+        "gas overflow"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::PanicInstruction {
+            index: 3,
+            instruction: PanicInstruction::error(
+                PanicReason::MemoryOverflow,
+                fuel_asm::op::noop().into(),
+            ),
+        },
+        Some(3),
+        "Predicate at input 3 panicked: PanicInstruction { reason: MemoryOverflow, instruction: NOOP (bytes: 47 00 00 00) }";
+        "panic instruction"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::Panic { index: 3, reason: PanicReason::PredicateReturnedNonOne },
+        Some(3),
+        "Predicate at input 3 panicked: PredicateReturnedNonOne";
+        "panic"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::Interpreter {
+            index: 3,
+            error: Box::new(InterpreterError::Storage(
+                predicate::PredicateStorageError::UnsupportedStorageOperation.into(),
+            )),
+        },
+        Some(3),
+        "Predicate at input 3 failed to execute: Storage error: Storage operation is unavailable in predicate context";
+        "interpreter"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::TaskPanicked,
+        None,
+        "The task checking the predicate panicked";
+        "task panicked"
+    )]
+    #[test_case(
+        PredicateVerificationFailed::StaticCheck {
+            index: 3,
+            issue: StaticIssue::InvalidInstruction { offset: 8 },
+        },
+        Some(3),
+        "Predicate at input 3 failed the static check: Invalid instruction at offset 8";
+        "static check"
+    )]
+    fn predicate_verification_failed__message_and_input_index(
+        error: PredicateVerificationFailed,
+        expected_index: Option<usize>,
+        expected_message: &str,
+    ) {
+        assert_eq!(error.input_index(), expected_index);
+        assert_eq!(error.to_string(), expected_message);
+    }
+
+    #[test]
+    fn predicate_verification_failed__bug__message_and_input_index() {
+        // Given
+        let bug = Bug::new(BugVariant::GlobalGasUnderflow);
+
+        // When
+        let error = PredicateVerificationFailed::Bug {
+            index: 3,
+            bug: bug.clone(),
+        };
+
+        // Then
+        assert_eq!(error.input_index(), Some(3));
+        assert_eq!(
+            error.to_string(),
+            format!("Predicate at input 3 reached an invalid state: {bug}")
+        );
+    }
+
+    #[test]
+    fn predicate_verification_failed__interpreter_error__is_the_source() {
+        // Given
+        let storage_error =
+            predicate::PredicateStorageError::StorageError("missing blob".to_string());
+
+        // When
+        let error = PredicateVerificationFailed::from_interpreter_error(
+            3,
+            5000,
+            InterpreterError::Storage(storage_error.clone().into()),
+        );
+
+        // Then
+        use std::error::Error;
+        let source = error.source().expect("the interpreter error is the source");
+        assert_eq!(source.to_string(), "Storage error: missing blob");
+        let source = source
+            .downcast_ref::<InterpreterError<predicate::PredicateStorageError>>()
+            .expect("the source is the interpreter error");
+        assert_eq!(source, &InterpreterError::Storage(storage_error.into()));
+        assert_eq!(error.input_index(), Some(3));
+    }
+
+    #[test_case(PanicReason::OutOfGas => PredicateVerificationFailed::OutOfGas { index: 3, available_gas: 5000 }; "out of gas")]
+    #[test_case(PanicReason::MemoryOverflow => PredicateVerificationFailed::Panic { index: 3, reason: PanicReason::MemoryOverflow }; "panic")]
+    fn predicate_verification_failed__from_interpreter_error__keeps_the_index(
+        reason: PanicReason,
+    ) -> PredicateVerificationFailed {
+        PredicateVerificationFailed::from_interpreter_error(
+            3,
+            5000,
+            InterpreterError::Panic(reason),
+        )
+    }
 }
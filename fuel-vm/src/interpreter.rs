@@ -10,7 +10,6 @@ use crate::{
     consts::*,
     context::Context,
     error::SimpleResult,
-    state::Debugger,
 };
 use alloc::vec::Vec;
 use core::{
@@ -18,6 +17,9 @@ use core::{
     ops::Index,
 };
 
+#[cfg(feature = "debugger")]
+use crate::state::Debugger;
+
 use fuel_asm::{
     Flags,
     PanicReason,
@@ -53,9 +55,12 @@ mod alu;
 mod balances;
 mod blob;
 mod blockchain;
+mod code_cache;
 mod constructors;
 pub mod contract;
+mod contract_info;
 mod crypto;
+mod curves;
 pub mod diff;
 mod executors;
 mod flow;
@@ -67,7 +72,10 @@ mod memory;
 mod metadata;
 mod post_execution;
 mod receipts;
+mod storage_journal;
+mod storage_write;
 
+#[cfg(feature = "debugger")]
 mod debug;
 mod ecal;
 
@@ -77,8 +85,13 @@ use crate::profiler::Profiler;
 use crate::profiler::InstructionLocation;
 
 pub use balances::RuntimeBalances;
+pub use code_cache::CodeCache;
+use contract_info::ContractInfoCache;
 pub use ecal::{
+    CheckedEcalHandler,
+    EcalContext,
     EcalHandler,
+    EcalOutcome,
     PredicateErrorEcal,
 };
 pub use executors::predicates;
@@ -86,7 +99,10 @@ pub use memory::{
     Memory,
     MemoryInstance,
     MemoryRange,
+    MemoryStats,
 };
+use storage_journal::StorageJournal;
+use storage_write::StorageWriteCounter;
 
 use crate::checked_transaction::{
     CreateCheckedMetadata,
@@ -122,12 +138,16 @@ pub struct Interpreter<M, S, Tx = (), Ecal = NotSupportedEcal> {
     registers: [Word; VM_REGISTER_COUNT],
     memory: M,
     frames: Vec<CallFrame>,
+    /// The context gas handed back to the caller by each call that returned, in the
+    /// order of the returns.
+    returned_call_gas: Vec<Word>,
     receipts: ReceiptsCtx,
     tx: Tx,
     initial_balances: InitialBalances,
     input_contracts: alloc::collections::BTreeSet<ContractId>,
     input_contracts_index_to_output_index: alloc::collections::BTreeMap<u16, u16>,
     storage: S,
+    #[cfg(feature = "debugger")]
     debugger: Debugger,
     context: Context,
     balances: RuntimeBalances,
@@ -137,6 +157,15 @@ pub struct Interpreter<M, S, Tx = (), Ecal = NotSupportedEcal> {
     /// `append_panic_receipt` and is `PanicContext::None` after consumption.
     panic_context: PanicContext,
     ecal_state: Ecal,
+    /// The contracts bytecode cached across the transactions.
+    code_cache: CodeCache,
+    /// The sizes and the code roots of the contracts looked up by the current
+    /// transaction.
+    contract_info: ContractInfoCache,
+    /// The bytes written to the storage by the current transaction.
+    storage_written: StorageWriteCounter,
+    /// The values replaced by the writes of the open call frames.
+    storage_journal: StorageJournal,
 }
 
 /// Interpreter parameters
@@ -160,6 +189,31 @@ pub struct InterpreterParams {
     pub fee_params: FeeParameters,
     /// Base Asset ID
     pub base_asset_id: AssetId,
+    /// The number of receipts to reserve space for before executing a transaction.
+    /// Avoids reallocations of the receipts during log-heavy transactions.
+    pub receipts_capacity_hint: usize,
+    /// Panic on `CALL` forwarding more gas than the remaining context gas instead
+    /// of forwarding all of it
+    pub strict_call_gas: bool,
+    /// Round the `ALOC` allocation sizes up to a multiple of the word size, keeping
+    /// `$hp` word-aligned
+    pub word_aligned_heap: bool,
+    /// Allow `RETD`, `LOGD`, `SMO`, `S256`, `K256` and `MEQ` to read the empty range
+    /// at any address up to the end of the memory
+    pub allow_empty_reads: bool,
+    /// Panic on the relative jumps targeting outside of the code of the current
+    /// context
+    pub relative_jumps_within_code: bool,
+    /// Revert only the call frame on `RVRT` and the panics inside of it, returning to
+    /// the caller instead of terminating the transaction
+    pub call_frame_reverts: bool,
+    /// Panic with `ExpectedOutputVariable` and `DoubleSpendOutput` on `TRO` to the
+    /// output that isn't `Output::Variable` or is already filled, instead of
+    /// `OutputNotFound`
+    pub precise_output_panics: bool,
+    /// The maximum number of bytes a transaction can write to the storage, or `None`
+    /// for no limit. It bounds the state growth independently of the gas costs.
+    pub max_storage_write_bytes: Option<u64>,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -176,6 +230,15 @@ impl Default for InterpreterParams {
             chain_id: ChainId::default(),
             fee_params: FeeParameters::default(),
             base_asset_id: Default::default(),
+            receipts_capacity_hint: 0,
+            strict_call_gas: fuel_tx::TxParameters::DEFAULT.strict_call_gas(),
+            word_aligned_heap: fuel_tx::TxParameters::DEFAULT.word_aligned_heap(),
+            allow_empty_reads: fuel_tx::TxParameters::DEFAULT.allow_empty_reads(),
+            relative_jumps_within_code: fuel_tx::TxParameters::DEFAULT
+                .relative_jumps_within_code(),
+            call_frame_reverts: fuel_tx::TxParameters::DEFAULT.call_frame_reverts(),
+            precise_output_panics: fuel_tx::TxParameters::DEFAULT.precise_output_panics(),
+            max_storage_write_bytes: None,
         }
     }
 }
@@ -194,6 +257,14 @@ impl InterpreterParams {
             chain_id: params.chain_id,
             fee_params: params.fee_params,
             base_asset_id: params.base_asset_id,
+            receipts_capacity_hint: 0,
+            strict_call_gas: params.strict_call_gas,
+            word_aligned_heap: params.word_aligned_heap,
+            allow_empty_reads: params.allow_empty_reads,
+            relative_jumps_within_code: params.relative_jumps_within_code,
+            call_frame_reverts: params.call_frame_reverts,
+            precise_output_panics: params.precise_output_panics,
+            max_storage_write_bytes: None,
         }
     }
 }
@@ -239,7 +310,17 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         self.frames.as_slice()
     }
 
+    pub(crate) fn returned_call_gas(&self) -> &[Word] {
+        self.returned_call_gas.as_slice()
+    }
+
+    /// The current context of the execution.
+    pub const fn context(&self) -> &Context {
+        &self.context
+    }
+
     /// Debug handler
+    #[cfg(feature = "debugger")]
     pub const fn debugger(&self) -> &Debugger {
         &self.debugger
     }
@@ -305,11 +386,22 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         self.interpreter_params.chain_id
     }
 
+    /// The parameters of the interpreter.
+    pub fn interpreter_params(&self) -> &InterpreterParams {
+        &self.interpreter_params
+    }
+
     /// Receipts generated by a transaction execution.
     pub fn receipts(&self) -> &[Receipt] {
         self.receipts.as_ref().as_slice()
     }
 
+    /// The number of receipts the interpreter can hold without reallocating. The
+    /// allocation is reused by the following transactions.
+    pub fn receipts_capacity(&self) -> usize {
+        self.receipts.capacity()
+    }
+
     /// Compute current receipts root
     pub fn compute_receipts_root(&self) -> Bytes32 {
         self.receipts.root()
@@ -321,6 +413,23 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         &mut self.receipts
     }
 
+    /// The cache of the contracts bytecode.
+    pub fn code_cache(&self) -> &CodeCache {
+        &self.code_cache
+    }
+
+    /// Mutable access to the cache of the contracts bytecode, e.g. to release the
+    /// memory of the entries after reverting the storage.
+    pub fn code_cache_mut(&mut self) -> &mut CodeCache {
+        &mut self.code_cache
+    }
+
+    /// The number of bytes written to the storage by the current transaction, which
+    /// are limited by the [`InterpreterParams::max_storage_write_bytes`].
+    pub fn storage_bytes_written(&self) -> u64 {
+        self.storage_written.written()
+    }
+
     pub(crate) fn contract_id(&self) -> Option<ContractId> {
         self.frames.last().map(|frame| *frame.to())
     }
@@ -490,51 +599,14 @@ pub trait ExecutableTransaction:
             .refund_fee(gas_costs, fee_params, used_gas, gas_price)
             .ok_or(ValidityError::GasCostsCoinsOverflow)?;
 
-        self.outputs_mut().iter_mut().try_for_each(|o| match o {
-            // If revert, set base asset to initial balance and refund unused gas
-            //
-            // Note: the initial balance deducts the gas limit from base asset
-            Output::Change {
-                asset_id, amount, ..
-            } if revert && asset_id == base_asset_id => initial_balances.non_retryable
-                [base_asset_id]
-                .checked_add(gas_refund)
-                .map(|v| *amount = v)
-                .ok_or(ValidityError::BalanceOverflow),
-
-            // If revert, reset any non-base asset to its initial balance
-            Output::Change {
-                asset_id, amount, ..
-            } if revert => {
-                *amount = initial_balances.non_retryable[asset_id];
-                Ok(())
-            }
-
-            // The change for the base asset will be the available balance + unused gas
-            Output::Change {
-                asset_id, amount, ..
-            } if asset_id == base_asset_id => balances[asset_id]
-                .checked_add(gas_refund)
-                .map(|v| *amount = v)
-                .ok_or(ValidityError::BalanceOverflow),
-
-            // Set changes to the remainder provided balances
-            Output::Change {
-                asset_id, amount, ..
-            } => {
-                *amount = balances[asset_id];
-                Ok(())
-            }
-
-            // If revert, zeroes all variable output values
-            Output::Variable { amount, .. } if revert => {
-                *amount = 0;
-                Ok(())
-            }
-
-            // Other outputs are unaffected
-            _ => Ok(()),
-        })
+        crate::util::outputs::finalize_outputs(
+            self.outputs_mut(),
+            revert,
+            gas_refund,
+            initial_balances,
+            balances,
+            base_asset_id,
+        )
     }
 }
 
@@ -610,6 +682,7 @@ impl ExecutableTransaction for Blob {
 
 /// The initial balances of the transaction.
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitialBalances {
     /// See [`NonRetryableFreeBalances`].
     pub non_retryable: NonRetryableFreeBalances,
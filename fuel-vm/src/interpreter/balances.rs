@@ -128,7 +128,6 @@ impl RuntimeBalances {
         Ok(value)
     }
 
-    #[cfg(test)]
     /// Attempt to add the balance of an asset, updating the VM memory in the appropriate
     /// offset
     ///
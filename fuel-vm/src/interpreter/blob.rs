@@ -3,10 +3,17 @@ use fuel_asm::{
     Word,
 };
 use fuel_tx::PanicReason;
-use fuel_types::BlobId;
+use fuel_types::{
+    BlobId,
+    Bytes32,
+};
 
 use crate::{
-    error::IoResult,
+    error::{
+        IoResult,
+        StorageErrorContext,
+        StorageOp,
+    },
     interpreter::{
         contract::blob_size,
         memory::copy_from_storage_zero_fill,
@@ -87,6 +94,7 @@ where
             blob_offset,
             blob_len,
             PanicReason::BlobNotFound,
+            StorageErrorContext::new(StorageOp::BlobRead).key(Bytes32::new(*blob_id)),
         )?;
 
         Ok(inc_pc(self.registers.pc_mut())?)
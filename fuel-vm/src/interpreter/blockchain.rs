@@ -8,14 +8,22 @@ use crate::{
         IoResult,
         RuntimeError,
         SimpleResult,
+        StorageErrorContext,
+        StorageOp,
     },
     interpreter::{
+        code_cache::{
+            CachedContractCode,
+            CodeCache,
+            ContractCode,
+        },
         contract::{
             balance,
             balance_decrease,
             blob_size,
             contract_size,
         },
+        contract_info::ContractInfoCache,
         gas::{
             dependent_gas_charge_without_base,
             gas_charge,
@@ -30,9 +38,12 @@ use crate::{
         },
         memory::{
             copy_from_storage_zero_fill,
+            MemoryRange,
             OwnershipRegisters,
         },
         receipts::ReceiptsCtx,
+        storage_journal::StorageJournal,
+        storage_write::StorageWriteCounter,
         ExecutableTransaction,
         InputContracts,
         Interpreter,
@@ -44,7 +55,6 @@ use crate::{
     storage::{
         BlobData,
         ContractsAssetsStorage,
-        ContractsRawCode,
         ContractsStateData,
         InterpreterStorage,
     },
@@ -54,7 +64,6 @@ use fuel_asm::{
     Imm06,
     PanicReason,
 };
-use fuel_storage::StorageSize;
 use fuel_tx::{
     consts::BALANCE_ENTRY_SIZE,
     BlobId,
@@ -63,10 +72,7 @@ use fuel_tx::{
     Receipt,
 };
 use fuel_types::{
-    bytes::{
-        self,
-        padded_len_word,
-    },
+    bytes::padded_len_word,
     Address,
     AssetId,
     BlockHeight,
@@ -133,6 +139,7 @@ where
             context: &self.context,
             profiler: &mut self.profiler,
             storage: &mut self.storage,
+            code_cache: &mut self.code_cache,
             contract_max_size,
             input_contracts: InputContracts::new(
                 &self.input_contracts,
@@ -165,6 +172,8 @@ where
             context: &self.context,
             memory: self.memory.as_ref(),
             receipts: &mut self.receipts,
+            storage_written: &mut self.storage_written,
+            storage_journal: &mut self.storage_journal,
             fp: fp.as_ref(),
             pc,
             is: is.as_ref(),
@@ -191,6 +200,8 @@ where
             memory: self.memory.as_ref(),
             receipts: &mut self.receipts,
             profiler: &mut self.profiler,
+            storage_written: &mut self.storage_written,
+            storage_journal: &mut self.storage_journal,
             new_storage_gas_per_byte,
             cgas,
             ggas,
@@ -229,6 +240,7 @@ where
                 &mut self.panic_context,
             ),
             storage: &mut self.storage,
+            code_cache: &mut self.code_cache,
             profiler: &mut self.profiler,
             current_contract,
             owner,
@@ -285,6 +297,7 @@ where
         CodeRootCtx {
             memory: self.memory.as_mut(),
             storage: &mut self.storage,
+            contract_info: &mut self.contract_info,
             gas_cost,
             profiler: &mut self.profiler,
             input_contracts: InputContracts::new(
@@ -322,6 +335,8 @@ where
         let input = CodeSizeCtx {
             memory: self.memory.as_mut(),
             storage: &mut self.storage,
+            code_cache: &mut self.code_cache,
+            contract_info: &mut self.contract_info,
             gas_cost,
             profiler: &mut self.profiler,
             input_contracts: InputContracts::new(
@@ -351,10 +366,21 @@ where
         let Self {
             ref mut storage,
             ref memory,
+            ref mut storage_written,
+            ref mut storage_journal,
             ..
         } = self;
 
-        state_clear_qword(&contract_id?, storage, memory.as_ref(), pc, result, input)
+        state_clear_qword(
+            &contract_id?,
+            storage,
+            memory.as_ref(),
+            storage_written,
+            storage_journal,
+            pc,
+            result,
+            input,
+        )
     }
 
     pub(crate) fn state_read_word(
@@ -456,6 +482,8 @@ where
                 memory: memory.as_ref(),
                 context,
                 profiler: &mut self.profiler,
+                storage_written: &mut self.storage_written,
+                storage_journal: &mut self.storage_journal,
                 new_storage_gas_per_byte,
                 current_contract: self.frames.last().map(|frame| frame.to()).copied(),
                 cgas,
@@ -504,6 +532,8 @@ where
             storage,
             memory.as_ref(),
             &mut self.profiler,
+            &mut self.storage_written,
+            &mut self.storage_journal,
             new_storage_per_byte,
             self.frames.last().map(|frame| frame.to()).copied(),
             cgas,
@@ -535,6 +565,7 @@ where
     ) -> IoResult<(), S::DataError> {
         let base_asset_id = self.interpreter_params.base_asset_id;
         let max_message_data_length = self.max_message_data_length();
+        let allow_empty_reads = self.interpreter_params.allow_empty_reads;
         let (SystemRegisters { fp, pc, .. }, _) = split_registers(&mut self.registers);
         let input = MessageOutputCtx {
             base_asset_id,
@@ -543,6 +574,8 @@ where
             receipts: &mut self.receipts,
             balances: &mut self.balances,
             storage: &mut self.storage,
+            storage_written: &mut self.storage_written,
+            storage_journal: &mut self.storage_journal,
             current_contract: self.frames.last().map(|frame| frame.to()).copied(),
             fp: fp.as_ref(),
             pc,
@@ -550,6 +583,7 @@ where
             msg_data_ptr: b,
             msg_data_len: c,
             amount_coins_to_send: d,
+            allow_empty_reads,
         };
         input.message_output()
     }
@@ -562,6 +596,7 @@ struct LoadContractCodeCtx<'vm, S> {
     profiler: &'vm mut Profiler,
     input_contracts: InputContracts<'vm>,
     storage: &'vm S,
+    code_cache: &'vm mut CodeCache,
     gas_cost: DependentCost,
     cgas: RegMut<'vm, CGAS>,
     ggas: RegMut<'vm, GGAS>,
@@ -577,6 +612,29 @@ impl<S> LoadContractCodeCtx<'_, S>
 where
     S: InterpreterStorage,
 {
+    /// Checks that the code of the current call frame extended by `length` bytes of
+    /// the loaded code doesn't exceed the `contract_max_size`. Outside of a call frame,
+    /// only the loaded code counts towards the limit.
+    ///
+    /// Used by the blob and memory modes of `LDC`. The contract mode only checks the
+    /// length of the loaded code.
+    fn check_code_size(&self, length: Word) -> Result<(), PanicReason> {
+        let current_code_size = if self.context.is_internal() {
+            let code_size_ptr =
+                (*self.fp).saturating_add(CallFrame::code_size_offset() as Word);
+            let code_size = Word::from_be_bytes(self.memory.read_bytes(code_size_ptr)?);
+            padded_len_word(code_size).ok_or(PanicReason::MemoryOverflow)?
+        } else {
+            0
+        };
+
+        if current_code_size.saturating_add(length) > self.contract_max_size {
+            return Err(PanicReason::ContractMaxSize)
+        }
+
+        Ok(())
+    }
+
     /// Loads contract ID pointed by `a`, and then for that contract,
     /// copies `c` bytes from it starting from offset `b` into the stack.
     /// ```txt
@@ -625,7 +683,11 @@ where
             current_contract,
             profiler: self.profiler,
         };
-        let contract_len = contract_size(&self.storage, &contract_id)?;
+        let mut code = CachedContractCode {
+            storage: self.storage,
+            cache: self.code_cache,
+        };
+        let contract_len = code.code_size(&contract_id)?;
         let charge_len = core::cmp::max(contract_len as u64, length);
         dependent_gas_charge_without_base(
             self.cgas,
@@ -646,16 +708,14 @@ where
         *self.sp = new_sp;
         *self.ssp = new_sp;
 
-        copy_from_storage_zero_fill::<ContractsRawCode, _>(
+        code.copy_code_zero_fill(
             self.memory,
             owner,
-            self.storage,
             region_start,
             length,
             &contract_id,
             contract_offset,
             contract_len,
-            PanicReason::ContractNotFound,
         )?;
 
         // Update frame code size, if we have a stack frame (i.e. fp > 0)
@@ -707,7 +767,10 @@ where
 
         let current_contract = current_contract(self.context, self.fp, self.memory)?;
 
-        let length = bytes::padded_len_word(length_unpadded).unwrap_or(Word::MAX);
+        let length =
+            padded_len_word(length_unpadded).ok_or(PanicReason::MemoryOverflow)?;
+
+        self.check_code_size(length)?;
 
         let blob_len = blob_size(self.storage, &blob_id)?;
 
@@ -749,6 +812,7 @@ where
             blob_offset,
             blob_len,
             PanicReason::BlobNotFound,
+            StorageErrorContext::new(StorageOp::BlobRead).key(Bytes32::new(*blob_id)),
         )?;
 
         // Update frame code size, if we have a stack frame (i.e. fp > 0)
@@ -802,9 +866,12 @@ where
 
         let current_contract = current_contract(self.context, self.fp, self.memory)?;
 
-        let length = bytes::padded_len_word(length_unpadded).unwrap_or(Word::MAX);
+        let length =
+            padded_len_word(length_unpadded).ok_or(PanicReason::MemoryOverflow)?;
         let length_padding = length.saturating_sub(length_unpadded);
 
+        self.check_code_size(length)?;
+
         // Fetch the storage blob
         let profiler = ProfileGas {
             pc: self.pc.as_ref(),
@@ -869,6 +936,8 @@ struct BurnCtx<'vm, S> {
     context: &'vm Context,
     memory: &'vm MemoryInstance,
     receipts: &'vm mut ReceiptsCtx,
+    storage_written: &'vm mut StorageWriteCounter,
+    storage_journal: &'vm mut StorageJournal,
     fp: Reg<'vm, FP>,
     pc: RegMut<'vm, PC>,
     is: Reg<'vm, IS>,
@@ -888,9 +957,19 @@ where
             .checked_sub(a)
             .ok_or(PanicReason::NotEnoughBalance)?;
 
+        self.storage_written.charge(WORD_SIZE as u64)?;
+        self.storage_journal
+            .record_balance(self.storage, &contract_id, &asset_id)?;
         self.storage
             .contract_asset_id_balance_insert(&contract_id, &asset_id, balance)
-            .map_err(RuntimeError::Storage)?;
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ContractBalanceWrite)
+                        .contract(contract_id)
+                        .key(Bytes32::new(*asset_id)),
+                )
+            })?;
 
         let receipt = Receipt::burn(sub_id, contract_id, a, *self.pc, *self.is);
 
@@ -906,6 +985,8 @@ struct MintCtx<'vm, S> {
     memory: &'vm MemoryInstance,
     profiler: &'vm mut Profiler,
     receipts: &'vm mut ReceiptsCtx,
+    storage_written: &'vm mut StorageWriteCounter,
+    storage_journal: &'vm mut StorageJournal,
     new_storage_gas_per_byte: Word,
     cgas: RegMut<'vm, CGAS>,
     ggas: RegMut<'vm, GGAS>,
@@ -926,10 +1007,20 @@ where
         let balance = balance(self.storage, &contract_id, &asset_id)?;
         let balance = balance.checked_add(a).ok_or(PanicReason::BalanceOverflow)?;
 
+        self.storage_written.charge(WORD_SIZE as u64)?;
+        self.storage_journal
+            .record_balance(self.storage, &contract_id, &asset_id)?;
         let old_value = self
             .storage
             .contract_asset_id_balance_replace(&contract_id, &asset_id, balance)
-            .map_err(RuntimeError::Storage)?;
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ContractBalanceWrite)
+                        .contract(contract_id)
+                        .key(Bytes32::new(*asset_id)),
+                )
+            })?;
 
         if old_value.is_none() {
             // New data was written, charge gas for it
@@ -959,6 +1050,7 @@ struct CodeCopyCtx<'vm, S> {
     memory: &'vm mut MemoryInstance,
     input_contracts: InputContracts<'vm>,
     storage: &'vm S,
+    code_cache: &'vm mut CodeCache,
     profiler: &'vm mut Profiler,
     current_contract: Option<ContractId>,
     owner: OwnershipRegisters,
@@ -983,12 +1075,21 @@ where
     where
         S: InterpreterStorage,
     {
-        let contract_id = ContractId::from(self.memory.read_bytes(contract_id_addr)?);
-
-        self.memory.write(self.owner, dst_addr, length)?;
+        let contract_id_range =
+            MemoryRange::checked_new(contract_id_addr, ContractId::LEN as Word)?;
+        let contract_id =
+            ContractId::from(self.memory.read_bytes(contract_id_range.start())?);
+
+        let dst_range = MemoryRange::checked_new(dst_addr, length)?;
+        self.memory
+            .write(self.owner, dst_range.start(), dst_range.len())?;
         self.input_contracts.check(&contract_id)?;
 
-        let contract_len = contract_size(&self.storage, &contract_id)?;
+        let mut code = CachedContractCode {
+            storage: self.storage,
+            cache: self.code_cache,
+        };
+        let contract_len = code.code_size(&contract_id)?;
         let charge_len = core::cmp::max(contract_len as u64, length);
         let profiler = ProfileGas {
             pc: self.pc.as_ref(),
@@ -1004,16 +1105,14 @@ where
             charge_len,
         )?;
 
-        copy_from_storage_zero_fill::<ContractsRawCode, _>(
+        code.copy_code_zero_fill(
             self.memory,
             self.owner,
-            self.storage,
             dst_addr,
             length,
             &contract_id,
             contract_offset,
             contract_len,
-            PanicReason::ContractNotFound,
         )?;
 
         Ok(inc_pc(self.pc)?)
@@ -1031,7 +1130,9 @@ pub(crate) fn block_hash<S: InterpreterStorage>(
     let height = u32::try_from(b)
         .map_err(|_| PanicReason::InvalidBlockHeight)?
         .into();
-    let hash = storage.block_hash(height).map_err(RuntimeError::Storage)?;
+    let hash = storage.block_hash(height).map_err(|e| {
+        RuntimeError::storage(e, StorageErrorContext::new(StorageOp::BlockInfoRead))
+    })?;
 
     memory.write_bytes(owner, a, *hash)?;
 
@@ -1061,7 +1162,9 @@ pub(crate) fn coinbase<S: InterpreterStorage>(
     pc: RegMut<PC>,
     a: Word,
 ) -> IoResult<(), S::DataError> {
-    let coinbase = storage.coinbase().map_err(RuntimeError::Storage)?;
+    let coinbase = storage.coinbase().map_err(|e| {
+        RuntimeError::storage(e, StorageErrorContext::new(StorageOp::BlockInfoRead))
+    })?;
     memory.write_bytes(owner, a, *coinbase)?;
     inc_pc(pc)?;
     Ok(())
@@ -1069,6 +1172,7 @@ pub(crate) fn coinbase<S: InterpreterStorage>(
 
 struct CodeRootCtx<'vm, S> {
     storage: &'vm S,
+    contract_info: &'vm mut ContractInfoCache,
     memory: &'vm mut MemoryInstance,
     gas_cost: DependentCost,
     profiler: &'vm mut Profiler,
@@ -1086,13 +1190,20 @@ impl<S> CodeRootCtx<'_, S> {
     where
         S: InterpreterStorage,
     {
-        self.memory.write_noownerchecks(a, Bytes32::LEN)?;
+        let root_range = MemoryRange::checked_new(a, Bytes32::LEN as Word)?;
+        self.memory
+            .write_noownerchecks(root_range.start(), root_range.len())?;
 
-        let contract_id = ContractId::new(self.memory.read_bytes(b)?);
+        let contract_id_range = MemoryRange::checked_new(b, ContractId::LEN as Word)?;
+        let contract_id =
+            ContractId::new(self.memory.read_bytes(contract_id_range.start())?);
 
         self.input_contracts.check(&contract_id)?;
 
-        let len = contract_size(self.storage, &contract_id)?;
+        let storage = self.storage;
+        let len = self
+            .contract_info
+            .code_size(&contract_id, || contract_size(storage, &contract_id))?;
         let profiler = ProfileGas {
             pc: self.pc.as_ref(),
             is: self.is,
@@ -1106,15 +1217,10 @@ impl<S> CodeRootCtx<'_, S> {
             self.gas_cost,
             len as u64,
         )?;
-        let root = self
-            .storage
-            .storage_contract(&contract_id)
-            .transpose()
-            .ok_or(PanicReason::ContractNotFound)?
-            .map_err(RuntimeError::Storage)?
-            .root();
+        let root = self.contract_info.code_root(self.storage, &contract_id)?;
 
-        self.memory.write_bytes(self.owner, a, *root)?;
+        self.memory
+            .write_bytes(self.owner, root_range.start(), *root)?;
 
         Ok(inc_pc(self.pc)?)
     }
@@ -1122,6 +1228,8 @@ impl<S> CodeRootCtx<'_, S> {
 
 struct CodeSizeCtx<'vm, S> {
     storage: &'vm S,
+    code_cache: &'vm mut CodeCache,
+    contract_info: &'vm mut ContractInfoCache,
     memory: &'vm mut MemoryInstance,
     gas_cost: DependentCost,
     profiler: &'vm mut Profiler,
@@ -1138,15 +1246,23 @@ impl<S> CodeSizeCtx<'_, S> {
         mut self,
         result: &mut Word,
         b: Word,
-    ) -> Result<(), RuntimeError<S::Error>>
+    ) -> IoResult<(), S::DataError>
     where
-        S: StorageSize<ContractsRawCode>,
+        S: InterpreterStorage,
     {
-        let contract_id = ContractId::new(self.memory.read_bytes(b)?);
+        let contract_id_range = MemoryRange::checked_new(b, ContractId::LEN as Word)?;
+        let contract_id =
+            ContractId::new(self.memory.read_bytes(contract_id_range.start())?);
 
         self.input_contracts.check(&contract_id)?;
 
-        let len = contract_size(self.storage, &contract_id)?;
+        let mut code = CachedContractCode {
+            storage: self.storage,
+            cache: self.code_cache,
+        };
+        let len = self
+            .contract_info
+            .code_size(&contract_id, || code.code_size(&contract_id))?;
         let profiler = ProfileGas {
             pc: self.pc.as_ref(),
             is: self.is,
@@ -1192,7 +1308,14 @@ pub(crate) fn state_read_word<S: InterpreterStorage>(
 
     let value = storage
         .contract_state(&contract, &key)
-        .map_err(RuntimeError::Storage)?
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractStateRead)
+                    .contract(contract)
+                    .key(key),
+            )
+        })?
         .map(|bytes| {
             Word::from_be_bytes(
                 bytes.as_ref().as_ref()[..8]
@@ -1212,6 +1335,8 @@ pub(crate) struct StateWriteWordCtx<'vm, S> {
     pub memory: &'vm MemoryInstance,
     pub context: &'vm Context,
     pub profiler: &'vm mut Profiler,
+    pub storage_written: &'vm mut StorageWriteCounter,
+    pub storage_journal: &'vm mut StorageJournal,
     pub new_storage_gas_per_byte: Word,
     pub current_contract: Option<ContractId>,
     pub cgas: RegMut<'vm, CGAS>,
@@ -1227,6 +1352,8 @@ pub(crate) fn state_write_word<S: InterpreterStorage>(
         memory,
         context,
         profiler,
+        storage_written,
+        storage_journal,
         new_storage_gas_per_byte,
         current_contract,
         cgas,
@@ -1245,9 +1372,18 @@ pub(crate) fn state_write_word<S: InterpreterStorage>(
     let mut value = Bytes32::zeroed();
     value.as_mut()[..WORD_SIZE].copy_from_slice(&c.to_be_bytes());
 
+    storage_written.charge(Bytes32::LEN as u64)?;
+    storage_journal.record_state(storage, &contract, &key, 1)?;
     let prev = storage
         .contract_state_replace(&contract, &key, value.as_ref())
-        .map_err(RuntimeError::Storage)?;
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractStateWrite)
+                    .contract(contract)
+                    .key(key),
+            )
+        })?;
 
     *created_new = prev.is_none() as Word;
 
@@ -1286,7 +1422,9 @@ pub(crate) fn timestamp<S: InterpreterStorage>(
         .then_some(())
         .ok_or(PanicReason::TransactionValidity)?;
 
-    *result = storage.timestamp(b).map_err(RuntimeError::Storage)?;
+    *result = storage.timestamp(b).map_err(|e| {
+        RuntimeError::storage(e, StorageErrorContext::new(StorageOp::BlockInfoRead))
+    })?;
 
     Ok(inc_pc(pc)?)
 }
@@ -1300,6 +1438,8 @@ where
     receipts: &'vm mut ReceiptsCtx,
     balances: &'vm mut RuntimeBalances,
     storage: &'vm mut S,
+    storage_written: &'vm mut StorageWriteCounter,
+    storage_journal: &'vm mut StorageJournal,
     current_contract: Option<ContractId>,
     fp: Reg<'vm, FP>,
     pc: RegMut<'vm, PC>,
@@ -1311,6 +1451,7 @@ where
     msg_data_len: Word,
     /// D
     amount_coins_to_send: Word,
+    allow_empty_reads: bool,
 }
 
 impl<S> MessageOutputCtx<'_, S>
@@ -1324,7 +1465,7 @@ where
 
         let msg_data = self
             .memory
-            .read(self.msg_data_ptr, self.msg_data_len)?
+            .read_range(self.msg_data_ptr, self.msg_data_len, self.allow_empty_reads)?
             .to_vec();
         let recipient = Address::new(self.memory.read_bytes(self.recipient_mem_address)?);
         let sender = Address::new(self.memory.read_bytes(*self.fp)?);
@@ -1332,6 +1473,12 @@ where
         // validations passed, perform the mutations
 
         if let Some(source_contract) = self.current_contract {
+            self.storage_written.charge(WORD_SIZE as u64)?;
+            self.storage_journal.record_balance(
+                self.storage,
+                &source_contract,
+                &self.base_asset_id,
+            )?;
             balance_decrease(
                 self.storage,
                 &source_contract,
@@ -1389,13 +1536,23 @@ fn state_read_qword<S: InterpreterStorage>(
     let contract_id = internal_contract(context, fp, memory)?;
     let num_slots = convert::to_usize(num_slots).ok_or(PanicReason::TooManySlots)?;
     let slots_len = Bytes32::LEN.saturating_mul(num_slots);
-    let origin_key = Bytes32::new(memory.read_bytes(origin_key_pointer)?);
-    let dst = memory.write(ownership_registers, destination_pointer, slots_len)?;
+    let origin_key_range =
+        MemoryRange::checked_new(origin_key_pointer, Bytes32::LEN as Word)?;
+    let origin_key = Bytes32::new(memory.read_bytes(origin_key_range.start())?);
+    let dst_range = MemoryRange::checked_new(destination_pointer, slots_len as Word)?;
+    let dst = memory.write(ownership_registers, dst_range.start(), dst_range.len())?;
 
     let mut all_set = true;
     let result: Vec<u8> = storage
         .contract_state_range(&contract_id, &origin_key, num_slots)
-        .map_err(RuntimeError::Storage)?
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractStateRead)
+                    .contract(contract_id)
+                    .key(origin_key),
+            )
+        })?
         .into_iter()
         .flat_map(|bytes| match bytes {
             Some(bytes) => bytes.into_owned(),
@@ -1430,6 +1587,8 @@ fn state_write_qword<'vm, S: InterpreterStorage>(
     storage: &mut S,
     memory: &MemoryInstance,
     profiler: &'vm mut Profiler,
+    storage_written: &'vm mut StorageWriteCounter,
+    storage_journal: &'vm mut StorageJournal,
     new_storage_gas_per_byte: Word,
     current_contract: Option<ContractId>,
     cgas: RegMut<'vm, CGAS>,
@@ -1442,16 +1601,24 @@ fn state_write_qword<'vm, S: InterpreterStorage>(
     let destination_key =
         Bytes32::new(memory.read_bytes(input.starting_storage_key_pointer)?);
 
+    let values_len = (Bytes32::LEN as Word).saturating_mul(input.num_slots);
     let values = memory
-        .read(
-            input.source_pointer,
-            (Bytes32::LEN as Word).saturating_mul(input.num_slots),
-        )?
+        .read(input.source_pointer, values_len)?
         .chunks_exact(Bytes32::LEN);
 
+    storage_written.charge(values_len)?;
+    storage_journal.record_state(storage, contract_id, &destination_key, values.len())?;
+
     let unset_count = storage
         .contract_state_insert_range(contract_id, &destination_key, values)
-        .map_err(RuntimeError::Storage)?;
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractStateWrite)
+                    .contract(*contract_id)
+                    .key(destination_key),
+            )
+        })?;
     *result_register = unset_count as Word;
 
     if unset_count > 0 {
@@ -1495,19 +1662,34 @@ impl StateClearQWord {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn state_clear_qword<S: InterpreterStorage>(
     contract_id: &ContractId,
     storage: &mut S,
     memory: &MemoryInstance,
+    storage_written: &mut StorageWriteCounter,
+    storage_journal: &mut StorageJournal,
     pc: RegMut<PC>,
     result_register: &mut Word,
     input: StateClearQWord,
 ) -> IoResult<(), S::DataError> {
     let start_key = Bytes32::new(memory.read_bytes(input.start_storage_key_pointer)?);
 
+    // Every cleared slot counts as written, whether it was set or not.
+    storage_written
+        .charge((Bytes32::LEN as u64).saturating_mul(input.num_slots as u64))?;
+    storage_journal.record_state(storage, contract_id, &start_key, input.num_slots)?;
+
     let all_previously_set = storage
         .contract_state_remove_range(contract_id, &start_key, input.num_slots)
-        .map_err(RuntimeError::Storage)?
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractStateClear)
+                    .contract(*contract_id)
+                    .key(start_key),
+            )
+        })?
         .is_some();
 
     *result_register = all_previously_set as Word;
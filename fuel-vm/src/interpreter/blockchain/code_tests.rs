@@ -1,4 +1,4 @@
-#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::arithmetic_side_effects, clippy::cast_possible_truncation)]
 
 use alloc::vec;
 
@@ -10,7 +10,9 @@ use crate::{
         MemoryStorageError,
     },
 };
+use fuel_storage::StorageAsMut;
 use fuel_tx::Contract;
+use test_case::test_case;
 
 #[test]
 fn test_load_contract_in_script() -> IoResult<(), MemoryStorageError> {
@@ -48,6 +50,7 @@ fn test_load_contract_in_script() -> IoResult<(), MemoryStorageError> {
     let input = LoadContractCodeCtx {
         contract_max_size: 100,
         storage: &storage,
+        code_cache: &mut CodeCache::default(),
         memory: &mut memory,
         context: &Context::Script {
             block_height: Default::default(),
@@ -94,6 +97,12 @@ fn test_load_contract_in_call() -> IoResult<(), MemoryStorageError> {
     memory[contract_id_mem_address as usize
         ..contract_id_mem_address as usize + ContractId::LEN]
         .copy_from_slice(contract_id.as_ref());
+    memory
+        .write_bytes_noownerchecks(
+            fp + CallFrame::code_size_offset() as Word,
+            8u64.to_be_bytes(),
+        )
+        .unwrap();
     storage
         .storage_contract_insert(
             &contract_id,
@@ -107,6 +116,7 @@ fn test_load_contract_in_call() -> IoResult<(), MemoryStorageError> {
     let input = LoadContractCodeCtx {
         contract_max_size: 100,
         storage: &storage,
+        code_cache: &mut CodeCache::default(),
         memory: &mut memory,
         context: &Context::Call {
             block_height: Default::default(),
@@ -131,6 +141,110 @@ fn test_load_contract_in_call() -> IoResult<(), MemoryStorageError> {
     Ok(())
 }
 
+#[test_case(0, None, 96 => Ok(()); "Contract code up to the limit in script")]
+#[test_case(0, None, 97 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Contract code over the limit in script")]
+#[test_case(0, Some(40), 96 => Ok(()); "Contract code ignores the frame code up to the limit in call")]
+#[test_case(0, Some(40), 97 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Contract code ignores the frame code over the limit in call")]
+#[test_case(1, None, 96 => Ok(()); "Blob code up to the limit in script")]
+#[test_case(1, None, 97 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Blob code over the limit in script")]
+#[test_case(1, Some(40), 56 => Ok(()); "Blob code up to the limit in call")]
+#[test_case(1, Some(40), 57 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Blob code over the limit in call")]
+#[test_case(1, Some(41), 48 => Ok(()); "Blob code up to the limit with padded frame code")]
+#[test_case(1, Some(41), 49 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Blob code over the limit with padded frame code")]
+#[test_case(2, None, 96 => Ok(()); "Memory code up to the limit in script")]
+#[test_case(2, None, 97 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Memory code over the limit in script")]
+#[test_case(2, Some(40), 56 => Ok(()); "Memory code up to the limit in call")]
+#[test_case(2, Some(40), 57 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Memory code over the limit in call")]
+#[test_case(2, Some(41), 48 => Ok(()); "Memory code up to the limit with padded frame code")]
+#[test_case(2, Some(41), 49 => Err(RuntimeError::Recoverable(PanicReason::ContractMaxSize)); "Memory code over the limit with padded frame code")]
+fn test_load_code_respects_contract_max_size(
+    mode: u8,
+    frame_code_size: Option<Word>,
+    length: Word,
+) -> IoResult<(), MemoryStorageError> {
+    const CONTRACT_MAX_SIZE: u64 = 96;
+
+    let mut storage = MemoryStorage::default();
+    let mut memory: MemoryInstance = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut pc = 4;
+    let mut cgas = 1_000_000;
+    let mut ggas = 1_000_000;
+    let mut ssp = 1000;
+    let mut sp = 1000;
+    let hp = VM_MAX_RAM;
+    let is = 0;
+
+    let contract_id = ContractId::from([4u8; 32]);
+    let blob_id = BlobId::from([6u8; 32]);
+    let id_mem_address: Word = 32;
+
+    let (fp, context) = match frame_code_size {
+        Some(code_size) => {
+            let fp = 200;
+            memory
+                .write_bytes_noownerchecks(
+                    fp + CallFrame::code_size_offset() as Word,
+                    code_size.to_be_bytes(),
+                )
+                .unwrap();
+            (
+                fp,
+                Context::Call {
+                    block_height: Default::default(),
+                },
+            )
+        }
+        None => (
+            0,
+            Context::Script {
+                block_height: Default::default(),
+            },
+        ),
+    };
+
+    let id: &[u8] = if mode == 1 {
+        blob_id.as_ref()
+    } else {
+        contract_id.as_ref()
+    };
+    memory[id_mem_address as usize..id_mem_address as usize + 32].copy_from_slice(id);
+    storage
+        .storage_contract_insert(&contract_id, &Contract::from(vec![5u8; 400]))
+        .unwrap();
+    storage
+        .storage_as_mut::<BlobData>()
+        .insert(&blob_id, &[5u8; 400])
+        .unwrap();
+
+    let mut panic_context = PanicContext::None;
+    let input_contracts = [contract_id];
+    let input_contracts = input_contracts.into_iter().collect();
+    let input = LoadContractCodeCtx {
+        contract_max_size: CONTRACT_MAX_SIZE,
+        storage: &storage,
+        code_cache: &mut CodeCache::default(),
+        memory: &mut memory,
+        context: &context,
+        profiler: &mut Profiler::default(),
+        input_contracts: InputContracts::new(&input_contracts, &mut panic_context),
+        gas_cost: DependentCost::from_units_per_gas(13, 1),
+        cgas: RegMut::new(&mut cgas),
+        ggas: RegMut::new(&mut ggas),
+        ssp: RegMut::new(&mut ssp),
+        sp: RegMut::new(&mut sp),
+        hp: Reg::new(&hp),
+        fp: Reg::new(&fp),
+        pc: RegMut::new(&mut pc),
+        is: Reg::new(&is),
+    };
+    match mode {
+        0 => input.load_contract_code(id_mem_address, 0, length),
+        1 => input.load_blob_code(id_mem_address, 0, length),
+        2 => input.load_memory_code(0, 0, length),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn test_code_copy() -> IoResult<(), MemoryStorageError> {
     let mut storage = MemoryStorage::default();
@@ -163,6 +277,7 @@ fn test_code_copy() -> IoResult<(), MemoryStorageError> {
     let mut panic_context = PanicContext::None;
     let input = CodeCopyCtx {
         storage: &storage,
+        code_cache: &mut CodeCache::default(),
         memory: &mut memory,
         input_contracts: InputContracts::new(&input_contracts, &mut panic_context),
         profiler: &mut Profiler::default(),
@@ -77,6 +77,7 @@ fn test_code_root() {
     CodeRootCtx {
         memory: &mut memory,
         storage: &storage,
+        contract_info: &mut Default::default(),
         gas_cost,
         profiler: &mut Default::default(),
         input_contracts: InputContracts::new(
@@ -133,6 +134,7 @@ fn test_code_root_contract_not_found() {
     let _ = CodeRootCtx {
         memory: &mut memory,
         storage: &storage,
+        contract_info: &mut Default::default(),
         gas_cost,
         profiler: &mut Default::default(),
         input_contracts: InputContracts::new(
@@ -183,6 +185,7 @@ fn test_code_root_contract_not_in_inputs() {
     let _ = CodeRootCtx {
         memory: &mut memory,
         storage: &storage,
+        contract_info: &mut Default::default(),
         gas_cost,
         profiler: &mut Default::default(),
         input_contracts: InputContracts::new(
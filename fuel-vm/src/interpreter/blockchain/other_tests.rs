@@ -3,6 +3,7 @@
 use alloc::vec;
 
 use crate::storage::{
+    ContractsRawCode,
     MemoryStorage,
     MemoryStorageError,
 };
@@ -62,6 +63,8 @@ fn test_burn(
         context: &context,
         receipts: &mut receipts,
         memory: &mut memory,
+        storage_written: &mut StorageWriteCounter::default(),
+        storage_journal: &mut StorageJournal::default(),
         fp: Reg::new(&fp),
         pc: RegMut::new(&mut pc),
         is: Reg::new(&is),
@@ -144,6 +147,8 @@ fn test_mint(
         receipts: &mut receipts,
         memory: &mut memory,
         profiler: &mut Profiler::default(),
+        storage_written: &mut StorageWriteCounter::default(),
+        storage_journal: &mut StorageJournal::default(),
         new_storage_gas_per_byte: 1,
         cgas: RegMut::new(&mut cgas),
         ggas: RegMut::new(&mut ggas),
@@ -234,6 +239,8 @@ fn test_code_size() {
     let mut panic_context = PanicContext::None;
     let input = CodeSizeCtx {
         storage: &mut storage,
+        code_cache: &mut CodeCache::default(),
+        contract_info: &mut Default::default(),
         memory: &mut memory,
         gas_cost: DependentCost::free(),
         profiler: &mut Profiler::default(),
@@ -252,6 +259,8 @@ fn test_code_size() {
 
     let input = CodeSizeCtx {
         storage: &mut storage,
+        code_cache: &mut CodeCache::default(),
+        contract_info: &mut Default::default(),
         memory: &mut memory,
         gas_cost: DependentCost::free(),
         input_contracts: InputContracts::new(&input_contracts, &mut panic_context),
@@ -270,6 +279,8 @@ fn test_code_size() {
     let input_contracts = Default::default();
     let input = CodeSizeCtx {
         storage: &mut storage,
+        code_cache: &mut CodeCache::default(),
+        contract_info: &mut Default::default(),
         memory: &mut memory,
         gas_cost: DependentCost::free(),
         input_contracts: InputContracts::new(&input_contracts, &mut panic_context),
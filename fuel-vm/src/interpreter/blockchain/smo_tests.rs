@@ -237,6 +237,8 @@ fn test_smo(
         receipts: &mut receipts,
         balances: &mut balances,
         storage: &mut storage,
+        storage_written: &mut StorageWriteCounter::default(),
+        storage_journal: &mut StorageJournal::default(),
         current_contract: if internal {
             Some(ContractId::default())
         } else {
@@ -248,6 +250,7 @@ fn test_smo(
         msg_data_len,
         msg_data_ptr,
         amount_coins_to_send,
+        allow_empty_reads: false,
     };
 
     input.message_output()?;
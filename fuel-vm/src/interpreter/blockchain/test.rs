@@ -93,6 +93,8 @@ fn test_state_read_word(
             memory: &mut memory,
             context: &context,
             profiler: &mut Profiler::default(),
+            storage_written: &mut StorageWriteCounter::default(),
+            storage_journal: &mut StorageJournal::default(),
             new_storage_gas_per_byte: 1,
             current_contract: None,
             cgas: RegMut::new(&mut cgas),
@@ -163,6 +165,8 @@ fn test_state_write_word(
             memory: &mut memory,
             context: &context,
             profiler: &mut Profiler::default(),
+            storage_written: &mut StorageWriteCounter::default(),
+            storage_journal: &mut StorageJournal::default(),
             new_storage_gas_per_byte: 1,
             current_contract: None,
             cgas: RegMut::new(&mut cgas),
@@ -182,6 +186,8 @@ fn test_state_write_word(
         new_storage_gas_per_byte: 1,
         current_contract: None,
         profiler: &mut Profiler::default(),
+        storage_written: &mut StorageWriteCounter::default(),
+        storage_journal: &mut StorageJournal::default(),
         cgas: RegMut::new(&mut cgas),
         ggas: RegMut::new(&mut ggas),
         is: Reg::new(&is),
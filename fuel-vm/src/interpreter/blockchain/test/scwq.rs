@@ -121,6 +121,8 @@ fn test_state_clear_qword(
         &Default::default(),
         &mut storage,
         &memory,
+        &mut StorageWriteCounter::default(),
+        &mut StorageJournal::default(),
         RegMut::new(&mut pc),
         &mut result_register,
         input,
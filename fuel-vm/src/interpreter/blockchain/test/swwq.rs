@@ -245,6 +245,8 @@ fn test_state_write_qword(
         &mut storage,
         &memory,
         &mut Profiler::default(),
+        &mut StorageWriteCounter::default(),
+        &mut StorageJournal::default(),
         1,
         None,
         RegMut::new(&mut cgas),
@@ -0,0 +1,300 @@
+//! Cache of the contracts bytecode shared between the transactions.
+
+use alloc::{
+    collections::BTreeMap,
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    PanicReason,
+    Word,
+};
+use fuel_storage::StorageAsRef;
+use fuel_types::ContractId;
+use hashbrown::HashMap;
+
+use crate::{
+    error::{
+        IoResult,
+        RuntimeError,
+        StorageErrorContext,
+        StorageOp,
+    },
+    interpreter::{
+        contract::contract_size,
+        memory::OwnershipRegisters,
+        MemoryInstance,
+    },
+    storage::{
+        ContractsRawCode,
+        InterpreterStorage,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// LRU cache of the contracts bytecode keyed by the [`ContractId`], limited by the
+/// total size of the cached bytecode.
+///
+/// The `CALL`, `LDC`, `CCP` and `CSIZ` instructions consult the cache before reading
+/// the bytecode from the storage. Only the contract mode of `LDC` goes through the
+/// cache, the blob and memory modes always read their source directly. The bytecode
+/// of the deployed contract never changes, so the cache stays valid across
+/// transactions. Every cache hit still checks that the contract exists in the
+/// storage with the same code size, and drops the entry otherwise, so the cache never
+/// serves the bytecode of a contract removed by a reverted storage transaction.
+///
+/// The cache with zero capacity, which is the default, is disabled.
+#[derive(Debug, Clone, Default)]
+pub struct CodeCache {
+    capacity_bytes: usize,
+    size_bytes: usize,
+    /// The counter of the accesses, used to order the entries by the last usage.
+    clock: u64,
+    entries: HashMap<ContractId, CodeCacheEntry>,
+    /// The cached contracts ordered by the last usage, the least recent first.
+    recency: BTreeMap<u64, ContractId>,
+}
+
+#[derive(Debug, Clone)]
+struct CodeCacheEntry {
+    code: Vec<u8>,
+    last_used: u64,
+}
+
+impl CodeCache {
+    /// Creates the cache holding at most `capacity_bytes` of the bytecode.
+    pub fn new(capacity_bytes: usize) -> Self {
+        Self {
+            capacity_bytes,
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if the cache has non-zero capacity.
+    pub fn is_enabled(&self) -> bool {
+        self.capacity_bytes > 0
+    }
+
+    /// The maximum total size of the cached bytecode.
+    pub fn capacity_bytes(&self) -> usize {
+        self.capacity_bytes
+    }
+
+    /// The total size of the cached bytecode.
+    pub fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+
+    /// The number of the cached contracts.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no contract is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the bytecode of the contract is cached.
+    pub fn contains(&self, contract_id: &ContractId) -> bool {
+        self.entries.contains_key(contract_id)
+    }
+
+    /// Removes the bytecode of the contract from the cache.
+    pub fn invalidate(&mut self, contract_id: &ContractId) {
+        if let Some(entry) = self.entries.remove(contract_id) {
+            self.recency.remove(&entry.last_used);
+            self.size_bytes = self.size_bytes.saturating_sub(entry.code.len());
+        }
+    }
+
+    /// Removes all cached bytecode.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.size_bytes = 0;
+    }
+
+    /// Returns the size of the cached bytecode without updating its usage.
+    fn cached_size(&self, contract_id: &ContractId) -> Option<usize> {
+        self.entries.get(contract_id).map(|entry| entry.code.len())
+    }
+
+    /// Returns the cached bytecode and marks it as the most recently used.
+    fn get(&mut self, contract_id: &ContractId) -> Option<&[u8]> {
+        let now = self.tick();
+        let entry = self.entries.get_mut(contract_id)?;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(now, *contract_id);
+        entry.last_used = now;
+        Some(entry.code.as_slice())
+    }
+
+    /// Caches the bytecode, evicting the least recently used contracts to fit into
+    /// the capacity. The bytecode larger than the capacity is not cached.
+    fn insert(&mut self, contract_id: ContractId, code: Vec<u8>) {
+        self.invalidate(&contract_id);
+        if code.len() > self.capacity_bytes {
+            return
+        }
+
+        while self.size_bytes.saturating_add(code.len()) > self.capacity_bytes {
+            let Some((_, evicted)) = self.recency.pop_first() else {
+                break
+            };
+            if let Some(entry) = self.entries.remove(&evicted) {
+                self.size_bytes = self.size_bytes.saturating_sub(entry.code.len());
+            }
+        }
+
+        let now = self.tick();
+        self.size_bytes = self.size_bytes.saturating_add(code.len());
+        self.recency.insert(now, contract_id);
+        self.entries.insert(
+            contract_id,
+            CodeCacheEntry {
+                code,
+                last_used: now,
+            },
+        );
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock = self.clock.wrapping_add(1);
+        self.clock
+    }
+}
+
+/// The bytecode of the contracts read by the `CALL`, `LDC`, `CCP` and `CSIZ`
+/// instructions.
+pub(crate) trait ContractCode {
+    type Error;
+
+    /// Returns the size of the bytecode of the contract.
+    fn code_size(&mut self, contract_id: &ContractId) -> IoResult<usize, Self::Error>;
+
+    /// Reads the bytecode of the contract starting from the `offset` into the `dst`.
+    /// Returns the number of bytes read.
+    fn read_code(
+        &mut self,
+        contract_id: &ContractId,
+        offset: usize,
+        dst: &mut [u8],
+    ) -> IoResult<usize, Self::Error>;
+
+    /// Copies the bytecode of the contract starting from the `src_offset` to the
+    /// memory, filling the rest of the `dst_len` bytes with zeroes.
+    #[allow(clippy::too_many_arguments)]
+    fn copy_code_zero_fill(
+        &mut self,
+        memory: &mut MemoryInstance,
+        owner: OwnershipRegisters,
+        dst_addr: Word,
+        dst_len: Word,
+        contract_id: &ContractId,
+        src_offset: Word,
+        src_len: usize,
+    ) -> IoResult<(), Self::Error> {
+        let write_buffer = memory.write(owner, dst_addr, dst_len)?;
+        let mut empty_offset = 0;
+
+        if src_offset < src_len as Word {
+            let src_offset =
+                u32::try_from(src_offset).map_err(|_| PanicReason::MemoryOverflow)?;
+
+            let src_read_length = src_len.saturating_sub(src_offset as usize);
+            let src_read_length = src_read_length.min(write_buffer.len());
+
+            let (src_read_buffer, _) = write_buffer.split_at_mut(src_read_length);
+            self.read_code(contract_id, src_offset as usize, src_read_buffer)?;
+
+            empty_offset = src_read_length;
+        }
+
+        write_buffer[empty_offset..].fill(0);
+
+        Ok(())
+    }
+}
+
+/// Reads the bytecode from the [`CodeCache`], loading it from the storage on miss.
+pub(crate) struct CachedContractCode<'vm, S> {
+    pub storage: &'vm S,
+    pub cache: &'vm mut CodeCache,
+}
+
+impl<S> ContractCode for CachedContractCode<'_, S>
+where
+    S: InterpreterStorage,
+{
+    type Error = S::DataError;
+
+    fn code_size(&mut self, contract_id: &ContractId) -> IoResult<usize, Self::Error> {
+        // The size is always taken from the storage, so the entry of a contract that
+        // doesn't exist there anymore, or was replaced, is never used.
+        let size = contract_size(self.storage, contract_id)
+            .inspect_err(|_| self.cache.invalidate(contract_id))?;
+        if self
+            .cache
+            .cached_size(contract_id)
+            .is_some_and(|cached| cached != size)
+        {
+            self.cache.invalidate(contract_id);
+        }
+        Ok(size)
+    }
+
+    fn read_code(
+        &mut self,
+        contract_id: &ContractId,
+        offset: usize,
+        dst: &mut [u8],
+    ) -> IoResult<usize, Self::Error> {
+        if self.cache.is_enabled() {
+            let size = self.code_size(contract_id)?;
+            if !self.cache.contains(contract_id) && size <= self.cache.capacity_bytes() {
+                let mut code = vec![0u8; size];
+                let bytes_read = self
+                    .storage
+                    .storage::<ContractsRawCode>()
+                    .read(contract_id, 0, &mut code)
+                    .map_err(|e| {
+                        RuntimeError::storage(
+                            e,
+                            StorageErrorContext::new(StorageOp::ContractCodeRead)
+                                .contract(*contract_id),
+                        )
+                    })?
+                    .ok_or(PanicReason::ContractNotFound)?;
+                if bytes_read != size {
+                    return Err(PanicReason::ContractMismatch.into())
+                }
+                self.cache.insert(*contract_id, code);
+            }
+        }
+
+        if let Some(code) = self.cache.get(contract_id) {
+            let src = code.get(offset..).unwrap_or_default();
+            let len = src.len().min(dst.len());
+            dst[..len].copy_from_slice(&src[..len]);
+            return Ok(len)
+        }
+
+        let bytes_read = self
+            .storage
+            .storage::<ContractsRawCode>()
+            .read(contract_id, offset, dst)
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ContractCodeRead)
+                        .contract(*contract_id),
+                )
+            })?
+            .ok_or(PanicReason::ContractNotFound)?;
+        Ok(bytes_read)
+    }
+}
@@ -0,0 +1,168 @@
+#![allow(non_snake_case)]
+
+use alloc::vec;
+
+use fuel_storage::StorageAsMut;
+
+use super::*;
+use crate::storage::MemoryStorage;
+
+fn id(byte: u8) -> ContractId {
+    ContractId::from([byte; 32])
+}
+
+#[test]
+fn insert__evicts_least_recently_used() {
+    let mut cache = CodeCache::new(10);
+    cache.insert(id(1), vec![1; 4]);
+    cache.insert(id(2), vec![2; 4]);
+
+    // Touch the first contract, so the second one becomes the least recently used.
+    assert!(cache.get(&id(1)).is_some());
+    cache.insert(id(3), vec![3; 4]);
+
+    assert!(cache.contains(&id(1)));
+    assert!(!cache.contains(&id(2)));
+    assert!(cache.contains(&id(3)));
+    assert_eq!(cache.size_bytes(), 8);
+}
+
+#[test]
+fn insert__skips_code_larger_than_capacity() {
+    let mut cache = CodeCache::new(10);
+    cache.insert(id(1), vec![1; 4]);
+
+    cache.insert(id(2), vec![2; 11]);
+
+    assert!(cache.contains(&id(1)));
+    assert!(!cache.contains(&id(2)));
+    assert_eq!(cache.size_bytes(), 4);
+}
+
+#[test]
+fn insert__replaces_existing_entry() {
+    let mut cache = CodeCache::new(10);
+    cache.insert(id(1), vec![1; 4]);
+
+    cache.insert(id(1), vec![1; 6]);
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.size_bytes(), 6);
+}
+
+#[test]
+fn invalidate__removes_entry() {
+    let mut cache = CodeCache::new(10);
+    cache.insert(id(1), vec![1; 4]);
+    cache.insert(id(2), vec![2; 4]);
+
+    cache.invalidate(&id(1));
+
+    assert!(!cache.contains(&id(1)));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.size_bytes(), 4);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.size_bytes(), 0);
+}
+
+#[test]
+fn read_code__caches_code_on_miss() {
+    let mut storage = MemoryStorage::default();
+    StorageAsMut::storage::<ContractsRawCode>(&mut storage)
+        .insert(&id(1), &[1, 2, 3, 4])
+        .unwrap();
+    let mut cache = CodeCache::new(10);
+    let mut code = CachedContractCode {
+        storage: &storage,
+        cache: &mut cache,
+    };
+
+    let mut dst = [0; 3];
+    let bytes_read = code.read_code(&id(1), 1, &mut dst).unwrap();
+
+    assert_eq!(bytes_read, 3);
+    assert_eq!(dst, [2, 3, 4]);
+    assert_eq!(code.code_size(&id(1)).unwrap(), 4);
+    assert!(cache.contains(&id(1)));
+}
+
+#[test]
+fn read_code__disabled_cache_reads_from_storage() {
+    let mut storage = MemoryStorage::default();
+    StorageAsMut::storage::<ContractsRawCode>(&mut storage)
+        .insert(&id(1), &[1, 2, 3, 4])
+        .unwrap();
+    let mut cache = CodeCache::default();
+    let mut code = CachedContractCode {
+        storage: &storage,
+        cache: &mut cache,
+    };
+
+    let mut dst = [0; 4];
+    let bytes_read = code.read_code(&id(1), 0, &mut dst).unwrap();
+
+    assert_eq!(bytes_read, 4);
+    assert_eq!(dst, [1, 2, 3, 4]);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn read_code__missing_contract_is_not_cached() {
+    let storage = MemoryStorage::default();
+    let mut cache = CodeCache::new(10);
+    let mut code = CachedContractCode {
+        storage: &storage,
+        cache: &mut cache,
+    };
+
+    let err = code.read_code(&id(1), 0, &mut [0; 4]).unwrap_err();
+
+    assert_eq!(err, PanicReason::ContractNotFound.into());
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn code_size__cached_contract_missing_in_storage_is_invalidated() {
+    let storage = MemoryStorage::default();
+    let mut cache = CodeCache::new(10);
+    cache.insert(id(1), vec![1; 4]);
+    let mut code = CachedContractCode {
+        storage: &storage,
+        cache: &mut cache,
+    };
+
+    let size_err = code.code_size(&id(1)).unwrap_err();
+    cache.insert(id(1), vec![1; 4]);
+    let mut code = CachedContractCode {
+        storage: &storage,
+        cache: &mut cache,
+    };
+    let read_err = code.read_code(&id(1), 0, &mut [0; 4]).unwrap_err();
+
+    assert_eq!(size_err, PanicReason::ContractNotFound.into());
+    assert_eq!(read_err, PanicReason::ContractNotFound.into());
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn read_code__cached_code_with_different_size_is_reloaded() {
+    let mut storage = MemoryStorage::default();
+    StorageAsMut::storage::<ContractsRawCode>(&mut storage)
+        .insert(&id(1), &[1, 2, 3, 4])
+        .unwrap();
+    let mut cache = CodeCache::new(10);
+    cache.insert(id(1), vec![9; 6]);
+    let mut code = CachedContractCode {
+        storage: &storage,
+        cache: &mut cache,
+    };
+
+    let mut dst = [0; 6];
+    let bytes_read = code.read_code(&id(1), 0, &mut dst).unwrap();
+
+    assert_eq!(bytes_read, 4);
+    assert_eq!(dst, [1, 2, 3, 4, 0, 0]);
+    assert_eq!(cache.size_bytes(), 4);
+}
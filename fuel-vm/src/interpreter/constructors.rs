@@ -1,15 +1,16 @@
 //! Exposed constructors API for the [`Interpreter`]
 #![allow(clippy::default_constructed_unit_structs)] // need for ::default() depends on cfg
 
+use super::{
+    CodeCache,
+    Interpreter,
+    RuntimeBalances,
+};
 #[cfg(any(test, feature = "test-helpers"))]
 use super::{
     ExecutableTransaction,
     MemoryInstance,
 };
-use super::{
-    Interpreter,
-    RuntimeBalances,
-};
 use crate::{
     consts::*,
     context::Context,
@@ -17,7 +18,6 @@ use crate::{
         InterpreterParams,
         PanicContext,
     },
-    state::Debugger,
 };
 
 use alloc::vec;
@@ -71,19 +71,25 @@ where
             registers: [0; VM_REGISTER_COUNT],
             memory,
             frames: vec![],
+            returned_call_gas: vec![],
             receipts: Default::default(),
             tx: Default::default(),
             input_contracts: Default::default(),
             input_contracts_index_to_output_index: Default::default(),
             initial_balances: Default::default(),
             storage,
-            debugger: Debugger::default(),
+            #[cfg(feature = "debugger")]
+            debugger: Default::default(),
             context: Context::default(),
             balances: RuntimeBalances::default(),
             profiler: Profiler::default(),
             interpreter_params,
             panic_context: PanicContext::None,
             ecal_state,
+            code_cache: CodeCache::default(),
+            contract_info: Default::default(),
+            storage_written: Default::default(),
+            storage_journal: Default::default(),
         }
     }
 }
@@ -98,6 +104,24 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal> {
         self.profiler.set_receiver(alloc::boxed::Box::new(receiver));
         self
     }
+
+    /// Makes the gas profiler record only every `interval`th gas charge, lowering
+    /// the profiling overhead. The recorded gas is scaled by the `interval`.
+    #[cfg(feature = "profile-gas")]
+    pub fn with_gas_sampling_interval(
+        &mut self,
+        interval: core::num::NonZeroU64,
+    ) -> &mut Self {
+        self.profiler.set_gas_sampling_interval(interval);
+        self
+    }
+
+    /// Enables the [`CodeCache`] holding at most `capacity_bytes` of the contracts
+    /// bytecode. The zero capacity disables the cache.
+    pub fn with_code_cache(&mut self, capacity_bytes: usize) -> &mut Self {
+        self.code_cache = CodeCache::new(capacity_bytes);
+        self
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
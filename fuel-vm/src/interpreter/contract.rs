@@ -25,9 +25,16 @@ use crate::{
     error::{
         IoResult,
         RuntimeError,
+        StorageErrorContext,
+        StorageOp,
     },
     interpreter::{
         receipts::ReceiptsCtx,
+        storage_journal::{
+            JournalEntry,
+            StorageJournal,
+        },
+        storage_write::StorageWriteCounter,
         InputContracts,
     },
     prelude::Profiler,
@@ -35,9 +42,11 @@ use crate::{
         BlobData,
         ContractsAssetsStorage,
         ContractsRawCode,
+        ContractsStateData,
         InterpreterStorage,
     },
 };
+use alloc::borrow::Cow;
 use fuel_asm::{
     PanicReason,
     RegisterId,
@@ -45,6 +54,7 @@ use fuel_asm::{
 };
 use fuel_storage::StorageSize;
 use fuel_tx::{
+    Contract,
     Output,
     Receipt,
 };
@@ -109,6 +119,8 @@ where
             balances: &mut self.balances,
             receipts: &mut self.receipts,
             profiler: &mut self.profiler,
+            storage_written: &mut self.storage_written,
+            storage_journal: &mut self.storage_journal,
             new_storage_gas_per_byte,
             tx: &mut self.tx,
             input_contracts: InputContracts::new(
@@ -116,6 +128,7 @@ where
                 &mut self.panic_context,
             ),
             tx_offset,
+            precise_output_panics: self.interpreter_params.precise_output_panics,
             cgas,
             ggas,
             fp: fp.as_ref(),
@@ -152,6 +165,8 @@ where
             balances: &mut self.balances,
             receipts: &mut self.receipts,
             profiler: &mut self.profiler,
+            storage_written: &mut self.storage_written,
+            storage_journal: &mut self.storage_journal,
             new_storage_gas_per_byte,
             tx: &mut self.tx,
             input_contracts: InputContracts::new(
@@ -159,6 +174,7 @@ where
                 &mut self.panic_context,
             ),
             tx_offset,
+            precise_output_panics: self.interpreter_params.precise_output_panics,
             cgas,
             ggas,
             fp: fp.as_ref(),
@@ -172,9 +188,41 @@ where
         &self,
         contract: &ContractId,
     ) -> IoResult<bool, S::DataError> {
-        self.storage
-            .storage_contract_exists(contract)
-            .map_err(RuntimeError::Storage)
+        self.contract_exists(contract).map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractCodeRead).contract(*contract),
+            )
+        })
+    }
+}
+
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
+where
+    S: InterpreterStorage,
+{
+    /// Returns `true` if the contract is deployed in the storage of the interpreter.
+    pub fn contract_exists(&self, contract: &ContractId) -> Result<bool, S::DataError> {
+        self.storage.storage_contract_exists(contract)
+    }
+
+    /// Returns the code of the contract from the storage of the interpreter, or
+    /// `None` if the contract isn't deployed.
+    pub fn contract_code(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Option<Cow<'_, Contract>>, S::DataError> {
+        self.storage.storage_contract(contract)
+    }
+
+    /// Returns the value of the storage slot `key` of the contract, or `None` if the
+    /// slot isn't set.
+    pub fn contract_state_value(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> Result<Option<Cow<'_, ContractsStateData>>, S::DataError> {
+        self.storage.contract_state(contract, key)
     }
 }
 
@@ -214,10 +262,13 @@ struct TransferCtx<'vm, S, Tx> {
     balances: &'vm mut RuntimeBalances,
     receipts: &'vm mut ReceiptsCtx,
     profiler: &'vm mut Profiler,
+    storage_written: &'vm mut StorageWriteCounter,
+    storage_journal: &'vm mut StorageJournal,
     new_storage_gas_per_byte: Word,
     tx: &'vm mut Tx,
     input_contracts: InputContracts<'vm>,
     tx_offset: usize,
+    precise_output_panics: bool,
     cgas: RegMut<'vm, CGAS>,
     ggas: RegMut<'vm, GGAS>,
     fp: Reg<'vm, FP>,
@@ -264,12 +315,21 @@ impl<S, Tx> TransferCtx<'_, S, Tx> {
 
         if let Some(source_contract) = internal_context {
             // debit funding source (source contract balance)
+            self.storage_written.charge(WORD_SIZE as u64)?;
+            self.storage_journal.record_balance(
+                self.storage,
+                &source_contract,
+                &asset_id,
+            )?;
             balance_decrease(self.storage, &source_contract, &asset_id, amount)?;
         } else {
             // debit external funding source (i.e. free balance)
             external_asset_id_balance_sub(self.balances, self.memory, &asset_id, amount)?;
         }
         // credit destination contract
+        self.storage_written.charge(WORD_SIZE as u64)?;
+        self.storage_journal
+            .record_balance(self.storage, &destination, &asset_id)?;
         let (_, created_new_entry) =
             balance_increase(self.storage, &destination, &asset_id, amount)?;
         if created_new_entry {
@@ -342,6 +402,12 @@ impl<S, Tx> TransferCtx<'_, S, Tx> {
 
         if let Some(source_contract) = internal_context {
             // debit funding source (source contract balance)
+            self.storage_written.charge(WORD_SIZE as u64)?;
+            self.storage_journal.record_balance(
+                self.storage,
+                &source_contract,
+                &asset_id,
+            )?;
             balance_decrease(self.storage, &source_contract, &asset_id, amount)?;
         } else {
             // debit external funding source (i.e. UTXOs)
@@ -351,7 +417,20 @@ impl<S, Tx> TransferCtx<'_, S, Tx> {
         // credit variable output
         let variable = Output::variable(to, amount, asset_id);
 
-        set_variable_output(self.tx, self.memory, self.tx_offset, out_idx, variable)?;
+        if let Some(output) = self.tx.outputs().get(out_idx) {
+            self.storage_journal.record(JournalEntry::VariableOutput {
+                index: out_idx,
+                output: *output,
+            });
+        }
+        set_variable_output(
+            self.tx,
+            self.memory,
+            self.tx_offset,
+            out_idx,
+            variable,
+            self.precise_output_panics,
+        )?;
 
         let receipt = Receipt::transfer_out(
             internal_context.unwrap_or_default(),
@@ -377,7 +456,12 @@ where
 {
     let size = storage
         .size_of_value(contract)
-        .map_err(RuntimeError::Storage)?
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractCodeRead).contract(*contract),
+            )
+        })?
         .ok_or(PanicReason::ContractNotFound)?;
     Ok(size)
 }
@@ -388,7 +472,13 @@ where
 {
     let size = storage
         .size_of_value(blob_id)
-        .map_err(RuntimeError::Storage)?
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::BlobRead)
+                    .key(Bytes32::new(**blob_id)),
+            )
+        })?
         .ok_or(PanicReason::BlobNotFound)?;
     Ok(size)
 }
@@ -403,7 +493,14 @@ where
 {
     Ok(storage
         .contract_asset_id_balance(contract, asset_id)
-        .map_err(RuntimeError::Storage)?
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractBalanceRead)
+                    .contract(*contract)
+                    .key(Bytes32::new(**asset_id)),
+            )
+        })?
         .unwrap_or_default())
 }
 
@@ -425,7 +522,14 @@ where
 
     let old_value = storage
         .contract_asset_id_balance_replace(contract, asset_id, balance)
-        .map_err(RuntimeError::Storage)?;
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractBalanceWrite)
+                    .contract(*contract)
+                    .key(Bytes32::new(**asset_id)),
+            )
+        })?;
 
     Ok((balance, old_value.is_none()))
 }
@@ -446,6 +550,13 @@ where
         .ok_or(PanicReason::NotEnoughBalance)?;
     storage
         .contract_asset_id_balance_insert(contract, asset_id, balance)
-        .map_err(RuntimeError::Storage)?;
+        .map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractBalanceWrite)
+                    .contract(*contract)
+                    .key(Bytes32::new(**asset_id)),
+            )
+        })?;
     Ok(balance)
 }
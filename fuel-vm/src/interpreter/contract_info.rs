@@ -0,0 +1,98 @@
+//! Cache of the sizes and the code roots of the contracts within a transaction.
+
+use alloc::collections::BTreeMap;
+
+use fuel_asm::PanicReason;
+use fuel_tx::Contract;
+use fuel_types::{
+    Bytes32,
+    ContractId,
+};
+
+use crate::{
+    error::{
+        IoResult,
+        RuntimeError,
+        StorageErrorContext,
+        StorageOp,
+    },
+    storage::InterpreterStorage,
+};
+
+/// The sizes and the code roots of the contracts looked up by the `CALL`, `CSIZ` and
+/// `CROO` instructions during the current transaction.
+///
+/// The entries are populated lazily on the first lookup. The bytecode of the
+/// contract can't change during the script execution, so the entries are never
+/// invalidated. The cache is cleared when the VM is initialized with a new
+/// transaction.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ContractInfoCache {
+    entries: BTreeMap<ContractId, ContractInfo>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ContractInfo {
+    size: usize,
+    /// Computed only by `CROO`, since it requires reading the whole bytecode.
+    root: Option<Bytes32>,
+}
+
+impl ContractInfoCache {
+    /// Removes all cached entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the size of the bytecode of the contract, calling `lookup` on the
+    /// first access.
+    pub fn code_size<E>(
+        &mut self,
+        contract_id: &ContractId,
+        lookup: impl FnOnce() -> IoResult<usize, E>,
+    ) -> IoResult<usize, E> {
+        if let Some(info) = self.entries.get(contract_id) {
+            return Ok(info.size)
+        }
+
+        let size = lookup()?;
+        self.entries
+            .insert(*contract_id, ContractInfo { size, root: None });
+        Ok(size)
+    }
+
+    /// Returns the code root of the contract, reading the bytecode from the `storage`
+    /// on the first access.
+    pub fn code_root<S>(
+        &mut self,
+        storage: &S,
+        contract_id: &ContractId,
+    ) -> IoResult<Bytes32, S::DataError>
+    where
+        S: InterpreterStorage,
+    {
+        if let Some(root) = self.entries.get(contract_id).and_then(|info| info.root) {
+            return Ok(root)
+        }
+
+        let contract = storage
+            .storage_contract(contract_id)
+            .transpose()
+            .ok_or(PanicReason::ContractNotFound)?
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ContractCodeRead)
+                        .contract(*contract_id),
+                )
+            })?;
+        let contract: &Contract = &contract;
+        let root = contract.root();
+        let info = ContractInfo {
+            size: contract.as_ref().len(),
+            root: Some(root),
+        };
+        self.entries.insert(*contract_id, info);
+        Ok(root)
+    }
+}
@@ -1,4 +1,8 @@
 use super::{
+    curves::{
+        curve,
+        EcOperation,
+    },
     internal::{
         clear_err,
         inc_pc,
@@ -15,18 +19,7 @@ use crate::{
     error::SimpleResult,
 };
 
-use alloc::vec::Vec;
-use bn::{
-    AffineG1,
-    AffineG2,
-    Fq,
-    Fq2,
-    Fr,
-    Group,
-    Gt,
-    G1,
-    G2,
-};
+use fuel_asm::PanicReason;
 use fuel_crypto::{
     Hasher,
     Message,
@@ -83,6 +76,7 @@ where
 
     pub(crate) fn keccak256(&mut self, a: Word, b: Word, c: Word) -> SimpleResult<()> {
         let owner = self.ownership_registers();
+        let allow_empty_reads = self.interpreter_params.allow_empty_reads;
         keccak256(
             self.memory.as_mut(),
             owner,
@@ -90,11 +84,13 @@ where
             a,
             b,
             c,
+            allow_empty_reads,
         )
     }
 
     pub(crate) fn sha256(&mut self, a: Word, b: Word, c: Word) -> SimpleResult<()> {
         let owner = self.ownership_registers();
+        let allow_empty_reads = self.interpreter_params.allow_empty_reads;
         sha256(
             self.memory.as_mut(),
             owner,
@@ -102,6 +98,7 @@ where
             a,
             b,
             c,
+            allow_empty_reads,
         )
     }
 
@@ -112,6 +109,11 @@ where
         c: Word,
         d: Word,
     ) -> SimpleResult<()> {
+        let operation = EcOperation::try_from(c)?;
+        let cost = curve(b)?
+            .operation_cost(self.gas_costs(), operation)
+            .map_err(PanicReason::from)?;
+        self.gas_charge(cost)?;
         let owner = self.ownership_registers();
         ec_operation(
             self.memory.as_mut(),
@@ -131,6 +133,10 @@ where
         c: Word,
         d: Word,
     ) -> SimpleResult<()> {
+        let cost = curve(b)?
+            .pairing_cost(self.gas_costs())
+            .map_err(PanicReason::from)?;
+        self.dependent_gas_charge(cost, c)?;
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let dest = &mut w[ra.try_into()?];
         ec_pairing(self.memory.as_mut(), pc, dest, b, c, d)
@@ -146,19 +152,20 @@ pub(crate) fn secp256k1_recover(
     b: Word,
     c: Word,
 ) -> SimpleResult<()> {
-    let sig = Bytes64::from(memory.read_bytes(b)?);
-    let msg = Bytes32::from(memory.read_bytes(c)?);
-
-    let signature = Signature::from_bytes_ref(&sig);
-    let message = Message::from_bytes_ref(&msg);
+    // The signature and the message are borrowed from the memory, and the recovered
+    // key is copied directly into the destination, so no intermediate buffers are used.
+    let signature = Signature::from_bytes_ref(memory.read_bytes_ref(b)?);
+    let message = Message::from_bytes_ref(memory.read_bytes_ref(c)?);
+    let recovered = signature.recover(message);
 
-    match signature.recover(message) {
+    let dst = memory.write(owner, a, PublicKey::LEN)?;
+    match recovered {
         Ok(pub_key) => {
-            memory.write_bytes(owner, a, *pub_key)?;
+            dst.copy_from_slice(pub_key.as_ref());
             clear_err(err);
         }
         Err(_) => {
-            memory.write_bytes(owner, a, [0; PublicKey::LEN])?;
+            dst.fill(0);
             set_err(err);
         }
     }
@@ -222,13 +229,14 @@ pub(crate) fn keccak256(
     a: Word,
     b: Word,
     c: Word,
+    allow_empty_reads: bool,
 ) -> SimpleResult<()> {
     use sha3::{
         Digest,
         Keccak256,
     };
     let mut h = Keccak256::new();
-    h.update(memory.read(b, c)?);
+    h.update(memory.read_range(b, c, allow_empty_reads)?);
 
     memory.write_bytes(owner, a, *h.finalize().as_ref())?;
 
@@ -242,60 +250,16 @@ pub(crate) fn sha256(
     a: Word,
     b: Word,
     c: Word,
+    allow_empty_reads: bool,
 ) -> SimpleResult<()> {
-    memory.write_bytes(owner, a, *Hasher::hash(memory.read(b, c)?))?;
+    memory.write_bytes(
+        owner,
+        a,
+        *Hasher::hash(memory.read_range(b, c, allow_empty_reads)?),
+    )?;
     Ok(inc_pc(pc)?)
 }
 
-fn read_g1_point_alt_bn_128(
-    memory: &MemoryInstance,
-    point_ptr: Word,
-) -> SimpleResult<G1> {
-    // Big endian required by the library
-    let arg_bytes: [u8; 2 * 32] = memory.read_bytes(point_ptr)?;
-
-    let px = Fq::from_slice(&arg_bytes[..32])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let py = Fq::from_slice(&arg_bytes[32..64])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-
-    Ok(if px == Fq::zero() && py == Fq::zero() {
-        G1::zero()
-    } else {
-        AffineG1::new(px, py)
-            .map(Into::into)
-            .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?
-    })
-}
-
-fn read_g2_point_alt_bn_128(
-    memory: &MemoryInstance,
-    point_ptr: Word,
-) -> SimpleResult<G2> {
-    // Big endian required by the library
-    let arg_bytes: [u8; 4 * 32] = memory.read_bytes(point_ptr)?;
-
-    let ay = Fq::from_slice(&arg_bytes[..32])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let ax = Fq::from_slice(&arg_bytes[32..64])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let by = Fq::from_slice(&arg_bytes[64..96])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-    let bx = Fq::from_slice(&arg_bytes[96..128])
-        .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-
-    let a = Fq2::new(ax, ay);
-    let b = Fq2::new(bx, by);
-    Ok(if a.is_zero() && b.is_zero() {
-        G2::zero()
-    } else {
-        G2::from(
-            AffineG2::new(a, b)
-                .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?,
-        )
-    })
-}
-
 pub(crate) fn ec_operation(
     memory: &mut MemoryInstance,
     owner: OwnershipRegisters,
@@ -305,57 +269,12 @@ pub(crate) fn ec_operation(
     operation_type: Word,
     points_ptr: Word,
 ) -> SimpleResult<()> {
-    match curve_id {
-        0 => {
-            match operation_type {
-                // Two points addition
-                0 => {
-                    let point1 = read_g1_point_alt_bn_128(memory, points_ptr)?;
-                    let point2 = read_g1_point_alt_bn_128(
-                        memory,
-                        points_ptr
-                            .checked_add(64)
-                            .ok_or(fuel_tx::PanicReason::MemoryOverflow)?,
-                    )?;
-                    let mut output = [0u8; 64];
-                    // SAFETY: The library override the addition and is tested and
-                    // audited. Here is the code of the addition :
-                    // https://github.com/paritytech/bn/blob/63f8c587356a67b33c7396af98e065b66fca5dda/src/groups/mod.rs#L297
-                    #[allow(clippy::arithmetic_side_effects)]
-                    if let Some(sum) = AffineG1::from_jacobian(point1 + point2) {
-                        sum.x().to_big_endian(&mut output[..32]).unwrap();
-                        sum.y().to_big_endian(&mut output[32..]).unwrap();
-                    }
-                    memory.write_bytes(owner, dst, output)?;
-                }
-                // Scalar multiplication
-                1 => {
-                    let point = read_g1_point_alt_bn_128(memory, points_ptr)?;
-                    let scalar = Fr::from_slice(
-                        memory.read(
-                            points_ptr
-                                .checked_add(64)
-                                .ok_or(fuel_tx::PanicReason::MemoryOverflow)?,
-                            32u64,
-                        )?,
-                    )
-                    .map_err(|_| fuel_tx::PanicReason::InvalidEllipticCurvePoint)?;
-                    let mut output = [0u8; 64];
-                    // SAFETY: The library override the multiplication and is tested and
-                    // audited. Here is the code of the multiplication
-                    // : https://github.com/paritytech/bn/blob/63f8c587356a67b33c7396af98e065b66fca5dda/src/groups/mod.rs#L275
-                    #[allow(clippy::arithmetic_side_effects)]
-                    if let Some(product) = AffineG1::from_jacobian(point * scalar) {
-                        product.x().to_big_endian(&mut output[..32]).unwrap();
-                        product.y().to_big_endian(&mut output[32..]).unwrap();
-                    }
-                    memory.write_bytes(owner, dst, output)?;
-                }
-                _ => return Err(fuel_tx::PanicReason::UnsupportedOperationType.into()),
-            }
-        }
-        _ => return Err(fuel_tx::PanicReason::UnsupportedCurveId.into()),
-    }
+    let curve = curve(curve_id)?;
+    let operation = EcOperation::try_from(operation_type)?;
+    let output = curve.operation(memory, operation, points_ptr)?;
+    memory
+        .write(owner, dst, output.len())?
+        .copy_from_slice(&output);
     Ok(inc_pc(pc)?)
 }
 
@@ -363,39 +282,11 @@ pub(crate) fn ec_pairing(
     memory: &mut MemoryInstance,
     pc: RegMut<PC>,
     success: &mut u64,
-    identifier: Word,
-    number_elements: Word,
-    elements_ptr: Word,
+    curve_id: Word,
+    number_pairs: Word,
+    pairs_ptr: Word,
 ) -> SimpleResult<()> {
-    match identifier {
-        // Optimal ate pairing / alt_bn128
-        0 => {
-            // Each element consists of an uncompressed G1 point (64 bytes) and an
-            // uncompressed G2 point (128 bytes).
-            let element_size = 128 + 64;
-            let mut elements = Vec::with_capacity(
-                usize::try_from(number_elements)
-                    .map_err(|_| fuel_tx::PanicReason::MemoryOverflow)?,
-            );
-            for idx in 0..number_elements {
-                let start_offset = elements_ptr
-                    .checked_add(
-                        idx.checked_mul(element_size)
-                            .ok_or(fuel_tx::PanicReason::MemoryOverflow)?,
-                    )
-                    .ok_or(fuel_tx::PanicReason::MemoryOverflow)?;
-                let a = read_g1_point_alt_bn_128(memory, start_offset)?;
-                let b = read_g2_point_alt_bn_128(
-                    memory,
-                    start_offset
-                        .checked_add(64)
-                        .ok_or(fuel_tx::PanicReason::MemoryOverflow)?,
-                )?;
-                elements.push((a, b));
-            }
-            *success = (bn::pairing_batch(&elements) == Gt::one()) as u64;
-        }
-        _ => return Err(fuel_tx::PanicReason::UnsupportedOperationType.into()),
-    }
+    let curve = curve(curve_id)?;
+    *success = curve.pairing_check(memory, number_pairs, pairs_ptr)? as u64;
     Ok(inc_pc(pc)?)
 }
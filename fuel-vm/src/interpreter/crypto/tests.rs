@@ -1,5 +1,8 @@
-#![allow(clippy::cast_possible_truncation)]
-use alloc::vec;
+#![allow(clippy::cast_possible_truncation, non_snake_case)]
+use alloc::{
+    vec,
+    vec::Vec,
+};
 
 use fuel_crypto::SecretKey;
 use rand::{
@@ -57,6 +60,136 @@ fn test_recover_secp256k1() -> SimpleResult<()> {
     Ok(())
 }
 
+/// The implementation of `ECK1` before it started borrowing the signature and
+/// the message from the memory, used as the reference for the differential tests.
+fn secp256k1_recover_reference(
+    memory: &mut MemoryInstance,
+    owner: OwnershipRegisters,
+    err: RegMut<ERR>,
+    pc: RegMut<PC>,
+    a: Word,
+    b: Word,
+    c: Word,
+) -> SimpleResult<()> {
+    let sig = Bytes64::from(memory.read_bytes(b)?);
+    let msg = Bytes32::from(memory.read_bytes(c)?);
+
+    let signature = Signature::from_bytes_ref(&sig);
+    let message = Message::from_bytes_ref(&msg);
+
+    match signature.recover(message) {
+        Ok(pub_key) => {
+            memory.write_bytes(owner, a, *pub_key)?;
+            clear_err(err);
+        }
+        Err(_) => {
+            memory.write_bytes(owner, a, [0; PublicKey::LEN])?;
+            set_err(err);
+        }
+    }
+
+    Ok(inc_pc(pc)?)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Corruption {
+    None,
+    SignatureByte,
+    MessageByte,
+    RandomSignature,
+}
+
+#[rstest]
+#[case::owned_destination(2100, 0, 64)]
+#[case::not_owned_destination(1500, 0, 64)]
+#[case::destination_overflow(VM_MAX_RAM - 1, 0, 64)]
+#[case::signature_overflow(2100, VM_MAX_RAM - 1, 64)]
+#[case::message_overflow(2100, 0, VM_MAX_RAM - 1)]
+fn secp256k1_recover__matches_reference_implementation(
+    #[case] recovered: Word,
+    #[case] sig_address: Word,
+    #[case] msg_address: Word,
+    #[values(
+        Corruption::None,
+        Corruption::SignatureByte,
+        Corruption::MessageByte,
+        Corruption::RandomSignature
+    )]
+    corruption: Corruption,
+) {
+    let rng = &mut StdRng::seed_from_u64(0xec1);
+    let owner = OwnershipRegisters {
+        sp: 1000,
+        ssp: 1000,
+        hp: 2000,
+        prev_hp: VM_MAX_RAM - 1,
+    };
+    let mut reference_memory: MemoryInstance = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory = reference_memory.clone();
+
+    for _ in 0..16 {
+        let mut secret = [0u8; 32];
+        rng.fill_bytes(&mut secret);
+        let Ok(secret) = SecretKey::try_from(&secret[..]) else {
+            continue
+        };
+        let mut message = [0u8; 32];
+        rng.fill_bytes(&mut message);
+        let message = Message::from_bytes(message);
+        let mut signature: [u8; 64] = *Signature::sign(&secret, &message);
+        let mut message: [u8; 32] = *message;
+
+        let index = rng.next_u32() as usize;
+        match corruption {
+            Corruption::None => {}
+            Corruption::SignatureByte => signature[index % 64] ^= 0x01,
+            Corruption::MessageByte => message[index % 32] ^= 0x01,
+            Corruption::RandomSignature => rng.fill_bytes(&mut signature),
+        }
+
+        for target in [&mut reference_memory, &mut memory] {
+            if let Ok(dst) = target.write_noownerchecks(recovered, PublicKey::LEN) {
+                dst.fill(1);
+            }
+            if let Ok(dst) = target.write_noownerchecks(sig_address, Signature::LEN) {
+                dst.copy_from_slice(&signature);
+            }
+            if let Ok(dst) = target.write_noownerchecks(msg_address, Message::LEN) {
+                dst.copy_from_slice(&message);
+            }
+        }
+
+        let (mut reference_err, mut reference_pc) = (0, 4);
+        let reference_result = secp256k1_recover_reference(
+            &mut reference_memory,
+            owner,
+            RegMut::new(&mut reference_err),
+            RegMut::new(&mut reference_pc),
+            recovered,
+            sig_address,
+            msg_address,
+        );
+
+        let (mut err, mut pc) = (0, 4);
+        let result = secp256k1_recover(
+            &mut memory,
+            owner,
+            RegMut::new(&mut err),
+            RegMut::new(&mut pc),
+            recovered,
+            sig_address,
+            msg_address,
+        );
+
+        assert_eq!(result, reference_result);
+        assert_eq!((err, pc), (reference_err, reference_pc));
+        assert_eq!(
+            memory.read(recovered, PublicKey::LEN),
+            reference_memory.read(recovered, PublicKey::LEN)
+        );
+    }
+}
+
 #[test]
 fn test_recover_secp256r1() -> SimpleResult<()> {
     use fuel_crypto::secp256r1::encode_pubkey;
@@ -165,6 +298,7 @@ fn test_keccak256() -> SimpleResult<()> {
         hash,
         bytes_address,
         num_bytes,
+        false,
     )?;
     assert_eq!(pc, 8);
     assert_ne!(&memory[hash as usize..hash as usize + 32], &[1u8; 32][..]);
@@ -191,6 +325,7 @@ fn test_sha256() -> SimpleResult<()> {
         hash,
         bytes_address,
         num_bytes,
+        false,
     )?;
     assert_eq!(pc, 8);
     assert_ne!(&memory[hash as usize..hash as usize + 32], &[1u8; 32][..]);
@@ -0,0 +1,247 @@
+//! Registry of the elliptic curves supported by the `ECOP` and `EPAR` instructions.
+//!
+//! Every curve implements [`EllipticCurveOps`] and is selected by the `curve_id`
+//! register. Supporting a new curve only requires a new implementation and an entry
+//! in [`curve`], together with its gas costs.
+
+use super::MemoryInstance;
+use crate::error::SimpleResult;
+
+use alloc::vec::Vec;
+use bn::{
+    AffineG1,
+    AffineG2,
+    Fq,
+    Fq2,
+    Fr,
+    Group,
+    Gt,
+    G1,
+    G2,
+};
+use fuel_asm::PanicReason;
+use fuel_tx::{
+    consensus_parameters::GasCostNotDefined,
+    DependentCost,
+    GasCostsValues,
+};
+use fuel_types::Word;
+
+/// The operation performed by the `ECOP` instruction, selected by the
+/// `operation_type` register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EcOperation {
+    /// Addition of two points.
+    Add,
+    /// Multiplication of a point by a scalar.
+    Mul,
+}
+
+impl TryFrom<Word> for EcOperation {
+    type Error = PanicReason;
+
+    fn try_from(operation_type: Word) -> Result<Self, Self::Error> {
+        match operation_type {
+            0 => Ok(Self::Add),
+            1 => Ok(Self::Mul),
+            _ => Err(PanicReason::UnsupportedOperationType),
+        }
+    }
+}
+
+/// Operations and their gas costs for one elliptic curve.
+pub(crate) trait EllipticCurveOps {
+    /// Adds the two points at `points_ptr`, returning the encoded sum.
+    fn add(&self, memory: &MemoryInstance, points_ptr: Word) -> SimpleResult<Vec<u8>>;
+
+    /// Multiplies the point at `points_ptr` by the scalar following it, returning
+    /// the encoded product.
+    fn mul(&self, memory: &MemoryInstance, points_ptr: Word) -> SimpleResult<Vec<u8>>;
+
+    /// Checks that the product of the pairings of the `number_pairs` pairs at
+    /// `pairs_ptr` is one.
+    fn pairing_check(
+        &self,
+        memory: &MemoryInstance,
+        number_pairs: Word,
+        pairs_ptr: Word,
+    ) -> SimpleResult<bool>;
+
+    /// The gas cost of the `operation`.
+    fn operation_cost(
+        &self,
+        gas_costs: &GasCostsValues,
+        operation: EcOperation,
+    ) -> Result<Word, GasCostNotDefined>;
+
+    /// The gas cost of the pairing check, charged per pair.
+    fn pairing_cost(
+        &self,
+        gas_costs: &GasCostsValues,
+    ) -> Result<DependentCost, GasCostNotDefined>;
+
+    /// Performs the `operation` on the points at `points_ptr`.
+    fn operation(
+        &self,
+        memory: &MemoryInstance,
+        operation: EcOperation,
+        points_ptr: Word,
+    ) -> SimpleResult<Vec<u8>> {
+        match operation {
+            EcOperation::Add => self.add(memory, points_ptr),
+            EcOperation::Mul => self.mul(memory, points_ptr),
+        }
+    }
+}
+
+/// Returns the curve selected by the `curve_id` register.
+pub(crate) fn curve(
+    curve_id: Word,
+) -> Result<&'static dyn EllipticCurveOps, PanicReason> {
+    match curve_id {
+        0 => Ok(&Bn254),
+        _ => Err(PanicReason::UnsupportedCurveId),
+    }
+}
+
+/// The alt_bn128 curve, with the optimal ate pairing.
+pub(crate) struct Bn254;
+
+impl Bn254 {
+    /// Size of the uncompressed G1 point.
+    const G1_SIZE: Word = 64;
+    /// Size of the uncompressed G2 point.
+    const G2_SIZE: Word = 128;
+    /// Size of the scalar.
+    const SCALAR_SIZE: Word = 32;
+
+    fn read_g1_point(memory: &MemoryInstance, point_ptr: Word) -> SimpleResult<G1> {
+        // Big endian required by the library
+        let arg_bytes: [u8; 2 * 32] = memory.read_bytes(point_ptr)?;
+
+        let px = Fq::from_slice(&arg_bytes[..32])
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?;
+        let py = Fq::from_slice(&arg_bytes[32..64])
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?;
+
+        Ok(if px == Fq::zero() && py == Fq::zero() {
+            G1::zero()
+        } else {
+            AffineG1::new(px, py)
+                .map(Into::into)
+                .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?
+        })
+    }
+
+    fn read_g2_point(memory: &MemoryInstance, point_ptr: Word) -> SimpleResult<G2> {
+        // Big endian required by the library
+        let arg_bytes: [u8; 4 * 32] = memory.read_bytes(point_ptr)?;
+
+        let ay = Fq::from_slice(&arg_bytes[..32])
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?;
+        let ax = Fq::from_slice(&arg_bytes[32..64])
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?;
+        let by = Fq::from_slice(&arg_bytes[64..96])
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?;
+        let bx = Fq::from_slice(&arg_bytes[96..128])
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?;
+
+        let a = Fq2::new(ax, ay);
+        let b = Fq2::new(bx, by);
+        Ok(if a.is_zero() && b.is_zero() {
+            G2::zero()
+        } else {
+            G2::from(
+                AffineG2::new(a, b)
+                    .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?,
+            )
+        })
+    }
+
+    /// Encodes the point, the point at infinity is encoded as zeroes.
+    fn encode_g1_point(point: G1) -> Vec<u8> {
+        let mut output = alloc::vec![0u8; 64];
+        if let Some(point) = AffineG1::from_jacobian(point) {
+            point.x().to_big_endian(&mut output[..32]).unwrap();
+            point.y().to_big_endian(&mut output[32..]).unwrap();
+        }
+        output
+    }
+}
+
+fn offset(ptr: Word, offset: Word) -> Result<Word, PanicReason> {
+    ptr.checked_add(offset).ok_or(PanicReason::MemoryOverflow)
+}
+
+impl EllipticCurveOps for Bn254 {
+    fn add(&self, memory: &MemoryInstance, points_ptr: Word) -> SimpleResult<Vec<u8>> {
+        let point1 = Self::read_g1_point(memory, points_ptr)?;
+        let point2 = Self::read_g1_point(memory, offset(points_ptr, Self::G1_SIZE)?)?;
+        // SAFETY: The library override the addition and is tested and
+        // audited. Here is the code of the addition :
+        // https://github.com/paritytech/bn/blob/63f8c587356a67b33c7396af98e065b66fca5dda/src/groups/mod.rs#L297
+        #[allow(clippy::arithmetic_side_effects)]
+        let sum = point1 + point2;
+        Ok(Self::encode_g1_point(sum))
+    }
+
+    fn mul(&self, memory: &MemoryInstance, points_ptr: Word) -> SimpleResult<Vec<u8>> {
+        let point = Self::read_g1_point(memory, points_ptr)?;
+        let scalar = Fr::from_slice(
+            memory.read(offset(points_ptr, Self::G1_SIZE)?, Self::SCALAR_SIZE)?,
+        )
+        .map_err(|_| PanicReason::InvalidEllipticCurvePoint)?;
+        // SAFETY: The library override the multiplication and is tested and
+        // audited. Here is the code of the multiplication
+        // : https://github.com/paritytech/bn/blob/63f8c587356a67b33c7396af98e065b66fca5dda/src/groups/mod.rs#L275
+        #[allow(clippy::arithmetic_side_effects)]
+        let product = point * scalar;
+        Ok(Self::encode_g1_point(product))
+    }
+
+    fn pairing_check(
+        &self,
+        memory: &MemoryInstance,
+        number_pairs: Word,
+        pairs_ptr: Word,
+    ) -> SimpleResult<bool> {
+        // Each pair consists of an uncompressed G1 point (64 bytes) and an
+        // uncompressed G2 point (128 bytes).
+        let pair_size = Self::G1_SIZE.saturating_add(Self::G2_SIZE);
+        let mut pairs = Vec::with_capacity(
+            usize::try_from(number_pairs).map_err(|_| PanicReason::MemoryOverflow)?,
+        );
+        for idx in 0..number_pairs {
+            let start_offset = offset(
+                pairs_ptr,
+                idx.checked_mul(pair_size)
+                    .ok_or(PanicReason::MemoryOverflow)?,
+            )?;
+            let a = Self::read_g1_point(memory, start_offset)?;
+            let b = Self::read_g2_point(memory, offset(start_offset, Self::G1_SIZE)?)?;
+            pairs.push((a, b));
+        }
+        Ok(bn::pairing_batch(&pairs) == Gt::one())
+    }
+
+    fn operation_cost(
+        &self,
+        gas_costs: &GasCostsValues,
+        operation: EcOperation,
+    ) -> Result<Word, GasCostNotDefined> {
+        match operation {
+            EcOperation::Add => gas_costs.ecop_bn254_add(),
+            EcOperation::Mul => gas_costs.ecop_bn254_mul(),
+        }
+    }
+
+    fn pairing_cost(
+        &self,
+        gas_costs: &GasCostsValues,
+    ) -> Result<DependentCost, GasCostNotDefined> {
+        gas_costs.epar_bn254()
+    }
+}
+
+#[cfg(test)]
+mod tests;
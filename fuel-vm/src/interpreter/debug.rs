@@ -1,6 +1,22 @@
-use super::Interpreter;
-use crate::prelude::*;
-use fuel_asm::RegId;
+use super::{
+    Interpreter,
+    Memory,
+    MemoryRange,
+};
+use crate::{
+    consts::WORD_SIZE,
+    prelude::*,
+    state::WriteTarget,
+};
+use fuel_asm::{
+    Instruction,
+    RegId,
+};
+use fuel_types::{
+    bytes::padded_len_word,
+    Bytes32,
+    ContractId,
+};
 
 impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
@@ -39,6 +55,26 @@ where
         self.debugger.remove_breakpoint(breakpoint)
     }
 
+    /// Break the execution after an instruction writes to the memory range.
+    ///
+    /// Checked for `SB`, `SW`, `MCL`, `MCLI`, `MCP`, `MCPI`, `CCP` and `LDC`.
+    pub fn add_memory_watch(&mut self, range: MemoryRange) {
+        self.debugger.add_memory_watch(range)
+    }
+
+    /// Break the execution after an instruction writes to the storage slot of the
+    /// contract.
+    ///
+    /// Checked for `SWW`, `SWWQ` and `SCWQ`.
+    pub fn add_storage_watch(&mut self, contract: ContractId, key: Bytes32) {
+        self.debugger.add_storage_watch(contract, key)
+    }
+
+    /// Clear all set memory and storage watches.
+    pub fn clear_watches(&mut self) {
+        self.debugger.clear_watches();
+    }
+
     pub(crate) fn eval_debugger_state(&mut self) -> DebugEval {
         let debugger = &mut self.debugger;
 
@@ -57,6 +93,107 @@ where
     }
 }
 
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
+where
+    M: Memory,
+    Tx: ExecutableTransaction,
+{
+    /// Returns the location the `instruction` is about to write, together with the
+    /// location of the instruction itself, if it may touch a watch.
+    ///
+    /// Must be called before the instruction is executed. `None` is returned if the
+    /// write target can't be resolved, since the instruction panics in that case.
+    pub(crate) fn watched_write(
+        &self,
+        instruction: Instruction,
+    ) -> Option<(Breakpoint, WriteTarget)> {
+        macro_rules! r {
+            ($id:expr) => {
+                self.registers[$id]
+            };
+        }
+
+        let memory = |addr: Word, len: Word| {
+            Some(WriteTarget::Memory(
+                MemoryRange::checked_new(addr, len).ok()?,
+            ))
+        };
+        let storage = |key: Word, slots: Word| {
+            let contract = *self.frames.last()?.to();
+            let start_key = Bytes32::new(self.memory().read_bytes(key).ok()?);
+            Some(WriteTarget::Storage {
+                contract,
+                start_key,
+                slots,
+            })
+        };
+
+        let target = match instruction {
+            Instruction::SB(sb) => {
+                let (a, _, imm) = sb.unpack();
+                memory(r!(a).checked_add(imm.into())?, 1)
+            }
+            Instruction::SW(sw) => {
+                let (a, _, imm) = sw.unpack();
+                let offset = Word::from(imm).checked_mul(WORD_SIZE as Word)?;
+                memory(r!(a).checked_add(offset)?, WORD_SIZE as Word)
+            }
+            Instruction::SHW(shw) => {
+                let (a, _, imm) = shw.unpack();
+                memory(r!(a).checked_add(Word::from(imm).checked_mul(4)?)?, 4)
+            }
+            Instruction::SQW(sqw) => {
+                let (a, _, imm) = sqw.unpack();
+                memory(r!(a).checked_add(Word::from(imm).checked_mul(2)?)?, 2)
+            }
+            Instruction::MCL(mcl) => {
+                let (a, b) = mcl.unpack();
+                memory(r!(a), r!(b))
+            }
+            Instruction::MCLI(mcli) => {
+                let (a, imm) = mcli.unpack();
+                memory(r!(a), imm.into())
+            }
+            Instruction::MCP(mcp) => {
+                let (a, _, c) = mcp.unpack();
+                memory(r!(a), r!(c))
+            }
+            Instruction::MCPI(mcpi) => {
+                let (a, _, imm) = mcpi.unpack();
+                memory(r!(a), imm.into())
+            }
+            Instruction::CCP(ccp) => {
+                let (a, _, _, d) = ccp.unpack();
+                memory(r!(a), r!(d))
+            }
+            Instruction::LDC(ldc) => {
+                // The code is always loaded at the top of the stack.
+                let (_, _, c, _) = ldc.unpack();
+                memory(r!(RegId::SSP), padded_len_word(r!(c))?)
+            }
+            Instruction::SWW(sww) => {
+                let (a, _, _) = sww.unpack();
+                storage(r!(a), 1)
+            }
+            Instruction::SWWQ(swwq) => {
+                let (a, _, _, d) = swwq.unpack();
+                storage(r!(a), r!(d))
+            }
+            Instruction::SCWQ(scwq) => {
+                let (a, _, c) = scwq.unpack();
+                storage(r!(a), r!(c))
+            }
+            _ => None,
+        }?;
+
+        let contract = self.frames.last().map(CallFrame::to);
+        let pc = self.registers[RegId::PC].saturating_sub(self.registers[RegId::IS]);
+        let location = Breakpoint::raw(contract.copied().unwrap_or_default(), pc);
+
+        Some((location, target))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::{
@@ -2,6 +2,7 @@ use core::fmt::Debug;
 use hashbrown::HashMap;
 
 use fuel_storage::{
+    StorageBatchMutate,
     StorageRead,
     StorageSize,
     StorageWrite,
@@ -97,6 +98,7 @@ where
             registers: self.registers,
             memory: self.memory,
             frames: self.frames,
+            returned_call_gas: self.returned_call_gas,
             receipts: self.receipts,
             tx: self.tx,
             initial_balances: self.initial_balances,
@@ -104,6 +106,7 @@ where
             input_contracts_index_to_output_index: self
                 .input_contracts_index_to_output_index,
             storage: self.storage.0,
+            #[cfg(feature = "debugger")]
             debugger: self.debugger,
             context: self.context,
             balances: self.balances,
@@ -111,6 +114,10 @@ where
             profiler: self.profiler,
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
+            code_cache: self.code_cache,
+            contract_info: self.contract_info,
+            storage_written: self.storage_written,
+            storage_journal: self.storage_journal,
         }
     }
 
@@ -187,6 +194,7 @@ where
             registers: self.registers,
             memory: self.memory,
             frames: self.frames,
+            returned_call_gas: self.returned_call_gas,
             receipts: self.receipts,
             tx: self.tx,
             initial_balances: self.initial_balances,
@@ -194,6 +202,7 @@ where
             input_contracts_index_to_output_index: self
                 .input_contracts_index_to_output_index,
             storage: Record::new(self.storage),
+            #[cfg(feature = "debugger")]
             debugger: self.debugger,
             context: self.context,
             balances: self.balances,
@@ -201,6 +210,10 @@ where
             profiler: self.profiler,
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
+            code_cache: self.code_cache,
+            contract_info: self.contract_info,
+            storage_written: self.storage_written,
+            storage_journal: self.storage_journal,
         }
     }
 
@@ -413,6 +426,15 @@ where
     }
 }
 
+// Every entry of the batch goes through `StorageMutate` to be recorded.
+impl<Type: StorageType, S> StorageBatchMutate<Type> for Record<S>
+where
+    S: StorageInspect<Type>,
+    S: StorageMutate<Type>,
+    S: InterpreterStorage,
+{
+}
+
 impl<Type: StorageType, S> StorageWrite<Type> for Record<S>
 where
     S: StorageWrite<Type>,
@@ -487,6 +509,18 @@ where
         self.0.set_state_transition_bytecode(version, hash)
     }
 
+    fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        self.0.consensus_parameters_history()
+    }
+
+    fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        self.0.state_transition_history()
+    }
+
     fn contract_state_range(
         &self,
         id: &ContractId,
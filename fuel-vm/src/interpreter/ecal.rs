@@ -1,15 +1,21 @@
 //! See `fuel-vm/examples/external.rs` for example usage.
 
+use alloc::vec::Vec;
+
 use fuel_asm::{
     PanicReason,
     RegId,
+    Word,
 };
+use fuel_tx::GasCosts;
 
 use crate::{
     constraints::reg_key::{
         split_registers,
         SystemRegisters,
+        WriteRegKey,
     },
+    consts::VM_REGISTER_COUNT,
     error::SimpleResult,
     interpreter::NotSupportedEcal,
 };
@@ -18,8 +24,49 @@ use super::{
     internal::inc_pc,
     Interpreter,
     Memory,
+    MemoryInstance,
 };
 
+/// The outcome of the [`CheckedEcalHandler::ecal`], applied by the VM after the
+/// handler returns.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EcalOutcome {
+    /// The gas charged before applying the rest of the outcome.
+    pub gas: Word,
+    /// The value written to the register `a` of the instruction.
+    pub ra: Option<Word>,
+    /// The value written to the register `b` of the instruction.
+    pub rb: Option<Word>,
+    /// The bytes written to the memory at the given addresses. Every write is
+    /// checked for the ownership, same as for the store instructions.
+    pub mem_writes: Vec<(Word, Vec<u8>)>,
+}
+
+/// Read-only view of the VM available to the [`CheckedEcalHandler`].
+#[derive(Debug, Clone, Copy)]
+pub struct EcalContext<'a> {
+    registers: &'a [Word; VM_REGISTER_COUNT],
+    memory: &'a MemoryInstance,
+    gas_costs: &'a GasCosts,
+}
+
+impl<'a> EcalContext<'a> {
+    /// Returns the registers of the VM.
+    pub fn registers(&self) -> &'a [Word; VM_REGISTER_COUNT] {
+        self.registers
+    }
+
+    /// Returns the memory of the VM.
+    pub fn memory(&self) -> &'a MemoryInstance {
+        self.memory
+    }
+
+    /// Returns the gas costs of the VM.
+    pub fn gas_costs(&self) -> &'a GasCosts {
+        self.gas_costs
+    }
+}
+
 /// ECAL opcode handler
 pub trait EcalHandler: Clone
 where
@@ -41,6 +88,45 @@ where
         M: Memory;
 }
 
+/// ECAL opcode handler without the mutable access to the VM.
+///
+/// Instead, the handler returns the [`EcalOutcome`], which the VM applies with the
+/// same checks as for the regular instructions. Every type implementing this trait
+/// is an [`EcalHandler`].
+pub trait CheckedEcalHandler: Clone
+where
+    Self: Sized,
+{
+    /// ECAL opcode handler. The `a`, `b`, `c` and `d` are the values of the
+    /// registers of the instruction.
+    fn ecal(
+        &mut self,
+        context: EcalContext<'_>,
+        a: Word,
+        b: Word,
+        c: Word,
+        d: Word,
+    ) -> SimpleResult<EcalOutcome>;
+}
+
+impl<T> EcalHandler for T
+where
+    T: CheckedEcalHandler,
+{
+    fn ecal<M, S, Tx>(
+        vm: &mut Interpreter<M, S, Tx, Self>,
+        a: RegId,
+        b: RegId,
+        c: RegId,
+        d: RegId,
+    ) -> SimpleResult<()>
+    where
+        M: Memory,
+    {
+        vm.apply_ecal_outcome(a, b, c, d)
+    }
+}
+
 /// Default ECAL opcode handler function, which just errors immediately.
 impl EcalHandler for NotSupportedEcal {
     fn ecal<M, S, Tx>(
@@ -94,6 +180,72 @@ where
     }
 }
 
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
+where
+    M: Memory,
+    Ecal: CheckedEcalHandler,
+{
+    /// Runs the [`CheckedEcalHandler::ecal`] and applies its outcome. Nothing is
+    /// applied unless all the register and memory writes are allowed.
+    fn apply_ecal_outcome(
+        &mut self,
+        a: RegId,
+        b: RegId,
+        c: RegId,
+        d: RegId,
+    ) -> SimpleResult<()> {
+        let context = EcalContext {
+            registers: &self.registers,
+            memory: self.memory.as_ref(),
+            gas_costs: &self.interpreter_params.gas_costs,
+        };
+        let EcalOutcome {
+            gas,
+            ra,
+            rb,
+            mem_writes,
+        } = self.ecal_state.ecal(
+            context,
+            self.registers[a],
+            self.registers[b],
+            self.registers[c],
+            self.registers[d],
+        )?;
+
+        self.gas_charge(gas)?;
+
+        let ra = ra
+            .map(|value| WriteRegKey::try_from(usize::from(a)).map(|key| (key, value)))
+            .transpose()?;
+        let rb = rb
+            .map(|value| WriteRegKey::try_from(usize::from(b)).map(|key| (key, value)))
+            .transpose()?;
+
+        let owner = self.ownership_registers();
+        let memory = self.memory.as_mut();
+        let ranges = mem_writes
+            .iter()
+            .map(|(addr, bytes)| {
+                let range = memory.verify(*addr, bytes.len())?;
+                owner.verify_ownership(&range)?;
+                Ok(range)
+            })
+            .collect::<Result<Vec<_>, PanicReason>>()?;
+        for (range, (_, bytes)) in ranges.into_iter().zip(mem_writes) {
+            memory
+                .write_noownerchecks(range.start(), range.len())?
+                .copy_from_slice(&bytes);
+        }
+
+        let (_, mut w) = split_registers(&mut self.registers);
+        for (key, value) in ra.into_iter().chain(rb) {
+            w[key] = value;
+        }
+
+        Ok(())
+    }
+}
+
 impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     Ecal: EcalHandler,
@@ -2,6 +2,7 @@ mod instruction;
 mod main;
 mod predicate;
 
+#[cfg(feature = "debugger")]
 mod debug;
 
 pub use main::predicates;
@@ -1,5 +1,6 @@
 use crate::{
     constraints::reg_key::ProgramRegistersSegment,
+    context::ContextKind,
     error::{
         InterpreterError,
         IoResult,
@@ -23,6 +24,7 @@ use crate::{
 use fuel_asm::{
     wideint,
     Instruction,
+    Opcode,
     PanicInstruction,
     PanicReason,
     RawInstruction,
@@ -73,6 +75,7 @@ where
         &mut self,
         raw: R,
     ) -> Result<ExecuteState, InterpreterError<S::DataError>> {
+        #[cfg(feature = "debugger")]
         if self.debugger.is_active() {
             let debug = self.eval_debugger_state();
             if !debug.should_continue() {
@@ -80,8 +83,37 @@ where
             }
         }
 
-        self.instruction_inner(raw.into())
-            .map_err(|e| InterpreterError::from_runtime(e, raw.into()))
+        // The watches are checked only if any are set, to keep the regular
+        // execution free of the additional decoding.
+        #[cfg(feature = "debugger")]
+        let watched_write = if self.debugger.has_watches() {
+            Instruction::try_from(raw.into())
+                .ok()
+                .and_then(|instruction| self.watched_write(instruction))
+        } else {
+            None
+        };
+
+        #[cfg(feature = "debugger")]
+        let instruction_index = self.instruction_index();
+
+        let state = self.instruction_inner(raw.into()).map_err(|e| {
+            InterpreterError::from_runtime(e.resolve_storage(), raw.into())
+        })?;
+
+        #[cfg(feature = "debugger")]
+        self.debugger
+            .record_context(instruction_index, &self.context);
+
+        #[cfg(feature = "debugger")]
+        if let Some((location, target)) = watched_write {
+            let debug = self.debugger.eval_write(location, &target);
+            if !debug.should_continue() {
+                return Ok(debug.into())
+            }
+        }
+
+        Ok(state)
     }
 
     fn instruction_inner(
@@ -93,7 +125,12 @@ where
 
         // TODO additional branch that might be optimized after
         // https://github.com/FuelLabs/fuel-asm/issues/68
-        if self.is_predicate() && !instruction.opcode().is_predicate_allowed() {
+        let opcode = instruction.opcode();
+        // The predicates may return data only during the estimation, so the tooling
+        // can introspect them while the verification stays unchanged.
+        let is_estimation_retd = opcode == Opcode::RETD
+            && self.context.kind() == ContextKind::PredicateEstimation;
+        if self.is_predicate() && !opcode.is_predicate_allowed() && !is_estimation_retd {
             return Err(PanicReason::ContractInstructionNotAllowed.into())
         }
 
@@ -593,6 +630,10 @@ where
                 self.gas_charge(self.gas_costs().rvrt())?;
                 let a = rvrt.unpack();
                 let ra = r!(a);
+                if self.reverts_call_frame() {
+                    self.revert_call_frame(ra)?;
+                    return Ok(ExecuteState::Proceed)
+                }
                 self.revert(ra)?;
                 return Ok(ExecuteState::Revert(ra))
             }
@@ -671,6 +712,18 @@ where
                 self.load_word(a.into(), r!(b), imm)?;
             }
 
+            Instruction::LHW(lhw) => {
+                self.gas_charge(self.gas_costs().lhw().map_err(PanicReason::from)?)?;
+                let (a, b, imm) = lhw.unpack();
+                self.load_half_word(a.into(), r!(b), imm)?;
+            }
+
+            Instruction::LQW(lqw) => {
+                self.gas_charge(self.gas_costs().lqw().map_err(PanicReason::from)?)?;
+                let (a, b, imm) = lqw.unpack();
+                self.load_quarter_word(a.into(), r!(b), imm)?;
+            }
+
             Instruction::MCL(mcl) => {
                 let (a, b) = mcl.unpack();
                 let len = r!(b);
@@ -718,6 +771,18 @@ where
                 self.store_word(r!(a), r!(b), imm)?;
             }
 
+            Instruction::SHW(shw) => {
+                self.gas_charge(self.gas_costs().shw().map_err(PanicReason::from)?)?;
+                let (a, b, imm) = shw.unpack();
+                self.store_half_word(r!(a), r!(b), imm)?;
+            }
+
+            Instruction::SQW(sqw) => {
+                self.gas_charge(self.gas_costs().sqw().map_err(PanicReason::from)?)?;
+                let (a, b, imm) = sqw.unpack();
+                self.store_quarter_word(r!(a), r!(b), imm)?;
+            }
+
             Instruction::BAL(bal) => {
                 self.gas_charge(self.gas_costs().bal())?;
                 let (a, b, c) = bal.unpack();
@@ -878,8 +943,8 @@ where
             }
 
             Instruction::GM(gm) => {
-                self.gas_charge(self.gas_costs().gm())?;
                 let (a, imm) = gm.unpack();
+                self.gas_charge(self.metadata_gas_cost(imm.into())?)?;
                 self.metadata(a.into(), imm.into())?;
             }
 
@@ -919,19 +984,15 @@ where
             }
 
             Instruction::ECOP(ecop) => {
-                self.gas_charge(self.gas_costs().ecop().map_err(PanicReason::from)?)?;
+                // We charge for this inside the function.
                 let (a, b, c, d) = ecop.unpack();
                 self.ec_operation(r!(a), r!(b), r!(c), r!(d))?;
             }
 
             Instruction::EPAR(epar) => {
+                // We charge for this inside the function.
                 let (a, b, c, d) = epar.unpack();
-                let len = r!(c);
-                self.dependent_gas_charge(
-                    self.gas_costs().epar().map_err(PanicReason::from)?,
-                    len,
-                )?;
-                self.ec_pairing(a.into(), r!(b), len, r!(d))?;
+                self.ec_pairing(a.into(), r!(b), r!(c), r!(d))?;
             }
         }
 
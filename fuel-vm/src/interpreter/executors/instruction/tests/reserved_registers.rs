@@ -96,10 +96,10 @@ fn cant_write_to_reserved_registers(raw_random_instruction: u32) -> TestResult {
             // and thus fail before the destination register writability check occurs.
             Err(Some(PanicReason::InvalidImmediateValue)) => return TestResult::discard(),
             // Epar opcode parse the memory read and throw error if incorrect memory
-            // before changing the register
-            Err(Some(PanicReason::InvalidEllipticCurvePoint))
-                if opcode == Opcode::EPAR =>
-            {
+            // or unknown curve before changing the register
+            Err(Some(
+                PanicReason::InvalidEllipticCurvePoint | PanicReason::UnsupportedCurveId,
+            )) if opcode == Opcode::EPAR => {
                 return TestResult::discard();
             }
             _ => {
@@ -206,6 +206,8 @@ fn writes_to_ra(opcode: Opcode) -> bool {
         Opcode::CFSI => false,
         Opcode::LB => true,
         Opcode::LW => true,
+        Opcode::LHW => true,
+        Opcode::LQW => true,
         Opcode::ALOC => false,
         Opcode::MCL => false,
         Opcode::MCLI => false,
@@ -214,6 +216,8 @@ fn writes_to_ra(opcode: Opcode) -> bool {
         Opcode::MEQ => true,
         Opcode::SB => false,
         Opcode::SW => false,
+        Opcode::SHW => false,
+        Opcode::SQW => false,
         Opcode::BAL => true,
         Opcode::BHSH => false,
         Opcode::BHEI => true,
@@ -325,6 +329,8 @@ fn writes_to_rb(opcode: Opcode) -> bool {
         Opcode::CFSI => false,
         Opcode::LB => false,
         Opcode::LW => false,
+        Opcode::LHW => false,
+        Opcode::LQW => false,
         Opcode::ALOC => false,
         Opcode::MCL => false,
         Opcode::MCLI => false,
@@ -333,6 +339,8 @@ fn writes_to_rb(opcode: Opcode) -> bool {
         Opcode::MEQ => false,
         Opcode::SB => false,
         Opcode::SW => false,
+        Opcode::SHW => false,
+        Opcode::SQW => false,
         Opcode::BAL => false,
         Opcode::BHSH => false,
         Opcode::BHEI => false,
@@ -6,6 +6,7 @@ use crate::{
         Checked,
         IntoChecked,
         ParallelExecutor,
+        PredicateCheckCache,
     },
     context::Context,
     error::{
@@ -15,18 +16,26 @@ use crate::{
     },
     interpreter::{
         CheckedMetadata,
+        CodeCache,
         EcalHandler,
         ExecutableTransaction,
         InitialBalances,
         Interpreter,
         Memory,
         RuntimeBalances,
+        StorageWriteCounter,
     },
     pool::VmMemoryPool,
-    predicate::RuntimePredicate,
+    predicate::{
+        static_check,
+        CheckContext,
+        RuntimePredicate,
+    },
     prelude::{
         BugVariant,
         RuntimeError,
+        StorageErrorContext,
+        StorageOp,
     },
     state::{
         ExecuteState,
@@ -59,7 +68,12 @@ use crate::{
         UploadedBytecodes,
     },
 };
-use fuel_asm::PanicReason;
+#[cfg(any(test, feature = "test-helpers"))]
+use fuel_asm::RegId;
+use fuel_asm::{
+    PanicInstruction,
+    PanicReason,
+};
 use fuel_storage::{
     StorageAsMut,
     StorageAsRef,
@@ -106,19 +120,40 @@ use fuel_tx::{
 use fuel_types::{
     AssetId,
     BlobId,
+    Bytes32,
     Word,
 };
 
 /// Predicates were checked succesfully
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct PredicatesChecked {
     gas_used: Word,
+    per_input_gas: Vec<(usize, Word)>,
+    return_data: Vec<(usize, Vec<u8>)>,
 }
 
 impl PredicatesChecked {
     pub fn gas_used(&self) -> Word {
         self.gas_used
     }
+
+    /// The index of every predicate input and the gas used by its predicate, in
+    /// the order of the inputs. The gas sums up to [`Self::gas_used`].
+    pub fn per_input_gas(&self) -> &[(usize, Word)] {
+        &self.per_input_gas
+    }
+
+    /// The data returned with `RETD` by the predicate of the input at the `index`.
+    ///
+    /// Only the estimation allows the predicates to return data, so it is always
+    /// `None` for the verified predicates. The parallel estimation doesn't capture
+    /// the data.
+    pub fn predicate_return_data(&self, index: usize) -> Option<&[u8]> {
+        self.return_data
+            .iter()
+            .find(|(input_index, _)| *input_index == index)
+            .map(|(_, data)| data.as_slice())
+    }
 }
 
 enum PredicateRunKind<'a, Tx> {
@@ -171,6 +206,63 @@ pub mod predicates {
         )
     }
 
+    /// Initialize the VM with the provided transaction and check all predicates defined
+    /// in the inputs, skipping the predicates already verified according to the
+    /// `cache`. Newly verified predicates are recorded in the `cache`.
+    ///
+    /// The storage provider is not used since contract opcodes are not allowed for
+    /// predicates.
+    pub fn check_predicates_cached<Tx>(
+        checked: &Checked<Tx>,
+        params: &CheckPredicateParams,
+        cache: &mut PredicateCheckCache,
+        mut memory: impl Memory,
+        storage: &impl PredicateStorageRequirements,
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed>
+    where
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        let tx = checked.transaction();
+        let params_digest = PredicateCheckCache::params_digest(params);
+        let tx_bytes = tx.to_bytes();
+        let mut checks = vec![];
+
+        for index in 0..tx.inputs().len() {
+            if let Some(predicate) =
+                RuntimePredicate::from_tx(tx, params.tx_offset, index)
+            {
+                let digest = PredicateCheckCache::entry_digest_from_bytes(
+                    &tx_bytes,
+                    index,
+                    &params_digest,
+                );
+
+                if let Some(gas_used) = cache.lookup(index, &digest) {
+                    checks.push(Ok((gas_used, index)));
+                    continue;
+                }
+
+                let (gas_used, result) = check_predicate(
+                    tx.clone(),
+                    index,
+                    PredicateAction::Verifying,
+                    predicate,
+                    params.clone(),
+                    memory.as_mut(),
+                    storage,
+                );
+
+                if result.is_ok() {
+                    cache.insert(index, digest, gas_used);
+                }
+                checks.push(result.map(|_| (gas_used, index)));
+            }
+        }
+
+        finalize_check_predicate(PredicateRunKind::Verifying(tx), checks, vec![], params)
+    }
+
     /// Initialize the VM with the provided transaction and check all predicates defined
     /// in the inputs in parallel.
     ///
@@ -294,6 +386,12 @@ pub mod predicates {
                         memory.as_mut(),
                         &storage_instance,
                     );
+                    trace_event!(
+                        index,
+                        gas_used = used_gas,
+                        error = ?result.as_ref().err(),
+                        "predicate checked"
+                    );
 
                     result.map(|_| (used_gas, index))
                 });
@@ -304,7 +402,7 @@ pub mod predicates {
 
         let checks = E::execute_tasks(checks).await;
 
-        finalize_check_predicate(kind, checks, params)
+        finalize_check_predicate(kind, checks, vec![], params)
     }
 
     fn run_predicates<Tx>(
@@ -317,6 +415,7 @@ pub mod predicates {
         Tx: ExecutableTransaction,
     {
         let mut checks = vec![];
+        let mut return_data = vec![];
 
         let max_gas = kind.tx().max_gas(&params.gas_costs, &params.fee_params);
         let max_gas_per_tx = params.max_gas_per_tx;
@@ -346,12 +445,21 @@ pub mod predicates {
                     storage,
                 );
                 global_available_gas = global_available_gas.saturating_sub(gas_used);
-                let result = result.map(|_| (gas_used, index));
+                trace_event!(
+                    index,
+                    gas_used,
+                    error = ?result.as_ref().err(),
+                    "predicate checked"
+                );
+                let result = result.map(|data| {
+                    return_data.extend(data.map(|data| (index, data)));
+                    (gas_used, index)
+                });
                 checks.push(result);
             }
         }
 
-        finalize_check_predicate(kind, checks, params)
+        finalize_check_predicate(kind, checks, return_data, params)
     }
 
     fn check_predicate<Tx>(
@@ -362,7 +470,7 @@ pub mod predicates {
         params: CheckPredicateParams,
         memory: &mut MemoryInstance,
         storage: &impl PredicateStorageRequirements,
-    ) -> (Word, Result<(), PredicateVerificationFailed>)
+    ) -> (Word, Result<Option<Vec<u8>>, PredicateVerificationFailed>)
     where
         Tx: ExecutableTransaction,
     {
@@ -370,20 +478,60 @@ pub mod predicates {
             Input::CoinPredicate(CoinPredicate {
                 owner: address,
                 predicate,
+                predicate_data,
                 ..
             })
             | Input::MessageDataPredicate(MessageDataPredicate {
                 recipient: address,
                 predicate,
+                predicate_data,
                 ..
             })
             | Input::MessageCoinPredicate(MessageCoinPredicate {
                 predicate,
+                predicate_data,
                 recipient: address,
                 ..
             }) => {
+                let predicate_length = predicate.len() as u64;
+                if predicate_length > params.max_predicate_length {
+                    return (
+                        0,
+                        Err(PredicateVerificationFailed::PredicateLength {
+                            index,
+                            actual: predicate_length,
+                            limit: params.max_predicate_length,
+                        }),
+                    );
+                }
+
+                let predicate_data_length = predicate_data.len() as u64;
+                if predicate_data_length > params.max_predicate_data_length {
+                    return (
+                        0,
+                        Err(PredicateVerificationFailed::PredicateDataLength {
+                            index,
+                            actual: predicate_data_length,
+                            limit: params.max_predicate_data_length,
+                        }),
+                    );
+                }
+
                 if !Input::is_predicate_owner_valid(address, &**predicate) {
-                    return (0, Err(PredicateVerificationFailed::InvalidOwner));
+                    return (0, Err(PredicateVerificationFailed::InvalidOwner { index }));
+                }
+
+                if params.static_check {
+                    let issues = static_check(predicate, CheckContext::Predicate);
+                    if let Some(issue) = issues.into_iter().next() {
+                        return (
+                            0,
+                            Err(PredicateVerificationFailed::StaticCheck {
+                                index,
+                                issue,
+                            }),
+                        );
+                    }
                 }
             }
             _ => {}
@@ -415,36 +563,77 @@ pub mod predicates {
         };
 
         if let Err(err) = vm.init_predicate(context, tx, available_gas) {
-            return (0, Err(err.into()));
+            return (
+                0,
+                Err(PredicateVerificationFailed::from_interpreter_error(
+                    index,
+                    available_gas,
+                    err,
+                )),
+            );
         }
 
         let result = vm.verify_predicate();
-        let is_successful = matches!(result, Ok(ProgramState::Return(0x01)));
+        let is_successful = matches!(
+            result,
+            Ok(ProgramState::Return(0x01) | ProgramState::ReturnData(_))
+        );
 
         let Some(gas_used) = available_gas.checked_sub(vm.remaining_gas()) else {
-            return (0, Err(Bug::new(BugVariant::GlobalGasUnderflow).into()));
+            return (
+                0,
+                Err(PredicateVerificationFailed::Bug {
+                    index,
+                    bug: Bug::new(BugVariant::GlobalGasUnderflow),
+                }),
+            );
         };
 
         if let PredicateAction::Verifying = predicate_action {
             if !is_successful {
                 return if let Err(err) = result {
-                    (gas_used, Err(err))
+                    (
+                        gas_used,
+                        Err(PredicateVerificationFailed::from_interpreter_error(
+                            index,
+                            available_gas,
+                            err,
+                        )),
+                    )
                 } else {
-                    (gas_used, Err(PredicateVerificationFailed::False))
+                    (
+                        gas_used,
+                        Err(PredicateVerificationFailed::False {
+                            index,
+                            gas_used,
+                            available_gas,
+                        }),
+                    )
                 }
             }
 
             if vm.remaining_gas() != 0 {
-                return (gas_used, Err(PredicateVerificationFailed::GasMismatch));
+                return (
+                    gas_used,
+                    Err(PredicateVerificationFailed::GasMismatch {
+                        index,
+                        gas_used,
+                        available_gas,
+                    }),
+                );
             }
         }
 
-        (gas_used, Ok(()))
+        let return_data = matches!(result, Ok(ProgramState::ReturnData(_)))
+            .then(|| vm.predicate_return_data().to_vec());
+
+        (gas_used, Ok(return_data))
     }
 
     fn finalize_check_predicate<Tx>(
         mut kind: PredicateRunKind<Tx>,
         checks: Vec<Result<(Word, usize), PredicateVerificationFailed>>,
+        return_data: Vec<(usize, Vec<u8>)>,
         params: &CheckPredicateParams,
     ) -> Result<PredicatesChecked, PredicateVerificationFailed>
     where
@@ -485,13 +674,20 @@ pub mod predicates {
             );
         }
 
-        let cumulative_gas_used = checks.into_iter().try_fold(0u64, |acc, result| {
-            acc.checked_add(result.map(|(gas_used, _)| gas_used)?)
-                .ok_or(PredicateVerificationFailed::OutOfGas)
-        })?;
+        let mut per_input_gas = Vec::with_capacity(checks.len());
+        let mut cumulative_gas_used: Word = 0;
+        for result in checks {
+            let (gas_used, index) = result?;
+            cumulative_gas_used = cumulative_gas_used
+                .checked_add(gas_used)
+                .ok_or(PredicateVerificationFailed::GasOverflow)?;
+            per_input_gas.push((index, gas_used));
+        }
 
         Ok(PredicatesChecked {
             gas_used: cumulative_gas_used,
+            per_input_gas,
+            return_data,
         })
     }
 }
@@ -500,9 +696,12 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
 {
+    #[allow(clippy::too_many_arguments)]
     fn deploy_inner(
         create: &mut Create,
         storage: &mut S,
+        code_cache: &mut CodeCache,
+        storage_written: &mut StorageWriteCounter,
         initial_balances: InitialBalances,
         gas_costs: &GasCosts,
         fee_params: &FeeParameters,
@@ -536,18 +735,39 @@ where
         };
 
         // Prevent redeployment of contracts
-        if storage
-            .storage_contract_exists(&id)
-            .map_err(RuntimeError::Storage)?
-        {
+        if storage.storage_contract_exists(&id).map_err(|e| {
+            RuntimeError::storage(
+                e,
+                StorageErrorContext::new(StorageOp::ContractDeploy).contract(id),
+            )
+        })? {
             return Err(InterpreterError::Panic(
                 PanicReason::ContractIdAlreadyDeployed,
             ));
         }
 
+        let written = (contract.as_ref().len() as u64).saturating_add(
+            (storage_slots.len() as u64).saturating_mul(Bytes32::LEN as u64),
+        );
+        storage_written
+            .charge(written)
+            .map_err(InterpreterError::Panic)?;
         storage
             .deploy_contract_with_id(storage_slots, &contract, &id)
-            .map_err(RuntimeError::Storage)?;
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ContractDeploy).contract(id),
+                )
+            })?;
+        trace_event!(
+            contract_id = %id,
+            storage_slots = storage_slots.len(),
+            "contract deployed"
+        );
+        // The storage could have been reverted outside of the VM, so the cache may
+        // still hold the bytecode of the previous deployment.
+        code_cache.invalidate(&id);
         Self::finalize_outputs(
             create,
             gas_costs,
@@ -591,25 +811,45 @@ where
                     Self::get_consensus_parameters(&metadata)?
                 };
 
-                let current_version = storage
-                    .consensus_parameters_version()
-                    .map_err(RuntimeError::Storage)?;
+                let current_version =
+                    storage.consensus_parameters_version().map_err(|e| {
+                        RuntimeError::storage(
+                            e,
+                            StorageErrorContext::new(
+                                StorageOp::ConsensusParametersUpgrade,
+                            ),
+                        )
+                    })?;
                 let next_version = current_version.saturating_add(1);
 
                 let prev = storage
                     .set_consensus_parameters(next_version, &consensus_parameters)
-                    .map_err(RuntimeError::Storage)?;
+                    .map_err(|e| {
+                        RuntimeError::storage(
+                            e,
+                            StorageErrorContext::new(
+                                StorageOp::ConsensusParametersUpgrade,
+                            ),
+                        )
+                    })?;
 
                 if prev.is_some() {
                     return Err(InterpreterError::Panic(
                         PanicReason::OverridingConsensusParameters,
                     ));
                 }
+                trace_event!(version = next_version, "consensus parameters upgraded");
             }
             UpgradePurpose::StateTransition { root } => {
                 let exists = storage
                     .contains_state_transition_bytecode_root(root)
-                    .map_err(RuntimeError::Storage)?;
+                    .map_err(|e| {
+                        RuntimeError::storage(
+                            e,
+                            StorageErrorContext::new(StorageOp::StateTransitionUpgrade)
+                                .key(*root),
+                        )
+                    })?;
 
                 if !exists {
                     return Err(InterpreterError::Panic(
@@ -617,20 +857,35 @@ where
                     ))
                 }
 
-                let current_version = storage
-                    .state_transition_version()
-                    .map_err(RuntimeError::Storage)?;
+                let current_version =
+                    storage.state_transition_version().map_err(|e| {
+                        RuntimeError::storage(
+                            e,
+                            StorageErrorContext::new(StorageOp::StateTransitionUpgrade),
+                        )
+                    })?;
                 let next_version = current_version.saturating_add(1);
 
                 let prev = storage
                     .set_state_transition_bytecode(next_version, root)
-                    .map_err(RuntimeError::Storage)?;
+                    .map_err(|e| {
+                        RuntimeError::storage(
+                            e,
+                            StorageErrorContext::new(StorageOp::StateTransitionUpgrade)
+                                .key(*root),
+                        )
+                    })?;
 
                 if prev.is_some() {
                     return Err(InterpreterError::Panic(
                         PanicReason::OverridingStateTransactionBytecode,
                     ));
                 }
+                trace_event!(
+                    version = next_version,
+                    %root,
+                    "state transition bytecode upgraded"
+                );
             }
         }
 
@@ -670,9 +925,11 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
 {
+    #[allow(clippy::too_many_arguments)]
     fn upload_inner(
         upload: &mut Upload,
         storage: &mut S,
+        storage_written: &mut StorageWriteCounter,
         initial_balances: InitialBalances,
         gas_costs: &GasCosts,
         fee_params: &FeeParameters,
@@ -683,7 +940,12 @@ where
         let uploaded_bytecode = storage
             .storage_as_ref::<UploadedBytecodes>()
             .get(&root)
-            .map_err(RuntimeError::Storage)?
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::BytecodeUpload).key(root),
+                )
+            })?
             .map(|x| x.into_owned())
             .unwrap_or_else(|| UploadedBytecode::Uncompleted {
                 bytecode: vec![],
@@ -706,10 +968,31 @@ where
             }
         };
 
+        // Only the uploaded subsection is new, the rest of the bytecode is rewritten.
+        let written = upload
+            .witnesses()
+            .get(*upload.bytecode_witness_index() as usize)
+            .map(|subsection| subsection.as_ref().len() as u64)
+            .unwrap_or_default();
+        storage_written
+            .charge(written)
+            .map_err(InterpreterError::Panic)?;
+
         storage
             .storage_as_mut::<UploadedBytecodes>()
             .insert(&root, &new_bytecode)
-            .map_err(RuntimeError::Storage)?;
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::BytecodeUpload).key(root),
+                )
+            })?;
+        trace_event!(
+            %root,
+            subsection_index = *upload.subsection_index(),
+            completed = matches!(new_bytecode, UploadedBytecode::Completed(_)),
+            "bytecode subsection uploaded"
+        );
 
         Self::finalize_outputs(
             upload,
@@ -779,9 +1062,11 @@ impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
 {
+    #[allow(clippy::too_many_arguments)]
     fn blob_inner(
         blob: &mut Blob,
         storage: &mut S,
+        storage_written: &mut StorageWriteCounter,
         initial_balances: InitialBalances,
         gas_costs: &GasCosts,
         fee_params: &FeeParameters,
@@ -799,20 +1084,31 @@ where
 
         let blob_id = blob.blob_id();
 
+        // `Checked<Blob>` guarantees the id, see `Blob::validate_id`.
         debug_assert_eq!(
             BlobId::compute(blob_data.as_ref()),
             *blob_id,
             "Tx has invalid BlobId",
         );
 
+        storage_written
+            .charge(blob_data.as_ref().len() as u64)
+            .map_err(InterpreterError::Panic)?;
         let old = storage
             .storage_as_mut::<BlobData>()
             .replace(blob_id, blob_data.as_ref())
-            .map_err(RuntimeError::Storage)?;
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::BlobWrite)
+                        .key(Bytes32::new(**blob_id)),
+                )
+            })?;
 
         if old.is_some() {
             return Err(InterpreterError::Panic(PanicReason::BlobIdAlreadyUploaded));
         }
+        trace_event!(%blob_id, len = blob_data.as_ref().len(), "blob uploaded");
 
         Self::finalize_outputs(
             blob,
@@ -900,16 +1196,18 @@ where
         }
 
         let state = if let Some(create) = self.tx.as_create_mut() {
-            Self::deploy_inner(
+            let result = Self::deploy_inner(
                 create,
                 &mut self.storage,
+                &mut self.code_cache,
+                &mut self.storage_written,
                 self.initial_balances.clone(),
                 &gas_costs,
                 &fee_params,
                 &base_asset_id,
                 gas_price,
-            )?;
-            ProgramState::Return(1)
+            );
+            self.panic_on_storage_write_limit(result)?
         } else if let Some(upgrade) = self.tx.as_upgrade_mut() {
             Self::upgrade_inner(
                 upgrade,
@@ -922,27 +1220,29 @@ where
             )?;
             ProgramState::Return(1)
         } else if let Some(upload) = self.tx.as_upload_mut() {
-            Self::upload_inner(
+            let result = Self::upload_inner(
                 upload,
                 &mut self.storage,
+                &mut self.storage_written,
                 self.initial_balances.clone(),
                 &gas_costs,
                 &fee_params,
                 &base_asset_id,
                 gas_price,
-            )?;
-            ProgramState::Return(1)
+            );
+            self.panic_on_storage_write_limit(result)?
         } else if let Some(blob) = self.tx.as_blob_mut() {
-            Self::blob_inner(
+            let result = Self::blob_inner(
                 blob,
                 &mut self.storage,
+                &mut self.storage_written,
                 self.initial_balances.clone(),
                 &gas_costs,
                 &fee_params,
                 &base_asset_id,
                 gas_price,
-            )?;
-            ProgramState::Return(1)
+            );
+            self.panic_on_storage_write_limit(result)?
         } else {
             // `Interpreter` supports only `Create` and `Script` transactions. It is not
             // `Create` -> it is `Script`.
@@ -953,6 +1253,42 @@ where
         Ok(state)
     }
 
+    /// Turns the storage write limit exceeded by the `Create`, `Upload` or `Blob`
+    /// transaction into a panic receipt, as for the script, instead of invalidating
+    /// the transaction. Nothing is written to the storage in this case, and the
+    /// outputs are finalized as reverted.
+    fn panic_on_storage_write_limit(
+        &mut self,
+        result: Result<(), InterpreterError<S::DataError>>,
+    ) -> Result<ProgramState, InterpreterError<S::DataError>> {
+        match result {
+            Ok(()) => Ok(ProgramState::Return(1)),
+            Err(InterpreterError::Panic(PanicReason::StorageWriteLimitExceeded)) => {
+                self.append_panic_receipt(PanicInstruction::error(
+                    PanicReason::StorageWriteLimitExceeded,
+                    0,
+                ));
+                let gas_costs = self.gas_costs().clone();
+                let fee_params = *self.fee_params();
+                let base_asset_id = *self.base_asset_id();
+                let gas_price = self.gas_price();
+                Self::finalize_outputs(
+                    &mut self.tx,
+                    &gas_costs,
+                    &fee_params,
+                    &base_asset_id,
+                    true,
+                    0,
+                    &self.initial_balances,
+                    &RuntimeBalances::try_from(self.initial_balances.clone())?,
+                    gas_price,
+                )?;
+                Ok(ProgramState::Revert(0))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     pub(crate) fn run_program(
         &mut self,
     ) -> Result<ProgramState, InterpreterError<S::DataError>> {
@@ -979,10 +1315,13 @@ where
                     // Proceeding with the execution normally
                     Ok(ExecuteState::Proceed) => continue,
                     // Debugger events are returned directly to the caller
+                    #[cfg(feature = "debugger")]
                     Ok(ExecuteState::DebugEvent(d)) => {
                         self.debugger_set_last_state(ProgramState::RunProgram(d));
                         return Ok(ProgramState::RunProgram(d));
                     }
+                    #[cfg(not(feature = "debugger"))]
+                    Ok(ExecuteState::DebugEvent(d)) => match d {},
                     // Reverting terminated execution immediately
                     Ok(ExecuteState::Revert(r)) => {
                         break (ScriptExecutionResult::Revert, ProgramState::Revert(r))
@@ -1003,8 +1342,14 @@ where
                             ProgramState::ReturnData(d),
                         )
                     }
-                    // Error always terminates the execution
+                    // Error terminates the execution
                     Err(e) => match e.instruction_result() {
+                        // A panic inside of a call frame only reverts the frame, if
+                        // enabled
+                        Some(result) if self.reverts_call_frame() => {
+                            self.panic_call_frame(result)?;
+                            continue
+                        }
                         Some(result) => {
                             self.append_panic_receipt(result);
                             break (ScriptExecutionResult::Panic, ProgramState::Revert(0));
@@ -1025,6 +1370,11 @@ where
             .ok_or_else(|| Bug::new(BugVariant::GlobalGasUnderflow))?;
         self.receipts
             .push(Receipt::script_result(result, gas_used))?;
+        debug_assert_eq!(
+            Receipt::validate_sequence(self.receipts.as_ref()),
+            Ok(()),
+            "the receipts are produced in the illegal order"
+        );
 
         // Finalize the outputs
         let fee_params = *self.fee_params();
@@ -1072,9 +1422,26 @@ where
     ) -> Result<StateTransitionRef<'_, Tx>, InterpreterError<S::DataError>> {
         self.verify_ready_tx(&tx)?;
 
-        let state_result = self.init_script(tx).and_then(|_| self.run());
+        trace_span!("transaction", tx_id = %tx.id());
+
+        let state_result = self.init_script(tx).and_then(|_| {
+            trace_event!(
+                gas_limit = self
+                    .transaction()
+                    .as_script()
+                    .map(|script| *script.script_gas_limit()),
+                "transaction started"
+            );
+            self.run()
+        });
         self.post_execute();
 
+        trace_event!(
+            state = ?state_result.as_ref().ok(),
+            receipts = self.receipts().len(),
+            "transaction finished"
+        );
+
         #[cfg(feature = "profile-any")]
         {
             let r = match &state_result {
@@ -1091,6 +1458,71 @@ where
             self.receipts(),
         ))
     }
+
+    /// Same as [`Self::transact`], but seeds the writable registers with `registers`
+    /// before the first instruction of the script is executed.
+    ///
+    /// Only for tests: it allows skipping long `MOVI` preambles.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn transact_with_initial_registers(
+        &mut self,
+        tx: Ready<Tx>,
+        registers: &[(RegId, Word)],
+    ) -> Result<StateTransitionRef<'_, Tx>, InterpreterError<S::DataError>> {
+        self.verify_ready_tx(&tx)?;
+
+        let state_result = self
+            .init_script(tx)
+            .and_then(|_| {
+                registers
+                    .iter()
+                    .try_for_each(|(reg, value)| self.set_writable_register(*reg, *value))
+            })
+            .and_then(|_| self.run());
+        self.post_execute();
+
+        let state = state_result?;
+        Ok(StateTransitionRef::new(
+            state,
+            self.transaction(),
+            self.receipts(),
+        ))
+    }
+}
+
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
+where
+    S: InterpreterStorage,
+{
+    /// Sets the writable register `reg` to the `value`.
+    ///
+    /// Only for tests. Can be called only after the script is initialized and before
+    /// its first instruction is executed, otherwise returns
+    /// [`InterpreterError::NoTransactionInitialized`]. Reserved registers are rejected
+    /// with [`PanicReason::ReservedRegisterNotWritable`].
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn set_writable_register(
+        &mut self,
+        reg: RegId,
+        value: Word,
+    ) -> Result<(), InterpreterError<S::DataError>> {
+        if reg < RegId::WRITABLE {
+            return Err(InterpreterError::Panic(
+                PanicReason::ReservedRegisterNotWritable,
+            ))
+        }
+
+        let not_started = matches!(self.context, Context::Script { .. })
+            && self.frames.is_empty()
+            && self.receipts.is_empty()
+            && self.registers[RegId::PC] == self.registers[RegId::IS];
+        if !not_started {
+            return Err(InterpreterError::NoTransactionInitialized)
+        }
+
+        self.registers[reg] = value;
+        Ok(())
+    }
 }
 
 impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
@@ -1101,6 +1533,11 @@ where
     /// the last state of execution of the `Script` transaction.
     ///
     /// Returns `Create` transaction with all modifications after execution.
+    ///
+    /// This method produces no receipts, so it fails with the
+    /// `PanicReason::StorageWriteLimitExceeded` over the
+    /// [`InterpreterParams::max_storage_write_bytes`](crate::interpreter::InterpreterParams::max_storage_write_bytes),
+    /// while [`Self::transact`] produces the panic receipt instead.
     pub fn deploy(
         &mut self,
         tx: Ready<Create>,
@@ -1112,9 +1549,13 @@ where
             checked.into();
         let base_asset_id = *self.base_asset_id();
         let gas_price = self.gas_price();
+        self.storage_written
+            .reset(self.interpreter_params.max_storage_write_bytes);
         Self::deploy_inner(
             &mut create,
             &mut self.storage,
+            &mut self.code_cache,
+            &mut self.storage_written,
             metadata.balances(),
             &self.interpreter_params.gas_costs,
             &self.interpreter_params.fee_params,
@@ -1165,6 +1606,11 @@ where
     /// of the last state of execution of the `Script` transaction.
     ///
     /// Returns `Upload` transaction with all modifications after execution.
+    ///
+    /// This method produces no receipts, so it fails with the
+    /// `PanicReason::StorageWriteLimitExceeded` over the
+    /// [`InterpreterParams::max_storage_write_bytes`](crate::interpreter::InterpreterParams::max_storage_write_bytes),
+    /// while [`Self::transact`] produces the panic receipt instead.
     pub fn upload(
         &mut self,
         tx: Ready<Upload>,
@@ -1176,9 +1622,12 @@ where
             checked.into();
         let base_asset_id = *self.base_asset_id();
         let gas_price = self.gas_price();
+        self.storage_written
+            .reset(self.interpreter_params.max_storage_write_bytes);
         Self::upload_inner(
             &mut upload,
             &mut self.storage,
+            &mut self.storage_written,
             metadata.balances(),
             &self.interpreter_params.gas_costs,
             &self.interpreter_params.fee_params,
@@ -1197,6 +1646,11 @@ where
     /// of the last state of execution of the `Script` transaction.
     ///
     /// Returns `Blob` transaction with all modifications after execution.
+    ///
+    /// This method produces no receipts, so it fails with the
+    /// `PanicReason::StorageWriteLimitExceeded` over the
+    /// [`InterpreterParams::max_storage_write_bytes`](crate::interpreter::InterpreterParams::max_storage_write_bytes),
+    /// while [`Self::transact`] produces the panic receipt instead.
     pub fn blob(
         &mut self,
         tx: Ready<Blob>,
@@ -1208,9 +1662,12 @@ where
             checked.into();
         let base_asset_id = *self.base_asset_id();
         let gas_price = self.gas_price();
+        self.storage_written
+            .reset(self.interpreter_params.max_storage_write_bytes);
         Self::blob_inner(
             &mut blob,
             &mut self.storage,
+            &mut self.storage_written,
             metadata.balances(),
             &self.interpreter_params.gas_costs,
             &self.interpreter_params.fee_params,
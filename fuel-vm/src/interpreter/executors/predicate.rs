@@ -1,5 +1,6 @@
 use crate::{
-    error::PredicateVerificationFailed,
+    consts::WORD_SIZE,
+    error::InterpreterError,
     interpreter::{
         EcalHandler,
         Memory,
@@ -12,11 +13,17 @@ use crate::{
         ExecuteState,
         ProgramState,
     },
-    storage::predicate::PredicateStorage,
+    storage::predicate::{
+        PredicateStorage,
+        PredicateStorageError,
+    },
 };
 
 use crate::storage::predicate::PredicateStorageRequirements;
-use fuel_asm::PanicReason;
+use fuel_asm::{
+    PanicReason,
+    RegId,
+};
 
 impl<M, Tx, Ecal, S> Interpreter<M, PredicateStorage<S>, Tx, Ecal>
 where
@@ -28,30 +35,53 @@ where
     /// Verify a predicate that has been initialized already
     pub(crate) fn verify_predicate(
         &mut self,
-    ) -> Result<ProgramState, PredicateVerificationFailed> {
+    ) -> Result<ProgramState, InterpreterError<PredicateStorageError>> {
         loop {
             match self.execute()? {
                 ExecuteState::Return(r) => {
                     if r == 1 {
                         return Ok(ProgramState::Return(r))
                     } else {
-                        return Err(PanicReason::PredicateReturnedNonOne.into())
+                        return Err(InterpreterError::Panic(
+                            PanicReason::PredicateReturnedNonOne,
+                        ))
                     }
                 }
 
-                // A predicate is not expected to return data
-                ExecuteState::ReturnData(_) => {
-                    return Err(PanicReason::ContractInstructionNotAllowed.into())
+                // Only the estimation allows `RETD`, and the predicate succeeds if
+                // the first word of the returned data is 1, like with `RET`.
+                ExecuteState::ReturnData(digest) => {
+                    let first_word = self.predicate_return_data().get(..WORD_SIZE);
+                    if first_word == Some(&1u64.to_be_bytes()[..]) {
+                        return Ok(ProgramState::ReturnData(digest))
+                    } else {
+                        return Err(InterpreterError::Panic(
+                            PanicReason::PredicateReturnedNonOne,
+                        ))
+                    }
                 }
 
                 ExecuteState::Revert(r) => return Ok(ProgramState::Revert(r)),
 
                 ExecuteState::Proceed => (),
 
+                #[cfg(feature = "debugger")]
                 ExecuteState::DebugEvent(d) => {
                     return Ok(ProgramState::VerifyPredicate(d))
                 }
+
+                #[cfg(not(feature = "debugger"))]
+                ExecuteState::DebugEvent(d) => match d {},
             }
         }
     }
+
+    /// The data returned by the predicate with `RETD`, or the empty slice if it
+    /// didn't return any data.
+    pub(crate) fn predicate_return_data(&self) -> &[u8] {
+        self.memory
+            .as_ref()
+            .read(self.registers[RegId::RET], self.registers[RegId::RETL])
+            .unwrap_or_default()
+    }
 }
@@ -8,15 +8,19 @@ use crate::{
     context::Context,
     error::{
         IoResult,
-        RuntimeError,
         SimpleResult,
     },
     interpreter::{
+        code_cache::{
+            CachedContractCode,
+            CodeCache,
+            ContractCode,
+        },
         contract::{
             balance_decrease,
             balance_increase,
-            contract_size,
         },
+        contract_info::ContractInfoCache,
         gas::{
             dependent_gas_charge_without_base,
             gas_charge,
@@ -30,6 +34,11 @@ use crate::{
             set_frame_pointer,
         },
         receipts::ReceiptsCtx,
+        storage_journal::{
+            JournalEntry,
+            StorageJournal,
+        },
+        storage_write::StorageWriteCounter,
         ExecutableTransaction,
         InputContracts,
         Interpreter,
@@ -50,7 +59,10 @@ use crate::{
     },
 };
 use alloc::vec::Vec;
-use core::cmp;
+use core::{
+    cmp,
+    ops::Range,
+};
 use fuel_asm::{
     Instruction,
     PanicInstruction,
@@ -62,12 +74,19 @@ use fuel_storage::{
     StorageSize,
 };
 use fuel_tx::{
+    field::{
+        FlagDefaults,
+        Script as _,
+    },
     DependentCost,
     PanicReason,
     Receipt,
 };
 use fuel_types::{
-    bytes::padded_len_usize,
+    bytes::{
+        padded_len_usize,
+        padded_len_word,
+    },
     canonical::Serialize,
     AssetId,
     Bytes32,
@@ -88,11 +107,61 @@ where
     Tx: ExecutableTransaction,
 {
     pub(crate) fn jump(&mut self, args: JumpArgs) -> SimpleResult<()> {
+        // The code region is only looked up for the relative jumps that are taken
+        let restricted = self.interpreter_params.relative_jumps_within_code
+            && args.condition
+            && args.mode != JumpMode::Absolute;
+        let code = if restricted {
+            self.code_region()?
+        } else {
+            None
+        };
+        let args = match code {
+            Some(code) => args.within(code),
+            None => args,
+        };
         let (SystemRegisters { pc, is, .. }, _) = split_registers(&mut self.registers);
         args.jump(is.as_ref(), pc)
     }
 
+    /// The code of the current context, or `None` if the VM isn't initialized.
+    ///
+    /// The code of the contract, together with the code appended to it by `LDC`, is
+    /// described by the code size of its call frame. The scripts and the predicates
+    /// keep the loaded code at the top of the stack, right after the transaction.
+    fn code_region(&self) -> SimpleResult<Option<CodeRegion>> {
+        let is = self.registers[RegId::IS];
+        let program_len = match &self.context {
+            Context::Call { .. } => {
+                let code_size_ptr = self.registers[RegId::FP]
+                    .saturating_add(CallFrame::code_size_offset() as Word);
+                let code_size =
+                    Word::from_be_bytes(self.memory().read_bytes(code_size_ptr)?);
+                let code_size =
+                    padded_len_word(code_size).ok_or(PanicReason::MemoryOverflow)?;
+                return Ok(Some(CodeRegion::new(is, code_size, 0..0)))
+            }
+            Context::PredicateEstimation { program }
+            | Context::PredicateVerification { program } => program.program().len(),
+            Context::Script { .. } => self
+                .tx
+                .as_script()
+                .map(|script| script.script().len())
+                .unwrap_or_default(),
+            Context::NotInitialized => return Ok(None),
+        };
+
+        // The size of the transaction is stored right before its bytes.
+        let tx_offset = self.tx_offset() as Word;
+        let tx_size_ptr = tx_offset.saturating_sub(WORD_SIZE as Word);
+        let tx_size = Word::from_be_bytes(self.memory().read_bytes(tx_size_ptr)?);
+        let loaded = tx_offset.saturating_add(tx_size)..self.registers[RegId::SSP];
+
+        Ok(Some(CodeRegion::new(is, program_len as Word, loaded)))
+    }
+
     pub(crate) fn ret(&mut self, a: Word) -> SimpleResult<()> {
+        let returned_gas = self.returned_gas();
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
         let input = RetCtx {
@@ -102,11 +171,18 @@ where
             memory: self.memory.as_ref(),
             context: &mut self.context,
             current_contract,
+            allow_empty_reads: self.interpreter_params.allow_empty_reads,
         };
-        input.ret(a)
+        input.ret(a)?;
+        if returned_gas.is_some() {
+            self.storage_journal.merge();
+        }
+        self.returned_call_gas.extend(returned_gas);
+        Ok(())
     }
 
     pub(crate) fn ret_data(&mut self, a: Word, b: Word) -> SimpleResult<Bytes32> {
+        let returned_gas = self.returned_gas();
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
         let input = RetCtx {
@@ -116,8 +192,40 @@ where
             receipts: &mut self.receipts,
             context: &mut self.context,
             current_contract,
+            allow_empty_reads: self.interpreter_params.allow_empty_reads,
         };
-        input.ret_data(a, b)
+        let digest = input.ret_data(a, b)?;
+        if returned_gas.is_some() {
+            self.storage_journal.merge();
+        }
+        self.returned_call_gas.extend(returned_gas);
+        Ok(digest)
+    }
+
+    /// Returns to the caller of the reverted call frame, setting `$err` and handing
+    /// back the unused context gas. `$ret` is set to `a` and `$retl` to zero.
+    fn return_from_reverted(&mut self, a: Word) -> SimpleResult<()> {
+        let returned_gas = self.returned_gas();
+        let current_contract =
+            current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
+        let input = RetCtx {
+            frames: &mut self.frames,
+            registers: &mut self.registers,
+            memory: self.memory.as_ref(),
+            receipts: &mut self.receipts,
+            context: &mut self.context,
+            current_contract,
+            allow_empty_reads: self.interpreter_params.allow_empty_reads,
+        };
+        input.revert(a)?;
+        self.returned_call_gas.extend(returned_gas);
+        Ok(())
+    }
+
+    /// The context gas that a return from the current frame hands back to the
+    /// caller, or `None` when returning from the script.
+    fn returned_gas(&self) -> Option<Word> {
+        (!self.frames.is_empty()).then(|| self.registers[RegId::CGAS])
     }
 
     pub(crate) fn revert(&mut self, a: Word) -> SimpleResult<()> {
@@ -136,9 +244,10 @@ where
     pub(crate) fn append_panic_receipt(&mut self, result: PanicInstruction) {
         let pc = self.registers[RegId::PC];
         let is = self.registers[RegId::IS];
+        let contract_id = self.internal_contract().unwrap_or_default();
+        trace_event!(reason = ?result.reason(), pc, %contract_id, "panic");
 
-        let mut receipt =
-            Receipt::panic(self.internal_contract().unwrap_or_default(), result, pc, is);
+        let mut receipt = Receipt::panic(contract_id, result, pc, is);
 
         match self.panic_context {
             PanicContext::None => {}
@@ -161,6 +270,7 @@ struct RetCtx<'vm> {
     receipts: &'vm mut ReceiptsCtx,
     context: &'vm mut Context,
     current_contract: Option<ContractId>,
+    allow_empty_reads: bool,
 }
 
 impl RetCtx<'_> {
@@ -180,6 +290,27 @@ impl RetCtx<'_> {
     }
 
     pub(crate) fn return_from_context(mut self, receipt: Receipt) -> SimpleResult<()> {
+        self.restore_caller()?;
+
+        self.receipts.push(receipt)?;
+
+        Ok(inc_pc(self.registers.pc_mut())?)
+    }
+
+    /// Returns from the reverted call frame without a receipt, since the `Revert` or
+    /// the `Panic` receipt is already pushed. `$err` tells the caller about the revert.
+    pub(crate) fn revert(mut self, a: Word) -> SimpleResult<()> {
+        self.registers[RegId::RET] = a;
+        self.registers[RegId::RETL] = 0;
+
+        self.restore_caller()?;
+        self.registers[RegId::ERR] = 1;
+
+        Ok(inc_pc(self.registers.pc_mut())?)
+    }
+
+    /// Pops the current call frame, restoring the registers of the caller.
+    fn restore_caller(&mut self) -> SimpleResult<()> {
         if let Some(frame) = self.frames.pop() {
             let registers = &mut self.registers;
             let context = &mut self.context;
@@ -206,13 +337,14 @@ impl RetCtx<'_> {
             set_frame_pointer(context, registers.fp_mut(), fp);
         }
 
-        self.receipts.push(receipt)?;
-
-        Ok(inc_pc(self.registers.pc_mut())?)
+        Ok(())
     }
 
     pub(crate) fn ret_data(self, a: Word, b: Word) -> SimpleResult<Bytes32> {
-        let data = self.memory.read(a, b)?.to_vec();
+        let data = self
+            .memory
+            .read_range(a, b, self.allow_empty_reads)?
+            .to_vec();
 
         let receipt = Receipt::return_data(
             self.current_contract.unwrap_or_else(ContractId::zeroed),
@@ -261,6 +393,30 @@ pub enum JumpMode {
     RelativeBackwards,
 }
 
+/// The memory holding the code of the current context, which the relative jumps
+/// may target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct CodeRegion {
+    /// The code of the program, starting at `$is`.
+    program: (Word, Word),
+    /// The code loaded by `LDC` outside of the program.
+    loaded: (Word, Word),
+}
+
+impl CodeRegion {
+    pub(crate) fn new(is: Word, program_len: Word, loaded: Range<Word>) -> Self {
+        Self {
+            program: (is, is.saturating_add(program_len)),
+            loaded: (loaded.start, loaded.end),
+        }
+    }
+
+    fn contains(&self, addr: Word) -> bool {
+        let within = |(start, end): (Word, Word)| start <= addr && addr < end;
+        within(self.program) || within(self.loaded)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct JumpArgs {
     /// Condition. The jump is performed only if this is true.
@@ -271,6 +427,8 @@ pub struct JumpArgs {
     dynamic: Word,
     /// Fixed part of the jump target, i.e. immediate value
     fixed: Word,
+    /// The code the relative jumps must stay within, if known
+    code: Option<CodeRegion>,
 }
 
 impl JumpArgs {
@@ -280,9 +438,15 @@ impl JumpArgs {
             mode,
             dynamic: 0,
             fixed: 0,
+            code: None,
         }
     }
 
+    pub(crate) fn within(mut self, code: CodeRegion) -> Self {
+        self.code = Some(code);
+        self
+    }
+
     pub(crate) fn with_condition(mut self, condition: bool) -> Self {
         self.condition = condition;
         self
@@ -326,6 +490,14 @@ impl JumpArgs {
             return Err(PanicReason::MemoryOverflow.into())
         }
 
+        // The relative jumps can't leave the code of the current context
+        if let Some(code) = &self.code {
+            let is_relative = self.mode != JumpMode::Absolute;
+            if is_relative && !code.contains(target_addr) {
+                return Err(PanicReason::MemoryOverflow.into())
+            }
+        }
+
         *pc = target_addr;
         Ok(())
     }
@@ -353,6 +525,25 @@ where
         )
     }
 
+    /// Reverts the current call frame on `RVRT`, pushing the `Revert` receipt and
+    /// returning to the caller with `$ret` set to `a`.
+    pub(crate) fn revert_call_frame(&mut self, a: Word) -> IoResult<(), S::DataError> {
+        self.undo_call_frame()?;
+        self.revert(a)?;
+        Ok(self.return_from_reverted(a)?)
+    }
+
+    /// Reverts the current call frame on a panic, pushing the `Panic` receipt and
+    /// returning to the caller.
+    pub(crate) fn panic_call_frame(
+        &mut self,
+        result: PanicInstruction,
+    ) -> IoResult<(), S::DataError> {
+        self.undo_call_frame()?;
+        self.append_panic_receipt(result);
+        Ok(self.return_from_reverted(0)?)
+    }
+
     /// Prepare a call instruction for execution
     fn prepare_call_inner(
         &mut self,
@@ -369,13 +560,18 @@ where
         };
         let gas_cost = self.gas_costs().call();
         let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte();
+        let strict_call_gas = self.interpreter_params.strict_call_gas;
+        let flag_defaults = self.tx.flag_defaults();
         // Charge only for the `base` execution.
         // We will charge for the frame size in the `prepare_call`.
         self.gas_charge(gas_cost.base())?;
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?;
 
-        PrepareCallCtx {
+        // The checkpoint covers the forwarded coins, so they are returned to the
+        // caller if the called frame reverts.
+        self.storage_journal.open(self.receipts.len());
+        let result = PrepareCallCtx {
             params,
             registers: (&mut self.registers).into(),
             memory: self.memory.as_mut(),
@@ -383,17 +579,28 @@ where
             gas_cost,
             runtime_balances: &mut self.balances,
             storage: &mut self.storage,
+            storage_written: &mut self.storage_written,
+            storage_journal: &mut self.storage_journal,
+            code_cache: &mut self.code_cache,
+            contract_info: &mut self.contract_info,
             input_contracts: InputContracts::new(
                 &self.input_contracts,
                 &mut self.panic_context,
             ),
             new_storage_gas_per_byte,
+            strict_call_gas,
+            flag_defaults,
             receipts: &mut self.receipts,
             frames: &mut self.frames,
             current_contract,
             profiler: &mut self.profiler,
         }
-        .prepare_call()
+        .prepare_call();
+        // The writes of the failed call belong to the caller
+        if result.is_err() {
+            self.storage_journal.merge();
+        }
+        result
     }
 }
 
@@ -451,7 +658,14 @@ struct PrepareCallCtx<'vm, S> {
     gas_cost: DependentCost,
     runtime_balances: &'vm mut RuntimeBalances,
     new_storage_gas_per_byte: Word,
+    strict_call_gas: bool,
+    /// The initial `$flag` of the called contract.
+    flag_defaults: Word,
     storage: &'vm mut S,
+    storage_written: &'vm mut StorageWriteCounter,
+    storage_journal: &'vm mut StorageJournal,
+    code_cache: &'vm mut CodeCache,
+    contract_info: &'vm mut ContractInfoCache,
     input_contracts: InputContracts<'vm>,
     receipts: &'vm mut ReceiptsCtx,
     frames: &'vm mut Vec<CallFrame>,
@@ -470,14 +684,24 @@ where
             + StorageRead<ContractsRawCode>
             + StorageAsRef,
     {
-        let call_bytes = self
-            .memory
-            .read(self.params.call_params_pointer, Call::LEN)?;
-        let call = Call::try_from(call_bytes)?;
+        // The call structure cut off by the end of the memory is malformed, while
+        // the pointer outside of the memory overflows it.
+        let call_params_pointer = self.params.call_params_pointer;
+        let call_len = usize::try_from(VM_MAX_RAM.saturating_sub(call_params_pointer))
+            .unwrap_or(usize::MAX)
+            .min(Call::SERIALIZED_LEN);
+        let call_bytes = self.memory.read(call_params_pointer, call_len)?;
+        let call = Call::try_from(call_bytes).map_err(PanicReason::from)?;
         let asset_id =
             AssetId::new(self.memory.read_bytes(self.params.asset_id_pointer)?);
 
-        let code_size = contract_size(&self.storage, call.to())? as usize;
+        let mut code = CachedContractCode {
+            storage: self.storage,
+            cache: self.code_cache,
+        };
+        let code_size = self
+            .contract_info
+            .code_size(call.to(), || code.code_size(call.to()))?;
         let code_size_padded =
             padded_len_usize(code_size).ok_or(PanicReason::MemoryOverflow)?;
 
@@ -500,6 +724,12 @@ where
         )?;
 
         if let Some(source_contract) = self.current_contract {
+            self.storage_written.charge(WORD_SIZE as u64)?;
+            self.storage_journal.record_balance(
+                self.storage,
+                &source_contract,
+                &asset_id,
+            )?;
             balance_decrease(
                 self.storage,
                 &source_contract,
@@ -514,11 +744,16 @@ where
                 &asset_id,
                 amount,
             )?;
+            self.storage_journal
+                .record(JournalEntry::FreeBalance { asset_id, amount });
         }
 
         self.input_contracts.check(call.to())?;
 
         // credit contract asset_id balance
+        self.storage_written.charge(WORD_SIZE as u64)?;
+        self.storage_journal
+            .record_balance(self.storage, call.to(), &asset_id)?;
         let (_, created_new_entry) = balance_increase(
             self.storage,
             call.to(),
@@ -543,10 +778,14 @@ where
             )?;
         }
 
-        let forward_gas_amount = cmp::min(
-            *self.registers.system_registers.cgas,
-            self.params.amount_of_gas_to_forward,
-        );
+        // The requested gas is clamped to the remaining context gas, unless the
+        // strict call gas is enabled.
+        let available_gas = *self.registers.system_registers.cgas;
+        if self.strict_call_gas && self.params.amount_of_gas_to_forward > available_gas {
+            return Err(PanicReason::InsufficientGasForCall.into())
+        }
+        let forward_gas_amount =
+            cmp::min(available_gas, self.params.amount_of_gas_to_forward);
 
         // subtract gas
         *self.registers.system_registers.cgas = (*self.registers.system_registers.cgas)
@@ -586,7 +825,8 @@ where
             old_sp,
         );
 
-        // Write the frame to memory
+        // Write the frame to memory, with the registers in the order of the
+        // `SavedRegister`.
         // Ownership checks are disabled because we just allocated the memory above.
         let dst = self.memory.write_noownerchecks(
             *self.registers.system_registers.fp,
@@ -595,7 +835,11 @@ where
         let (mem_frame, mem_code) = dst.split_at_mut(CallFrame::serialized_size());
         mem_frame.copy_from_slice(&frame.to_bytes());
         let (mem_code, mem_code_padding) = mem_code.split_at_mut(code_size);
-        read_contract(call.to(), self.storage, mem_code)?;
+        let mut code = CachedContractCode {
+            storage: self.storage,
+            cache: self.code_cache,
+        };
+        read_contract(call.to(), &mut code, mem_code)?;
         mem_code_padding.fill(0);
 
         #[allow(clippy::arithmetic_side_effects)] // Checked above
@@ -606,7 +850,14 @@ where
         *self.registers.system_registers.bal = self.params.amount_of_coins_to_forward;
         *self.registers.system_registers.is = *self.registers.system_registers.pc;
         *self.registers.system_registers.cgas = forward_gas_amount;
-        *self.registers.system_registers.flag = 0;
+        *self.registers.system_registers.flag = self.flag_defaults;
+        trace_event!(
+            contract_id = %call.to(),
+            forward_gas = forward_gas_amount,
+            coins = self.params.amount_of_coins_to_forward,
+            %asset_id,
+            "call"
+        );
 
         let receipt = Receipt::call(
             id,
@@ -628,19 +879,15 @@ where
     }
 }
 
-fn read_contract<S>(
+fn read_contract<C>(
     contract: &ContractId,
-    storage: &S,
+    code: &mut C,
     dst: &mut [u8],
-) -> IoResult<(), S::Error>
+) -> IoResult<(), C::Error>
 where
-    S: StorageSize<ContractsRawCode> + StorageRead<ContractsRawCode> + StorageAsRef,
+    C: ContractCode,
 {
-    let bytes_read = storage
-        .storage::<ContractsRawCode>()
-        .read(contract, 0, dst)
-        .map_err(RuntimeError::Storage)?
-        .ok_or(PanicReason::ContractNotFound)?;
+    let bytes_read = code.read_code(contract, 0, dst)?;
     if bytes_read != dst.len() {
         return Err(PanicReason::ContractMismatch.into())
     }
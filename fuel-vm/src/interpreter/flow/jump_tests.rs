@@ -54,3 +54,20 @@ fn test_not_performed_conditional_jump(
         .jump(Reg::new(&is), RegMut::new(&mut pc))
         .map(|_| pc)
 }
+
+#[test_case(JumpMode::RelativeForwards, 40, 4 => Ok(60); "forwards to the last instruction")]
+#[test_case(JumpMode::RelativeForwards, 40, 5 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "forwards past the code")]
+#[test_case(JumpMode::RelativeForwards, 40, 14 => Ok(100); "forwards to the loaded code")]
+#[test_case(JumpMode::RelativeForwards, 40, 19 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "forwards past the loaded code")]
+#[test_case(JumpMode::RelativeBackwards, 40, 4 => Ok(20); "backwards to the first instruction")]
+#[test_case(JumpMode::RelativeBackwards, 40, 5 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "backwards before the code")]
+#[test_case(JumpMode::Absolute, 40, 50 => Ok(220); "absolute jump isn't limited")]
+fn test_jump_within_code(mode: JumpMode, mut pc: Word, j: Word) -> SimpleResult<Word> {
+    let is = 20;
+    let code = CodeRegion::new(is, 44, 100..120);
+    JumpArgs::new(mode)
+        .to_address(j)
+        .within(code)
+        .jump(Reg::new(&is), RegMut::new(&mut pc))
+        .map(|_| pc)
+}
@@ -158,6 +158,7 @@ fn input<'a>(
         memory,
         context,
         current_contract: Default::default(),
+        allow_empty_reads: false,
     }
 }
 
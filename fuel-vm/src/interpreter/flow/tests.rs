@@ -11,7 +11,10 @@ use crate::storage::{
 };
 
 use super::*;
-use crate::crypto;
+use crate::{
+    crypto,
+    error::RuntimeError,
+};
 use fuel_storage::StorageAsMut;
 use fuel_tx::{
     field::ReceiptsRoot,
@@ -297,6 +300,19 @@ fn mem(set: &[(usize, Vec<u8>)]) -> MemoryInstance {
         reg: RegInput{hp: 1000, sp: 0, ssp: 0, fp: 0, pc: 0, is: 0, bal: 0, cgas: 11, ggas: 11 },
         context: Context::Script{ block_height: Default::default() },
         ..Default::default()
+    } => using check_output(Err(RuntimeError::Recoverable(PanicReason::MalformedCallStructure))); "call_params_pointer truncated by the end of the memory"
+)]
+#[test_case(
+    Input{
+        params: PrepareCallParams {
+            call_params_pointer: VM_MAX_RAM + 1,
+            amount_of_coins_to_forward: 0,
+            asset_id_pointer: 0,
+            amount_of_gas_to_forward: 0,
+        },
+        reg: RegInput{hp: 1000, sp: 0, ssp: 0, fp: 0, pc: 0, is: 0, bal: 0, cgas: 11, ggas: 11 },
+        context: Context::Script{ block_height: Default::default() },
+        ..Default::default()
     } => using check_output(Err(RuntimeError::Recoverable(PanicReason::MemoryOverflow))); "call_params_pointer overflow"
 )]
 #[test_case(
@@ -378,8 +394,14 @@ fn test_prepare_call(input: Input) -> Result<Output, RuntimeError<MemoryStorageE
         gas_cost,
         runtime_balances: &mut runtime_balances,
         storage: &mut storage,
+        storage_written: &mut StorageWriteCounter::default(),
+        storage_journal: &mut StorageJournal::default(),
+        code_cache: &mut CodeCache::default(),
+        contract_info: &mut Default::default(),
         input_contracts: InputContracts::new(&input_contracts, &mut panic_context),
         new_storage_gas_per_byte: 0,
+        strict_call_gas: false,
+        flag_defaults: 0,
         receipts: &mut receipts,
         frames: &mut frames,
         current_contract,
@@ -12,13 +12,18 @@ use crate::{
     },
     consts::*,
     context::Context,
-    error::InterpreterError,
+    error::{
+        InterpreterError,
+        StorageErrorContext,
+        StorageOp,
+    },
     prelude::RuntimeError,
     storage::InterpreterStorage,
 };
 use fuel_asm::RegId;
 use fuel_tx::{
     field::{
+        FlagDefaults,
         Script,
         ScriptGasLimit,
     },
@@ -76,7 +81,14 @@ where
         self.initial_balances = initial_balances.clone();
 
         self.frames.clear();
-        self.receipts.clear();
+        self.contract_info.clear();
+        self.storage_written
+            .reset(self.interpreter_params.max_storage_write_bytes);
+        self.storage_journal
+            .reset(self.interpreter_params.call_frame_reverts);
+        self.returned_call_gas.clear();
+        self.receipts
+            .reset(self.interpreter_params.receipts_capacity_hint);
         self.memory_mut().reset();
 
         // Optimized for memset
@@ -153,6 +165,10 @@ where
         self.registers[RegId::PC] = range.start as fuel_asm::Word;
         self.registers[RegId::IS] = range.start as fuel_asm::Word;
 
+        #[cfg(feature = "debugger")]
+        self.debugger
+            .record_context(self.instruction_index(), &self.context);
+
         Ok(())
     }
 }
@@ -173,7 +189,9 @@ where
         &mut self,
         ready_tx: Ready<Tx>,
     ) -> Result<(), InterpreterError<S::DataError>> {
-        let block_height = self.storage.block_height().map_err(RuntimeError::Storage)?;
+        let block_height = self.storage.block_height().map_err(|e| {
+            RuntimeError::storage(e, StorageErrorContext::new(StorageOp::BlockInfoRead))
+        })?;
 
         self.context = Context::Script { block_height };
 
@@ -189,6 +207,11 @@ where
         let runtime_balances = initial_balances.try_into()?;
         self.init_inner(tx, metadata.balances(), runtime_balances, gas_limit)?;
 
+        // The flag defaults apply to the script and the call frames only, the
+        // predicates always start with the zero `$flag`. The flags are validated by
+        // the check of the transaction policies.
+        self.registers[RegId::FLAG] = self.tx.flag_defaults();
+
         if let Some(script) = self.transaction().as_script() {
             let offset = self.tx_offset().saturating_add(script.script_offset()) as Word;
 
@@ -198,6 +221,10 @@ where
             self.registers[RegId::IS] = offset;
         }
 
+        #[cfg(feature = "debugger")]
+        self.debugger
+            .record_context(self.instruction_index(), &self.context);
+
         Ok(())
     }
 }
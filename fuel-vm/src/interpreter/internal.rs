@@ -49,13 +49,28 @@ where
 
 /// Increase the variable output with a given asset ID. Modifies both the referenced tx
 /// and the serialized tx in vm memory.
+///
+/// With `precise_output_panics`, the missing, the non-variable and the filled outputs
+/// panic with `OutputNotFound`, `ExpectedOutputVariable` and `DoubleSpendOutput`
+/// respectively, instead of `OutputNotFound` for all of them.
 pub(crate) fn set_variable_output<Tx: ExecutableTransaction>(
     tx: &mut Tx,
     memory: &mut MemoryInstance,
     tx_offset: usize,
     idx: usize,
     variable: Output,
+    precise_output_panics: bool,
 ) -> SimpleResult<()> {
+    if precise_output_panics {
+        match tx.outputs().get(idx) {
+            Some(Output::Variable { amount: 0, .. }) => {}
+            Some(Output::Variable { .. }) => {
+                return Err(PanicReason::DoubleSpendOutput.into())
+            }
+            Some(_) => return Err(PanicReason::ExpectedOutputVariable.into()),
+            None => return Err(PanicReason::OutputNotFound.into()),
+        }
+    }
     tx.replace_variable_output(idx, variable)?;
     update_memory_output(tx, memory, tx_offset, idx)
 }
@@ -107,8 +122,14 @@ where
         set_flag(flag, pc, a)
     }
 
-    pub(crate) const fn context(&self) -> &Context {
-        &self.context
+    /// The index of the instruction at `$pc` in the code of the current frame.
+    #[cfg(feature = "debugger")]
+    pub(crate) fn instruction_index(&self) -> Word {
+        self.registers
+            .pc()
+            .saturating_sub(*self.registers.is())
+            .checked_div(Instruction::SIZE as Word)
+            .unwrap_or_default()
     }
 
     pub(crate) const fn is_predicate(&self) -> bool {
@@ -150,7 +150,15 @@ fn variable_output_updates_in_memory() {
     let variable = Output::variable(owner, amount_to_set, asset_id_to_update);
     let tx_offset = vm.tx_offset();
 
-    set_variable_output(&mut vm.tx, vm.memory.as_mut(), tx_offset, 0, variable).unwrap();
+    set_variable_output(
+        &mut vm.tx,
+        vm.memory.as_mut(),
+        tx_offset,
+        0,
+        variable,
+        false,
+    )
+    .unwrap();
 
     // verify the referenced tx output is updated properly
     assert!(matches!(
@@ -36,6 +36,7 @@ where
             fp: fp.as_ref(),
             is: is.as_ref(),
             pc,
+            allow_empty_reads: self.interpreter_params.allow_empty_reads,
         };
         input.log(a, b, c, d)
     }
@@ -56,6 +57,7 @@ where
             fp: fp.as_ref(),
             is: is.as_ref(),
             pc,
+            allow_empty_reads: self.interpreter_params.allow_empty_reads,
         };
         input.log_data(a, b, c, d)
     }
@@ -68,6 +70,7 @@ struct LogInput<'vm> {
     fp: Reg<'vm, FP>,
     is: Reg<'vm, IS>,
     pc: RegMut<'vm, PC>,
+    allow_empty_reads: bool,
 }
 
 impl LogInput<'_> {
@@ -88,7 +91,10 @@ impl LogInput<'_> {
     }
 
     pub(crate) fn log_data(self, a: Word, b: Word, c: Word, d: Word) -> SimpleResult<()> {
-        let data = self.memory.read(c, d)?.to_vec();
+        let data = self
+            .memory
+            .read_range(c, d, self.allow_empty_reads)?
+            .to_vec();
 
         let receipt = Receipt::log_data(
             internal_contract(self.context, self.fp, self.memory).unwrap_or_default(),
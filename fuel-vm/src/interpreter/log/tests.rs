@@ -21,6 +21,7 @@ fn test_log() -> SimpleResult<()> {
         fp: Reg::new(&fp),
         is: Reg::new(&is),
         pc: RegMut::new(&mut pc),
+        allow_empty_reads: false,
     };
     input.log(1, 2, 3, 4)?;
 
@@ -37,6 +38,7 @@ fn test_log() -> SimpleResult<()> {
         fp: Reg::new(&fp),
         is: Reg::new(&is),
         pc: RegMut::new(&mut pc),
+        allow_empty_reads: false,
     };
     input.log_data(1, 2, 3, 4)?;
 
@@ -17,6 +17,7 @@ use fuel_asm::{
     RegId,
 };
 use fuel_types::{
+    bytes::padded_len_word,
     fmt_truncated_hex,
     RegisterId,
     Word,
@@ -38,6 +39,7 @@ use core::ops::{
 use crate::error::{
     IoResult,
     RuntimeError,
+    StorageErrorContext,
 };
 use alloc::vec::Vec;
 use fuel_storage::{
@@ -64,6 +66,7 @@ impl<M> Memory for M where M: AsRef<MemoryInstance> + AsMut<MemoryInstance> {}
 
 /// The memory of the VM, represented as stack and heap.
 #[derive(Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryInstance {
     /// Stack. Grows upwards.
     stack: Vec<u8>,
@@ -72,6 +75,21 @@ pub struct MemoryInstance {
     /// Lowest allowed heap address, i.e. hp register value.
     /// This is needed since we can allocate extra heap for performance reasons.
     hp: usize,
+    /// The usage statistics since the last reset.
+    stats: MemoryStats,
+}
+
+/// The memory usage of the VM since the last reset of the [`MemoryInstance`], e.g.
+/// during the last executed transaction.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryStats {
+    /// The maximum size of the stack in bytes.
+    pub max_stack: usize,
+    /// The maximum size of the heap in bytes.
+    pub max_heap: usize,
+    /// The number of the stack and heap growths.
+    pub grows: u64,
 }
 
 impl Default for MemoryInstance {
@@ -121,6 +139,7 @@ impl MemoryInstance {
             stack: Vec::new(),
             heap: Vec::new(),
             hp: MEM_SIZE,
+            stats: MemoryStats::default(),
         }
     }
 
@@ -128,6 +147,28 @@ impl MemoryInstance {
     pub fn reset(&mut self) {
         self.stack.truncate(0);
         self.hp = MEM_SIZE;
+        self.stats = MemoryStats::default();
+    }
+
+    /// The memory usage since the last reset.
+    pub fn stats(&self) -> MemoryStats {
+        self.stats
+    }
+
+    /// Number of bytes allocated for the stack and the heap.
+    pub fn allocated_bytes(&self) -> usize {
+        self.stack.capacity().saturating_add(self.heap.capacity())
+    }
+
+    /// Resets memory to initial state, releasing the allocations above `max_bytes`.
+    /// The heap allocation is kept first, since it is reused zeroed on growth.
+    pub fn reset_and_trim(&mut self, max_bytes: usize) {
+        self.reset();
+        let heap_len = self.heap.len().min(max_bytes);
+        self.heap.truncate(heap_len);
+        self.heap.shrink_to(heap_len);
+        self.stack
+            .shrink_to(max_bytes.saturating_sub(self.heap.capacity()));
     }
 
     /// Offset of the heap section
@@ -149,6 +190,8 @@ impl MemoryInstance {
             }
 
             self.stack.resize(new_sp, 0);
+            self.stats.max_stack = self.stats.max_stack.max(new_sp);
+            self.stats.grows = self.stats.grows.saturating_add(1);
         }
         Ok(())
     }
@@ -199,6 +242,8 @@ impl MemoryInstance {
 
         self.hp = new_hp;
         *hp_reg = new_hp as Word;
+        self.stats.max_heap = self.stats.max_heap.max(new_len);
+        self.stats.grows = self.stats.grows.saturating_add(1);
 
         // If heap enters region where stack has been, truncate the stack
         self.stack.truncate(new_hp);
@@ -234,6 +279,25 @@ impl MemoryInstance {
         self.verify(addr, C)
     }
 
+    /// Verify that the memory range can be read.
+    ///
+    /// Same as [`Self::verify`], except that the empty range can be read at any
+    /// address up to `MEM_SIZE`, including the unallocated memory between the stack
+    /// and the heap.
+    pub fn verify_read<A: ToAddr, B: ToAddr>(
+        &self,
+        addr: A,
+        count: B,
+    ) -> Result<MemoryRange, PanicReason> {
+        let start = addr.to_addr()?;
+        let len = count.to_addr()?;
+        if len == 0 {
+            return Ok(MemoryRange(start..start))
+        }
+
+        self.verify(start, len)
+    }
+
     /// Returns a reference to memory for reading, if possible.
     #[allow(clippy::arithmetic_side_effects)] // Safety: subtractions are checked
     pub fn read<A: ToAddr, C: ToAddr>(
@@ -254,14 +318,44 @@ impl MemoryInstance {
         }
     }
 
+    /// Returns a reference to the dynamically sized range read by `RETD`, `LOGD`,
+    /// `SMO`, `S256`, `K256` and `MEQ`, if possible.
+    ///
+    /// If `allow_empty_reads`, the range is checked with [`Self::verify_read`], so
+    /// the empty range can be read at any address up to `MEM_SIZE`. Otherwise, same
+    /// as [`Self::read`].
+    pub fn read_range<A: ToAddr, C: ToAddr>(
+        &self,
+        addr: A,
+        count: C,
+        allow_empty_reads: bool,
+    ) -> Result<&[u8], PanicReason> {
+        let addr = addr.to_addr()?;
+        let count = count.to_addr()?;
+        if allow_empty_reads && self.verify_read(addr, count)?.is_empty() {
+            return Ok(&[])
+        }
+
+        self.read(addr, count)
+    }
+
     /// Reads a constant-sized byte array from memory, if possible.
     pub fn read_bytes<A: ToAddr, const C: usize>(
         &self,
         at: A,
     ) -> Result<[u8; C], PanicReason> {
-        let mut result = [0; C];
-        result.copy_from_slice(self.read(at, C)?);
-        Ok(result)
+        Ok(*self.read_bytes_ref(at)?)
+    }
+
+    /// Borrows a constant-sized byte array from memory without copying it.
+    pub fn read_bytes_ref<A: ToAddr, const C: usize>(
+        &self,
+        at: A,
+    ) -> Result<&[u8; C], PanicReason> {
+        let bytes = self.read(at, C)?;
+        Ok(bytes
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("Range was verified to be of length C")))
     }
 
     /// Gets write access to memory, if possible.
@@ -403,6 +497,24 @@ impl MemoryInstance {
         &self.heap
     }
 
+    /// Writes a 256-bit integer in the byte order used by the wide integer
+    /// instructions. Doesn't perform any ownership checks.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn write_u256<A: ToAddr>(
+        &mut self,
+        addr: A,
+        value: fuel_types::U256,
+    ) -> Result<(), PanicReason> {
+        self.write_bytes_noownerchecks(addr, value.into())
+    }
+
+    /// Reads a 256-bit integer in the byte order used by the wide integer
+    /// instructions.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn read_u256<A: ToAddr>(&self, addr: A) -> Result<fuel_types::U256, PanicReason> {
+        self.read_bytes(addr).map(Into::into)
+    }
+
     /// Returns a `MemoryRollbackData` that can be used to achieve the state of the
     /// `desired_memory_state` instance.
     pub fn collect_rollback_data(
@@ -612,6 +724,18 @@ impl MemoryRange {
         Self(start..start.saturating_add(len))
     }
 
+    /// Create a new memory range from VM-provided `addr` and `len`.
+    /// Returns `MemoryOverflow` if the range doesn't fit into the VM memory.
+    pub fn checked_new(addr: Word, len: Word) -> Result<Self, PanicReason> {
+        let end = addr.checked_add(len).ok_or(PanicReason::MemoryOverflow)?;
+        if end > VM_MAX_RAM {
+            return Err(PanicReason::MemoryOverflow)
+        }
+        let start = addr.to_addr()?;
+        let end = end.to_addr()?;
+        Ok(Self(start..end))
+    }
+
     /// Start of the range.
     pub fn start(&self) -> usize {
         self.0.start
@@ -642,6 +766,36 @@ impl MemoryRange {
         self.0.start as Word..self.0.end as Word
     }
 
+    /// End of the range as a `Word`, clamped to `VM_MAX_RAM`.
+    pub fn saturating_end(&self) -> Word {
+        core::cmp::min(self.0.end as Word, VM_MAX_RAM)
+    }
+
+    /// Do the ranges share at least one byte? Empty ranges never overlap.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        core::cmp::max(self.start(), other.start())
+            < core::cmp::min(self.end(), other.end())
+    }
+
+    /// Is `other` fully inside of this range?
+    pub fn contains_range(&self, other: &Self) -> bool {
+        self.start() <= other.start() && other.end() <= self.end()
+    }
+
+    /// Splits range at given relative offset.
+    /// Returns `MemoryOverflow` if offset > range length or the range is outside of
+    /// the VM memory.
+    pub fn split_at(self, at: Word) -> Result<(Self, Self), PanicReason> {
+        if self.end() > MEM_SIZE {
+            return Err(PanicReason::MemoryOverflow)
+        }
+        let at = at.to_addr()?;
+        if at > self.len() {
+            return Err(PanicReason::MemoryOverflow)
+        }
+        Ok(self.split_at_offset(at))
+    }
+
     /// Splits range at given relative offset. Panics if offset > range length.
     pub fn split_at_offset(self, at: usize) -> (Self, Self) {
         let mid = self.0.start.saturating_add(at);
@@ -748,6 +902,28 @@ where
         load_word(self.memory.as_ref(), pc, result, b, c)
     }
 
+    pub(crate) fn load_half_word(
+        &mut self,
+        ra: RegisterId,
+        b: Word,
+        c: Imm12,
+    ) -> SimpleResult<()> {
+        let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
+        let result = &mut w[WriteRegKey::try_from(ra)?];
+        load_half_word(self.memory.as_ref(), pc, result, b, c)
+    }
+
+    pub(crate) fn load_quarter_word(
+        &mut self,
+        ra: RegisterId,
+        b: Word,
+        c: Imm12,
+    ) -> SimpleResult<()> {
+        let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
+        let result = &mut w[WriteRegKey::try_from(ra)?];
+        load_quarter_word(self.memory.as_ref(), pc, result, b, c)
+    }
+
     pub(crate) fn store_byte(&mut self, a: Word, b: Word, c: Word) -> SimpleResult<()> {
         let owner = self.ownership_registers();
         store_byte(
@@ -772,6 +948,40 @@ where
         )
     }
 
+    pub(crate) fn store_half_word(
+        &mut self,
+        a: Word,
+        b: Word,
+        c: Imm12,
+    ) -> SimpleResult<()> {
+        let owner = self.ownership_registers();
+        store_half_word(
+            self.memory.as_mut(),
+            owner,
+            self.registers.pc_mut(),
+            a,
+            b,
+            c,
+        )
+    }
+
+    pub(crate) fn store_quarter_word(
+        &mut self,
+        a: Word,
+        b: Word,
+        c: Imm12,
+    ) -> SimpleResult<()> {
+        let owner = self.ownership_registers();
+        store_quarter_word(
+            self.memory.as_mut(),
+            owner,
+            self.registers.pc_mut(),
+            a,
+            b,
+            c,
+        )
+    }
+
     /// Expand heap by `amount` bytes.
     pub fn allocate(&mut self, amount: Word) -> SimpleResult<()> {
         let (SystemRegisters { hp, sp, .. }, _) = split_registers(&mut self.registers);
@@ -782,7 +992,8 @@ where
     pub(crate) fn malloc(&mut self, a: Word) -> SimpleResult<()> {
         let (SystemRegisters { hp, sp, pc, .. }, _) =
             split_registers(&mut self.registers);
-        malloc(hp, sp.as_ref(), pc, a, self.memory.as_mut())
+        let word_aligned = self.interpreter_params.word_aligned_heap;
+        malloc(hp, sp.as_ref(), pc, a, word_aligned, self.memory.as_mut())
     }
 
     pub(crate) fn memclear(&mut self, a: Word, b: Word) -> SimpleResult<()> {
@@ -811,7 +1022,8 @@ where
     ) -> SimpleResult<()> {
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let result = &mut w[WriteRegKey::try_from(ra)?];
-        memeq(self.memory.as_mut(), result, pc, b, c, d)
+        let allow_empty_reads = self.interpreter_params.allow_empty_reads;
+        memeq(self.memory.as_mut(), result, pc, b, c, d, allow_empty_reads)
     }
 }
 
@@ -958,6 +1170,43 @@ pub(crate) fn load_word(
     Ok(inc_pc(pc)?)
 }
 
+/// The address of the `c`-th value of `width` bytes starting at `b`.
+///
+/// The narrow loads and stores don't require the address to be aligned, same as
+/// `LW` and `SW`.
+fn narrow_addr(b: Word, c: Imm12, width: Word) -> SimpleResult<Word> {
+    let offset = u64::from(c)
+        .checked_mul(width)
+        .expect("u12 * 4 cannot overflow a Word");
+    Ok(b.checked_add(offset).ok_or(PanicReason::MemoryOverflow)?)
+}
+
+/// Loads the half word at `b + c * 4`, zero-extended.
+pub(crate) fn load_half_word(
+    memory: &MemoryInstance,
+    pc: RegMut<PC>,
+    result: &mut Word,
+    b: Word,
+    c: Imm12,
+) -> SimpleResult<()> {
+    let addr = narrow_addr(b, c, 4)?;
+    *result = Word::from(u32::from_be_bytes(memory.read_bytes(addr)?));
+    Ok(inc_pc(pc)?)
+}
+
+/// Loads the quarter word at `b + c * 2`, zero-extended.
+pub(crate) fn load_quarter_word(
+    memory: &MemoryInstance,
+    pc: RegMut<PC>,
+    result: &mut Word,
+    b: Word,
+    c: Imm12,
+) -> SimpleResult<()> {
+    let addr = narrow_addr(b, c, 2)?;
+    *result = Word::from(u16::from_be_bytes(memory.read_bytes(addr)?));
+    Ok(inc_pc(pc)?)
+}
+
 #[allow(clippy::cast_possible_truncation)]
 pub(crate) fn store_byte(
     memory: &mut MemoryInstance,
@@ -988,13 +1237,54 @@ pub(crate) fn store_word(
     Ok(inc_pc(pc)?)
 }
 
+/// Stores the least significant half word of `b` at `a + c * 4`.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn store_half_word(
+    memory: &mut MemoryInstance,
+    owner: OwnershipRegisters,
+    pc: RegMut<PC>,
+    a: Word,
+    b: Word,
+    c: Imm12,
+) -> SimpleResult<()> {
+    let addr = narrow_addr(a, c, 4)?;
+    memory.write_bytes(owner, addr, (b as u32).to_be_bytes())?;
+    Ok(inc_pc(pc)?)
+}
+
+/// Stores the least significant quarter word of `b` at `a + c * 2`.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn store_quarter_word(
+    memory: &mut MemoryInstance,
+    owner: OwnershipRegisters,
+    pc: RegMut<PC>,
+    a: Word,
+    b: Word,
+    c: Imm12,
+) -> SimpleResult<()> {
+    let addr = narrow_addr(a, c, 2)?;
+    memory.write_bytes(owner, addr, (b as u16).to_be_bytes())?;
+    Ok(inc_pc(pc)?)
+}
+
+/// Allocates `amount` bytes on the heap.
+///
+/// If `word_aligned`, the amount is rounded up to a multiple of the word size. The
+/// heap starts at the word-aligned end of the memory, so the rounding keeps `$hp`
+/// word-aligned and the allocations can be accessed with `LW`/`SW`.
 pub(crate) fn malloc(
     hp: RegMut<HP>,
     sp: Reg<SP>,
     pc: RegMut<PC>,
     amount: Word,
+    word_aligned: bool,
     memory: &mut MemoryInstance,
 ) -> SimpleResult<()> {
+    let amount = if word_aligned {
+        padded_len_word(amount).ok_or(PanicReason::MemoryOverflow)?
+    } else {
+        amount
+    };
     memory.grow_heap_by(sp, hp, amount)?;
     Ok(inc_pc(pc)?)
 }
@@ -1030,8 +1320,12 @@ pub(crate) fn memeq(
     b: Word,
     c: Word,
     d: Word,
+    allow_empty_reads: bool,
 ) -> SimpleResult<()> {
-    *result = (memory.read(b, d)? == memory.read(c, d)?) as Word;
+    // Both ranges are borrowed from the memory without copying, so overlapping
+    // ranges are compared as is.
+    *result = (memory.read_range(b, d, allow_empty_reads)?
+        == memory.read_range(c, d, allow_empty_reads)?) as Word;
     Ok(inc_pc(pc)?)
 }
 
@@ -1148,6 +1442,7 @@ pub(crate) fn copy_from_storage_zero_fill<M, S>(
     src_offset: u64,
     src_len: usize,
     no_found_error: PanicReason,
+    context: StorageErrorContext,
 ) -> IoResult<(), S::Error>
 where
     M: Mappable,
@@ -1168,7 +1463,7 @@ where
             .read(src_id, src_offset as usize, src_read_buffer)
             .transpose()
             .ok_or(no_found_error)?
-            .map_err(RuntimeError::Storage)?;
+            .map_err(|e| RuntimeError::storage(e, context))?;
 
         empty_offset = src_read_length;
     }
@@ -16,7 +16,26 @@ use crate::error::PanicOrBug;
 #[test_case(20, 10, 0 => Ok(20); "Zero allocation size")]
 #[test_case(20, 10, 10 => Ok(10); "Allocation size equal to available memory")]
 #[test_case(20, 10, 5 => Ok(15); "Allocation size smaller than available memory")]
-fn test_malloc(mut hp: Word, sp: Word, a: Word) -> SimpleResult<Word> {
+fn test_malloc(hp: Word, sp: Word, a: Word) -> SimpleResult<Word> {
+    malloc_with(hp, sp, a, false)
+}
+
+#[test_case(20, 10, 0 => Ok(20); "Zero allocation size")]
+#[test_case(24, 8, 16 => Ok(8); "Allocation size equal to available memory")]
+#[test_case(24, 8, 8 => Ok(16); "Allocation size smaller than available memory")]
+#[test_case(24, 8, 5 => Ok(16); "Allocation size is rounded up to the word size")]
+#[test_case(24, 17, 1 => Err(PanicOrBug::Panic(PanicReason::MemoryGrowthOverlap)); "Rounded allocation size exceeds available memory")]
+#[test_case(Word::MAX, 0, Word::MAX => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "Rounding overflows")]
+fn test_malloc_word_aligned(hp: Word, sp: Word, a: Word) -> SimpleResult<Word> {
+    malloc_with(hp, sp, a, true)
+}
+
+fn malloc_with(
+    mut hp: Word,
+    sp: Word,
+    a: Word,
+    word_aligned: bool,
+) -> SimpleResult<Word> {
     let mut memory = MemoryInstance::new();
     memory.hp = hp as usize;
     let mut pc = 4;
@@ -25,6 +44,7 @@ fn test_malloc(mut hp: Word, sp: Word, a: Word) -> SimpleResult<Word> {
         Reg::new(&sp),
         RegMut::new(&mut pc),
         a,
+        word_aligned,
         &mut memory,
     )?;
     assert_eq!(pc, 8);
@@ -102,7 +122,15 @@ fn test_memeq(b: Word, c: Word, d: Word) -> SimpleResult<()> {
     let mut pc = 4;
     let mut result = 0;
 
-    memeq(&mut memory, &mut result, RegMut::new(&mut pc), b, c, d)?;
+    memeq(
+        &mut memory,
+        &mut result,
+        RegMut::new(&mut pc),
+        b,
+        c,
+        d,
+        false,
+    )?;
 
     assert_eq!(pc, 8);
     assert_eq!(result, 1);
@@ -110,6 +138,35 @@ fn test_memeq(b: Word, c: Word, d: Word) -> SimpleResult<()> {
     Ok(())
 }
 
+#[test_case(true, VM_MAX_RAM / 2, VM_MAX_RAM / 2 + 1 => Ok(1); "unallocated memory")]
+#[test_case(false, VM_MAX_RAM / 2, VM_MAX_RAM / 2 + 1 => Err(PanicOrBug::Panic(PanicReason::UninitalizedMemoryAccess)); "unallocated memory without empty reads")]
+#[test_case(true, VM_MAX_RAM, 0 => Ok(1); "end of memory")]
+#[test_case(false, VM_MAX_RAM, 0 => Ok(1); "end of memory without empty reads")]
+#[test_case(true, VM_MAX_RAM + 1, 0 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "b > VM_MAX_RAM")]
+#[test_case(true, 0, VM_MAX_RAM + 1 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "c > VM_MAX_RAM")]
+fn test_memeq_zero_length(
+    allow_empty_reads: bool,
+    b: Word,
+    c: Word,
+) -> SimpleResult<Word> {
+    let mut memory = MemoryInstance::new();
+    let mut pc = 4;
+    let mut result = 0;
+
+    memeq(
+        &mut memory,
+        &mut result,
+        RegMut::new(&mut pc),
+        b,
+        c,
+        0,
+        allow_empty_reads,
+    )?;
+
+    assert_eq!(pc, 8);
+    Ok(result)
+}
+
 #[test_case(true, 20, 0, 40, 10 => Ok(()); "Can move sp up")]
 #[test_case(false, 20, 0, 40, 10 => Ok(()); "Can move sp down")]
 #[test_case(true, u64::MAX - 10, 0, u64::MAX, 20 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "Panics on overflowing addition")]
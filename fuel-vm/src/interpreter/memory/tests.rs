@@ -1,4 +1,8 @@
-#![allow(clippy::cast_possible_truncation)]
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::cast_possible_truncation,
+    non_snake_case
+)]
 
 use alloc::vec;
 use core::ops::Range;
@@ -296,8 +300,169 @@ fn test_copy_from_storage_zero_fill(
         src_offset,
         contract_size,
         PanicReason::ContractNotFound,
+        StorageErrorContext::new(StorageOp::ContractCodeRead).contract(contract_id),
     )
     .is_ok();
     let memory: [u8; 5] = memory[..5].try_into().unwrap();
     (r, memory)
 }
+
+/// Naive reference implementation of [`MemoryRange::checked_new`] over `u128`.
+fn naive_range(addr: Word, len: Word) -> Option<(u128, u128)> {
+    let start = u128::from(addr);
+    let end = start + u128::from(len);
+    (end <= u128::from(VM_MAX_RAM)).then_some((start, end))
+}
+
+/// Maps random values close to the VM memory bounds half of the time,
+/// since uniformly random 64-bit values are almost never valid ranges.
+fn near_memory(value: Word) -> Word {
+    if value & 1 == 0 {
+        value
+    } else {
+        value % (VM_MAX_RAM * 2)
+    }
+}
+
+#[quickcheck_macros::quickcheck]
+fn memory_range_checked_new__matches_naive(addr: Word, len: Word) -> bool {
+    let (addr, len) = (near_memory(addr), near_memory(len));
+    match (MemoryRange::checked_new(addr, len), naive_range(addr, len)) {
+        (Ok(range), Some((start, end))) => {
+            range.start() as u128 == start
+                && range.end() as u128 == end
+                && range.saturating_end() as u128 == end
+        }
+        (Err(PanicReason::MemoryOverflow), None) => true,
+        _ => false,
+    }
+}
+
+#[quickcheck_macros::quickcheck]
+fn memory_range_saturating_end__matches_naive(start: Word, len: Word) -> bool {
+    let (start, len) = (near_memory(start), near_memory(len));
+    let range = MemoryRange::new(start as usize, len as usize);
+    let expected = core::cmp::min(
+        (u128::from(start) + u128::from(len)).min(usize::MAX as u128),
+        u128::from(VM_MAX_RAM),
+    );
+    u128::from(range.saturating_end()) == expected
+}
+
+#[quickcheck_macros::quickcheck]
+fn memory_range_overlaps_and_contains__match_naive(
+    a: (Word, Word),
+    b: (Word, Word),
+) -> quickcheck::TestResult {
+    // Keep the ranges short and close to each other, so that they overlap often.
+    let a = (a.0 % VM_MAX_RAM, a.1 % 1024);
+    let b = (
+        a.0.saturating_sub(1024).saturating_add(b.0 % 2048),
+        b.1 % 1024,
+    );
+    let (Ok(ra), Ok(rb)) = (
+        MemoryRange::checked_new(a.0, a.1),
+        MemoryRange::checked_new(b.0, b.1),
+    ) else {
+        return quickcheck::TestResult::discard()
+    };
+    let (a_start, a_end) = naive_range(a.0, a.1).unwrap();
+    let (b_start, b_end) = naive_range(b.0, b.1).unwrap();
+
+    let naive_overlaps = (a_start..a_end).any(|byte| (b_start..b_end).contains(&byte));
+    let naive_contains = a_start <= b_start && b_end <= a_end;
+
+    quickcheck::TestResult::from_bool(
+        ra.overlaps(&rb) == naive_overlaps
+            && rb.overlaps(&ra) == naive_overlaps
+            && ra.contains_range(&rb) == naive_contains,
+    )
+}
+
+#[quickcheck_macros::quickcheck]
+fn memory_range_split_at__matches_naive(addr: Word, len: Word, at: Word) -> bool {
+    let (addr, len, at) = (near_memory(addr), near_memory(len), near_memory(at));
+    let Some((start, end)) = naive_range(addr, len) else {
+        return MemoryRange::checked_new(addr, len).is_err()
+    };
+    let range = MemoryRange::checked_new(addr, len).unwrap();
+    let result = range.clone().split_at(at);
+
+    if u128::from(at) > end - start {
+        return result == Err(PanicReason::MemoryOverflow)
+    }
+    let Ok((left, right)) = result else {
+        return false
+    };
+    let mid = start + u128::from(at);
+    left.start() as u128 == start
+        && left.end() as u128 == mid
+        && right.start() as u128 == mid
+        && right.end() as u128 == end
+        && range.contains_range(&left)
+        && range.contains_range(&right)
+        && !left.overlaps(&right)
+}
+
+#[test]
+fn memory_range__empty_ranges_never_overlap() {
+    let range = MemoryRange::checked_new(10, 10).unwrap();
+    let empty = MemoryRange::checked_new(15, 0).unwrap();
+    assert!(!range.overlaps(&empty));
+    assert!(!empty.overlaps(&empty));
+    assert!(range.contains_range(&empty));
+}
+
+#[test]
+fn memory_range_checked_new__rejects_out_of_memory() {
+    assert_eq!(
+        MemoryRange::checked_new(VM_MAX_RAM, 1),
+        Err(PanicReason::MemoryOverflow)
+    );
+    assert_eq!(
+        MemoryRange::checked_new(Word::MAX, 2),
+        Err(PanicReason::MemoryOverflow)
+    );
+    assert!(MemoryRange::checked_new(VM_MAX_RAM, 0).is_ok());
+}
+
+#[test]
+fn write_u256__uses_big_endian_byte_order() {
+    let mut memory = MemoryInstance::new();
+    memory.grow_stack(64).unwrap();
+    let value = fuel_types::U256::from(0x0102u64);
+
+    memory.write_u256(16, value).unwrap();
+
+    assert_eq!(memory.read_u256(16), Ok(value));
+    assert_eq!(memory.read(46, 2), Ok(&[0x01, 0x02][..]));
+    assert_eq!(
+        memory.write_u256(VM_MAX_RAM - 31, value),
+        Err(PanicReason::MemoryOverflow)
+    );
+}
+
+#[test]
+fn memory_stats__are_reset_with_the_memory() {
+    // Given
+    let mut memory = MemoryInstance::new();
+    let mut hp = MEM_SIZE as Word;
+    memory.grow_stack(64).unwrap();
+    memory
+        .grow_heap_by(Reg::new(&64), RegMut::new(&mut hp), 128)
+        .unwrap();
+    assert_eq!(
+        memory.stats(),
+        MemoryStats {
+            max_stack: 64,
+            max_heap: 128,
+            grows: 2,
+        }
+    );
+
+    // When
+    memory.reset();
+
+    // Then
+    assert_eq!(memory.stats(), MemoryStats::default());
+}
@@ -10,7 +10,14 @@ use crate::{
     consts::*,
     context::Context,
     convert,
-    error::SimpleResult,
+    error::{
+        IoResult,
+        RuntimeError,
+        SimpleResult,
+        StorageErrorContext,
+        StorageOp,
+    },
+    storage::InterpreterStorage,
 };
 
 use fuel_asm::{
@@ -21,12 +28,18 @@ use fuel_asm::{
 };
 use fuel_tx::{
     field::{
+        BlobId as BlobIdField,
+        BytecodeRoot,
         BytecodeWitnessIndex,
+        ProofSet,
         Salt,
         Script as ScriptField,
         ScriptData,
         ScriptGasLimit,
         StorageSlots,
+        SubsectionIndex,
+        SubsectionsNumber,
+        UpgradePurpose as UpgradePurposeField,
     },
     policies::PolicyType,
     Input,
@@ -36,6 +49,7 @@ use fuel_tx::{
     UtxoId,
 };
 use fuel_types::{
+    Bytes32,
     ChainId,
     Immediate12,
     Immediate18,
@@ -51,16 +65,45 @@ where
     M: Memory,
     Tx: ExecutableTransaction,
 {
+    /// The gas cost of the `GM` with the `imm` selector. The selectors reading the
+    /// storage have their own costs, the others only read the interpreter state and
+    /// cost `gm`.
+    pub(crate) fn metadata_gas_cost(&self, imm: Immediate18) -> SimpleResult<Word> {
+        let gas_costs = self.gas_costs();
+        let cost = match GMArgs::try_from(imm) {
+            Ok(GMArgs::GetConsensusParametersVersion) => gas_costs
+                .gm_consensus_parameters_version()
+                .map_err(PanicReason::from)?,
+            Ok(GMArgs::GetStateTransitionVersion) => gas_costs
+                .gm_state_transition_version()
+                .map_err(PanicReason::from)?,
+            Ok(
+                GMArgs::IsCallerExternal
+                | GMArgs::GetCaller
+                | GMArgs::GetVerifyingPredicate
+                | GMArgs::GetChainId
+                | GMArgs::TxStart
+                | GMArgs::BaseAssetId,
+            )
+            | Err(_) => gas_costs.gm(),
+        };
+        Ok(cost)
+    }
+
     pub(crate) fn metadata(
         &mut self,
         ra: RegisterId,
         imm: Immediate18,
-    ) -> SimpleResult<()> {
+    ) -> IoResult<(), S::DataError>
+    where
+        S: InterpreterStorage,
+    {
         let tx_offset = self.tx_offset() as Word;
         let chain_id = self.chain_id();
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let result = &mut w[WriteRegKey::try_from(ra)?];
         metadata(
+            &self.storage,
             &self.context,
             &self.frames,
             pc,
@@ -99,7 +142,9 @@ where
     }
 }
 
-pub(crate) fn metadata(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn metadata<S: InterpreterStorage>(
+    storage: &S,
     context: &Context,
     frames: &[CallFrame],
     pc: RegMut<PC>,
@@ -107,12 +152,17 @@ pub(crate) fn metadata(
     imm: Immediate18,
     chain_id: ChainId,
     tx_offset: Word,
-) -> SimpleResult<()> {
+) -> IoResult<(), S::DataError> {
     let parent = context
         .is_internal()
         .then(|| frames.last().map(|f| f.registers()[RegId::FP]))
         .flatten();
 
+    // The predicates don't have access to the storage.
+    let storage = (!context.is_predicate())
+        .then_some(storage)
+        .ok_or(PanicReason::ContractInstructionNotAllowed);
+
     *result = match GMArgs::try_from(imm)? {
         GMArgs::GetVerifyingPredicate => context
             .predicate()
@@ -130,6 +180,24 @@ pub(crate) fn metadata(
             Some(p) => (p == 0) as Word,
             None => return Err(PanicReason::ExpectedInternalContext.into()),
         },
+        GMArgs::GetConsensusParametersVersion => storage?
+            .consensus_parameters_version()
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ConsensusParametersRead),
+                )
+            })?
+            .into(),
+        GMArgs::GetStateTransitionVersion => storage?
+            .state_transition_version()
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::StateTransitionRead),
+                )
+            })?
+            .into(),
     };
 
     inc_pc(pc)?;
@@ -193,6 +261,10 @@ impl<Tx> GTFInput<'_, Tx> {
                 .policies()
                 .get(PolicyType::MaxFee)
                 .ok_or(PanicReason::PolicyIsNotSet)?,
+            GTFArgs::PolicyFlagDefaults => tx
+                .policies()
+                .get(PolicyType::FlagDefaults)
+                .ok_or(PanicReason::PolicyIsNotSet)?,
             GTFArgs::ScriptInputsCount | GTFArgs::CreateInputsCount => {
                 tx.inputs().len() as Word
             }
@@ -554,7 +626,7 @@ impl<Tx> GTFInput<'_, Tx> {
                             create.storage_slots_offset_at(b).unwrap_or_default(),
                         )) as Word
                     }
-                    _ => return Err(PanicReason::InvalidMetadataIdentifier.into()),
+                    _ => upgradable_field(tx, ofs, b, specific_args)?,
                 }
             }
         };
@@ -565,3 +637,58 @@ impl<Tx> GTFInput<'_, Tx> {
         Ok(())
     }
 }
+
+/// The fields specific to the `Upgrade`, `Upload` and `Blob` transactions.
+fn upgradable_field<Tx>(
+    tx: &Tx,
+    ofs: usize,
+    b: usize,
+    args: GTFArgs,
+) -> SimpleResult<Word>
+where
+    Tx: ExecutableTransaction,
+{
+    let field = match args {
+        // Upgrade
+        GTFArgs::UpgradePurpose => tx
+            .as_upgrade()
+            .map(|upgrade| ofs.saturating_add(upgrade.upgrade_purpose_offset()) as Word),
+
+        // Upload
+        GTFArgs::UploadRoot => tx
+            .as_upload()
+            .map(|upload| ofs.saturating_add(upload.bytecode_root_offset()) as Word),
+        GTFArgs::UploadWitnessIndex => tx
+            .as_upload()
+            .map(|upload| *upload.bytecode_witness_index() as Word),
+        GTFArgs::UploadSubsectionIndex => tx
+            .as_upload()
+            .map(|upload| *upload.subsection_index() as Word),
+        GTFArgs::UploadSubsectionsNumber => tx
+            .as_upload()
+            .map(|upload| *upload.subsections_number() as Word),
+        GTFArgs::UploadProofSetCount => tx
+            .as_upload()
+            .map(|upload| upload.proof_set().len() as Word),
+        GTFArgs::UploadProofSetAtIndex => tx
+            .as_upload()
+            .filter(|upload| b < upload.proof_set().len())
+            .map(|upload| {
+                ofs.saturating_add(upload.proof_set_offset())
+                    .saturating_add(b.saturating_mul(Bytes32::LEN))
+                    as Word
+            }),
+
+        // Blob
+        GTFArgs::BlobId => tx
+            .as_blob()
+            .map(|blob| ofs.saturating_add(blob.blob_id_offset()) as Word),
+        GTFArgs::BlobWitnessIndex => tx
+            .as_blob()
+            .map(|blob| *blob.bytecode_witness_index() as Word),
+
+        _ => None,
+    };
+
+    field.ok_or_else(|| PanicReason::InvalidMetadataIdentifier.into())
+}
@@ -1,13 +1,20 @@
 use alloc::vec;
 
 use fuel_tx::{
+    Blob,
+    Create,
     Script,
     TxParameters,
+    Upgrade,
+    Upload,
 };
 use fuel_types::BlockHeight;
 use test_case::test_case;
 
-use crate::prelude::RuntimePredicate;
+use crate::{
+    prelude::RuntimePredicate,
+    storage::MemoryStorage,
+};
 
 use super::*;
 
@@ -21,6 +28,7 @@ fn test_metadata() {
     let mut result = 1;
     let imm = 0x03;
     metadata(
+        &MemoryStorage::default(),
         &context,
         &frames,
         RegMut::new(&mut pc),
@@ -69,6 +77,7 @@ fn get_chain_id(context: Context, chain_id: u64) {
         frames.push(CallFrame::default());
     }
     metadata(
+        &MemoryStorage::default(),
         &context,
         &frames,
         RegMut::new(&mut pc),
@@ -81,3 +90,192 @@ fn get_chain_id(context: Context, chain_id: u64) {
 
     assert_eq!(result, chain_id);
 }
+
+#[test_case(Context::Script { block_height: BlockHeight::default() }, GMArgs::GetConsensusParametersVersion => Ok(7); "consensus parameters version inside script")]
+#[test_case(Context::Call { block_height: BlockHeight::default() }, GMArgs::GetConsensusParametersVersion => Ok(7); "consensus parameters version inside call")]
+#[test_case(Context::Script { block_height: BlockHeight::default() }, GMArgs::GetStateTransitionVersion => Ok(9); "state transition version inside script")]
+#[test_case(Context::Call { block_height: BlockHeight::default() }, GMArgs::GetStateTransitionVersion => Ok(9); "state transition version inside call")]
+#[test_case(Context::PredicateEstimation { program: RuntimePredicate::empty() }, GMArgs::GetConsensusParametersVersion => Err(PanicReason::ContractInstructionNotAllowed); "consensus parameters version inside predicate estimation")]
+#[test_case(Context::PredicateVerification { program: RuntimePredicate::empty() }, GMArgs::GetStateTransitionVersion => Err(PanicReason::ContractInstructionNotAllowed); "state transition version inside predicate verification")]
+fn get_versions(context: Context, args: GMArgs) -> Result<Word, PanicReason> {
+    let storage =
+        MemoryStorage::new_with_versions(Default::default(), Default::default(), 7, 9);
+    let mut frames = vec![];
+    let mut pc = 4;
+    let mut result = 1;
+
+    if context.is_internal() {
+        frames.push(CallFrame::default());
+    }
+    metadata(
+        &storage,
+        &context,
+        &frames,
+        RegMut::new(&mut pc),
+        &mut result,
+        args as Immediate18,
+        ChainId::default(),
+        TxParameters::default().tx_offset() as Word,
+    )
+    .map_err(|e| match e {
+        RuntimeError::Recoverable(reason) => reason,
+        e => panic!("unexpected error {e:?}"),
+    })?;
+
+    Ok(result)
+}
+
+fn get_transaction_field<Tx: ExecutableTransaction>(
+    tx: &Tx,
+    args: GTFArgs,
+    b: Word,
+) -> SimpleResult<Word> {
+    let mut pc = 4;
+    let input_contracts_index_to_output_index = Default::default();
+    let input = GTFInput {
+        tx,
+        input_contracts_index_to_output_index: &input_contracts_index_to_output_index,
+        tx_offset: 0,
+        tx_size: 0,
+        pc: RegMut::new(&mut pc),
+    };
+    let mut result = 0;
+    input.get_transaction_field(&mut result, b, args as Immediate12)?;
+    Ok(result)
+}
+
+const TX_SPECIFIC_ARGS: [GTFArgs; 9] = [
+    GTFArgs::UpgradePurpose,
+    GTFArgs::UploadRoot,
+    GTFArgs::UploadWitnessIndex,
+    GTFArgs::UploadSubsectionIndex,
+    GTFArgs::UploadSubsectionsNumber,
+    GTFArgs::UploadProofSetCount,
+    GTFArgs::UploadProofSetAtIndex,
+    GTFArgs::BlobId,
+    GTFArgs::BlobWitnessIndex,
+];
+
+/// Checks that only the `allowed` transaction specific selectors succeed, while the
+/// others panic with `InvalidMetadataIdentifier`. The general selectors succeed for
+/// any transaction type.
+fn assert_tx_specific_fields<Tx: ExecutableTransaction>(tx: &Tx, allowed: &[GTFArgs]) {
+    for args in TX_SPECIFIC_ARGS {
+        let result = get_transaction_field(tx, args, 0);
+        if allowed.contains(&args) {
+            assert!(result.is_ok(), "{args:?} should be available: {result:?}");
+        } else {
+            assert_eq!(
+                result,
+                Err(PanicReason::InvalidMetadataIdentifier.into()),
+                "{args:?} should not be available"
+            );
+        }
+    }
+
+    for args in [
+        GTFArgs::Type,
+        GTFArgs::ScriptInputsCount,
+        GTFArgs::ScriptOutputsCount,
+        GTFArgs::ScriptWitnessesCount,
+        GTFArgs::PolicyTypes,
+    ] {
+        let result = get_transaction_field(tx, args, 0);
+        assert!(result.is_ok(), "{args:?} should be available: {result:?}");
+    }
+}
+
+#[test]
+fn get_transaction_field_tx_specific_fields_of_script() {
+    assert_tx_specific_fields(&Script::default(), &[]);
+}
+
+#[test]
+fn get_transaction_field_tx_specific_fields_of_create() {
+    assert_tx_specific_fields(&Create::default(), &[]);
+}
+
+#[test]
+fn get_transaction_field_tx_specific_fields_of_upgrade() {
+    assert_tx_specific_fields(&Upgrade::default(), &[GTFArgs::UpgradePurpose]);
+}
+
+#[test]
+fn get_transaction_field_tx_specific_fields_of_upload() {
+    let mut upload = Upload::default();
+    upload.proof_set_mut().push(Bytes32::zeroed());
+
+    assert_tx_specific_fields(
+        &upload,
+        &[
+            GTFArgs::UploadRoot,
+            GTFArgs::UploadWitnessIndex,
+            GTFArgs::UploadSubsectionIndex,
+            GTFArgs::UploadSubsectionsNumber,
+            GTFArgs::UploadProofSetCount,
+            GTFArgs::UploadProofSetAtIndex,
+        ],
+    );
+}
+
+#[test]
+fn get_transaction_field_tx_specific_fields_of_blob() {
+    assert_tx_specific_fields(
+        &Blob::default(),
+        &[GTFArgs::BlobId, GTFArgs::BlobWitnessIndex],
+    );
+}
+
+#[test]
+fn get_transaction_field_upload_fields() {
+    let mut upload = Upload::default();
+    *upload.bytecode_witness_index_mut() = 3;
+    *upload.subsection_index_mut() = 1;
+    *upload.subsections_number_mut() = 2;
+    upload.proof_set_mut().push(Bytes32::zeroed());
+    upload.proof_set_mut().push(Bytes32::zeroed());
+
+    assert_eq!(
+        get_transaction_field(&upload, GTFArgs::UploadRoot, 0),
+        Ok(upload.bytecode_root_offset() as Word)
+    );
+    assert_eq!(
+        get_transaction_field(&upload, GTFArgs::UploadWitnessIndex, 0),
+        Ok(3)
+    );
+    assert_eq!(
+        get_transaction_field(&upload, GTFArgs::UploadSubsectionIndex, 0),
+        Ok(1)
+    );
+    assert_eq!(
+        get_transaction_field(&upload, GTFArgs::UploadSubsectionsNumber, 0),
+        Ok(2)
+    );
+    assert_eq!(
+        get_transaction_field(&upload, GTFArgs::UploadProofSetCount, 0),
+        Ok(2)
+    );
+    assert_eq!(
+        get_transaction_field(&upload, GTFArgs::UploadProofSetAtIndex, 1),
+        Ok((upload.proof_set_offset() + Bytes32::LEN) as Word)
+    );
+    assert_eq!(
+        get_transaction_field(&upload, GTFArgs::UploadProofSetAtIndex, 2),
+        Err(PanicReason::InvalidMetadataIdentifier.into())
+    );
+}
+
+#[test]
+fn get_transaction_field_blob_fields() {
+    let mut blob = Blob::default();
+    *blob.bytecode_witness_index_mut() = 5;
+
+    assert_eq!(
+        get_transaction_field(&blob, GTFArgs::BlobId, 0),
+        Ok(blob.blob_id_offset() as Word)
+    );
+    assert_eq!(
+        get_transaction_field(&blob, GTFArgs::BlobWitnessIndex, 0),
+        Ok(5)
+    );
+}
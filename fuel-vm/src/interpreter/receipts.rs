@@ -5,12 +5,8 @@ use core::{
 };
 use fuel_asm::PanicReason;
 
-use fuel_merkle::binary::root_calculator::MerkleRootCalculator as MerkleTree;
 use fuel_tx::Receipt;
-use fuel_types::{
-    canonical::Serialize,
-    Bytes32,
-};
+use fuel_types::Bytes32;
 
 use crate::{
     error::SimpleResult,
@@ -20,19 +16,22 @@ use crate::{
     },
 };
 
-/// Receipts and the associated Merkle tree
+/// Receipts generated by the transaction execution
 #[derive(Debug, Default, Clone)]
 pub struct ReceiptsCtx {
     receipts: Vec<Receipt>,
-    receipts_tree: MerkleTree,
 }
 
 impl ReceiptsCtx {
     /// The maximum number of receipts that can be stored in a single context.
     /// https://github.com/FuelLabs/fuel-specs/blob/master/src/fuel-vm/instruction-set.md#Receipts
     pub const MAX_RECEIPTS: usize = u16::MAX as usize;
+    /// The capacity kept by [`Self::reset`] regardless of the capacity hint. The
+    /// larger allocations are released, so a single log-heavy transaction doesn't
+    /// pin its memory for the lifetime of the interpreter.
+    pub const RETAINED_CAPACITY: usize = 1024;
 
-    /// Add a new receipt, updating the Merkle tree as well.
+    /// Add a new receipt.
     /// Returns a panic if the context is full.
     pub fn push(&mut self, receipt: Receipt) -> SimpleResult<()> {
         if self.receipts.len() == Self::MAX_RECEIPTS {
@@ -52,17 +51,40 @@ impl ReceiptsCtx {
             return Err(PanicReason::TooManyReceipts.into())
         }
 
-        self.receipts_tree.push(receipt.to_bytes().as_slice());
         self.receipts.push(receipt);
         Ok(())
     }
 
     /// Reset the context to an empty state
     pub fn clear(&mut self) {
-        self.receipts_tree = MerkleTree::new();
         self.receipts.clear();
     }
 
+    /// Resets the context for the next transaction, keeping the allocation and
+    /// reserving space for at least `capacity_hint` receipts.
+    ///
+    /// The allocation is shrunk only if it exceeds both the `capacity_hint` and
+    /// [`Self::RETAINED_CAPACITY`].
+    pub fn reset(&mut self, capacity_hint: usize) {
+        self.receipts.clear();
+        let capacity_hint = capacity_hint.min(Self::MAX_RECEIPTS);
+        let retained = capacity_hint.max(Self::RETAINED_CAPACITY);
+        if self.receipts.capacity() > retained {
+            self.receipts.shrink_to(retained);
+        }
+        self.receipts.reserve(capacity_hint);
+    }
+
+    /// The number of receipts the context can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.receipts.capacity()
+    }
+
+    /// Drops the receipts after the first `len` receipts.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.receipts.truncate(len);
+    }
+
     /// Return how many receipts are in this context
     pub fn len(&self) -> usize {
         self.receipts.len()
@@ -73,24 +95,15 @@ impl ReceiptsCtx {
         self.receipts.len() == 0
     }
 
-    /// Return current Merkle root of the receipts
+    /// Return current Merkle root of the receipts, see [`Receipt::compute_root`].
     pub fn root(&self) -> Bytes32 {
-        self.receipts_tree.clone().root().into()
+        Receipt::compute_root(&self.receipts)
     }
 
     /// Get a mutable lock on this context
     pub fn lock(&mut self) -> ReceiptsCtxMut {
         ReceiptsCtxMut::new(self)
     }
-
-    /// Recalculates the Merkle root of the receipts from scratch. This should
-    /// only be used when the list of receipts has been mutated externally.
-    fn recalculate_root(&mut self) {
-        self.receipts_tree = MerkleTree::new();
-        for receipt in &self.receipts {
-            self.receipts_tree.push(receipt.to_bytes().as_slice())
-        }
-    }
 }
 
 impl Index<usize> for ReceiptsCtx {
@@ -132,13 +145,10 @@ impl From<ReceiptsCtx> for Vec<Receipt> {
     }
 }
 
-/// When modifying the list of receipts directly, it is imperative that the
-/// receipts tree remain in sync with the modified list. Therefore, we lock
-/// mutable access to the list of receipts behind an opaque context with
-/// explicit getters and automatic root calculation. As long as the mutable
-/// context is in scope, access to the original context is forbidden due to
-/// borrowing semantics. When the mutable context is dropped, we recalculate the
-/// root and return access to the original context.
+/// Mutable access to the list of receipts behind an opaque context with explicit
+/// getters. As long as the mutable context is in scope, access to the original
+/// context is forbidden due to borrowing semantics. The root is always computed
+/// from the current list, so it stays in sync with the modified receipts.
 pub struct ReceiptsCtxMut<'a> {
     receipts_ctx: &'a mut ReceiptsCtx,
 }
@@ -153,13 +163,6 @@ impl<'a> ReceiptsCtxMut<'a> {
     }
 }
 
-impl Drop for ReceiptsCtxMut<'_> {
-    fn drop(&mut self) {
-        // The receipts may have been modified; recalculate the root
-        self.receipts_ctx.recalculate_root()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -223,4 +226,33 @@ mod tests {
         let expected_root = ephemeral_merkle_root(leaves);
         assert_eq!(root, expected_root)
     }
+
+    #[test]
+    fn reset_reserves_capacity_hint_and_clears_receipts() {
+        let mut ctx = ReceiptsCtx::default();
+        ctx.push(create_receipt()).expect("context not full");
+
+        ctx.reset(100);
+
+        assert!(ctx.is_empty());
+        assert!(ctx.capacity() >= 100);
+    }
+
+    #[test]
+    fn reset_shrinks_only_above_retained_capacity() {
+        let mut ctx = ReceiptsCtx::default();
+        ctx.reset(ReceiptsCtx::RETAINED_CAPACITY * 4);
+        let large = ctx.capacity();
+
+        ctx.reset(ReceiptsCtx::RETAINED_CAPACITY * 4);
+        assert_eq!(ctx.capacity(), large);
+
+        ctx.reset(0);
+        assert!(ctx.capacity() >= ReceiptsCtx::RETAINED_CAPACITY);
+        assert!(ctx.capacity() < large);
+
+        let retained = ctx.capacity();
+        ctx.reset(0);
+        assert_eq!(ctx.capacity(), retained);
+    }
 }
@@ -0,0 +1,320 @@
+//! Journaling of the writes made by the call frames, allowing to revert a single
+//! frame.
+
+use alloc::vec::Vec;
+use core::iter;
+
+use ethnum::U256;
+use fuel_storage::StorageMutate;
+use fuel_tx::Output;
+use fuel_types::{
+    AssetId,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use crate::{
+    error::{
+        IoResult,
+        RuntimeError,
+        StorageErrorContext,
+        StorageOp,
+    },
+    interpreter::{
+        internal::update_memory_output,
+        ExecutableTransaction,
+        Interpreter,
+        Memory,
+    },
+    prelude::{
+        Bug,
+        BugVariant,
+    },
+    storage::{
+        ContractsAssets,
+        ContractsAssetsStorage,
+        ContractsState,
+        ContractsStateData,
+        InterpreterStorage,
+    },
+};
+
+/// The values replaced by the writes of the open call frames, enabled by the
+/// [`InterpreterParams::call_frame_reverts`](super::InterpreterParams::call_frame_reverts).
+///
+/// `CALL` opens a checkpoint before moving the forwarded coins. `RET` and `RETD`
+/// merge the entries of the returning frame into its caller, so they are reverted
+/// together with the caller. `RVRT` and the panics inside a frame restore the entries
+/// recorded since its checkpoint in the reverse order: the contract state written by
+/// `SWW`, `SWWQ` and `SCWQ`, the contract balances updated by `CALL`, `TR`, `TRO`,
+/// `SMO`, `MINT` and `BURN`, the free balance forwarded by the `CALL` from the script
+/// and the variable outputs set by `TRO`.
+///
+/// Nothing is recorded outside of the call frames, since the script can be reverted
+/// only together with the whole transaction.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StorageJournal {
+    enabled: bool,
+    entries: Vec<JournalEntry>,
+    checkpoints: Vec<Checkpoint>,
+}
+
+/// The state of the journal when a call frame was opened.
+#[derive(Debug, Clone, Copy)]
+struct Checkpoint {
+    /// The number of entries recorded before the call.
+    entries: usize,
+    /// The number of receipts kept when the frame reverts, up to the `Call` receipt.
+    receipts: usize,
+}
+
+/// A value replaced by a write within a call frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum JournalEntry {
+    /// The contract storage slot, or `None` if it was unset.
+    State {
+        contract: ContractId,
+        key: Bytes32,
+        value: Option<ContractsStateData>,
+    },
+    /// The contract balance, or `None` if it was unset.
+    Balance {
+        contract: ContractId,
+        asset_id: AssetId,
+        value: Option<Word>,
+    },
+    /// The `amount` subtracted from the free balance of the transaction.
+    FreeBalance { asset_id: AssetId, amount: Word },
+    /// The variable output at the `index`.
+    VariableOutput { index: usize, output: Output },
+}
+
+impl StorageJournal {
+    /// Resets the journal for a new transaction, recording the writes of the call
+    /// frames only if `enabled`.
+    pub fn reset(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.entries.clear();
+        self.checkpoints.clear();
+    }
+
+    /// The number of open checkpoints, matching the number of the call frames while
+    /// the journal is enabled.
+    pub fn depth(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Whether the writes are recorded, i.e. a checkpoint is open.
+    pub fn is_recording(&self) -> bool {
+        !self.checkpoints.is_empty()
+    }
+
+    /// Opens the checkpoint of a new call frame, if the journal is enabled. The
+    /// `receipts` are the receipts before the `Call` receipt of the frame.
+    pub fn open(&mut self, receipts: usize) {
+        if self.enabled {
+            self.checkpoints.push(Checkpoint {
+                entries: self.entries.len(),
+                // The `Call` receipt is kept when the frame reverts
+                receipts: receipts.saturating_add(1),
+            });
+        }
+    }
+
+    /// Closes the checkpoint of the returning call frame, keeping its entries for
+    /// the caller. The entries are dropped once no frame is open, since they can't
+    /// be reverted anymore.
+    pub fn merge(&mut self) {
+        if self.checkpoints.pop().is_some() && self.checkpoints.is_empty() {
+            self.entries.clear();
+        }
+    }
+
+    /// Closes the checkpoint of the reverting call frame, returning the number of
+    /// receipts to keep and the entries to restore, in the order they were recorded.
+    fn revert(&mut self) -> Option<(usize, Vec<JournalEntry>)> {
+        let checkpoint = self.checkpoints.pop()?;
+        let entries = self.entries.split_off(checkpoint.entries);
+        Some((checkpoint.receipts, entries))
+    }
+
+    /// Records the `entry`, if a checkpoint is open.
+    pub fn record(&mut self, entry: JournalEntry) {
+        if self.is_recording() {
+            self.entries.push(entry);
+        }
+    }
+
+    /// Records the balance of the `asset_id` of the `contract`, before it's written.
+    pub fn record_balance<S>(
+        &mut self,
+        storage: &S,
+        contract: &ContractId,
+        asset_id: &AssetId,
+    ) -> IoResult<(), S::Error>
+    where
+        S: ContractsAssetsStorage + ?Sized,
+    {
+        if !self.is_recording() {
+            return Ok(())
+        }
+        let value = storage
+            .contract_asset_id_balance(contract, asset_id)
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ContractBalanceRead)
+                        .contract(*contract)
+                        .key(Bytes32::new(**asset_id)),
+                )
+            })?;
+        self.entries.push(JournalEntry::Balance {
+            contract: *contract,
+            asset_id: *asset_id,
+            value,
+        });
+        Ok(())
+    }
+
+    /// Records the `num_slots` consecutive storage slots of the `contract`, starting
+    /// at the `start_key`, before they are written.
+    pub fn record_state<S>(
+        &mut self,
+        storage: &S,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        num_slots: usize,
+    ) -> IoResult<(), S::DataError>
+    where
+        S: InterpreterStorage + ?Sized,
+    {
+        if !self.is_recording() {
+            return Ok(())
+        }
+        let values = storage
+            .contract_state_range(contract, start_key, num_slots)
+            .map_err(|e| {
+                RuntimeError::storage(
+                    e,
+                    StorageErrorContext::new(StorageOp::ContractStateRead)
+                        .contract(*contract)
+                        .key(*start_key),
+                )
+            })?;
+        // The range stops at the last key, as the writes do
+        let keys = iter::successors(Some(U256::from_be_bytes(**start_key)), |key| {
+            key.checked_add(U256::ONE)
+        });
+        self.entries
+            .extend(keys.zip(values).map(|(key, value)| JournalEntry::State {
+                contract: *contract,
+                key: Bytes32::new(key.to_be_bytes()),
+                value: value.map(|value| value.into_owned()),
+            }));
+        Ok(())
+    }
+}
+
+impl<M, S, Tx, Ecal> Interpreter<M, S, Tx, Ecal>
+where
+    M: Memory,
+    S: InterpreterStorage,
+    Tx: ExecutableTransaction,
+{
+    /// Whether `RVRT` or a panic reverts only the current call frame: the journal
+    /// has a checkpoint for every open frame.
+    pub(crate) fn reverts_call_frame(&self) -> bool {
+        !self.frames.is_empty() && self.storage_journal.depth() == self.frames.len()
+    }
+
+    /// Restores the values replaced by the current call frame and drops its
+    /// receipts, keeping the `Call` receipt.
+    pub(crate) fn undo_call_frame(&mut self) -> IoResult<(), S::DataError> {
+        let (receipts, entries) = self
+            .storage_journal
+            .revert()
+            .ok_or_else(|| Bug::new(BugVariant::CallFrameCheckpointMissing))?;
+
+        for entry in entries.into_iter().rev() {
+            self.undo(entry)?;
+        }
+        self.receipts.truncate(receipts);
+
+        Ok(())
+    }
+
+    fn undo(&mut self, entry: JournalEntry) -> IoResult<(), S::DataError> {
+        match entry {
+            JournalEntry::State {
+                contract,
+                key,
+                value,
+            } => {
+                let result = match value {
+                    Some(value) => self.storage.contract_state_insert(
+                        &contract,
+                        &key,
+                        value.as_ref(),
+                    ),
+                    None => StorageMutate::<ContractsState>::remove(
+                        &mut self.storage,
+                        &(&contract, &key).into(),
+                    ),
+                };
+                result.map_err(|e| {
+                    RuntimeError::storage(
+                        e,
+                        StorageErrorContext::new(StorageOp::ContractStateWrite)
+                            .contract(contract)
+                            .key(key),
+                    )
+                })?;
+            }
+            JournalEntry::Balance {
+                contract,
+                asset_id,
+                value,
+            } => {
+                let result = match value {
+                    Some(value) => self
+                        .storage
+                        .contract_asset_id_balance_insert(&contract, &asset_id, value),
+                    None => StorageMutate::<ContractsAssets>::remove(
+                        &mut self.storage,
+                        &(&contract, &asset_id).into(),
+                    ),
+                };
+                result.map_err(|e| {
+                    RuntimeError::storage(
+                        e,
+                        StorageErrorContext::new(StorageOp::ContractBalanceWrite)
+                            .contract(contract)
+                            .key(Bytes32::new(*asset_id)),
+                    )
+                })?;
+            }
+            JournalEntry::FreeBalance { asset_id, amount } => {
+                self.balances
+                    .checked_balance_add(self.memory.as_mut(), &asset_id, amount)
+                    .ok_or_else(|| Bug::new(BugVariant::CallFrameUndoMismatch))?;
+            }
+            JournalEntry::VariableOutput { index, output } => {
+                let tx_offset = self.tx_offset();
+                let target = self
+                    .tx
+                    .outputs_mut()
+                    .get_mut(index)
+                    .ok_or_else(|| Bug::new(BugVariant::CallFrameUndoMismatch))?;
+                *target = output;
+                update_memory_output(
+                    &mut self.tx,
+                    self.memory.as_mut(),
+                    tx_offset,
+                    index,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
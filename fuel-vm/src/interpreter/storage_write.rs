@@ -0,0 +1,43 @@
+//! Accounting of the bytes written to the storage within a transaction.
+
+use fuel_asm::PanicReason;
+
+/// The bytes written to the storage within a transaction, limited by the
+/// [`InterpreterParams::max_storage_write_bytes`](super::InterpreterParams::max_storage_write_bytes).
+///
+/// Every state write counts: the storage slots written by `SWW` and `SWWQ` or
+/// cleared by `SCWQ`, the contract balances updated by `CALL`, `TR`, `TRO`, `SMO`,
+/// `MINT` and `BURN`, and the data stored by the deployment, upload and blob
+/// transactions.
+///
+/// The counter is reset when the VM is initialized with a new transaction, so the
+/// writes of the reverted transactions don't count towards the following ones.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct StorageWriteCounter {
+    limit: Option<u64>,
+    written: u64,
+}
+
+impl StorageWriteCounter {
+    /// Resets the counter for a new transaction with the `limit`.
+    pub fn reset(&mut self, limit: Option<u64>) {
+        self.limit = limit;
+        self.written = 0;
+    }
+
+    /// The number of bytes written since the last reset.
+    pub const fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Accounts the `bytes` before they are written. The bytes aren't accounted if
+    /// they exceed the limit, since the write must not happen.
+    pub fn charge(&mut self, bytes: u64) -> Result<(), PanicReason> {
+        let written = self.written.saturating_add(bytes);
+        if self.limit.is_some_and(|limit| written > limit) {
+            return Err(PanicReason::StorageWriteLimitExceeded)
+        }
+        self.written = written;
+        Ok(())
+    }
+}
@@ -23,6 +23,10 @@ extern crate libm as _; // Not needed with stdlib
 #[cfg(test)]
 use criterion as _;
 
+#[macro_use]
+mod trace;
+
+#[cfg(feature = "vm-backtrace")]
 pub mod backtrace;
 pub mod call;
 pub mod checked_transaction;
@@ -108,6 +112,7 @@ pub mod prelude {
         MerkleRootStorage,
         StorageAsMut,
         StorageAsRef,
+        StorageBatchMutate,
         StorageInspect,
         StorageMutate,
     };
@@ -133,17 +138,25 @@ pub mod prelude {
     };
 
     pub use crate::{
-        backtrace::Backtrace,
         call::{
             Call,
             CallFrame,
+            CallGasReport,
+            CallParseError,
+            SavedRegister,
+        },
+        context::{
+            Context,
+            ContextKind,
         },
-        context::Context,
         error::{
             Bug,
             BugVariant,
             InterpreterError,
             RuntimeError,
+            StorageErrorContext,
+            StorageFailure,
+            StorageOp,
         },
         interpreter::{
             predicates,
@@ -152,17 +165,19 @@ pub mod prelude {
             Memory,
             MemoryInstance,
             MemoryRange,
+            MemoryStats,
         },
         pool::VmMemoryPool,
         predicate::RuntimePredicate,
         state::{
-            Debugger,
             ProgramState,
             StateTransition,
             StateTransitionRef,
         },
         storage::{
             predicate::PredicateStorage,
+            ConsensusParametersAt,
+            ConsensusParametersProvider,
             InterpreterStorage,
         },
         transactor::Transactor,
@@ -171,15 +186,31 @@ pub mod prelude {
     pub use crate::state::{
         Breakpoint,
         DebugEval,
+        Watch,
+        Watchpoint,
+    };
+
+    #[cfg(feature = "vm-backtrace")]
+    pub use crate::backtrace::{
+        Backtrace,
+        CompactBacktrace,
     };
 
+    #[cfg(feature = "debugger")]
+    pub use crate::state::Debugger;
+
     #[cfg(any(test, feature = "test-helpers"))]
     pub use crate::{
         checked_transaction::{
             builder::TransactionBuilderExt,
             IntoChecked,
         },
-        memory_client::MemoryClient,
+        memory_client::{
+            CreatedContract,
+            DeployError,
+            MemoryClient,
+        },
+        state::VerifyMismatch,
         storage::MemoryStorage,
         util::test_helpers::TestBuilder,
     };
@@ -1,35 +1,119 @@
 //! In-memory client implementation
 
+#[cfg(feature = "vm-backtrace")]
+use crate::backtrace::{
+    Backtrace,
+    CompactBacktrace,
+};
 use crate::{
-    backtrace::Backtrace,
-    checked_transaction::Checked,
-    error::InterpreterError,
+    checked_transaction::{
+        CheckError,
+        Checked,
+        IntoChecked,
+    },
+    error::{
+        InterpreterError,
+        StorageErrorContext,
+        StorageFailure,
+        StorageOp,
+    },
     interpreter::{
         EcalHandler,
         InterpreterParams,
         Memory,
+        MemoryInstance,
         NotSupportedEcal,
     },
-    state::StateTransitionRef,
+    state::{
+        StateTransition,
+        StateTransitionRef,
+    },
     storage::{
+        ContractsStateData,
+        InterpreterStorage,
         MemoryStorage,
         MemoryStorageError,
+        ReadOnlyStorage,
+        ReadOnlyStorageError,
     },
     transactor::Transactor,
 };
+use alloc::{
+    borrow::Cow,
+    vec::Vec,
+};
 use fuel_tx::{
     Blob,
+    ConsensusParameters,
+    Contract,
     Create,
     FeeParameters,
+    Finalizable,
     GasCosts,
     Receipt,
     Script,
+    StorageSlot,
+    TransactionBuilder,
+    TxId,
     Upgrade,
     Upload,
 };
+use fuel_types::{
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Salt,
+};
 
-#[cfg(any(test, feature = "test-helpers"))]
-use crate::interpreter::MemoryInstance;
+/// The contract deployed by [`MemoryClient::deploy_contract`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreatedContract {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The root of the initial storage slots of the contract.
+    pub state_root: Bytes32,
+    /// The root of the contract bytecode.
+    pub code_root: Bytes32,
+}
+
+impl CreatedContract {
+    /// Computes the roots and the id of the contract created from the given
+    /// bytecode, salt and initial storage slots.
+    pub fn new(code: &[u8], salt: &Salt, storage_slots: &[StorageSlot]) -> Self {
+        let contract = Contract::from(code);
+        let code_root = contract.root();
+        let state_root = Contract::initial_state_root(storage_slots.iter());
+        let contract_id = contract.id(salt, &code_root, &state_root);
+
+        Self {
+            contract_id,
+            state_root,
+            code_root,
+        }
+    }
+}
+
+/// The error returned by [`MemoryClient::deploy_contract`].
+#[derive(Debug)]
+pub enum DeployError {
+    /// The `Create` transaction is invalid under the consensus parameters of the
+    /// client.
+    Check(CheckError),
+    /// The execution of the `Create` transaction failed.
+    Interpreter(InterpreterError<MemoryStorageError>),
+}
+
+impl From<CheckError> for DeployError {
+    fn from(error: CheckError) -> Self {
+        Self::Check(error)
+    }
+}
+
+impl From<InterpreterError<MemoryStorageError>> for DeployError {
+    fn from(error: InterpreterError<MemoryStorageError>) -> Self {
+        Self::Interpreter(error)
+    }
+}
 
 #[derive(Debug)]
 /// Client implementation with in-memory storage backend.
@@ -86,10 +170,19 @@ where
 {
     /// If a transaction was executed and produced a VM panic, returns the
     /// backtrace; return `None` otherwise.
+    #[cfg(feature = "vm-backtrace")]
     pub fn backtrace(&self) -> Option<Backtrace> {
         self.transactor.backtrace()
     }
 
+    /// If a transaction was executed and produced a VM panic, returns the compact
+    /// backtrace if requested with [`Transactor::with_compact_backtraces`]; return
+    /// `None` otherwise.
+    #[cfg(feature = "vm-backtrace")]
+    pub fn compact_backtrace(&self) -> Option<CompactBacktrace> {
+        self.transactor.compact_backtrace()
+    }
+
     /// If a transaction was successfully executed, returns the produced
     /// receipts; return `None` otherwise.
     pub fn receipts(&self) -> Option<&[Receipt]> {
@@ -101,6 +194,20 @@ where
         self.transactor.state_transition()
     }
 
+    /// State transitions of the recently executed transactions, from the oldest
+    /// to the newest. See [`Transactor::with_history`].
+    pub fn history(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&TxId, &StateTransition<Script>)> + '_ {
+        self.transactor.history()
+    }
+
+    /// Finds the state transition of the most recent execution of the transaction
+    /// with the given id, if it is still kept in the history.
+    pub fn find_by_txid(&self, id: &TxId) -> Option<&StateTransition<Script>> {
+        self.transactor.find_by_txid(id)
+    }
+
     /// Deploys a `Create` transaction.
     pub fn deploy(
         &mut self,
@@ -109,6 +216,85 @@ where
         self.transactor.deploy(tx)
     }
 
+    /// Deploys the contract with the given bytecode, salt and initial storage
+    /// slots using the consensus parameters of the client.
+    pub fn deploy_contract(
+        &mut self,
+        code: Vec<u8>,
+        salt: Salt,
+        storage_slots: Vec<StorageSlot>,
+    ) -> Result<CreatedContract, DeployError> {
+        let created = CreatedContract::new(&code, &salt, &storage_slots);
+        let consensus_params = self.consensus_params();
+        let block_height = self.as_ref().block_height().map_err(|e| {
+            InterpreterError::Storage(StorageFailure::with_context(
+                e,
+                StorageErrorContext::new(StorageOp::BlockInfoRead),
+            ))
+        })?;
+
+        let tx = TransactionBuilder::create(code.into(), salt, storage_slots)
+            .with_params(consensus_params.clone())
+            .max_fee_limit(u32::MAX as u64)
+            .add_fee_input()
+            .add_contract_created()
+            .finalize()
+            .into_checked(block_height, &consensus_params)?;
+        self.deploy(tx)?;
+
+        Ok(created)
+    }
+
+    /// Returns `true` if the contract is deployed.
+    pub fn contract_exists(
+        &self,
+        contract: &ContractId,
+    ) -> Result<bool, MemoryStorageError> {
+        self.transactor.interpreter().contract_exists(contract)
+    }
+
+    /// Returns the code of the contract, or `None` if the contract isn't deployed.
+    pub fn contract_code(
+        &self,
+        contract: &ContractId,
+    ) -> Result<Option<Cow<'_, Contract>>, MemoryStorageError> {
+        self.transactor.interpreter().contract_code(contract)
+    }
+
+    /// Returns the value of the storage slot `key` of the contract, or `None` if the
+    /// slot isn't set.
+    pub fn contract_state_value(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> Result<Option<Cow<'_, ContractsStateData>>, MemoryStorageError> {
+        self.transactor
+            .interpreter()
+            .contract_state_value(contract, key)
+    }
+
+    /// The consensus parameters matching the parameters of the interpreter.
+    fn consensus_params(&self) -> ConsensusParameters {
+        let interpreter = self.transactor.interpreter();
+        let mut params = ConsensusParameters::standard_with_id(interpreter.chain_id());
+        params
+            .set_tx_params(params.tx_params().with_max_inputs(interpreter.max_inputs()));
+        params.set_predicate_params(
+            params
+                .predicate_params()
+                .with_max_message_data_length(interpreter.max_message_data_length()),
+        );
+        params.set_contract_params(
+            params
+                .contract_params()
+                .with_contract_max_size(interpreter.contract_max_size()),
+        );
+        params.set_fee_params(*interpreter.fee_params());
+        params.set_gas_costs(interpreter.gas_costs().clone());
+        params.set_base_asset_id(*interpreter.base_asset_id());
+        params
+    }
+
     /// Executes `Upgrade` transaction.
     pub fn upgrade(
         &mut self,
@@ -140,18 +326,25 @@ where
         // In this case, we should expect `Infallible` error.
         if let Ok(state) = self.transactor.result() {
             if state.should_revert() {
-                self.transactor.as_mut().revert();
+                self.revert();
             } else {
                 self.transactor.as_mut().commit();
             }
         } else {
             // if vm failed to execute, revert storage just in case
-            self.transactor.as_mut().revert();
+            self.revert();
         }
 
         self.transactor.receipts().unwrap_or_default()
     }
 
+    /// Reverts the uncommitted changes of the storage. The code cache never serves
+    /// the reverted contracts, but it is cleared as well to release their bytecode.
+    fn revert(&mut self) {
+        self.transactor.as_mut().revert();
+        self.transactor.code_cache_mut().clear();
+    }
+
     /// Persist the changes caused by [`Self::transact`].
     pub fn persist(&mut self) {
         self.as_mut().persist();
@@ -177,6 +370,52 @@ where
     pub fn set_gas_price(&mut self, gas_price: u64) {
         self.transactor.set_gas_price(gas_price);
     }
+
+    #[cfg(feature = "test-helpers")]
+    /// Persists the changes of the current block and starts the block at the
+    /// `new_height` produced by the `coinbase`.
+    ///
+    /// The deployed contracts and the uploaded blobs, which aren't committed by
+    /// themselves, are persisted as well.
+    pub fn advance_block(&mut self, new_height: BlockHeight, coinbase: ContractId) {
+        let storage = self.as_mut();
+        storage.commit();
+        storage.persist();
+        storage.set_block_height(new_height);
+        storage.set_coinbase(coinbase);
+    }
+}
+
+impl<M, Ecal: EcalHandler + Default> MemoryClient<M, Ecal> {
+    /// Executes the script against the storage of the client without changing it,
+    /// e.g. to simulate the script. The script panics with the
+    /// [`PanicReason::WriteInReadOnlyMode`](fuel_asm::PanicReason::WriteInReadOnlyMode)
+    /// if it writes to the storage.
+    ///
+    /// The script is executed by a separate interpreter with the parameters of the
+    /// client, so the state of the client, e.g. [`Self::receipts`], stays the same.
+    pub fn transact_read_only(
+        &self,
+        tx: Checked<Script>,
+    ) -> Result<
+        StateTransition<Script>,
+        InterpreterError<ReadOnlyStorageError<MemoryStorageError>>,
+    > {
+        let storage = ReadOnlyStorage::new(self.as_ref());
+        let interpreter_params =
+            self.transactor.interpreter().interpreter_params().clone();
+        let mut transactor = Transactor::<_, _, Script, Ecal>::new(
+            MemoryInstance::new(),
+            storage,
+            interpreter_params,
+        );
+        transactor.transact(tx);
+
+        transactor
+            .result()
+            .map(StateTransition::from)
+            .map_err(Clone::clone)
+    }
 }
 
 impl<M, Ecal: EcalHandler> From<MemoryClient<M, Ecal>>
@@ -2,9 +2,17 @@
 
 use crate::interpreter::Memory;
 
-#[cfg(any(test, feature = "test-helpers"))]
+#[cfg(any(test, feature = "test-helpers", feature = "std"))]
 use crate::interpreter::MemoryInstance;
 
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
 /// Trait for a VM memory pool.
 pub trait VmMemoryPool: Sync {
     /// The memory instance returned by this pool.
@@ -27,3 +35,141 @@ impl VmMemoryPool for DummyPool {
         core::future::ready(MemoryInstance::new())
     }
 }
+
+/// Trimming policy of the [`MemoryPool`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VmMemoryPoolConfig {
+    /// Instances allocating more bytes than this are trimmed when returned to the pool.
+    pub max_retained_bytes: usize,
+    /// Number of bytes the trimmed instances keep allocated.
+    pub trim_to_bytes: usize,
+}
+
+#[cfg(feature = "std")]
+impl Default for VmMemoryPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_retained_bytes: 16 * 1024 * 1024,
+            trim_to_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Statistics of the instances retained by the [`MemoryPool`].
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VmMemoryPoolStats {
+    /// Number of instances waiting for reuse.
+    pub instances: usize,
+    /// Number of bytes allocated by the instances waiting for reuse.
+    pub retained_bytes: usize,
+}
+
+/// Pool of memory instances, trimming the oversized ones when they are returned.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone)]
+pub struct MemoryPool {
+    config: VmMemoryPoolConfig,
+    instances: Arc<Mutex<Vec<MemoryInstance>>>,
+}
+
+#[cfg(feature = "std")]
+impl MemoryPool {
+    /// Creates an empty pool with the given trimming policy.
+    pub fn new(config: VmMemoryPoolConfig) -> Self {
+        Self {
+            config,
+            instances: Default::default(),
+        }
+    }
+
+    /// The trimming policy of the pool.
+    pub fn config(&self) -> &VmMemoryPoolConfig {
+        &self.config
+    }
+
+    /// Takes a memory instance from the pool, or allocates a new one if it is empty.
+    /// The instance is returned to the pool when dropped.
+    pub fn take(&self) -> MemoryFromPool {
+        let memory = self.take_raw();
+        MemoryFromPool {
+            pool: self.clone(),
+            memory,
+        }
+    }
+
+    /// Takes a memory instance from the pool without tying it to the pool.
+    /// Use [`Self::recycle_raw`] to return it.
+    pub fn take_raw(&self) -> MemoryInstance {
+        self.lock().pop().unwrap_or_default()
+    }
+
+    /// Resets the memory instance and returns it to the pool, trimming it
+    /// if it retains more than [`VmMemoryPoolConfig::max_retained_bytes`].
+    pub fn recycle_raw(&self, mut memory: MemoryInstance) {
+        if memory.allocated_bytes() > self.config.max_retained_bytes {
+            memory.reset_and_trim(self.config.trim_to_bytes);
+        } else {
+            memory.reset();
+        }
+        self.lock().push(memory);
+    }
+
+    /// Statistics of the instances currently retained by the pool.
+    pub fn stats(&self) -> VmMemoryPoolStats {
+        let instances = self.lock();
+        VmMemoryPoolStats {
+            instances: instances.len(),
+            retained_bytes: instances
+                .iter()
+                .map(MemoryInstance::allocated_bytes)
+                .fold(0, usize::saturating_add),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<MemoryInstance>> {
+        // The instances are pushed and popped whole, so a poisoned lock is consistent.
+        self.instances
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl VmMemoryPool for MemoryPool {
+    type Memory = MemoryFromPool;
+
+    fn get_new(&self) -> impl core::future::Future<Output = Self::Memory> + Send {
+        core::future::ready(self.take())
+    }
+}
+
+/// Memory instance taken from a [`MemoryPool`], returned to it when dropped.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct MemoryFromPool {
+    pool: MemoryPool,
+    memory: MemoryInstance,
+}
+
+#[cfg(feature = "std")]
+impl Drop for MemoryFromPool {
+    fn drop(&mut self) {
+        self.pool.recycle_raw(core::mem::take(&mut self.memory));
+    }
+}
+
+#[cfg(feature = "std")]
+impl AsRef<MemoryInstance> for MemoryFromPool {
+    fn as_ref(&self) -> &MemoryInstance {
+        &self.memory
+    }
+}
+
+#[cfg(feature = "std")]
+impl AsMut<MemoryInstance> for MemoryFromPool {
+    fn as_mut(&mut self) -> &mut MemoryInstance {
+        &mut self.memory
+    }
+}
@@ -1,5 +1,10 @@
 //! Predicate representations with required data to be executed during VM runtime
 
+use alloc::vec::Vec;
+use fuel_asm::{
+    Instruction,
+    Opcode,
+};
 use fuel_tx::field;
 
 use crate::interpreter::MemoryRange;
@@ -48,6 +53,117 @@ impl RuntimePredicate {
     }
 }
 
+/// The context the bytecode is statically checked for by [`static_check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckContext {
+    /// The bytecode is executed as a script.
+    Script,
+    /// The bytecode is executed as a predicate, so only the predicate-allowed opcodes
+    /// can be used.
+    Predicate,
+}
+
+/// An issue with the static structure of the bytecode found by [`static_check`].
+///
+/// Each issue carries the byte offset of the instruction it was found at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StaticIssue {
+    /// The length of the bytecode isn't a multiple of the instruction size, so the
+    /// last instruction is truncated.
+    #[display(fmt = "Truncated instruction at offset {offset}")]
+    TruncatedInstruction {
+        /// The offset of the truncated instruction
+        offset: usize,
+    },
+    /// The instruction can't be decoded.
+    #[display(fmt = "Invalid instruction at offset {offset}")]
+    InvalidInstruction {
+        /// The offset of the instruction
+        offset: usize,
+    },
+    /// The opcode isn't allowed in the predicate context.
+    #[display(fmt = "Opcode {opcode:?} at offset {offset} isn't allowed in predicates")]
+    OpcodeNotAllowed {
+        /// The offset of the instruction
+        offset: usize,
+        /// The opcode of the instruction
+        opcode: Opcode,
+    },
+    /// The absolute immediate jump targets an address beyond the bytecode.
+    #[display(fmt = "Jump at offset {offset} targets {target}, beyond the bytecode")]
+    JumpOutOfBounds {
+        /// The offset of the instruction
+        offset: usize,
+        /// The byte offset of the jump target
+        target: u64,
+    },
+}
+
+impl StaticIssue {
+    /// The byte offset of the instruction the issue was found at.
+    pub const fn offset(&self) -> usize {
+        match self {
+            Self::TruncatedInstruction { offset }
+            | Self::InvalidInstruction { offset }
+            | Self::OpcodeNotAllowed { offset, .. }
+            | Self::JumpOutOfBounds { offset, .. } => *offset,
+        }
+    }
+}
+
+/// Checks the static structure of the bytecode without executing it.
+///
+/// Every instruction is decoded and, in the predicate context, checked to be allowed
+/// in predicates. Absolute immediate jumps (`JI`, `JNZI` and `JNEI`) are checked to
+/// target an instruction within the bytecode. Jumps through registers and relative
+/// jumps aren't checked, since their targets can't be known statically.
+///
+/// The check doesn't distinguish the code from the data embedded in the bytecode, so
+/// the embedded data is reported as invalid instructions too.
+pub fn static_check(code: &[u8], context: CheckContext) -> Vec<StaticIssue> {
+    let mut issues = Vec::new();
+    let code_len = code.len() as u64;
+
+    let instructions = fuel_asm::from_bytes_with_offsets(code.iter().copied());
+    for (offset, instruction) in instructions {
+        let instruction = match instruction {
+            Ok(instruction) => instruction,
+            Err(_) => {
+                issues.push(StaticIssue::InvalidInstruction { offset });
+                continue
+            }
+        };
+
+        let opcode = instruction.opcode();
+        if context == CheckContext::Predicate && !opcode.is_predicate_allowed() {
+            issues.push(StaticIssue::OpcodeNotAllowed { offset, opcode });
+        }
+
+        let target = match instruction {
+            Instruction::JI(ji) => Some(u64::from(u32::from(ji.unpack()))),
+            Instruction::JNZI(jnzi) => Some(u64::from(jnzi.unpack().1)),
+            Instruction::JNEI(jnei) => Some(u64::from(jnei.unpack().2)),
+            _ => None,
+        };
+        if let Some(target) = target {
+            let target = target.saturating_mul(Instruction::SIZE as u64);
+            if target >= code_len {
+                issues.push(StaticIssue::JumpOutOfBounds { offset, target });
+            }
+        }
+    }
+
+    let truncated = code.chunks_exact(Instruction::SIZE).remainder();
+    if !truncated.is_empty() {
+        issues.push(StaticIssue::TruncatedInstruction {
+            offset: code.len().saturating_sub(truncated.len()),
+        });
+    }
+
+    issues
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[cfg(test)]
 mod tests {
@@ -402,37 +518,44 @@ mod tests {
                     op::ret(0x01),
                 ],
                 INCORRECT_GAS,
-                Err(PredicateVerificationFailed::GasMismatch),
+                Err(PredicateVerificationFailed::GasMismatch {
+                    index: 0,
+                    gas_used: 15,
+                    available_gas: 1234,
+                }),
             ),
             (
                 // Returning an invalid value
                 vec![op::ret(0x0)],
                 CORRECT_GAS,
-                Err(PredicateVerificationFailed::Panic(
-                    PanicReason::PredicateReturnedNonOne,
-                )),
+                Err(PredicateVerificationFailed::Panic {
+                    index: 0,
+                    reason: PanicReason::PredicateReturnedNonOne,
+                }),
             ),
             (
                 // Using a contract instruction
                 vec![op::time(0x20, 0x1), op::ret(0x1)],
                 CORRECT_GAS,
-                Err(PredicateVerificationFailed::PanicInstruction(
-                    PanicInstruction::error(
+                Err(PredicateVerificationFailed::PanicInstruction {
+                    index: 0,
+                    instruction: PanicInstruction::error(
                         PanicReason::ContractInstructionNotAllowed,
                         op::time(0x20, 0x1).into(),
                     ),
-                )),
+                }),
             ),
             (
                 // Using a contract instruction
                 vec![op::ldc(ONE, ONE, ONE, 0)],
                 CORRECT_GAS,
-                Err(PredicateVerificationFailed::PanicInstruction(
-                    PanicInstruction::error(
+                Err(PredicateVerificationFailed::PanicInstruction {
+                    index: 0,
+                    instruction: PanicInstruction::error(
                         PanicReason::ContractInstructionNotAllowed,
                         op::ldc(ONE, ONE, ONE, 0).into(),
                     ),
-                )),
+                }),
             ),
             (
                 // Use `LDC` with mode `1` to load the blob into the predicate.
@@ -457,9 +580,10 @@ mod tests {
                     op::jmp(0x12),
                 ],
                 CORRECT_GAS,
-                Err(PredicateVerificationFailed::Panic(
-                    PanicReason::PredicateReturnedNonOne,
-                )),
+                Err(PredicateVerificationFailed::Panic {
+                    index: 0,
+                    reason: PanicReason::PredicateReturnedNonOne,
+                }),
             ),
             (
                 // Use `LDC` with mode `2` to load the part of the predicate from the
@@ -495,9 +619,10 @@ mod tests {
                     op::jmp(0x12),
                 ],
                 CORRECT_GAS,
-                Err(PredicateVerificationFailed::Panic(
-                    PanicReason::PredicateReturnedNonOne,
-                )),
+                Err(PredicateVerificationFailed::Panic {
+                    index: 0,
+                    reason: PanicReason::PredicateReturnedNonOne,
+                }),
             ),
         ];
 
@@ -9,7 +9,10 @@ use alloc::{
     },
     vec::Vec,
 };
-use core::fmt;
+use core::{
+    fmt,
+    num::NonZeroU64,
+};
 use hashbrown::HashMap;
 
 use dyn_clone::DynClone;
@@ -220,7 +223,14 @@ impl Profiler {
 
     /// Add gas to the current coverage location.
     pub fn add_gas(&mut self, location: InstructionLocation, gas_use: u64) {
-        self.data_mut().gas_mut().add(location, gas_use);
+        self.data_mut().gas_mut().add_sampled(location, gas_use);
+    }
+
+    /// Record only every `interval`th gas charge. See
+    /// [`GasProfilingData::set_sampling_interval`].
+    #[cfg(feature = "profile-gas")]
+    pub fn set_gas_sampling_interval(&mut self, interval: NonZeroU64) {
+        self.data_mut().gas_mut().set_sampling_interval(interval);
     }
 }
 
@@ -308,13 +318,54 @@ impl fmt::Display for CoverageProfilingData {
 }
 
 /// Used gas per memory address
-#[derive(Debug, Clone, Default)]
+///
+/// With a sampling interval `N` above one, only every `N`th gas charge is recorded
+/// and its gas is multiplied by `N`, so the totals estimate the full profile at a
+/// fraction of the cost. The sampling is counter-based, so repeated runs agree.
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GasProfilingData {
     gas_use: PerLocation<u64>,
+    #[cfg_attr(feature = "serde", serde(default = "unsampled"))]
+    sampling_interval: NonZeroU64,
+    /// Gas charges to skip before the next recorded one.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    skip: u64,
+}
+
+#[cfg(feature = "serde")]
+fn unsampled() -> NonZeroU64 {
+    NonZeroU64::MIN
+}
+
+impl Default for GasProfilingData {
+    fn default() -> Self {
+        Self::with_sampling_interval(NonZeroU64::MIN)
+    }
 }
 
 impl<'a> GasProfilingData {
+    /// Empty profiling data recording every `interval`th gas charge
+    pub fn with_sampling_interval(interval: NonZeroU64) -> Self {
+        Self {
+            gas_use: Default::default(),
+            sampling_interval: interval,
+            skip: 0,
+        }
+    }
+
+    /// Only every `sampling_interval`th gas charge is recorded.
+    /// The interval of one means that the data is exact.
+    pub fn sampling_interval(&self) -> NonZeroU64 {
+        self.sampling_interval
+    }
+
+    /// Sets the sampling interval and restarts the sampling from the next gas charge
+    pub fn set_sampling_interval(&mut self, interval: NonZeroU64) {
+        self.sampling_interval = interval;
+        self.skip = 0;
+    }
+
     /// Get total gas used at location
     pub fn get(&self, location: &InstructionLocation) -> u64 {
         self.gas_use.get(location).copied().unwrap_or(0)
@@ -328,6 +379,19 @@ impl<'a> GasProfilingData {
         *gas_use = gas_use.saturating_add(amount);
     }
 
+    /// Increase gas used at location if the gas charge is sampled, scaling the amount
+    /// by the sampling interval
+    pub fn add_sampled(&mut self, location: InstructionLocation, amount: u64) {
+        if self.skip > 0 {
+            self.skip = self.skip.saturating_sub(1);
+            return;
+        }
+
+        let interval = self.sampling_interval.get();
+        self.skip = interval.saturating_sub(1);
+        self.add(location, amount.saturating_mul(interval));
+    }
+
     /// Iterate through locations and gas values
     pub fn iter(&'a self) -> PerLocationIter<'a, u64> {
         PerLocationIter(self.gas_use.iter())
@@ -347,6 +411,13 @@ impl<'a> GasProfilingData {
 
 impl fmt::Display for GasProfilingData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.sampling_interval > NonZeroU64::MIN {
+            writeln!(
+                f,
+                "Sampled every {} gas charges, values are estimates",
+                self.sampling_interval
+            )?;
+        }
         let mut items: Vec<(_, _)> = self.iter().collect();
         items.sort();
         for (addr, count) in items {
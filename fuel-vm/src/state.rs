@@ -10,14 +10,26 @@ use fuel_types::{
 
 mod debug;
 
+#[cfg(feature = "debugger")]
 mod debugger;
 
+#[cfg(any(test, feature = "test-helpers"))]
+mod verify;
+
 pub use debug::{
     Breakpoint,
     DebugEval,
+    Watch,
+    Watchpoint,
 };
 
+#[cfg(feature = "debugger")]
 pub use debugger::Debugger;
+#[cfg(feature = "debugger")]
+pub(crate) use debugger::WriteTarget;
+
+#[cfg(any(test, feature = "test-helpers"))]
+pub use verify::VerifyMismatch;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 /// Resulting state of an instruction set execution.
@@ -32,13 +44,28 @@ pub enum ExecuteState {
     Revert(Word),
 
     /// A debug event was reached.
-    DebugEvent(DebugEval),
+    DebugEvent(DebugEventEval),
 }
 
+/// Debug event carried by [`ExecuteState::DebugEvent`].
+#[cfg(feature = "debugger")]
+pub type DebugEventEval = DebugEval;
+
+/// Debug event carried by [`ExecuteState::DebugEvent`].
+///
+/// The debug events can't be produced without the `debugger` feature.
+#[cfg(not(feature = "debugger"))]
+pub type DebugEventEval = core::convert::Infallible;
+
 impl ExecuteState {
     /// Return true if the VM execution should continue.
     pub const fn should_continue(&self) -> bool {
-        matches!(self, Self::Proceed | Self::DebugEvent(DebugEval::Continue))
+        match self {
+            Self::Proceed => true,
+            #[cfg(feature = "debugger")]
+            Self::DebugEvent(DebugEval::Continue) => true,
+            _ => false,
+        }
     }
 }
 
@@ -48,6 +75,7 @@ impl Default for ExecuteState {
     }
 }
 
+#[cfg(feature = "debugger")]
 impl From<DebugEval> for ExecuteState {
     fn from(d: DebugEval) -> Self {
         Self::DebugEvent(d)
@@ -100,6 +128,7 @@ impl ProgramState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Representation of the result of a transaction execution.
 pub struct StateTransition<Tx> {
     state: ProgramState,
@@ -128,10 +157,11 @@ impl<Tx> StateTransition<Tx> {
     }
 
     /// Flag whether the client should revert after execution.
+    ///
+    /// The `Revert` and `Panic` receipts of the reverted call frames don't revert the
+    /// transaction, only the final state does.
     pub fn should_revert(&self) -> bool {
-        self.receipts
-            .iter()
-            .any(|r| matches!(r, Receipt::Revert { .. } | Receipt::Panic { .. }))
+        matches!(self.state, ProgramState::Revert(_))
     }
 
     /// Transaction receipts representing the state transition.
@@ -152,6 +182,7 @@ impl<Tx> From<StateTransition<Tx>> for ProgramState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// Zero-copy Representation of the result of a transaction execution bound to
 /// the lifetime of the VM.
 pub struct StateTransitionRef<'a, Tx> {
@@ -186,10 +217,11 @@ impl<'a, Tx> StateTransitionRef<'a, Tx> {
     }
 
     /// Flag whether the client should revert after execution.
+    ///
+    /// The `Revert` and `Panic` receipts of the reverted call frames don't revert the
+    /// transaction, only the final state does.
     pub fn should_revert(&self) -> bool {
-        self.receipts
-            .iter()
-            .any(|r| matches!(r, Receipt::Revert { .. } | Receipt::Panic { .. }))
+        matches!(self.state, ProgramState::Revert(_))
     }
 }
 
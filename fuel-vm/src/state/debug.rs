@@ -1,5 +1,6 @@
 use fuel_asm::Instruction;
 use fuel_types::{
+    Bytes32,
     ContractId,
     Word,
 };
@@ -58,6 +59,55 @@ impl Breakpoint {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Location watched by the debugger for writes.
+pub enum Watch {
+    /// The memory range `start..end`.
+    Memory {
+        /// First byte of the range.
+        start: usize,
+        /// One past the last byte of the range.
+        end: usize,
+    },
+    /// The storage slot of the contract.
+    Storage {
+        /// Contract owning the slot.
+        contract: ContractId,
+        /// Key of the slot.
+        key: Bytes32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Description of a write to a watched location.
+///
+/// The program is suspended right after the writing instruction is executed, so
+/// the write is already visible in the memory or the storage.
+pub struct Watchpoint {
+    watch: Watch,
+    location: Breakpoint,
+}
+
+impl Watchpoint {
+    #[cfg(feature = "debugger")]
+    pub(crate) const fn new(watch: Watch, location: Breakpoint) -> Self {
+        Self { watch, location }
+    }
+
+    /// Watched location that was written.
+    pub const fn watch(&self) -> &Watch {
+        &self.watch
+    }
+
+    /// Contract and `$pc` of the writing instruction, in the same format as the
+    /// [`Breakpoint`].
+    pub const fn location(&self) -> &Breakpoint {
+        &self.location
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// State evaluation of the interpreter that will describe if a program should
@@ -66,6 +116,9 @@ pub enum DebugEval {
     /// This evaluation should break the program in the location described in
     /// `Breakpoint`.
     Breakpoint(Breakpoint),
+    /// This evaluation should break the program after a write to a watched
+    /// location described in `Watchpoint`.
+    Watchpoint(Watchpoint),
     /// This evaluation should not break the program.
     Continue,
 }
@@ -96,4 +149,19 @@ impl DebugEval {
             _ => None,
         }
     }
+
+    /// Return a watchpoint description if the current evaluation should break
+    /// because of a write to a watched location; return `None` otherwise.
+    pub const fn watchpoint(&self) -> Option<&Watchpoint> {
+        match self {
+            Self::Watchpoint(w) => Some(w),
+            _ => None,
+        }
+    }
+}
+
+impl From<Watchpoint> for DebugEval {
+    fn from(w: Watchpoint) -> Self {
+        Self::Watchpoint(w)
+    }
 }
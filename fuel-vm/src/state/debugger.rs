@@ -1,10 +1,18 @@
-use crate::state::{
-    Breakpoint,
-    DebugEval,
-    ProgramState,
+use crate::{
+    context::Context,
+    interpreter::MemoryRange,
+    state::{
+        Breakpoint,
+        DebugEval,
+        ProgramState,
+        Watch,
+        Watchpoint,
+    },
 };
 
+use alloc::vec::Vec;
 use fuel_types::{
+    Bytes32,
     ContractId,
     Word,
 };
@@ -22,7 +30,21 @@ pub struct Debugger {
     /// Single-stepping mode triggers a breakpoint after each instruction
     single_stepping: bool,
     breakpoints: HashMap<ContractId, HashSet<Word>>,
+    memory_watches: Vec<MemoryRange>,
+    storage_watches: HashMap<ContractId, HashSet<Bytes32>>,
     last_state: Option<ProgramState>,
+    context_transitions: Vec<(Word, Context)>,
+}
+
+/// Location written by an instruction, checked against the watches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum WriteTarget {
+    Memory(MemoryRange),
+    Storage {
+        contract: ContractId,
+        start_key: Bytes32,
+        slots: Word,
+    },
 }
 
 impl Debugger {
@@ -74,6 +96,91 @@ impl Debugger {
             .map(|set| set.remove(&breakpoint.pc()));
     }
 
+    /// Watch the writes to the memory range.
+    pub fn add_memory_watch(&mut self, range: MemoryRange) {
+        self.is_active = true;
+        self.memory_watches.push(range);
+    }
+
+    /// Watch the writes to the storage slot of the contract.
+    pub fn add_storage_watch(&mut self, contract: ContractId, key: Bytes32) {
+        self.is_active = true;
+        self.storage_watches
+            .entry(contract)
+            .or_default()
+            .insert(key);
+    }
+
+    /// Remove all memory and storage watches.
+    pub fn clear_watches(&mut self) {
+        self.memory_watches.clear();
+        self.storage_watches.clear();
+    }
+
+    /// Returns `true` if any memory or storage watch is set.
+    pub fn has_watches(&self) -> bool {
+        !self.memory_watches.is_empty() || !self.storage_watches.is_empty()
+    }
+
+    /// The contexts entered by the VM, in order, each with the index of the
+    /// instruction that entered it, see [`fuel_tx::Receipt::instruction_index`].
+    /// The initialization enters its context at the index of the first
+    /// instruction.
+    pub fn context_transitions(&self) -> &[(Word, Context)] {
+        self.context_transitions.as_slice()
+    }
+
+    /// Record the `context` if it differs from the last recorded one.
+    pub(crate) fn record_context(&mut self, instruction_index: Word, context: &Context) {
+        let is_recorded = matches!(
+            self.context_transitions.last(),
+            Some((_, last)) if last == context
+        );
+        if !is_recorded {
+            self.context_transitions
+                .push((instruction_index, context.clone()));
+        }
+    }
+
+    /// Evaluate whether the write performed by the instruction at `location`
+    /// touched a watched location.
+    pub(crate) fn eval_write(
+        &self,
+        location: Breakpoint,
+        target: &WriteTarget,
+    ) -> DebugEval {
+        let watch = match target {
+            WriteTarget::Memory(written) => self
+                .memory_watches
+                .iter()
+                .find(|watch| {
+                    !written.is_empty()
+                        && watch.start() < written.end()
+                        && written.start() < watch.end()
+                })
+                .map(|watch| Watch::Memory {
+                    start: watch.start(),
+                    end: watch.end(),
+                }),
+            WriteTarget::Storage {
+                contract,
+                start_key,
+                slots,
+            } => self.storage_watches.get(contract).and_then(|keys| {
+                keys.iter()
+                    .find(|key| key_in_range(start_key, *slots, key))
+                    .map(|key| Watch::Storage {
+                        contract: *contract,
+                        key: *key,
+                    })
+            }),
+        };
+
+        watch
+            .map(|watch| Watchpoint::new(watch, location).into())
+            .unwrap_or_default()
+    }
+
     /// Evaluate the current state of the interpreter whether or not a
     /// breakpoint was reached.
     pub fn eval_state(&mut self, contract: Option<&ContractId>, pc: Word) -> DebugEval {
@@ -112,3 +219,24 @@ impl Debugger {
         &self.last_state
     }
 }
+
+/// Returns `true` if the `key` is one of the `slots` consecutive keys starting at
+/// the `start`, the same way as the storage ranges are iterated by `SWWQ` and `SCWQ`.
+fn key_in_range(start: &Bytes32, slots: Word, key: &Bytes32) -> bool {
+    let halves = |key: &Bytes32| {
+        let mut high = [0u8; 16];
+        let mut low = [0u8; 16];
+        high.copy_from_slice(&key[..16]);
+        low.copy_from_slice(&key[16..]);
+        (u128::from_be_bytes(high), u128::from_be_bytes(low))
+    };
+    let (start_high, start_low) = halves(start);
+    let (key_high, key_low) = halves(key);
+
+    let (offset_low, borrow) = key_low.overflowing_sub(start_low);
+    let offset_high = key_high
+        .checked_sub(start_high)
+        .and_then(|high| high.checked_sub(u128::from(borrow)));
+
+    offset_high == Some(0) && offset_low < u128::from(slots)
+}
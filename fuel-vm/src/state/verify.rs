@@ -0,0 +1,146 @@
+use alloc::string::String;
+
+use fuel_tx::{
+    Output,
+    Receipt,
+};
+
+use crate::{
+    checked_transaction::{
+        Checked,
+        IntoChecked,
+    },
+    error::InterpreterError,
+    interpreter::{
+        CheckedMetadata,
+        ExecutableTransaction,
+        InterpreterParams,
+        MemoryInstance,
+    },
+    storage::MemoryStorage,
+    transactor::Transactor,
+};
+
+use super::{
+    ProgramState,
+    StateTransition,
+};
+
+/// The first difference between the [`StateTransition`] and its re-execution.
+#[derive(Debug, Clone, PartialEq, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VerifyMismatch {
+    /// The re-execution of the transaction failed.
+    #[display(fmt = "The re-execution failed: {_0}")]
+    Execution(InterpreterError<String>),
+    /// The program state differs.
+    #[display(
+        fmt = "The program state is {claimed:?}, but the re-execution resulted in {recomputed:?}"
+    )]
+    State {
+        /// The state of the verified transition.
+        claimed: ProgramState,
+        /// The state of the re-execution.
+        recomputed: ProgramState,
+    },
+    /// The receipt at the `index` differs, or only one of the executions
+    /// produced it.
+    #[display(fmt = "The receipt {index} differs from the re-execution")]
+    Receipt {
+        /// The index of the receipt.
+        index: usize,
+        /// The receipt of the verified transition.
+        claimed: Option<Receipt>,
+        /// The receipt of the re-execution.
+        recomputed: Option<Receipt>,
+    },
+    /// The output at the `index` differs, or only one of the transactions has it.
+    #[display(fmt = "The output {index} differs from the re-execution")]
+    Output {
+        /// The index of the output.
+        index: usize,
+        /// The output of the verified transition.
+        claimed: Option<Output>,
+        /// The output of the re-execution.
+        recomputed: Option<Output>,
+    },
+    /// The outputs and the receipts match, but another field of the resulting
+    /// transaction differs, e.g. the receipts root.
+    #[display(fmt = "The transaction differs from the re-execution")]
+    Transaction,
+}
+
+impl<Tx> StateTransition<Tx>
+where
+    Tx: ExecutableTransaction + PartialEq,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata,
+{
+    /// Verifies the transition by re-executing the `checked` transaction on top of
+    /// the `storage_snapshot` taken before the execution.
+    ///
+    /// Returns the first difference of the program state, the receipts, the outputs
+    /// or the rest of the resulting transaction.
+    #[allow(clippy::result_large_err)] // The mismatch holds the differing values.
+    pub fn verify(
+        &self,
+        checked: Checked<Tx>,
+        storage_snapshot: MemoryStorage,
+        params: InterpreterParams,
+    ) -> Result<(), VerifyMismatch> {
+        let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Tx>::new(
+            MemoryInstance::new(),
+            storage_snapshot,
+            params,
+        );
+        let recomputed = transactor
+            .transact(checked)
+            .result()
+            .map_err(|e| VerifyMismatch::Execution(e.erase_generics()))?;
+
+        if self.state() != recomputed.state() {
+            return Err(VerifyMismatch::State {
+                claimed: *self.state(),
+                recomputed: *recomputed.state(),
+            })
+        }
+        if let Some((index, claimed, recomputed)) =
+            first_difference(self.receipts(), recomputed.receipts())
+        {
+            return Err(VerifyMismatch::Receipt {
+                index,
+                claimed,
+                recomputed,
+            })
+        }
+        if let Some((index, claimed, recomputed)) =
+            first_difference(self.tx().outputs(), recomputed.tx().outputs())
+        {
+            return Err(VerifyMismatch::Output {
+                index,
+                claimed,
+                recomputed,
+            })
+        }
+        if self.tx() != recomputed.tx() {
+            return Err(VerifyMismatch::Transaction)
+        }
+        Ok(())
+    }
+}
+
+/// Returns the index and the values of the first difference of the slices,
+/// including the items present only in the longer slice.
+fn first_difference<T>(
+    claimed: &[T],
+    recomputed: &[T],
+) -> Option<(usize, Option<T>, Option<T>)>
+where
+    T: Clone + PartialEq,
+{
+    let len = claimed.len().max(recomputed.len());
+    (0..len).find_map(|index| {
+        let claimed = claimed.get(index);
+        let recomputed = recomputed.get(index);
+        (claimed != recomputed).then(|| (index, claimed.cloned(), recomputed.cloned()))
+    })
+}
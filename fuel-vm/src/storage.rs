@@ -7,18 +7,45 @@ use fuel_types::{
     ContractId,
 };
 
+#[cfg(feature = "async-storage")]
+mod async_bridge;
+mod binary_merkle;
 mod blob_data;
+mod consensus_parameters;
 mod contracts_assets;
 mod contracts_state;
+mod instrumented;
 mod interpreter;
+pub mod layout;
 #[cfg(feature = "test-helpers")]
 mod memory;
 pub mod predicate;
+mod read_only;
 
+#[cfg(feature = "async-storage")]
+pub use async_bridge::{
+    AsyncInterpreterStorage,
+    AsyncStorageBridge,
+    AsyncStorageBytes,
+    AsyncStorageTable,
+    BlockOn,
+    BlockingFuture,
+};
+pub use binary_merkle::{
+    BinaryMerkleMetadata,
+    BinaryMerkleNodeKey,
+    BinaryMerkleStorage,
+    BinaryMerkleTreeMetadata,
+    BinaryMerkleTreeNodes,
+};
 pub use blob_data::{
     BlobBytes,
     BlobData,
 };
+pub use consensus_parameters::{
+    ConsensusParametersAt,
+    ConsensusParametersProvider,
+};
 pub use contracts_assets::{
     ContractsAssetKey,
     ContractsAssets,
@@ -28,15 +55,25 @@ pub use contracts_state::{
     ContractsStateData,
     ContractsStateKey,
 };
+pub use instrumented::{
+    InstrumentedStorage,
+    StorageMetrics,
+};
 pub use interpreter::{
+    is_history_strictly_increasing,
     ContractsAssetsStorage,
     InterpreterStorage,
 };
 #[cfg(feature = "test-helpers")]
 pub use memory::{
+    dump,
     MemoryStorage,
     MemoryStorageError,
 };
+pub use read_only::{
+    ReadOnlyStorage,
+    ReadOnlyStorageError,
+};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -158,6 +195,22 @@ macro_rules! double_key {
             }
         }
 
+        impl From<($first, $second)> for $i {
+            fn from(pair: ($first, $second)) -> Self {
+                Self::new(&pair.0, &pair.1)
+            }
+        }
+
+        /// Displays the sub-keys separated by `:`. The width and the alternate
+        /// flag apply to both sub-keys, so `{:8}` is a compact form.
+        impl core::fmt::Display for $i {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self.$first_getter(), f)?;
+                f.write_str(":")?;
+                core::fmt::Display::fmt(self.$second_getter(), f)
+            }
+        }
+
         impl AsRef<[u8]> for $i {
             fn as_ref(&self) -> &[u8] {
                 self.0.as_ref()
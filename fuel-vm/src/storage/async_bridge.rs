@@ -0,0 +1,520 @@
+//! Bridge between the synchronous [`InterpreterStorage`] used by the VM and
+//! asynchronous storage backends.
+//!
+//! The VM executes instructions synchronously, so every storage access performed by
+//! [`AsyncStorageBridge`] blocks the current thread until the future returned by the
+//! [`AsyncInterpreterStorage`] completes. The futures are driven either by a
+//! [`tokio::runtime::Handle`] or by a user provided callback.
+//!
+//! # Executor constraints
+//!
+//! [`tokio::runtime::Handle::block_on`] panics when it is called from a thread that
+//! is driving an asynchronous task of the runtime. If the storage futures depend on
+//! the runtime (e.g. they wait for I/O or for other tasks), blocking one of
+//! the runtime workers can also deadlock the runtime with a single worker.
+//!
+//! Because of that, the VM must never be executed directly inside of an asynchronous
+//! task when the bridge is used. Either run it on a thread that doesn't belong to
+//! the runtime, or use [`AsyncStorageBridge::execute_blocking`], which moves
+//! the execution to the blocking thread pool of the runtime via
+//! [`tokio::task::spawn_blocking`].
+
+use crate::{
+    prelude::{
+        InterpreterError,
+        RuntimeError,
+    },
+    storage::{
+        BlobData,
+        ContractsAssets,
+        ContractsAssetsStorage,
+        ContractsRawCode,
+        ContractsState,
+        ContractsStateData,
+        InterpreterStorage,
+        UploadedBytecodes,
+    },
+};
+
+use fuel_storage::{
+    Mappable,
+    StorageBatchMutate,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::ConsensusParameters;
+use fuel_types::{
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    future::Future,
+    pin::Pin,
+};
+
+/// Asynchronous counterpart of [`StorageInspect`] and [`StorageMutate`] for
+/// the `Type` table.
+#[async_trait::async_trait(?Send)]
+pub trait AsyncStorageTable<Type: Mappable> {
+    /// Error implementation of the storage backend.
+    type Error;
+
+    /// Retrieve the value by the key.
+    async fn get(&self, key: &Type::Key)
+        -> Result<Option<Type::OwnedValue>, Self::Error>;
+
+    /// Return `true` if there is a value by the key.
+    async fn contains_key(&self, key: &Type::Key) -> Result<bool, Self::Error>;
+
+    /// Append the value by the key. Returns the previous value, if any.
+    async fn replace(
+        &mut self,
+        key: &Type::Key,
+        value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error>;
+
+    /// Remove the value by the key. Returns the removed value, if any.
+    async fn take(
+        &mut self,
+        key: &Type::Key,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error>;
+}
+
+/// Asynchronous counterpart of [`StorageSize`] and [`StorageRead`] for the tables
+/// storing raw bytes.
+#[async_trait::async_trait(?Send)]
+pub trait AsyncStorageBytes<Type: Mappable>: AsyncStorageTable<Type> {
+    /// Return the size of the value by the key.
+    async fn size_of_value(&self, key: &Type::Key) -> Result<Option<usize>, Self::Error>;
+
+    /// Read the value by the key starting at the `offset` into the `buf`.
+    /// Returns the number of bytes read, or `None` if there is no value.
+    async fn read(
+        &self,
+        key: &Type::Key,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, Self::Error>;
+}
+
+/// Asynchronous counterpart of [`InterpreterStorage`].
+///
+/// Use [`AsyncStorageBridge`] to pass the implementation to the VM.
+#[async_trait::async_trait(?Send)]
+pub trait AsyncInterpreterStorage:
+    AsyncStorageBytes<ContractsRawCode, Error = Self::DataError>
+    + AsyncStorageBytes<ContractsState, Error = Self::DataError>
+    + AsyncStorageBytes<BlobData, Error = Self::DataError>
+    + AsyncStorageTable<ContractsAssets, Error = Self::DataError>
+    + AsyncStorageTable<UploadedBytecodes, Error = Self::DataError>
+{
+    /// Error implementation for reasons unspecified in the protocol.
+    type DataError: Into<InterpreterError<Self::DataError>>
+        + Into<RuntimeError<Self::DataError>>
+        + core::fmt::Debug;
+
+    /// See [`InterpreterStorage::block_height`].
+    async fn block_height(&self) -> Result<BlockHeight, Self::DataError>;
+
+    /// See [`InterpreterStorage::consensus_parameters_version`].
+    async fn consensus_parameters_version(&self) -> Result<u32, Self::DataError>;
+
+    /// See [`InterpreterStorage::state_transition_version`].
+    async fn state_transition_version(&self) -> Result<u32, Self::DataError>;
+
+    /// See [`InterpreterStorage::timestamp`].
+    async fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError>;
+
+    /// See [`InterpreterStorage::block_hash`].
+    async fn block_hash(
+        &self,
+        block_height: BlockHeight,
+    ) -> Result<Bytes32, Self::DataError>;
+
+    /// See [`InterpreterStorage::coinbase`].
+    async fn coinbase(&self) -> Result<ContractId, Self::DataError>;
+
+    /// See [`InterpreterStorage::set_consensus_parameters`].
+    async fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError>;
+
+    /// See [`InterpreterStorage::set_state_transition_bytecode`].
+    async fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError>;
+
+    /// See [`InterpreterStorage::consensus_parameters_history`].
+    async fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Ok(None)
+    }
+
+    /// See [`InterpreterStorage::state_transition_history`].
+    async fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Ok(None)
+    }
+
+    /// See [`InterpreterStorage::contract_state_range`].
+    async fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<ContractsStateData>>, Self::DataError>;
+
+    /// See [`InterpreterStorage::contract_state_insert_range`].
+    async fn contract_state_insert_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: &[&[u8]],
+    ) -> Result<usize, Self::DataError>;
+
+    /// See [`InterpreterStorage::contract_state_remove_range`].
+    async fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError>;
+}
+
+/// The future passed to the user provided blocking callback.
+pub type BlockingFuture<'a> = Pin<Box<dyn Future<Output = ()> + 'a>>;
+
+/// The callback that drives the future to completion on the current thread.
+pub type BlockOn = dyn for<'a> Fn(BlockingFuture<'a>) + Send + Sync;
+
+#[derive(Clone)]
+enum Blocker {
+    Handle(tokio::runtime::Handle),
+    Callback(Arc<BlockOn>),
+}
+
+/// Implements [`InterpreterStorage`] on top of [`AsyncInterpreterStorage`] by blocking
+/// the current thread on each storage access.
+///
+/// [`tokio::runtime::Handle::block_on`] panics inside of an asynchronous task, and
+/// blocking a runtime worker may deadlock the storage futures. Run the VM on a thread
+/// outside of the runtime or use [`AsyncStorageBridge::execute_blocking`].
+#[derive(Clone)]
+pub struct AsyncStorageBridge<T> {
+    storage: T,
+    blocker: Blocker,
+}
+
+impl<T> core::fmt::Debug for AsyncStorageBridge<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AsyncStorageBridge")
+            .field("storage", &self.storage)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> AsyncStorageBridge<T> {
+    /// Creates the bridge that drives the storage futures with the `handle`.
+    pub fn new(storage: T, handle: tokio::runtime::Handle) -> Self {
+        Self {
+            storage,
+            blocker: Blocker::Handle(handle),
+        }
+    }
+
+    /// Creates the bridge that drives the storage futures with the `block_on`
+    /// callback. The callback must run the future to completion before returning.
+    pub fn with_block_on<F>(storage: T, block_on: F) -> Self
+    where
+        F: for<'a> Fn(BlockingFuture<'a>) + Send + Sync + 'static,
+    {
+        Self {
+            storage,
+            blocker: Blocker::Callback(Arc::new(block_on)),
+        }
+    }
+
+    /// Returns the underlying asynchronous storage.
+    pub fn storage(&self) -> &T {
+        &self.storage
+    }
+
+    /// Returns the mutable underlying asynchronous storage.
+    pub fn storage_mut(&mut self) -> &mut T {
+        &mut self.storage
+    }
+
+    /// Returns the underlying asynchronous storage.
+    pub fn into_inner(self) -> T {
+        self.storage
+    }
+
+    fn block_on<F: Future>(blocker: &Blocker, future: F) -> F::Output {
+        match blocker {
+            Blocker::Handle(handle) => handle.block_on(future),
+            Blocker::Callback(block_on) => {
+                let mut output = None;
+                block_on(Box::pin(async {
+                    output = Some(future.await);
+                }));
+                output.expect("The `block_on` callback didn't complete the future")
+            }
+        }
+    }
+
+    /// Runs the `f` on the blocking thread pool of the current tokio runtime, and
+    /// returns the bridge back together with the result of the `f`.
+    ///
+    /// It is the deadlock-free way to execute the VM with the bridge from inside of
+    /// an asynchronous task. Panics inside of `f` are propagated to the caller.
+    pub async fn execute_blocking<F, R>(mut self, f: F) -> (Self, R)
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Self) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let result = tokio::task::spawn_blocking(move || {
+            let result = f(&mut self);
+            (self, result)
+        })
+        .await;
+
+        match result {
+            Ok(result) => result,
+            Err(err) => std::panic::resume_unwind(err.into_panic()),
+        }
+    }
+}
+
+impl<T, Type> StorageInspect<Type> for AsyncStorageBridge<T>
+where
+    Type: Mappable,
+    T: AsyncStorageTable<Type>,
+{
+    type Error = T::Error;
+
+    fn get(&self, key: &Type::Key) -> Result<Option<Cow<Type::OwnedValue>>, Self::Error> {
+        Ok(Self::block_on(&self.blocker, self.storage.get(key))?.map(Cow::Owned))
+    }
+
+    fn contains_key(&self, key: &Type::Key) -> Result<bool, Self::Error> {
+        Self::block_on(&self.blocker, self.storage.contains_key(key))
+    }
+}
+
+impl<T, Type> StorageMutate<Type> for AsyncStorageBridge<T>
+where
+    Type: Mappable,
+    T: AsyncStorageTable<Type>,
+{
+    fn replace(
+        &mut self,
+        key: &Type::Key,
+        value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        Self::block_on(&self.blocker, self.storage.replace(key, value))
+    }
+
+    fn take(&mut self, key: &Type::Key) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        Self::block_on(&self.blocker, self.storage.take(key))
+    }
+}
+
+impl<T, Type> StorageBatchMutate<Type> for AsyncStorageBridge<T>
+where
+    Type: Mappable,
+    T: AsyncStorageTable<Type>,
+{
+}
+
+impl<T, Type> StorageSize<Type> for AsyncStorageBridge<T>
+where
+    Type: Mappable,
+    T: AsyncStorageBytes<Type>,
+{
+    fn size_of_value(&self, key: &Type::Key) -> Result<Option<usize>, Self::Error> {
+        Self::block_on(&self.blocker, self.storage.size_of_value(key))
+    }
+}
+
+impl<T, Type> StorageRead<Type> for AsyncStorageBridge<T>
+where
+    Type: Mappable,
+    Type::OwnedValue: AsRef<[u8]>,
+    T: AsyncStorageBytes<Type>,
+{
+    fn read(
+        &self,
+        key: &Type::Key,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, Self::Error> {
+        Self::block_on(&self.blocker, self.storage.read(key, offset, buf))
+    }
+
+    fn read_alloc(&self, key: &Type::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        let value = Self::block_on(&self.blocker, self.storage.get(key))?;
+        Ok(value.map(|value| value.as_ref().to_vec()))
+    }
+}
+
+impl<T, Type> StorageWrite<Type> for AsyncStorageBridge<T>
+where
+    Type: Mappable<Value = [u8]>,
+    Type::OwnedValue: AsRef<[u8]>,
+    T: AsyncStorageBytes<Type>,
+{
+    fn write_bytes(&mut self, key: &Type::Key, buf: &[u8]) -> Result<usize, Self::Error> {
+        Self::block_on(&self.blocker, self.storage.replace(key, buf))?;
+        Ok(buf.len())
+    }
+
+    fn replace_bytes(
+        &mut self,
+        key: &Type::Key,
+        buf: &[u8],
+    ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+        let prev = Self::block_on(&self.blocker, self.storage.replace(key, buf))?;
+        Ok((buf.len(), prev.map(|prev| prev.as_ref().to_vec())))
+    }
+
+    fn take_bytes(&mut self, key: &Type::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        let prev = Self::block_on(&self.blocker, self.storage.take(key))?;
+        Ok(prev.map(|prev| prev.as_ref().to_vec()))
+    }
+}
+
+impl<T> ContractsAssetsStorage for AsyncStorageBridge<T> where T: AsyncInterpreterStorage {}
+
+impl<T> InterpreterStorage for AsyncStorageBridge<T>
+where
+    T: AsyncInterpreterStorage,
+{
+    type DataError = T::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.block_height())
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.consensus_parameters_version())
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.state_transition_version())
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.timestamp(height))
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.block_hash(block_height))
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.coinbase())
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        Self::block_on(
+            &self.blocker,
+            self.storage
+                .set_consensus_parameters(version, consensus_parameters),
+        )
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        Self::block_on(
+            &self.blocker,
+            self.storage.set_state_transition_bytecode(version, hash),
+        )
+    }
+
+    fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.consensus_parameters_history())
+    }
+
+    fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Self::block_on(&self.blocker, self.storage.state_transition_history())
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<ContractsStateData>>>, Self::DataError> {
+        let values = Self::block_on(
+            &self.blocker,
+            self.storage.contract_state_range(id, start_key, range),
+        )?;
+        Ok(values
+            .into_iter()
+            .map(|value| value.map(Cow::Owned))
+            .collect())
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        let values: Vec<&[u8]> = values.collect();
+        Self::block_on(
+            &self.blocker,
+            self.storage
+                .contract_state_insert_range(contract, start_key, &values),
+        )
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        Self::block_on(
+            &self.blocker,
+            self.storage
+                .contract_state_remove_range(contract, start_key, range),
+        )
+    }
+}
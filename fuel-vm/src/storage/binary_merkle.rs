@@ -0,0 +1,227 @@
+//! The binary Merkle trees persisted in the storage, e.g. the trees of the
+//! messages and the receipts used to prove their inclusion.
+//!
+//! Every tree is identified by a 32 bytes key chosen by the user, and its nodes are
+//! stored in the [`BinaryMerkleTreeNodes`] by the in-order index of the node in the
+//! tree, as expected by the [`MerkleTree`] of the `fuel-merkle`.
+
+use fuel_merkle::{
+    binary::{
+        MerkleTree,
+        MerkleTreeError,
+        Primitive,
+    },
+    common::ProofSet,
+};
+use fuel_storage::{
+    Mappable,
+    StorageAsRef,
+    StorageInspect,
+    StorageMutate,
+};
+use fuel_types::Bytes32;
+
+use alloc::borrow::Cow;
+
+/// The storage table for the nodes of the binary Merkle trees.
+pub struct BinaryMerkleTreeNodes;
+
+impl Mappable for BinaryMerkleTreeNodes {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BinaryMerkleNodeKey;
+    type OwnedValue = Self::Value;
+    type Value = Primitive;
+}
+
+/// The storage table for the metadata of the binary Merkle trees.
+pub struct BinaryMerkleMetadata;
+
+impl Mappable for BinaryMerkleMetadata {
+    /// The key is the identifier of the tree.
+    type Key = Self::OwnedKey;
+    type OwnedKey = Bytes32;
+    type OwnedValue = Self::Value;
+    type Value = BinaryMerkleTreeMetadata;
+}
+
+/// The key of the node of the binary Merkle tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BinaryMerkleNodeKey {
+    tree: Bytes32,
+    index: u64,
+}
+
+impl BinaryMerkleNodeKey {
+    /// Creates the key of the node at the in-order `index` of the `tree`.
+    pub const fn new(tree: Bytes32, index: u64) -> Self {
+        Self { tree, index }
+    }
+
+    /// Returns the identifier of the tree.
+    pub const fn tree(&self) -> &Bytes32 {
+        &self.tree
+    }
+
+    /// Returns the in-order index of the node in the tree.
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+impl From<(Bytes32, u64)> for BinaryMerkleNodeKey {
+    fn from((tree, index): (Bytes32, u64)) -> Self {
+        Self::new(tree, index)
+    }
+}
+
+/// The metadata of the binary Merkle tree.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BinaryMerkleTreeMetadata {
+    /// The number of the leaves of the tree.
+    pub leaves_count: u64,
+    /// The root of the tree.
+    pub root: Bytes32,
+}
+
+/// Storage operations for the binary Merkle trees.
+pub trait BinaryMerkleStorage:
+    StorageMutate<BinaryMerkleTreeNodes, Error = Self::DataError>
+    + StorageMutate<BinaryMerkleMetadata, Error = Self::DataError>
+{
+    /// Error implementation for reasons unspecified in the protocol.
+    type DataError;
+
+    /// Fetch the metadata of the `tree`, or `None` if it has no leaves.
+    fn binary_merkle_metadata(
+        &self,
+        tree: &Bytes32,
+    ) -> Result<Option<BinaryMerkleTreeMetadata>, Self::DataError> {
+        let metadata = self
+            .storage::<BinaryMerkleMetadata>()
+            .get(tree)?
+            .map(Cow::into_owned);
+
+        Ok(metadata)
+    }
+
+    /// Fetch the root of the `tree`, which is the root of the empty tree if it has
+    /// no leaves.
+    fn binary_merkle_root(&self, tree: &Bytes32) -> Result<Bytes32, Self::DataError> {
+        let root = self
+            .binary_merkle_metadata(tree)?
+            .map(|metadata| metadata.root)
+            .unwrap_or_else(|| (*MerkleTree::<TreeNodes, ()>::empty_root()).into());
+
+        Ok(root)
+    }
+
+    /// Append the leaf with the `data` to the `tree`, creating the tree if it
+    /// doesn't exist. Returns the updated metadata of the tree.
+    fn binary_merkle_push(
+        &mut self,
+        tree: &Bytes32,
+        data: &[u8],
+    ) -> Result<BinaryMerkleTreeMetadata, MerkleTreeError<Self::DataError>> {
+        let leaves_count = self
+            .binary_merkle_metadata(tree)?
+            .map(|metadata| metadata.leaves_count)
+            .unwrap_or_default();
+        let storage = TreeStorage {
+            tree: *tree,
+            storage: &mut *self,
+        };
+        let mut merkle_tree = MerkleTree::<TreeNodes, _>::load(storage, leaves_count)?;
+        merkle_tree.push(data)?;
+
+        let metadata = BinaryMerkleTreeMetadata {
+            leaves_count: merkle_tree.leaves_count(),
+            root: merkle_tree.root().into(),
+        };
+        StorageMutate::<BinaryMerkleMetadata>::insert(self, tree, &metadata)?;
+
+        Ok(metadata)
+    }
+
+    /// Prove the inclusion of the leaf at the `index` of the `tree`. Returns the
+    /// root of the tree and the proof set, which are accepted by the
+    /// [`verify`](fuel_merkle::binary::verify) with the number of the leaves.
+    fn binary_merkle_prove(
+        &self,
+        tree: &Bytes32,
+        index: u64,
+    ) -> Result<(Bytes32, ProofSet), MerkleTreeError<Self::DataError>> {
+        let leaves_count = self
+            .binary_merkle_metadata(tree)?
+            .map(|metadata| metadata.leaves_count)
+            .unwrap_or_default();
+        let storage = TreeStorage {
+            tree: *tree,
+            storage: self,
+        };
+        let merkle_tree = MerkleTree::<TreeNodes, _>::load(storage, leaves_count)?;
+        let (root, proof_set) = merkle_tree.prove(index)?;
+
+        Ok((root.into(), proof_set))
+    }
+}
+
+impl<S> BinaryMerkleStorage for &mut S
+where
+    S: BinaryMerkleStorage,
+{
+    type DataError = <S as BinaryMerkleStorage>::DataError;
+}
+
+/// The nodes of one tree keyed by their in-order index, as the [`MerkleTree`]
+/// requires.
+struct TreeNodes;
+
+impl Mappable for TreeNodes {
+    type Key = Self::OwnedKey;
+    type OwnedKey = u64;
+    type OwnedValue = Self::Value;
+    type Value = Primitive;
+}
+
+/// The view of the [`BinaryMerkleTreeNodes`] of the `tree` as the [`TreeNodes`].
+struct TreeStorage<S> {
+    tree: Bytes32,
+    storage: S,
+}
+
+impl<S> StorageInspect<TreeNodes> for TreeStorage<S>
+where
+    S: StorageInspect<BinaryMerkleTreeNodes>,
+{
+    type Error = S::Error;
+
+    fn get(&self, key: &u64) -> Result<Option<Cow<'_, Primitive>>, Self::Error> {
+        self.storage.get(&BinaryMerkleNodeKey::new(self.tree, *key))
+    }
+
+    fn contains_key(&self, key: &u64) -> Result<bool, Self::Error> {
+        self.storage
+            .contains_key(&BinaryMerkleNodeKey::new(self.tree, *key))
+    }
+}
+
+impl<S> StorageMutate<TreeNodes> for TreeStorage<S>
+where
+    S: StorageMutate<BinaryMerkleTreeNodes>,
+{
+    fn replace(
+        &mut self,
+        key: &u64,
+        value: &Primitive,
+    ) -> Result<Option<Primitive>, Self::Error> {
+        self.storage
+            .replace(&BinaryMerkleNodeKey::new(self.tree, *key), value)
+    }
+
+    fn take(&mut self, key: &u64) -> Result<Option<Primitive>, Self::Error> {
+        self.storage
+            .take(&BinaryMerkleNodeKey::new(self.tree, *key))
+    }
+}
@@ -0,0 +1,44 @@
+//! Access to the versioned consensus parameters for the transaction checks.
+
+use alloc::borrow::Cow;
+use core::fmt::Display;
+
+use fuel_tx::ConsensusParameters;
+use fuel_types::BlockHeight;
+
+/// Identifies the consensus parameters requested from a
+/// [`ConsensusParametersProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsensusParametersAt {
+    /// The parameters that are active at the block height.
+    Height(BlockHeight),
+    /// The parameters of the version.
+    Version(u32),
+}
+
+/// Provides the consensus parameters stored by the upgrade transactions via
+/// [`InterpreterStorage::set_consensus_parameters`](crate::storage::InterpreterStorage::set_consensus_parameters).
+pub trait ConsensusParametersProvider {
+    /// The error returned when the parameters can't be provided.
+    type Error: Display;
+
+    /// Returns the consensus parameters identified by `at`.
+    fn params_at(
+        &self,
+        at: ConsensusParametersAt,
+    ) -> Result<Cow<'_, ConsensusParameters>, Self::Error>;
+}
+
+impl<T> ConsensusParametersProvider for &T
+where
+    T: ConsensusParametersProvider + ?Sized,
+{
+    type Error = T::Error;
+
+    fn params_at(
+        &self,
+        at: ConsensusParametersAt,
+    ) -> Result<Cow<'_, ConsensusParameters>, Self::Error> {
+        <T as ConsensusParametersProvider>::params_at(self, at)
+    }
+}
@@ -0,0 +1,589 @@
+//! Storage decorator counting the operations performed by the VM.
+
+use crate::storage::{
+    BlobData,
+    ContractsAssets,
+    ContractsAssetsStorage,
+    ContractsRawCode,
+    ContractsState,
+    ContractsStateData,
+    InterpreterStorage,
+    UploadedBytecodes,
+};
+
+use fuel_storage::{
+    Mappable,
+    StorageBatchMutate,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+    StorageSlot,
+};
+use fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use alloc::{
+    borrow::Cow,
+    vec::Vec,
+};
+use core::cell::Cell;
+
+/// Counters of the storage operations performed through [`InstrumentedStorage`].
+///
+/// Only the operations on the contract state and the contract bytecode are
+/// counted. Size lookups of the bytecode are counted separately from the reads,
+/// and existence checks of the bytecode are not counted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StorageMetrics {
+    /// The number of contract state slots read.
+    pub state_reads: u64,
+    /// The number of contract state slots written or removed.
+    pub state_writes: u64,
+    /// The number of written contract state slots that were unset before.
+    ///
+    /// Writes via `insert` and `write_bytes` don't report the previous value, so
+    /// they are never counted here.
+    pub new_slots: u64,
+    /// The number of contract bytecode reads.
+    pub code_reads: u64,
+    /// The number of contract bytecode size lookups.
+    pub code_size_lookups: u64,
+    /// The total number of bytes read from the contract state and bytecode.
+    pub bytes_read: u64,
+}
+
+/// The kind of the table for the purposes of [`StorageMetrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TableKind {
+    Code,
+    State,
+    Other,
+}
+
+impl StorageMetrics {
+    fn record_read(&mut self, kind: TableKind, bytes: usize) {
+        match kind {
+            TableKind::Code => self.code_reads = self.code_reads.saturating_add(1),
+            TableKind::State => self.state_reads = self.state_reads.saturating_add(1),
+            TableKind::Other => return,
+        }
+        self.bytes_read = self.bytes_read.saturating_add(bytes as u64);
+    }
+
+    fn record_size_lookup(&mut self, kind: TableKind) {
+        if kind == TableKind::Code {
+            self.code_size_lookups = self.code_size_lookups.saturating_add(1);
+        }
+    }
+
+    fn record_write(&mut self, kind: TableKind, created_new: bool) {
+        if kind == TableKind::State {
+            self.state_writes = self.state_writes.saturating_add(1);
+            if created_new {
+                self.new_slots = self.new_slots.saturating_add(1);
+            }
+        }
+    }
+}
+
+/// [`InterpreterStorage`] decorator that delegates every call to the underlying
+/// storage and accumulates [`StorageMetrics`] of the performed operations.
+///
+/// The metrics are accumulated until they are taken with [`Self::take_metrics`].
+#[derive(Debug, Default, Clone)]
+pub struct InstrumentedStorage<S> {
+    storage: S,
+    metrics: Cell<StorageMetrics>,
+}
+
+impl<S> InstrumentedStorage<S> {
+    /// Wraps the `storage`.
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            metrics: Cell::new(StorageMetrics::default()),
+        }
+    }
+
+    /// Returns the metrics accumulated so far.
+    pub fn metrics(&self) -> StorageMetrics {
+        self.metrics.get()
+    }
+
+    /// Returns the metrics accumulated so far and resets them.
+    pub fn take_metrics(&mut self) -> StorageMetrics {
+        self.metrics.take()
+    }
+
+    /// Returns the underlying storage.
+    pub fn inner(&self) -> &S {
+        &self.storage
+    }
+
+    /// Returns the underlying storage mutably.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Unwraps the underlying storage.
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+
+    fn record(&self, f: impl FnOnce(&mut StorageMetrics)) {
+        record_in(&self.metrics, f)
+    }
+}
+
+fn record_in(metrics: &Cell<StorageMetrics>, f: impl FnOnce(&mut StorageMetrics)) {
+    let mut value = metrics.get();
+    f(&mut value);
+    metrics.set(value);
+}
+
+fn bytes_len<V: AsRef<[u8]>>(value: &V) -> usize {
+    value.as_ref().len()
+}
+
+fn no_bytes<V>(_: &V) -> usize {
+    0
+}
+
+macro_rules! instrumented_table {
+    ($table:ty, $kind:expr, $value_len:ident) => {
+        impl<S> StorageInspect<$table> for InstrumentedStorage<S>
+        where
+            S: StorageInspect<$table>,
+        {
+            type Error = S::Error;
+
+            fn get(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Cow<<$table as Mappable>::OwnedValue>>, Self::Error> {
+                let value = self.storage.get(key)?;
+                let bytes = value.as_deref().map(|v| $value_len(v)).unwrap_or(0);
+                self.record(|m| m.record_read($kind, bytes));
+                Ok(value)
+            }
+
+            fn contains_key(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<bool, Self::Error> {
+                let contains = self.storage.contains_key(key)?;
+                if $kind == TableKind::State {
+                    self.record(|m| m.record_read($kind, 0));
+                }
+                Ok(contains)
+            }
+        }
+
+        impl<S> StorageMutate<$table> for InstrumentedStorage<S>
+        where
+            S: StorageMutate<$table>,
+        {
+            fn insert(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                value: &<$table as Mappable>::Value,
+            ) -> Result<(), Self::Error> {
+                self.storage.insert(key, value)?;
+                self.record(|m| m.record_write($kind, false));
+                Ok(())
+            }
+
+            fn replace(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                value: &<$table as Mappable>::Value,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                let prev = self.storage.replace(key, value)?;
+                self.record(|m| m.record_write($kind, prev.is_none()));
+                Ok(prev)
+            }
+
+            fn remove(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<(), Self::Error> {
+                self.storage.remove(key)?;
+                self.record(|m| m.record_write($kind, false));
+                Ok(())
+            }
+
+            fn take(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                let prev = self.storage.take(key)?;
+                self.record(|m| m.record_write($kind, false));
+                Ok(prev)
+            }
+        }
+
+        impl<S> StorageBatchMutate<$table> for InstrumentedStorage<S>
+        where
+            S: StorageBatchMutate<$table>,
+        {
+            fn init_storage<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+            where
+                Iter: Iterator<
+                    Item = (
+                        &'a <$table as Mappable>::Key,
+                        &'a <$table as Mappable>::Value,
+                    ),
+                >,
+            {
+                let metrics = &self.metrics;
+                self.storage.init_storage(
+                    set.inspect(|_| record_in(metrics, |m| m.record_write($kind, true))),
+                )
+            }
+
+            fn insert_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+            where
+                Iter: Iterator<
+                    Item = (
+                        &'a <$table as Mappable>::Key,
+                        &'a <$table as Mappable>::Value,
+                    ),
+                >,
+            {
+                let metrics = &self.metrics;
+                self.storage.insert_batch(
+                    set.inspect(|_| record_in(metrics, |m| m.record_write($kind, false))),
+                )
+            }
+
+            fn remove_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+            where
+                Iter: Iterator<Item = &'a <$table as Mappable>::Key>,
+            {
+                let metrics = &self.metrics;
+                self.storage.remove_batch(
+                    set.inspect(|_| record_in(metrics, |m| m.record_write($kind, false))),
+                )
+            }
+        }
+
+        impl<S> StorageSize<$table> for InstrumentedStorage<S>
+        where
+            S: StorageSize<$table>,
+        {
+            fn size_of_value(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<usize>, Self::Error> {
+                let size = self.storage.size_of_value(key)?;
+                self.record(|m| m.record_size_lookup($kind));
+                Ok(size)
+            }
+        }
+
+        impl<S> StorageRead<$table> for InstrumentedStorage<S>
+        where
+            S: StorageRead<$table>,
+        {
+            fn read(
+                &self,
+                key: &<$table as Mappable>::Key,
+                offset: usize,
+                buf: &mut [u8],
+            ) -> Result<Option<usize>, Self::Error> {
+                let read = self.storage.read(key, offset, buf)?;
+                self.record(|m| m.record_read($kind, read.unwrap_or(0)));
+                Ok(read)
+            }
+
+            fn read_alloc(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                let value = self.storage.read_alloc(key)?;
+                let bytes = value.as_ref().map(Vec::len).unwrap_or(0);
+                self.record(|m| m.record_read($kind, bytes));
+                Ok(value)
+            }
+        }
+
+        impl<S> StorageWrite<$table> for InstrumentedStorage<S>
+        where
+            S: StorageWrite<$table>,
+        {
+            fn write_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<usize, Self::Error> {
+                let written = self.storage.write_bytes(key, buf)?;
+                self.record(|m| m.record_write($kind, false));
+                Ok(written)
+            }
+
+            fn replace_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+                let (written, prev) = self.storage.replace_bytes(key, buf)?;
+                self.record(|m| m.record_write($kind, prev.is_none()));
+                Ok((written, prev))
+            }
+
+            fn take_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                let prev = self.storage.take_bytes(key)?;
+                self.record(|m| m.record_write($kind, false));
+                Ok(prev)
+            }
+        }
+    };
+}
+
+instrumented_table!(ContractsRawCode, TableKind::Code, bytes_len);
+instrumented_table!(ContractsState, TableKind::State, bytes_len);
+instrumented_table!(BlobData, TableKind::Other, bytes_len);
+instrumented_table!(UploadedBytecodes, TableKind::Other, no_bytes);
+instrumented_table!(ContractsAssets, TableKind::Other, no_bytes);
+
+impl<S> ContractsAssetsStorage for InstrumentedStorage<S>
+where
+    S: ContractsAssetsStorage,
+{
+    fn contract_asset_id_balance(
+        &self,
+        id: &ContractId,
+        asset_id: &AssetId,
+    ) -> Result<Option<Word>, Self::Error> {
+        self.storage.contract_asset_id_balance(id, asset_id)
+    }
+
+    fn contract_asset_id_balance_insert(
+        &mut self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+        value: Word,
+    ) -> Result<(), Self::Error> {
+        self.storage
+            .contract_asset_id_balance_insert(contract, asset_id, value)
+    }
+
+    fn contract_asset_id_balance_replace(
+        &mut self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+        value: Word,
+    ) -> Result<Option<Word>, Self::Error> {
+        self.storage
+            .contract_asset_id_balance_replace(contract, asset_id, value)
+    }
+}
+
+impl<S> InterpreterStorage for InstrumentedStorage<S>
+where
+    S: InterpreterStorage,
+{
+    type DataError = S::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        self.storage.block_height()
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.consensus_parameters_version()
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        self.storage.state_transition_version()
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        self.storage.timestamp(height)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        self.storage.coinbase()
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        self.storage
+            .set_consensus_parameters(version, consensus_parameters)
+    }
+
+    fn contains_state_transition_bytecode_root(
+        &self,
+        root: &Bytes32,
+    ) -> Result<bool, Self::DataError> {
+        self.storage.contains_state_transition_bytecode_root(root)
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.storage.set_state_transition_bytecode(version, hash)
+    }
+
+    fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        self.storage.consensus_parameters_history()
+    }
+
+    fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        self.storage.state_transition_history()
+    }
+
+    fn deploy_contract_with_id(
+        &mut self,
+        slots: &[StorageSlot],
+        contract: &Contract,
+        id: &ContractId,
+    ) -> Result<(), Self::DataError> {
+        self.storage.deploy_contract_with_id(slots, contract, id)?;
+        self.record(|m| {
+            slots
+                .iter()
+                .for_each(|_| m.record_write(TableKind::State, false))
+        });
+        Ok(())
+    }
+
+    fn storage_contract(
+        &self,
+        id: &ContractId,
+    ) -> Result<Option<Cow<'_, Contract>>, Self::DataError> {
+        let contract = self.storage.storage_contract(id)?;
+        let bytes = contract.as_deref().map(bytes_len).unwrap_or(0);
+        self.record(|m| m.record_read(TableKind::Code, bytes));
+        Ok(contract)
+    }
+
+    fn storage_contract_size(
+        &self,
+        id: &ContractId,
+    ) -> Result<Option<usize>, Self::DataError> {
+        let size = self.storage.storage_contract_size(id)?;
+        self.record(|m| m.record_size_lookup(TableKind::Code));
+        Ok(size)
+    }
+
+    fn storage_contract_insert(
+        &mut self,
+        id: &ContractId,
+        contract: &Contract,
+    ) -> Result<(), Self::DataError> {
+        self.storage.storage_contract_insert(id, contract)
+    }
+
+    fn storage_contract_exists(&self, id: &ContractId) -> Result<bool, Self::DataError> {
+        self.storage.storage_contract_exists(id)
+    }
+
+    fn contract_state(
+        &self,
+        id: &ContractId,
+        key: &Bytes32,
+    ) -> Result<Option<Cow<'_, ContractsStateData>>, Self::DataError> {
+        let value = self.storage.contract_state(id, key)?;
+        let bytes = value.as_deref().map(bytes_len).unwrap_or(0);
+        self.record(|m| m.record_read(TableKind::State, bytes));
+        Ok(value)
+    }
+
+    fn contract_state_insert(
+        &mut self,
+        contract: &ContractId,
+        key: &Bytes32,
+        value: &[u8],
+    ) -> Result<(), Self::DataError> {
+        self.storage.contract_state_insert(contract, key, value)?;
+        self.record(|m| m.record_write(TableKind::State, false));
+        Ok(())
+    }
+
+    fn contract_state_replace(
+        &mut self,
+        contract: &ContractId,
+        key: &Bytes32,
+        value: &[u8],
+    ) -> Result<Option<Vec<u8>>, Self::DataError> {
+        let prev = self.storage.contract_state_replace(contract, key, value)?;
+        self.record(|m| m.record_write(TableKind::State, prev.is_none()));
+        Ok(prev)
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<ContractsStateData>>>, Self::DataError> {
+        let values = self.storage.contract_state_range(id, start_key, range)?;
+        self.record(|m| {
+            values.iter().for_each(|value| {
+                let bytes = value.as_deref().map(bytes_len).unwrap_or(0);
+                m.record_read(TableKind::State, bytes)
+            })
+        });
+        Ok(values)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        let mut written: usize = 0;
+        let values = values.inspect(|_| written = written.saturating_add(1));
+        let created_new = self
+            .storage
+            .contract_state_insert_range(contract, start_key, values)?;
+        self.record(|m| {
+            (0..written).for_each(|i| m.record_write(TableKind::State, i < created_new))
+        });
+        Ok(created_new)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        let removed = self
+            .storage
+            .contract_state_remove_range(contract, start_key, range)?;
+        self.record(|m| (0..range).for_each(|_| m.record_write(TableKind::State, false)));
+        Ok(removed)
+    }
+}
@@ -2,6 +2,7 @@
 
 use fuel_storage::{
     StorageAsRef,
+    StorageBatchMutate,
     StorageInspect,
     StorageMutate,
     StorageRead,
@@ -31,6 +32,7 @@ use crate::{
         ContractsRawCode,
         ContractsState,
         ContractsStateData,
+        ContractsStateKey,
         UploadedBytecode,
         UploadedBytecodes,
     },
@@ -55,6 +57,7 @@ pub trait InterpreterStorage:
     + StorageWrite<ContractsState, Error = Self::DataError>
     + StorageSize<ContractsState, Error = Self::DataError>
     + StorageRead<ContractsState, Error = Self::DataError>
+    + StorageBatchMutate<ContractsState, Error = Self::DataError>
     + StorageMutate<UploadedBytecodes, Error = Self::DataError>
     + StorageWrite<BlobData, Error = Self::DataError>
     + StorageSize<BlobData, Error = Self::DataError>
@@ -128,6 +131,28 @@ pub trait InterpreterStorage:
         hash: &Bytes32,
     ) -> Result<Option<Bytes32>, Self::DataError>;
 
+    /// Returns the append-only log of versions and checksums of the consensus
+    /// parameters, in the order they were passed to
+    /// [`Self::set_consensus_parameters`].
+    ///
+    /// Returns `None` if the storage doesn't track the history.
+    fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Ok(None)
+    }
+
+    /// Returns the append-only log of versions and roots of the state transition
+    /// bytecodes, in the order they were passed to
+    /// [`Self::set_state_transition_bytecode`].
+    ///
+    /// Returns `None` if the storage doesn't track the history.
+    fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Ok(None)
+    }
+
     /// Deploy a contract into the storage with contract id
     fn deploy_contract_with_id(
         &mut self,
@@ -137,12 +162,12 @@ pub trait InterpreterStorage:
     ) -> Result<(), Self::DataError> {
         self.storage_contract_insert(id, contract)?;
 
-        // On the `fuel-core` side it is done in more optimal way
-        slots.iter().try_for_each(|s| {
-            self.contract_state_insert(id, s.key(), s.value().as_ref())?;
-            Ok(())
-        })?;
-        Ok(())
+        let keys = slots
+            .iter()
+            .map(|slot| ContractsStateKey::new(id, slot.key()))
+            .collect::<Vec<_>>();
+        let values = slots.iter().map(|slot| slot.value().as_ref());
+        StorageBatchMutate::<ContractsState>::init_storage(self, keys.iter().zip(values))
     }
 
     /// Fetch a previously inserted contract code from the chain state for a
@@ -249,6 +274,15 @@ pub trait InterpreterStorage:
     ) -> Result<Option<()>, Self::DataError>;
 }
 
+/// Returns `true` if the versions of the `history` returned by
+/// [`InterpreterStorage::consensus_parameters_history`] or
+/// [`InterpreterStorage::state_transition_history`] are strictly increasing.
+pub fn is_history_strictly_increasing(history: &[(u32, Bytes32)]) -> bool {
+    history
+        .windows(2)
+        .all(|pair| matches!(pair, [(prev, _), (next, _)] if prev < next))
+}
+
 /// Storage operations for contract assets.
 pub trait ContractsAssetsStorage: StorageMutate<ContractsAssets> {
     /// Fetch the balance of an asset ID in a contract storage.
@@ -351,6 +385,18 @@ where
         )
     }
 
+    fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        <S as InterpreterStorage>::consensus_parameters_history(self.deref())
+    }
+
+    fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        <S as InterpreterStorage>::state_transition_history(self.deref())
+    }
+
     fn storage_contract_size(
         &self,
         id: &ContractId,
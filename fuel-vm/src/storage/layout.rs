@@ -0,0 +1,112 @@
+//! The keys of the storage slots of the Sway contracts.
+//!
+//! Sway derives the key of the slot of a storage field by hashing its path, so the
+//! state of a contract can be read without copying the hashes into the tests:
+//!
+//! ```ignore
+//! test_context
+//!     .get_storage()
+//!     .contract_state(&contract_id, &slot_key("counter"));
+//! ```
+//!
+//! The key of the field `counter` is `sha256("storage.counter")`. The namespaces
+//! are prepended with `::`, so the key of the field `counter` in the namespace
+//! `my_ns` is `sha256("storage::my_ns.counter")`. The value of the `StorageMap` is
+//! stored at `sha256(key || slot)`, where the `key` is encoded by Sway, e.g. as 8
+//! big-endian bytes for `u64` or as 32 bytes for `b256`.
+
+use fuel_crypto::Hasher;
+use fuel_types::Bytes32;
+
+/// The domain of the paths of the storage fields.
+pub const STORAGE_DOMAIN: &str = "storage";
+
+/// Returns the key of the slot of the storage field at the `path`.
+///
+/// The `path` is the name of the field, preceded by the namespaces separated with
+/// `::`, e.g. `counter` or `my_ns::counter`.
+pub fn slot_key(path: &str) -> Bytes32 {
+    let mut hasher = Hasher::default();
+    hasher.input(STORAGE_DOMAIN);
+    let field = match path.rsplit_once("::") {
+        Some((namespaces, field)) => {
+            hasher.input("::");
+            hasher.input(namespaces);
+            field
+        }
+        None => path,
+    };
+    hasher.input(".");
+    hasher.input(field);
+    hasher.finalize()
+}
+
+/// Returns the key of the slot of the value of the `StorageMap` at the `path`,
+/// for the key encoded as the `key_bytes`.
+pub fn mapping_key(path: &str, key_bytes: &[u8]) -> Bytes32 {
+    Hasher::default()
+        .chain(key_bytes)
+        .chain(slot_key(path))
+        .finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use core::str::FromStr;
+
+    // The vectors are the SHA-256 of the documented preimages computed with an
+    // independent implementation. Replace them with the output of `forc` when the
+    // layout of the compiler changes.
+    fn bytes32(hex: &str) -> Bytes32 {
+        Bytes32::from_str(hex).expect("the vector is a valid hex")
+    }
+
+    #[test_case::test_case(
+        "counter",
+        "6e3c7b4f69bbff7132c3c3a62883a6868f47b0bc2a7f21605f29038cd9a5e05f";
+        "field"
+    )]
+    #[test_case::test_case(
+        "owner",
+        "1d63cc2495bbf5570c9a6d7f632018dc033107e7f4452405c44601bb771a4a5d";
+        "another field"
+    )]
+    #[test_case::test_case(
+        "my_ns::counter",
+        "e2f059f786546de31dbe84a4e939586a39b82d86c6d963e99a2cd71796da23b2";
+        "namespace"
+    )]
+    #[test_case::test_case(
+        "a::b::balances",
+        "f07b490241dea66db60384fb20ac6845d4f87a1244fcf625ebca3a849f2d71f1";
+        "nested namespaces"
+    )]
+    fn slot_key__matches_the_vector(path: &str, expected: &str) {
+        assert_eq!(slot_key(path), bytes32(expected));
+    }
+
+    #[test_case::test_case(
+        "balances",
+        &7u64.to_be_bytes(),
+        "9490ea96b8e2ec280c76befaa77a91326121940fccfd0e56ee638a90059c1ed0";
+        "u64 key"
+    )]
+    #[test_case::test_case(
+        "balances",
+        &[1; 32],
+        "304bf404733d15c12a0e988099b46f9a69fad1bd9f0d1193832ba460ca32227a";
+        "b256 key"
+    )]
+    #[test_case::test_case(
+        "a::b::balances",
+        &7u64.to_be_bytes(),
+        "aa3872e8c73f63dc4f1db36302f8fd6741cb734748f5e9653340a134fde555eb";
+        "namespace"
+    )]
+    fn mapping_key__matches_the_vector(path: &str, key: &[u8], expected: &str) {
+        assert_eq!(mapping_key(path, key), bytes32(expected));
+    }
+}
@@ -6,6 +6,13 @@ use crate::{
         RuntimeError,
     },
     storage::{
+        BinaryMerkleMetadata,
+        BinaryMerkleNodeKey,
+        BinaryMerkleStorage,
+        BinaryMerkleTreeMetadata,
+        BinaryMerkleTreeNodes,
+        ConsensusParametersAt,
+        ConsensusParametersProvider,
         ContractsAssetKey,
         ContractsAssets,
         ContractsRawCode,
@@ -19,9 +26,11 @@ use crate::{
 };
 
 use fuel_crypto::Hasher;
+use fuel_merkle::binary::Primitive;
 use fuel_storage::{
     Mappable,
     StorageAsRef,
+    StorageBatchMutate,
     StorageInspect,
     StorageMutate,
     StorageRead,
@@ -54,23 +63,31 @@ use alloc::{
     vec::Vec,
 };
 
+pub mod dump;
+
 /// Errors arising from accessing the memory storage.
 #[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
 pub enum MemoryStorageError {
     /// The offset specified for the serialized value exceeds its length
     #[display(fmt = "Offset {_0} is greater than the length of the value {_1}")]
     OffsetOutOfBounds(usize, usize),
+    /// The consensus parameters can't be serialized to calculate their checksum
+    #[display(fmt = "Unable to serialize the consensus parameters")]
+    ConsensusParametersSerialization,
+    /// The consensus parameters of the version are not in the storage
+    #[display(fmt = "Consensus parameters of the version {_0} are not found")]
+    ConsensusParametersNotFound(u32),
 }
 
 impl From<MemoryStorageError> for RuntimeError<MemoryStorageError> {
     fn from(e: MemoryStorageError) -> Self {
-        RuntimeError::Storage(e)
+        RuntimeError::Storage(e.into())
     }
 }
 
 impl From<MemoryStorageError> for InterpreterError<MemoryStorageError> {
     fn from(e: MemoryStorageError) -> Self {
-        InterpreterError::Storage(e)
+        InterpreterError::Storage(e.into())
     }
 }
 
@@ -86,6 +103,17 @@ struct MemoryStorageInner {
     state_transition_bytecodes: BTreeMap<Bytes32, UploadedBytecode>,
     /// Mapping from state transition bytecode version to hash.
     state_transition_bytecodes_versions: BTreeMap<u32, Bytes32>,
+    /// Versions and checksums of consensus parameters in the order they were set.
+    consensus_parameters_history: Vec<(u32, Bytes32)>,
+    /// Versions and roots of state transition bytecodes in the order they were set.
+    state_transition_history: Vec<(u32, Bytes32)>,
+    /// Mapping from block height to the consensus parameters version that becomes
+    /// active at it.
+    consensus_parameters_activations: BTreeMap<BlockHeight, u32>,
+    /// The nodes of the binary Merkle trees.
+    binary_merkle_nodes: BTreeMap<BinaryMerkleNodeKey, Primitive>,
+    /// Mapping from the identifier of the binary Merkle tree to its metadata.
+    binary_merkle_metadata: BTreeMap<Bytes32, BinaryMerkleTreeMetadata>,
 }
 
 #[derive(Debug, Clone)]
@@ -119,14 +147,23 @@ impl MemoryStorage {
         consensus_parameters_version: u32,
         state_transition_version: u32,
     ) -> Self {
+        let inner = MemoryStorageInner {
+            consensus_parameters_activations: [(
+                BlockHeight::new(0),
+                consensus_parameters_version,
+            )]
+            .into(),
+            ..Default::default()
+        };
+
         Self {
             block_height,
             coinbase,
             consensus_parameters_version,
             state_transition_version,
-            memory: Default::default(),
-            transacted: Default::default(),
-            persisted: Default::default(),
+            memory: inner.clone(),
+            transacted: inner.clone(),
+            persisted: inner,
         }
     }
 
@@ -137,6 +174,13 @@ impl MemoryStorage {
         self.memory.contract_state.iter()
     }
 
+    /// Iterate over all contract balances in storage
+    pub fn all_contract_balances(
+        &self,
+    ) -> impl Iterator<Item = (&ContractsAssetKey, &Word)> {
+        self.memory.balances.iter()
+    }
+
     /// Fetch a mapping from the contract state.
     pub fn contract_state(
         &self,
@@ -177,6 +221,12 @@ impl MemoryStorage {
         self.block_height = block_height;
     }
 
+    #[cfg(feature = "test-helpers")]
+    /// Set the coinbase contract of the current block
+    pub fn set_coinbase(&mut self, coinbase: ContractId) {
+        self.coinbase = coinbase;
+    }
+
     #[cfg(feature = "test-helpers")]
     /// Set the consensus parameters version
     pub fn set_consensus_parameters_version(
@@ -184,6 +234,9 @@ impl MemoryStorage {
         consensus_parameters_version: u32,
     ) {
         self.consensus_parameters_version = consensus_parameters_version;
+        self.memory
+            .consensus_parameters_activations
+            .insert(self.block_height, consensus_parameters_version);
     }
 
     #[cfg(feature = "test-helpers")]
@@ -399,6 +452,25 @@ impl StorageMutate<ContractsAssets> for MemoryStorage {
     }
 }
 
+impl StorageBatchMutate<ContractsAssets> for MemoryStorage {
+    fn init_storage<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a ContractsAssetKey, &'a Word)>,
+    {
+        let mut new_balances = set.map(|(k, v)| (*k, *v)).collect::<BTreeMap<_, _>>();
+        self.memory.balances.append(&mut new_balances);
+        Ok(())
+    }
+
+    fn insert_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a ContractsAssetKey, &'a Word)>,
+    {
+        self.memory.balances.extend(set.map(|(k, v)| (*k, *v)));
+        Ok(())
+    }
+}
+
 impl StorageInspect<ContractsState> for MemoryStorage {
     type Error = MemoryStorageError;
 
@@ -435,6 +507,31 @@ impl StorageMutate<ContractsState> for MemoryStorage {
     }
 }
 
+impl StorageBatchMutate<ContractsState> for MemoryStorage {
+    fn init_storage<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a ContractsStateKey, &'a [u8])>,
+    {
+        // Bulk-building the new entries and merging them in one pass is cheaper than
+        // inserting them into the existing tree one by one.
+        let mut new_state = set
+            .map(|(k, v)| (*k, ContractsStateData::from(v)))
+            .collect::<BTreeMap<_, _>>();
+        self.memory.contract_state.append(&mut new_state);
+        Ok(())
+    }
+
+    fn insert_batch<'a, Iter>(&mut self, set: Iter) -> Result<(), Self::Error>
+    where
+        Iter: Iterator<Item = (&'a ContractsStateKey, &'a [u8])>,
+    {
+        self.memory
+            .contract_state
+            .extend(set.map(|(k, v)| (*k, ContractsStateData::from(v))));
+        Ok(())
+    }
+}
+
 impl StorageWrite<ContractsState> for MemoryStorage {
     fn write_bytes(
         &mut self,
@@ -648,6 +745,84 @@ impl StorageWrite<BlobData> for MemoryStorage {
     }
 }
 
+impl StorageInspect<BinaryMerkleTreeNodes> for MemoryStorage {
+    type Error = MemoryStorageError;
+
+    fn get(
+        &self,
+        key: &<BinaryMerkleTreeNodes as Mappable>::Key,
+    ) -> Result<Option<Cow<'_, Primitive>>, Self::Error> {
+        Ok(self.memory.binary_merkle_nodes.get(key).map(Cow::Borrowed))
+    }
+
+    fn contains_key(
+        &self,
+        key: &<BinaryMerkleTreeNodes as Mappable>::Key,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.memory.binary_merkle_nodes.contains_key(key))
+    }
+}
+
+impl StorageMutate<BinaryMerkleTreeNodes> for MemoryStorage {
+    fn replace(
+        &mut self,
+        key: &<BinaryMerkleTreeNodes as Mappable>::Key,
+        value: &<BinaryMerkleTreeNodes as Mappable>::Value,
+    ) -> Result<Option<Primitive>, Self::Error> {
+        Ok(self.memory.binary_merkle_nodes.insert(*key, *value))
+    }
+
+    fn take(
+        &mut self,
+        key: &<BinaryMerkleTreeNodes as Mappable>::Key,
+    ) -> Result<Option<Primitive>, Self::Error> {
+        Ok(self.memory.binary_merkle_nodes.remove(key))
+    }
+}
+
+impl StorageInspect<BinaryMerkleMetadata> for MemoryStorage {
+    type Error = MemoryStorageError;
+
+    fn get(
+        &self,
+        key: &<BinaryMerkleMetadata as Mappable>::Key,
+    ) -> Result<Option<Cow<'_, BinaryMerkleTreeMetadata>>, Self::Error> {
+        Ok(self
+            .memory
+            .binary_merkle_metadata
+            .get(key)
+            .map(Cow::Borrowed))
+    }
+
+    fn contains_key(
+        &self,
+        key: &<BinaryMerkleMetadata as Mappable>::Key,
+    ) -> Result<bool, Self::Error> {
+        Ok(self.memory.binary_merkle_metadata.contains_key(key))
+    }
+}
+
+impl StorageMutate<BinaryMerkleMetadata> for MemoryStorage {
+    fn replace(
+        &mut self,
+        key: &<BinaryMerkleMetadata as Mappable>::Key,
+        value: &<BinaryMerkleMetadata as Mappable>::Value,
+    ) -> Result<Option<BinaryMerkleTreeMetadata>, Self::Error> {
+        Ok(self.memory.binary_merkle_metadata.insert(*key, *value))
+    }
+
+    fn take(
+        &mut self,
+        key: &<BinaryMerkleMetadata as Mappable>::Key,
+    ) -> Result<Option<BinaryMerkleTreeMetadata>, Self::Error> {
+        Ok(self.memory.binary_merkle_metadata.remove(key))
+    }
+}
+
+impl BinaryMerkleStorage for MemoryStorage {
+    type DataError = MemoryStorageError;
+}
+
 impl ContractsAssetsStorage for MemoryStorage {}
 
 impl InterpreterStorage for MemoryStorage {
@@ -686,6 +861,18 @@ impl InterpreterStorage for MemoryStorage {
         version: u32,
         consensus_parameters: &ConsensusParameters,
     ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        let checksum = consensus_parameters
+            .checksum()
+            .map_err(|_| MemoryStorageError::ConsensusParametersSerialization)?;
+        self.memory
+            .consensus_parameters_history
+            .push((version, checksum));
+        // The upgrade takes effect starting from the next block.
+        if let Some(next_height) = self.block_height.succ() {
+            self.memory
+                .consensus_parameters_activations
+                .insert(next_height, version);
+        }
         Ok(self
             .memory
             .consensus_parameters_versions
@@ -697,12 +884,27 @@ impl InterpreterStorage for MemoryStorage {
         version: u32,
         bytecode: &Bytes32,
     ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.memory
+            .state_transition_history
+            .push((version, *bytecode));
         Ok(self
             .memory
             .state_transition_bytecodes_versions
             .insert(version, *bytecode))
     }
 
+    fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Ok(Some(self.memory.consensus_parameters_history.clone()))
+    }
+
+    fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        Ok(Some(self.memory.state_transition_history.clone()))
+    }
+
     fn contract_state_range(
         &self,
         id: &ContractId,
@@ -803,6 +1005,32 @@ impl InterpreterStorage for MemoryStorage {
     }
 }
 
+impl ConsensusParametersProvider for MemoryStorage {
+    type Error = MemoryStorageError;
+
+    fn params_at(
+        &self,
+        at: ConsensusParametersAt,
+    ) -> Result<Cow<'_, ConsensusParameters>, Self::Error> {
+        let version = match at {
+            ConsensusParametersAt::Height(height) => self
+                .memory
+                .consensus_parameters_activations
+                .range(..=height)
+                .next_back()
+                .map(|(_, version)| *version)
+                .unwrap_or(self.consensus_parameters_version),
+            ConsensusParametersAt::Version(version) => version,
+        };
+
+        self.memory
+            .consensus_parameters_versions
+            .get(&version)
+            .map(Cow::Borrowed)
+            .ok_or(MemoryStorageError::ConsensusParametersNotFound(version))
+    }
+}
+
 impl PredicateStorageRequirements for MemoryStorage {
     fn storage_error_to_string(error: Self::Error) -> alloc::string::String {
         alloc::format!("{:?}", error)
@@ -824,8 +1052,17 @@ fn add_one(a: &mut [u8; 32]) -> bool {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use super::*;
-    use alloc::vec;
+    use alloc::{
+        format,
+        string::ToString,
+        vec,
+    };
+    use fuel_storage::StorageAsMut;
+    use fuel_tx::StorageSlot;
+    use fuel_types::AssetId;
     use test_case::test_case;
 
     const fn key(k: u8) -> [u8; 32] {
@@ -908,4 +1145,186 @@ mod tests {
 
         bytes_read
     }
+
+    fn state_root(storage: &MemoryStorage, id: &ContractId) -> Bytes32 {
+        let slots = storage
+            .all_contract_state()
+            .filter(|(key, _)| key.contract_id() == id)
+            .map(|(key, value)| {
+                let value = Bytes32::try_from(value.as_ref()).expect("32 bytes value");
+                StorageSlot::new(*key.state_key(), value)
+            })
+            .collect::<Vec<_>>();
+        Contract::initial_state_root(slots.iter())
+    }
+
+    fn slots(count: u32) -> Vec<StorageSlot> {
+        (0..count)
+            .map(|i| {
+                let key = Hasher::hash(i.to_be_bytes());
+                let value = Hasher::hash(key);
+                StorageSlot::new(key, value)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deploy_contract_with_id__batched_slots_match_per_key_insertion() {
+        // Given
+        let slots = slots(1000);
+        let contract = Contract::from(vec![1u8; 32]);
+        let id = ContractId::from([2u8; 32]);
+        let mut per_key = MemoryStorage::default();
+        per_key.storage_contract_insert(&id, &contract).unwrap();
+        for slot in &slots {
+            per_key
+                .contract_state_insert(&id, slot.key(), slot.value().as_ref())
+                .unwrap();
+        }
+        let mut batched = MemoryStorage::default();
+
+        // When
+        batched
+            .deploy_contract_with_id(&slots, &contract, &id)
+            .unwrap();
+
+        // Then
+        assert_eq!(batched.memory, per_key.memory);
+        assert_eq!(state_root(&batched, &id), state_root(&per_key, &id));
+        assert_eq!(
+            state_root(&batched, &id),
+            Contract::initial_state_root(slots.iter())
+        );
+    }
+
+    #[test]
+    fn init_storage__keeps_state_of_other_contracts() {
+        // Given
+        let slots = slots(1000);
+        let contract = Contract::from(vec![1u8; 32]);
+        let first = ContractId::from([2u8; 32]);
+        let second = ContractId::from([3u8; 32]);
+        let mut storage = MemoryStorage::default();
+        storage
+            .deploy_contract_with_id(&slots[..500], &contract, &first)
+            .unwrap();
+        let first_root = state_root(&storage, &first);
+
+        // When
+        storage
+            .deploy_contract_with_id(&slots[500..], &contract, &second)
+            .unwrap();
+
+        // Then
+        assert_eq!(state_root(&storage, &first), first_root);
+        assert_eq!(
+            state_root(&storage, &second),
+            Contract::initial_state_root(slots[500..].iter())
+        );
+    }
+
+    #[test]
+    fn remove_batch__removes_only_given_keys() {
+        // Given
+        let slots = slots(10);
+        let contract = Contract::from(vec![1u8; 32]);
+        let id = ContractId::from([2u8; 32]);
+        let mut storage = MemoryStorage::default();
+        storage
+            .deploy_contract_with_id(&slots, &contract, &id)
+            .unwrap();
+        let removed = slots[..5]
+            .iter()
+            .map(|slot| ContractsStateKey::new(&id, slot.key()))
+            .collect::<Vec<_>>();
+
+        // When
+        storage
+            .storage_as_mut::<ContractsState>()
+            .remove_batch(removed.iter())
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            state_root(&storage, &id),
+            Contract::initial_state_root(slots[5..].iter())
+        );
+    }
+
+    #[test]
+    fn all_contract_state__groups_slots_by_contract() {
+        // Given
+        let first = ContractId::from([1u8; 32]);
+        let second = ContractId::from([2u8; 32]);
+        let mut storage = MemoryStorage::default();
+        for (contract, k) in [(second, 1), (first, 2), (second, 0), (first, 1)] {
+            storage
+                .storage_as_mut::<ContractsState>()
+                .insert(&(contract, Bytes32::from(key(k))).into(), &[k])
+                .unwrap();
+        }
+
+        // When
+        let order = storage
+            .all_contract_state()
+            .map(|(key, _)| (*key.contract_id(), key.state_key()[31]))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            order,
+            vec![(first, 1), (first, 2), (second, 0), (second, 1)]
+        );
+    }
+
+    #[test]
+    fn all_contract_balances__groups_assets_by_contract() {
+        // Given
+        let first = ContractId::from([1u8; 32]);
+        let second = ContractId::from([2u8; 32]);
+        let low = AssetId::from([0u8; 32]);
+        let high = AssetId::from([u8::MAX; 32]);
+        let mut storage = MemoryStorage::default();
+        for (contract, asset) in
+            [(second, low), (first, high), (second, high), (first, low)]
+        {
+            storage
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&(contract, asset).into(), &1)
+                .unwrap();
+        }
+
+        // When
+        let order = storage
+            .all_contract_balances()
+            .map(|(key, _)| (*key.contract_id(), *key.asset_id()))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            order,
+            vec![(first, low), (first, high), (second, low), (second, high)]
+        );
+    }
+
+    #[test]
+    fn contracts_asset_key__displays_sub_keys() {
+        // Given
+        let mut contract_id = [0u8; 32];
+        contract_id[0] = 0xab;
+        let mut asset_id = [0u8; 32];
+        asset_id[31] = 0x01;
+        let key = ContractsAssetKey::from((
+            ContractId::from(contract_id),
+            AssetId::from(asset_id),
+        ));
+
+        // When
+        let full = key.to_string();
+        let compact = format!("{key:#8}");
+
+        // Then
+        assert_eq!(full, format!("ab{}:{}01", "00".repeat(31), "00".repeat(31)));
+        assert_eq!(compact, "0xab000000:0x00000001");
+    }
 }
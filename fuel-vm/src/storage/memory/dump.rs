@@ -0,0 +1,730 @@
+//! The stable representation of the [`MemoryStorage`].
+//!
+//! The in-memory tables are keyed by the types of this crate, whose encoding may
+//! change between the versions, e.g. how the `(ContractId, AssetId)` pair of the
+//! [`ContractsAssetKey`] is packed. A [`StorageDump`] lists every entry of every
+//! table as the `(table, key, value)` triple of bytes with the encoding documented
+//! on the [`TableId`], and carries the [`version`](StorageDump::version) of that
+//! encoding. So the dump can be persisted, and the dumps of the older versions are
+//! brought to the current one with [`migrate`] before being loaded.
+
+use super::{
+    MemoryStorage,
+    MemoryStorageInner,
+};
+use crate::storage::{
+    BinaryMerkleNodeKey,
+    BinaryMerkleTreeMetadata,
+    ContractsAssetKey,
+    ContractsStateData,
+    ContractsStateKey,
+    UploadedBytecode,
+};
+
+use alloc::vec::Vec;
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+};
+use fuel_types::{
+    BlobId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+/// The version of the [`StorageDump`] layout produced by this crate.
+///
+/// - `1`: the layout without the [`TableId::ConsensusParametersActivations`].
+/// - `2`: the layout without the [`TableId::BinaryMerkleTreeNodes`] and the
+///   [`TableId::BinaryMerkleMetadata`].
+/// - `3`: the current layout.
+pub const STORAGE_DUMP_VERSION: u32 = 3;
+
+/// The tables of the [`MemoryStorage`].
+///
+/// The integers are encoded in the big-endian order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum TableId {
+    /// The key is the `ContractId`, the value is the bytecode.
+    ContractsRawCode = 0,
+    /// The key is the `ContractId` followed by the `AssetId`, the value is the
+    /// balance as `u64`.
+    ContractsAssets = 1,
+    /// The key is the `ContractId` followed by the state key, the value is the
+    /// state data.
+    ContractsState = 2,
+    /// The key is the `BlobId`, the value is the blob bytes.
+    BlobData = 3,
+    /// The key is the root of the bytecode, the value is `0` followed by the
+    /// bytecode if it is completed, or `1` followed by the number of uploaded
+    /// subsections as `u16` and the bytecode otherwise.
+    UploadedBytecodes = 4,
+    /// The key is the version as `u32`, the value is the consensus parameters
+    /// serialized with `postcard`.
+    ConsensusParametersVersions = 5,
+    /// The key is the version as `u32`, the value is the root of the bytecode.
+    StateTransitionBytecodeVersions = 6,
+    /// The key is the position in the history as `u32`, the value is the version
+    /// as `u32` followed by the checksum.
+    ConsensusParametersHistory = 7,
+    /// The key is the position in the history as `u32`, the value is the version
+    /// as `u32` followed by the root of the bytecode.
+    StateTransitionHistory = 8,
+    /// The key is the block height as `u32`, the value is the consensus parameters
+    /// version activated at it as `u32`.
+    ConsensusParametersActivations = 9,
+    /// The key is the identifier of the tree followed by the in-order index of the
+    /// node as `u64`, the value is the height of the node as `u64` followed by its
+    /// hash.
+    BinaryMerkleTreeNodes = 10,
+    /// The key is the identifier of the tree, the value is the number of the leaves
+    /// as `u64` followed by the root.
+    BinaryMerkleMetadata = 11,
+}
+
+/// An entry of the table of the [`StorageDump`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageDumpEntry {
+    /// The table of the entry.
+    pub table: TableId,
+    /// The encoded key.
+    pub key: Vec<u8>,
+    /// The encoded value.
+    pub value: Vec<u8>,
+}
+
+/// The versioned representation of the [`MemoryStorage`], independent of the
+/// encoding of its keys in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageDump {
+    /// The version of the layout of the dump.
+    pub version: u32,
+    /// The current block height.
+    pub block_height: BlockHeight,
+    /// The coinbase contract.
+    pub coinbase: ContractId,
+    /// The current consensus parameters version.
+    pub consensus_parameters_version: u32,
+    /// The current state transition version.
+    pub state_transition_version: u32,
+    /// The entries of all the tables.
+    pub entries: Vec<StorageDumpEntry>,
+}
+
+/// Errors arising from loading or migrating the [`StorageDump`].
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum StorageDumpError {
+    /// The version of the dump is not the expected one
+    #[display(fmt = "The dump has version {actual}, expected {expected}")]
+    VersionMismatch {
+        /// The expected version
+        expected: u32,
+        /// The version of the dump
+        actual: u32,
+    },
+    /// There is no migration between the versions
+    #[display(fmt = "Migration from version {from} to {to} is not supported")]
+    UnsupportedMigration {
+        /// The version of the dump
+        from: u32,
+        /// The requested version
+        to: u32,
+    },
+    /// The key of the entry has the wrong encoding
+    #[display(fmt = "Invalid key of the length {_1} in the table {_0:?}")]
+    InvalidKey(TableId, usize),
+    /// The value of the entry has the wrong encoding
+    #[display(fmt = "Invalid value of the length {_1} in the table {_0:?}")]
+    InvalidValue(TableId, usize),
+}
+
+impl StorageDump {
+    /// Returns the entries of the `table`.
+    pub fn table(&self, table: TableId) -> impl Iterator<Item = &StorageDumpEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.table == table)
+    }
+}
+
+impl MemoryStorage {
+    /// Dumps the memory state of the storage in the current
+    /// [`STORAGE_DUMP_VERSION`].
+    pub fn dump(&self) -> StorageDump {
+        let inner = &self.memory;
+        let mut entries = Vec::new();
+        let mut push = |table, key: &[u8], value: &[u8]| {
+            entries.push(StorageDumpEntry {
+                table,
+                key: key.to_vec(),
+                value: value.to_vec(),
+            })
+        };
+
+        for (id, contract) in &inner.contracts {
+            push(TableId::ContractsRawCode, id.as_ref(), contract.as_ref());
+        }
+        for (key, balance) in &inner.balances {
+            push(
+                TableId::ContractsAssets,
+                key.as_ref(),
+                &balance.to_be_bytes(),
+            );
+        }
+        for (key, data) in &inner.contract_state {
+            push(TableId::ContractsState, key.as_ref(), data.as_ref());
+        }
+        for (id, blob) in &inner.blobs {
+            push(TableId::BlobData, id.as_ref(), blob.as_ref());
+        }
+        for (root, bytecode) in &inner.state_transition_bytecodes {
+            push(
+                TableId::UploadedBytecodes,
+                root.as_ref(),
+                &encode_uploaded_bytecode(bytecode),
+            );
+        }
+        for (version, parameters) in &inner.consensus_parameters_versions {
+            let parameters = postcard::to_allocvec(parameters)
+                .expect("The consensus parameters are serializable");
+            push(
+                TableId::ConsensusParametersVersions,
+                &version.to_be_bytes(),
+                &parameters,
+            );
+        }
+        for (version, root) in &inner.state_transition_bytecodes_versions {
+            push(
+                TableId::StateTransitionBytecodeVersions,
+                &version.to_be_bytes(),
+                root.as_ref(),
+            );
+        }
+        for (table, history) in [
+            (
+                TableId::ConsensusParametersHistory,
+                &inner.consensus_parameters_history,
+            ),
+            (
+                TableId::StateTransitionHistory,
+                &inner.state_transition_history,
+            ),
+        ] {
+            for (position, (version, hash)) in (0u32..).zip(history) {
+                let value = [&version.to_be_bytes()[..], hash.as_ref()].concat();
+                push(table, &position.to_be_bytes(), &value);
+            }
+        }
+        for (height, version) in &inner.consensus_parameters_activations {
+            push(
+                TableId::ConsensusParametersActivations,
+                &u32::from(*height).to_be_bytes(),
+                &version.to_be_bytes(),
+            );
+        }
+        for (key, (height, hash)) in &inner.binary_merkle_nodes {
+            let key = [key.tree().as_ref(), &key.index().to_be_bytes()].concat();
+            let value = [&height.to_be_bytes()[..], hash].concat();
+            push(TableId::BinaryMerkleTreeNodes, &key, &value);
+        }
+        for (tree, metadata) in &inner.binary_merkle_metadata {
+            let value = [
+                &metadata.leaves_count.to_be_bytes()[..],
+                metadata.root.as_ref(),
+            ]
+            .concat();
+            push(TableId::BinaryMerkleMetadata, tree.as_ref(), &value);
+        }
+
+        StorageDump {
+            version: STORAGE_DUMP_VERSION,
+            block_height: self.block_height,
+            coinbase: self.coinbase,
+            consensus_parameters_version: self.consensus_parameters_version,
+            state_transition_version: self.state_transition_version,
+            entries,
+        }
+    }
+}
+
+impl TryFrom<StorageDump> for MemoryStorage {
+    type Error = StorageDumpError;
+
+    /// Loads the dump of the current [`STORAGE_DUMP_VERSION`] as the memory,
+    /// transacted and persisted states of the storage. The dumps of the older
+    /// versions must be [`migrate`]d first.
+    fn try_from(dump: StorageDump) -> Result<Self, Self::Error> {
+        if dump.version != STORAGE_DUMP_VERSION {
+            return Err(StorageDumpError::VersionMismatch {
+                expected: STORAGE_DUMP_VERSION,
+                actual: dump.version,
+            })
+        }
+
+        let mut inner = MemoryStorageInner::default();
+        for StorageDumpEntry { table, key, value } in dump.entries {
+            let invalid_key = || StorageDumpError::InvalidKey(table, key.len());
+            let invalid_value = || StorageDumpError::InvalidValue(table, value.len());
+
+            match table {
+                TableId::ContractsRawCode => {
+                    let id = ContractId::try_from(key.as_slice())
+                        .map_err(|_| invalid_key())?;
+                    inner.contracts.insert(id, Contract::from(value));
+                }
+                TableId::ContractsAssets => {
+                    let key =
+                        ContractsAssetKey::from_slice(&key).map_err(|_| invalid_key())?;
+                    let balance = Word::from_be_bytes(
+                        value.as_slice().try_into().map_err(|_| invalid_value())?,
+                    );
+                    inner.balances.insert(key, balance);
+                }
+                TableId::ContractsState => {
+                    let key =
+                        ContractsStateKey::from_slice(&key).map_err(|_| invalid_key())?;
+                    inner
+                        .contract_state
+                        .insert(key, ContractsStateData::from(value));
+                }
+                TableId::BlobData => {
+                    let id =
+                        BlobId::try_from(key.as_slice()).map_err(|_| invalid_key())?;
+                    inner.blobs.insert(id, value.into());
+                }
+                TableId::UploadedBytecodes => {
+                    let root =
+                        Bytes32::try_from(key.as_slice()).map_err(|_| invalid_key())?;
+                    let bytecode =
+                        decode_uploaded_bytecode(&value).ok_or_else(invalid_value)?;
+                    inner.state_transition_bytecodes.insert(root, bytecode);
+                }
+                TableId::ConsensusParametersVersions => {
+                    let version = decode_u32(&key).ok_or_else(invalid_key)?;
+                    let parameters: ConsensusParameters =
+                        postcard::from_bytes(&value).map_err(|_| invalid_value())?;
+                    inner
+                        .consensus_parameters_versions
+                        .insert(version, parameters);
+                }
+                TableId::StateTransitionBytecodeVersions => {
+                    let version = decode_u32(&key).ok_or_else(invalid_key)?;
+                    let root = Bytes32::try_from(value.as_slice())
+                        .map_err(|_| invalid_value())?;
+                    inner
+                        .state_transition_bytecodes_versions
+                        .insert(version, root);
+                }
+                TableId::ConsensusParametersHistory | TableId::StateTransitionHistory => {
+                    // The entries are dumped in the order of the positions.
+                    decode_u32(&key).ok_or_else(invalid_key)?;
+                    let version = value
+                        .get(..4)
+                        .and_then(decode_u32)
+                        .ok_or_else(invalid_value)?;
+                    let hash = value
+                        .get(4..)
+                        .and_then(|hash| Bytes32::try_from(hash).ok())
+                        .ok_or_else(invalid_value)?;
+                    let history = if table == TableId::ConsensusParametersHistory {
+                        &mut inner.consensus_parameters_history
+                    } else {
+                        &mut inner.state_transition_history
+                    };
+                    history.push((version, hash));
+                }
+                TableId::ConsensusParametersActivations => {
+                    let height = decode_u32(&key).ok_or_else(invalid_key)?;
+                    let version = decode_u32(&value).ok_or_else(invalid_value)?;
+                    inner
+                        .consensus_parameters_activations
+                        .insert(height.into(), version);
+                }
+                TableId::BinaryMerkleTreeNodes => {
+                    let tree = key
+                        .get(..32)
+                        .and_then(|tree| Bytes32::try_from(tree).ok())
+                        .ok_or_else(invalid_key)?;
+                    let index =
+                        key.get(32..).and_then(decode_u64).ok_or_else(invalid_key)?;
+                    let height = value
+                        .get(..8)
+                        .and_then(decode_u64)
+                        .ok_or_else(invalid_value)?;
+                    let hash = value
+                        .get(8..)
+                        .and_then(|hash| hash.try_into().ok())
+                        .ok_or_else(invalid_value)?;
+                    inner
+                        .binary_merkle_nodes
+                        .insert(BinaryMerkleNodeKey::new(tree, index), (height, hash));
+                }
+                TableId::BinaryMerkleMetadata => {
+                    let tree =
+                        Bytes32::try_from(key.as_slice()).map_err(|_| invalid_key())?;
+                    let leaves_count = value
+                        .get(..8)
+                        .and_then(decode_u64)
+                        .ok_or_else(invalid_value)?;
+                    let root = value
+                        .get(8..)
+                        .and_then(|root| Bytes32::try_from(root).ok())
+                        .ok_or_else(invalid_value)?;
+                    inner
+                        .binary_merkle_metadata
+                        .insert(tree, BinaryMerkleTreeMetadata { leaves_count, root });
+                }
+            }
+        }
+
+        Ok(Self {
+            block_height: dump.block_height,
+            coinbase: dump.coinbase,
+            consensus_parameters_version: dump.consensus_parameters_version,
+            state_transition_version: dump.state_transition_version,
+            memory: inner.clone(),
+            transacted: inner.clone(),
+            persisted: inner,
+        })
+    }
+}
+
+/// Migrates the `dump` of the `from_version` layout to the `to_version` one.
+///
+/// Only the migrations to the newer versions, up to the [`STORAGE_DUMP_VERSION`],
+/// are supported.
+pub fn migrate(
+    mut dump: StorageDump,
+    from_version: u32,
+    to_version: u32,
+) -> Result<StorageDump, StorageDumpError> {
+    if dump.version != from_version {
+        return Err(StorageDumpError::VersionMismatch {
+            expected: from_version,
+            actual: dump.version,
+        })
+    }
+    if from_version == 0 || from_version > to_version || to_version > STORAGE_DUMP_VERSION
+    {
+        return Err(StorageDumpError::UnsupportedMigration {
+            from: from_version,
+            to: to_version,
+        })
+    }
+
+    while dump.version < to_version {
+        dump = match dump.version {
+            1 => migrate_v1_to_v2(dump),
+            2 => migrate_v2_to_v3(dump),
+            version => {
+                return Err(StorageDumpError::UnsupportedMigration {
+                    from: version,
+                    to: to_version,
+                })
+            }
+        };
+    }
+
+    Ok(dump)
+}
+
+/// The storage of the version `1` didn't track the activations of the consensus
+/// parameters, so the current version is active since the genesis, as in
+/// [`MemoryStorage::new_with_versions`].
+fn migrate_v1_to_v2(mut dump: StorageDump) -> StorageDump {
+    if dump
+        .table(TableId::ConsensusParametersActivations)
+        .next()
+        .is_none()
+    {
+        dump.entries.push(StorageDumpEntry {
+            table: TableId::ConsensusParametersActivations,
+            key: 0u32.to_be_bytes().to_vec(),
+            value: dump.consensus_parameters_version.to_be_bytes().to_vec(),
+        });
+    }
+    dump.version = 2;
+    dump
+}
+
+/// The storage of the version `2` didn't have the binary Merkle trees, so there is
+/// nothing to convert.
+fn migrate_v2_to_v3(mut dump: StorageDump) -> StorageDump {
+    dump.version = 3;
+    dump
+}
+
+fn encode_uploaded_bytecode(bytecode: &UploadedBytecode) -> Vec<u8> {
+    match bytecode {
+        UploadedBytecode::Completed(bytecode) => [&[0u8][..], bytecode].concat(),
+        UploadedBytecode::Uncompleted {
+            bytecode,
+            uploaded_subsections_number,
+        } => [
+            &[1u8][..],
+            &uploaded_subsections_number.to_be_bytes(),
+            bytecode,
+        ]
+        .concat(),
+    }
+}
+
+fn decode_uploaded_bytecode(value: &[u8]) -> Option<UploadedBytecode> {
+    match value.split_first()? {
+        (0, bytecode) => Some(UploadedBytecode::Completed(bytecode.to_vec())),
+        (1, rest) => {
+            let uploaded_subsections_number =
+                u16::from_be_bytes(rest.get(..2)?.try_into().ok()?);
+            Some(UploadedBytecode::Uncompleted {
+                bytecode: rest.get(2..)?.to_vec(),
+                uploaded_subsections_number,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn decode_u32(bytes: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
+fn decode_u64(bytes: &[u8]) -> Option<u64> {
+    Some(u64::from_be_bytes(bytes.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::storage::{
+        BinaryMerkleStorage,
+        BlobData,
+        ContractsAssets,
+        ContractsRawCode,
+        ContractsState,
+        InterpreterStorage,
+    };
+    use alloc::vec;
+    use fuel_merkle::sparse::{
+        in_memory::MerkleTree,
+        MerkleTreeKey,
+    };
+    use fuel_storage::StorageAsMut;
+    use fuel_tx::StorageSlot;
+    use fuel_types::AssetId;
+
+    fn populated_storage() -> MemoryStorage {
+        let mut storage = MemoryStorage::default();
+        for seed in 1u8..=3 {
+            let id = ContractId::new([seed; 32]);
+            storage
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(&id, &[seed; 64])
+                .unwrap();
+            for slot in 0u8..4 {
+                let key = Bytes32::new([slot; 32]);
+                storage
+                    .storage_as_mut::<ContractsState>()
+                    .insert(&(&id, &key).into(), &[slot.wrapping_mul(seed); 32])
+                    .unwrap();
+                let asset_id = AssetId::new([slot; 32]);
+                storage
+                    .storage_as_mut::<ContractsAssets>()
+                    .insert(&(&id, &asset_id).into(), &Word::from(slot).pow(seed.into()))
+                    .unwrap();
+            }
+        }
+        storage
+            .storage_as_mut::<BlobData>()
+            .insert(&BlobId::new([7; 32]), &[7; 100])
+            .unwrap();
+        storage
+            .set_consensus_parameters(1, &ConsensusParameters::default())
+            .unwrap();
+        storage.state_transition_bytecodes_mut().insert(
+            Bytes32::new([8; 32]),
+            UploadedBytecode::Completed(vec![8; 40]),
+        );
+        storage.state_transition_bytecodes_mut().insert(
+            Bytes32::new([9; 32]),
+            UploadedBytecode::Uncompleted {
+                bytecode: vec![9; 20],
+                uploaded_subsections_number: 2,
+            },
+        );
+        storage
+            .set_state_transition_bytecode(1, &Bytes32::new([8; 32]))
+            .unwrap();
+        for leaf in 0u8..5 {
+            storage
+                .binary_merkle_push(&Bytes32::new([10; 32]), &[leaf; 16])
+                .unwrap();
+        }
+        storage
+    }
+
+    /// The code, state and balance roots of every contract.
+    fn roots(storage: &MemoryStorage) -> Vec<(Bytes32, Bytes32, Bytes32)> {
+        storage
+            .memory
+            .contracts
+            .iter()
+            .map(|(id, contract)| {
+                let slots = storage
+                    .all_contract_state()
+                    .filter(|(key, _)| key.contract_id() == id)
+                    .map(|(key, value)| {
+                        let value = Bytes32::try_from(value.as_ref()).unwrap();
+                        StorageSlot::new(*key.state_key(), value)
+                    })
+                    .collect::<Vec<_>>();
+                let balances = storage
+                    .all_contract_balances()
+                    .filter(|(key, _)| key.contract_id() == id)
+                    .map(|(key, balance)| {
+                        (MerkleTreeKey::new(key.asset_id()), balance.to_be_bytes())
+                    });
+                (
+                    contract.root(),
+                    Contract::initial_state_root(slots.iter()),
+                    MerkleTree::root_from_set(balances).into(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dump__round_trip__preserves_the_storage_and_the_roots() {
+        // Given
+        let storage = populated_storage();
+
+        // When
+        let dump = storage.dump();
+        let restored = MemoryStorage::try_from(dump.clone()).unwrap();
+
+        // Then
+        assert_eq!(dump.version, STORAGE_DUMP_VERSION);
+        assert_eq!(restored.memory, storage.memory);
+        assert_eq!(restored.persisted, storage.memory);
+        assert_eq!(roots(&restored), roots(&storage));
+        assert_eq!(roots(&restored).len(), 3);
+        assert_eq!(restored.block_height, storage.block_height);
+        assert_eq!(restored.coinbase, storage.coinbase);
+        assert_eq!(restored.dump(), dump);
+    }
+
+    #[test]
+    fn dump__balance_key__is_the_contract_id_followed_by_the_asset_id() {
+        // Given
+        let mut storage = MemoryStorage::default();
+        let contract_id = ContractId::new([1; 32]);
+        let asset_id = AssetId::new([2; 32]);
+        storage
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&(&contract_id, &asset_id).into(), &5)
+            .unwrap();
+
+        // When
+        let dump = storage.dump();
+
+        // Then
+        let entries = dump.table(TableId::ContractsAssets).collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![&StorageDumpEntry {
+                table: TableId::ContractsAssets,
+                key: [[1; 32], [2; 32]].concat(),
+                value: 5u64.to_be_bytes().to_vec(),
+            }]
+        );
+    }
+
+    #[test]
+    fn migrate__v1__adds_the_consensus_parameters_activation() {
+        // Given
+        let storage =
+            MemoryStorage::new_with_versions(10.into(), ContractId::zeroed(), 3, 0);
+        let mut dump = storage.dump();
+        dump.entries
+            .retain(|entry| entry.table != TableId::ConsensusParametersActivations);
+        dump.version = 1;
+        assert_eq!(
+            MemoryStorage::try_from(dump.clone()).unwrap_err(),
+            StorageDumpError::VersionMismatch {
+                expected: STORAGE_DUMP_VERSION,
+                actual: 1
+            }
+        );
+
+        // When
+        let migrated = migrate(dump, 1, STORAGE_DUMP_VERSION).unwrap();
+
+        // Then
+        assert_eq!(migrated, storage.dump());
+        let restored = MemoryStorage::try_from(migrated).unwrap();
+        assert_eq!(restored.memory, storage.memory);
+    }
+
+    #[test]
+    fn migrate__to_the_same_version__is_noop() {
+        let dump = populated_storage().dump();
+
+        let migrated = migrate(dump.clone(), STORAGE_DUMP_VERSION, STORAGE_DUMP_VERSION);
+
+        assert_eq!(migrated, Ok(dump));
+    }
+
+    #[test]
+    fn migrate__downgrade__fails() {
+        let dump = populated_storage().dump();
+
+        let result = migrate(dump, STORAGE_DUMP_VERSION, 1);
+
+        assert_eq!(
+            result,
+            Err(StorageDumpError::UnsupportedMigration {
+                from: STORAGE_DUMP_VERSION,
+                to: 1
+            })
+        );
+    }
+
+    #[test]
+    fn migrate__wrong_from_version__fails() {
+        let dump = populated_storage().dump();
+
+        let result = migrate(dump, 1, STORAGE_DUMP_VERSION);
+
+        assert_eq!(
+            result,
+            Err(StorageDumpError::VersionMismatch {
+                expected: 1,
+                actual: STORAGE_DUMP_VERSION
+            })
+        );
+    }
+
+    #[test]
+    fn try_from__invalid_key__fails() {
+        let mut dump = MemoryStorage::default().dump();
+        dump.entries.push(StorageDumpEntry {
+            table: TableId::ContractsAssets,
+            key: vec![1; 32],
+            value: 5u64.to_be_bytes().to_vec(),
+        });
+
+        let result = MemoryStorage::try_from(dump);
+
+        assert_eq!(
+            result.unwrap_err(),
+            StorageDumpError::InvalidKey(TableId::ContractsAssets, 32)
+        );
+    }
+}
@@ -17,6 +17,7 @@ use core::fmt::Debug;
 use fuel_asm::Word;
 use fuel_storage::{
     Mappable,
+    StorageBatchMutate,
     StorageInspect,
     StorageMutate,
     StorageRead,
@@ -65,14 +66,20 @@ impl<D> PredicateStorage<D> {
 }
 
 /// Errors that happen when using predicate storage
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PredicateStorageError {
     /// Storage operation is unavailable in predicate context.
+    #[display(fmt = "Storage operation is unavailable in predicate context")]
     UnsupportedStorageOperation,
     /// An storage error occurred
+    #[display(fmt = "{_0}")]
     StorageError(String),
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for PredicateStorageError {}
+
 impl From<PredicateStorageError> for InterpreterError<PredicateStorageError> {
     fn from(val: PredicateStorageError) -> Self {
         let rt: RuntimeError<PredicateStorageError> = val.into();
@@ -82,7 +89,7 @@ impl From<PredicateStorageError> for InterpreterError<PredicateStorageError> {
 
 impl From<PredicateStorageError> for RuntimeError<PredicateStorageError> {
     fn from(val: PredicateStorageError) -> Self {
-        RuntimeError::Storage(val)
+        RuntimeError::Storage(val.into())
     }
 }
 
@@ -236,6 +243,13 @@ where
     }
 }
 
+impl<Type, D> StorageBatchMutate<Type> for PredicateStorage<D>
+where
+    Type: Mappable,
+    Self: StorageInspect<Type, Error = PredicateStorageError>,
+{
+}
+
 impl<D> StorageSize<ContractsRawCode> for PredicateStorage<D> {
     fn size_of_value(&self, _key: &ContractId) -> Result<Option<usize>, Self::Error> {
         Err(Self::Error::UnsupportedStorageOperation)
@@ -0,0 +1,376 @@
+//! The storage adapter for the read-only execution of the transactions.
+
+use crate::{
+    prelude::{
+        InterpreterError,
+        RuntimeError,
+    },
+    storage::{
+        ContractsAssetsStorage,
+        ContractsStateData,
+        InterpreterStorage,
+    },
+};
+use alloc::{
+    borrow::Cow,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    PanicReason,
+    Word,
+};
+use fuel_storage::{
+    Mappable,
+    StorageBatchMutate,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::ConsensusParameters;
+use fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+};
+
+/// The storage that borrows the underlying storage immutably, so the transactions
+/// can be executed against it without changing it, e.g. to simulate a script.
+///
+/// The reads are delegated to the underlying storage, while the writes fail with
+/// the [`ReadOnlyStorageError::WriteInReadOnlyMode`], which the VM turns into the
+/// [`PanicReason::WriteInReadOnlyMode`].
+#[derive(Debug)]
+pub struct ReadOnlyStorage<'a, S> {
+    storage: &'a S,
+}
+
+impl<'a, S> ReadOnlyStorage<'a, S> {
+    /// Creates the read-only view of the `storage`.
+    pub fn new(storage: &'a S) -> Self {
+        Self { storage }
+    }
+
+    /// Returns the underlying storage.
+    pub fn storage(&self) -> &'a S {
+        self.storage
+    }
+}
+
+impl<S> Clone for ReadOnlyStorage<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for ReadOnlyStorage<'_, S> {}
+
+/// Errors that happen when using the read-only storage.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReadOnlyStorageError<E> {
+    /// The storage was written in the read-only mode.
+    WriteInReadOnlyMode,
+    /// The error of the underlying storage.
+    Storage(E),
+}
+
+impl<E> core::fmt::Display for ReadOnlyStorageError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WriteInReadOnlyMode => {
+                write!(f, "The storage can't be written in the read-only mode")
+            }
+            Self::Storage(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ReadOnlyStorageError<E> where
+    E: core::fmt::Debug + core::fmt::Display
+{
+}
+
+impl<E> From<ReadOnlyStorageError<E>> for RuntimeError<ReadOnlyStorageError<E>> {
+    fn from(error: ReadOnlyStorageError<E>) -> Self {
+        match error {
+            ReadOnlyStorageError::WriteInReadOnlyMode => {
+                RuntimeError::Recoverable(PanicReason::WriteInReadOnlyMode)
+            }
+            error @ ReadOnlyStorageError::Storage(_) => {
+                RuntimeError::Storage(error.into())
+            }
+        }
+    }
+}
+
+impl<E> From<ReadOnlyStorageError<E>> for InterpreterError<ReadOnlyStorageError<E>> {
+    fn from(error: ReadOnlyStorageError<E>) -> Self {
+        InterpreterError::Storage(error.into())
+    }
+}
+
+impl<Type, S> StorageInspect<Type> for ReadOnlyStorage<'_, S>
+where
+    Type: Mappable,
+    S: StorageInspect<Type>,
+{
+    type Error = ReadOnlyStorageError<S::Error>;
+
+    fn get(
+        &self,
+        key: &Type::Key,
+    ) -> Result<Option<Cow<'_, Type::OwnedValue>>, Self::Error> {
+        self.storage.get(key).map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn contains_key(&self, key: &Type::Key) -> Result<bool, Self::Error> {
+        self.storage
+            .contains_key(key)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+}
+
+impl<Type, S> StorageMutate<Type> for ReadOnlyStorage<'_, S>
+where
+    Type: Mappable,
+    S: StorageInspect<Type>,
+{
+    fn replace(
+        &mut self,
+        _key: &Type::Key,
+        _value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+
+    fn take(
+        &mut self,
+        _key: &Type::Key,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+}
+
+impl<Type, S> StorageBatchMutate<Type> for ReadOnlyStorage<'_, S>
+where
+    Type: Mappable,
+    S: StorageInspect<Type>,
+{
+}
+
+impl<Type, S> StorageSize<Type> for ReadOnlyStorage<'_, S>
+where
+    Type: Mappable,
+    S: StorageSize<Type>,
+{
+    fn size_of_value(&self, key: &Type::Key) -> Result<Option<usize>, Self::Error> {
+        self.storage
+            .size_of_value(key)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+}
+
+impl<Type, S> StorageRead<Type> for ReadOnlyStorage<'_, S>
+where
+    Type: Mappable,
+    S: StorageRead<Type>,
+{
+    fn read(
+        &self,
+        key: &Type::Key,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, Self::Error> {
+        self.storage
+            .read(key, offset, buf)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn read_alloc(&self, key: &Type::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.storage
+            .read_alloc(key)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+}
+
+impl<Type, S> StorageWrite<Type> for ReadOnlyStorage<'_, S>
+where
+    Type: Mappable,
+    S: StorageInspect<Type>,
+{
+    fn write_bytes(
+        &mut self,
+        _key: &Type::Key,
+        _buf: &[u8],
+    ) -> Result<usize, Self::Error> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+
+    fn replace_bytes(
+        &mut self,
+        _key: &Type::Key,
+        _buf: &[u8],
+    ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+
+    fn take_bytes(&mut self, _key: &Type::Key) -> Result<Option<Vec<u8>>, Self::Error> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+}
+
+/// The `CALL` credits the forwarded coins to the callee even if no coins are
+/// forwarded, so the writes keeping the balance are accepted without changing the
+/// storage. They return the same old balance as the actual write would.
+impl<S> ContractsAssetsStorage for ReadOnlyStorage<'_, S>
+where
+    S: ContractsAssetsStorage,
+{
+    fn contract_asset_id_balance_insert(
+        &mut self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+        value: Word,
+    ) -> Result<(), Self::Error> {
+        self.contract_asset_id_balance_replace(contract, asset_id, value)?;
+        Ok(())
+    }
+
+    fn contract_asset_id_balance_replace(
+        &mut self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+        value: Word,
+    ) -> Result<Option<Word>, Self::Error> {
+        let balance = self.contract_asset_id_balance(contract, asset_id)?;
+        if balance.unwrap_or_default() == value {
+            Ok(balance)
+        } else {
+            Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+        }
+    }
+}
+
+impl<S> InterpreterStorage for ReadOnlyStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    type DataError = ReadOnlyStorageError<S::DataError>;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        self.storage
+            .block_height()
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        self.storage
+            .consensus_parameters_version()
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        self.storage
+            .state_transition_version()
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        self.storage
+            .timestamp(height)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        self.storage
+            .block_hash(block_height)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        self.storage
+            .coinbase()
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        _version: u32,
+        _consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        _version: u32,
+        _hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+
+    fn consensus_parameters_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        self.storage
+            .consensus_parameters_history()
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn state_transition_history(
+        &self,
+    ) -> Result<Option<Vec<(u32, Bytes32)>>, Self::DataError> {
+        self.storage
+            .state_transition_history()
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn storage_contract_size(
+        &self,
+        id: &ContractId,
+    ) -> Result<Option<usize>, Self::DataError> {
+        self.storage
+            .storage_contract_size(id)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<'_, ContractsStateData>>>, Self::DataError> {
+        self.storage
+            .contract_state_range(id, start_key, range)
+            .map_err(ReadOnlyStorageError::Storage)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        _contract: &ContractId,
+        _start_key: &Bytes32,
+        _values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        _contract: &ContractId,
+        _start_key: &Bytes32,
+        _range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        Err(ReadOnlyStorageError::WriteInReadOnlyMode)
+    }
+}
@@ -70,6 +70,33 @@ fn reserved_register() {
     alu_reserved(&[(0x10, 128)], op::add(RegId::FLAG, 0x10, 0x11));
 }
 
+#[test]
+fn add_with_junk_in_reserved_bits_panics() {
+    // Given
+    let add = u32::from(op::add(0x10, 0x11, 0x12)) | 0x3f;
+    let script = add
+        .to_be_bytes()
+        .into_iter()
+        .chain(op::ret(RegId::ONE).to_bytes())
+        .collect();
+
+    // When
+    let result = TestBuilder::new(2322u64)
+        .start_script_bytes(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute();
+
+    // Then
+    let reason = result
+        .receipts()
+        .iter()
+        .find_map(Receipt::reason)
+        .map(|r| *r.reason())
+        .expect("Expected panic reason");
+    assert_eq!(PanicReason::InvalidInstruction, reason);
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AluOk {
     value: Word,
@@ -0,0 +1,101 @@
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    Flags,
+    PanicReason,
+};
+
+use crate::util::{
+    export_alu_vectors,
+    AluVector,
+};
+
+/// The committed vectors. Regenerate them with `UPDATE_ALU_VECTORS=1 cargo test`
+/// after an intentional change of the arithmetic.
+const GOLDEN_PATH: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/alu_vectors.json");
+
+/// Serializes the vectors as a JSON array with one vector per line.
+fn to_json(vectors: &[AluVector]) -> String {
+    let lines = vectors
+        .iter()
+        .map(|vector| serde_json::to_string(vector).expect("the vector is serializable"))
+        .collect::<Vec<_>>();
+    format!("[\n{}\n]\n", lines.join(",\n"))
+}
+
+fn find<'a>(
+    vectors: &'a [AluVector],
+    opcode: &str,
+    flags: Flags,
+    inputs: [&str; 2],
+) -> &'a AluVector {
+    vectors
+        .iter()
+        .find(|vector| {
+            vector.opcode == opcode
+                && vector.flags == flags.bits()
+                && vector.inputs == inputs
+        })
+        .expect("the vector is exported")
+}
+
+#[test]
+fn export_alu_vectors__matches_the_golden_file() {
+    // Given
+    let vectors = export_alu_vectors();
+
+    // When
+    let json = to_json(&vectors);
+
+    // Then
+    if std::env::var_os("UPDATE_ALU_VECTORS").is_some() {
+        std::fs::write(GOLDEN_PATH, &json).expect("the golden file is writable");
+    }
+    let golden = std::fs::read_to_string(GOLDEN_PATH).unwrap_or_default();
+    assert!(
+        golden == json,
+        "the ALU vectors changed, regenerate them with `UPDATE_ALU_VECTORS=1`"
+    );
+    let parsed: Vec<AluVector> =
+        serde_json::from_str(&golden).expect("the golden file is valid");
+    assert_eq!(parsed, vectors);
+}
+
+#[test]
+fn export_alu_vectors__add_overflow__depends_on_the_flags() {
+    // Given
+    let vectors = export_alu_vectors();
+    let inputs = ["ffffffffffffffff", "0000000000000001"];
+
+    // When
+    let panicking = find(&vectors, "ADD", Flags::empty(), inputs);
+    let wrapping = find(&vectors, "ADD", Flags::WRAPPING, inputs);
+
+    // Then
+    assert_eq!(panicking.panic, Some(PanicReason::ArithmeticOverflow));
+    assert_eq!(panicking.result, None);
+    assert_eq!(wrapping.panic, None);
+    assert_eq!(wrapping.result.as_deref(), Some("0000000000000000"));
+    assert_eq!((wrapping.of, wrapping.err), (1, 0));
+}
+
+#[test]
+fn export_alu_vectors__division_by_zero__depends_on_the_flags() {
+    // Given
+    let vectors = export_alu_vectors();
+    let inputs = ["0000000000000001", "0000000000000000"];
+
+    // When
+    let panicking = find(&vectors, "DIV", Flags::empty(), inputs);
+    let unsafe_math = find(&vectors, "DIV", Flags::UNSAFEMATH, inputs);
+
+    // Then
+    assert_eq!(panicking.panic, Some(PanicReason::ArithmeticError));
+    assert_eq!(unsafe_math.panic, None);
+    assert_eq!(unsafe_math.result.as_deref(), Some("0000000000000000"));
+    assert_eq!((unsafe_math.of, unsafe_math.err), (0, 1));
+}
@@ -0,0 +1,343 @@
+use alloc::{
+    borrow::Cow,
+    vec,
+    vec::Vec,
+};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::InterpreterParams,
+    prelude::*,
+    script_with_data_offset,
+    storage::{
+        AsyncInterpreterStorage,
+        AsyncStorageBridge,
+        AsyncStorageBytes,
+        AsyncStorageTable,
+        ContractsStateData,
+        MemoryStorage,
+        MemoryStorageError,
+    },
+    tests::test_helpers::assert_success,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_storage::{
+    Mappable,
+    StorageRead,
+    StorageSize,
+};
+use fuel_tx::ConsensusParameters;
+use fuel_types::{
+    canonical::Serialize,
+    BlockHeight,
+};
+
+/// Returns `Pending` once, so every storage access goes through the executor.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+async fn yield_now() {
+    YieldNow(false).await
+}
+
+/// Asynchronous in-memory storage delegating to the [`MemoryStorage`].
+#[derive(Debug, Clone)]
+struct AsyncMemoryStorage(MemoryStorage);
+
+#[async_trait::async_trait(?Send)]
+impl<Type> AsyncStorageTable<Type> for AsyncMemoryStorage
+where
+    Type: Mappable,
+    MemoryStorage: StorageMutate<Type, Error = MemoryStorageError>,
+{
+    type Error = MemoryStorageError;
+
+    async fn get(
+        &self,
+        key: &Type::Key,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        yield_now().await;
+        Ok(StorageInspect::<Type>::get(&self.0, key)?.map(Cow::into_owned))
+    }
+
+    async fn contains_key(&self, key: &Type::Key) -> Result<bool, Self::Error> {
+        yield_now().await;
+        StorageInspect::<Type>::contains_key(&self.0, key)
+    }
+
+    async fn replace(
+        &mut self,
+        key: &Type::Key,
+        value: &Type::Value,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        yield_now().await;
+        StorageMutate::<Type>::replace(&mut self.0, key, value)
+    }
+
+    async fn take(
+        &mut self,
+        key: &Type::Key,
+    ) -> Result<Option<Type::OwnedValue>, Self::Error> {
+        yield_now().await;
+        StorageMutate::<Type>::take(&mut self.0, key)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<Type> AsyncStorageBytes<Type> for AsyncMemoryStorage
+where
+    Type: Mappable,
+    MemoryStorage: StorageMutate<Type, Error = MemoryStorageError> + StorageRead<Type>,
+{
+    async fn size_of_value(&self, key: &Type::Key) -> Result<Option<usize>, Self::Error> {
+        yield_now().await;
+        StorageSize::<Type>::size_of_value(&self.0, key)
+    }
+
+    async fn read(
+        &self,
+        key: &Type::Key,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<Option<usize>, Self::Error> {
+        yield_now().await;
+        StorageRead::<Type>::read(&self.0, key, offset, buf)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl AsyncInterpreterStorage for AsyncMemoryStorage {
+    type DataError = MemoryStorageError;
+
+    async fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        yield_now().await;
+        self.0.block_height()
+    }
+
+    async fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        yield_now().await;
+        self.0.consensus_parameters_version()
+    }
+
+    async fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        yield_now().await;
+        self.0.state_transition_version()
+    }
+
+    async fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        yield_now().await;
+        self.0.timestamp(height)
+    }
+
+    async fn block_hash(
+        &self,
+        block_height: BlockHeight,
+    ) -> Result<Bytes32, Self::DataError> {
+        yield_now().await;
+        self.0.block_hash(block_height)
+    }
+
+    async fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        yield_now().await;
+        self.0.coinbase()
+    }
+
+    async fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        yield_now().await;
+        self.0
+            .set_consensus_parameters(version, consensus_parameters)
+    }
+
+    async fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        yield_now().await;
+        self.0.set_state_transition_bytecode(version, hash)
+    }
+
+    async fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<ContractsStateData>>, Self::DataError> {
+        yield_now().await;
+        let values = self.0.contract_state_range(id, start_key, range)?;
+        Ok(values
+            .into_iter()
+            .map(|value| value.map(Cow::into_owned))
+            .collect())
+    }
+
+    async fn contract_state_insert_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: &[&[u8]],
+    ) -> Result<usize, Self::DataError> {
+        yield_now().await;
+        self.0
+            .contract_state_insert_range(contract, start_key, values.iter().copied())
+    }
+
+    async fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        yield_now().await;
+        self.0
+            .contract_state_remove_range(contract, start_key, range)
+    }
+}
+
+/// Deploys the contract touching the storage and returns the storage together with
+/// the script calling the contract.
+fn storage_and_script() -> (MemoryStorage, ContractId, Checked<Script>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+
+    let contract = vec![
+        // Store two zeroed slots, read them back and log the statuses.
+        op::movi(0x14, 64),
+        op::aloc(0x14),
+        op::movi(0x15, 2),
+        op::swwq(RegId::ZERO, 0x16, RegId::HP, 0x15),
+        op::srwq(RegId::HP, 0x17, RegId::ZERO, 0x15),
+        op::log(0x16, 0x17, RegId::ZERO, RegId::ZERO),
+        // Overwrite the first slot with the word, read it back and log it.
+        op::movi(0x10, 0x2a),
+        op::sww(RegId::ZERO, 0x11, 0x10),
+        op::srw(0x12, 0x13, RegId::ZERO),
+        op::log(0x12, 0x11, 0x13, RegId::ZERO),
+        op::ret(0x12),
+    ];
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::RET),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(gas_limit)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    let mut storage = test_context.get_storage().clone();
+    storage.set_block_height(test_context.get_block_height());
+
+    (storage, contract_id, tx)
+}
+
+fn interpreter_params() -> InterpreterParams {
+    InterpreterParams::new(0, ConsensusParameters::standard())
+}
+
+fn transact<S>(storage: S, tx: Checked<Script>) -> Vec<Receipt>
+where
+    S: InterpreterStorage,
+{
+    let mut transactor =
+        Transactor::<_, _, _>::new(MemoryInstance::new(), storage, interpreter_params());
+    transactor.transact(tx);
+    let receipts = transactor.receipts().expect("Expected receipts").to_vec();
+    assert_success(&receipts);
+    receipts
+}
+
+#[test]
+fn async_storage_bridge__handle_produces_same_receipts_as_memory_storage() {
+    let (storage, _, tx) = storage_and_script();
+    let expected = transact(storage.clone(), tx.clone());
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("Failed to build the runtime");
+    let bridge =
+        AsyncStorageBridge::new(AsyncMemoryStorage(storage), runtime.handle().clone());
+    let receipts = transact(bridge, tx);
+
+    assert_eq!(receipts, expected);
+}
+
+#[test]
+fn async_storage_bridge__block_on_callback_produces_same_receipts_as_memory_storage() {
+    let (storage, _, tx) = storage_and_script();
+    let expected = transact(storage.clone(), tx.clone());
+
+    let bridge =
+        AsyncStorageBridge::with_block_on(AsyncMemoryStorage(storage), |future| {
+            futures::executor::block_on(future)
+        });
+    let receipts = transact(bridge, tx);
+
+    assert_eq!(receipts, expected);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn async_storage_bridge__execute_blocking_produces_same_receipts_as_memory_storage()
+{
+    let (storage, contract_id, tx) = storage_and_script();
+    // The contract uses the memory at address zero, i.e. the transaction id, as the
+    // key of the overwritten slot.
+    let key = Bytes32::from(*tx.id());
+    let expected = transact(storage.clone(), tx.clone());
+
+    let bridge = AsyncStorageBridge::new(
+        AsyncMemoryStorage(storage),
+        tokio::runtime::Handle::current(),
+    );
+    let (bridge, receipts) = bridge
+        .execute_blocking(move |bridge| transact(bridge, tx))
+        .await;
+
+    assert_eq!(receipts, expected);
+    let stored = bridge.storage().0.contract_state(&contract_id, &key);
+    let mut expected_value = Bytes32::zeroed();
+    expected_value[..8].copy_from_slice(&0x2au64.to_be_bytes());
+    assert_eq!(stored.as_ref().as_ref(), expected_value.as_ref());
+}
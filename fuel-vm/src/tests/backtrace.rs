@@ -1,13 +1,21 @@
-use alloc::vec;
+use alloc::{
+    string::ToString,
+    vec,
+    vec::Vec,
+};
 
 use crate::{
+    checked_transaction::Checked,
     consts::*,
     prelude::*,
 };
 use fuel_asm::{
     op,
+    PanicReason,
     RegId,
 };
+use fuel_types::canonical::Serialize;
+use test_case::test_case;
 
 #[test]
 fn backtrace() {
@@ -91,3 +99,120 @@ fn backtrace() {
         .to();
     assert_eq!(id, &contract_call);
 }
+
+fn panicking_script() -> Checked<Script> {
+    let script = vec![
+        op::movi(0x10, 0x1234),
+        op::div(0x11, 0x10, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default())
+}
+
+#[test]
+fn compact_backtrace__survives_json_roundtrip() {
+    // Given
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.with_compact_backtraces(256);
+
+    // When
+    transactor.transact(panicking_script());
+
+    // Then
+    assert!(transactor.backtrace().is_none());
+    let backtrace = transactor
+        .compact_backtrace()
+        .expect("Expected a compact backtrace of the panic");
+    assert_eq!(backtrace.registers()[0x10], 0x1234);
+    assert_eq!(
+        backtrace.panic_instruction().map(|panic| *panic.reason()),
+        Some(PanicReason::ArithmeticError)
+    );
+
+    let json = serde_json::to_string(&backtrace).expect("Failed to serialize");
+    assert!(
+        json.len() < 64 * 1024,
+        "The compact backtrace has {} bytes",
+        json.len()
+    );
+    let decoded: CompactBacktrace =
+        serde_json::from_str(&json).expect("Failed to deserialize");
+    assert_eq!(decoded.registers(), backtrace.registers());
+    assert_eq!(decoded, backtrace);
+}
+
+#[test]
+fn compact_backtrace__keeps_memory_windows_around_registers() {
+    // Given
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.transact(panicking_script());
+    let backtrace = transactor.backtrace().expect("Expected a backtrace");
+
+    // When
+    let compact = backtrace.compact(64);
+
+    // Then
+    assert_eq!(compact.registers(), backtrace.registers());
+    assert_eq!(compact.panic_instruction(), backtrace.panic_instruction());
+    let registers: Vec<_> = compact.memory().iter().map(|w| w.register()).collect();
+    assert_eq!(registers, vec![RegId::PC, RegId::SP, RegId::FP, RegId::HP]);
+    assert!(compact.memory().iter().all(|w| w.bytes().len() <= 64));
+
+    let pc = compact.registers()[RegId::PC] as usize;
+    let window = &compact.memory()[0];
+    let at = pc - window.start() as usize;
+    let instruction: [u8; 4] = op::div(0x11, 0x10, RegId::ZERO).into();
+    assert_eq!(&window.bytes()[at..at + 4], &instruction);
+
+    let report = compact.to_string();
+    assert!(report.contains("Panic: ArithmeticError"));
+    assert!(report.contains("at index 1"));
+    assert!(report.contains("Registers:"));
+}
+
+#[test_case(0)]
+#[test_case(3)]
+#[test_case(17)]
+fn panic_instruction_index__is_relative_to_the_called_contract(index: usize) {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let mut contract = vec![op::noop(); index];
+    contract.push(op::div(0x10, RegId::ONE, RegId::ZERO));
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let tx = test_context
+        .start_script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    // When
+    let (state, backtrace) = test_context
+        .execute_tx_with_backtrace(tx, 0)
+        .expect("Should execute tx");
+
+    // Then
+    let panic = state
+        .receipts()
+        .iter()
+        .find(|receipt| matches!(receipt, Receipt::Panic { .. }))
+        .expect("Expected a panic receipt");
+    let expected = Some(index as Word);
+    assert_eq!(panic.instruction_index(), expected);
+    assert_ne!(panic.pc().map(|pc| pc / 4), expected);
+    let backtrace = backtrace.expect("Expected a backtrace");
+    assert_eq!(backtrace.panic_instruction_index(), expected);
+    assert_eq!(backtrace.compact(0).panic_instruction_index(), expected);
+}
@@ -0,0 +1,146 @@
+use alloc::vec::Vec;
+
+use fuel_merkle::binary::{
+    in_memory,
+    verify,
+    MerkleTreeError,
+};
+use fuel_types::Bytes32;
+
+use crate::{
+    prelude::MemoryStorage,
+    storage::{
+        BinaryMerkleStorage,
+        BinaryMerkleTreeMetadata,
+    },
+};
+
+const LEAVES_COUNT: u64 = 1000;
+
+fn leaf(index: u64) -> Vec<u8> {
+    index.to_be_bytes().repeat(4)
+}
+
+/// Returns the storage with the tree of the `LEAVES_COUNT` leaves and the same tree
+/// built in memory.
+fn trees(tree: &Bytes32) -> (MemoryStorage, in_memory::MerkleTree) {
+    let mut storage = MemoryStorage::default();
+    let mut in_memory_tree = in_memory::MerkleTree::new();
+    for index in 0..LEAVES_COUNT {
+        storage
+            .binary_merkle_push(tree, &leaf(index))
+            .expect("Infallible");
+        in_memory_tree.push(&leaf(index));
+    }
+    (storage, in_memory_tree)
+}
+
+#[test]
+fn binary_merkle_push__many_leaves__root_matches_in_memory_tree() {
+    // Given
+    let tree = Bytes32::new([1; 32]);
+
+    // When
+    let (storage, in_memory_tree) = trees(&tree);
+
+    // Then
+    let root = Bytes32::from(in_memory_tree.root());
+    assert_eq!(
+        storage.binary_merkle_metadata(&tree),
+        Ok(Some(BinaryMerkleTreeMetadata {
+            leaves_count: LEAVES_COUNT,
+            root,
+        }))
+    );
+    assert_eq!(storage.binary_merkle_root(&tree), Ok(root));
+}
+
+#[test]
+fn binary_merkle_prove__every_leaf__proof_is_verified() {
+    // Given
+    let tree = Bytes32::new([1; 32]);
+    let (storage, in_memory_tree) = trees(&tree);
+
+    for index in 0..LEAVES_COUNT {
+        // When
+        let (root, proof_set) = storage
+            .binary_merkle_prove(&tree, index)
+            .expect("The leaf is in the tree");
+
+        // Then
+        assert_eq!(
+            (*root, proof_set.clone()),
+            in_memory_tree
+                .prove(index)
+                .expect("The leaf is in the tree")
+        );
+        assert!(verify(&root, &leaf(index), &proof_set, index, LEAVES_COUNT));
+        assert!(!verify(
+            &root,
+            &leaf(index + 1),
+            &proof_set,
+            index,
+            LEAVES_COUNT
+        ));
+    }
+}
+
+#[test]
+fn binary_merkle_push__different_trees__are_independent() {
+    // Given
+    let first = Bytes32::new([1; 32]);
+    let second = Bytes32::new([2; 32]);
+    let mut storage = MemoryStorage::default();
+    let mut in_memory_tree = in_memory::MerkleTree::new();
+
+    // When
+    for index in 0..10 {
+        storage
+            .binary_merkle_push(&first, &leaf(index))
+            .expect("Infallible");
+        storage
+            .binary_merkle_push(&second, &leaf(index * 2))
+            .expect("Infallible");
+        in_memory_tree.push(&leaf(index));
+    }
+
+    // Then
+    assert_eq!(
+        storage.binary_merkle_root(&first),
+        Ok(in_memory_tree.root().into())
+    );
+    assert_ne!(
+        storage.binary_merkle_root(&first),
+        storage.binary_merkle_root(&second)
+    );
+    let (root, proof_set) = storage.binary_merkle_prove(&second, 3).unwrap();
+    assert!(verify(&root, &leaf(6), &proof_set, 3, 10));
+}
+
+#[test]
+fn binary_merkle_root__unknown_tree__is_empty_root() {
+    // Given
+    let storage = MemoryStorage::default();
+    let tree = Bytes32::new([1; 32]);
+
+    // When
+    let root = storage.binary_merkle_root(&tree);
+
+    // Then
+    assert_eq!(storage.binary_merkle_metadata(&tree), Ok(None));
+    assert_eq!(root, Ok(in_memory::MerkleTree::new().root().into()));
+}
+
+#[test]
+fn binary_merkle_prove__index_out_of_tree__fails() {
+    // Given
+    let tree = Bytes32::new([1; 32]);
+    let mut storage = MemoryStorage::default();
+    storage.binary_merkle_push(&tree, &leaf(0)).unwrap();
+
+    // When
+    let result = storage.binary_merkle_prove(&tree, 1);
+
+    // Then
+    assert_eq!(result, Err(MerkleTreeError::InvalidProofIndex(1)));
+}
@@ -1,8 +1,14 @@
 #![allow(non_snake_case)]
 
 use crate::{
+    checked_transaction::CheckError,
     consts::VM_MAX_RAM,
     prelude::*,
+    storage::BlobData,
+    util::packed::{
+        encode_packed,
+        DecodedField,
+    },
 };
 use alloc::{
     vec,
@@ -12,6 +18,10 @@ use fuel_asm::{
     op,
     RegId,
 };
+use fuel_tx::consensus_parameters::gas::{
+    GasCostsValues,
+    GasCostsValuesV6,
+};
 use fuel_types::{
     canonical::Serialize,
     BlobId,
@@ -88,6 +98,32 @@ fn blob_cannot_be_reuploaded() {
     );
 }
 
+#[test]
+fn blob__mismatched_id__is_rejected_at_check_time() {
+    // Given
+    let blob_data = vec![1; 100];
+    let declared = BlobId::new([0xf0; 32]);
+    let tx = TransactionBuilder::blob(BlobBody {
+        id: declared,
+        witness_index: 0,
+    })
+    .add_witness(blob_data.clone().into())
+    .add_fee_input()
+    .finalize();
+
+    // When
+    let result = tx.into_checked(Default::default(), &ConsensusParameters::standard());
+
+    // Then
+    assert_eq!(
+        result.map(|_| ()),
+        Err(CheckError::Validity(ValidityError::BlobIdMismatch {
+            declared,
+            computed: BlobId::compute(&blob_data),
+        }))
+    );
+}
+
 fn test_ctx_with_random_blob(size: usize) -> (TestBuilder, BlobId) {
     let mut test_context = TestBuilder::new(1234u64);
 
@@ -157,8 +193,12 @@ fn blob_size__works_with_different_sizes_in_internal_context(
         .contract_id;
 
     // When
-    let mut script_data = blob_id.to_bytes();
-    script_data.extend(Call::new(contract_to_call, 0, 0).to_bytes());
+    let script_data = encode_packed(&[
+        DecodedField::B256((*blob_id).into()),
+        DecodedField::B256((*contract_to_call).into()),
+        DecodedField::U64(0),
+        DecodedField::U64(0),
+    ]);
     let state = test_context
         .start_script(
             vec![
@@ -246,8 +286,12 @@ fn load_blob__loading_whole_blob_works_with_different_sizes_in_internal_context(
     let contract_to_call = test_context.setup_contract(ops, None, None).contract_id;
 
     // When
-    let mut script_data = blob_id.to_bytes();
-    script_data.extend(Call::new(contract_to_call, 0, 0).to_bytes());
+    let script_data = encode_packed(&[
+        DecodedField::B256((*blob_id).into()),
+        DecodedField::B256((*contract_to_call).into()),
+        DecodedField::U64(0),
+        DecodedField::U64(0),
+    ]);
     let state = test_context
         .start_script(
             vec![
@@ -387,3 +431,142 @@ fn blob_load_data__bounds(
             .next()
     })
 }
+
+/// The default costs of `BSIZ` and `BLDD`, with every other operation free.
+fn blob_gas_costs() -> GasCostsValuesV6 {
+    let GasCostsValues::V6(defaults) = GasCostsValues::default() else {
+        panic!("expected the default gas costs to be V6")
+    };
+    GasCostsValuesV6 {
+        bsiz: defaults.bsiz,
+        bldd: defaults.bldd,
+        ..GasCostsValuesV6::free()
+    }
+}
+
+/// Executes the `ops` with the blob of the `size` bytes and its id in the script
+/// data, and returns the receipts.
+fn execute_with_blob_costs(
+    size: usize,
+    ops: Vec<Instruction>,
+    gas_costs: &GasCostsValuesV6,
+) -> Vec<Receipt> {
+    let blob_data = vec![1; size];
+    let blob_id = BlobId::compute(&blob_data);
+    let mut storage = MemoryStorage::default();
+    storage
+        .storage_as_mut::<BlobData>()
+        .insert(&blob_id, &blob_data)
+        .expect("the blob is inserted");
+
+    let mut test_context = TestBuilder::new(1234u64);
+    TestBuilder::storage(&mut test_context, storage)
+        .with_gas_costs(GasCosts::new(gas_costs.clone().into()));
+
+    let mut script = vec![op::gtf_args(0x11, RegId::ZERO, GTFArgs::ScriptData)];
+    script.extend(ops);
+    script.push(op::ret(RegId::ONE));
+    test_context
+        .start_script(script, blob_id.to_bytes())
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute()
+        .receipts()
+        .to_vec()
+}
+
+fn gas_used(receipts: &[Receipt]) -> Word {
+    match receipts.last() {
+        Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+        _ => panic!("expected script result, instead of {receipts:?}"),
+    }
+}
+
+#[test_case(1024 ; "1KB")]
+#[test_case(100 * 1024 ; "100KB")]
+#[test_case(1024 * 1024 ; "1MB")]
+fn blob_size__charges_proportionally_to_blob_size(size: usize) {
+    // Given
+    let gas_costs = blob_gas_costs();
+    let ops = vec![op::bsiz(0x10, 0x11)];
+
+    // When
+    let receipts = execute_with_blob_costs(size, ops, &gas_costs);
+
+    // Then
+    assert_success(&receipts);
+    let expected = gas_costs.bsiz.resolve(size as Word);
+    assert_eq!(gas_used(&receipts), expected);
+}
+
+#[test_case(1024 ; "1KB")]
+#[test_case(100 * 1024 ; "100KB")]
+#[test_case(1024 * 1024 ; "1MB")]
+fn blob_load_data__charges_proportionally_to_copied_length(size: usize) {
+    // Given
+    let gas_costs = blob_gas_costs();
+    let mut ops = set_full_word(0x13, size as Word);
+    ops.extend([
+        op::aloc(0x13),
+        op::move_(0x10, RegId::HP),
+        op::bldd(0x10, 0x11, RegId::ZERO, 0x13),
+    ]);
+
+    // When
+    let receipts = execute_with_blob_costs(size, ops, &gas_costs);
+
+    // Then
+    assert_success(&receipts);
+    let expected = gas_costs.bldd.resolve(size as Word);
+    assert_eq!(gas_used(&receipts), expected);
+}
+
+#[test]
+fn blob_load_data__gas_grows_with_blob_size() {
+    // Given
+    let gas_costs = blob_gas_costs();
+    let gas_used_for = |size: usize| {
+        let mut ops = set_full_word(0x13, size as Word);
+        ops.extend([
+            op::aloc(0x13),
+            op::move_(0x10, RegId::HP),
+            op::bldd(0x10, 0x11, RegId::ZERO, 0x13),
+        ]);
+        gas_used(&execute_with_blob_costs(size, ops, &gas_costs))
+    };
+
+    // When
+    let small = gas_used_for(1024);
+    let medium = gas_used_for(100 * 1024);
+    let large = gas_used_for(1024 * 1024);
+
+    // Then
+    assert!(small < medium, "{small} < {medium}");
+    assert!(medium < large, "{medium} < {large}");
+}
+
+#[test]
+fn blob_load_data__zero_length__charges_blob_size_and_writes_nothing() {
+    // Given
+    let size = 100 * 1024;
+    let gas_costs = blob_gas_costs();
+    let ops = vec![
+        op::aloc(RegId::ONE),
+        op::movi(0x10, 0xff),
+        op::sb(RegId::HP, 0x10, 0),
+        op::bldd(RegId::HP, 0x11, RegId::ZERO, RegId::ZERO),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, RegId::ONE),
+    ];
+
+    // When
+    let receipts = execute_with_blob_costs(size, ops, &gas_costs);
+
+    // Then
+    assert_success(&receipts);
+    let Receipt::LogData { data, .. } = &receipts[0] else {
+        panic!("expected log data receipt, instead of {:?}", receipts[0])
+    };
+    assert_eq!(data.as_deref(), Some([0xff].as_slice()));
+    let expected = gas_costs.bldd.resolve(size as Word);
+    assert_eq!(gas_used(&receipts), expected);
+}
@@ -0,0 +1,207 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    BlobBody,
+    Receipt,
+    TransactionBuilder,
+};
+use fuel_types::{
+    canonical::Serialize,
+    BlobId,
+    ContractId,
+    Word,
+};
+
+use crate::{
+    prelude::*,
+    util::loader::{
+        blob_loader_bytecode,
+        loader_contract_root,
+    },
+};
+
+/// The original program: the code followed by the data section, which the code
+/// reads relatively to the `$pc`, as the compiled contracts do.
+fn original_code() -> Vec<u8> {
+    [
+        op::move_(0x20, RegId::PC),
+        op::lw(0x21, 0x20, 4),
+        op::log(0x21, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::addi(0x21, 0x21, 1),
+        op::lw(0x22, 0x20, 5),
+        op::log(0x21, 0x22, RegId::ZERO, RegId::ZERO),
+        op::noop(),
+        op::ret(0x21),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn original_data_section() -> Vec<u8> {
+    [0xdead_beef_u64, 0x1234].map(Word::to_be_bytes).concat()
+}
+
+fn upload_blob(client: &mut MemoryClient<MemoryInstance>, data: &[u8]) -> BlobId {
+    let id = BlobId::compute(data);
+    let tx = TransactionBuilder::blob(BlobBody {
+        id,
+        witness_index: 0,
+    })
+    .add_witness(data.to_vec().into())
+    .add_fee_input()
+    .finalize_checked_basic(Default::default());
+    client.blob(tx).expect("failed to upload the blob");
+    id
+}
+
+fn deploy(client: &mut MemoryClient<MemoryInstance>, code: Vec<u8>) -> ContractId {
+    client
+        .deploy_contract(code, Default::default(), vec![])
+        .expect("failed to deploy the contract")
+        .contract_id
+}
+
+fn call(client: &mut MemoryClient<MemoryInstance>, contract_id: ContractId) -> Vec<Receipt> {
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default());
+
+    client.transact(tx).to_vec()
+}
+
+/// The logged registers and the returned value of the called contract.
+fn observed(receipts: &[Receipt], contract_id: ContractId) -> Vec<[Word; 4]> {
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log {
+                id, ra, rb, rc, rd, ..
+            } if id == &contract_id => Some([*ra, *rb, *rc, *rd]),
+            Receipt::Return { id, val, .. } if id == &contract_id => {
+                Some([*val, 0, 0, 0])
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn blob_loader__observes_the_behavior_of_the_original_program() {
+    // Given
+    let mut client = MemoryClient::default();
+    let code = original_code();
+    let data_section = original_data_section();
+    let original = deploy(&mut client, [code.clone(), data_section.clone()].concat());
+    let blob_ids = code
+        .chunks(16)
+        .map(|chunk| upload_blob(&mut client, chunk))
+        .collect::<Vec<_>>();
+    let loader = deploy(&mut client, blob_loader_bytecode(&blob_ids, &data_section));
+
+    // When
+    let original_receipts = call(&mut client, original);
+    let loader_receipts = call(&mut client, loader);
+
+    // Then
+    let expected = vec![
+        [0xdead_beef, 0, 0, 0],
+        [0xdead_bef0, 0x1234, 0, 0],
+        [0xdead_bef0, 0, 0, 0],
+    ];
+    assert_eq!(observed(&original_receipts, original), expected);
+    assert_eq!(observed(&loader_receipts, loader), expected);
+}
+
+#[test]
+fn blob_loader__single_blob_without_data_section__works() {
+    // Given
+    let mut client = MemoryClient::default();
+    let code: Vec<u8> = [
+        op::movi(0x21, 7),
+        op::log(0x21, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(0x21),
+    ]
+    .into_iter()
+    .collect();
+    let blob_ids = vec![upload_blob(&mut client, &code)];
+    let loader = deploy(&mut client, blob_loader_bytecode(&blob_ids, &[]));
+
+    // When
+    let receipts = call(&mut client, loader);
+
+    // Then
+    assert_eq!(
+        observed(&receipts, loader),
+        vec![[7, 0, 0, 0], [7, 0, 0, 0]]
+    );
+}
+
+#[test]
+fn blob_loader__missing_blob__panics() {
+    // Given
+    let mut client = MemoryClient::default();
+    let blob_ids = vec![BlobId::compute(&original_code())];
+    let loader = deploy(
+        &mut client,
+        blob_loader_bytecode(&blob_ids, &original_data_section()),
+    );
+
+    // When
+    let receipts = call(&mut client, loader);
+
+    // Then
+    let reason = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    });
+    assert_eq!(reason, Some(PanicReason::BlobNotFound));
+}
+
+#[test]
+fn loader_contract_root__matches_the_deployed_loader() {
+    // Given
+    let mut client = MemoryClient::default();
+    let blob_ids = original_code()
+        .chunks(16)
+        .map(BlobId::compute)
+        .collect::<Vec<_>>();
+    let data_section = original_data_section();
+
+    // When
+    let root = loader_contract_root(&blob_ids, &data_section);
+
+    // Then
+    let created = client
+        .deploy_contract(
+            blob_loader_bytecode(&blob_ids, &data_section),
+            Default::default(),
+            vec![],
+        )
+        .expect("failed to deploy the contract");
+    assert_eq!(created.code_root, root);
+}
@@ -0,0 +1,145 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    Receipt,
+    TransactionBuilder,
+};
+use fuel_types::{
+    canonical::Serialize,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    prelude::*,
+    storage::MemoryStorage,
+    util::test_helpers::BlockScenario,
+};
+
+/// Stores the block height to the zero slot and logs the coinbase.
+fn block_recorder(client: &mut MemoryClient<MemoryInstance>) -> ContractId {
+    let code = vec![
+        op::movi(0x10, 64),
+        op::aloc(0x10),
+        op::addi(0x11, RegId::HP, 32),
+        op::cb(0x11),
+        op::bhei(0x12),
+        op::sww(RegId::HP, 0x13, 0x12),
+        op::movi(0x14, 32),
+        op::logd(RegId::ZERO, RegId::ZERO, 0x11, 0x14),
+        op::ret(RegId::ONE),
+    ];
+    client
+        .deploy_contract(code.into_iter().collect(), Default::default(), vec![])
+        .expect("failed to deploy the contract")
+        .contract_id
+}
+
+fn call(height: BlockHeight, contract_id: ContractId) -> Checked<Script> {
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(height)
+}
+
+fn recorded_height(storage: &MemoryStorage, contract_id: &ContractId) -> Word {
+    let state = storage.contract_state(contract_id, &Bytes32::zeroed());
+    let data: &[u8] = state.as_ref().as_ref();
+    let bytes = data[..8].try_into().expect("the slot holds a word");
+    Word::from_be_bytes(bytes)
+}
+
+fn logged_coinbase(receipts: &[Receipt]) -> Vec<ContractId> {
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::LogData { data, .. } => data.as_deref().map(|data| {
+                ContractId::try_from(data).expect("the log is a contract id")
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn block_scenario__three_blocks__contract_observes_each_block() {
+    // Given
+    let mut client = MemoryClient::default();
+    let contract_id = block_recorder(&mut client);
+    let blocks = [(10u32, 0xaau8), (11, 0xbb), (25, 0xcc)];
+    let mut scenario = BlockScenario::new(client);
+    for (height, coinbase) in blocks {
+        let height = BlockHeight::new(height);
+        let coinbase = ContractId::new([coinbase; 32]);
+        scenario
+            .block(height, coinbase, vec![call(height, contract_id)])
+            .check_receipts(move |storage, receipts| {
+                assert_eq!(receipts.len(), 1);
+                assert_eq!(logged_coinbase(&receipts[0]), vec![coinbase]);
+                assert_eq!(recorded_height(storage, &contract_id), *height as Word);
+            });
+    }
+
+    // When
+    let receipts = scenario.execute();
+
+    // Then
+    assert_eq!(receipts.len(), blocks.len());
+    let storage = scenario.client().as_ref();
+    assert_eq!(storage.block_height(), Ok(BlockHeight::new(25)));
+    assert_eq!(storage.coinbase(), Ok(ContractId::new([0xcc; 32])));
+    assert_eq!(recorded_height(storage, &contract_id), 25);
+}
+
+#[test]
+fn block_scenario__several_transactions_in_block__share_the_block() {
+    // Given
+    let mut client = MemoryClient::default();
+    let contract_id = block_recorder(&mut client);
+    let height = BlockHeight::new(7);
+    let coinbase = ContractId::new([0x11; 32]);
+    let mut scenario = BlockScenario::new(client);
+    scenario.block(
+        height,
+        coinbase,
+        vec![call(height, contract_id), call(height, contract_id)],
+    );
+
+    // When
+    let receipts = scenario.execute();
+
+    // Then
+    let [block] = receipts.as_slice() else {
+        panic!("expected a single block, got {receipts:?}");
+    };
+    for tx_receipts in block {
+        assert_eq!(logged_coinbase(tx_receipts), vec![coinbase]);
+    }
+    assert_eq!(recorded_height(scenario.client().as_ref(), &contract_id), 7);
+}
@@ -64,27 +64,6 @@ use rand::{
     SeedableRng,
 };
 
-fn deploy_contract<M>(
-    client: &mut MemoryClient<M>,
-    contract: Witness,
-    salt: Salt,
-    storage_slots: Vec<StorageSlot>,
-) where
-    M: Memory,
-{
-    let tx_params = TxParameters::default();
-    let height = Default::default();
-    let contract_deployer = TransactionBuilder::create(contract, salt, storage_slots)
-        .with_tx_params(tx_params)
-        .add_fee_input()
-        .add_contract_created()
-        .finalize_checked(height);
-
-    client
-        .deploy(contract_deployer)
-        .expect("valid contract deployment");
-}
-
 fn write_contract_id(
     script: &mut Vec<Instruction>,
     register: u8,
@@ -858,6 +837,123 @@ fn ldc_contract_not_in_inputs() {
     ldc_reason_helper(load_contract, ContractNotInInputs);
 }
 
+/// Calls a contract loading `len` bytes of the code of another contract with the
+/// contract mode of `LDC`, when the `contract_max_size` is `CONTRACT_MAX_SIZE`.
+fn ldc_contract_mode_in_call(len: u32) -> Vec<Receipt> {
+    const CONTRACT_MAX_SIZE: u64 = 512;
+
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_contract_params(
+        consensus_params
+            .contract_params()
+            .with_contract_max_size(CONTRACT_MAX_SIZE),
+    );
+    let mut client = MemoryClient::<_, NotSupportedEcal>::from_txtor(Transactor::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &consensus_params),
+    ));
+    let target = client
+        .deploy_contract(
+            vec![0u8; CONTRACT_MAX_SIZE as usize],
+            Default::default(),
+            vec![],
+        )
+        .expect("failed to deploy the target contract");
+
+    let mut caller_code = vec![];
+    write_contract_id(&mut caller_code, 0x10, target.contract_id);
+    caller_code.extend([
+        op::movi(0x11, len),
+        op::ldc(0x10, RegId::ZERO, 0x11, 0),
+        op::ret(RegId::ONE),
+    ]);
+    let caller_code: Vec<u8> = caller_code.into_iter().collect();
+    let caller = client
+        .deploy_contract(caller_code, Default::default(), vec![])
+        .expect("failed to deploy the caller contract");
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        consensus_params.tx_params().tx_offset()
+    );
+    let mut script_data = caller.contract_id.to_vec();
+    script_data.extend([0u8; WORD_SIZE * 2]);
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            caller.contract_id,
+        ))
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            target.contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to check tx");
+
+    client.transact(tx).to_vec()
+}
+
+#[test]
+fn ldc__contract_mode_in_call__code_up_to_contract_max_size_is_loaded() {
+    // When
+    // The code of the caller and the loaded code together exceed the limit, but the
+    // contract mode only checks the length of the loaded code.
+    let receipts = ldc_contract_mode_in_call(512);
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn ldc__contract_mode_in_call__code_over_contract_max_size_panics() {
+    // When
+    let receipts = ldc_contract_mode_in_call(513);
+
+    // Then
+    let panic = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    });
+    assert_eq!(panic, Some(ContractMaxSize));
+}
+
+#[test]
+fn ldc__fails_with_unknown_mode() {
+    let (load_contract, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::ldc(0x10, RegId::ZERO, RegId::ONE, 5),
+        ],
+        TxParameters::DEFAULT.tx_offset()
+    );
+
+    ldc_reason_helper(load_contract, PanicReason::InvalidImmediateValue);
+}
+
 #[test]
 fn load_contract_code_copies_expected_bytes() {
     let mut test_context = TestBuilder::new(2322u64);
@@ -1179,17 +1275,14 @@ fn code_root_a_plus_32_overflow() {
     // Given
     let mut client = MemoryClient::default();
     let instructions = vec![op::noop(), op::noop(), op::noop()];
-    let contract: Witness = instructions.into_iter().collect::<Vec<u8>>().into();
-
-    let salt = Default::default();
-    let code_root = Contract::root_from_code(contract.as_ref());
-    let storage_slots = vec![];
-
-    let state_root = Contract::initial_state_root(storage_slots.iter());
-    let contract_id =
-        Contract::from(contract.as_ref()).id(&salt, &code_root, &state_root);
-
-    deploy_contract(&mut client, contract, salt, storage_slots);
+    let contract_id = client
+        .deploy_contract(
+            instructions.into_iter().collect(),
+            Default::default(),
+            vec![],
+        )
+        .expect("valid contract deployment")
+        .contract_id;
 
     let reg_a = 0x20;
     let reg_contract = 0x21;
@@ -1225,17 +1318,14 @@ fn code_root_a_over_max_ram() {
     // Given
     let mut client = MemoryClient::default();
     let instructions = vec![op::noop(), op::noop(), op::noop()];
-    let contract: Witness = instructions.into_iter().collect::<Vec<u8>>().into();
-
-    let salt = Default::default();
-    let code_root = Contract::root_from_code(contract.as_ref());
-    let storage_slots = vec![];
-
-    let state_root = Contract::initial_state_root(storage_slots.iter());
-    let contract_id =
-        Contract::from(contract.as_ref()).id(&salt, &code_root, &state_root);
-
-    deploy_contract(&mut client, contract, salt, storage_slots);
+    let contract_id = client
+        .deploy_contract(
+            instructions.into_iter().collect(),
+            Default::default(),
+            vec![],
+        )
+        .expect("valid contract deployment")
+        .contract_id;
 
     let reg_a = 0x20;
     let reg_contract = 0x21;
@@ -1897,6 +1987,67 @@ fn smo_instruction_works() {
     ));
 }
 
+#[test]
+fn smo__message_out_receipt__matches_the_message_id() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut client = MemoryClient::default();
+    let recipient: Address = rng.gen();
+    let msg_data = vec![0xaa, 0xbb, 0xcc];
+    let amount = 7;
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Address::LEN as Immediate12),
+        op::movi(0x12, msg_data.len() as Immediate18),
+        op::movi(0x13, amount as Immediate18),
+        op::smo(0x10, 0x11, 0x12, 0x13),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = recipient.iter().chain(&msg_data).copied().collect();
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1_000,
+            AssetId::BASE,
+            rng.gen(),
+        )
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+        .finalize_checked(Default::default());
+    let txid = tx.transaction().id(&ChainId::default());
+
+    // When
+    let receipts = client.transact(tx);
+
+    // Then
+    let (index, receipt) = receipts
+        .iter()
+        .enumerate()
+        .find(|(_, receipt)| matches!(receipt, Receipt::MessageOut { .. }))
+        .expect("the message is sent");
+    let Receipt::MessageOut {
+        sender,
+        recipient: receipt_recipient,
+        amount: receipt_amount,
+        nonce,
+        digest,
+        ..
+    } = receipt
+    else {
+        unreachable!()
+    };
+    assert_eq!(*receipt_recipient, recipient);
+    assert_eq!(*receipt_amount, amount);
+    assert_eq!(*nonce, Output::message_nonce(&txid, index as Word));
+    assert_eq!(*digest, Output::message_digest(&msg_data));
+    let expected =
+        Input::compute_message_id(sender, &recipient, nonce, amount, &msg_data);
+    assert_eq!(receipt.message_id(), Some(expected));
+}
+
 #[test]
 fn timestamp_works() {
     let mut client = MemoryClient::default();
@@ -2556,7 +2707,7 @@ fn ldcv1__fails_when_mem_offset_is_above_reg_hp() {
         TxParameters::DEFAULT.tx_offset()
     );
 
-    ldcv1_reason_helper(load_blob, MemoryOverflow);
+    ldcv1_reason_helper(load_blob, ContractMaxSize);
 }
 
 #[test]
@@ -9,6 +9,8 @@ use crate::{
 };
 use fuel_asm::{
     op,
+    GTFArgs,
+    Instruction,
     RegId,
 };
 use fuel_types::canonical::Serialize;
@@ -219,3 +221,283 @@ fn cgas_uses_min_available_gas() {
         .iter()
         .any(|receipt| matches!(&receipt, Receipt::Log {ra: cgas, ..} if *cgas < gas_forward_amount.into())));
 }
+
+const OUTER_FORWARD_GAS: Immediate18 = 50_000;
+const MIDDLE_FORWARD_GAS: Immediate18 = 20_000;
+const INNER_FORWARD_GAS: Immediate18 = 5_000;
+
+/// The script calls the outer contract, which calls the middle contract, which calls
+/// the inner contract. Every call forwards a distinct amount of gas. The inner
+/// contract logs its `$cgas` and runs `inner_body`.
+fn nested_calls_with_forwarded_gas(
+    inner_body: Vec<Instruction>,
+    call_frame_reverts: bool,
+) -> (Vec<Receipt>, Vec<CallGasReport>, [ContractId; 3]) {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_tx_params(
+        TxParameters::DEFAULT.with_call_frame_reverts(call_frame_reverts),
+    );
+    let gas_limit = 1_000_000;
+
+    // Every frame finds the `Call` structure of its callee in the script data.
+    let forwarding_call = |index: usize, forward_gas: Immediate18| {
+        vec![
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+            op::addi(0x10, 0x10, (index * Call::LEN) as Immediate12),
+            op::movi(0x11, forward_gas),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, 0x11),
+        ]
+    };
+
+    let mut inner = vec![op::log(RegId::CGAS, RegId::ZERO, RegId::ZERO, RegId::ZERO)];
+    inner.extend(inner_body);
+    let inner = test_context.setup_contract(inner, None, None).contract_id;
+
+    let mut middle = forwarding_call(2, INNER_FORWARD_GAS);
+    middle.push(op::ret(RegId::ONE));
+    let middle = test_context.setup_contract(middle, None, None).contract_id;
+
+    let mut outer = forwarding_call(1, MIDDLE_FORWARD_GAS);
+    outer.push(op::ret(RegId::ONE));
+    let outer = test_context.setup_contract(outer, None, None).contract_id;
+
+    let mut script = forwarding_call(0, OUTER_FORWARD_GAS);
+    script.push(op::ret(RegId::ONE));
+    let script_data = [
+        Call::new(outer, 0, 0).to_bytes(),
+        Call::new(middle, 0, 0).to_bytes(),
+        Call::new(inner, 0, 0).to_bytes(),
+    ]
+    .concat();
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(gas_limit)
+        .contract_input(outer)
+        .contract_input(middle)
+        .contract_input(inner)
+        .fee_input()
+        .contract_output(&outer)
+        .contract_output(&middle)
+        .contract_output(&inner)
+        .build();
+    let (state, report) = test_context
+        .execute_tx_with_call_gas_report(tx)
+        .expect("expected successful vm execution");
+
+    (state.receipts().to_vec(), report, [outer, middle, inner])
+}
+
+fn assert_forwarded(report: &[CallGasReport], contracts: [ContractId; 3]) {
+    let forwarded = [OUTER_FORWARD_GAS, MIDDLE_FORWARD_GAS, INNER_FORWARD_GAS];
+
+    assert_eq!(report.len(), 3);
+    for ((call, to), forwarded) in report.iter().zip(contracts).zip(forwarded) {
+        assert_eq!(call.to, to);
+        assert_eq!(call.forwarded, forwarded as Word);
+        assert!(call.consumed <= call.forwarded);
+    }
+    // Every call consumes its own gas in addition to the gas of its subtree
+    assert!(report[0].consumed > report[1].consumed);
+    assert!(report[1].consumed > report[2].consumed);
+}
+
+#[test]
+fn call_gas_report__nested_calls_return_unused_gas_to_caller() {
+    // When
+    let (receipts, report, contracts) =
+        nested_calls_with_forwarded_gas(vec![op::ret(RegId::ONE)], false);
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+    assert_forwarded(&report, contracts);
+    for call in &report {
+        assert!(matches!(receipts[call.receipt_index], Receipt::Call { .. }));
+        assert_eq!(Some(call.forwarded - call.consumed), call.returned);
+    }
+
+    // The inner contract returns what is left after logging, minus the cost of `RET`
+    let logged_cgas = receipts
+        .iter()
+        .find_map(|r| match r {
+            Receipt::Log { ra, .. } => Some(*ra),
+            _ => None,
+        })
+        .expect("The inner contract logs its $cgas");
+    let ret_cost = GasCosts::default().ret();
+    assert_eq!(report[2].returned, Some(logged_cgas - ret_cost));
+}
+
+#[test]
+fn call_gas_report__revert_in_nested_call_consumes_only_used_gas() {
+    // When
+    let (receipts, report, contracts) =
+        nested_calls_with_forwarded_gas(vec![op::rvrt(RegId::ONE)], false);
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Revert,
+            ..
+        })
+    ));
+    assert_forwarded(&report, contracts);
+    assert!(report.iter().all(|call| call.returned.is_none()));
+    assert!(report[2].consumed < report[2].forwarded);
+}
+
+#[test]
+fn call_gas_report__reverted_call_frame_returns_unused_gas_to_caller() {
+    // When
+    let (receipts, report, contracts) =
+        nested_calls_with_forwarded_gas(vec![op::rvrt(RegId::ONE)], true);
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+    assert_forwarded(&report, contracts);
+    for call in &report {
+        assert_eq!(Some(call.forwarded - call.consumed), call.returned);
+    }
+}
+
+#[test]
+fn call_gas_report__out_of_gas_in_nested_call_is_bounded_by_forwarded_gas() {
+    // When
+    let (receipts, report, contracts) =
+        nested_calls_with_forwarded_gas(vec![op::jmpb(RegId::ZERO, 0)], false);
+
+    // Then
+    assert!(receipts.iter().any(|r| matches!(
+        r,
+        Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::OutOfGas
+    )));
+    assert_forwarded(&report, contracts);
+    assert!(report.iter().all(|call| call.returned.is_none()));
+    // The inner contract burns exactly the forwarded gas and nothing more
+    assert_eq!(report[2].consumed, report[2].forwarded);
+}
+
+/// Calls the contract logging its `$cgas`, forwarding the gas computed by the
+/// `gas_to_forward` instruction into the register `0x11`.
+fn call_forwarding_gas(
+    strict_call_gas: bool,
+    gas_to_forward: Instruction,
+) -> StateTransition<Script> {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context
+        .with_free_gas_costs()
+        .with_tx_params(TxParameters::DEFAULT.with_strict_call_gas(strict_call_gas));
+    let contract_id = test_context
+        .setup_contract(
+            vec![
+                op::log(RegId::CGAS, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+                op::ret(RegId::ONE),
+            ],
+            None,
+            None,
+        )
+        .contract_id;
+
+    test_context
+        .start_script(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                gas_to_forward,
+                op::call(0x10, RegId::ZERO, RegId::ZERO, 0x11),
+                op::ret(RegId::ONE),
+            ],
+            Call::new(contract_id, 0, 0).to_bytes(),
+        )
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute()
+}
+
+/// Returns the gas of the `Call` receipt and the `$cgas` logged by the contract.
+fn call_receipt_gas_and_logged_cgas(receipts: &[Receipt]) -> (Word, Word) {
+    let call_gas = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Call { gas, .. } => Some(*gas),
+            _ => None,
+        })
+        .expect("the script calls the contract");
+    let logged_cgas = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Log { ra, .. } => Some(*ra),
+            _ => None,
+        })
+        .expect("the contract logs its cgas");
+    (call_gas, logged_cgas)
+}
+
+#[test]
+fn call__gas_exceeding_cgas_without_strict_call_gas__receipt_reports_forwarded_gas() {
+    // Given
+    let requested = op::addi(0x11, RegId::CGAS, 1000);
+
+    // When
+    let result = call_forwarding_gas(false, requested);
+
+    // Then
+    assert!(!result.should_revert());
+    let (call_gas, logged_cgas) = call_receipt_gas_and_logged_cgas(result.receipts());
+    assert_eq!(call_gas, logged_cgas);
+    assert_eq!(call_gas, 1_000_000);
+}
+
+#[test]
+fn call__gas_exceeding_cgas_with_strict_call_gas__panics() {
+    // Given
+    let requested = op::addi(0x11, RegId::CGAS, 1000);
+
+    // When
+    let result = call_forwarding_gas(true, requested);
+
+    // Then
+    let reason = result
+        .receipts()
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Panic { reason, .. } => Some(*reason.reason()),
+            _ => None,
+        })
+        .expect("the call panics");
+    assert_eq!(reason, PanicReason::InsufficientGasForCall);
+    assert!(!result
+        .receipts()
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Call { .. })));
+}
+
+#[test_case::test_case(false; "without strict call gas")]
+#[test_case::test_case(true; "with strict call gas")]
+fn call__gas_within_cgas__forwards_requested_gas(strict_call_gas: bool) {
+    // Given
+    let requested = op::movi(0x11, 1000);
+
+    // When
+    let result = call_forwarding_gas(strict_call_gas, requested);
+
+    // Then
+    assert!(!result.should_revert());
+    let (call_gas, logged_cgas) = call_receipt_gas_and_logged_cgas(result.receipts());
+    assert_eq!(call_gas, 1000);
+    assert_eq!(logged_cgas, 1000);
+}
@@ -0,0 +1,200 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    consts::WORD_SIZE,
+    prelude::*,
+    script_with_data_offset,
+    tests::test_helpers::assert_success,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::Outputs,
+    TxParameters,
+};
+
+fn contract_program() -> Vec<Instruction> {
+    vec![
+        op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+}
+
+fn create(salt: Salt) -> Checked<Create> {
+    let program: Witness = contract_program().into_iter().collect::<Vec<u8>>().into();
+
+    TransactionBuilder::create(program, salt, vec![])
+        .add_fee_input()
+        .add_contract_created()
+        .finalize_checked(Default::default())
+}
+
+/// Calls the contract and reads its bytecode with `CSIZ`, `CCP` and `LDC`.
+fn script(contract_id: ContractId) -> Checked<Script> {
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::movi(0x10, data_offset),
+            op::csiz(0x11, 0x10),
+            op::aloc(0x11),
+            op::ccp(RegId::HP, 0x10, RegId::ZERO, 0x11),
+            op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x11),
+            op::move_(0x12, RegId::SSP),
+            op::ldc(0x10, RegId::ZERO, 0x11, 0),
+            op::logd(RegId::ZERO, RegId::ZERO, 0x12, 0x11),
+            op::ret(RegId::ONE),
+        ],
+        TxParameters::DEFAULT.tx_offset()
+    );
+
+    let mut script_data = contract_id.to_vec();
+    script_data.extend([0u8; WORD_SIZE * 2]);
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default())
+}
+
+fn contract_id(create: &Create) -> ContractId {
+    create
+        .outputs()
+        .iter()
+        .find_map(|output| match output {
+            Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+            _ => None,
+        })
+        .expect("Expected `ContractCreated` output")
+}
+
+/// Deploys the contract, calls it twice, reverts the storage, redeploys the contract
+/// and calls it again. Returns the receipts of every call.
+fn deploy_call_redeploy(capacity_bytes: usize) -> Vec<Vec<Receipt>> {
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.with_code_cache(capacity_bytes);
+
+    let create = create(Salt::zeroed());
+    let id = contract_id(
+        &transactor
+            .deploy(create.clone())
+            .expect("Failed to deploy the contract"),
+    );
+
+    let mut receipts = vec![];
+    let mut call = |transactor: &mut Transactor<_, _, _>| {
+        let call_receipts = transactor
+            .transact(script(id))
+            .receipts()
+            .expect("Failed to execute the script")
+            .to_vec();
+        assert_success(&call_receipts);
+        receipts.push(call_receipts);
+    };
+
+    call(&mut transactor);
+    assert_eq!(transactor.code_cache().contains(&id), capacity_bytes > 0);
+    call(&mut transactor);
+
+    // The reverted storage doesn't contain the contract anymore, so the
+    // redeployment must invalidate the stale bytecode in the cache.
+    transactor.as_mut().revert();
+    transactor
+        .deploy(create)
+        .expect("Failed to redeploy the contract");
+    assert!(!transactor.code_cache().contains(&id));
+    call(&mut transactor);
+
+    receipts
+}
+
+#[test]
+fn code_cache__deploy_call_redeploy_produces_same_receipts_as_uncached() {
+    let uncached = deploy_call_redeploy(0);
+    let cached = deploy_call_redeploy(1024 * 1024);
+
+    assert_eq!(cached, uncached);
+}
+
+#[test]
+fn code_cache__code_larger_than_capacity_is_not_cached() {
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.with_code_cache(1);
+
+    let create = transactor
+        .deploy(create(Salt::zeroed()))
+        .expect("Failed to deploy the contract");
+    let id = contract_id(&create);
+
+    let receipts = transactor
+        .transact(script(id))
+        .receipts()
+        .expect("Failed to execute the script")
+        .to_vec();
+
+    assert_success(&receipts);
+    assert!(transactor.code_cache().is_empty());
+}
+
+#[test]
+fn code_cache__disabled_by_default() {
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+
+    let create = transactor
+        .deploy(create(Salt::zeroed()))
+        .expect("Failed to deploy the contract");
+    transactor.transact(script(contract_id(&create)));
+
+    assert!(!transactor.code_cache().is_enabled());
+    assert!(transactor.code_cache().is_empty());
+}
+
+/// Deploys and calls the contract, then reverts the storage without redeploying the
+/// contract and calls it again. Returns the outcome of the last call.
+fn call_after_revert(capacity_bytes: usize) -> Result<Vec<Receipt>, String> {
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.with_code_cache(capacity_bytes);
+
+    let id = contract_id(
+        &transactor
+            .deploy(create(Salt::zeroed()))
+            .expect("Failed to deploy the contract"),
+    );
+    transactor.transact(script(id));
+    assert_eq!(transactor.code_cache().contains(&id), capacity_bytes > 0);
+
+    transactor.as_mut().revert();
+    transactor
+        .transact(script(id))
+        .result()
+        .map(|state| state.receipts().to_vec())
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn code_cache__reverted_contract_is_not_served_from_cache() {
+    // Given
+    let uncached = call_after_revert(0);
+
+    // When
+    let cached = call_after_revert(1024 * 1024);
+
+    // Then
+    assert!(uncached.is_err());
+    assert_eq!(cached, uncached);
+}
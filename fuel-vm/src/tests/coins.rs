@@ -24,6 +24,7 @@ use fuel_tx::{
     Output,
     PanicReason,
     Receipt,
+    TxParameters,
 };
 use fuel_types::canonical::Serialize;
 
@@ -674,6 +675,77 @@ fn transfer_to_output(
     })
 }
 
+#[test_case(Ctx::External, &[1, 1], false => RunResult::Panic(PanicReason::OutputNotFound); "(external) Transfer twice to the same output")]
+#[test_case(Ctx::External, &[1, 1], true => RunResult::Panic(PanicReason::DoubleSpendOutput); "(external) Transfer twice to the same output with precise panics")]
+#[test_case(Ctx::External, &[0], true => RunResult::Panic(PanicReason::ExpectedOutputVariable); "(external) Target output is not Variable with precise panics")]
+#[test_case(Ctx::External, &[9], true => RunResult::Panic(PanicReason::OutputNotFound); "(external) Target output doesn't exist with precise panics")]
+#[test_case(Ctx::Internal, &[1, 1], false => RunResult::Panic(PanicReason::OutputNotFound); "(internal) Transfer twice to the same output")]
+#[test_case(Ctx::Internal, &[1, 1], true => RunResult::Panic(PanicReason::DoubleSpendOutput); "(internal) Transfer twice to the same output with precise panics")]
+#[test_case(Ctx::Internal, &[0], true => RunResult::Panic(PanicReason::ExpectedOutputVariable); "(internal) Target output is not Variable with precise panics")]
+#[test_case(Ctx::Internal, &[9], true => RunResult::Panic(PanicReason::OutputNotFound); "(internal) Target output doesn't exist with precise panics")]
+fn transfer_to_output_precise_panics(
+    ctx: Ctx,
+    to_indices: &[Word], // 1 = the variable output
+    precise_output_panics: bool,
+) -> RunResult<()> {
+    let reg_tmp = 0x10;
+    let asset_id_ptr = 0x12;
+    let reg_amount = 0x13;
+    let reg_index = 0x14;
+
+    let mut ops = vec![
+        op::movi(reg_amount, 1),
+        op::gtf_args(reg_tmp, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(asset_id_ptr, reg_tmp, Call::LEN.try_into().unwrap()),
+    ];
+    for &to_index in to_indices {
+        ops.push(op::movi(reg_index, to_index.try_into().unwrap()));
+        ops.push(op::tro(reg_tmp, reg_index, reg_amount, asset_id_ptr));
+    }
+    ops.push(op::ret(RegId::ONE));
+
+    let mut test_context = TestBuilder::new(1234u64);
+    test_context.with_tx_params(
+        TxParameters::DEFAULT.with_precise_output_panics(precise_output_panics),
+    );
+    let asset_id: AssetId = test_context.rng.gen();
+
+    let contract_id = test_context
+        .setup_contract(ops.clone(), Some((asset_id, 10)), None)
+        .contract_id;
+
+    let script_ops = match ctx {
+        Ctx::Internal => vec![
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        Ctx::External => ops,
+    };
+
+    let script_data: Vec<u8> = [Call::new(contract_id, 0, 0).to_bytes().as_slice()]
+        .into_iter()
+        .flatten()
+        .copied()
+        .chain(asset_id.to_bytes())
+        .collect();
+
+    let mut builder = test_context
+        .start_script(script_ops, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .variable_output(asset_id);
+
+    if ctx == Ctx::External {
+        builder = builder.coin_input(asset_id, 10).change_output(asset_id);
+    }
+
+    let receipts = builder.execute().receipts().to_vec();
+    RunResult::extract_novalue(&receipts)
+}
+
 #[test_case(None, None => RunResult::Success(()); "Normal case works")]
 #[test_case(Some(Word::MAX - 31), None => RunResult::Panic(PanicReason::MemoryOverflow); "$rA + 32 overflows")]
 #[test_case(Some(VM_MAX_RAM - 31), None => RunResult::Panic(PanicReason::MemoryOverflow); "$rA + 32 > VM_MAX_RAM")]
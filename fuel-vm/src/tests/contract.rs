@@ -1,6 +1,7 @@
 use crate::{
+    checked_transaction::CheckError,
+    interpreter::InterpreterParams,
     prelude::*,
-    script_with_data_offset,
     util::test_helpers::TestBuilder,
 };
 use alloc::{
@@ -123,26 +124,13 @@ fn mint_consumes_gas_for_new_assets() {
 
         let contract_id = test_context.setup_contract(program, None, None).contract_id;
 
-        let (script_call, _) = script_with_data_offset!(
-            data_offset,
-            vec![
-                op::movi(0x10, data_offset as Immediate18),
-                op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
-                op::ret(RegId::ONE),
-            ],
-            test_context.get_tx_params().tx_offset()
+        let receipts = test_context.script_gas_limit(gas_limit).call_contract(
+            contract_id,
+            0,
+            balance,
         );
-        let script_call_data = Call::new(contract_id, 0, balance).to_bytes();
 
-        let result = test_context
-            .start_script(script_call.clone(), script_call_data)
-            .script_gas_limit(gas_limit)
-            .contract_input(contract_id)
-            .fee_input()
-            .contract_output(&contract_id)
-            .execute();
-
-        let mut gas_values = result.receipts().iter().filter_map(|v| match v {
+        let mut gas_values = receipts.iter().filter_map(|v| match v {
             Receipt::Log { ra, .. } => Some(ra),
             _ => None,
         });
@@ -154,3 +142,137 @@ fn mint_consumes_gas_for_new_assets() {
 
     assert!(new_asset > existing_asset);
 }
+
+#[test]
+fn deploy_contract__deployed_contract_can_be_called() {
+    let mut client = MemoryClient::default();
+
+    // Given
+    let slot_value = 0xdead_beef_u64;
+    let mut value = [0u8; 32];
+    value[..8].copy_from_slice(&slot_value.to_be_bytes());
+    let storage_slots = vec![StorageSlot::new(Bytes32::zeroed(), value.into())];
+    let code: Vec<u8> = [
+        op::movi(0x10, Bytes32::LEN as u32),
+        op::aloc(0x10),
+        op::srw(0x11, 0x12, RegId::HP),
+        op::log(0x11, 0x12, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let salt: Salt = StdRng::seed_from_u64(2322u64).gen();
+
+    // When
+    let created = client
+        .deploy_contract(code.clone(), salt, storage_slots.clone())
+        .expect("Failed to deploy the contract");
+
+    // Then
+    assert_eq!(created, CreatedContract::new(&code, &salt, &storage_slots));
+    assert_eq!(
+        created.state_root,
+        Contract::initial_state_root(storage_slots.iter())
+    );
+    assert_eq!(created.code_root, Contract::root_from_code(&code));
+
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(created.contract_id, 0, 0).to_bytes();
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            created.contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default());
+
+    let receipts = client.transact(tx);
+    let logged = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Log { id, ra, rb, .. } if id == &created.contract_id => Some((*ra, *rb)),
+        _ => None,
+    });
+    assert_eq!(logged, Some((slot_value, 1)));
+}
+
+#[test]
+fn deploy_contract__exceeding_contract_max_size__fails() {
+    // Given
+    let contract_max_size = 16;
+    let mut client = MemoryClient::<_>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            contract_max_size,
+            ..Default::default()
+        },
+    );
+    let code = vec![0u8; contract_max_size as usize + 4];
+
+    // When
+    let result = client.deploy_contract(code, Default::default(), vec![]);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(DeployError::Check(CheckError::Validity(
+            ValidityError::TransactionCreateBytecodeLen
+        )))
+    ));
+}
+
+#[test]
+fn memory_client__deployed_contract__exposes_code_and_state() {
+    let mut client = MemoryClient::default();
+
+    // Given
+    let key = Bytes32::new([0x11; 32]);
+    let value = Bytes32::new([0x22; 32]);
+    let storage_slots = vec![StorageSlot::new(key, value)];
+    let code: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let created = client
+        .deploy_contract(code.clone(), Default::default(), storage_slots)
+        .expect("Failed to deploy the contract");
+
+    // When
+    let exists = client.contract_exists(&created.contract_id);
+    let contract = client.contract_code(&created.contract_id);
+    let state = client.contract_state_value(&created.contract_id, &key);
+    let unset_state = client.contract_state_value(&created.contract_id, &value);
+
+    // Then
+    assert_eq!(exists, Ok(true));
+    let contract = contract.unwrap().expect("The contract is deployed");
+    assert_eq!(contract.into_owned(), Contract::from(code));
+    let state = state.unwrap().expect("The slot is set");
+    let state: &[u8] = state.as_ref().as_ref();
+    assert_eq!(state, value.as_ref());
+    assert_eq!(unset_state, Ok(None));
+}
+
+#[test]
+fn memory_client__unknown_contract__does_not_exist() {
+    // Given
+    let client = MemoryClient::default();
+    let contract_id = ContractId::new([0x33; 32]);
+
+    // When
+    let exists = client.contract_exists(&contract_id);
+    let contract = client.contract_code(&contract_id);
+    let state = client.contract_state_value(&contract_id, &Bytes32::zeroed());
+
+    // Then
+    assert_eq!(exists, Ok(false));
+    assert_eq!(contract, Ok(None));
+    assert_eq!(state, Ok(None));
+}
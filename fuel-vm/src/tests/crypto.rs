@@ -20,7 +20,10 @@ use fuel_crypto::{
     Signature,
 };
 use fuel_tx::{
+    consensus_parameters::gas::GasCostsValuesV6,
     ConsensusParameters,
+    DependentCost,
+    GasCosts,
     TransactionBuilder,
 };
 use fuel_types::ChainId;
@@ -34,6 +37,10 @@ use sha3::{
 };
 
 use crate::{
+    interpreter::{
+        InterpreterParams,
+        NotSupportedEcal,
+    },
     prelude::*,
     tests::test_helpers::set_full_word,
     util::test_helpers::check_expected_reason_for_instructions,
@@ -396,6 +403,61 @@ fn secp256r1_recover() {
     assert!(success);
 }
 
+/// The message "sample" produces the signature with the odd `y`, and "test" with the
+/// even one, for the key of the RFC 6979 P-256 test vectors.
+#[test_case("sample"; "odd y")]
+#[test_case("test"; "even y")]
+fn secp256r1_recover__signature_from_sign_prehashed__recovers_signer(message: &str) {
+    use p256::ecdsa::SigningKey;
+
+    // Given
+    let mut client = MemoryClient::default();
+    let secret: Bytes32 =
+        "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"
+            .parse()
+            .unwrap();
+    let signing_key = SigningKey::from_bytes(&(*secret).into()).unwrap();
+    let public_key = encode_pubkey(*signing_key.verifying_key());
+    let message = Message::new(message);
+    let signature = fuel_crypto::secp256r1::sign_prehashed(&signing_key, &message)
+        .expect("Signing failed");
+    fuel_crypto::secp256r1::verify(&signature, &public_key.into(), &message)
+        .expect("The signature is valid");
+
+    #[rustfmt::skip]
+    let script = vec![
+        op::gtf_args(0x20, 0x00, GTFArgs::ScriptData),
+        op::addi(0x21, 0x20, signature.len() as Immediate12),
+        op::addi(0x22, 0x21, message.as_ref().len() as Immediate12),
+        op::movi(0x10, 64),
+        op::aloc(0x10),
+        op::move_(0x11, RegId::HP),
+        op::ecr1(0x11, 0x20, 0x21),
+        op::meq(0x12, 0x22, 0x11, 0x10),
+        op::log(0x12, 0x00, 0x00, 0x00),
+        op::ret(RegId::ONE),
+    ].into_iter().collect();
+    let script_data = signature
+        .iter()
+        .copied()
+        .chain(message.as_ref().iter().copied())
+        .chain(public_key)
+        .collect();
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default());
+
+    // When
+    let receipts = client.transact(tx);
+
+    // Then
+    let success = receipts
+        .iter()
+        .any(|r| matches!(r, Receipt::Log{ ra, .. } if *ra == 1));
+    assert!(success, "{receipts:?}");
+}
+
 #[test]
 fn secp256r1_recover_error() {
     let rng = &mut StdRng::seed_from_u64(2322u64);
@@ -1086,3 +1148,84 @@ fn epar__works() {
         .any(|r| matches!(r, Receipt::Log{ ra, .. } if *ra == 1));
     assert!(success);
 }
+
+#[test_case(op::ecop(0x11, 0x10, RegId::ZERO, RegId::ZERO); "ecop")]
+#[test_case(op::epar(0x11, 0x10, RegId::ZERO, RegId::ZERO); "epar")]
+fn ec_instruction__unknown_curve_id__panics(instruction: Instruction) {
+    let script = vec![op::movi(0x10, 1), instruction];
+
+    check_expected_reason_for_instructions(script, PanicReason::UnsupportedCurveId);
+}
+
+/// Two pairs of points passing the BN254 pairing check.
+const BN254_PAIRS: &str = "\
+    1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59\
+    3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41\
+    209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7\
+    04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678\
+    2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d\
+    120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550\
+    111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c\
+    2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411\
+    198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+    1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+    090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+    12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+
+/// Runs `EPAR` over the first `pairs` of [`BN254_PAIRS`] with only the pairing
+/// priced, returning the gas used by the script.
+fn epar_gas_used(pairs: Immediate18, epar_bn254: DependentCost) -> Word {
+    let gas_costs = GasCostsValuesV6 {
+        epar_bn254,
+        ..GasCostsValuesV6::free()
+    };
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_gas_costs(GasCosts::new(gas_costs.into()));
+    let mut client = MemoryClient::<_, NotSupportedEcal>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &consensus_params),
+    );
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::movi(0x11, pairs),
+        op::epar(0x12, RegId::ZERO, 0x11, 0x10),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(
+        script.into_iter().collect(),
+        hex::decode(BN254_PAIRS).unwrap(),
+    )
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .finalize()
+    .into_checked(Default::default(), &consensus_params)
+    .expect("failed to check tx");
+
+    let receipts = client.transact(tx);
+    match receipts.last() {
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            gas_used,
+        }) => *gas_used,
+        receipt => panic!("Expected a successful script result, got {receipt:?}"),
+    }
+}
+
+#[test]
+fn epar__gas_scales_per_pair() {
+    // Given
+    let cost = DependentCost::HeavyOperation {
+        base: 1_000,
+        gas_per_unit: 10_000,
+    };
+
+    // When
+    let one_pair = epar_gas_used(1, cost);
+    let two_pairs = epar_gas_used(2, cost);
+
+    // Then
+    assert_eq!(one_pair, 1_000 + 10_000);
+    assert_eq!(two_pairs, 1_000 + 2 * 10_000);
+}
@@ -0,0 +1,83 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    consts::VM_MAX_RAM,
+    prelude::*,
+    tests::test_helpers::assert_success,
+    util::test_helpers::{
+        TestBuilder,
+        DATA_IN_HEAP_REGISTER,
+    },
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::Receipt;
+use test_case::test_case;
+
+#[test_case(vec![]; "empty data")]
+#[test_case(vec![0xab]; "one byte")]
+#[test_case((0..=255).collect(); "256 bytes")]
+fn with_data_in_heap__copies_script_data_to_heap(data: Vec<u8>) {
+    // Given
+    let data_len = data.len() as Word;
+    let script = vec![
+        op::log(DATA_IN_HEAP_REGISTER, RegId::HP, 0x11, RegId::ZERO),
+        op::movi(0x12, data_len as Immediate18),
+        op::logd(RegId::ZERO, RegId::ZERO, DATA_IN_HEAP_REGISTER, 0x12),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let result = TestBuilder::new(2322u64)
+        .with_data_in_heap()
+        .start_script(script, data.clone())
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute();
+
+    // Then
+    let receipts = result.receipts();
+    assert_success(receipts);
+    let Receipt::Log { ra, rb, rc, .. } = receipts[0] else {
+        panic!("Expected `Log` receipt, got {:?}", receipts[0]);
+    };
+    assert_eq!(
+        ra,
+        VM_MAX_RAM - data_len,
+        "The data is at the top of the heap"
+    );
+    assert_eq!(rb, ra, "Only the data is allocated in the heap");
+    assert_eq!(rc, 0, "The scratch register is reset");
+    assert_eq!(receipts[1].data(), Some(data.as_slice()));
+}
+
+#[test]
+fn with_data_in_heap__is_disabled_by_default() {
+    let result = TestBuilder::new(2322u64)
+        .start_script(
+            vec![
+                op::log(DATA_IN_HEAP_REGISTER, RegId::HP, RegId::ZERO, RegId::ZERO),
+                op::ret(RegId::ONE),
+            ],
+            vec![0xab; 32],
+        )
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute();
+
+    let receipts = result.receipts();
+    assert_success(receipts);
+    assert!(matches!(
+        receipts[0],
+        Receipt::Log {
+            ra: 0,
+            rb: VM_MAX_RAM,
+            ..
+        }
+    ));
+}
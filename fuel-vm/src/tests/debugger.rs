@@ -5,22 +5,50 @@ use alloc::{
 
 use fuel_asm::{
     op,
+    GTFArgs,
+    PanicReason,
     RegId,
 };
 use fuel_tx::{
     ConsensusParameters,
     Finalizable,
     GasCosts,
+    Input,
     Script,
     TransactionBuilder,
 };
+use fuel_types::{
+    canonical::Serialize,
+    Bytes32,
+    Word,
+};
 
 use crate::{
+    checked_transaction::builder::TransactionBuilderExt,
+    consts::MEM_SIZE,
+    context::{
+        Context,
+        ContextKind,
+    },
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+        MemoryRange,
+    },
     prelude::{
+        Breakpoint,
+        Call,
         Interpreter,
         IntoChecked,
+        MemoryStorage,
+        RuntimePredicate,
     },
-    state::ProgramState,
+    state::{
+        ProgramState,
+        Watch,
+    },
+    storage::predicate::empty_predicate_storage,
+    util::test_helpers::TestBuilder,
 };
 
 #[test]
@@ -70,3 +98,219 @@ fn receipts_are_produced_correctly_with_stepping() {
 
     assert_eq!(receipts_without_debugger, receipts_with_debugger);
 }
+
+#[test]
+fn storage_watch__pauses_after_the_write_and_resumes() {
+    // Given
+    let value: Word = 42;
+    let mut watched_key = Bytes32::zeroed();
+    watched_key[31] = 1;
+    let contract_code = vec![
+        op::movi(0x10, Bytes32::LEN as u32),
+        op::aloc(0x10),
+        op::movi(0x11, value as u32),
+        // Writes the zero key, which isn't watched
+        op::sww(RegId::HP, 0x12, 0x11),
+        op::movi(0x13, 1),
+        op::sb(RegId::HP, 0x13, 31),
+        // Writes the watched key
+        op::sww(RegId::HP, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(contract_code, None, None)
+        .contract_id;
+    let tx = test_context
+        .start_script(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            Call::new(contract_id, 0, 0).to_bytes(),
+        )
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build()
+        .test_into_ready();
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    vm.add_storage_watch(contract_id, watched_key);
+
+    // When
+    let state = *vm.transact(tx).expect("panicked").state();
+
+    // Then
+    let watchpoint = *state
+        .debug_ref()
+        .and_then(|debug| debug.watchpoint())
+        .expect("Expected a watchpoint");
+    assert_eq!(
+        watchpoint.watch(),
+        &Watch::Storage {
+            contract: contract_id,
+            key: watched_key,
+        }
+    );
+    assert_eq!(watchpoint.location(), &Breakpoint::new(contract_id, 6));
+    let storage: &MemoryStorage = vm.as_ref();
+    let written = storage.contract_state(&contract_id, &watched_key);
+    assert_eq!(written.as_ref().as_ref()[..8], value.to_be_bytes());
+
+    let state = vm.resume().expect("Failed to resume");
+    assert_eq!(state, ProgramState::Return(1));
+}
+
+#[test]
+fn memory_watch__pauses_after_the_overlapping_write() {
+    // Given
+    let script = vec![
+        op::movi(0x10, 64),
+        op::aloc(0x10),
+        op::movi(0x11, 0xff),
+        op::sw(RegId::HP, 0x11, 0),
+        op::sw(RegId::HP, 0x11, 4),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(script, Vec::new())
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &params)
+        .expect("failed to check tx")
+        .into_ready(0, params.gas_costs(), params.fee_params(), None)
+        .expect("failed to ready tx");
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    let heap_top = MEM_SIZE - 64;
+    vm.add_memory_watch(MemoryRange::new(heap_top + 36, 1));
+
+    // When
+    let state = *vm.transact(tx).expect("panicked").state();
+
+    // Then
+    let watchpoint = *state
+        .debug_ref()
+        .and_then(|debug| debug.watchpoint())
+        .expect("Expected a watchpoint");
+    assert_eq!(
+        watchpoint.watch(),
+        &Watch::Memory {
+            start: heap_top + 36,
+            end: heap_top + 37,
+        }
+    );
+    assert_eq!(watchpoint.location(), &Breakpoint::script(4));
+
+    let state = vm.resume().expect("Failed to resume");
+    assert_eq!(state, ProgramState::Return(1));
+}
+
+#[test]
+fn context_transitions__predicate_using_sww__records_predicate_verification() {
+    // Given
+    let predicate: Vec<u8> =
+        [op::sww(RegId::ZERO, 0x10, RegId::ZERO), op::ret(RegId::ONE)]
+            .into_iter()
+            .collect();
+    let input = Input::coin_predicate(
+        Default::default(),
+        Input::predicate_owner(&predicate),
+        0,
+        Default::default(),
+        Default::default(),
+        0,
+        predicate,
+        vec![],
+    );
+    let params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(input)
+        .add_fee_input()
+        .finalize_checked_basic(Default::default());
+    let program =
+        RuntimePredicate::from_tx(tx.as_ref(), params.tx_params().tx_offset(), 0)
+            .expect("the input is the predicate");
+    let context = Context::PredicateVerification { program };
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        empty_predicate_storage(),
+        InterpreterParams::new(0, &params),
+    );
+    vm.init_predicate(context.clone(), tx.transaction().clone(), 1_000_000)
+        .expect("the predicate is initialized");
+
+    // When
+    let result = vm.verify_predicate();
+
+    // Then
+    let reason = result.expect_err("SWW is not allowed").panic_reason();
+    assert_eq!(reason, Some(PanicReason::ContractInstructionNotAllowed));
+    assert_eq!(vm.context().kind(), ContextKind::PredicateVerification);
+    assert_eq!(vm.debugger().context_transitions(), &[(0, context)]);
+    #[cfg(feature = "vm-backtrace")]
+    {
+        let backtrace = crate::backtrace::Backtrace::from_vm_error(
+            &vm,
+            fuel_tx::ScriptExecutionResult::Panic,
+        );
+        assert_eq!(backtrace.context(), ContextKind::PredicateVerification);
+    }
+}
+
+#[test]
+fn context_transitions__script_calling_contract__records_call_and_return() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(vec![op::ret(RegId::ONE)], None, None)
+        .contract_id;
+    let tx = test_context
+        .start_script(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            Call::new(contract_id, 0, 0).to_bytes(),
+        )
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build()
+        .test_into_ready();
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+
+    // When
+    let state = *vm.transact(tx).expect("panicked").state();
+
+    // Then
+    assert_eq!(state, ProgramState::Return(1));
+    let transitions: Vec<_> = vm
+        .debugger()
+        .context_transitions()
+        .iter()
+        .map(|(index, context)| (*index, context.kind()))
+        .collect();
+    assert_eq!(
+        transitions,
+        vec![
+            (0, ContextKind::Script),
+            (1, ContextKind::Call),
+            (0, ContextKind::Script),
+        ]
+    );
+}
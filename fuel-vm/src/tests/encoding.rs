@@ -20,6 +20,7 @@ use fuel_types::{
         Deserialize,
         Serialize,
     },
+    ContractId,
     Word,
 };
 
@@ -102,6 +103,73 @@ fn call_frame() {
     );
 }
 
+#[test]
+fn call__try_from_bytes__round_trips() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    for _ in 0..10 {
+        // Given
+        let call = Call::new(rng.gen(), rng.gen(), rng.gen());
+        let bytes = call.to_bytes();
+
+        // When
+        let parsed = Call::try_from(bytes.as_slice());
+
+        // Then
+        assert_eq!(bytes.len(), Call::SERIALIZED_LEN);
+        assert_eq!(parsed, Ok(call));
+    }
+}
+
+#[test]
+fn call__try_from_bytes__rejects_truncated_and_trailing_bytes() {
+    let bytes = Call::new(ContractId::new([7; 32]), 1, 2).to_bytes();
+
+    assert_eq!(
+        Call::try_from(&bytes[..Call::SERIALIZED_LEN - 1]),
+        Err(CallParseError::Truncated {
+            available: Call::SERIALIZED_LEN - 1
+        })
+    );
+    assert_eq!(
+        Call::try_from(&[][..]),
+        Err(CallParseError::Truncated { available: 0 })
+    );
+    assert_eq!(
+        Call::try_from([bytes.as_slice(), &[0; 3]].concat().as_slice()),
+        Err(CallParseError::TrailingBytes { remaining: 3 })
+    );
+}
+
+#[test]
+fn call_and_call_frame__serde_round_trip() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    // Given
+    let call = Call::new(rng.gen(), rng.gen(), rng.gen());
+    let frame = CallFrame::new(
+        rng.gen(),
+        rng.gen(),
+        [rng.gen(); VM_REGISTER_COUNT],
+        200,
+        rng.gen(),
+        rng.gen(),
+    )
+    .unwrap();
+
+    // When
+    let call_json = serde_json::to_string(&call).expect("Failed to serialize");
+    let frame_json = serde_json::to_string(&frame).expect("Failed to serialize");
+
+    // Then
+    let decoded_call: Call =
+        serde_json::from_str(&call_json).expect("Failed to deserialize");
+    let decoded_frame: CallFrame =
+        serde_json::from_str(&frame_json).expect("Failed to deserialize");
+    assert_eq!(decoded_call, call);
+    assert_eq!(decoded_frame, frame);
+}
+
 #[test]
 fn witness() {
     assert_encoding_correct(&[Witness::from(vec![0xef]), Witness::from(vec![])]);
@@ -1,4 +1,7 @@
-use alloc::vec;
+use alloc::{
+    vec,
+    vec::Vec,
+};
 use fuel_asm::{
     op,
     GTFArgs,
@@ -14,7 +17,13 @@ use fuel_tx::{
     ScriptExecutionResult,
     TransactionBuilder,
 };
-use fuel_vm::prelude::*;
+use fuel_vm::{
+    interpreter::{
+        EcalContext,
+        EcalOutcome,
+    },
+    prelude::*,
+};
 use itertools::Itertools;
 use test_case::test_case;
 
@@ -225,3 +234,137 @@ fn complex_ecal_fn(val: u32, result: PanicReason) {
 
     assert_panics(receipts, result);
 }
+
+/// Writes 32 bytes at the address from the register `b` and returns the value of the
+/// register `a` incremented by one.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeapWriteEcal;
+
+impl ::fuel_vm::interpreter::CheckedEcalHandler for HeapWriteEcal {
+    fn ecal(
+        &mut self,
+        _: EcalContext<'_>,
+        a: Word,
+        b: Word,
+        _: Word,
+        _: Word,
+    ) -> ::fuel_vm::error::SimpleResult<EcalOutcome> {
+        Ok(EcalOutcome {
+            gas: 1,
+            ra: Some(a.saturating_add(1)),
+            rb: None,
+            mem_writes: vec![(b, vec![0xab; 32])],
+        })
+    }
+}
+
+fn run_heap_write_ecal(result: RegId, destination: RegId) -> Vec<Receipt> {
+    let vm: Interpreter<_, _, Script, HeapWriteEcal> = Interpreter::with_memory_storage();
+
+    let script = vec![
+        op::movi(0x10, 32),
+        op::aloc(0x10),
+        op::movi(0x20, 41),
+        op::ecal(result, destination, RegId::ZERO, RegId::ZERO),
+        op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x10),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut client = MemoryClient::from_txtor(vm.into());
+    let consensus_params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .maturity(Default::default())
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate a checked tx");
+    client.transact(tx);
+    client.receipts().expect("Expected receipts").to_vec()
+}
+
+#[test]
+fn ecal_outcome_is_applied_to_registers_and_memory() {
+    let receipts = run_heap_write_ecal(RegId::new(0x20), RegId::HP);
+
+    let Receipt::Log { ra, .. } = receipts[0] else {
+        panic!("Expected a log receipt");
+    };
+    assert_eq!(ra, 42);
+    assert!(matches!(receipts[1], Receipt::LogData { .. }));
+    assert_eq!(receipts[1].data_b256(0), Some(Bytes32::new([0xab; 32])));
+    assert_eq!(receipts[1].data_slice(32, 1), None);
+}
+
+#[test_case(RegId::new(0x20), RegId::ZERO => PanicReason::MemoryOwnership; "write to the memory not owned by the script")]
+#[test_case(RegId::ONE, RegId::HP => PanicReason::ReservedRegisterNotWritable; "write to the reserved register")]
+fn ecal_outcome_is_checked_like_regular_instructions(
+    result: RegId,
+    destination: RegId,
+) -> PanicReason {
+    let receipts = run_heap_write_ecal(result, destination);
+
+    let Some(Receipt::Panic { reason, .. }) = receipts.first() else {
+        panic!("Expected a panic receipt");
+    };
+    *reason.reason()
+}
+
+/// Writes 32 bytes at the address from the register `b` and 8 bytes at the address
+/// from the register `c`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TwoWritesEcal;
+
+impl ::fuel_vm::interpreter::CheckedEcalHandler for TwoWritesEcal {
+    fn ecal(
+        &mut self,
+        _: EcalContext<'_>,
+        _: Word,
+        b: Word,
+        c: Word,
+        _: Word,
+    ) -> ::fuel_vm::error::SimpleResult<EcalOutcome> {
+        Ok(EcalOutcome {
+            gas: 1,
+            mem_writes: vec![(b, vec![0xab; 32]), (c, vec![0xcd; 8])],
+            ..Default::default()
+        })
+    }
+}
+
+#[test]
+fn ecal_outcome_with_a_forbidden_write_applies_no_writes() {
+    // Given
+    let mut vm: Transactor<_, _, Script, TwoWritesEcal> =
+        Interpreter::with_memory_storage().into();
+    let script = vec![
+        op::movi(0x10, 32),
+        op::aloc(0x10),
+        // The first write targets the heap, the second the memory not owned by the
+        // script
+        op::ecal(RegId::ZERO, RegId::HP, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .maturity(Default::default())
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &ConsensusParameters::standard())
+        .expect("failed to generate a checked tx");
+
+    // When
+    vm.transact(tx);
+
+    // Then
+    let receipts = vm.receipts().expect("Expected receipts");
+    assert_panics(receipts, PanicReason::MemoryOwnership);
+    let vm: &Interpreter<_, _, Script, TwoWritesEcal> = vm.as_ref();
+    let hp = vm.registers()[RegId::HP] as usize;
+    assert_eq!(&vm.memory()[hp..hp + 32], &[0u8; 32][..]);
+}
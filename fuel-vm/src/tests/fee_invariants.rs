@@ -0,0 +1,182 @@
+//! The checker of the fee invariants must fire when the fee is computed incorrectly.
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::Outputs,
+    FeeParameters,
+    Output,
+    Receipt,
+    Script,
+};
+use fuel_types::Word;
+use test_case::test_case;
+
+use crate::util::test_helpers::{
+    assert_fee_invariants,
+    check_fee_invariants,
+    TestBuilder,
+};
+
+const GAS_PRICE: Word = 1;
+
+/// Executes a script paying a non-zero fee, and returns the transaction before
+/// and after the execution together with the receipts. The execution itself
+/// asserts the fee invariants.
+///
+/// The gas price factor is 1, so any change of the gas price changes the fee.
+fn execute(
+    test_context: &mut TestBuilder,
+    revert: bool,
+) -> (Script, Script, Vec<Receipt>) {
+    let base_asset_id = *test_context.get_base_asset_id();
+    let result = if revert {
+        op::rvrt(RegId::ONE)
+    } else {
+        op::ret(RegId::ONE)
+    };
+    test_context
+        .with_fee_params(FeeParameters::default().with_gas_price_factor(1))
+        .fee_invariants_check(true)
+        .start_script(vec![op::noop(), result], vec![])
+        .script_gas_limit(1_000)
+        .gas_price(GAS_PRICE)
+        .max_fee_limit(1_000_000)
+        .coin_input(base_asset_id, 10_000_000)
+        .change_output(base_asset_id);
+    let tx_before = test_context.build().transaction().clone();
+    let state = test_context.execute();
+
+    (tx_before, state.tx().clone(), state.receipts().to_vec())
+}
+
+/// Refunds one more coin than the VM does, as a fee computation with an off-by-one
+/// error would.
+fn overrefund(tx: &mut Script) {
+    for output in tx.outputs_mut() {
+        if let Output::Change { amount, .. } = output {
+            *amount += 1;
+        }
+    }
+}
+
+#[test_case(false; "success")]
+#[test_case(true; "revert")]
+fn check_fee_invariants__correct_fee__passes(revert: bool) {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let (tx_before, tx_after, receipts) = execute(&mut test_context, revert);
+
+    // When
+    let result = check_fee_invariants(
+        &tx_before,
+        &tx_after,
+        &receipts,
+        test_context.get_gas_costs(),
+        test_context.get_fee_params(),
+        test_context.get_base_asset_id(),
+        GAS_PRICE,
+    );
+
+    // Then
+    result.expect("The VM computes the fee correctly");
+}
+
+#[test_case(false; "success")]
+#[test_case(true; "revert")]
+fn check_fee_invariants__corrupted_refund__fails(revert: bool) {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let (tx_before, mut tx_after, receipts) = execute(&mut test_context, revert);
+    overrefund(&mut tx_after);
+
+    // When
+    let result = check_fee_invariants(
+        &tx_before,
+        &tx_after,
+        &receipts,
+        test_context.get_gas_costs(),
+        test_context.get_fee_params(),
+        test_context.get_base_asset_id(),
+        GAS_PRICE,
+    );
+
+    // Then
+    result.expect_err("The corrupted refund must be detected");
+}
+
+#[test]
+fn check_fee_invariants__wrong_gas_price__fails() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let (tx_before, tx_after, receipts) = execute(&mut test_context, false);
+
+    // When
+    let result = check_fee_invariants(
+        &tx_before,
+        &tx_after,
+        &receipts,
+        test_context.get_gas_costs(),
+        test_context.get_fee_params(),
+        test_context.get_base_asset_id(),
+        GAS_PRICE + 1,
+    );
+
+    // Then
+    result.expect_err("The fee at the other gas price must not match");
+}
+
+#[test]
+fn check_fee_invariants__gas_used_above_limit__fails() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let (tx_before, tx_after, mut receipts) = execute(&mut test_context, false);
+    for receipt in receipts.iter_mut() {
+        if let Receipt::ScriptResult { gas_used, .. } = receipt {
+            *gas_used = 1_001;
+        }
+    }
+
+    // When
+    let result = check_fee_invariants(
+        &tx_before,
+        &tx_after,
+        &receipts,
+        test_context.get_gas_costs(),
+        test_context.get_fee_params(),
+        test_context.get_base_asset_id(),
+        GAS_PRICE,
+    );
+
+    // Then
+    let err = result.expect_err("The gas used above the limit must be detected");
+    assert!(
+        err.to_string().contains("exceeds the script gas limit"),
+        "{err}"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Fee invariant violated")]
+fn assert_fee_invariants__corrupted_refund__panics() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let (tx_before, mut tx_after, receipts) = execute(&mut test_context, false);
+    overrefund(&mut tx_after);
+
+    assert_fee_invariants(
+        &tx_before,
+        &tx_after,
+        &receipts,
+        test_context.get_gas_costs(),
+        test_context.get_fee_params(),
+        test_context.get_base_asset_id(),
+        GAS_PRICE,
+    );
+}
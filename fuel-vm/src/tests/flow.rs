@@ -8,20 +8,62 @@ use crate::{
     consts::*,
     prelude::*,
     script_with_data_offset,
-    tests::test_helpers::assert_success,
-    util::test_helpers::TestBuilder,
+    storage::ContractsStateData,
+    tests::test_helpers::{
+        assert_success,
+        run_script,
+        set_full_word,
+    },
+    util::{
+        packed::{
+            decode_packed,
+            DecodedField,
+            FieldKind,
+        },
+        test_helpers::{
+            TestBuilder,
+            CALL_PAYLOAD_OFFSET,
+        },
+    },
 };
 use fuel_asm::{
     op,
     Flags,
+    GTFArgs,
     RegId,
 };
 use fuel_crypto::Hasher;
+use fuel_tx::field::Outputs;
 use fuel_types::canonical::Serialize;
 use itertools::Itertools;
+use rand::Rng;
+use test_case::test_case;
 
 const SET_STATUS_REG: u8 = 0x29;
 
+#[test_case(VM_MAX_RAM - Call::SERIALIZED_LEN as Word + 1 => PanicReason::MalformedCallStructure; "truncated by the end of the memory")]
+#[test_case(VM_MAX_RAM => PanicReason::MalformedCallStructure; "at the end of the memory")]
+#[test_case(VM_MAX_RAM + 1 => PanicReason::MemoryOverflow; "outside of the memory")]
+fn call__truncated_call_structure__panics(call_params_pointer: Word) -> PanicReason {
+    // Given
+    // The end of the memory is readable only after the heap is allocated
+    let mut script = vec![op::movi(0x11, 64), op::aloc(0x11)];
+    script.extend(set_full_word(0x10, call_params_pointer));
+    script.extend([
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]);
+
+    // When
+    let receipts = run_script(script);
+
+    // Then
+    let Some(Receipt::Panic { reason, .. }) = receipts.get(receipts.len() - 2) else {
+        panic!("Expected the script to panic, got {receipts:?}");
+    };
+    *reason.reason()
+}
+
 #[test]
 fn can_execute_empty_script_transaction() {
     let mut test_context = TestBuilder::new(2322u64);
@@ -291,6 +333,350 @@ fn call_zeroes_flag() {
     );
 }
 
+/// Sets both flags and leaves `$of` nonzero, so every ALU register differs from the
+/// states produced by the callers below.
+fn flag_changing_contract() -> Vec<Instruction> {
+    vec![
+        op::log(RegId::FLAG, RegId::OF, RegId::ERR, RegId::ZERO),
+        op::movi(
+            0x10,
+            (Flags::WRAPPING | Flags::UNSAFEMATH)
+                .bits()
+                .try_into()
+                .unwrap(),
+        ),
+        op::flag(0x10),
+        op::not(0x11, RegId::ZERO),
+        op::div(0x12, 0x11, RegId::ZERO),
+        op::mul(0x12, 0x11, 0x11),
+        op::log(RegId::FLAG, RegId::OF, RegId::ERR, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+}
+
+#[test_case(
+    Flags::WRAPPING,
+    op::add(0x12, 0x11, 0x11),
+    (Flags::WRAPPING.bits(), 1, 0) ; "overflow with wrapping"
+)]
+#[test_case(
+    Flags::UNSAFEMATH,
+    op::div(0x12, 0x11, RegId::ZERO),
+    (Flags::UNSAFEMATH.bits(), 0, 1) ; "division by zero with unsafe math"
+)]
+fn call_ret_restores_caller_flag_of_and_err(
+    flags: Flags,
+    alu_op: Instruction,
+    expected: (Word, Word, Word),
+) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+
+    let contract_id = test_context
+        .setup_contract(flag_changing_contract(), None, None)
+        .contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, flags.bits().try_into().unwrap()),
+            op::flag(0x10),
+            op::movi(0x20, data_offset as Immediate18),
+            op::not(0x11, RegId::ZERO),
+            // The last ALU operation before the call defines `$of` and `$err`.
+            alu_op,
+            op::log(RegId::FLAG, RegId::OF, RegId::ERR, RegId::ZERO),
+            op::call(0x20, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::log(RegId::FLAG, RegId::OF, RegId::ERR, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let result = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(gas_limit)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute();
+
+    let receipts = result.receipts();
+    assert_success(receipts);
+
+    let logs = receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log { ra, rb, rc, .. } => Some((*ra, *rb, *rc)),
+            _ => None,
+        })
+        .collect_vec();
+    let [before_call, callee_entry, callee_exit, after_call] = logs[..] else {
+        panic!("Expected four log receipts, got {logs:?}");
+    };
+
+    assert_eq!(before_call, expected);
+    assert_eq!(
+        callee_entry,
+        (Flags::empty().bits(), expected.1, expected.2),
+        "Call should zero $flag and keep $of and $err"
+    );
+    assert_eq!(
+        callee_exit,
+        (
+            (Flags::WRAPPING | Flags::UNSAFEMATH).bits(),
+            Word::MAX - 1,
+            0
+        ),
+        "Callee should be able to change $flag, $of and $err"
+    );
+    assert_eq!(
+        after_call, expected,
+        "Return should restore $flag, $of and $err of the caller"
+    );
+}
+
+#[test]
+fn panic_in_call_doesnt_return_to_caller() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+
+    let mut program = flag_changing_contract();
+    // Replace the return with a panic caused by the division by zero.
+    program.pop();
+    program.push(op::movi(0x10, 0));
+    program.push(op::flag(0x10));
+    program.push(op::div(0x12, 0x11, RegId::ZERO));
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, Flags::WRAPPING.bits().try_into().unwrap()),
+            op::flag(0x10),
+            op::movi(0x20, data_offset as Immediate18),
+            op::not(0x11, RegId::ZERO),
+            op::add(0x12, 0x11, 0x11),
+            op::call(0x20, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::log(RegId::FLAG, RegId::OF, RegId::ERR, RegId::ONE),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let result = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(gas_limit)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute();
+
+    let receipts = result.receipts();
+
+    let panic = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Panic { id, reason, .. } => Some((*id, *reason.reason())),
+            _ => None,
+        })
+        .expect("Expected a panic receipt");
+    assert_eq!(panic, (contract_id, PanicReason::ArithmeticError));
+
+    // Only the two logs of the callee, the caller never continues after the panic.
+    let logs = receipts
+        .iter()
+        .filter(|receipt| matches!(receipt, Receipt::Log { .. }))
+        .count();
+    assert_eq!(logs, 2);
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Panic,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn flag_defaults_policy_initializes_script_flag() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+
+    // Given
+    let script = vec![
+        op::not(0x11, RegId::ZERO),
+        op::add(0x12, 0x11, 0x11),
+        op::log(RegId::FLAG, RegId::OF, RegId::ERR, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let result = test_context
+        .with_tx_params(TxParameters::DEFAULT.with_allow_flag_defaults(true))
+        .start_script(script, vec![])
+        .script_gas_limit(gas_limit)
+        .flag_defaults(Flags::WRAPPING.bits())
+        .fee_input()
+        .execute();
+
+    // Then
+    let receipts = result.receipts();
+    assert_success(receipts);
+    let logs = receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log { ra, rb, rc, .. } => Some((*ra, *rb, *rc)),
+            _ => None,
+        })
+        .collect_vec();
+    assert_eq!(logs, vec![(Flags::WRAPPING.bits(), 1, 0)]);
+}
+
+#[test]
+fn flag_defaults_policy_initializes_callee_flag() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+
+    let contract_id = test_context
+        .setup_contract(flag_changing_contract(), None, None)
+        .contract_id;
+
+    // Given
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::flag(RegId::ZERO),
+            op::movi(0x20, data_offset as Immediate18),
+            op::call(0x20, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    // When
+    let result = test_context
+        .with_tx_params(TxParameters::DEFAULT.with_allow_flag_defaults(true))
+        .start_script(script, script_data)
+        .script_gas_limit(gas_limit)
+        .flag_defaults(Flags::UNSAFEMATH.bits())
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute();
+
+    // Then
+    let receipts = result.receipts();
+    assert_success(receipts);
+    let callee_entry = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Log { ra, .. } => Some(*ra),
+        _ => None,
+    });
+    assert_eq!(
+        callee_entry,
+        Some(Flags::UNSAFEMATH.bits()),
+        "Call should apply the flag defaults to the callee"
+    );
+}
+
+/// The script sets `WRAPPING`, calls a contract running `callee` and then performs an
+/// overflowing `ADD` itself.
+fn wrapping_caller_with_callee(callee: Vec<Instruction>) -> (ContractId, Vec<Receipt>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+
+    let contract_id = test_context.setup_contract(callee, None, None).contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, Flags::WRAPPING.bits().try_into().unwrap()),
+            op::flag(0x10),
+            op::movi(0x20, data_offset as Immediate18),
+            op::call(0x20, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::not(0x11, RegId::ZERO),
+            op::add(0x12, 0x11, 0x11),
+            op::log(RegId::FLAG, RegId::OF, 0x12, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let result = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(gas_limit)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute();
+
+    (contract_id, result.receipts().to_vec())
+}
+
+#[test]
+fn overflow_in_call_panics_even_if_caller_set_wrapping() {
+    // Given
+    let callee = vec![
+        op::not(0x11, RegId::ZERO),
+        op::add(0x12, 0x11, 0x11),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let (contract_id, receipts) = wrapping_caller_with_callee(callee);
+
+    // Then
+    let panic = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Panic { id, reason, .. } => Some((*id, *reason.reason())),
+            _ => None,
+        })
+        .expect("Expected a panic receipt");
+    assert_eq!(panic, (contract_id, PanicReason::ArithmeticOverflow));
+    assert!(!receipts.iter().any(|r| matches!(r, Receipt::Log { .. })));
+}
+
+#[test_case(op::ret(RegId::ONE) ; "ret")]
+#[test_case(op::retd(RegId::ZERO, RegId::ZERO) ; "retd")]
+fn overflow_after_call_wraps_with_caller_flag(terminator: Instruction) {
+    // Given
+    let callee = vec![
+        op::log(RegId::FLAG, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        terminator,
+    ];
+
+    // When
+    let (_, receipts) = wrapping_caller_with_callee(callee);
+
+    // Then
+    assert_success(&receipts);
+    let logs = receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log { ra, rb, rc, .. } => Some((*ra, *rb, *rc)),
+            _ => None,
+        })
+        .collect_vec();
+    let [(callee_flag, _, _), after_call] = logs[..] else {
+        panic!("Expected two log receipts, got {logs:?}");
+    };
+    assert_eq!(
+        callee_flag,
+        Flags::empty().bits(),
+        "Callee should start with $flag = 0"
+    );
+    assert_eq!(
+        after_call,
+        (Flags::WRAPPING.bits(), 1, Word::MAX - 1),
+        "Caller's overflowing ADD should wrap with the restored $flag"
+    );
+}
+
 #[test]
 fn revert_from_call_immediately_ends_execution() {
     let mut test_context = TestBuilder::new(2322u64);
@@ -339,6 +725,104 @@ fn revert_from_call_immediately_ends_execution() {
     assert_eq!(revert_receipts.len(), 1);
 }
 
+/// The contract logging the `a` and `b` arguments of its call frame, the forwarded
+/// coins and the first word at the address in `b`.
+fn call_arguments_logging_contract() -> Vec<Instruction> {
+    vec![
+        op::addi(0x10, RegId::FP, CallFrame::a_offset() as Immediate12),
+        op::lw(0x10, 0x10, 0),
+        op::addi(0x11, RegId::FP, CallFrame::b_offset() as Immediate12),
+        op::lw(0x11, 0x11, 0),
+        op::log(0x10, 0x11, RegId::BAL, RegId::ZERO),
+        op::lw(0x12, 0x11, 0),
+        op::log(0x12, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+}
+
+fn logged_registers(receipts: &[Receipt]) -> Vec<(Word, Word, Word)> {
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log { ra, rb, rc, .. } => Some((*ra, *rb, *rc)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn call_contract__passes_a_and_b_to_callee() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(call_arguments_logging_contract(), None, None)
+        .contract_id;
+    let a = 0x1234;
+    let b = test_context.call_data_offset();
+
+    // When
+    let receipts =
+        test_context
+            .script_gas_limit(1_000_000)
+            .call_contract(contract_id, a, b);
+
+    // Then
+    assert_success(&receipts);
+    let logs = logged_registers(&receipts);
+    assert_eq!(logs[0], (a, b, 0));
+    // `b` points to the call data, which starts with the id of the callee.
+    let first_word = Word::from_be_bytes(contract_id[..WORD_SIZE].try_into().unwrap());
+    assert_eq!(logs[1].0, first_word);
+}
+
+#[test]
+fn call_contract_with_payload__payload_is_at_call_payload_offset() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(call_arguments_logging_contract(), None, None)
+        .contract_id;
+    let payload_word: Word = 0xdead_beef;
+    let b = test_context.call_data_offset() + CALL_PAYLOAD_OFFSET as Word;
+
+    // When
+    let receipts = test_context
+        .script_gas_limit(1_000_000)
+        .call_contract_with_payload(contract_id, 0, b, &payload_word.to_be_bytes());
+
+    // Then
+    assert_success(&receipts);
+    let logs = logged_registers(&receipts);
+    assert_eq!(logs[0], (0, b, 0));
+    assert_eq!(logs[1].0, payload_word);
+}
+
+#[test]
+fn call_contract_with_coins__forwards_coins_to_callee() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(call_arguments_logging_contract(), None, None)
+        .contract_id;
+    let asset_id: AssetId = test_context.rng.gen();
+    let amount = 100;
+    let b = test_context.call_data_offset();
+
+    // When
+    let receipts = test_context
+        .script_gas_limit(1_000_000)
+        .call_contract_with_coins(contract_id, 7, b, amount, asset_id);
+
+    // Then
+    assert_success(&receipts);
+    let logs = logged_registers(&receipts);
+    assert_eq!(logs[0], (7, b, amount));
+    assert_eq!(
+        test_context.get_contract_balance(&contract_id, &asset_id),
+        amount
+    );
+}
+
 /// Makes sure that infinte recursion with CALL instruction doesn't crash
 #[test]
 fn repeated_nested_calls() {
@@ -357,22 +841,11 @@ fn repeated_nested_calls() {
         )
         .contract_id;
 
-    let script = vec![
-        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
-        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
-        op::ret(RegId::ONE),
-    ];
-    let script_data = Call::new(contract_id, 0, 1000).to_bytes();
-
     // initiate the call to the contract which reverts
-    let result = test_context
-        .start_script(script, script_data)
-        .script_gas_limit(gas_limit)
-        .contract_input(contract_id)
-        .fee_input()
-        .contract_output(&contract_id)
-        .execute();
-    let receipts = result.receipts();
+    let receipts =
+        test_context
+            .script_gas_limit(gas_limit)
+            .call_contract(contract_id, 0, 1000);
 
     if let Receipt::ScriptResult { result, .. } = receipts[receipts.len() - 1] {
         if result != ScriptExecutionResult::Panic {
@@ -586,3 +1059,558 @@ fn logd_from_top_of_heap() {
     assert_eq!(receipts.len(), 3);
     assert!(matches!(receipts[0], Receipt::LogData { .. }));
 }
+
+/// Calls a chain of contracts, one per terminator. Every contract writes to its
+/// storage, calls the next contract, logs `$err` and `$ret` of the call and finishes
+/// with its terminator. Returns the receipts and whether each contract's storage slot
+/// is set afterward.
+fn nested_calls_with_storage_writes<const N: usize>(
+    terminators: [Instruction; N],
+    call_frame_reverts: bool,
+) -> (Vec<Receipt>, [bool; N]) {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_tx_params(
+        TxParameters::DEFAULT.with_call_frame_reverts(call_frame_reverts),
+    );
+    let gas_limit = 1_000_000;
+
+    // Every contract uses the first 32 bytes of the script data as the storage key.
+    let contract_ids: Vec<ContractId> = terminators
+        .into_iter()
+        .enumerate()
+        .map(|(index, terminator)| {
+            let next = index + 1;
+            let mut code = vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::movi(0x11, next as Immediate18),
+                op::sww(0x10, SET_STATUS_REG, 0x11),
+            ];
+            if next < N {
+                code.extend([
+                    op::addi(0x12, 0x10, (next * Call::LEN) as Immediate12),
+                    op::call(0x12, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                    // Only reached if the call returns or its frame is reverted
+                    op::log(0x11, RegId::ERR, RegId::RET, RegId::ZERO),
+                ]);
+            }
+            code.push(terminator);
+            test_context.setup_contract(code, None, None).contract_id
+        })
+        .collect();
+
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = contract_ids
+        .iter()
+        .flat_map(|contract_id| Call::new(*contract_id, 0, 0).to_bytes())
+        .collect_vec();
+    let [DecodedField::B256(key), ..] = decode_packed(
+        &script_data[..Call::LEN],
+        &[FieldKind::B256, FieldKind::U64, FieldKind::U64],
+    )
+    .expect("Script data starts with a call")[..] else {
+        panic!("Expected the contract id of the outermost call");
+    };
+
+    let builder = test_context.start_script(script, script_data);
+    builder.script_gas_limit(gas_limit).fee_input();
+    for contract_id in &contract_ids {
+        builder.contract_input(*contract_id);
+    }
+    for contract_id in &contract_ids {
+        builder.contract_output(contract_id);
+    }
+    let result = builder.execute();
+
+    let storage = test_context.get_storage();
+    let is_set = |index: usize| {
+        *storage.contract_state(&contract_ids[index], &key)
+            != ContractsStateData::default()
+    };
+
+    (result.receipts().to_vec(), core::array::from_fn(is_set))
+}
+
+#[test]
+fn revert_in_nested_call__rolls_back_storage_writes_of_every_frame() {
+    // When
+    let (receipts, [outer_is_set, inner_is_set]) = nested_calls_with_storage_writes(
+        [op::ret(RegId::ONE), op::rvrt(RegId::ONE)],
+        false,
+    );
+
+    // Then
+    // Without the `call_frame_reverts` switch, `RVRT` terminates the whole
+    // transaction, so the outer frame never resumes and its writes are discarded
+    // together with the writes of the inner frame.
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Revert,
+            ..
+        })
+    ));
+    assert!(!receipts.iter().any(|r| matches!(r, Receipt::Log { .. })));
+    assert!(!outer_is_set);
+    assert!(!inner_is_set);
+}
+
+#[test_case(false; "without call frame reverts")]
+#[test_case(true; "with call frame reverts")]
+fn return_from_nested_call__persists_storage_writes_of_every_frame(
+    call_frame_reverts: bool,
+) {
+    // When
+    let (receipts, [outer_is_set, inner_is_set]) = nested_calls_with_storage_writes(
+        [op::ret(RegId::ONE), op::ret(RegId::ONE)],
+        call_frame_reverts,
+    );
+
+    // Then
+    assert_success(&receipts);
+    assert!(receipts.iter().any(|r| matches!(r, Receipt::Log { .. })));
+    assert!(outer_is_set);
+    assert!(inner_is_set);
+}
+
+#[test]
+fn revert_in_nested_call__with_call_frame_reverts__rolls_back_only_the_inner_frame() {
+    // When
+    let (receipts, [outer_is_set, inner_is_set]) = nested_calls_with_storage_writes(
+        [op::ret(RegId::ONE), op::rvrt(RegId::ONE)],
+        true,
+    );
+
+    // Then
+    assert_success(&receipts);
+    assert!(receipts
+        .iter()
+        .any(|r| matches!(r, Receipt::Revert { ra: 1, .. })));
+    // The outer frame resumes with `$err` set and `$ret` holding the revert value
+    assert!(receipts.iter().any(|r| matches!(
+        r,
+        Receipt::Log {
+            ra: 1,
+            rb: 1,
+            rc: 1,
+            ..
+        }
+    )));
+    assert!(outer_is_set);
+    assert!(!inner_is_set);
+}
+
+#[test]
+fn panic_in_nested_call__with_call_frame_reverts__rolls_back_only_the_inner_frame() {
+    // When
+    let (receipts, [outer_is_set, inner_is_set]) = nested_calls_with_storage_writes(
+        [op::ret(RegId::ONE), op::div(0x13, 0x11, RegId::ZERO)],
+        true,
+    );
+
+    // Then
+    assert_success(&receipts);
+    assert!(receipts.iter().any(|r| matches!(
+        r.reason().map(|reason| *reason.reason()),
+        Some(PanicReason::ArithmeticError)
+    )));
+    assert!(receipts.iter().any(|r| matches!(
+        r,
+        Receipt::Log {
+            ra: 1,
+            rb: 1,
+            rc: 0,
+            ..
+        }
+    )));
+    assert!(outer_is_set);
+    assert!(!inner_is_set);
+}
+
+#[test]
+fn revert_after_nested_return__with_call_frame_reverts__rolls_back_the_returned_frame() {
+    // When
+    let (receipts, [outer_is_set, middle_is_set, inner_is_set]) =
+        nested_calls_with_storage_writes(
+            [
+                op::ret(RegId::ONE),
+                op::rvrt(RegId::ONE),
+                op::ret(RegId::ONE),
+            ],
+            true,
+        );
+
+    // Then
+    // The writes of the inner frame are merged into the middle frame on return, so
+    // they are reverted together with it.
+    assert_success(&receipts);
+    assert!(outer_is_set);
+    assert!(!middle_is_set);
+    assert!(!inner_is_set);
+}
+
+#[test]
+fn revert_in_nested_call__with_call_frame_reverts__drops_receipts_of_the_frame() {
+    // When
+    let (receipts, _) = nested_calls_with_storage_writes(
+        [
+            op::ret(RegId::ONE),
+            op::rvrt(RegId::ONE),
+            op::ret(RegId::ONE),
+        ],
+        true,
+    );
+
+    // Then
+    // The `Call` receipt of the reverted frame is kept, followed by its `Revert`
+    let kinds = receipts.iter().map(ReceiptRepr::from).collect_vec();
+    assert_eq!(
+        kinds,
+        vec![
+            ReceiptRepr::Call,
+            ReceiptRepr::Call,
+            ReceiptRepr::Revert,
+            ReceiptRepr::Log,
+            ReceiptRepr::Return,
+            ReceiptRepr::Return,
+            ReceiptRepr::ScriptResult,
+        ]
+    );
+}
+
+#[test]
+fn call_with_coins__with_call_frame_reverts__refunds_coins_of_reverted_frame() {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_tx_params(TxParameters::DEFAULT.with_call_frame_reverts(true));
+    let asset_id: AssetId = test_context.rng.gen();
+    let amount = 100;
+
+    // Given
+    let contract_id = test_context
+        .setup_contract(vec![op::rvrt(RegId::ONE)], None, None)
+        .contract_id;
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Call::LEN as Immediate12),
+        op::movi(0x12, amount as Immediate18),
+        op::call(0x10, 0x12, 0x11, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data =
+        [Call::new(contract_id, 0, 0).to_bytes(), asset_id.to_vec()].concat();
+
+    // When
+    let (_, tx, receipts) = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .coin_input(asset_id, amount)
+        .fee_input()
+        .contract_output(&contract_id)
+        .change_output(asset_id)
+        .execute()
+        .into_inner();
+
+    // Then
+    assert_success(&receipts);
+    assert_eq!(
+        test_context.get_contract_balance(&contract_id, &asset_id),
+        0
+    );
+    let change = tx
+        .outputs()
+        .iter()
+        .find(|output| output.is_change())
+        .and_then(Output::amount);
+    assert_eq!(change, Some(amount));
+}
+
+#[test_case(op::ret(RegId::ONE), false; "returned")]
+#[test_case(op::rvrt(RegId::ONE), true; "reverted")]
+fn transfers_in_call__with_call_frame_reverts__are_kept_unless_reverted(
+    terminator: Instruction,
+    reverted: bool,
+) {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_tx_params(TxParameters::DEFAULT.with_call_frame_reverts(true));
+    let asset_id: AssetId = test_context.rng.gen();
+    let balance = 100;
+    let transferred = 30;
+    let transferred_out = 20;
+
+    // Given
+    let recipient = test_context
+        .setup_contract(vec![op::ret(RegId::ONE)], None, None)
+        .contract_id;
+    // The script data is the call, the recipient and the asset id
+    let sender = test_context
+        .setup_contract(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::addi(0x11, 0x10, Call::LEN as Immediate12),
+                op::addi(0x12, 0x11, ContractId::LEN as Immediate12),
+                op::movi(0x13, transferred as Immediate18),
+                op::tr(0x11, 0x13, 0x12),
+                op::movi(0x13, transferred_out as Immediate18),
+                op::movi(0x14, 2),
+                op::tro(0x10, 0x14, 0x13, 0x12),
+                terminator,
+            ],
+            Some((asset_id, balance)),
+            None,
+        )
+        .contract_id;
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = [
+        Call::new(sender, 0, 0).to_bytes(),
+        recipient.to_vec(),
+        asset_id.to_vec(),
+    ]
+    .concat();
+
+    // When
+    let (_, tx, receipts) = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(sender)
+        .contract_input(recipient)
+        .fee_input()
+        .contract_output(&sender)
+        .contract_output(&recipient)
+        .variable_output(asset_id)
+        .execute()
+        .into_inner();
+
+    // Then
+    assert_success(&receipts);
+    let sender_balance = test_context.get_contract_balance(&sender, &asset_id);
+    let recipient_balance = test_context.get_contract_balance(&recipient, &asset_id);
+    let Some(Output::Variable { amount, .. }) = tx.outputs().get(2).copied() else {
+        panic!("Expected the variable output");
+    };
+    let has_transfer_receipts = receipts
+        .iter()
+        .any(|r| matches!(r, Receipt::Transfer { .. } | Receipt::TransferOut { .. }));
+    if reverted {
+        assert_eq!(sender_balance, balance);
+        assert_eq!(recipient_balance, 0);
+        assert_eq!(amount, 0);
+        assert!(!has_transfer_receipts);
+    } else {
+        assert_eq!(sender_balance, balance - transferred - transferred_out);
+        assert_eq!(recipient_balance, transferred);
+        assert_eq!(amount, transferred_out);
+        assert!(has_transfer_receipts);
+    }
+}
+
+#[test_case(op::ret(RegId::ONE), false; "returned")]
+#[test_case(op::rvrt(RegId::ONE), true; "reverted")]
+fn swwq_and_scwq_in_call__with_call_frame_reverts__are_kept_unless_reverted(
+    terminator: Instruction,
+    reverted: bool,
+) {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_tx_params(TxParameters::DEFAULT.with_call_frame_reverts(true));
+    let key = Bytes32::new([0x11; 32]);
+    let mut next_key = key;
+    next_key[31] = 0x12;
+    let initial = Bytes32::new([1; 32]);
+    let written = [Bytes32::new([2; 32]), Bytes32::new([3; 32])];
+
+    // Given
+    // The script data is the call, the key and the two written values. The contract
+    // writes both slots and then clears the first one.
+    let contract_id = test_context
+        .setup_contract(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::addi(0x11, 0x10, Call::LEN as Immediate12),
+                op::addi(0x12, 0x11, Bytes32::LEN as Immediate12),
+                op::movi(0x13, 2),
+                op::swwq(0x11, SET_STATUS_REG, 0x12, 0x13),
+                op::scwq(0x11, SET_STATUS_REG, RegId::ONE),
+                terminator,
+            ],
+            None,
+            Some(vec![StorageSlot::new(key, initial)]),
+        )
+        .contract_id;
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = [
+        Call::new(contract_id, 0, 0).to_bytes(),
+        key.to_vec(),
+        written[0].to_vec(),
+        written[1].to_vec(),
+    ]
+    .concat();
+
+    // When
+    let receipts = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute()
+        .receipts()
+        .to_vec();
+
+    // Then
+    assert_success(&receipts);
+    let slots = test_context
+        .get_storage()
+        .contract_state_range(&contract_id, &key, 2)
+        .expect("Infallible")
+        .into_iter()
+        .map(|value| value.map(|value| value.into_owned()))
+        .collect_vec();
+    if reverted {
+        assert_eq!(slots, vec![Some(initial.as_ref().into()), None]);
+    } else {
+        assert_eq!(slots, vec![None, Some(written[1].as_ref().into())]);
+    }
+}
+
+#[cfg(feature = "debugger")]
+#[test]
+fn call__saved_registers__match_the_documented_layout() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let forwarded_gas = 100_000;
+
+    // Given
+    let contract_id = test_context
+        .setup_contract(
+            vec![
+                op::addi(
+                    0x10,
+                    RegId::FP,
+                    CallFrame::registers_offset() as Immediate12,
+                ),
+                op::movi(0x11, (VM_REGISTER_COUNT * WORD_SIZE) as Immediate18),
+                op::logd(RegId::ZERO, RegId::ZERO, 0x10, 0x11),
+                op::ret(RegId::ONE),
+            ],
+            None,
+            None,
+        )
+        .contract_id;
+    let script = vec![
+        op::movi(0x20, 0x20),
+        op::movi(0x3f, 0x3f),
+        op::movi(0x10, Flags::WRAPPING.bits().try_into().unwrap()),
+        op::flag(0x10),
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::movi(0x11, forwarded_gas as Immediate18),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let call_index = 6;
+    let tx = test_context
+        .start_script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build()
+        .test_into_ready();
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        test_context.get_storage().clone(),
+        crate::interpreter::InterpreterParams::new(0, ConsensusParameters::standard()),
+    );
+    vm.set_breakpoint(Breakpoint::script(call_index));
+    vm.set_breakpoint(Breakpoint::new(contract_id, 0));
+
+    // When
+    let state = *vm.transact(tx).expect("panicked").state();
+    assert!(matches!(state, ProgramState::RunProgram(_)));
+    let caller_registers: [Word; VM_REGISTER_COUNT] = vm
+        .registers()
+        .try_into()
+        .expect("The registers have the fixed count");
+
+    let state = vm.resume().expect("Failed to resume");
+    assert!(matches!(state, ProgramState::RunProgram(_)));
+    let frame = vm
+        .call_stack()
+        .last()
+        .expect("Expected a call frame")
+        .clone();
+    let frame_bytes = vm
+        .memory()
+        .read(vm.registers()[RegId::FP], CallFrame::serialized_size())
+        .expect("The frame is in the memory")
+        .to_vec();
+
+    let state = vm.resume().expect("Failed to resume");
+    assert_eq!(state, ProgramState::Return(1));
+
+    // Then
+    let saved = frame.registers();
+    for reg in 0..VM_REGISTER_COUNT as u8 {
+        let reg = RegId::new(reg);
+        assert_eq!(
+            CallFrame::read_saved_register(&frame_bytes, reg),
+            Some(saved[reg]),
+            "Saved {reg:?} should be read from the documented offset"
+        );
+        if reg != RegId::CGAS && reg != RegId::GGAS {
+            assert_eq!(
+                saved[reg], caller_registers[reg],
+                "Saved {reg:?} should be the value of the caller"
+            );
+        }
+    }
+    assert_eq!(
+        CallFrame::read_saved_register(&frame_bytes, SavedRegister::Flag),
+        Some(Flags::WRAPPING.bits())
+    );
+    assert_eq!(
+        CallFrame::read_saved_register(&frame_bytes, SavedRegister::Program(0x10)),
+        Some(0x20)
+    );
+    assert!(
+        saved[RegId::CGAS] <= caller_registers[RegId::CGAS] - forwarded_gas,
+        "Saved $cgas should exclude the forwarded gas"
+    );
+
+    let logged = vm.receipts().iter().find_map(|receipt| match receipt {
+        Receipt::LogData { data, .. } => data.clone(),
+        _ => None,
+    });
+    let expected = saved
+        .iter()
+        .flat_map(|value| value.to_be_bytes())
+        .collect::<Vec<u8>>();
+    assert_eq!(logged, Some(expected));
+}
+
+#[test]
+fn saved_register__index__matches_the_register_id() {
+    for reg in 0..VM_REGISTER_COUNT as u8 {
+        // Given
+        let reg = RegId::new(reg);
+
+        // When
+        let saved = SavedRegister::from(reg);
+
+        // Then
+        assert_eq!(saved.index(), reg.to_u8() as usize);
+    }
+    assert!(SavedRegister::SYSTEM
+        .iter()
+        .enumerate()
+        .all(|(index, reg)| reg.index() == index));
+}
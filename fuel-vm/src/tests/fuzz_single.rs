@@ -0,0 +1,122 @@
+use alloc::vec;
+
+use fuel_asm::{
+    op,
+    Instruction,
+    Opcode,
+    PanicReason,
+};
+use fuel_types::{
+    AssetId,
+    ContractId,
+};
+
+use crate::{
+    consts::VM_MAX_RAM,
+    state::ExecuteState,
+    util::{
+        fuzz_single,
+        FuzzContract,
+        FuzzVmState,
+        SingleStepOutcome,
+    },
+};
+
+#[test]
+fn fuzz_single__every_opcode__never_unwinds() {
+    for opcode in 0..=u8::MAX {
+        if Opcode::try_from(opcode).is_err() {
+            continue
+        }
+        // Registers 0x10, 0x11, 0x12 and 0x13, or the immediate values made of them.
+        let raw = u32::from_be_bytes([opcode, 0x41, 0x14, 0x93]);
+        let Ok(instruction) = Instruction::try_from(raw) else {
+            continue
+        };
+
+        // When
+        let outcome = fuzz_single(instruction, FuzzVmState::default());
+
+        // Then
+        assert!(
+            matches!(
+                outcome,
+                SingleStepOutcome::Executed { .. } | SingleStepOutcome::Panicked { .. }
+            ),
+            "{instruction:?}: {outcome:?}"
+        );
+    }
+}
+
+#[test]
+fn fuzz_single__seeded_registers__are_used() {
+    // Given
+    let state = FuzzVmState {
+        registers: vec![7, 0],
+        ..Default::default()
+    };
+
+    // When
+    let outcome = fuzz_single(op::div(0x12, 0x10, 0x11), state);
+
+    // Then
+    assert!(
+        matches!(
+            outcome,
+            SingleStepOutcome::Panicked {
+                reason: PanicReason::ArithmeticError,
+                ..
+            }
+        ),
+        "{outcome:?}"
+    );
+}
+
+#[test]
+fn fuzz_single__seeded_contract__is_in_inputs() {
+    // Given
+    let asset_id = AssetId::new([1; 32]);
+    let contract_id = ContractId::new([2; 32]);
+    let memory = [asset_id.as_ref(), contract_id.as_ref()].concat();
+    let registers = vec![0, VM_MAX_RAM - 64, VM_MAX_RAM - 32];
+    let instruction = op::bal(0x10, 0x11, 0x12);
+    let without_contract = FuzzVmState {
+        registers,
+        memory,
+        contracts: vec![],
+    };
+    let with_contract = FuzzVmState {
+        contracts: vec![FuzzContract {
+            id: contract_id,
+            balances: vec![(asset_id, 100)],
+            ..Default::default()
+        }],
+        ..without_contract.clone()
+    };
+
+    // When
+    let missing = fuzz_single(instruction, without_contract);
+    let present = fuzz_single(instruction, with_contract);
+
+    // Then
+    assert!(
+        matches!(
+            missing,
+            SingleStepOutcome::Panicked {
+                reason: PanicReason::ContractNotInInputs,
+                ..
+            }
+        ),
+        "{missing:?}"
+    );
+    assert!(
+        matches!(
+            present,
+            SingleStepOutcome::Executed {
+                state: ExecuteState::Proceed,
+                gas_used,
+            } if gas_used > 0
+        ),
+        "{present:?}"
+    );
+}
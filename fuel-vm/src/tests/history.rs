@@ -0,0 +1,120 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+
+/// The script data makes the id of every transaction unique.
+fn script(program: Vec<Instruction>, nonce: u8) -> Checked<Script> {
+    TransactionBuilder::script(program.into_iter().collect(), vec![nonce])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default())
+}
+
+fn success(nonce: u8) -> Checked<Script> {
+    script(vec![op::ret(RegId::ONE)], nonce)
+}
+
+fn revert(nonce: u8) -> Checked<Script> {
+    script(vec![op::rvrt(RegId::ONE)], nonce)
+}
+
+fn history_ids(
+    transactor: &Transactor<MemoryInstance, MemoryStorage, Script>,
+) -> Vec<TxId> {
+    transactor.history().map(|(id, _)| *id).collect()
+}
+
+#[test]
+fn history__keeps_only_last_transaction_by_default() {
+    // Given
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    let (first, second) = (success(0), success(1));
+    let (first_id, second_id) = (first.id(), second.id());
+
+    // When
+    transactor.transact(first);
+    transactor.transact(second);
+
+    // Then
+    assert_eq!(history_ids(&transactor), vec![second_id]);
+    assert!(transactor.find_by_txid(&first_id).is_none());
+}
+
+#[test]
+fn history__is_ordered_from_oldest_and_evicts_at_capacity() {
+    // Given
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.with_history(3);
+    let txs: Vec<_> = (0..5).map(success).collect();
+    let ids: Vec<_> = txs.iter().map(Checked::id).collect();
+
+    // When
+    for tx in txs {
+        transactor.transact(tx);
+    }
+
+    // Then
+    assert_eq!(history_ids(&transactor), ids[2..]);
+    assert!(transactor.find_by_txid(&ids[1]).is_none());
+    let transition = transactor
+        .find_by_txid(&ids[4])
+        .expect("The last transaction is in the history");
+    assert_eq!(transition.receipts(), transactor.receipts().unwrap());
+}
+
+#[test]
+fn history__records_reverted_transactions() {
+    // Given
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.with_history(2);
+    let (reverted, succeeded) = (revert(0), success(1));
+    let (reverted_id, succeeded_id) = (reverted.id(), succeeded.id());
+
+    // When
+    transactor.transact(reverted);
+    transactor.transact(succeeded);
+
+    // Then
+    assert_eq!(history_ids(&transactor), vec![reverted_id, succeeded_id]);
+    let transition = transactor.find_by_txid(&reverted_id).unwrap();
+    assert!(transition.should_revert());
+    assert!(matches!(
+        transition.receipts().last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Revert,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn with_history__shrinking_evicts_oldest_and_zero_disables() {
+    // Given
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::default();
+    transactor.with_history(3);
+    let txs: Vec<_> = (0..3).map(success).collect();
+    let last_id = txs[2].id();
+    for tx in txs {
+        transactor.transact(tx);
+    }
+
+    // When
+    transactor.with_history(1);
+
+    // Then
+    assert_eq!(history_ids(&transactor), vec![last_id]);
+
+    transactor.with_history(0);
+    transactor.transact(success(3));
+    assert_eq!(transactor.history().count(), 0);
+}
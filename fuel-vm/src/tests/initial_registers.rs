@@ -0,0 +1,99 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::Ready,
+    prelude::*,
+    tests::test_helpers::assert_success,
+    util::test_helpers::TestBuilder,
+};
+use fuel_asm::{
+    op,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::Receipt;
+use test_case::test_case;
+
+fn script_tx(script: Vec<Instruction>) -> Ready<Script> {
+    TestBuilder::new(2322u64)
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build()
+        .test_into_ready()
+}
+
+#[test]
+fn initial_registers__are_visible_to_the_first_instruction() {
+    let first = RegId::WRITABLE;
+    let last = RegId::new(0x3f);
+
+    let result = TestBuilder::new(2322u64)
+        .start_script(
+            vec![
+                op::log(first, last, RegId::ZERO, RegId::ZERO),
+                op::ret(RegId::ONE),
+            ],
+            vec![],
+        )
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .initial_registers(&[(first, Word::MAX), (last, 0x1234)])
+        .execute();
+
+    let receipts = result.receipts();
+    assert_success(receipts);
+    assert!(matches!(
+        receipts[0],
+        Receipt::Log {
+            ra: Word::MAX,
+            rb: 0x1234,
+            ..
+        }
+    ));
+}
+
+#[test_case(RegId::ZERO)]
+#[test_case(RegId::ONE)]
+#[test_case(RegId::PC)]
+#[test_case(RegId::SP)]
+#[test_case(RegId::FLAG)]
+#[test_case(RegId::new(0x0f))]
+fn initial_registers__reserved_register_is_rejected(reg: RegId) {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    let tx = script_tx(vec![op::ret(RegId::ONE)]);
+
+    let result = vm
+        .transact_with_initial_registers(tx, &[(reg, 1)])
+        .map(|_| ());
+
+    assert_eq!(
+        result,
+        Err(InterpreterError::Panic(
+            PanicReason::ReservedRegisterNotWritable
+        ))
+    );
+}
+
+#[test]
+fn set_writable_register__rejected_before_initialization() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+
+    let result = vm.set_writable_register(RegId::WRITABLE, 1);
+
+    assert_eq!(result, Err(InterpreterError::NoTransactionInitialized));
+}
+
+#[test]
+fn set_writable_register__rejected_after_execution_started() {
+    let mut vm = Interpreter::<_, _, Script>::with_memory_storage();
+    let tx = script_tx(vec![op::ret(RegId::ONE)]);
+    vm.transact(tx).expect("failed to execute the script");
+
+    let result = vm.set_writable_register(RegId::WRITABLE, 1);
+
+    assert_eq!(result, Err(InterpreterError::NoTransactionInitialized));
+}
@@ -0,0 +1,355 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::InterpreterParams,
+    prelude::*,
+    script_with_data_offset,
+    storage::{
+        ContractsStateData,
+        InstrumentedStorage,
+        StorageMetrics,
+    },
+    tests::test_helpers::assert_success,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::Outputs,
+    TxParameters,
+};
+use fuel_types::canonical::Serialize;
+
+/// Routine `0` adds the word to the state slot with `SRW` and `SWW`, routine `1`
+/// copies the slot with `SRWQ` and `SWWQ`.
+fn contract_program() -> Vec<Instruction> {
+    vec![
+        op::addi(0x10, RegId::FP, CallFrame::a_offset() as Immediate12),
+        op::lw(0x10, 0x10, 0),
+        op::addi(0x11, RegId::FP, CallFrame::b_offset() as Immediate12),
+        op::lw(0x11, 0x11, 0),
+        op::jnei(0x10, RegId::ZERO, 9),
+        op::lw(0x20, 0x11, 4),
+        op::srw(0x21, 0x22, 0x11),
+        op::sww(0x11, 0x22, 0x20),
+        op::ret(RegId::ONE),
+        op::movi(0x20, Bytes32::LEN as u32),
+        op::aloc(0x20),
+        op::srwq(RegId::HP, 0x22, 0x11, RegId::ONE),
+        op::swwq(0x11, 0x22, RegId::HP, RegId::ONE),
+        op::ret(RegId::ONE),
+    ]
+}
+
+fn code_len() -> u64 {
+    (contract_program().len() * Instruction::SIZE) as u64
+}
+
+fn deploy<S>(transactor: &mut Transactor<MemoryInstance, S, Script>) -> ContractId
+where
+    S: InterpreterStorage,
+{
+    let program: Witness = contract_program().into_iter().collect::<Vec<u8>>().into();
+    let create = TransactionBuilder::create(program, Salt::zeroed(), vec![])
+        .add_fee_input()
+        .add_contract_created()
+        .finalize_checked(Default::default());
+
+    transactor
+        .deploy(create)
+        .expect("Failed to deploy the contract")
+        .outputs()
+        .iter()
+        .find_map(|output| match output {
+            Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+            _ => None,
+        })
+        .expect("Expected `ContractCreated` output")
+}
+
+fn script(
+    contract_id: ContractId,
+    routine: Word,
+    key: Bytes32,
+    value: Word,
+) -> Checked<Script> {
+    let (script, data_offset) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        TxParameters::DEFAULT.tx_offset()
+    );
+    let call_data_offset = data_offset as usize + Call::LEN;
+
+    let mut script_data =
+        Call::new(contract_id, routine, call_data_offset as Word).to_bytes();
+    script_data.extend(key.as_ref());
+    script_data.extend(value.to_be_bytes());
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default())
+}
+
+fn call<S>(
+    transactor: &mut Transactor<MemoryInstance, S, Script>,
+    contract_id: ContractId,
+    routine: Word,
+) -> Vec<Receipt>
+where
+    S: InterpreterStorage,
+{
+    let key = Hasher::hash(b"some key");
+    let receipts = transactor
+        .transact(script(contract_id, routine, key, 150))
+        .receipts()
+        .expect("Failed to execute the script")
+        .to_vec();
+    assert_success(&receipts);
+    receipts
+}
+
+#[test]
+fn instrumented_storage__state_read_write__counts_operations() {
+    // Given
+    let mut transactor = Transactor::<
+        MemoryInstance,
+        InstrumentedStorage<MemoryStorage>,
+        Script,
+    >::default();
+    let contract_id = deploy(&mut transactor);
+    transactor.take_storage_metrics();
+
+    // When
+    call(&mut transactor, contract_id, 0);
+
+    // Then
+    assert_eq!(
+        transactor.take_storage_metrics(),
+        StorageMetrics {
+            state_reads: 1,
+            state_writes: 1,
+            new_slots: 1,
+            code_reads: 1,
+            code_size_lookups: 1,
+            bytes_read: code_len(),
+        }
+    );
+
+    // When
+    call(&mut transactor, contract_id, 0);
+
+    // Then
+    assert_eq!(
+        transactor.take_storage_metrics(),
+        StorageMetrics {
+            state_reads: 1,
+            state_writes: 1,
+            new_slots: 0,
+            code_reads: 1,
+            code_size_lookups: 1,
+            bytes_read: code_len() + Bytes32::LEN as u64,
+        }
+    );
+
+    // When
+    call(&mut transactor, contract_id, 1);
+
+    // Then
+    assert_eq!(
+        transactor.take_storage_metrics(),
+        StorageMetrics {
+            state_reads: 1,
+            state_writes: 1,
+            new_slots: 0,
+            code_reads: 1,
+            code_size_lookups: 1,
+            bytes_read: code_len() + Bytes32::LEN as u64,
+        }
+    );
+    assert_eq!(transactor.storage_metrics(), StorageMetrics::default());
+}
+
+#[test]
+fn instrumented_storage__produces_same_results_as_underlying_storage() {
+    let mut plain = Transactor::<MemoryInstance, MemoryStorage, Script>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+    );
+    let mut instrumented =
+        Transactor::<MemoryInstance, InstrumentedStorage<MemoryStorage>, Script>::new(
+            MemoryInstance::new(),
+            InstrumentedStorage::new(MemoryStorage::default()),
+            InterpreterParams::default(),
+        );
+
+    let plain_id = deploy(&mut plain);
+    let instrumented_id = deploy(&mut instrumented);
+    assert_eq!(plain_id, instrumented_id);
+
+    for routine in [0, 0, 1, 0] {
+        assert_eq!(
+            call(&mut plain, plain_id, routine),
+            call(&mut instrumented, instrumented_id, routine)
+        );
+    }
+
+    let key = Hasher::hash(b"some key");
+    let plain_state = AsRef::<MemoryStorage>::as_ref(&plain)
+        .contract_state(&plain_id, &key)
+        .into_owned();
+    let instrumented_state = AsRef::<InstrumentedStorage<_>>::as_ref(&instrumented)
+        .inner()
+        .contract_state(&instrumented_id, &key)
+        .into_owned();
+    assert_ne!(plain_state, ContractsStateData::default());
+    assert_eq!(plain_state, instrumented_state);
+}
+
+fn transact<S>(
+    transactor: &mut Transactor<MemoryInstance, S, Script>,
+    contract_id: ContractId,
+    script: Vec<Instruction>,
+    script_data: Vec<u8>,
+) -> Vec<Receipt>
+where
+    S: InterpreterStorage,
+{
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default());
+    let receipts = transactor
+        .transact(tx)
+        .receipts()
+        .expect("Failed to execute the script")
+        .to_vec();
+    assert_success(&receipts);
+    receipts
+}
+
+fn logged_registers(receipts: &[Receipt]) -> Vec<(Word, Word)> {
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log { ra, rb, .. } => Some((*ra, *rb)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn instrumented_storage__repeated_csiz_and_croo__read_code_once() {
+    // Given
+    let mut transactor = Transactor::<
+        MemoryInstance,
+        InstrumentedStorage<MemoryStorage>,
+        Script,
+    >::default();
+    let contract_id = deploy(&mut transactor);
+    transactor.take_storage_metrics();
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::movi(0x11, Bytes32::LEN as u32),
+        op::aloc(0x11),
+        op::log(RegId::GGAS, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::csiz(0x20, 0x10),
+        op::log(RegId::GGAS, 0x20, RegId::ZERO, RegId::ZERO),
+        op::csiz(0x20, 0x10),
+        op::log(RegId::GGAS, 0x20, RegId::ZERO, RegId::ZERO),
+        op::croo(RegId::HP, 0x10),
+        op::log(RegId::GGAS, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::croo(RegId::HP, 0x10),
+        op::log(RegId::GGAS, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x11),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = transact(&mut transactor, contract_id, script, contract_id.to_vec());
+
+    // Then
+    let logs = logged_registers(&receipts);
+    let gas = |i: usize| logs[i].0 - logs[i + 1].0;
+    assert_eq!(logs[1].1, code_len());
+    assert_eq!(logs[2].1, code_len());
+    assert_eq!(gas(0), gas(1), "Cached CSIZ must charge the same gas");
+    assert_eq!(gas(2), gas(3), "Cached CROO must charge the same gas");
+    let root = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::LogData { data, .. } => data.clone(),
+        _ => None,
+    });
+    let code: Vec<u8> = contract_program().into_iter().collect();
+    assert_eq!(root, Some(Contract::root_from_code(code).to_vec()));
+    assert_eq!(
+        transactor.take_storage_metrics(),
+        StorageMetrics {
+            code_reads: 1,
+            code_size_lookups: 1,
+            bytes_read: code_len(),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn instrumented_storage__csiz_after_call__reuses_code_size() {
+    // Given
+    let mut transactor = Transactor::<
+        MemoryInstance,
+        InstrumentedStorage<MemoryStorage>,
+        Script,
+    >::default();
+    let contract_id = deploy(&mut transactor);
+    transactor.take_storage_metrics();
+    let (script, data_offset) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::csiz(0x20, 0x10),
+            op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ],
+        TxParameters::DEFAULT.tx_offset()
+    );
+    let mut script_data =
+        Call::new(contract_id, 0, data_offset as Word + Call::LEN as Word).to_bytes();
+    script_data.extend(Hasher::hash(b"some key").as_ref());
+    script_data.extend(150u64.to_be_bytes());
+
+    // When
+    let receipts = transact(&mut transactor, contract_id, script, script_data);
+
+    // Then
+    assert_eq!(logged_registers(&receipts), vec![(code_len(), 0)]);
+    let metrics = transactor.take_storage_metrics();
+    assert_eq!(metrics.code_reads, 1);
+    assert_eq!(metrics.code_size_lookups, 1);
+}
@@ -1,13 +1,27 @@
 #![cfg(feature = "std")]
 
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
 use fuel_asm::{
     op,
+    GTFArgs,
     Imm18,
     Instruction,
     PanicReason,
     RegId,
 };
-use fuel_tx::Receipt;
+use fuel_tx::{
+    BlobIdExt,
+    Receipt,
+    TxParameters,
+};
+use fuel_types::{
+    canonical::Serialize,
+    BlobId,
+};
 use rstest::rstest;
 
 use super::test_helpers::{
@@ -15,6 +29,7 @@ use super::test_helpers::{
     assert_success,
     run_script,
 };
+use crate::prelude::TestBuilder;
 
 #[rstest]
 fn relative_jump_forwards(
@@ -173,3 +188,166 @@ fn relative_jump_repeat_loop() {
         }
     }
 }
+
+/// The builder of the transactions keeping the relative jumps within the code.
+fn within_code() -> TestBuilder {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context
+        .with_tx_params(TxParameters::DEFAULT.with_relative_jumps_within_code(true));
+    test_context
+}
+
+fn run_script_within_code(script: Vec<Instruction>) -> Vec<Receipt> {
+    within_code()
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute()
+        .receipts()
+        .to_vec()
+}
+
+#[rstest]
+fn relative_jump_dynamic_outside_of_script(
+    #[values(
+        op::jmpf(0x20, 0),
+        op::jnzf(RegId::ONE, 0x20, 0),
+        op::jmpb(0x20, 0),
+        op::jnzb(RegId::ONE, 0x20, 0)
+    )]
+    jump: Instruction,
+) {
+    // Given
+    #[rustfmt::skip]
+    let script = vec![
+        op::movi(0x20, 100),
+        jump,
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = run_script_within_code(script);
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
+
+#[rstest]
+#[case::within_code(true, Some(PanicReason::MemoryOverflow))]
+#[case::anywhere(false, None)]
+fn relative_jump_into_script_data(
+    #[case] relative_jumps_within_code: bool,
+    #[case] panic: Option<PanicReason>,
+) {
+    // Given
+    let script = vec![op::jmpf(RegId::ZERO, 1), op::rvrt(RegId::ONE)];
+    let script_data: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+
+    // When
+    let state = TestBuilder::new(2322u64)
+        .with_tx_params(
+            TxParameters::DEFAULT
+                .with_relative_jumps_within_code(relative_jumps_within_code),
+        )
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute();
+
+    // Then
+    match panic {
+        Some(reason) => assert_panics(state.receipts(), reason),
+        None => assert_success(state.receipts()),
+    }
+}
+
+#[test]
+fn relative_jump_into_loaded_code() {
+    // Given
+    let mut test_context = within_code();
+    let blob: Vec<u8> = [
+        op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let blob_len = blob.len() as u32;
+    let blob_id = BlobId::compute(&blob);
+    test_context.setup_blob(blob);
+    #[rustfmt::skip]
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::move_(0x11, RegId::SSP),
+        op::movi(0x12, blob_len),
+        op::ldc(0x10, RegId::ZERO, 0x12, 1),
+        // The jump is three instructions after reading the `$pc`, and skips one
+        // more instruction than its offset
+        op::sub(0x13, 0x11, RegId::PC),
+        op::divi(0x13, 0x13, Instruction::SIZE as u16),
+        op::subi(0x13, 0x13, 4),
+        op::jmpf(0x13, 0),
+        op::rvrt(RegId::ONE),
+    ];
+
+    // When
+    let state = test_context
+        .start_script(script, blob_id.to_bytes())
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute();
+
+    // Then
+    let receipts = state.receipts();
+    assert_success(receipts);
+    assert!(matches!(receipts[0], Receipt::Log { ra: 1, .. }));
+}
+
+#[rstest]
+fn relative_jump_to_last_instruction(
+    #[values(op::jmpf(0x20, 0), op::jnzf(RegId::ONE, 0x20, 0))] jump: Instruction,
+) {
+    // Given
+    #[rustfmt::skip]
+    let script = vec![
+        op::movi(0x20, 2),
+        jump,
+        op::rvrt(RegId::ONE),
+        op::rvrt(RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = run_script_within_code(script);
+
+    // Then
+    assert_success(&receipts);
+}
+
+#[rstest]
+#[case::last_instruction(1, None)]
+#[case::after_the_code(2, Some(PanicReason::MemoryOverflow))]
+fn relative_jump_in_contract(#[case] offset: u32, #[case] panic: Option<PanicReason>) {
+    // Given
+    let mut test_context = within_code();
+    let contract_code = vec![
+        op::movi(0x20, offset),
+        op::jmpf(0x20, 0),
+        op::rvrt(RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context
+        .setup_contract(contract_code, None, None)
+        .contract_id;
+
+    // When
+    let receipts =
+        test_context
+            .script_gas_limit(1_000_000)
+            .call_contract(contract_id, 0, 0);
+
+    // Then
+    match panic {
+        Some(reason) => assert_panics(&receipts, reason),
+        None => assert_success(&receipts),
+    }
+}
@@ -1,12 +1,21 @@
 use fuel_asm::{
     op,
+    Imm18,
+    Instruction,
     RegId,
 };
 use fuel_tx::{
     ConsensusParameters,
     TransactionBuilder,
 };
-use fuel_vm::prelude::*;
+use fuel_vm::{
+    interpreter::{
+        InterpreterParams,
+        NotSupportedEcal,
+    },
+    prelude::*,
+    util::build_script_with_data,
+};
 use rand::{
     rngs::StdRng,
     Rng,
@@ -76,3 +85,49 @@ fn cannot_exceed_max_witnesses() {
         .into_checked(0u32.into(), &params)
         .expect_err("Tx is invalid and shouldn't validate");
 }
+
+#[test]
+fn build_script_with_data__script_reads_data_beyond_imm18_offset() {
+    let mut params = ConsensusParameters::standard();
+    params.set_tx_params(params.tx_params().with_max_size(1024 * 1024));
+    let expected_value: Word = 0xdead_beef;
+
+    // Given
+    let mut body = vec![
+        op::lw(0x11, 0x10, 0),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    // Unreachable padding that pushes the script data beyond the `Imm18` range.
+    let padding = (Imm18::MAX.to_u32() as usize).div_ceil(Instruction::SIZE);
+    body.extend(core::iter::repeat(op::noop()).take(padding));
+    let (script, data_offset) =
+        build_script_with_data(RegId::new(0x10), body, params.tx_params().tx_offset());
+    assert!(data_offset > Word::from(Imm18::MAX.to_u32()));
+
+    let tx = TransactionBuilder::script(
+        script.into_iter().collect(),
+        expected_value.to_be_bytes().to_vec(),
+    )
+    .script_gas_limit(1_000_000)
+    .max_fee_limit(0)
+    .add_fee_input()
+    .finalize()
+    .into_checked(0u32.into(), &params)
+    .expect("Tx should be valid");
+
+    // When
+    let mut client = MemoryClient::<_, NotSupportedEcal>::new(
+        MemoryInstance::new(),
+        Default::default(),
+        InterpreterParams::new(0, &params),
+    );
+    let receipts = client.transact(tx);
+
+    // Then
+    let logged = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Log { ra, .. } => Some(*ra),
+        _ => None,
+    });
+    assert_eq!(logged, Some(expected_value));
+}
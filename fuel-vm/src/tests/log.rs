@@ -4,6 +4,7 @@ use alloc::{
 };
 
 use super::test_helpers;
+use crate::util::test_helpers::TestBuilder;
 use fuel_asm::*;
 use fuel_tx::Receipt;
 use fuel_types::Immediate24;
@@ -64,12 +65,20 @@ fn logd_memory_out_of_range_fails() {
 
 #[test]
 fn logd_just_below_memory_limit_succeeds() {
-    let mut script = set_full_word(0x30, VM_MAX_RAM - 100);
-    script.push(op::movi(0x31, 100));
-    script.push(op::aloc(0x31));
-    script.push(op::logd(RegId::ZERO, RegId::ZERO, 0x30, 0x31));
-    script.push(op::ret(RegId::ONE));
+    let script = vec![
+        op::aloc(0x31),
+        op::logd(RegId::ZERO, RegId::ZERO, 0x30, 0x31),
+        op::ret(RegId::ONE),
+    ];
 
-    let receipts = run_script(script.into_iter().collect());
-    assert_success(&receipts);
+    let result = TestBuilder::new(2322u64)
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .initial_registers(&[
+            (RegId::new(0x30), VM_MAX_RAM - 100),
+            (RegId::new(0x31), 100),
+        ])
+        .execute();
+    assert_success(result.receipts());
 }
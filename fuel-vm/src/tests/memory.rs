@@ -14,6 +14,7 @@ use fuel_asm::{
 };
 use fuel_tx::Receipt;
 use fuel_vm::{
+    checked_transaction::Checked,
     consts::VM_MAX_RAM,
     interpreter::InterpreterParams,
     prelude::*,
@@ -24,34 +25,48 @@ use super::test_helpers::{
     assert_success,
     run_script,
     set_full_word,
+    RunResult,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    TxParameters,
 };
-use fuel_tx::ConsensusParameters;
 
 fn setup(program: Vec<Instruction>) -> Transactor<MemoryInstance, MemoryStorage, Script> {
+    setup_with_params(program, ConsensusParameters::standard())
+}
+
+fn setup_with_params(
+    program: Vec<Instruction>,
+    consensus_params: ConsensusParameters,
+) -> Transactor<MemoryInstance, MemoryStorage, Script> {
     let storage = MemoryStorage::default();
 
     let gas_price = 0;
+    let interpreter_params = InterpreterParams::new(gas_price, &consensus_params);
+
+    let mut vm = Transactor::new(MemoryInstance::new(), storage, interpreter_params);
+    vm.transact(checked_script(program, &consensus_params));
+    vm
+}
+
+fn checked_script(
+    program: Vec<Instruction>,
+    consensus_params: &ConsensusParameters,
+) -> Checked<Script> {
     let gas_limit = 1_000_000;
     let maturity = Default::default();
     let height = Default::default();
 
-    let consensus_params = ConsensusParameters::standard();
-
     let script = program.into_iter().collect();
 
-    let tx = TransactionBuilder::script(script, vec![])
+    TransactionBuilder::script(script, vec![])
         .script_gas_limit(gas_limit)
         .maturity(maturity)
         .add_fee_input()
         .finalize()
-        .into_checked(height, &consensus_params)
-        .expect("failed to check tx");
-
-    let interpreter_params = InterpreterParams::new(gas_price, &consensus_params);
-
-    let mut vm = Transactor::new(MemoryInstance::new(), storage, interpreter_params);
-    vm.transact(tx);
-    vm
+        .into_checked(height, consensus_params)
+        .expect("failed to check tx")
 }
 
 #[test]
@@ -164,6 +179,88 @@ fn test_stack_and_heap_cannot_overlap(offset: u64, cause_error: bool) {
     }
 }
 
+fn run_script_with_tx_params(
+    ops: Vec<Instruction>,
+    tx_params: impl FnOnce(TxParameters) -> TxParameters,
+) -> Vec<Receipt> {
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_tx_params(tx_params(*consensus_params.tx_params()));
+    let vm = setup_with_params(ops, consensus_params);
+    vm.receipts().expect("the script is executed").to_vec()
+}
+
+#[test]
+fn aloc__with_word_aligned_heap__keeps_hp_word_aligned_for_any_size() {
+    // Given
+    let mut ops = vec![];
+    for size in 1..=17 {
+        ops.extend([
+            op::movi(0x10, size),
+            op::aloc(0x10),
+            op::log(RegId::HP, 0x10, RegId::ZERO, RegId::ZERO),
+        ]);
+    }
+    ops.push(op::ret(RegId::ONE));
+
+    // When
+    let receipts =
+        run_script_with_tx_params(ops, |params| params.with_word_aligned_heap(true));
+
+    // Then
+    assert_success(&receipts);
+    let mut previous_hp = VM_MAX_RAM;
+    let mut logs = 0;
+    for receipt in &receipts {
+        if let Receipt::Log {
+            ra: hp, rb: size, ..
+        } = receipt
+        {
+            assert_eq!(
+                hp % 8,
+                0,
+                "$hp is not aligned after allocating {size} bytes"
+            );
+            assert!(previous_hp - hp >= *size);
+            assert!(previous_hp - hp < size + 8);
+            previous_hp = *hp;
+            logs += 1;
+        }
+    }
+    assert_eq!(logs, 17);
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(7)]
+#[test_case(9)]
+#[test_case(13)]
+#[test_case(17)]
+fn aloc__with_word_aligned_heap__returned_pointer_supports_word_access(size: u16) {
+    // Given
+    let ops = vec![
+        op::movi(0x10, 1),
+        op::aloc(0x10),
+        op::movi(0x10, size.into()),
+        op::aloc(0x10),
+        op::movi(0x11, 0xabcd),
+        op::sw(RegId::HP, 0x11, 0),
+        op::lw(0x12, RegId::HP, 0),
+        op::ret(0x12),
+    ];
+
+    // When
+    let receipts =
+        run_script_with_tx_params(ops, |params| params.with_word_aligned_heap(true));
+
+    // Then
+    assert_success(&receipts);
+    if let Some(Receipt::Return { val, .. }) = receipts.first() {
+        assert_eq!(*val, 0xabcd);
+    } else {
+        panic!("Expected return receipt");
+    }
+}
+
 /// tests for cfe & cfs
 #[test]
 fn dynamic_call_frame_ops() {
@@ -356,6 +453,170 @@ fn test_meq() {
     }
 }
 
+/// Allocates `2 * len` bytes on the heap and compares the first half with the second
+/// one. If `equal` is false, the last byte of the second half is changed.
+fn meq_halves(len: Word, equal: bool) -> Vec<Receipt> {
+    let mut ops = set_full_word(0x10, len);
+    ops.extend([op::add(0x11, 0x10, 0x10), op::aloc(0x11)]);
+    if !equal && len > 0 {
+        ops.extend([
+            op::add(0x12, RegId::HP, 0x11),
+            op::subi(0x12, 0x12, 1),
+            op::sb(0x12, RegId::ONE, 0),
+        ]);
+    }
+    ops.extend([
+        op::add(0x13, RegId::HP, 0x10),
+        op::meq(0x14, RegId::HP, 0x13, 0x10),
+        op::log(0x14, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]);
+    run_script(ops)
+}
+
+#[test_case(0)]
+#[test_case(1)]
+#[test_case(7)]
+#[test_case(8)]
+#[test_case(9)]
+#[test_case(1024 * 1024)]
+fn meq__compares_ranges_of_various_lengths(len: Word) {
+    for equal in [true, false] {
+        let receipts = meq_halves(len, equal);
+
+        assert_success(&receipts);
+        // Empty ranges are always equal
+        let expected = (equal || len == 0) as Word;
+        assert!(
+            matches!(receipts[0], Receipt::Log { ra, .. } if ra == expected),
+            "len: {len}, equal: {equal}, receipts: {receipts:?}"
+        );
+    }
+}
+
+fn meq_zero_length_in_unallocated_memory(allow_empty_reads: bool) -> Vec<Receipt> {
+    // Both addresses point to the unallocated memory between the stack and the heap.
+    let mut ops = set_full_word(0x10, VM_MAX_RAM / 2);
+    ops.extend([
+        op::addi(0x11, 0x10, 1),
+        op::meq(0x12, 0x10, 0x11, RegId::ZERO),
+        op::log(0x12, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]);
+
+    run_script_with_tx_params(ops, |params| {
+        params.with_allow_empty_reads(allow_empty_reads)
+    })
+}
+
+#[test]
+fn meq__zero_length_in_unallocated_memory_with_empty_reads__is_equal() {
+    // When
+    let receipts = meq_zero_length_in_unallocated_memory(true);
+
+    // Then
+    assert_success(&receipts);
+    assert!(matches!(receipts[0], Receipt::Log { ra: 1, .. }));
+}
+
+#[test]
+fn meq__zero_length_in_unallocated_memory_without_empty_reads__panics() {
+    // When
+    let receipts = meq_zero_length_in_unallocated_memory(false);
+
+    // Then
+    assert_panics(&receipts, PanicReason::UninitalizedMemoryAccess);
+}
+
+#[test]
+fn meq__zero_length_beyond_max_ram_panics() {
+    let mut ops = set_full_word(0x10, VM_MAX_RAM + 1);
+    ops.extend([
+        op::meq(0x12, 0x10, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]);
+
+    let receipts = run_script(ops);
+
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
+
+#[test_case(0, 0, 16 => 1; "same range")]
+#[test_case(0, 2, 4 => 1; "overlapping zeroes")]
+#[test_case(0, 1, 8 => 0; "rhs overlaps the marker")]
+#[test_case(1, 0, 8 => 0; "lhs overlaps the marker")]
+fn meq__overlapping_ranges(lhs_offset: u16, rhs_offset: u16, len: u32) -> Word {
+    // Given
+    // The heap allocation is zeroed, except for the marker byte at offset 8.
+    let ops = vec![
+        op::movi(0x10, 16),
+        op::aloc(0x10),
+        op::sb(RegId::HP, RegId::ONE, 8),
+        op::addi(0x11, RegId::HP, lhs_offset),
+        op::addi(0x12, RegId::HP, rhs_offset),
+        op::movi(0x13, len),
+        op::meq(0x14, 0x11, 0x12, 0x13),
+        op::log(0x14, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = run_script(ops);
+
+    // Then
+    assert_success(&receipts);
+    match receipts[0] {
+        Receipt::Log { ra, .. } => ra,
+        _ => panic!("Expected log receipt"),
+    }
+}
+
+#[test]
+fn meq__gas_grows_linearly_with_length() {
+    // Given
+    const MAX_LEN: Word = 1024 * 1024;
+    let gas_used = |len: Word| {
+        // The allocation doesn't depend on `len`, so only the cost of `MEQ` changes.
+        let mut ops = set_full_word(0x10, MAX_LEN);
+        ops.extend(set_full_word(0x11, len));
+        ops.extend([
+            op::aloc(0x10),
+            op::meq(0x12, RegId::HP, RegId::HP, 0x11),
+            op::ret(RegId::ONE),
+        ]);
+        let receipts = run_script(ops);
+        assert_success(&receipts);
+        match receipts.last() {
+            Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+            _ => panic!("Expected script result"),
+        }
+    };
+    let meq = ConsensusParameters::standard().gas_costs().meq();
+
+    // When
+    let base = gas_used(0);
+    let costs: Vec<_> = [1, 7, 8, 9, 1024, MAX_LEN / 2, MAX_LEN]
+        .into_iter()
+        .map(|len| (len, gas_used(len) - base))
+        .collect();
+
+    // Then
+    for (len, cost) in costs {
+        assert_eq!(
+            cost,
+            meq.resolve(len) - meq.resolve(0),
+            "Unexpected cost of MEQ over {len} bytes"
+        );
+    }
+    // The dependent cost is rounded down, so doubling the length may be off by one.
+    let half = gas_used(MAX_LEN / 2) - base;
+    let full = gas_used(MAX_LEN) - base;
+    assert!(
+        full.abs_diff(2 * half) <= 1,
+        "The cost must be linear in the length: {half} vs {full}"
+    );
+}
+
 #[test]
 fn test_push_pop_ops() {
     let rng = &mut StdRng::seed_from_u64(1234);
@@ -503,3 +764,216 @@ fn test_heap_allocation_zeroes_memory() {
         panic!("Expected return receipt");
     }
 }
+
+#[test]
+fn memory_stats__reports_stack_and_heap_high_water_marks() {
+    // Given
+    let heap_size = 4096;
+    let stack_size = 1024;
+    let ops = vec![
+        op::movi(0x10, heap_size),
+        op::aloc(0x10),
+        op::cfei(stack_size),
+        op::cfsi(stack_size),
+        op::movi(0x10, heap_size / 2),
+        op::aloc(0x10),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let vm = setup(ops);
+
+    // Then
+    assert_success(vm.receipts().unwrap());
+    let stats = vm.memory_stats();
+    let sp = vm.interpreter().registers()[RegId::SP] as usize;
+    assert_eq!(stats.max_stack, sp + stack_size as usize);
+    assert_eq!(stats.max_heap, (heap_size + heap_size / 2) as usize);
+    assert!(stats.grows >= 3, "{stats:?}");
+}
+
+#[test]
+fn memory_stats__are_reset_for_the_next_transaction() {
+    // Given
+    let consensus_params = ConsensusParameters::standard();
+    let mut vm = setup(vec![
+        op::movi(0x10, 4096),
+        op::aloc(0x10),
+        op::cfei(1024),
+        op::ret(RegId::ONE),
+    ]);
+    let first = vm.memory_stats();
+
+    // When
+    vm.transact(checked_script(vec![op::ret(RegId::ONE)], &consensus_params));
+
+    // Then
+    assert_success(vm.receipts().unwrap());
+    let second = vm.memory_stats();
+    assert_eq!(first.max_heap, 4096);
+    assert_eq!(second.max_heap, 0);
+    assert_eq!(
+        second.max_stack,
+        vm.interpreter().registers()[RegId::SP] as usize
+    );
+    assert!(second.max_stack < first.max_stack);
+}
+
+/// The instructions reading a dynamically sized memory range.
+#[derive(Debug, Clone, Copy)]
+enum RangeReader {
+    Retd,
+    Logd,
+    Smo,
+    S256,
+    K256,
+    Meq,
+}
+
+/// The address of the read range.
+#[derive(Debug, Clone, Copy)]
+enum ReadAddr {
+    Absolute(Word),
+    BelowSp(u16),
+    AboveHp(u16),
+    BelowHp(u16),
+}
+
+const HEAP_SIZE: u32 = 64;
+
+/// Reads the range `[$0x10, $0x10 + $0x11)` with the `reader`, after allocating
+/// `HEAP_SIZE` bytes on the heap.
+fn read_range(
+    reader: RangeReader,
+    addr: ReadAddr,
+    len: Word,
+    allow_empty_reads: bool,
+) -> RunResult<()> {
+    let mut ops = vec![op::movi(0x12, HEAP_SIZE), op::aloc(0x12)];
+    match addr {
+        ReadAddr::Absolute(addr) => ops.extend(set_full_word(0x10, addr)),
+        ReadAddr::BelowSp(offset) => ops.push(op::subi(0x10, RegId::SP, offset)),
+        ReadAddr::AboveHp(offset) => ops.push(op::addi(0x10, RegId::HP, offset)),
+        ReadAddr::BelowHp(offset) => ops.push(op::subi(0x10, RegId::HP, offset)),
+    }
+    ops.extend(set_full_word(0x11, len));
+    ops.push(match reader {
+        RangeReader::Retd => op::retd(0x10, 0x11),
+        RangeReader::Logd => op::logd(RegId::ZERO, RegId::ZERO, 0x10, 0x11),
+        RangeReader::Smo => op::smo(RegId::ZERO, 0x10, 0x11, RegId::ZERO),
+        RangeReader::S256 => op::s256(RegId::HP, 0x10, 0x11),
+        RangeReader::K256 => op::k256(RegId::HP, 0x10, 0x11),
+        RangeReader::Meq => op::meq(0x12, 0x10, 0x10, 0x11),
+    });
+    ops.push(op::ret(RegId::ONE));
+
+    let receipts = run_script_with_tx_params(ops, |params| {
+        params.with_allow_empty_reads(allow_empty_reads)
+    });
+    RunResult::extract_novalue(&receipts)
+}
+
+#[rstest::rstest]
+#[case::empty_at_zero(ReadAddr::Absolute(0), 0, RunResult::Success(()))]
+#[case::stack(ReadAddr::Absolute(0), 32, RunResult::Success(()))]
+#[case::last_byte_of_stack(ReadAddr::BelowSp(1), 1, RunResult::Success(()))]
+#[case::past_the_stack(
+    ReadAddr::BelowSp(1),
+    2,
+    RunResult::Panic(PanicReason::UninitalizedMemoryAccess)
+)]
+#[case::empty_at_sp(ReadAddr::BelowSp(0), 0, RunResult::Success(()))]
+#[case::below_hp(
+    ReadAddr::BelowHp(1),
+    1,
+    RunResult::Panic(PanicReason::UninitalizedMemoryAccess)
+)]
+#[case::whole_heap(ReadAddr::AboveHp(0), HEAP_SIZE as Word, RunResult::Success(()))]
+#[case::past_the_heap(
+    ReadAddr::AboveHp(0),
+    HEAP_SIZE as Word + 1,
+    RunResult::Panic(PanicReason::MemoryOverflow)
+)]
+#[case::empty_at_the_end(ReadAddr::Absolute(VM_MAX_RAM), 0, RunResult::Success(()))]
+#[case::past_the_end(
+    ReadAddr::Absolute(VM_MAX_RAM),
+    1,
+    RunResult::Panic(PanicReason::MemoryOverflow)
+)]
+#[case::empty_past_the_end(
+    ReadAddr::Absolute(VM_MAX_RAM + 1),
+    0,
+    RunResult::Panic(PanicReason::MemoryOverflow)
+)]
+#[case::empty_at_max_word(
+    ReadAddr::Absolute(Word::MAX),
+    0,
+    RunResult::Panic(PanicReason::MemoryOverflow)
+)]
+#[case::shifted_past_the_heap(
+    ReadAddr::AboveHp(1),
+    HEAP_SIZE as Word,
+    RunResult::Panic(PanicReason::MemoryOverflow)
+)]
+fn read_range__same_bounds_check_for_all_readers(
+    #[values(
+        RangeReader::Retd,
+        RangeReader::Logd,
+        RangeReader::Smo,
+        RangeReader::S256,
+        RangeReader::K256,
+        RangeReader::Meq
+    )]
+    reader: RangeReader,
+    #[values(false, true)] allow_empty_reads: bool,
+    #[case] addr: ReadAddr,
+    #[case] len: Word,
+    #[case] expected: RunResult<()>,
+) {
+    assert_eq!(read_range(reader, addr, len, allow_empty_reads), expected);
+}
+
+#[rstest::rstest]
+fn read_range__empty_range_in_unallocated_memory__allowed_only_with_empty_reads(
+    #[values(
+        RangeReader::Retd,
+        RangeReader::Logd,
+        RangeReader::Smo,
+        RangeReader::S256,
+        RangeReader::K256,
+        RangeReader::Meq
+    )]
+    reader: RangeReader,
+    #[values(false, true)] allow_empty_reads: bool,
+) {
+    // When
+    let result = read_range(reader, ReadAddr::BelowHp(1), 0, allow_empty_reads);
+
+    // Then
+    let expected = if allow_empty_reads {
+        RunResult::Success(())
+    } else {
+        RunResult::Panic(PanicReason::UninitalizedMemoryAccess)
+    };
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn call__params_in_unallocated_memory__panic_with_empty_reads() {
+    // Given
+    // The call parameters are never empty, so the empty reads don't apply to them.
+    let ops = vec![
+        op::movi(0x12, HEAP_SIZE),
+        op::aloc(0x12),
+        op::subi(0x10, RegId::HP, 64),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts =
+        run_script_with_tx_params(ops, |params| params.with_allow_empty_reads(true));
+
+    // Then
+    assert_panics(&receipts, PanicReason::UninitalizedMemoryAccess);
+}
@@ -15,10 +15,12 @@ use fuel_asm::{
     op,
     GMArgs,
     GTFArgs,
+    Instruction,
     RegId,
 };
 use fuel_crypto::Hasher;
 use fuel_tx::{
+    consensus_parameters::gas::GasCostsValuesV6,
     field::{
         Inputs,
         Outputs,
@@ -29,6 +31,7 @@ use fuel_tx::{
     policies::PoliciesBits,
     ConsensusParameters,
     Finalizable,
+    GasCostsValues,
     Receipt,
     Script,
     TransactionBuilder,
@@ -44,6 +47,7 @@ use rand::{
     Rng,
     SeedableRng,
 };
+use strum::IntoEnumIterator;
 
 use crate::prelude::{
     GasCosts,
@@ -314,10 +318,217 @@ fn get_metadata_base_asset_id() {
     .expect("Failed to transact")
     .to_owned();
 
-    if let Receipt::LogData { data, .. } = receipts[0].clone() {
-        assert_eq!(data.unwrap(), params.base_asset_id().to_bytes());
-    } else {
-        panic!("expected LogData receipt, instead of {:?}", receipts[0]);
+    assert!(
+        matches!(receipts[0], Receipt::LogData { .. }),
+        "expected LogData receipt, instead of {:?}",
+        receipts[0]
+    );
+    assert_eq!(
+        receipts[0].data_b256(0),
+        Some(Bytes32::new(**params.base_asset_id()))
+    );
+}
+
+fn gm_script(args: &[GMArgs], gas_costs: GasCosts) -> Vec<Receipt> {
+    let height = BlockHeight::default();
+    let mut storage = MemoryStorage::new_with_versions(height, Default::default(), 3, 5);
+    let mut script: Vec<_> = args
+        .iter()
+        .enumerate()
+        .map(|(i, args)| op::gm_args(0x20 + i as u8, *args))
+        .collect();
+    script.push(op::log(0x20, 0x21, RegId::ZERO, RegId::ZERO));
+    script.push(op::ret(RegId::ONE));
+
+    let script = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(height, &ConsensusParameters::default())
+        .unwrap();
+
+    Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        &mut storage,
+        InterpreterParams {
+            gas_costs,
+            ..Default::default()
+        },
+    )
+    .transact(script)
+    .receipts()
+    .expect("Failed to transact")
+    .to_owned()
+}
+
+#[test]
+fn get_metadata_versions__returns_the_storage_versions() {
+    // When
+    let receipts = gm_script(
+        &[
+            GMArgs::GetConsensusParametersVersion,
+            GMArgs::GetStateTransitionVersion,
+        ],
+        GasCosts::default(),
+    );
+
+    // Then
+    let Receipt::Log { ra, rb, .. } = receipts[0] else {
+        panic!("expected log receipt, instead of {:?}", receipts[0])
+    };
+    assert_eq!((ra, rb), (3, 5));
+}
+
+#[test]
+fn get_metadata_versions__charge_their_own_gas_costs() {
+    // Given
+    let gas_costs = GasCosts::new(
+        GasCostsValuesV6 {
+            gm: 1,
+            gm_consensus_parameters_version: 100,
+            gm_state_transition_version: 1_000,
+            ..GasCostsValuesV6::free()
+        }
+        .into(),
+    );
+    let gas_used = |args| {
+        let receipts = gm_script(&[args], gas_costs.clone());
+        match receipts.last() {
+            Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+            _ => panic!("expected script result, instead of {receipts:?}"),
+        }
+    };
+
+    // When
+    let tx_start = gas_used(GMArgs::TxStart);
+    let consensus_parameters_version = gas_used(GMArgs::GetConsensusParametersVersion);
+    let state_transition_version = gas_used(GMArgs::GetStateTransitionVersion);
+
+    // Then
+    assert_eq!(consensus_parameters_version - tx_start, 99);
+    assert_eq!(state_transition_version - tx_start, 999);
+}
+
+/// The gas used by the script executing the `instruction` and returning.
+fn instruction_gas_used(instruction: Instruction, gas_costs: GasCosts) -> Word {
+    let height = BlockHeight::default();
+    let mut storage = MemoryStorage::new_with_versions(height, Default::default(), 3, 5);
+    let script = [instruction, op::ret(RegId::ONE)].into_iter().collect();
+
+    let script = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(height, &ConsensusParameters::default())
+        .unwrap();
+
+    let receipts = Transactor::<_, _, _>::new(
+        MemoryInstance::new(),
+        &mut storage,
+        InterpreterParams {
+            gas_costs,
+            ..Default::default()
+        },
+    )
+    .transact(script)
+    .receipts()
+    .expect("Failed to transact")
+    .to_owned();
+    match receipts.last() {
+        Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+        _ => panic!("expected script result, instead of {receipts:?}"),
+    }
+}
+
+/// The `GTF` with every selector, including an unknown one.
+fn gtf_selectors() -> impl Iterator<Item = Instruction> {
+    GTFArgs::iter()
+        .map(|args| op::gtf_args(0x20, RegId::ZERO, args))
+        .chain([op::gtf(0x20, RegId::ZERO, 0xfff)])
+}
+
+#[test]
+fn get_transaction_field__every_selector__charges_gtf() {
+    // Given
+    let gas_costs = |gtf| {
+        GasCosts::new(
+            GasCostsValuesV6 {
+                gtf,
+                ..GasCostsValuesV6::free()
+            }
+            .into(),
+        )
+    };
+
+    for instruction in gtf_selectors() {
+        // When
+        let cheap = instruction_gas_used(instruction, gas_costs(1));
+        let expensive = instruction_gas_used(instruction, gas_costs(1_000));
+
+        // Then
+        assert_eq!(expensive - cheap, 999, "{instruction:?}");
+    }
+}
+
+#[test]
+fn get_transaction_field__default_gas_costs__charge_gtf() {
+    // Given
+    let GasCostsValues::V6(default) = GasCostsValues::default() else {
+        panic!("the default gas costs are the latest ones")
+    };
+    let without_gtf = GasCosts::new(
+        GasCostsValuesV6 {
+            gtf: 0,
+            ..default.clone()
+        }
+        .into(),
+    );
+
+    for instruction in gtf_selectors() {
+        // When
+        let gas_used = instruction_gas_used(instruction, GasCosts::default());
+        let gas_used_without_gtf = instruction_gas_used(instruction, without_gtf.clone());
+
+        // Then
+        assert_eq!(
+            gas_used - gas_used_without_gtf,
+            default.gtf,
+            "{instruction:?}"
+        );
+    }
+}
+
+#[test]
+fn get_metadata__flat_selectors__charge_gm() {
+    // Given
+    let gas_costs = |gm| {
+        GasCosts::new(
+            GasCostsValuesV6 {
+                gm,
+                gm_consensus_parameters_version: 5,
+                gm_state_transition_version: 5,
+                ..GasCostsValuesV6::free()
+            }
+            .into(),
+        )
+    };
+    let selectors = [
+        GMArgs::IsCallerExternal,
+        GMArgs::GetCaller,
+        GMArgs::GetVerifyingPredicate,
+        GMArgs::GetChainId,
+        GMArgs::TxStart,
+        GMArgs::BaseAssetId,
+    ]
+    .map(|args| op::gm_args(0x20, args));
+
+    for instruction in selectors.into_iter().chain([op::gm(0x20, 0xff)]) {
+        // When
+        let cheap = instruction_gas_used(instruction, gas_costs(1));
+        let expensive = instruction_gas_used(instruction, gas_costs(1_000));
+
+        // Then
+        assert_eq!(expensive - cheap, 999, "{instruction:?}");
     }
 }
 
@@ -623,7 +834,11 @@ fn get_transaction_fields() {
         op::and(0x20, 0x20, 0x10),
 
         op::movi(0x19, 0x00),
-        op::movi(0x11, PoliciesBits::all().bits() as Immediate18),
+        // The flag defaults aren't allowed by the default consensus parameters.
+        op::movi(
+            0x11,
+            PoliciesBits::all().difference(PoliciesBits::FlagDefaults).bits() as Immediate18,
+        ),
         op::gtf_args(0x10, 0x19, GTFArgs::PolicyTypes),
         op::eq(0x10, 0x10, 0x11),
         op::and(0x20, 0x20, 0x10),
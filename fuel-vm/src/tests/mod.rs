@@ -9,32 +9,59 @@ use tokio_rayon as _;
 mod test_helpers;
 
 mod alu;
+mod alu_vectors;
+#[cfg(feature = "async-storage")]
+mod async_storage;
+#[cfg(feature = "vm-backtrace")]
 mod backtrace;
+mod binary_merkle;
 mod blob;
+mod blob_loader;
+mod block_scenario;
 mod blockchain;
 mod cgas;
+mod code_cache;
 mod code_coverage;
 mod coins;
 mod contract;
 mod crypto;
+mod data_in_heap;
+#[cfg(feature = "debugger")]
 mod debugger;
 mod encoding;
 mod external;
+mod fee_invariants;
 mod flow;
+mod fuzz_single;
 mod gas_factor;
+mod history;
+mod initial_registers;
+mod instrumented_storage;
 mod jump_absolute;
 mod jump_relative;
 mod limits;
 mod log;
 mod memory;
 mod metadata;
+mod narrowint;
 mod outputs;
+mod packed;
+mod parallel_execute;
+mod pool;
 mod predicate;
 mod profile_gas;
+mod read_only;
 mod receipts;
 mod serde_profile;
 mod spec;
+mod state_transition;
+mod storage_error_context;
+mod storage_write_limit;
+mod supply;
+mod trace_events;
+mod transaction_pipeline;
 mod upgrade;
 mod upload;
 mod validation;
 mod wideint;
+mod zero_gas_price;
@@ -0,0 +1,215 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    Instruction,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::Receipt;
+use fuel_types::Word;
+use test_case::test_case;
+
+use super::test_helpers::{
+    assert_panics,
+    assert_success,
+    run_script,
+    set_full_word,
+};
+
+const VALUE: Word = 0x0123_4567_89ab_cdef;
+
+#[derive(Debug, Clone, Copy)]
+enum Width {
+    Half,
+    Quarter,
+}
+
+impl Width {
+    fn bytes(self) -> u16 {
+        match self {
+            Width::Half => 4,
+            Width::Quarter => 2,
+        }
+    }
+
+    fn load(self, dst: u8, addr: u8, offset: u16) -> Instruction {
+        match self {
+            Width::Half => op::lhw(dst, addr, offset),
+            Width::Quarter => op::lqw(dst, addr, offset),
+        }
+    }
+
+    fn store(self, addr: u8, value: u8, offset: u16) -> Instruction {
+        match self {
+            Width::Half => op::shw(addr, value, offset),
+            Width::Quarter => op::sqw(addr, value, offset),
+        }
+    }
+}
+
+/// Allocates 16 zeroed bytes on the heap and points `0x10` to the byte `misalign`
+/// of them. Sets `0x11` to the [`VALUE`].
+fn prepare(misalign: u16) -> Vec<Instruction> {
+    let mut ops = vec![op::movi(0x10, 16), op::aloc(0x10)];
+    ops.push(op::addi(0x10, RegId::HP, misalign));
+    ops.extend(set_full_word(0x11, VALUE));
+    ops
+}
+
+fn logged(receipts: &[Receipt]) -> Vec<Word> {
+    assert_success(receipts);
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log { ra, .. } => Some(*ra),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test_case(Width::Half, 0 => 0x89ab_cdef; "half word aligned")]
+#[test_case(Width::Half, 1 => 0x89ab_cdef; "half word unaligned")]
+#[test_case(Width::Half, 3 => 0x89ab_cdef; "half word unaligned by three")]
+#[test_case(Width::Quarter, 0 => 0xcdef; "quarter word aligned")]
+#[test_case(Width::Quarter, 1 => 0xcdef; "quarter word unaligned")]
+fn store_and_load__truncates_and_zero_extends(width: Width, misalign: u16) -> Word {
+    // Given
+    let mut script = prepare(misalign);
+    script.extend(set_full_word(0x12, Word::MAX));
+
+    // When
+    script.extend([
+        width.store(0x10, 0x11, 0),
+        width.load(0x12, 0x10, 0),
+        op::log(0x12, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]);
+    let receipts = run_script(script);
+
+    // Then
+    logged(&receipts)[0]
+}
+
+#[test_case(Width::Half => [0, 0, 0, 0, 0x89, 0xab, 0xcd, 0xef]; "half word")]
+#[test_case(Width::Quarter => [0, 0, 0xcd, 0xef, 0, 0, 0, 0]; "quarter word")]
+fn store__writes_only_the_width_big_endian_at_the_scaled_offset(width: Width) -> [u8; 8] {
+    // Given
+    let mut script = prepare(0);
+
+    // When
+    script.extend([
+        width.store(0x10, 0x11, 1),
+        op::lw(0x12, 0x10, 0),
+        op::log(0x12, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]);
+    let receipts = run_script(script);
+
+    // Then
+    logged(&receipts)[0].to_be_bytes()
+}
+
+#[test_case(Width::Half)]
+#[test_case(Width::Quarter)]
+fn load__reads_the_scaled_offset(width: Width) {
+    // Given
+    let mut script = prepare(0);
+    script.push(op::sw(0x10, 0x11, 0));
+
+    // When
+    script.extend([
+        width.load(0x12, 0x10, 1),
+        op::log(0x12, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]);
+    let receipts = run_script(script);
+
+    // Then
+    let expected = match width {
+        Width::Half => 0x89ab_cdef,
+        Width::Quarter => 0x4567,
+    };
+    assert_eq!(logged(&receipts), vec![expected]);
+}
+
+#[test_case(Width::Half)]
+#[test_case(Width::Quarter)]
+fn store_and_load__at_the_end_of_memory__succeed(width: Width) {
+    // Given
+    let mut script = prepare(0);
+    script.push(op::addi(0x10, RegId::HP, 16 - width.bytes()));
+
+    // When
+    script.extend([
+        width.store(0x10, 0x11, 0),
+        width.load(0x12, 0x10, 0),
+        op::log(0x12, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]);
+    let receipts = run_script(script);
+
+    // Then
+    assert_eq!(logged(&receipts).len(), 1);
+}
+
+#[test_case(Width::Half)]
+#[test_case(Width::Quarter)]
+fn store__past_the_end_of_memory__panics(width: Width) {
+    // Given
+    let mut script = prepare(0);
+    script.push(op::addi(0x10, RegId::HP, 16 - width.bytes() + 1));
+
+    // When
+    script.extend([width.store(0x10, 0x11, 0), op::ret(RegId::ONE)]);
+    let receipts = run_script(script);
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
+
+#[test_case(Width::Half)]
+#[test_case(Width::Quarter)]
+fn load__past_the_end_of_memory__panics(width: Width) {
+    // Given
+    let mut script = prepare(0);
+    script.push(op::addi(0x10, RegId::HP, 16 - width.bytes() + 1));
+
+    // When
+    script.extend([width.load(0x12, 0x10, 0), op::ret(RegId::ONE)]);
+    let receipts = run_script(script);
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
+
+#[test_case(Width::Half)]
+#[test_case(Width::Quarter)]
+fn store__outside_of_the_owned_memory__panics(width: Width) {
+    // Given
+    let mut script = prepare(0);
+
+    // When
+    script.extend([width.store(RegId::ZERO.into(), 0x11, 0), op::ret(RegId::ONE)]);
+    let receipts = run_script(script);
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOwnership);
+}
+
+#[test_case(Width::Half)]
+#[test_case(Width::Quarter)]
+fn load__outside_of_the_owned_memory__succeeds(width: Width) {
+    // Given
+    let mut script = prepare(0);
+
+    // When
+    script.extend([width.load(0x12, RegId::ZERO.into(), 0), op::ret(RegId::ONE)]);
+    let receipts = run_script(script);
+
+    // Then
+    assert_success(&receipts);
+}
@@ -4,13 +4,17 @@ use alloc::{
 };
 
 use crate::{
+    interpreter::CheckedMetadata,
     prelude::{
         field::Outputs,
         *,
     },
-    util::test_helpers::{
-        find_change,
-        TestBuilder,
+    util::{
+        outputs::predict_outputs,
+        test_helpers::{
+            find_change,
+            TestBuilder,
+        },
     },
 };
 use fuel_asm::{
@@ -178,3 +182,89 @@ fn correct_change_is_provided_for_coin_outputs_create() {
 
     assert_eq!(change, input_amount - spend_amount);
 }
+
+fn predicted_outputs_match_vm_outputs(script: Vec<Instruction>, revert: bool) {
+    let mut rng = StdRng::seed_from_u64(2322u64);
+    let base_asset_id: AssetId = rng.gen();
+    let other_asset_id: AssetId = rng.gen();
+    let gas_price = 3;
+
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context
+        .start_script(script, vec![])
+        .max_fee_limit(10_000)
+        .gas_price(gas_price)
+        .base_asset_id(base_asset_id)
+        .coin_input(base_asset_id, 100_000)
+        .coin_input(other_asset_id, 500)
+        .change_output(base_asset_id)
+        .change_output(other_asset_id)
+        .coin_output(other_asset_id, 100)
+        .variable_output(other_asset_id);
+
+    // Given
+    let checked = test_context.build();
+    let initial_balances = checked.metadata().balances();
+    let tx = checked.transaction().clone();
+
+    // When
+    let state = test_context
+        .execute_tx(checked)
+        .expect("expected successful vm execution");
+    let gas_used = state
+        .receipts()
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .expect("expected script result receipt");
+    let predicted = predict_outputs(
+        &tx,
+        gas_used,
+        revert,
+        &initial_balances,
+        test_context.get_gas_costs(),
+        test_context.get_fee_params(),
+        test_context.get_base_asset_id(),
+        gas_price,
+    )
+    .expect("expected outputs to be predicted");
+
+    // Then
+    assert_eq!(state.should_revert(), revert);
+    assert_eq!(&predicted, state.tx().outputs());
+}
+
+#[test]
+fn predict_outputs__matches_vm_outputs_on_success() {
+    predicted_outputs_match_vm_outputs(
+        vec![
+            op::log(RegId::ONE, RegId::ONE, RegId::ONE, RegId::ONE),
+            op::ret(RegId::ONE),
+        ],
+        false,
+    );
+}
+
+#[test]
+fn predict_outputs__matches_vm_outputs_on_revert() {
+    predicted_outputs_match_vm_outputs(
+        vec![
+            op::log(RegId::ONE, RegId::ONE, RegId::ONE, RegId::ONE),
+            op::rvrt(RegId::ONE),
+        ],
+        true,
+    );
+}
+
+#[test]
+fn predict_outputs__matches_vm_outputs_on_panic() {
+    predicted_outputs_match_vm_outputs(
+        vec![
+            op::log(RegId::ONE, RegId::ONE, RegId::ONE, RegId::ONE),
+            op::div(0x10, RegId::ONE, RegId::ZERO),
+        ],
+        true,
+    );
+}
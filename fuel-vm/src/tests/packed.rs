@@ -0,0 +1,216 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_types::canonical::Serialize;
+use test_case::test_case;
+
+use crate::{
+    consts::WORD_SIZE,
+    prelude::*,
+    tests::test_helpers::assert_success,
+    util::{
+        packed::{
+            decode_packed,
+            encode_packed,
+            DecodeError,
+            DecodedField,
+            FieldKind,
+        },
+        test_helpers::TestBuilder,
+    },
+};
+
+#[test_case(DecodedField::U8(0xab) => vec![0, 0, 0, 0, 0, 0, 0, 0xab]; "u8")]
+#[test_case(DecodedField::U16(0xabcd) => vec![0, 0, 0, 0, 0, 0, 0xab, 0xcd]; "u16")]
+#[test_case(DecodedField::U32(0x0102_0304) => vec![0, 0, 0, 0, 1, 2, 3, 4]; "u32")]
+#[test_case(DecodedField::U64(0x0102_0304_0506_0708) => vec![1, 2, 3, 4, 5, 6, 7, 8]; "u64")]
+#[test_case(DecodedField::Bytes(vec![]) => vec![0; 8]; "empty bytes")]
+#[test_case(DecodedField::Bytes(vec![0xaa; 3]) => vec![0, 0, 0, 0, 0, 0, 0, 3, 0xaa, 0xaa, 0xaa, 0, 0, 0, 0, 0]; "bytes padded to the word")]
+#[test_case(DecodedField::Bytes(vec![0xaa; 8]) => [vec![0, 0, 0, 0, 0, 0, 0, 8], vec![0xaa; 8]].concat(); "bytes of the word size")]
+fn encode_packed__aligns_each_field_to_the_word(field: DecodedField) -> Vec<u8> {
+    // When
+    let data = encode_packed(core::slice::from_ref(&field));
+
+    // Then
+    assert_eq!(data.len() % WORD_SIZE, 0);
+    assert_eq!(
+        decode_packed(&data, &[field.kind()]).expect("Failed to decode"),
+        vec![field]
+    );
+    data
+}
+
+#[test]
+fn decode_packed__decodes_the_call_encoding() {
+    // Given
+    let call = Call::new(ContractId::from([0x11; 32]), 42, 0xdead_beef);
+
+    // When
+    let fields = decode_packed(
+        &call.to_bytes(),
+        &[FieldKind::B256, FieldKind::U64, FieldKind::U64],
+    );
+
+    // Then
+    assert_eq!(
+        fields,
+        Ok(vec![
+            DecodedField::B256([0x11; 32].into()),
+            DecodedField::U64(42),
+            DecodedField::U64(0xdead_beef),
+        ])
+    );
+}
+
+#[test]
+fn decode_packed__roundtrips_mixed_fields() {
+    // Given
+    let fields = vec![
+        DecodedField::U8(1),
+        DecodedField::Bytes(vec![2; 5]),
+        DecodedField::U16(3),
+        DecodedField::B256([4; 32].into()),
+        DecodedField::Bytes(vec![5; 17]),
+        DecodedField::U32(6),
+    ];
+    let layout: Vec<_> = fields.iter().map(DecodedField::kind).collect();
+
+    // When
+    let data = encode_packed(&fields);
+
+    // Then
+    assert_eq!(data.len(), 8 + 16 + 8 + 32 + 32 + 8);
+    assert_eq!(decode_packed(&data, &layout), Ok(fields));
+}
+
+#[test_case(&[FieldKind::U64], 7 => DecodeError::Truncated { offset: 0, expected: 8, available: 7 }; "word")]
+#[test_case(&[FieldKind::U8, FieldKind::B256], 39 => DecodeError::Truncated { offset: 8, expected: 32, available: 31 }; "b256")]
+#[test_case(&[FieldKind::Bytes], 4 => DecodeError::Truncated { offset: 0, expected: 8, available: 4 }; "bytes length")]
+#[test_case(&[FieldKind::Bytes], 15 => DecodeError::Truncated { offset: 8, expected: 8, available: 7 }; "bytes padding")]
+fn decode_packed__fails_on_truncated_data(
+    layout: &[FieldKind],
+    len: usize,
+) -> DecodeError {
+    // Given
+    let fields = [DecodedField::U8(1), DecodedField::B256([2; 32].into())];
+    let mut data = match layout {
+        [FieldKind::Bytes] => encode_packed(&[DecodedField::Bytes(vec![3; 3])]),
+        _ => encode_packed(&fields[..layout.len()]),
+    };
+    data.truncate(len);
+
+    // When
+    let result = decode_packed(&data, layout);
+
+    // Then
+    result.expect_err("Expected the data to be truncated")
+}
+
+#[test_case(FieldKind::U8, &[0, 0, 0, 0, 0, 0, 1, 0]; "u8")]
+#[test_case(FieldKind::U16, &[0, 0, 0, 0, 0, 1, 0, 0]; "u16")]
+#[test_case(FieldKind::U32, &[1, 0, 0, 0, 0, 0, 0, 0]; "u32")]
+fn decode_packed__fails_on_non_zero_integer_padding(kind: FieldKind, data: &[u8]) {
+    // When
+    let result = decode_packed(data, &[kind]);
+
+    // Then
+    assert_eq!(result, Err(DecodeError::NonZeroPadding { offset: 0 }));
+}
+
+#[test]
+fn decode_packed__fails_on_non_zero_bytes_padding() {
+    // Given
+    let mut data =
+        encode_packed(&[DecodedField::U64(1), DecodedField::Bytes(vec![2; 3])]);
+    *data.last_mut().unwrap() = 1;
+
+    // When
+    let result = decode_packed(&data, &[FieldKind::U64, FieldKind::Bytes]);
+
+    // Then
+    assert_eq!(result, Err(DecodeError::NonZeroPadding { offset: 16 }));
+}
+
+#[test]
+fn decode_packed__fails_on_trailing_bytes() {
+    // Given
+    let data = encode_packed(&[DecodedField::U64(1), DecodedField::U64(2)]);
+
+    // When
+    let result = decode_packed(&data, &[FieldKind::U64]);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(DecodeError::TrailingBytes {
+            offset: 8,
+            remaining: 8
+        })
+    );
+}
+
+#[test]
+fn decode_packed__fails_on_bytes_length_overflow() {
+    // Given
+    let data = encode_packed(&[DecodedField::U64(u64::MAX)]);
+
+    // When
+    let result = decode_packed(&data, &[FieldKind::Bytes]);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(DecodeError::LengthOverflow {
+            offset: 0,
+            length: u64::MAX
+        })
+    );
+}
+
+#[test]
+fn decode_packed__decodes_log_data_of_the_script_data() {
+    // Given
+    let fields = vec![
+        DecodedField::U16(0x1234),
+        DecodedField::Bytes(b"hello".to_vec()),
+        DecodedField::B256([7; 32].into()),
+    ];
+    let script_data = encode_packed(&fields);
+    let len = script_data.len().try_into().unwrap();
+    let mut test_context = TestBuilder::new(2322u64);
+
+    // When
+    let result = test_context
+        .start_script(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::movi(0x11, len),
+                op::logd(RegId::ZERO, RegId::ZERO, 0x10, 0x11),
+                op::ret(RegId::ONE),
+            ],
+            script_data,
+        )
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute();
+
+    // Then
+    let receipts = result.receipts();
+    assert_success(receipts);
+    let Some(Receipt::LogData {
+        data: Some(data), ..
+    }) = receipts.first()
+    else {
+        panic!("Expected a log data receipt");
+    };
+    assert_eq!(
+        decode_packed(data, &[FieldKind::U16, FieldKind::Bytes, FieldKind::B256]),
+        Ok(fields)
+    );
+}
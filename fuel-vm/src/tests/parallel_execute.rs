@@ -0,0 +1,199 @@
+#![cfg(feature = "std")]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    GTFArgs,
+    RegId,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Receipt,
+};
+use fuel_types::{
+    canonical::Serialize,
+    Bytes32,
+    ContractId,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::InterpreterParams,
+    pool::MemoryPool,
+    prelude::{
+        Call,
+        InterpreterStorage,
+        MemoryStorage,
+        Script,
+        TestBuilder,
+    },
+    storage::dump::TableId,
+    util::{
+        parallel_execute,
+        ExecutionOutcome,
+        StorageChange,
+    },
+};
+
+/// Deploys the counter contract, which logs the word stored at the zero key and
+/// stores it incremented, and returns the script calling it.
+fn counter() -> (TestBuilder, ContractId, Checked<Script>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract = vec![
+        op::movi(0x10, 32),
+        op::aloc(0x10),
+        op::srw(0x11, 0x12, RegId::HP),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::addi(0x11, 0x11, 1),
+        op::sww(RegId::HP, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+    let tx = test_context
+        .start_script(
+            vec![
+                op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            Call::new(contract_id, 0, 0).to_bytes(),
+        )
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+    (test_context, contract_id, tx)
+}
+
+/// Returns the snapshot with the `counter` stored at the zero key.
+fn snapshot_with_counter(
+    test_context: &TestBuilder,
+    contract_id: &ContractId,
+    counter: u64,
+) -> MemoryStorage {
+    let mut storage = test_context.get_storage().clone();
+    storage
+        .contract_state_insert(contract_id, &Bytes32::zeroed(), &word(counter))
+        .expect("Infallible");
+    storage
+}
+
+fn word(value: u64) -> Vec<u8> {
+    let mut word = value.to_be_bytes().to_vec();
+    word.resize(32, 0);
+    word
+}
+
+fn logged_values(outcome: &ExecutionOutcome) -> Vec<u64> {
+    outcome
+        .receipts()
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::Log { ra, .. } => Some(*ra),
+            _ => None,
+        })
+        .collect()
+}
+
+fn params() -> InterpreterParams {
+    InterpreterParams::new(0, ConsensusParameters::standard())
+}
+
+#[test]
+fn parallel_execute__different_snapshots__outcomes_follow_snapshots_order() {
+    // Given
+    let (test_context, contract_id, tx) = counter();
+    let counters = [5, 0, 42];
+    let storages = counters
+        .iter()
+        .map(|counter| snapshot_with_counter(&test_context, &contract_id, *counter))
+        .collect();
+    let pool = MemoryPool::default();
+
+    // When
+    let outcomes = parallel_execute(tx, storages, params(), &pool);
+
+    // Then
+    assert_eq!(outcomes.len(), counters.len());
+    let state_key = [contract_id.as_ref(), Bytes32::zeroed().as_ref()].concat();
+    for (outcome, counter) in outcomes.iter().zip(counters) {
+        assert_eq!(logged_values(outcome), vec![counter]);
+        let state_changes: Vec<_> = outcome
+            .storage_delta
+            .iter()
+            .filter(|change| change.table == TableId::ContractsState)
+            .collect();
+        assert_eq!(
+            state_changes,
+            vec![&StorageChange {
+                table: TableId::ContractsState,
+                key: state_key.clone(),
+                before: Some(word(counter)),
+                after: Some(word(counter + 1)),
+            }]
+        );
+    }
+    assert_ne!(outcomes[0].result, outcomes[1].result);
+    assert_ne!(outcomes[1].result, outcomes[2].result);
+}
+
+#[test]
+fn parallel_execute__identical_snapshots__outcomes_are_identical() {
+    // Given
+    let (test_context, contract_id, tx) = counter();
+    let storage = snapshot_with_counter(&test_context, &contract_id, 7);
+    let storages = vec![storage; 4];
+    let pool = MemoryPool::default();
+
+    // When
+    let outcomes = parallel_execute(tx, storages, params(), &pool);
+
+    // Then
+    assert_eq!(outcomes.len(), 4);
+    assert!(outcomes[0].result.is_ok());
+    assert_eq!(logged_values(&outcomes[0]), vec![7]);
+    for outcome in &outcomes[1..] {
+        assert_eq!(outcome, &outcomes[0]);
+    }
+}
+
+#[test]
+fn parallel_execute__reverted_execution__changes_nothing() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(vec![op::rvrt(RegId::ONE)], vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let storages = vec![test_context.get_storage().clone()];
+    let pool = MemoryPool::default();
+
+    // When
+    let outcomes = parallel_execute(tx, storages, params(), &pool);
+
+    // Then
+    let outcome = &outcomes[0];
+    assert!(outcome.result.as_ref().is_ok_and(|t| t.should_revert()));
+    assert_eq!(outcome.storage_delta, vec![]);
+}
+
+#[test]
+fn parallel_execute__no_snapshots__returns_no_outcomes() {
+    // Given
+    let (_, _, tx) = counter();
+    let pool = MemoryPool::default();
+
+    // When
+    let outcomes = parallel_execute(tx, vec![], params(), &pool);
+
+    // Then
+    assert_eq!(outcomes, vec![]);
+}
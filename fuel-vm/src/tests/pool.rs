@@ -0,0 +1,125 @@
+#![cfg(feature = "std")]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::InterpreterParams,
+    pool::{
+        MemoryFromPool,
+        MemoryPool,
+        VmMemoryPoolConfig,
+    },
+    prelude::*,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+
+fn script(program: Vec<Instruction>) -> Checked<Script> {
+    TransactionBuilder::script(program.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_checked(Default::default())
+}
+
+/// Writes `0xff` to the first heap byte, then allocates `extra` bytes more.
+fn dirty_heap(extra_log2: u16) -> Checked<Script> {
+    script(vec![
+        op::movi(0x10, 32),
+        op::aloc(0x10),
+        op::movi(0x11, 0xff),
+        op::sb(RegId::HP, 0x11, 0),
+        op::movi(0x10, 1),
+        op::slli(0x10, 0x10, extra_log2),
+        op::aloc(0x10),
+        op::ret(RegId::ONE),
+    ])
+}
+
+/// Logs the first heap byte after allocating it.
+fn read_heap() -> Checked<Script> {
+    script(vec![
+        op::movi(0x10, 32),
+        op::aloc(0x10),
+        op::lb(0x11, RegId::HP, 0),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ])
+}
+
+fn transact(pool: &MemoryPool, tx: Checked<Script>) -> Vec<Receipt> {
+    let mut transactor = Transactor::<MemoryFromPool, MemoryStorage, Script>::new(
+        pool.take(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+    );
+    transactor.transact(tx);
+    transactor.receipts().expect("Expected receipts").to_vec()
+}
+
+fn logged_byte(receipts: &[Receipt]) -> Word {
+    match receipts.first() {
+        Some(Receipt::Log { ra, .. }) => *ra,
+        receipt => panic!("Expected a log receipt, got {receipt:?}"),
+    }
+}
+
+#[test]
+fn memory_pool__trims_oversized_instances_on_return() {
+    // Given
+    let pool = MemoryPool::new(VmMemoryPoolConfig {
+        max_retained_bytes: 4 * 1024 * 1024,
+        trim_to_bytes: 1024 * 1024,
+    });
+
+    // When
+    transact(&pool, dirty_heap(24));
+
+    // Then
+    let stats = pool.stats();
+    assert_eq!(stats.instances, 1);
+    assert!(
+        stats.retained_bytes <= 1024 * 1024,
+        "The pool retains {} bytes",
+        stats.retained_bytes
+    );
+    assert_eq!(logged_byte(&transact(&pool, read_heap())), 0);
+    assert_eq!(pool.stats().instances, 1);
+}
+
+#[test]
+fn memory_pool__reused_instance_is_zeroed() {
+    // Given
+    let pool = MemoryPool::new(VmMemoryPoolConfig::default());
+    transact(&pool, dirty_heap(8));
+    let retained_bytes = pool.stats().retained_bytes;
+    assert!(retained_bytes > 0);
+
+    // When
+    let receipts = transact(&pool, read_heap());
+
+    // Then
+    assert_eq!(logged_byte(&receipts), 0);
+    assert_eq!(pool.stats().retained_bytes, retained_bytes);
+}
+
+#[test]
+fn memory_pool__take_allocates_when_empty() {
+    // Given
+    let pool = MemoryPool::default();
+
+    // When
+    let first = pool.take();
+    let second = pool.take();
+    drop(first);
+    drop(second);
+
+    // Then
+    assert_eq!(pool.stats().instances, 2);
+    assert_eq!(pool.stats().retained_bytes, 0);
+}
@@ -3,9 +3,11 @@
 
 use fuel_asm::{
     op,
+    Flags,
     GMArgs,
     GTFArgs,
     Instruction,
+    Opcode,
     RegId,
 };
 use fuel_tx::TransactionBuilder;
@@ -14,11 +16,19 @@ use rand::{
     Rng,
     SeedableRng,
 };
+use test_case::test_case;
 use tokio_rayon::AsyncRayonHandle;
 
 use crate::{
+    consts::WORD_SIZE,
     error::PredicateVerificationFailed,
+    interpreter::InterpreterParams,
     pool::DummyPool,
+    predicate::{
+        static_check,
+        CheckContext,
+        StaticIssue,
+    },
     prelude::*,
 };
 
@@ -27,20 +37,35 @@ use crate::{
         CheckError,
         CheckPredicateParams,
         CheckPredicates,
+        Checked,
+        Checks,
         EstimatePredicates,
         ParallelExecutor,
+        PredicateCheckCache,
+        RayonExecutor,
+        TokioExecutor,
     },
     prelude::predicates::{
+        self,
         check_predicates,
         check_predicates_async,
     },
-    storage::predicate::EmptyStorage,
+    storage::predicate::{
+        empty_predicate_storage,
+        EmptyStorage,
+    },
 };
 use core::iter;
 use fuel_tx::{
     consensus_parameters::gas::GasCostsValuesV5,
+    field::{
+        FlagDefaults,
+        Inputs,
+        Witnesses,
+    },
     ConsensusParameters,
 };
+use fuel_types::canonical::Serialize;
 
 pub struct TokioWithRayon;
 
@@ -276,6 +301,57 @@ async fn get_verifying_predicate() {
     }
 }
 
+#[test_case(GMArgs::GetConsensusParametersVersion; "consensus parameters version")]
+#[test_case(GMArgs::GetStateTransitionVersion; "state transition version")]
+fn check_predicates__version_metadata__panics(args: GMArgs) {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    // Given
+    let predicate: Vec<u8> = [op::gm_args(0x10, args), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect();
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(1000).maturity(Default::default());
+    builder.add_input(Input::coin_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        1_000,
+        AssetId::default(),
+        rng.gen(),
+        0,
+        predicate,
+        vec![],
+    ));
+    let params = ConsensusParameters::standard();
+    let check_params = params.clone().into();
+    let mut tx = builder.finalize();
+    tx.estimate_predicates(&check_params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Should estimate predicate");
+    let checked = tx
+        .into_checked_basic(Default::default(), &params)
+        .expect("Should successfully convert into Checked");
+
+    // When
+    let result = check_predicates(
+        &checked,
+        &check_params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    );
+
+    // Then
+    let Err(PredicateVerificationFailed::PanicInstruction { index, instruction }) =
+        result
+    else {
+        panic!("expected the predicate to panic, got {result:?}");
+    };
+    assert_eq!(index, 0);
+    assert_eq!(
+        instruction.reason(),
+        &PanicReason::ContractInstructionNotAllowed
+    );
+}
+
 /// Returns the amount of gas used if verification succeeds
 async fn execute_gas_metered_predicates(
     predicates: Vec<Vec<Instruction>>,
@@ -698,3 +774,949 @@ fn synchronous_estimate_predicates_respects_total_tx_gas_limit() {
     // Then
     assert_eq!(Ok(()), result);
 }
+
+#[test]
+fn estimate_predicates__reports_index_of_oversized_predicate() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let limit = 64;
+    let params = CheckPredicateParams {
+        max_predicate_length: limit,
+        max_predicate_data_length: limit,
+        ..Default::default()
+    };
+
+    let mut predicate_tx = |oversized: (usize, usize)| {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.max_fee_limit(1000).maturity(Default::default());
+        let sizes = [(8, 0), (16, 0), oversized, (24, 0)];
+        for (predicate_len, predicate_data_len) in sizes {
+            let predicate: Vec<u8> = iter::once(op::ret(RegId::ONE))
+                .chain(iter::repeat(op::noop()))
+                .take(predicate_len / Instruction::SIZE)
+                .collect();
+            builder.add_input(Input::coin_predicate(
+                rng.gen(),
+                Input::predicate_owner(&predicate),
+                1_000,
+                AssetId::default(),
+                rng.gen(),
+                0,
+                predicate,
+                vec![0; predicate_data_len],
+            ));
+        }
+        builder.finalize()
+    };
+
+    // Given
+    let mut oversized_predicate = predicate_tx((limit as usize + 8, 0));
+    let mut oversized_predicate_data = predicate_tx((32, 100));
+
+    // When
+    let predicate_result = oversized_predicate.estimate_predicates(
+        &params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    );
+    let predicate_data_result = oversized_predicate_data.estimate_predicates(
+        &params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    );
+
+    // Then
+    assert_eq!(
+        predicate_result,
+        Err(CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::PredicateLength {
+                index: 2,
+                actual: limit + 8,
+                limit,
+            }
+        ))
+    );
+    assert_eq!(
+        predicate_data_result,
+        Err(CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::PredicateDataLength {
+                index: 2,
+                actual: 100,
+                limit,
+            }
+        ))
+    );
+}
+
+fn cached_predicate_tx(params: &CheckPredicateParams) -> Checked<Script> {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    let predicate: Vec<u8> = vec![
+        op::movi(0x10, 0x11),
+        op::addi(0x10, 0x10, 0x01),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let owner = Input::predicate_owner(&predicate);
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(1000).maturity(Default::default());
+    builder.add_unsigned_coin_input(
+        SecretKey::random(rng),
+        rng.gen(),
+        1000,
+        params.base_asset_id,
+        rng.gen(),
+    );
+    for _ in 0..2 {
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            rng.gen_range(1..1000),
+            rng.gen(),
+            rng.gen(),
+            0,
+            predicate.clone(),
+            vec![0xaa; 8],
+        ));
+    }
+
+    let mut transaction = builder.finalize();
+    transaction
+        .estimate_predicates(params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Should estimate predicates");
+
+    transaction
+        .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+        .expect("Should successfully convert into Checked")
+}
+
+#[test]
+fn check_predicates_cached__populates_cache_with_verified_predicates() {
+    // Given
+    let params = CheckPredicateParams::default();
+    let checked = cached_predicate_tx(&params);
+    let mut cache = PredicateCheckCache::new();
+
+    // When
+    let expected_gas_used =
+        check_predicates(&checked, &params, MemoryInstance::new(), &EmptyStorage)
+            .expect("Predicates should be valid")
+            .gas_used();
+    let checked = checked
+        .check_predicates_cached(
+            &params,
+            &mut cache,
+            MemoryInstance::new(),
+            &EmptyStorage,
+        )
+        .expect("Predicates should be valid");
+
+    // Then
+    assert!(checked.checks().contains(Checks::Predicates));
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(0).is_none());
+    let params_digest = PredicateCheckCache::params_digest(&params);
+    let cached_gas_used: Word = [1, 2]
+        .into_iter()
+        .map(|index| {
+            let entry = cache.get(index).expect("Predicate should be cached");
+            assert_eq!(
+                entry.digest,
+                PredicateCheckCache::entry_digest(
+                    checked.transaction(),
+                    index,
+                    &params_digest
+                )
+            );
+            entry.gas_used
+        })
+        .sum();
+    assert_eq!(cached_gas_used, expected_gas_used);
+}
+
+#[test]
+fn check_predicates_cached__unrelated_fee_parameter_change_hits_cache() {
+    // Given
+    let params = CheckPredicateParams::default();
+    let checked = cached_predicate_tx(&params);
+    let mut cache = PredicateCheckCache::new();
+    let result = predicates::check_predicates_cached(
+        &checked,
+        &params,
+        &mut cache,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Predicates should be valid");
+    let cache_before = cache.clone();
+
+    // When
+    let new_params = CheckPredicateParams {
+        fee_params: params.fee_params.with_gas_price_factor(
+            params.fee_params.gas_price_factor().saturating_add(1),
+        ),
+        ..params.clone()
+    };
+    let new_result = predicates::check_predicates_cached(
+        &checked,
+        &new_params,
+        &mut cache,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Predicates should be valid");
+
+    // Then
+    assert_eq!(
+        PredicateCheckCache::params_digest(&params),
+        PredicateCheckCache::params_digest(&new_params)
+    );
+    assert_eq!(cache, cache_before);
+    assert_eq!(result.gas_used(), new_result.gas_used());
+}
+
+#[test]
+fn params_digest__consensus_switches__change_the_digest() {
+    // Given
+    let params = CheckPredicateParams::default();
+    let switches: [fn(&mut CheckPredicateParams); 7] = [
+        |params| params.static_check = !params.static_check,
+        |params| params.strict_call_gas = !params.strict_call_gas,
+        |params| params.word_aligned_heap = !params.word_aligned_heap,
+        |params| params.allow_empty_reads = !params.allow_empty_reads,
+        |params| params.relative_jumps_within_code = !params.relative_jumps_within_code,
+        |params| params.call_frame_reverts = !params.call_frame_reverts,
+        |params| params.precise_output_panics = !params.precise_output_panics,
+    ];
+
+    for toggle in switches {
+        // When
+        let mut new_params = params.clone();
+        toggle(&mut new_params);
+
+        // Then
+        assert_ne!(
+            PredicateCheckCache::params_digest(&params),
+            PredicateCheckCache::params_digest(&new_params)
+        );
+    }
+}
+
+#[test]
+fn check_predicates_cached__gas_costs_change_invalidates_cache() {
+    // Given
+    let params = CheckPredicateParams::default();
+    let checked = cached_predicate_tx(&params);
+    let mut cache = PredicateCheckCache::new();
+    predicates::check_predicates_cached(
+        &checked,
+        &params,
+        &mut cache,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Predicates should be valid");
+    let cache_before = cache.clone();
+
+    // When
+    let new_params = CheckPredicateParams {
+        gas_costs: GasCosts::unit(),
+        ..params.clone()
+    };
+    let result = predicates::check_predicates_cached(
+        &checked,
+        &new_params,
+        &mut cache,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    );
+
+    // Then
+    assert_ne!(
+        PredicateCheckCache::params_digest(&params),
+        PredicateCheckCache::params_digest(&new_params)
+    );
+    // The predicates are re-executed with new gas costs, so the estimated
+    // `predicate_gas_used` doesn't match anymore.
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, PredicateVerificationFailed::GasMismatch { .. }),
+        "{err}"
+    );
+    assert_eq!(cache, cache_before);
+}
+
+fn static_check_program(instructions: &[Instruction]) -> Vec<u8> {
+    instructions.iter().copied().collect()
+}
+
+#[test]
+fn static_check__clean_program_has_no_issues() {
+    // Given
+    let code = static_check_program(&[
+        op::movi(0x10, 1),
+        op::jnzi(0x10, 3),
+        op::noop(),
+        op::ret(RegId::ONE),
+    ]);
+
+    // When
+    let script_issues = static_check(&code, CheckContext::Script);
+    let predicate_issues = static_check(&code, CheckContext::Predicate);
+
+    // Then
+    assert_eq!(script_issues, vec![]);
+    assert_eq!(predicate_issues, vec![]);
+}
+
+#[test]
+fn static_check__reports_truncated_last_instruction() {
+    // Given
+    let mut code = static_check_program(&[op::noop(), op::ret(RegId::ONE)]);
+    code.extend([0, 0]);
+
+    // When
+    let issues = static_check(&code, CheckContext::Script);
+
+    // Then
+    assert_eq!(
+        issues,
+        vec![StaticIssue::TruncatedInstruction { offset: 8 }]
+    );
+}
+
+#[test]
+fn static_check__reports_invalid_instruction() {
+    // Given
+    let mut code = static_check_program(&[op::noop()]);
+    code.extend([0xff, 0, 0, 0]);
+    code.extend(op::ret(RegId::ONE).to_bytes());
+
+    // When
+    let issues = static_check(&code, CheckContext::Script);
+
+    // Then
+    assert_eq!(issues, vec![StaticIssue::InvalidInstruction { offset: 4 }]);
+}
+
+#[test]
+fn static_check__reports_opcode_not_allowed_only_in_predicates() {
+    // Given
+    let code = static_check_program(&[
+        op::noop(),
+        op::call(0x10, 0x11, 0x12, 0x13),
+        op::ret(RegId::ONE),
+    ]);
+
+    // When
+    let script_issues = static_check(&code, CheckContext::Script);
+    let predicate_issues = static_check(&code, CheckContext::Predicate);
+
+    // Then
+    assert_eq!(script_issues, vec![]);
+    assert_eq!(
+        predicate_issues,
+        vec![StaticIssue::OpcodeNotAllowed {
+            offset: 4,
+            opcode: Opcode::CALL,
+        }]
+    );
+}
+
+#[test_case(op::ji(1), None; "ji in bounds")]
+#[test_case(op::ji(2), Some(8); "ji out of bounds")]
+#[test_case(op::jnzi(0x10, 1), None; "jnzi in bounds")]
+#[test_case(op::jnzi(0x10, 100), Some(400); "jnzi out of bounds")]
+#[test_case(op::jnei(0x10, 0x11, 1), None; "jnei in bounds")]
+#[test_case(op::jnei(0x10, 0x11, 2), Some(8); "jnei out of bounds")]
+fn static_check__reports_absolute_jumps_beyond_the_code(
+    jump: Instruction,
+    target: Option<u64>,
+) {
+    // Given
+    let code = static_check_program(&[jump, op::ret(RegId::ONE)]);
+
+    // When
+    let issues = static_check(&code, CheckContext::Predicate);
+
+    // Then
+    let expected: Vec<_> = target
+        .map(|target| StaticIssue::JumpOutOfBounds { offset: 0, target })
+        .into_iter()
+        .collect();
+    assert_eq!(issues, expected);
+}
+
+#[test]
+fn static_check__reports_issue_offsets_in_order() {
+    // Given
+    let mut code = static_check_program(&[
+        op::ji(10),
+        op::call(0x10, 0x11, 0x12, 0x13),
+        op::ret(RegId::ONE),
+    ]);
+    code.push(0);
+
+    // When
+    let issues = static_check(&code, CheckContext::Predicate);
+
+    // Then
+    let offsets: Vec<_> = issues.iter().map(StaticIssue::offset).collect();
+    assert_eq!(offsets, vec![0, 4, 12]);
+}
+
+#[test]
+fn estimate_predicates__static_check_rejects_unreachable_disallowed_opcode() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    // Given
+    let predicate =
+        static_check_program(&[op::ret(RegId::ONE), op::call(0x10, 0x11, 0x12, 0x13)]);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(1000).maturity(Default::default());
+    builder.add_input(Input::coin_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        1_000,
+        AssetId::default(),
+        rng.gen(),
+        0,
+        predicate,
+        vec![],
+    ));
+    let tx = builder.finalize();
+    let params = CheckPredicateParams::default();
+    let static_params = CheckPredicateParams {
+        static_check: true,
+        ..params.clone()
+    };
+
+    // When
+    let result =
+        tx.clone()
+            .estimate_predicates(&params, MemoryInstance::new(), &EmptyStorage);
+    let static_result = tx.clone().estimate_predicates(
+        &static_params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    );
+
+    // Then
+    assert_eq!(result, Ok(()));
+    assert_eq!(
+        static_result,
+        Err(CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::StaticCheck {
+                index: 0,
+                issue: StaticIssue::OpcodeNotAllowed {
+                    offset: 4,
+                    opcode: Opcode::CALL,
+                },
+            }
+        ))
+    );
+}
+
+/// A script with 8 predicates using different amounts of gas.
+fn tx_with_8_predicates() -> Script {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    for i in 0..8 {
+        let predicate: Vec<u8> = iter::repeat(op::noop())
+            .take(i * 10)
+            .chain(iter::once(op::ret(RegId::ONE)))
+            .collect();
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&predicate),
+            1_000,
+            AssetId::default(),
+            rng.gen(),
+            0,
+            predicate,
+            vec![],
+        ));
+    }
+    builder.finalize()
+}
+
+async fn estimate_predicates_with<E: ParallelExecutor>() {
+    // Given
+    let params = CheckPredicateParams::default();
+    let mut sequential = tx_with_8_predicates();
+    sequential
+        .estimate_predicates(&params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Failed to estimate predicates");
+    let mut parallel = tx_with_8_predicates();
+
+    // When
+    parallel
+        .estimate_predicates_async::<E>(&params, &DummyPool, &EmptyStorage)
+        .await
+        .expect("Failed to estimate predicates");
+
+    // Then
+    assert_eq!(parallel, sequential);
+    let gas_used: Vec<_> = parallel
+        .inputs()
+        .iter()
+        .map(|input| input.predicate_gas_used().unwrap())
+        .collect();
+    assert!(gas_used.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+async fn panicking_task_with<E: ParallelExecutor>() {
+    // Given
+    let tasks = vec![
+        E::create_task(|| Ok((1, 0))),
+        E::create_task(|| panic!("The predicate task panicked")),
+        E::create_task(|| Ok((3, 2))),
+    ];
+
+    // When
+    let results = E::execute_tasks(tasks).await;
+
+    // Then
+    assert_eq!(
+        results,
+        vec![
+            Ok((1, 0)),
+            Err(PredicateVerificationFailed::TaskPanicked),
+            Ok((3, 2)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rayon_executor__estimates_same_as_sequential() {
+    estimate_predicates_with::<RayonExecutor>().await;
+}
+
+#[tokio::test]
+async fn rayon_executor__panicking_task_returns_error() {
+    panicking_task_with::<RayonExecutor>().await;
+}
+
+#[tokio::test]
+async fn tokio_executor__estimates_same_as_sequential() {
+    estimate_predicates_with::<TokioExecutor>().await;
+}
+
+#[tokio::test]
+async fn tokio_executor__panicking_task_returns_error() {
+    panicking_task_with::<TokioExecutor>().await;
+}
+
+#[test]
+fn dedup_witnesses__shrinks_tx_with_identical_predicates_and_signatures() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let params = ConsensusParameters::standard();
+    let chain_id = params.chain_id();
+    let secret = SecretKey::random(rng);
+    let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let owner = Input::predicate_owner(&predicate);
+
+    let mut script = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .finalize();
+    for _ in 0..4 {
+        script.add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            1_000,
+            AssetId::BASE,
+            rng.gen(),
+            0,
+            predicate.clone(),
+            vec![],
+        ));
+        let witness_index = u16::try_from(script.witnesses().len()).unwrap();
+        script.add_unsigned_coin_input(
+            rng.gen(),
+            &secret.public_key(),
+            1_000,
+            AssetId::BASE,
+            rng.gen(),
+            witness_index,
+        );
+        script.witnesses_mut().push(Witness::default());
+    }
+    script.precompute(&chain_id).unwrap();
+    script.sign_inputs(&secret, &chain_id);
+    let mut tx: Transaction = script.into();
+    let size = tx.size();
+
+    // When
+    let stats = tx.shared_predicate_stats();
+    let removed = tx.dedup_witnesses();
+
+    // Then
+    assert_eq!(stats.predicates, 4);
+    assert_eq!(stats.unique, 1);
+    // The predicate is padded to the word
+    assert_eq!(stats.duplicate_bytes, 3 * WORD_SIZE);
+    assert_eq!(removed, 3);
+    assert_eq!(size - tx.size(), 3 * Witness::from(vec![0; 64]).size());
+    let Transaction::Script(mut script) = tx else {
+        unreachable!("The transaction is a script")
+    };
+    // The predicates are estimated and the inputs signed again for the new transaction
+    script
+        .estimate_predicates(&params.clone().into(), MemoryInstance::new(), &EmptyStorage)
+        .expect("Should estimate predicates");
+    script.precompute(&chain_id).unwrap();
+    script.sign_inputs(&secret, &chain_id);
+    script
+        .into_checked(Default::default(), &params)
+        .expect("The deduplicated transaction should be valid");
+}
+
+/// A script with a signed coin followed by three predicates of different sizes.
+fn tx_with_3_predicates_after_coin() -> Script {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.add_unsigned_coin_input(
+        SecretKey::random(rng),
+        rng.gen(),
+        1_000,
+        AssetId::default(),
+        rng.gen(),
+    );
+    for noops in [5, 50, 500] {
+        let predicate: Vec<u8> = iter::repeat(op::noop())
+            .take(noops)
+            .chain(iter::once(op::ret(RegId::ONE)))
+            .collect();
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&predicate),
+            1_000,
+            AssetId::default(),
+            rng.gen(),
+            0,
+            predicate,
+            vec![],
+        ));
+    }
+    builder.finalize()
+}
+
+/// Asserts that the `per_input_gas` matches the `predicate_gas_used` of the inputs
+/// and sums up to the `gas_used`.
+fn assert_per_input_gas_matches_inputs(
+    per_input_gas: &[(usize, Word)],
+    gas_used: Word,
+    tx: &Script,
+) {
+    let expected: Vec<_> = tx
+        .inputs()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, input)| Some((index, input.predicate_gas_used()?)))
+        .collect();
+    assert_eq!(per_input_gas, expected.as_slice());
+    let sum = per_input_gas
+        .iter()
+        .map(|(_, gas_used)| gas_used)
+        .sum::<Word>();
+    assert_eq!(sum, gas_used);
+}
+
+#[test]
+fn estimate_predicates__reports_gas_of_every_predicate_input() {
+    // Given
+    let params = CheckPredicateParams::default();
+    let mut tx = tx_with_3_predicates_after_coin();
+
+    // When
+    let checked = predicates::estimate_predicates(
+        &mut tx,
+        &params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Failed to estimate predicates");
+
+    // Then
+    let indices: Vec<_> = checked
+        .per_input_gas()
+        .iter()
+        .map(|(index, _)| *index)
+        .collect();
+    assert_eq!(indices, vec![1, 2, 3]);
+    assert_per_input_gas_matches_inputs(checked.per_input_gas(), checked.gas_used(), &tx);
+    let gas_used: Vec<_> = checked
+        .per_input_gas()
+        .iter()
+        .map(|(_, gas)| *gas)
+        .collect();
+    assert!(gas_used.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[tokio::test]
+async fn estimate_predicates_async__reports_gas_of_every_predicate_input() {
+    // Given
+    let params = CheckPredicateParams::default();
+    let mut tx = tx_with_3_predicates_after_coin();
+
+    // When
+    let checked = predicates::estimate_predicates_async::<_, TokioWithRayon>(
+        &mut tx,
+        &params,
+        &DummyPool,
+        &EmptyStorage,
+    )
+    .await
+    .expect("Failed to estimate predicates");
+
+    // Then
+    assert_eq!(checked.per_input_gas().len(), 3);
+    assert_per_input_gas_matches_inputs(checked.per_input_gas(), checked.gas_used(), &tx);
+}
+
+#[test]
+fn check_predicates__reports_gas_of_every_predicate_input() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let check_params = CheckPredicateParams::from(&params);
+    let mut tx = tx_with_3_predicates_after_coin();
+    tx.estimate_predicates(&check_params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Failed to estimate predicates");
+    let checked = tx
+        .clone()
+        .into_checked_basic(Default::default(), &params)
+        .expect("Failed to check the transaction");
+
+    // When
+    let predicates_checked = check_predicates(
+        &checked,
+        &check_params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Failed to verify predicates");
+
+    // Then
+    assert_eq!(predicates_checked.per_input_gas().len(), 3);
+    assert_per_input_gas_matches_inputs(
+        predicates_checked.per_input_gas(),
+        predicates_checked.gas_used(),
+        &tx,
+    );
+}
+
+/// A script with the single predicate running the `instructions`.
+fn tx_with_predicate(instructions: Vec<Instruction>) -> Script {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let predicate: Vec<u8> = instructions.into_iter().collect();
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(1000).maturity(Default::default());
+    builder.add_input(Input::coin_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        1_000,
+        AssetId::default(),
+        rng.gen(),
+        0,
+        predicate,
+        vec![],
+    ));
+    builder.finalize()
+}
+
+/// The predicate returning two words with `RETD`, the `first_word` and 7.
+fn retd_predicate(first_word: u16) -> Vec<Instruction> {
+    vec![
+        op::movi(0x10, 2 * WORD_SIZE as u32),
+        op::aloc(0x10),
+        op::movi(0x11, first_word.into()),
+        op::sw(RegId::HP, 0x11, 0),
+        op::movi(0x11, 7),
+        op::sw(RegId::HP, 0x11, 1),
+        op::retd(RegId::HP, 0x10),
+    ]
+}
+
+fn retd_data(first_word: Word) -> Vec<u8> {
+    [first_word.to_be_bytes(), 7u64.to_be_bytes()].concat()
+}
+
+#[test]
+fn estimate_predicates__ret_one__returns_no_data() {
+    // Given
+    let mut tx = tx_with_predicate(vec![op::ret(RegId::ONE)]);
+
+    // When
+    let checked = predicates::estimate_predicates(
+        &mut tx,
+        &CheckPredicateParams::default(),
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Failed to estimate predicates");
+
+    // Then
+    assert_eq!(checked.predicate_return_data(0), None);
+    assert!(checked.gas_used() > 0);
+}
+
+#[test]
+fn estimate_predicates__retd_starting_with_one__captures_the_data() {
+    // Given
+    let mut tx = tx_with_predicate(retd_predicate(1));
+
+    // When
+    let checked = predicates::estimate_predicates(
+        &mut tx,
+        &CheckPredicateParams::default(),
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Failed to estimate predicates");
+
+    // Then
+    assert_eq!(
+        checked.predicate_return_data(0),
+        Some(retd_data(1).as_slice())
+    );
+    assert_eq!(
+        tx.inputs()[0].predicate_gas_used(),
+        Some(checked.gas_used())
+    );
+}
+
+#[test]
+fn verify_predicate__flag_defaults_policy__predicate_starts_with_zero_flag() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let mut tx =
+        tx_with_predicate(vec![op::eq(0x10, RegId::FLAG, RegId::ZERO), op::ret(0x10)]);
+    tx.set_flag_defaults(Flags::WRAPPING.bits());
+    tx.precompute(&params.chain_id())
+        .expect("Failed to precompute the transaction");
+    let program = RuntimePredicate::from_tx(&tx, params.tx_params().tx_offset(), 0)
+        .expect("The input is the predicate");
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        empty_predicate_storage(),
+        InterpreterParams::new(0, &params),
+    );
+    vm.init_predicate(Context::PredicateEstimation { program }, tx, 1_000_000)
+        .expect("The predicate is initialized");
+
+    // When
+    let result = vm.verify_predicate();
+
+    // Then
+    assert!(
+        result.is_ok(),
+        "The predicate must start with the zero `$flag`"
+    );
+}
+
+#[test_case(0; "zero")]
+#[test_case(2; "two")]
+fn verify_predicate__retd_not_starting_with_one__panics(first_word: u16) {
+    // Given
+    let params = ConsensusParameters::standard();
+    let tx = tx_with_predicate(retd_predicate(first_word));
+    let program = RuntimePredicate::from_tx(&tx, params.tx_params().tx_offset(), 0)
+        .expect("The input is the predicate");
+    let mut vm = Interpreter::<_, _, Script>::with_storage(
+        MemoryInstance::new(),
+        empty_predicate_storage(),
+        InterpreterParams::new(0, &params),
+    );
+    vm.init_predicate(Context::PredicateEstimation { program }, tx, 1_000_000)
+        .expect("The predicate is initialized");
+
+    // When
+    let result = vm.verify_predicate();
+
+    // Then
+    let reason = result
+        .expect_err("The predicate doesn't return 1")
+        .panic_reason();
+    assert_eq!(reason, Some(PanicReason::PredicateReturnedNonOne));
+}
+
+#[test_case(0; "zero")]
+#[test_case(2; "two")]
+fn estimate_predicates__retd_not_starting_with_one__returns_no_data(first_word: u16) {
+    // Given
+    let mut tx = tx_with_predicate(retd_predicate(first_word));
+
+    // When
+    let checked = predicates::estimate_predicates(
+        &mut tx,
+        &CheckPredicateParams::default(),
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Failed to estimate predicates");
+
+    // Then
+    assert_eq!(checked.predicate_return_data(0), None);
+}
+
+#[test]
+fn check_predicates__retd_starting_with_one__is_not_allowed() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let check_params = params.clone().into();
+    let mut tx = tx_with_predicate(retd_predicate(1));
+    tx.estimate_predicates(&check_params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Should estimate predicate");
+    let checked = tx
+        .into_checked_basic(Default::default(), &params)
+        .expect("Should successfully convert into Checked");
+
+    // When
+    let result = check_predicates(
+        &checked,
+        &check_params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    );
+
+    // Then
+    // Returning the data only adds the observability to the estimation, the
+    // verification still requires `RET 1`.
+    let Err(PredicateVerificationFailed::PanicInstruction { index, instruction }) =
+        result
+    else {
+        panic!("expected the predicate to panic, got {result:?}");
+    };
+    assert_eq!(index, 0);
+    assert_eq!(
+        instruction.reason(),
+        &PanicReason::ContractInstructionNotAllowed
+    );
+}
+
+#[test]
+fn check_predicates__ret_one__returns_no_data() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let check_params = params.clone().into();
+    let mut tx = tx_with_predicate(vec![op::ret(RegId::ONE)]);
+    tx.estimate_predicates(&check_params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Should estimate predicate");
+    let checked = tx
+        .into_checked_basic(Default::default(), &params)
+        .expect("Should successfully convert into Checked");
+
+    // When
+    let predicates_checked = check_predicates(
+        &checked,
+        &check_params,
+        MemoryInstance::new(),
+        &EmptyStorage,
+    )
+    .expect("Failed to verify predicates");
+
+    // Then
+    assert_eq!(predicates_checked.predicate_return_data(0), None);
+}
@@ -1,11 +1,15 @@
 #![cfg(feature = "std")]
 
+use core::num::NonZeroU64;
 use fuel_asm::{
     op,
     RegId,
 };
 use fuel_tx::TransactionBuilder;
-use fuel_vm::prelude::*;
+use fuel_vm::{
+    prelude::*,
+    profiler::ProfilingData,
+};
 use rand::{
     rngs::StdRng,
     Rng,
@@ -100,3 +104,109 @@ fn profile_gas() {
     assert!(items0[0] == items1[0] && items0[0] == items2[0]);
     assert!(items0[1] == items1[1] && items0[1] == items2[1]);
 }
+
+/// Profiles a script looping `count` times with the gas sampling `interval`.
+fn profile_loop(count: u16, interval: Option<NonZeroU64>) -> ProfilingData {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    let gas_limit = 1_000_000;
+    let reg_a = 0x20;
+    let script_code = vec![
+        op::xor(reg_a, reg_a, reg_a),
+        op::ori(reg_a, reg_a, count),
+        op::subi(reg_a, reg_a, 1),
+        op::jnei(RegId::ZERO, reg_a, 2),
+        op::ret(RegId::ONE),
+    ];
+
+    let tx = TransactionBuilder::script(script_code.into_iter().collect(), vec![])
+        .max_fee_limit(gas_limit)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            gas_limit,
+            Default::default(),
+            rng.gen(),
+        )
+        .script_gas_limit(gas_limit)
+        .finalize_checked(Default::default());
+
+    let output = GasProfiler::default();
+
+    let mut vm = Interpreter::<_, _, _>::with_memory_storage();
+    vm.with_profiler(output.clone());
+    if let Some(interval) = interval {
+        vm.with_gas_sampling_interval(interval);
+    }
+    let mut client = MemoryClient::from_txtor(vm.into());
+
+    let receipts = client.transact(tx);
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+
+    output.data().expect("failed to fetch profiling data")
+}
+
+fn total_gas(data: &ProfilingData) -> u64 {
+    data.gas().values().sum()
+}
+
+#[test]
+fn profile_gas__sampling_interval_one__is_exact() {
+    // Given
+    let full = profile_loop(1_000, None);
+
+    // When
+    let sampled = profile_loop(1_000, Some(NonZeroU64::MIN));
+
+    // Then
+    assert_eq!(sampled.gas().sampling_interval(), NonZeroU64::MIN);
+    let mut keys: Vec<_> = full.gas().keys().collect();
+    keys.sort();
+    let mut sampled_keys: Vec<_> = sampled.gas().keys().collect();
+    sampled_keys.sort();
+    assert_eq!(keys, sampled_keys);
+    for key in keys {
+        assert_eq!(full.gas().get(key), sampled.gas().get(key));
+    }
+}
+
+#[test]
+fn profile_gas__sampled_total__is_close_to_full_profile() {
+    // Given
+    let interval = NonZeroU64::new(7).unwrap();
+    let full = profile_loop(4_000, None);
+
+    // When
+    let sampled = profile_loop(4_000, Some(interval));
+
+    // Then
+    assert_eq!(sampled.gas().sampling_interval(), interval);
+    let full_total = total_gas(&full);
+    let sampled_total = total_gas(&sampled);
+    let tolerance = full_total / 100;
+    assert!(
+        full_total.abs_diff(sampled_total) <= tolerance,
+        "sampled total {sampled_total} differs from {full_total} by more than 1%"
+    );
+}
+
+#[test]
+fn profile_gas__sampling__is_deterministic() {
+    let interval = NonZeroU64::new(5).unwrap();
+
+    let first = profile_loop(1_000, Some(interval));
+    let second = profile_loop(1_000, Some(interval));
+
+    let mut keys: Vec<_> = first.gas().keys().collect();
+    keys.sort();
+    assert!(!keys.is_empty());
+    for key in keys {
+        assert_eq!(first.gas().get(key), second.gas().get(key));
+    }
+}
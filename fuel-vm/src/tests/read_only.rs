@@ -0,0 +1,184 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    GTFArgs,
+    Instruction,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    Input,
+    Output,
+    Receipt,
+    StorageSlot,
+    TransactionBuilder,
+};
+use fuel_types::{
+    canonical::Serialize,
+    Bytes32,
+    ContractId,
+};
+
+use crate::{
+    checked_transaction::{
+        builder::TransactionBuilderExt,
+        Checked,
+    },
+    interpreter::MemoryInstance,
+    memory_client::MemoryClient,
+    prelude::{
+        Call,
+        Script,
+    },
+};
+
+const SLOT_VALUE: u64 = 0xdead_beef;
+
+/// Deploys the contract with the `code` and the `SLOT_VALUE` stored at the zero key.
+fn deploy(
+    client: &mut MemoryClient<MemoryInstance>,
+    code: Vec<Instruction>,
+) -> ContractId {
+    let mut value = [0u8; 32];
+    value[..8].copy_from_slice(&SLOT_VALUE.to_be_bytes());
+    let storage_slots = vec![StorageSlot::new(Bytes32::zeroed(), value.into())];
+
+    client
+        .deploy_contract(
+            code.into_iter().collect(),
+            Default::default(),
+            storage_slots,
+        )
+        .expect("Failed to deploy the contract")
+        .contract_id
+}
+
+/// The script calling the `contract`.
+fn call(contract: ContractId) -> Checked<Script> {
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = Call::new(contract, 0, 0).to_bytes();
+
+    TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default())
+}
+
+#[test]
+fn transact_read_only__reading_script__succeeds_without_changes() {
+    let mut client = MemoryClient::default();
+
+    // Given
+    let contract = deploy(
+        &mut client,
+        vec![
+            op::movi(0x10, Bytes32::LEN as u32),
+            op::aloc(0x10),
+            op::srw(0x11, 0x12, RegId::HP),
+            op::log(0x11, 0x12, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ],
+    );
+    let before = client.as_ref().dump();
+
+    // When
+    let transition = client
+        .transact_read_only(call(contract))
+        .expect("The read-only execution failed");
+
+    // Then
+    assert!(!transition.should_revert());
+    let logged = transition
+        .receipts()
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Log { id, ra, rb, .. } if id == &contract => Some((*ra, *rb)),
+            _ => None,
+        });
+    assert_eq!(logged, Some((SLOT_VALUE, 1)));
+    assert_eq!(client.as_ref().dump(), before);
+}
+
+#[test]
+fn transact_read_only__writing_script__panics_without_changes() {
+    let mut client = MemoryClient::default();
+
+    // Given
+    let contract = deploy(
+        &mut client,
+        vec![
+            op::movi(0x10, Bytes32::LEN as u32),
+            op::aloc(0x10),
+            op::sww(RegId::HP, 0x11, RegId::ONE),
+            op::ret(RegId::ONE),
+        ],
+    );
+    let before = client.as_ref().dump();
+
+    // When
+    let transition = client
+        .transact_read_only(call(contract))
+        .expect("The read-only execution failed");
+
+    // Then
+    assert!(transition.should_revert());
+    let reason = transition
+        .receipts()
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::Panic { reason, .. } => Some(*reason.reason()),
+            _ => None,
+        });
+    assert_eq!(reason, Some(PanicReason::WriteInReadOnlyMode));
+    assert_eq!(client.as_ref().dump(), before);
+}
+
+#[test]
+fn transact_read_only__writing_script__can_be_transacted_afterwards() {
+    let mut client = MemoryClient::default();
+
+    // Given
+    let contract = deploy(
+        &mut client,
+        vec![
+            op::movi(0x10, Bytes32::LEN as u32),
+            op::aloc(0x10),
+            op::sww(RegId::HP, 0x11, RegId::ONE),
+            op::ret(RegId::ONE),
+        ],
+    );
+    let _ = client.transact_read_only(call(contract));
+
+    // When
+    let receipts = client.transact(call(contract));
+
+    // Then
+    assert!(receipts
+        .iter()
+        .all(|receipt| !matches!(receipt, Receipt::Panic { .. })));
+    let value = client
+        .contract_state_value(&contract, &Bytes32::zeroed())
+        .expect("Infallible")
+        .expect("The slot is set");
+    let mut expected = [0u8; 32];
+    expected[..8].copy_from_slice(&1u64.to_be_bytes());
+    assert_eq!(value.as_ref().as_ref(), &expected);
+}
@@ -1,16 +1,51 @@
 use fuel_asm::{
     op,
+    GTFArgs,
+    Instruction,
     PanicReason,
     RegId,
 };
+use fuel_crypto::SecretKey;
 use fuel_tx::{
+    field::ReceiptsRoot,
+    ConsensusParameters,
+    Finalizable,
     Receipt,
     ScriptExecutionResult,
+    TransactionBuilder,
 };
+use fuel_types::{
+    canonical::Serialize,
+    Bytes32,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use test_case::test_case;
 
-use alloc::vec;
+use alloc::{
+    vec,
+    vec::Vec,
+};
 
-use crate::interpreter::ReceiptsCtx;
+use crate::{
+    checked_transaction::IntoChecked,
+    consts::EMPTY_RECEIPTS_MERKLE_ROOT,
+    interpreter::{
+        InterpreterParams,
+        MemoryInstance,
+        ReceiptsCtx,
+    },
+    memory_client::MemoryClient,
+    prelude::{
+        Call,
+        TestBuilder,
+    },
+    storage::MemoryStorage,
+    transactor::Transactor,
+};
 
 use super::test_helpers::run_script;
 
@@ -68,3 +103,219 @@ fn can_return_successfully_just_below_max_receipts() {
     };
     assert_eq!(result, ScriptExecutionResult::Success);
 }
+
+#[test]
+fn compute_root__empty_receipts_matches_constant() {
+    let empty_root = Bytes32::from(EMPTY_RECEIPTS_MERKLE_ROOT);
+
+    assert_eq!(Receipt::compute_root(&[]), empty_root);
+    assert_eq!(ReceiptsCtx::default().root(), empty_root);
+}
+
+#[test_case(vec![op::ret(RegId::ONE)]; "return")]
+#[test_case(vec![
+    op::log(RegId::ONE, RegId::ZERO, RegId::ONE, RegId::ZERO),
+    op::log(RegId::ZERO, RegId::ONE, RegId::ZERO, RegId::ONE),
+    op::ret(RegId::ONE),
+]; "logs")]
+#[test_case(vec![
+    op::movi(0x10, 64),
+    op::logd(RegId::ZERO, RegId::ZERO, RegId::ZERO, 0x10),
+    op::retd(RegId::ZERO, 0x10),
+]; "data")]
+#[test_case(vec![
+    op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+    op::rvrt(RegId::ONE),
+]; "revert")]
+#[test_case(vec![
+    op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+    op::div(0x10, RegId::ONE, RegId::ZERO),
+]; "panic")]
+fn compute_root__matches_receipts_root_of_executed_script(script: Vec<Instruction>) {
+    let mut client = MemoryClient::default();
+    let consensus_params = ConsensusParameters::standard();
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let max_fee = 1000;
+
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .max_fee_limit(max_fee)
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            max_fee,
+            *consensus_params.base_asset_id(),
+            Default::default(),
+        )
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate a checked tx");
+    client.transact(tx);
+
+    let state = client.state_transition().expect("tx was executed");
+    assert!(!state.receipts().is_empty());
+    assert_eq!(
+        *state.tx().receipts_root(),
+        Receipt::compute_root(state.receipts())
+    );
+}
+
+/// Returns the transactor reserving space for `receipts_capacity_hint` receipts.
+fn transactor_with_hint(
+    receipts_capacity_hint: usize,
+) -> Transactor<MemoryInstance, MemoryStorage, fuel_tx::Script> {
+    let interpreter_params = InterpreterParams {
+        receipts_capacity_hint,
+        ..InterpreterParams::new(0, ConsensusParameters::standard())
+    };
+    Transactor::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        interpreter_params,
+    )
+}
+
+/// Executes the script logging `count` receipts and returns all receipts.
+fn transact_logs(
+    transactor: &mut Transactor<MemoryInstance, MemoryStorage, fuel_tx::Script>,
+    count: u32,
+) -> Vec<Receipt> {
+    let script = vec![
+        op::movi(0x10, count),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::subi(0x10, 0x10, 1),
+        op::jnzb(0x10, RegId::ZERO, 1),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(10_000_000)
+        .add_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &ConsensusParameters::standard())
+        .expect("failed to generate a checked tx");
+    transactor.transact(tx);
+    transactor.receipts().expect("tx was executed").to_vec()
+}
+
+#[test_case(16)]
+#[test_case(1_000)]
+#[test_case(10_000)]
+fn receipts_capacity_hint__keeps_receipts_identical(hint: usize) {
+    // Given
+    let mut without_hint = transactor_with_hint(0);
+    let mut with_hint = transactor_with_hint(hint);
+
+    // When
+    let expected = transact_logs(&mut without_hint, 100);
+    let receipts = transact_logs(&mut with_hint, 100);
+
+    // Then
+    assert_eq!(receipts, expected);
+    assert!(with_hint.interpreter().receipts_capacity() >= hint);
+}
+
+#[test]
+fn receipts_capacity__is_kept_for_the_next_transaction() {
+    // Given
+    let mut transactor = transactor_with_hint(0);
+    transact_logs(&mut transactor, 500);
+    let capacity = transactor.interpreter().receipts_capacity();
+
+    // When
+    let receipts = transact_logs(&mut transactor, 1);
+
+    // Then
+    assert_eq!(receipts.len(), 3);
+    assert_eq!(transactor.interpreter().receipts_capacity(), capacity);
+}
+
+#[test]
+fn receipts_capacity__is_shrunk_above_the_retained_capacity() {
+    // Given
+    let mut transactor = transactor_with_hint(0);
+    transact_logs(&mut transactor, 5_000);
+    let capacity = transactor.interpreter().receipts_capacity();
+
+    // When
+    transact_logs(&mut transactor, 1);
+
+    // Then
+    assert!(capacity > ReceiptsCtx::RETAINED_CAPACITY);
+    assert!(transactor.interpreter().receipts_capacity() < capacity);
+    assert!(
+        transactor.interpreter().receipts_capacity() >= ReceiptsCtx::RETAINED_CAPACITY
+    );
+}
+
+#[test_case(vec![]; "empty script")]
+#[test_case(vec![op::ret(RegId::ONE)]; "return")]
+#[test_case(vec![
+    op::movi(0x10, 64),
+    op::logd(RegId::ZERO, RegId::ZERO, RegId::ZERO, 0x10),
+    op::retd(RegId::ZERO, 0x10),
+]; "data")]
+#[test_case(vec![
+    op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+    op::rvrt(RegId::ONE),
+]; "revert")]
+#[test_case(vec![
+    op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+    op::div(0x10, RegId::ONE, RegId::ZERO),
+]; "panic")]
+fn validate_sequence__executed_script__succeeds(script: Vec<Instruction>) {
+    // When
+    let receipts = run_script(script);
+
+    // Then
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult { .. })
+    ));
+    assert_eq!(Receipt::validate_sequence(&receipts), Ok(()));
+}
+
+#[test]
+fn validate_sequence__too_many_receipts__succeeds() {
+    // When
+    let receipts = run_script(vec![
+        op::log(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::jmpb(RegId::ZERO, 0),
+    ]);
+
+    // Then
+    assert_eq!(Receipt::validate_sequence(&receipts), Ok(()));
+}
+
+#[test_case(vec![op::ret(RegId::ONE)]; "contract returns")]
+#[test_case(vec![op::rvrt(RegId::ONE)]; "contract reverts")]
+#[test_case(vec![op::div(0x10, RegId::ONE, RegId::ZERO)]; "contract panics")]
+fn validate_sequence__script_calling_contract__succeeds(contract: Vec<Instruction>) {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let receipts = test_context
+        .start_script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute()
+        .receipts()
+        .to_vec();
+
+    // Then
+    assert!(receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Call { .. })));
+    assert_eq!(Receipt::validate_sequence(&receipts), Ok(()));
+}
@@ -0,0 +1,177 @@
+use alloc::vec;
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::Outputs,
+    Receipt,
+    TransactionBuilder,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::InterpreterParams,
+    prelude::*,
+};
+
+fn checked_script() -> Checked<Script> {
+    let script = vec![
+        op::movi(0x10, 0xaa),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .max_fee_limit(1_000)
+        .add_fee_input()
+        .add_output(Output::change(Address::zeroed(), 0, AssetId::default()))
+        .finalize_checked(Default::default())
+}
+
+/// Executes the script and returns the transition with the storage before it.
+fn execute(
+    checked: Checked<Script>,
+    params: &InterpreterParams,
+) -> (StateTransition<Script>, MemoryStorage) {
+    let storage = MemoryStorage::default();
+    let snapshot = storage.clone();
+    let mut transactor = Transactor::<MemoryInstance, MemoryStorage, Script>::new(
+        MemoryInstance::new(),
+        storage,
+        params.clone(),
+    );
+    let transition = transactor
+        .transact(checked)
+        .to_owned_state_transition()
+        .expect("the script is executed");
+    (transition, snapshot)
+}
+
+#[test]
+fn verify__legitimate_transition__succeeds() {
+    // Given
+    let params = InterpreterParams {
+        gas_price: 1,
+        ..Default::default()
+    };
+    let checked = checked_script();
+    let (transition, snapshot) = execute(checked.clone(), &params);
+
+    // When
+    let result = transition.verify(checked, snapshot, params);
+
+    // Then
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn verify__deserialized_transition__succeeds() {
+    // Given
+    let params = InterpreterParams::default();
+    let checked = checked_script();
+    let (transition, snapshot) = execute(checked.clone(), &params);
+    let json =
+        serde_json::to_string(&transition).expect("the transition is serializable");
+
+    // When
+    let transition: StateTransition<Script> =
+        serde_json::from_str(&json).expect("the transition is deserializable");
+
+    // Then
+    assert_eq!(transition.verify(checked, snapshot, params), Ok(()));
+}
+
+#[test]
+fn verify__tampered_receipt__reports_its_index() {
+    // Given
+    let params = InterpreterParams::default();
+    let checked = checked_script();
+    let (transition, snapshot) = execute(checked.clone(), &params);
+    let (state, tx, mut receipts) = transition.into_inner();
+    let recomputed = receipts[0].clone();
+    let Receipt::Log { ra, .. } = &mut receipts[0] else {
+        panic!("expected the log receipt, got {:?}", receipts[0]);
+    };
+    *ra = 0xbb;
+    let tampered = receipts[0].clone();
+    let transition = StateTransition::new(state, tx, receipts);
+
+    // When
+    let result = transition.verify(checked, snapshot, params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(VerifyMismatch::Receipt {
+            index: 0,
+            claimed: Some(tampered),
+            recomputed: Some(recomputed),
+        })
+    );
+}
+
+#[test]
+fn verify__dropped_receipt__reports_its_index() {
+    // Given
+    let params = InterpreterParams::default();
+    let checked = checked_script();
+    let (transition, snapshot) = execute(checked.clone(), &params);
+    let (state, tx, mut receipts) = transition.into_inner();
+    let dropped = receipts.pop();
+    let index = receipts.len();
+    let transition = StateTransition::new(state, tx, receipts);
+
+    // When
+    let result = transition.verify(checked, snapshot, params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(VerifyMismatch::Receipt {
+            index,
+            claimed: None,
+            recomputed: dropped,
+        })
+    );
+}
+
+#[test]
+fn verify__tampered_output__reports_its_index() {
+    // Given
+    let params = InterpreterParams {
+        gas_price: 1,
+        ..Default::default()
+    };
+    let checked = checked_script();
+    let (transition, snapshot) = execute(checked.clone(), &params);
+    let (state, mut tx, receipts) = transition.into_inner();
+    let index = tx
+        .outputs()
+        .iter()
+        .position(|output| matches!(output, Output::Change { .. }))
+        .expect("the script has the change output");
+    let recomputed = tx.outputs()[index];
+    let Output::Change { amount, .. } = &mut tx.outputs_mut()[index] else {
+        unreachable!("the output is the change output");
+    };
+    *amount = amount.saturating_add(1);
+    let tampered = tx.outputs()[index];
+    let transition = StateTransition::new(state, tx, receipts);
+
+    // When
+    let result = transition.verify(checked, snapshot, params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(VerifyMismatch::Output {
+            index,
+            claimed: Some(tampered),
+            recomputed: Some(recomputed),
+        })
+    );
+}
@@ -0,0 +1,493 @@
+use alloc::{
+    borrow::Cow,
+    string::ToString,
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::{
+    BlobBody,
+    BlobIdExt,
+    ConsensusParameters,
+    TxParameters,
+};
+use fuel_types::{
+    canonical::Serialize,
+    BlobId,
+    BlockHeight,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::InterpreterParams,
+    prelude::*,
+    script_with_data_offset,
+    storage::{
+        BlobData,
+        ContractsAssets,
+        ContractsAssetsStorage,
+        ContractsRawCode,
+        ContractsState,
+        ContractsStateData,
+        MemoryStorageError,
+        UploadedBytecodes,
+    },
+};
+
+/// The table whose accesses fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Table {
+    Code,
+    State,
+    Blob,
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+enum FailingStorageError {
+    #[display(fmt = "The storage is unavailable")]
+    Unavailable,
+    #[display(fmt = "{_0}")]
+    Memory(MemoryStorageError),
+}
+
+impl From<MemoryStorageError> for FailingStorageError {
+    fn from(e: MemoryStorageError) -> Self {
+        Self::Memory(e)
+    }
+}
+
+impl From<FailingStorageError> for RuntimeError<FailingStorageError> {
+    fn from(e: FailingStorageError) -> Self {
+        RuntimeError::Storage(e.into())
+    }
+}
+
+impl From<FailingStorageError> for InterpreterError<FailingStorageError> {
+    fn from(e: FailingStorageError) -> Self {
+        InterpreterError::Storage(e.into())
+    }
+}
+
+/// [`MemoryStorage`] failing every access to the `failing` table.
+#[derive(Debug, Default, Clone)]
+struct FailingStorage {
+    storage: MemoryStorage,
+    failing: Option<Table>,
+}
+
+impl FailingStorage {
+    fn check(&self, table: Table) -> Result<(), FailingStorageError> {
+        if self.failing == Some(table) {
+            return Err(FailingStorageError::Unavailable)
+        }
+        Ok(())
+    }
+}
+
+macro_rules! failing_table {
+    ($table:ty, $kind:expr) => {
+        impl StorageInspect<$table> for FailingStorage {
+            type Error = FailingStorageError;
+
+            fn get(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Cow<'_, <$table as Mappable>::OwnedValue>>, Self::Error>
+            {
+                self.check($kind)?;
+                Ok(StorageInspect::<$table>::get(&self.storage, key)?)
+            }
+
+            fn contains_key(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<bool, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageInspect::<$table>::contains_key(&self.storage, key)?)
+            }
+        }
+
+        impl StorageMutate<$table> for FailingStorage {
+            fn replace(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                value: &<$table as Mappable>::Value,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageMutate::<$table>::replace(
+                    &mut self.storage,
+                    key,
+                    value,
+                )?)
+            }
+
+            fn take(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageMutate::<$table>::take(&mut self.storage, key)?)
+            }
+        }
+    };
+}
+
+macro_rules! failing_bytes_table {
+    ($table:ty, $kind:expr) => {
+        failing_table!($table, $kind);
+
+        impl StorageSize<$table> for FailingStorage {
+            fn size_of_value(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<usize>, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageSize::<$table>::size_of_value(&self.storage, key)?)
+            }
+        }
+
+        impl StorageRead<$table> for FailingStorage {
+            fn read(
+                &self,
+                key: &<$table as Mappable>::Key,
+                offset: usize,
+                buf: &mut [u8],
+            ) -> Result<Option<usize>, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageRead::<$table>::read(
+                    &self.storage,
+                    key,
+                    offset,
+                    buf,
+                )?)
+            }
+
+            fn read_alloc(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageRead::<$table>::read_alloc(&self.storage, key)?)
+            }
+        }
+
+        impl StorageWrite<$table> for FailingStorage {
+            fn write_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<usize, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageWrite::<$table>::write_bytes(
+                    &mut self.storage,
+                    key,
+                    buf,
+                )?)
+            }
+
+            fn replace_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+                self.check($kind)?;
+                Ok(StorageWrite::<$table>::replace_bytes(
+                    &mut self.storage,
+                    key,
+                    buf,
+                )?)
+            }
+
+            fn take_bytes(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                self.check($kind)?;
+                Ok(StorageWrite::<$table>::take_bytes(&mut self.storage, key)?)
+            }
+        }
+    };
+}
+
+failing_bytes_table!(ContractsRawCode, Table::Code);
+failing_bytes_table!(ContractsState, Table::State);
+failing_bytes_table!(BlobData, Table::Blob);
+failing_table!(UploadedBytecodes, Table::Other);
+failing_table!(ContractsAssets, Table::Other);
+
+impl fuel_storage::StorageBatchMutate<ContractsState> for FailingStorage {}
+
+impl ContractsAssetsStorage for FailingStorage {}
+
+impl InterpreterStorage for FailingStorage {
+    type DataError = FailingStorageError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        Ok(self.storage.block_height()?)
+    }
+
+    fn consensus_parameters_version(&self) -> Result<u32, Self::DataError> {
+        Ok(self.storage.consensus_parameters_version()?)
+    }
+
+    fn state_transition_version(&self) -> Result<u32, Self::DataError> {
+        Ok(self.storage.state_transition_version()?)
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        Ok(self.storage.timestamp(height)?)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        Ok(self.storage.block_hash(block_height)?)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        Ok(self.storage.coinbase()?)
+    }
+
+    fn set_consensus_parameters(
+        &mut self,
+        version: u32,
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<Option<ConsensusParameters>, Self::DataError> {
+        Ok(self
+            .storage
+            .set_consensus_parameters(version, consensus_parameters)?)
+    }
+
+    fn set_state_transition_bytecode(
+        &mut self,
+        version: u32,
+        hash: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        Ok(self.storage.set_state_transition_bytecode(version, hash)?)
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<'_, ContractsStateData>>>, Self::DataError> {
+        self.check(Table::State)?;
+        Ok(self.storage.contract_state_range(id, start_key, range)?)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        self.check(Table::State)?;
+        Ok(self
+            .storage
+            .contract_state_insert_range(contract, start_key, values)?)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        self.check(Table::State)?;
+        Ok(self
+            .storage
+            .contract_state_remove_range(contract, start_key, range)?)
+    }
+}
+
+type FailingTransactor = Transactor<MemoryInstance, FailingStorage, Script>;
+
+fn transactor() -> FailingTransactor {
+    Transactor::new(
+        MemoryInstance::new(),
+        FailingStorage::default(),
+        InterpreterParams::default(),
+    )
+}
+
+/// Routine `0` reads the slot with `SRW`, routine `1` writes it with `SWW`.
+fn contract_program() -> Vec<u8> {
+    vec![
+        op::addi(0x10, RegId::FP, CallFrame::a_offset() as Immediate12),
+        op::lw(0x10, 0x10, 0),
+        op::addi(0x11, RegId::FP, CallFrame::b_offset() as Immediate12),
+        op::lw(0x11, 0x11, 0),
+        op::jnei(0x10, RegId::ZERO, 7),
+        op::srw(0x20, 0x21, 0x11),
+        op::ret(RegId::ONE),
+        op::sww(0x11, 0x21, RegId::ONE),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn create(program: Vec<u8>) -> (Checked<Create>, ContractId) {
+    let salt = Salt::zeroed();
+    let contract = Contract::from(program.as_slice());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let create = TransactionBuilder::create(program.into(), salt, vec![])
+        .add_fee_input()
+        .add_contract_created()
+        .finalize_checked(Default::default());
+    (create, contract_id)
+}
+
+fn call(contract_id: ContractId, routine: Word, key: Bytes32) -> Checked<Script> {
+    let (script, data_offset) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        TxParameters::DEFAULT.tx_offset()
+    );
+    let key_offset = data_offset as usize + Call::LEN;
+
+    let mut script_data = Call::new(contract_id, routine, key_offset as Word).to_bytes();
+    script_data.extend(key.as_ref());
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default())
+}
+
+fn storage_failure(
+    error: InterpreterError<FailingStorageError>,
+) -> StorageFailure<FailingStorageError> {
+    match error {
+        InterpreterError::Storage(failure) => failure,
+        error => panic!("expected the storage error, got {error:?}"),
+    }
+}
+
+#[test_case::test_case(0, StorageOp::ContractStateRead; "srw")]
+#[test_case::test_case(1, StorageOp::ContractStateWrite; "sww")]
+fn state_access__failing_storage__reports_the_contract_and_the_key(
+    routine: Word,
+    op: StorageOp,
+) {
+    // Given
+    let mut transactor = transactor();
+    let (create, contract_id) = create(contract_program());
+    transactor
+        .deploy(create)
+        .expect("failed to deploy the contract");
+    transactor.as_mut().failing = Some(Table::State);
+    let key = Bytes32::new([0xaa; 32]);
+
+    // When
+    let error = transactor
+        .transact(call(contract_id, routine, key))
+        .error()
+        .cloned()
+        .expect("the storage access fails");
+
+    // Then
+    let context = StorageErrorContext::new(op).contract(contract_id).key(key);
+    assert_eq!(
+        storage_failure(error.clone()),
+        StorageFailure::with_context(FailingStorageError::Unavailable, context)
+    );
+    assert_eq!(
+        error.to_string(),
+        format!("Storage error: The storage is unavailable {context}")
+    );
+}
+
+#[test]
+fn deploy__failing_storage__reports_the_contract() {
+    // Given
+    let mut transactor = transactor();
+    transactor.as_mut().failing = Some(Table::Code);
+    let (create, contract_id) = create(contract_program());
+
+    // When
+    let error = transactor.deploy(create).expect_err("the deployment fails");
+
+    // Then
+    assert_eq!(
+        storage_failure(error),
+        StorageFailure::with_context(
+            FailingStorageError::Unavailable,
+            StorageErrorContext::new(StorageOp::ContractDeploy).contract(contract_id),
+        )
+    );
+}
+
+#[test]
+fn blob__failing_storage__reports_the_blob_id() {
+    // Given
+    let mut transactor = transactor();
+    transactor.as_mut().failing = Some(Table::Blob);
+    let data = vec![0x12; 32];
+    let blob_id = BlobId::compute(&data);
+    let blob = TransactionBuilder::blob(BlobBody {
+        id: blob_id,
+        witness_index: 0,
+    })
+    .add_witness(data.into())
+    .add_fee_input()
+    .finalize_checked_basic(Default::default());
+
+    // When
+    let error = transactor.blob(blob).expect_err("the blob upload fails");
+
+    // Then
+    let context =
+        StorageErrorContext::new(StorageOp::BlobWrite).key(Bytes32::new(*blob_id));
+    assert_eq!(
+        storage_failure(error.clone()),
+        StorageFailure::with_context(FailingStorageError::Unavailable, context)
+    );
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Storage error: The storage is unavailable while writing the blob at the \
+             key {blob_id}"
+        )
+    );
+}
+
+#[test]
+fn runtime_error__storage_without_context__displays_the_error() {
+    // Given
+    let error = RuntimeError::Storage(FailingStorageError::Unavailable.into());
+
+    // When
+    let message = error.to_string();
+
+    // Then
+    assert_eq!(message, "Unrecoverable storage error: Unavailable");
+}
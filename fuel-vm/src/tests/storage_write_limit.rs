@@ -0,0 +1,597 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    GTFArgs,
+    Instruction,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    field::Outputs,
+    policies::Policies,
+    BlobBody,
+    BlobIdExt,
+    UploadSubsection,
+};
+use fuel_types::{
+    canonical::Serialize,
+    BlobId,
+};
+
+use crate::{
+    checked_transaction::{
+        Checked,
+        IntoChecked,
+    },
+    consts::WORD_SIZE,
+    interpreter::InterpreterParams,
+    prelude::*,
+    storage::{
+        BlobData,
+        ContractsRawCode,
+    },
+};
+
+/// `CALL` credits the balance of the called contract, even with zero coins.
+const CALL_WRITTEN: u64 = WORD_SIZE as u64;
+
+/// The limit used by the tests, which is two storage slots written by the called
+/// contract.
+const LIMIT: u64 = 2 * Bytes32::LEN as u64 + CALL_WRITTEN;
+
+fn transactor() -> Transactor<MemoryInstance, MemoryStorage, Script> {
+    Transactor::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            max_storage_write_bytes: Some(LIMIT),
+            ..Default::default()
+        },
+    )
+}
+
+fn create(program: Vec<Instruction>, storage_slots: Vec<StorageSlot>) -> Checked<Create> {
+    TransactionBuilder::create(
+        program.into_iter().collect::<Vec<u8>>().into(),
+        Salt::zeroed(),
+        storage_slots,
+    )
+    .add_fee_input()
+    .add_contract_created()
+    .finalize_checked(Default::default())
+}
+
+/// Deploys the contracts without the limit, so only the writes of the contracts
+/// count.
+fn deploy<const N: usize>(
+    programs: [Vec<Instruction>; N],
+) -> (MemoryStorage, [ContractId; N]) {
+    let mut transactor = Transactor::<_, _, Script>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::default(),
+    );
+    let contract_ids = programs.map(|program| {
+        let contract = Contract::from(program.iter().copied().collect::<Vec<u8>>());
+        transactor
+            .deploy(create(program, vec![]))
+            .expect("Failed to deploy the contract");
+        contract.id(
+            &Salt::zeroed(),
+            &contract.root(),
+            &Contract::default_state_root(),
+        )
+    });
+    (
+        AsRef::<MemoryStorage>::as_ref(&transactor).clone(),
+        contract_ids,
+    )
+}
+
+/// The transactor with the limit over the `storage`.
+fn transactor_with(
+    storage: MemoryStorage,
+) -> Transactor<MemoryInstance, MemoryStorage, Script> {
+    let mut transactor = transactor();
+    *transactor.as_mut() = storage;
+    transactor
+}
+
+/// Writes `slots` storage slots with `SWW` at the different keys.
+fn sww_contract(slots: u16) -> Vec<Instruction> {
+    let mut program = vec![op::movi(0x10, Bytes32::LEN as u32), op::aloc(0x10)];
+    for _ in 0..slots {
+        program.extend([
+            op::sww(RegId::HP, 0x11, RegId::ONE),
+            op::lw(0x12, RegId::HP, 0),
+            op::addi(0x12, 0x12, 1),
+            op::sw(RegId::HP, 0x12, 0),
+        ]);
+    }
+    program.push(op::ret(RegId::ONE));
+    program
+}
+
+/// Writes `slots` storage slots with one `SWWQ`.
+fn swwq_contract(slots: u16) -> Vec<Instruction> {
+    vec![
+        op::movi(0x10, Bytes32::LEN as u32 * u32::from(slots)),
+        op::aloc(0x10),
+        op::movi(0x11, slots.into()),
+        op::swwq(RegId::HP, 0x12, RegId::HP, 0x11),
+        op::ret(RegId::ONE),
+    ]
+}
+
+/// Clears `slots` storage slots with one `SCWQ`.
+fn scwq_contract(slots: u16) -> Vec<Instruction> {
+    vec![
+        op::movi(0x10, Bytes32::LEN as u32),
+        op::aloc(0x10),
+        op::movi(0x11, slots.into()),
+        op::scwq(RegId::HP, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ]
+}
+
+/// Mints `times` coins one by one, each updating the balance of the contract.
+fn mint_contract(times: u16) -> Vec<Instruction> {
+    let mut program = vec![op::mint(RegId::ONE, RegId::ZERO); times.into()];
+    program.push(op::ret(RegId::ONE));
+    program
+}
+
+fn call(contract_id: ContractId) -> Checked<Script> {
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    script_with_contract(script, Call::new(contract_id, 0, 0).to_bytes(), contract_id)
+}
+
+/// Transfers one base asset coin to the contract `times` times from the script.
+fn transfer(contract_id: ContractId, times: u16) -> Checked<Script> {
+    let mut script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, ContractId::LEN as u16),
+    ];
+    script.extend(vec![op::tr(0x10, RegId::ONE, 0x11); times.into()]);
+    script.push(op::ret(RegId::ONE));
+    let mut script_data = contract_id.to_vec();
+    script_data.extend(ConsensusParameters::default().base_asset_id().as_ref());
+    script_with_contract(script, script_data, contract_id)
+}
+
+fn script_with_contract(
+    script: Vec<Instruction>,
+    script_data: Vec<u8>,
+    contract_id: ContractId,
+) -> Checked<Script> {
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_checked(Default::default())
+}
+
+fn transact(
+    transactor: &mut Transactor<MemoryInstance, MemoryStorage, Script>,
+    contract_id: ContractId,
+) -> Vec<Receipt> {
+    transact_script(transactor, call(contract_id))
+}
+
+fn transact_script(
+    transactor: &mut Transactor<MemoryInstance, MemoryStorage, Script>,
+    script: Checked<Script>,
+) -> Vec<Receipt> {
+    transactor
+        .transact(script)
+        .receipts()
+        .expect("The transaction is executed")
+        .to_vec()
+}
+
+fn panic_reason(receipts: &[Receipt]) -> Option<PanicReason> {
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    })
+}
+
+#[test_case::test_case(sww_contract; "sww")]
+#[test_case::test_case(swwq_contract; "swwq")]
+#[test_case::test_case(scwq_contract; "scwq")]
+fn state_write__within_the_limit__succeeds(program: fn(u16) -> Vec<Instruction>) {
+    // Given
+    let (storage, [contract_id]) = deploy([program(2)]);
+    let mut transactor = transactor_with(storage);
+
+    // When
+    let receipts = transact(&mut transactor, contract_id);
+
+    // Then
+    assert_eq!(panic_reason(&receipts), None);
+    assert_eq!(transactor.interpreter().storage_bytes_written(), LIMIT);
+}
+
+#[test_case::test_case(sww_contract; "sww")]
+#[test_case::test_case(swwq_contract; "swwq")]
+#[test_case::test_case(scwq_contract; "scwq")]
+fn state_write__over_the_limit__panics(program: fn(u16) -> Vec<Instruction>) {
+    // Given
+    let (storage, [contract_id]) = deploy([program(3)]);
+    let mut transactor = transactor_with(storage);
+
+    // When
+    let receipts = transact(&mut transactor, contract_id);
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StorageWriteLimitExceeded)
+    );
+    assert!(
+        transactor.interpreter().storage_bytes_written() <= LIMIT,
+        "The write over the limit isn't counted"
+    );
+}
+
+#[test]
+fn state_write__after_the_reverted_transaction__is_counted_from_zero() {
+    // Given
+    let (storage, [over_limit, within_limit]) =
+        deploy([sww_contract(3), sww_contract(2)]);
+    let mut transactor = transactor_with(storage);
+    let receipts = transact(&mut transactor, over_limit);
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StorageWriteLimitExceeded)
+    );
+
+    // When
+    let receipts = transact(&mut transactor, within_limit);
+
+    // Then
+    assert_eq!(panic_reason(&receipts), None);
+    assert_eq!(transactor.interpreter().storage_bytes_written(), LIMIT);
+}
+
+#[test]
+fn mint__within_the_limit__succeeds() {
+    // Given
+    let mints = ((LIMIT - CALL_WRITTEN) / WORD_SIZE as u64) as u16;
+    let (storage, [contract_id]) = deploy([mint_contract(mints)]);
+    let mut transactor = transactor_with(storage);
+
+    // When
+    let receipts = transact(&mut transactor, contract_id);
+
+    // Then
+    assert_eq!(panic_reason(&receipts), None);
+    assert_eq!(transactor.interpreter().storage_bytes_written(), LIMIT);
+}
+
+#[test]
+fn mint__over_the_limit__panics() {
+    // Given
+    let mints = ((LIMIT - CALL_WRITTEN) / WORD_SIZE as u64) as u16 + 1;
+    let (storage, [contract_id]) = deploy([mint_contract(mints)]);
+    let mut transactor = transactor_with(storage);
+
+    // When
+    let receipts = transact(&mut transactor, contract_id);
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StorageWriteLimitExceeded)
+    );
+    assert_eq!(transactor.interpreter().storage_bytes_written(), LIMIT);
+}
+
+#[test]
+fn transfer__within_the_limit__succeeds() {
+    // Given
+    let transfers = (LIMIT / WORD_SIZE as u64) as u16;
+    let (storage, [contract_id]) = deploy([vec![op::ret(RegId::ONE)]]);
+    let mut transactor = transactor_with(storage);
+
+    // When
+    let receipts = transact_script(&mut transactor, transfer(contract_id, transfers));
+
+    // Then
+    assert_eq!(panic_reason(&receipts), None);
+    assert_eq!(transactor.interpreter().storage_bytes_written(), LIMIT);
+}
+
+#[test]
+fn transfer__over_the_limit__panics() {
+    // Given
+    let transfers = (LIMIT / WORD_SIZE as u64) as u16 + 1;
+    let (storage, [contract_id]) = deploy([vec![op::ret(RegId::ONE)]]);
+    let mut transactor = transactor_with(storage);
+
+    // When
+    let receipts = transact_script(&mut transactor, transfer(contract_id, transfers));
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StorageWriteLimitExceeded)
+    );
+    assert_eq!(transactor.interpreter().storage_bytes_written(), LIMIT);
+}
+
+#[test]
+fn deploy__over_the_limit__fails() {
+    // Given
+    let mut transactor = transactor();
+    let program = vec![op::ret(RegId::ONE)];
+    let storage_slots = vec![
+        StorageSlot::default(),
+        StorageSlot::new([1; 32].into(), Default::default()),
+        StorageSlot::new([2; 32].into(), Default::default()),
+    ];
+
+    // When
+    let error = transactor
+        .deploy(create(program, storage_slots))
+        .expect_err("The deployment is over the limit");
+
+    // Then
+    assert_eq!(
+        error,
+        InterpreterError::Panic(PanicReason::StorageWriteLimitExceeded)
+    );
+}
+
+#[test]
+fn deploy__within_the_limit__succeeds() {
+    // Given
+    let mut transactor = transactor();
+    let program = vec![op::ret(RegId::ONE)];
+    let storage_slots = vec![StorageSlot::default()];
+
+    // When
+    let result = transactor.deploy(create(program, storage_slots));
+
+    // Then
+    assert!(result.is_ok());
+    assert_eq!(
+        transactor.interpreter().storage_bytes_written(),
+        Instruction::SIZE as u64 + Bytes32::LEN as u64
+    );
+}
+
+fn blob(data: Vec<u8>) -> Checked<Blob> {
+    TransactionBuilder::blob(BlobBody {
+        id: BlobId::compute(&data),
+        witness_index: 0,
+    })
+    .add_witness(data.into())
+    .add_fee_input()
+    .finalize_checked_basic(Default::default())
+}
+
+#[test]
+fn blob__over_the_limit__fails() {
+    // Given
+    let mut transactor = transactor();
+    let data = vec![0x12; LIMIT as usize + 1];
+    let blob_id = BlobId::compute(&data);
+
+    // When
+    let error = transactor
+        .blob(blob(data))
+        .expect_err("The blob is over the limit");
+
+    // Then
+    assert_eq!(
+        error,
+        InterpreterError::Panic(PanicReason::StorageWriteLimitExceeded)
+    );
+    let storage: &MemoryStorage = transactor.as_ref();
+    assert!(!storage
+        .storage_as_ref::<BlobData>()
+        .contains_key(&blob_id)
+        .expect("Infallible"));
+}
+
+#[test]
+fn blob__within_the_limit__succeeds() {
+    // Given
+    let mut transactor = transactor();
+
+    // When
+    let result = transactor.blob(blob(vec![0x12; LIMIT as usize]));
+
+    // Then
+    assert!(result.is_ok());
+}
+
+fn upload(subsection: UploadSubsection) -> Checked<Upload> {
+    let predicate = vec![op::ret(RegId::ONE)].into_iter().collect::<Vec<u8>>();
+    let owner = Input::predicate_owner(&predicate);
+    Transaction::upload_from_subsection(
+        subsection,
+        Policies::new().with_max_fee(0),
+        vec![Input::coin_predicate(
+            Default::default(),
+            owner,
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        )],
+        vec![],
+        vec![],
+    )
+    .into_checked_basic(Default::default(), &Default::default())
+    .expect("Failed to check the upload")
+}
+
+#[test]
+fn upload__subsections_within_the_limit__succeed() {
+    // Given
+    let mut transactor = transactor();
+    let bytecode = vec![0x12; 2 * LIMIT as usize];
+    let subsections = UploadSubsection::split_bytecode(&bytecode, LIMIT as usize)
+        .expect("Failed to split the bytecode");
+
+    // When
+    let results = subsections
+        .into_iter()
+        .map(|subsection| transactor.upload(upload(subsection)))
+        .collect::<Vec<_>>();
+
+    // Then
+    // Every subsection is uploaded by its own transaction, so only the uploaded
+    // subsection counts towards the limit.
+    assert!(results.iter().all(Result::is_ok));
+}
+
+#[test]
+fn upload__subsection_over_the_limit__fails() {
+    // Given
+    let mut transactor = transactor();
+    let bytecode = vec![0x12; LIMIT as usize + 1];
+    let subsections = UploadSubsection::split_bytecode(&bytecode, bytecode.len())
+        .expect("Failed to split the bytecode");
+
+    // When
+    let error = transactor
+        .upload(upload(subsections[0].clone()))
+        .expect_err("The subsection is over the limit");
+
+    // Then
+    assert_eq!(
+        error,
+        InterpreterError::Panic(PanicReason::StorageWriteLimitExceeded)
+    );
+}
+
+#[test]
+fn transact_create__over_the_limit__panics_without_deploying() {
+    // Given
+    let mut transactor = Transactor::<_, _, Create>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            max_storage_write_bytes: Some(LIMIT),
+            ..Default::default()
+        },
+    );
+    let storage_slots = (0..3u8)
+        .map(|i| StorageSlot::new([i; 32].into(), Default::default()))
+        .collect::<Vec<_>>();
+    let create = create(vec![op::ret(RegId::ONE)], storage_slots);
+    let contract_id = create
+        .transaction()
+        .outputs()
+        .iter()
+        .find_map(|output| output.contract_id().copied())
+        .expect("Expected `ContractCreated` output");
+
+    // When
+    let receipts = transactor
+        .transact(create)
+        .result()
+        .expect("The transaction is executed")
+        .receipts()
+        .to_vec();
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StorageWriteLimitExceeded)
+    );
+    assert!(transactor.is_reverted());
+    let storage: &MemoryStorage = transactor.as_ref();
+    assert!(!storage
+        .storage_as_ref::<ContractsRawCode>()
+        .contains_key(&contract_id)
+        .expect("Infallible"));
+}
+
+#[test]
+fn transact_blob__over_the_limit__panics_without_storing() {
+    // Given
+    let mut transactor = Transactor::<_, _, Blob>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            max_storage_write_bytes: Some(LIMIT),
+            ..Default::default()
+        },
+    );
+    let data = vec![0x12; LIMIT as usize + 1];
+    let blob_id = BlobId::compute(&data);
+
+    // When
+    let receipts = transactor
+        .transact(blob(data))
+        .result()
+        .expect("The transaction is executed")
+        .receipts()
+        .to_vec();
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StorageWriteLimitExceeded)
+    );
+    assert!(transactor.is_reverted());
+    let storage: &MemoryStorage = transactor.as_ref();
+    assert!(!storage
+        .storage_as_ref::<BlobData>()
+        .contains_key(&blob_id)
+        .expect("Infallible"));
+}
+
+#[test]
+fn transact_upload__over_the_limit__panics() {
+    // Given
+    let mut transactor = Transactor::<_, _, Upload>::new(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams {
+            max_storage_write_bytes: Some(LIMIT),
+            ..Default::default()
+        },
+    );
+    let bytecode = vec![0x12; LIMIT as usize + 1];
+    let subsections = UploadSubsection::split_bytecode(&bytecode, bytecode.len())
+        .expect("Failed to split the bytecode");
+
+    // When
+    let receipts = transactor
+        .transact(upload(subsections[0].clone()))
+        .result()
+        .expect("The transaction is executed")
+        .receipts()
+        .to_vec();
+
+    // Then
+    assert_eq!(
+        panic_reason(&receipts),
+        Some(PanicReason::StorageWriteLimitExceeded)
+    );
+    assert!(transactor.is_reverted());
+}
@@ -0,0 +1,124 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    ContractIdExt,
+    Receipt,
+};
+use fuel_types::{
+    Bytes32,
+    ContractId,
+    Immediate12,
+    Word,
+};
+
+use crate::{
+    call::CallFrame,
+    util::{
+        supply::SupplyTracker,
+        test_helpers::{
+            assert_mint_burn_consistency,
+            check_mint_burn_consistency,
+            TestBuilder,
+        },
+    },
+};
+
+/// Mints `$a` and then burns `$b` of the call of the asset with the zero sub id.
+fn mint_and_burn_contract(test_context: &mut TestBuilder) -> ContractId {
+    let code = vec![
+        op::movi(0x10, Bytes32::LEN as u32),
+        op::aloc(0x10),
+        op::addi(0x10, RegId::FP, CallFrame::a_offset() as Immediate12),
+        op::lw(0x11, 0x10, 0),
+        op::lw(0x12, 0x10, 1),
+        op::mint(0x11, RegId::HP),
+        op::burn(0x12, RegId::HP),
+        op::ret(RegId::ONE),
+    ];
+    test_context.setup_contract(code, None, None).contract_id
+}
+
+fn calls(
+    test_context: &mut TestBuilder,
+    calls: &[(ContractId, Word, Word)],
+) -> Vec<Receipt> {
+    calls
+        .iter()
+        .flat_map(|(contract_id, mint, burn)| {
+            test_context.script_gas_limit(1_000_000).call_contract(
+                *contract_id,
+                *mint,
+                *burn,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn supply_tracker__mint_and_burn_across_two_contracts__matches_storage() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let a = mint_and_burn_contract(&mut test_context);
+    let b = mint_and_burn_contract(&mut test_context);
+    let receipts = calls(
+        &mut test_context,
+        &[(a, 100, 0), (b, 50, 0), (a, 0, 30), (b, 10, 5), (a, 5, 0)],
+    );
+
+    // When
+    let tracker = SupplyTracker::from_receipts(&receipts);
+
+    // Then
+    let asset_a = a.asset_id(&Bytes32::zeroed());
+    let asset_b = b.asset_id(&Bytes32::zeroed());
+    assert_eq!(tracker.supply(&asset_a), 75);
+    assert_eq!(tracker.supply(&asset_b), 55);
+    let supply_a = tracker.get(&asset_a).expect("the asset is minted");
+    assert_eq!(supply_a.contract_id, a);
+    assert_eq!((supply_a.minted, supply_a.burned), (105, 30));
+    assert_eq!(tracker.iter().count(), 2);
+    assert_eq!(test_context.get_contract_balance(&a, &asset_a), 75);
+    assert_eq!(test_context.get_contract_balance(&b, &asset_b), 55);
+    assert_mint_burn_consistency(&receipts, test_context.get_storage());
+}
+
+#[test]
+fn supply_tracker__reverted_script__is_skipped() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let a = mint_and_burn_contract(&mut test_context);
+    let receipts = calls(&mut test_context, &[(a, 10, 0), (a, 5, 1_000)]);
+    assert!(receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Mint { val: 5, .. })));
+
+    // When
+    let tracker = SupplyTracker::from_receipts(&receipts);
+
+    // Then
+    assert_eq!(tracker.supply(&a.asset_id(&Bytes32::zeroed())), 10);
+    assert_mint_burn_consistency(&receipts, test_context.get_storage());
+}
+
+#[test]
+fn check_mint_burn_consistency__missing_receipts__fails() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let a = mint_and_burn_contract(&mut test_context);
+    calls(&mut test_context, &[(a, 10, 0)]);
+    let receipts = calls(&mut test_context, &[(a, 0, 4)]);
+
+    // When
+    let result = check_mint_burn_consistency(&receipts, test_context.get_storage());
+
+    // Then
+    let err = result.expect_err("the mint is missing from the receipts");
+    assert!(err.to_string().contains("hold 6"), "{err}");
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "tracing")]
+
+use alloc::{
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use std::sync::Mutex;
+
+use fuel_asm::{
+    op,
+    GTFArgs,
+    RegId,
+};
+use fuel_types::canonical::Serialize;
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::prelude::*;
+
+/// Collects the formatted events in memory.
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().expect("Poisoned lock").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Logs {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Runs `f` and returns the events emitted during its execution.
+fn capture_events(f: impl FnOnce()) -> String {
+    let logs = Logs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_writer(logs.clone())
+        .finish();
+    tracing::subscriber::with_default(subscriber, f);
+
+    let bytes = logs.0.lock().expect("Poisoned lock").clone();
+    String::from_utf8(bytes).expect("Events are valid UTF-8")
+}
+
+#[test]
+fn tracing__reports_contract_call_transaction() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let gas_limit = 1_000_000;
+    let mut contract_id = ContractId::zeroed();
+    let mut tx_id = TxId::zeroed();
+
+    // When
+    let events = capture_events(|| {
+        contract_id = test_context
+            .setup_contract(vec![op::ret(RegId::ONE)], None, None)
+            .contract_id;
+        let script = vec![
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ];
+        let script_data = Call::new(contract_id, 0, 0).to_bytes();
+        let tx = test_context
+            .start_script(script, script_data)
+            .script_gas_limit(gas_limit)
+            .contract_input(contract_id)
+            .fee_input()
+            .contract_output(&contract_id)
+            .build();
+        tx_id = tx.id();
+        test_context
+            .execute_tx(tx)
+            .expect("Expected successful execution");
+    });
+
+    // Then
+    assert!(events.contains("contract deployed"), "{events}");
+    assert!(
+        events.contains(&format!("contract_id={contract_id}")),
+        "{events}"
+    );
+    assert!(events.contains(&format!("tx_id={tx_id}")), "{events}");
+    assert!(events.contains("transaction started"), "{events}");
+    assert!(
+        events.contains(&format!("gas_limit={gas_limit}")),
+        "{events}"
+    );
+    assert!(events.contains(" call "), "{events}");
+    assert!(events.contains("forward_gas="), "{events}");
+    assert!(events.contains("transaction finished"), "{events}");
+    assert!(!events.contains("panic"), "{events}");
+}
+
+#[test]
+fn tracing__reports_panicking_transaction() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+
+    // When
+    let events = capture_events(|| {
+        let result = test_context
+            .start_script(
+                vec![op::div(0x10, RegId::ONE, RegId::ZERO), op::ret(RegId::ONE)],
+                vec![],
+            )
+            .script_gas_limit(1_000_000)
+            .fee_input()
+            .execute();
+        assert!(result
+            .receipts()
+            .iter()
+            .any(|receipt| matches!(receipt, Receipt::Panic { .. })));
+    });
+
+    // Then
+    assert!(events.contains("transaction started"), "{events}");
+    assert!(events.contains("panic"), "{events}");
+    assert!(events.contains("reason=ArithmeticError"), "{events}");
+    assert!(events.contains("pc="), "{events}");
+    assert!(
+        events.contains(&format!("contract_id={}", ContractId::zeroed())),
+        "{events}"
+    );
+    assert!(events.contains("transaction finished"), "{events}");
+}
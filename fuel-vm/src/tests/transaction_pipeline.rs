@@ -0,0 +1,285 @@
+//! Every variant of the [`Transaction`] goes through every public stage of the
+//! pipeline: `estimate_predicates`, `into_checked`, `check_predicates`, `into_ready`
+//! and the corresponding entry point of the [`Interpreter`].
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    checked_transaction::{
+        CheckError,
+        CheckPredicateParams,
+        CheckPredicates,
+        Ready,
+    },
+    interpreter::{
+        CheckedMetadata,
+        InterpreterParams,
+    },
+    prelude::*,
+    storage::{
+        predicate::EmptyStorage,
+        BlobData,
+        ContractsRawCode,
+        UploadedBytecode,
+        UploadedBytecodes,
+    },
+    tests::test_helpers::assert_success,
+};
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::{
+        ChargeableBody,
+        Outputs,
+    },
+    policies::Policies,
+};
+use fuel_types::BlockHeight;
+use test_case::test_case;
+
+const AMOUNT: u64 = 1000;
+const GAS_PRICE: Word = 0;
+const BLOCK_HEIGHT: BlockHeight = BlockHeight::new(0);
+
+fn predicate() -> Vec<u8> {
+    vec![op::ret(RegId::ONE)].into_iter().collect()
+}
+
+fn owner() -> Address {
+    Input::predicate_owner(predicate())
+}
+
+fn input() -> Input {
+    Input::coin_predicate(
+        Default::default(),
+        owner(),
+        AMOUNT,
+        AssetId::BASE,
+        Default::default(),
+        Default::default(),
+        predicate(),
+        vec![],
+    )
+}
+
+fn output() -> Output {
+    Output::change(owner(), 0, AssetId::BASE)
+}
+
+fn policies() -> Policies {
+    Policies::new().with_max_fee(AMOUNT)
+}
+
+/// The owner of the predicate is privileged, so it can upgrade the network.
+fn consensus_params() -> ConsensusParameters {
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_privileged_address(owner());
+    consensus_params
+}
+
+fn script() -> Script {
+    TransactionBuilder::script(vec![op::ret(RegId::ONE)].into_iter().collect(), vec![])
+        .script_gas_limit(100_000)
+        .max_fee_limit(AMOUNT)
+        .add_input(input())
+        .add_output(output())
+        .finalize()
+}
+
+fn create() -> Create {
+    let program: Witness = vec![op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>()
+        .into();
+
+    TransactionBuilder::create(program, Salt::zeroed(), vec![])
+        .max_fee_limit(AMOUNT)
+        .add_input(input())
+        .add_output(output())
+        .add_contract_created()
+        .finalize()
+}
+
+fn upgrade() -> Upgrade {
+    Transaction::upgrade_consensus_parameters(
+        &ConsensusParameters::standard(),
+        policies(),
+        vec![input()],
+        vec![output()],
+        vec![],
+    )
+    .expect("Failed to generate the upgrade transaction")
+}
+
+fn upload() -> Upload {
+    let bytecode = vec![123; 1024];
+    let subsection = UploadSubsection::split_bytecode(&bytecode, bytecode.len())
+        .expect("Failed to split the bytecode")
+        .remove(0);
+
+    Transaction::upload_from_subsection(
+        subsection,
+        policies(),
+        vec![input()],
+        vec![output()],
+        vec![],
+    )
+}
+
+fn blob() -> Blob {
+    let program: Witness = vec![op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>()
+        .into();
+
+    Transaction::blob(
+        BlobBody {
+            id: BlobId::compute(program.as_ref()),
+            witness_index: 0,
+        },
+        policies(),
+        vec![input()],
+        vec![output()],
+        vec![program],
+    )
+}
+
+/// The `Mint` is created by the block producer for the next block, so its
+/// `tx_pointer` doesn't match the current block height.
+fn mint() -> Mint {
+    Transaction::mint(
+        TxPointer::new(BLOCK_HEIGHT.succ().expect("Valid block height"), 0),
+        Default::default(),
+        Default::default(),
+        AMOUNT,
+        AssetId::BASE,
+        GAS_PRICE,
+    )
+}
+
+fn ready<Tx>(mut tx: Tx) -> Ready<Tx>
+where
+    Tx: ExecutableTransaction + Send + Sync + 'static,
+    <Tx as IntoChecked>::Metadata: CheckedMetadata + Send + Sync,
+{
+    let consensus_params = consensus_params();
+    let predicate_params = CheckPredicateParams::from(&consensus_params);
+
+    tx.estimate_predicates(&predicate_params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Failed to estimate predicates");
+    tx.into_checked(BLOCK_HEIGHT, &consensus_params)
+        .expect("Failed to check the transaction")
+        .check_predicates(&predicate_params, MemoryInstance::new(), &EmptyStorage)
+        .expect("Failed to check predicates")
+        .into_ready(
+            GAS_PRICE,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+            Some(BLOCK_HEIGHT),
+        )
+        .expect("Failed to finalize the transaction")
+}
+
+fn interpreter<Tx>() -> Interpreter<MemoryInstance, MemoryStorage, Tx>
+where
+    Tx: Default,
+{
+    Interpreter::with_storage(
+        MemoryInstance::new(),
+        MemoryStorage::default(),
+        InterpreterParams::new(GAS_PRICE, consensus_params()),
+    )
+}
+
+/// Runs the whole pipeline for the `tx`.
+///
+/// The `match` statement exists to ensure that all variants of `Transaction` are
+/// covered. If a new variant is added, the compiler will emit an error, and the
+/// matrix of the test cases below should be extended as well.
+fn run_pipeline(tx: Transaction) {
+    match tx {
+        Transaction::Script(script) => {
+            let mut client = interpreter::<Script>();
+            let state = client.transact(ready(script)).expect("Failed to transact");
+            assert_success(state.receipts());
+        }
+        Transaction::Create(create) => {
+            let mut client = interpreter::<Create>();
+            let create = client.deploy(ready(create)).expect("Failed to deploy");
+            let contract_id = create
+                .outputs()
+                .iter()
+                .find_map(|output| match output {
+                    Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+                    _ => None,
+                })
+                .expect("Expected `ContractCreated` output");
+            assert!(client
+                .as_ref()
+                .storage::<ContractsRawCode>()
+                .contains_key(&contract_id)
+                .unwrap());
+        }
+        Transaction::Upgrade(upgrade) => {
+            let mut client = interpreter::<Upgrade>();
+            client.upgrade(ready(upgrade)).expect("Failed to upgrade");
+            assert!(client
+                .as_mut()
+                .consensus_parameters_versions_mut()
+                .contains_key(&1));
+        }
+        Transaction::Upload(upload) => {
+            let mut client = interpreter::<Upload>();
+            let root = upload.body().root;
+            client.upload(ready(upload)).expect("Failed to upload");
+            assert!(matches!(
+                client
+                    .as_ref()
+                    .storage::<UploadedBytecodes>()
+                    .get(&root)
+                    .unwrap()
+                    .map(|bytecode| bytecode.into_owned()),
+                Some(UploadedBytecode::Completed(_))
+            ));
+        }
+        Transaction::Blob(blob) => {
+            let mut client = interpreter::<Blob>();
+            let id = blob.body().id;
+            client.blob(ready(blob)).expect("Failed to upload the blob");
+            assert!(client
+                .as_ref()
+                .storage::<BlobData>()
+                .contains_key(&id)
+                .unwrap());
+        }
+        Transaction::Mint(mint) => {
+            // The `Mint` doesn't implement `ExecutableTransaction` and can't be
+            // executed by the `Interpreter`. The only public stage it goes through
+            // is `into_checked`, which rejects it outside of its block.
+            let err = mint
+                .into_checked(BLOCK_HEIGHT, &consensus_params())
+                .expect_err("Expected the `Mint` to be rejected");
+            assert_eq!(
+                err,
+                CheckError::Validity(ValidityError::TransactionMintIncorrectBlockHeight)
+            );
+        }
+    }
+}
+
+static_assertions::assert_not_impl_any!(Mint: ExecutableTransaction, Chargeable);
+
+#[test_case(script().into(); "script")]
+#[test_case(create().into(); "create")]
+#[test_case(upgrade().into(); "upgrade")]
+#[test_case(upload().into(); "upload")]
+#[test_case(blob().into(); "blob")]
+#[test_case(mint().into(); "mint")]
+fn transaction_pipeline__handles_every_variant(tx: Transaction) {
+    run_pipeline(tx)
+}
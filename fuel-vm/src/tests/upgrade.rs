@@ -9,7 +9,10 @@ use crate::{
         InterpreterParams,
     },
     prelude::*,
-    storage::MemoryStorage,
+    storage::{
+        is_history_strictly_increasing,
+        MemoryStorage,
+    },
 };
 use fuel_asm::{
     op,
@@ -255,10 +258,106 @@ mod state_transition {
             result
         );
     }
+
+    #[test]
+    fn upgrade_records_state_transition_history() {
+        let first_hash = [1; 32].into();
+        let second_hash = [2; 32].into();
+        let mut storage = valid_storage(first_hash, vec![]);
+        storage
+            .state_transition_bytecodes_mut()
+            .insert(second_hash, UploadedBytecode::Completed(vec![]));
+        let mut client = Interpreter::<_, _, Upgrade>::with_storage(
+            MemoryInstance::new(),
+            storage,
+            InterpreterParams::default(),
+        );
+
+        // Given
+        let first_tx = valid_transaction(first_hash).test_into_ready();
+        let second_tx = valid_transaction(second_hash).test_into_ready();
+
+        // When
+        client
+            .upgrade(first_tx)
+            .expect("failed to do first upgrade");
+        client
+            .as_mut()
+            .set_state_transition_version(CURRENT_STATE_TRANSITION_VERSION + 1);
+        client
+            .upgrade(second_tx)
+            .expect("failed to do second upgrade");
+
+        // Then
+        let history = client
+            .as_ref()
+            .state_transition_history()
+            .expect("failed to read history")
+            .expect("`MemoryStorage` tracks history");
+        assert_eq!(
+            history,
+            vec![
+                (CURRENT_STATE_TRANSITION_VERSION + 1, first_hash),
+                (CURRENT_STATE_TRANSITION_VERSION + 2, second_hash),
+            ]
+        );
+        assert!(is_history_strictly_increasing(&history));
+    }
+
+    #[test]
+    fn upgrade_records_override_attempt_in_state_transition_history() {
+        let state_transition_hash = [1; 32].into();
+        let mut client = Interpreter::<_, _, Upgrade>::with_storage(
+            MemoryInstance::new(),
+            valid_storage(state_transition_hash, vec![]),
+            InterpreterParams::default(),
+        );
+        let first_tx = valid_transaction(state_transition_hash).test_into_ready();
+        client
+            .upgrade(first_tx)
+            .expect("failed to do first upgrade");
+
+        // Given
+        let second_tx = valid_transaction(state_transition_hash).test_into_ready();
+
+        // When
+        let result = client.upgrade(second_tx).map(|_| ());
+
+        // Then
+        assert_eq!(
+            Err(InterpreterError::Panic(
+                PanicReason::OverridingStateTransactionBytecode
+            )),
+            result
+        );
+        let history = client
+            .as_ref()
+            .state_transition_history()
+            .expect("failed to read history")
+            .expect("`MemoryStorage` tracks history");
+        assert_eq!(history.len(), 2);
+        assert!(!is_history_strictly_increasing(&history));
+    }
 }
 
 mod consensus_parameters {
     use super::*;
+    use crate::checked_transaction::CheckError;
+    use fuel_crypto::SecretKey;
+    use fuel_tx::{
+        field::UpgradePurpose as _,
+        Finalizable,
+        Script,
+        TransactionBuilder,
+        UpgradePurpose,
+        ValidityError,
+    };
+    use fuel_types::canonical::Serialize;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
 
     const CURRENT_CONSENSUS_PARAMETERS_VERSION: u32 = 123;
 
@@ -272,6 +371,12 @@ mod consensus_parameters {
     const AMOUNT: u64 = 1000;
 
     fn valid_transaction() -> Checked<Upgrade> {
+        transaction_with(&ConsensusParameters::standard())
+    }
+
+    fn transaction_with(
+        new_consensus_parameters: &ConsensusParameters,
+    ) -> Checked<Upgrade> {
         let predicate = vec![op::ret(1)].into_iter().collect::<Vec<u8>>();
         let owner = Input::predicate_owner(&predicate);
         let inputs = vec![Input::coin_predicate(
@@ -287,7 +392,7 @@ mod consensus_parameters {
         let outputs = vec![Output::change(owner, 0, AssetId::BASE)];
 
         let upgrade = Transaction::upgrade_consensus_parameters(
-            &ConsensusParameters::standard(),
+            new_consensus_parameters,
             Policies::new().with_max_fee(AMOUNT),
             inputs,
             outputs,
@@ -413,4 +518,141 @@ mod consensus_parameters {
             result
         );
     }
+
+    #[test]
+    fn upgrade_records_consensus_parameters_history() {
+        let mut client = Interpreter::<_, _, Upgrade>::with_storage(
+            MemoryInstance::new(),
+            valid_storage(),
+            InterpreterParams::default(),
+        );
+
+        // Given
+        let first_params = ConsensusParameters::standard();
+        let mut second_params = ConsensusParameters::standard();
+        second_params.set_privileged_address([1; 32].into());
+        let first_tx = transaction_with(&first_params);
+        let second_tx = transaction_with(&second_params).test_into_ready();
+        let UpgradePurpose::ConsensusParameters {
+            checksum: first_checksum,
+            ..
+        } = *first_tx.transaction().upgrade_purpose()
+        else {
+            panic!("expected consensus parameters upgrade");
+        };
+        let first_tx = first_tx.test_into_ready();
+
+        // When
+        client
+            .upgrade(first_tx)
+            .expect("failed to do first upgrade");
+        client
+            .as_mut()
+            .set_consensus_parameters_version(CURRENT_CONSENSUS_PARAMETERS_VERSION + 1);
+        client
+            .upgrade(second_tx)
+            .expect("failed to do second upgrade");
+
+        // Then
+        let history = client
+            .as_ref()
+            .consensus_parameters_history()
+            .expect("failed to read history")
+            .expect("`MemoryStorage` tracks history");
+        assert_eq!(
+            history,
+            vec![
+                (CURRENT_CONSENSUS_PARAMETERS_VERSION + 1, first_checksum),
+                (
+                    CURRENT_CONSENSUS_PARAMETERS_VERSION + 2,
+                    second_params
+                        .checksum()
+                        .expect("failed to compute checksum")
+                ),
+            ]
+        );
+        assert!(is_history_strictly_increasing(&history));
+    }
+
+    fn script_with_data(script_data: Vec<u8>) -> Script {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        TransactionBuilder::script(vec![op::ret(1)].into_iter().collect(), script_data)
+            .max_fee_limit(AMOUNT)
+            .script_gas_limit(100)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                AMOUNT,
+                AssetId::BASE,
+                Default::default(),
+            )
+            .finalize()
+    }
+
+    fn script_of_size_above(max_size: u64) -> Script {
+        let size = script_with_data(vec![]).size() as u64;
+        let padding = (max_size + 1).saturating_sub(size) as usize;
+        script_with_data(vec![0; padding])
+    }
+
+    #[test]
+    fn into_checked_at_version__uses_parameters_active_at_block_height() {
+        // Given
+        let old_params = ConsensusParameters::standard();
+        let mut storage = valid_storage();
+        storage
+            .consensus_parameters_versions_mut()
+            .insert(CURRENT_CONSENSUS_PARAMETERS_VERSION, old_params.clone());
+        let upgrade_height = storage.block_height().expect("infallible");
+        let mut client = Interpreter::<_, _, Upgrade>::with_storage(
+            MemoryInstance::new(),
+            storage,
+            InterpreterParams::default(),
+        );
+        let mut new_tx_params = *old_params.tx_params();
+        new_tx_params.set_max_size(old_params.tx_params().max_size() / 2);
+        let mut new_params = old_params.clone();
+        new_params.set_tx_params(new_tx_params);
+        client
+            .transact(transaction_with(&new_params).test_into_ready())
+            .expect("failed to upgrade");
+        let storage = client.as_ref();
+        let tx = script_of_size_above(new_tx_params.max_size());
+
+        // When
+        let before_activation = tx
+            .clone()
+            .into_checked_at_version(upgrade_height, storage)
+            .map(|_| ());
+        let after_activation = tx
+            .into_checked_at_version(upgrade_height.succ().unwrap(), storage)
+            .map(|_| ());
+
+        // Then
+        assert_eq!(before_activation, Ok(()));
+        assert_eq!(
+            after_activation,
+            Err(CheckError::Validity(
+                ValidityError::TransactionSizeLimitExceeded
+            ))
+        );
+    }
+
+    #[test]
+    fn into_checked_at_version__fails_for_unknown_parameters() {
+        // Given
+        let storage = valid_storage();
+        let tx = script_of_size_above(0);
+
+        // When
+        let result = tx
+            .into_checked_at_version(storage.block_height().unwrap(), &storage)
+            .map(|_| ());
+
+        // Then
+        assert!(matches!(
+            result,
+            Err(CheckError::ConsensusParametersUnavailable(_))
+        ));
+    }
 }
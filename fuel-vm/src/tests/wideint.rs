@@ -41,13 +41,26 @@ fn aloc_bytearray<const S: usize>(reg: u8, v: [u8; S]) -> Vec<Instruction> {
 }
 
 fn make_u128(reg: u8, v: u128) -> Vec<Instruction> {
-    aloc_bytearray(reg, v.to_be_bytes())
+    aloc_bytearray(reg, fuel_types::U128::from(v).into())
 }
 
 fn make_u256(reg: u8, v: U256) -> Vec<Instruction> {
     aloc_bytearray(reg, v.to_be_bytes())
 }
 
+/// Decodes the 128-bit value logged by `LOGD`.
+fn logged_u128(data: &[u8]) -> u128 {
+    fuel_types::U128::try_from(data)
+        .expect("Expected a 128-bit value")
+        .into()
+}
+
+/// Decodes the 256-bit value logged by `LOGD`.
+fn logged_u256(data: &[u8]) -> U256 {
+    let value = fuel_types::U256::try_from(data).expect("Expected a 256-bit value");
+    U256::from_be_bytes(value.into())
+}
+
 #[rstest::rstest]
 fn cmp_u128(
     #[values(0, 1, 2, u64::MAX as u128, (u64::MAX as u128) + 1, u128::MAX)] a: u128,
@@ -391,8 +404,7 @@ fn incr_u128(
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let expected = v + 1;
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, expected);
     } else {
         panic!("Expected logd receipt");
@@ -425,8 +437,7 @@ fn incr_u256(
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
         let expected = v + 1;
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, expected);
     } else {
         panic!("Expected logd receipt");
@@ -511,8 +522,7 @@ fn incr_wrapping_u128() {
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, 0);
     } else {
         panic!("Expected logd receipt");
@@ -543,8 +553,7 @@ fn incr_wrapping_u256() {
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, 0);
     } else {
         panic!("Expected logd receipt");
@@ -625,8 +634,7 @@ fn multiply_overflow_wrapping_u128() {
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, u128::MAX.wrapping_mul(u128::MAX));
     } else {
         panic!("Expected logd receipt");
@@ -657,8 +665,7 @@ fn multiply_overflow_wrapping_u256() {
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, U256::MAX.wrapping_mul(U256::MAX));
     } else {
         panic!("Expected logd receipt");
@@ -691,8 +698,7 @@ fn multiply_ok_u128(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, a * b);
     } else {
         panic!("Expected logd receipt");
@@ -725,8 +731,7 @@ fn multiply_ok_u256(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, a * b);
     } else {
         panic!("Expected logd receipt");
@@ -779,8 +784,7 @@ fn multiply_single_indirect_u128(
 
     if let Receipt::LogData { data, .. } = lhs_receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, expected);
     } else {
         panic!("Expected logd receipt");
@@ -788,8 +792,7 @@ fn multiply_single_indirect_u128(
 
     if let Receipt::LogData { data, .. } = rhs_receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, expected);
     } else {
         panic!("Expected logd receipt");
@@ -842,8 +845,7 @@ fn multiply_single_indirect_u256(
 
     if let Receipt::LogData { data, .. } = lhs_receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, expected);
     } else {
         panic!("Expected logd receipt");
@@ -851,8 +853,7 @@ fn multiply_single_indirect_u256(
 
     if let Receipt::LogData { data, .. } = rhs_receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, expected);
     } else {
         panic!("Expected logd receipt");
@@ -885,8 +886,7 @@ fn multiply_two_directs_u128(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, u128::from(a * b));
     } else {
         panic!("Expected logd receipt");
@@ -919,8 +919,7 @@ fn multiply_two_directs_u256(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, U256::from(a * b));
     } else {
         panic!("Expected logd receipt");
@@ -992,8 +991,7 @@ fn divide_by_zero_unsafemath_u128() {
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, 0);
     } else {
         panic!("Expected logd receipt");
@@ -1021,8 +1019,7 @@ fn divide_by_zero_unsafemath_u256() {
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, 0);
     } else {
         panic!("Expected logd receipt");
@@ -1054,8 +1051,7 @@ fn divide_ok_u128(
 
     if let &Receipt::LogData { data, .. } = &recp {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let result = u128::from_be_bytes(bytes);
+        let result = logged_u128(data);
         assert_eq!(result, a / b);
     } else {
         panic!("Expected logd receipt, found {:?}", recp);
@@ -1085,8 +1081,7 @@ fn divide_ok_u256(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let result = U256::from_be_bytes(bytes);
+        let result = logged_u256(data);
         assert_eq!(result, a / b);
     } else {
         panic!("Expected logd receipt");
@@ -1123,8 +1118,7 @@ fn fused_mul_div_u128(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let v = u128::from_be_bytes(bytes);
+        let v = logged_u128(data);
         assert_eq!(v, expected);
     } else {
         panic!("Expected logd receipt");
@@ -1234,8 +1228,7 @@ fn fused_mul_div_u256(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let v = U256::from_be_bytes(bytes);
+        let v = logged_u256(data);
         assert_eq!(v, expected);
     } else {
         panic!("Expected logd receipt");
@@ -1336,8 +1329,7 @@ fn addmod_u128(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let v = u128::from_be_bytes(bytes);
+        let v = logged_u128(data);
         assert_eq!(v, expected);
     } else {
         panic!("Expected logd receipt");
@@ -1375,8 +1367,7 @@ fn addmod_u256(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let v = U256::from_be_bytes(bytes);
+        let v = logged_u256(data);
         assert_eq!(v, expected);
     } else {
         panic!("Expected logd receipt");
@@ -1480,8 +1471,7 @@ fn mulmod_u128(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 16] = data.clone().try_into().unwrap();
-        let v = u128::from_be_bytes(bytes);
+        let v = logged_u128(data);
         assert_eq!(v, expected);
     } else {
         panic!("Expected logd receipt");
@@ -1521,8 +1511,7 @@ fn mulmod_u256(
 
     if let Receipt::LogData { data, .. } = receipts.first().unwrap() {
         let data = data.as_ref().unwrap();
-        let bytes: [u8; 32] = data.clone().try_into().unwrap();
-        let v = U256::from_be_bytes(bytes);
+        let v = logged_u256(data);
         assert_eq!(v, expected);
     } else {
         panic!("Expected logd receipt");
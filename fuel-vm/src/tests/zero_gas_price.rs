@@ -0,0 +1,192 @@
+//! Execution of the transactions at zero gas price, as on the development networks.
+//!
+//! Only the tip is charged at zero gas price, so the change outputs must return the
+//! free balances exactly, whether the script succeeds or reverts.
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    Instruction,
+    RegId,
+};
+use fuel_tx::{
+    field::Outputs,
+    TransactionFee,
+};
+use fuel_types::AssetId;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use test_case::test_case;
+
+use crate::{
+    prelude::*,
+    util::test_helpers::{
+        find_change,
+        TestBuilder,
+    },
+};
+
+/// Logs a few receipts to burn some gas, and then returns or reverts.
+fn script(revert: bool) -> Vec<Instruction> {
+    let mut script = vec![
+        op::movi(0x10, 10),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::subi(0x10, 0x10, 1),
+        op::jnzb(0x10, RegId::ZERO, 1),
+    ];
+    if revert {
+        script.push(op::rvrt(RegId::ONE));
+    } else {
+        script.push(op::ret(RegId::ONE));
+    }
+    script
+}
+
+fn execute(test_context: &mut TestBuilder, revert: bool) -> Vec<Output> {
+    let state = test_context.execute();
+    let receipts = state.receipts();
+    let reverted = receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Revert { .. }));
+    assert_eq!(reverted, revert, "{receipts:?}");
+    state.tx().outputs().to_vec()
+}
+
+#[test_case(false, 0; "success with zero max fee")]
+#[test_case(true, 0; "revert with zero max fee")]
+#[test_case(false, 1_000; "success with max fee")]
+#[test_case(true, 1_000; "revert with max fee")]
+fn zero_gas_price__base_asset_change_equals_input(revert: bool, max_fee_limit: Word) {
+    // Given
+    let input_amount = 10_000;
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    test_context
+        .start_script(script(revert), vec![])
+        .script_gas_limit(1_000_000)
+        .gas_price(0)
+        .max_fee_limit(max_fee_limit)
+        .coin_input(base_asset_id, input_amount)
+        .change_output(base_asset_id);
+
+    // When
+    let outputs = execute(&mut test_context, revert);
+
+    // Then
+    assert_eq!(find_change(outputs, base_asset_id), input_amount);
+}
+
+#[test_case(false; "success")]
+#[test_case(true; "revert")]
+fn zero_gas_price__non_base_asset_change_equals_input(revert: bool) {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let asset_id: AssetId = rng.gen();
+    let base_amount = 1_000;
+    let asset_amount = 500;
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    test_context
+        .start_script(script(revert), vec![])
+        .script_gas_limit(1_000_000)
+        .gas_price(0)
+        .coin_input(base_asset_id, base_amount)
+        .coin_input(asset_id, asset_amount)
+        .change_output(base_asset_id)
+        .change_output(asset_id);
+
+    // When
+    let outputs = execute(&mut test_context, revert);
+
+    // Then
+    assert_eq!(find_change(outputs.clone(), base_asset_id), base_amount);
+    assert_eq!(find_change(outputs, asset_id), asset_amount);
+}
+
+#[test_case(false; "success")]
+#[test_case(true; "revert")]
+fn zero_gas_price__coin_outputs_are_deducted_from_change(revert: bool) {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let asset_id: AssetId = rng.gen();
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    test_context
+        .start_script(script(revert), vec![])
+        .script_gas_limit(1_000_000)
+        .gas_price(0)
+        .coin_input(base_asset_id, 1_000)
+        .coin_input(asset_id, 500)
+        .coin_output(base_asset_id, 300)
+        .coin_output(asset_id, 200)
+        .change_output(base_asset_id)
+        .change_output(asset_id);
+
+    // When
+    let outputs = execute(&mut test_context, revert);
+
+    // Then
+    assert_eq!(find_change(outputs.clone(), base_asset_id), 700);
+    assert_eq!(find_change(outputs, asset_id), 300);
+}
+
+#[test_case(false; "success")]
+#[test_case(true; "revert")]
+fn zero_gas_price__only_tip_is_charged(revert: bool) {
+    // Given
+    let input_amount = 1_000;
+    let tip = 10;
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    test_context
+        .start_script(script(revert), vec![])
+        .script_gas_limit(1_000_000)
+        .gas_price(0)
+        .tip(tip)
+        .max_fee_limit(100)
+        .coin_input(base_asset_id, input_amount)
+        .change_output(base_asset_id);
+
+    // When
+    let outputs = execute(&mut test_context, revert);
+
+    // Then
+    assert_eq!(find_change(outputs, base_asset_id), input_amount - tip);
+}
+
+#[test]
+fn zero_gas_price__fee_is_zero_and_fully_refunded() {
+    // Given
+    let max_fee_limit = 1_000;
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    let tx = test_context
+        .start_script(script(false), vec![])
+        .script_gas_limit(1_000_000)
+        .max_fee_limit(max_fee_limit)
+        .coin_input(base_asset_id, 10_000)
+        .change_output(base_asset_id)
+        .build();
+    let gas_costs = test_context.get_gas_costs().clone();
+    let fee_params = *test_context.get_fee_params();
+
+    // When
+    let fee =
+        TransactionFee::checked_from_tx(&gas_costs, &fee_params, tx.transaction(), 0)
+            .expect("The fee can't overflow");
+    let refund = tx
+        .transaction()
+        .refund_fee(&gas_costs, &fee_params, Word::MAX, 0);
+
+    // Then
+    assert_eq!(fee.min_fee(), 0);
+    assert_eq!(fee.max_fee(), 0);
+    assert_eq!(refund, Some(max_fee_limit));
+}
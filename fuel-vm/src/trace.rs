@@ -0,0 +1,20 @@
+//! Diagnostics of the interpreter reported via [`tracing`](https://docs.rs/tracing).
+//!
+//! The macros expand to nothing unless the `tracing` feature is enabled, so the
+//! arguments are neither evaluated nor required to implement the `tracing` traits.
+
+/// Emits a debug-level `tracing` event.
+macro_rules! trace_event {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($arg)+);
+    };
+}
+
+/// Enters a debug-level `tracing` span until the end of the current scope.
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::debug_span!($($arg)+).entered();
+    };
+}
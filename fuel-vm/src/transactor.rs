@@ -1,7 +1,12 @@
 //! State machine of the interpreter.
 
+#[cfg(feature = "vm-backtrace")]
+use crate::backtrace::{
+    Backtrace,
+    CompactBacktrace,
+};
 use crate::{
-    backtrace::Backtrace,
+    call::CallGasReport,
     checked_transaction::{
         Checked,
         IntoChecked,
@@ -10,11 +15,13 @@ use crate::{
     error::InterpreterError,
     interpreter::{
         CheckedMetadata,
+        CodeCache,
         EcalHandler,
         ExecutableTransaction,
         Interpreter,
         InterpreterParams,
         Memory,
+        MemoryStats,
         NotSupportedEcal,
     },
     state::{
@@ -22,8 +29,17 @@ use crate::{
         StateTransition,
         StateTransitionRef,
     },
-    storage::InterpreterStorage,
+    storage::{
+        InstrumentedStorage,
+        InterpreterStorage,
+        StorageMetrics,
+    },
+};
+use alloc::{
+    collections::VecDeque,
+    vec::Vec,
 };
+use fuel_asm::RegId;
 use fuel_tx::{
     Blob,
     Create,
@@ -31,12 +47,15 @@ use fuel_tx::{
     GasCosts,
     Receipt,
     Script,
+    TxId,
     Upgrade,
     Upload,
 };
 
 #[cfg(any(test, feature = "test-helpers"))]
 use crate::interpreter::MemoryInstance;
+#[cfg(any(test, feature = "test-helpers"))]
+use fuel_types::Word;
 
 #[derive(Debug)]
 /// State machine to execute transactions and provide runtime entities on
@@ -53,8 +72,15 @@ where
     interpreter: Interpreter<M, S, Tx, Ecal>,
     program_state: Option<ProgramState>,
     error: Option<InterpreterError<S::DataError>>,
+    history: VecDeque<(TxId, StateTransition<Tx>)>,
+    history_capacity: usize,
+    #[cfg(feature = "vm-backtrace")]
+    compact_backtrace_window: Option<usize>,
 }
 
+/// By default, only the last executed transaction is kept in the history.
+const DEFAULT_HISTORY_CAPACITY: usize = 1;
+
 impl<M, S, Tx, Ecal> Transactor<M, S, Tx, Ecal>
 where
     S: InterpreterStorage,
@@ -71,6 +97,10 @@ where
             ),
             program_state: None,
             error: None,
+            history: VecDeque::new(),
+            history_capacity: DEFAULT_HISTORY_CAPACITY,
+            #[cfg(feature = "vm-backtrace")]
+            compact_backtrace_window: None,
         }
     }
 }
@@ -153,6 +183,62 @@ where
         &self.interpreter
     }
 
+    /// Keeps the state transitions of the last `capacity` executed transactions,
+    /// including the reverted ones. The oldest entries are evicted once the
+    /// capacity is reached. The zero capacity disables the history.
+    pub fn with_history(&mut self, capacity: usize) -> &mut Self {
+        while self.history.len() > capacity {
+            self.history.pop_front();
+        }
+        self.history_capacity = capacity;
+        self
+    }
+
+    /// Provides only the compact backtraces, keeping the windows of `memory_window`
+    /// bytes of the memory, instead of the backtraces with the full memory.
+    #[cfg(feature = "vm-backtrace")]
+    pub fn with_compact_backtraces(&mut self, memory_window: usize) -> &mut Self {
+        self.compact_backtrace_window = Some(memory_window);
+        self
+    }
+
+    /// State transitions of the recently executed transactions, from the oldest
+    /// to the newest.
+    ///
+    /// Transactions that failed with an [`InterpreterError`] are not recorded.
+    pub fn history(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&TxId, &StateTransition<Tx>)> + '_ {
+        self.history.iter().map(|(id, transition)| (id, transition))
+    }
+
+    /// Finds the state transition of the most recent execution of the transaction
+    /// with the given id, if it is still kept in the history.
+    pub fn find_by_txid(&self, id: &TxId) -> Option<&StateTransition<Tx>> {
+        self.history()
+            .rev()
+            .find_map(|(tx_id, transition)| (tx_id == id).then_some(transition))
+    }
+
+    /// Enables the [`CodeCache`] holding at most `capacity_bytes` of the contracts
+    /// bytecode across the executed transactions. The zero capacity disables the
+    /// cache.
+    pub fn with_code_cache(&mut self, capacity_bytes: usize) -> &mut Self {
+        self.interpreter.with_code_cache(capacity_bytes);
+        self
+    }
+
+    /// The cache of the contracts bytecode.
+    pub fn code_cache(&self) -> &CodeCache {
+        self.interpreter.code_cache()
+    }
+
+    /// Mutable access to the cache of the contracts bytecode, e.g. to release the
+    /// memory of the entries after reverting the storage.
+    pub fn code_cache_mut(&mut self) -> &mut CodeCache {
+        self.interpreter.code_cache_mut()
+    }
+
     /// Gas costs of opcodes
     pub fn gas_costs(&self) -> &GasCosts {
         self.interpreter.gas_costs()
@@ -192,11 +278,82 @@ where
 
     /// Generate a backtrace when at least one receipt of `ScriptResult` was
     /// found.
+    ///
+    /// Returns `None` if only the compact backtraces were requested with
+    /// [`Self::with_compact_backtraces`].
+    #[cfg(feature = "vm-backtrace")]
     pub fn backtrace(&self) -> Option<Backtrace> {
+        if self.compact_backtrace_window.is_some() {
+            return None
+        }
+
+        self.script_result()
+            .map(|result| Backtrace::from_vm_error(&self.interpreter, result))
+    }
+
+    /// Generate a compact backtrace when at least one receipt of `ScriptResult` was
+    /// found and the compact backtraces were requested with
+    /// [`Self::with_compact_backtraces`].
+    #[cfg(feature = "vm-backtrace")]
+    pub fn compact_backtrace(&self) -> Option<CompactBacktrace> {
+        let memory_window = self.compact_backtrace_window?;
+
+        self.script_result().map(|result| {
+            CompactBacktrace::from_vm_error(&self.interpreter, result, memory_window)
+        })
+    }
+
+    /// Gas accounting of every contract call made by the transaction, in the order
+    /// of the `Call` receipts.
+    ///
+    /// Follows the same criteria as [`Self::receipts`] to return `None`.
+    pub fn call_gas_report(&self) -> Option<Vec<CallGasReport>> {
+        let receipts = self.receipts()?;
+
+        Some(CallGasReport::from_execution(
+            receipts,
+            self.interpreter.returned_call_gas(),
+            self.interpreter.call_stack(),
+            self.interpreter.registers()[RegId::CGAS],
+        ))
+    }
+
+    #[cfg(feature = "vm-backtrace")]
+    fn script_result(&self) -> Option<fuel_tx::ScriptExecutionResult> {
         self.receipts()
             .and_then(|r| r.iter().find_map(Receipt::result))
             .copied()
-            .map(|result| Backtrace::from_vm_error(&self.interpreter, result))
+    }
+}
+
+impl<M, S, Tx, Ecal> Transactor<M, InstrumentedStorage<S>, Tx, Ecal>
+where
+    Tx: ExecutableTransaction,
+    S: InterpreterStorage,
+{
+    /// The storage metrics accumulated since the last
+    /// [`Self::take_storage_metrics`].
+    pub fn storage_metrics(&self) -> StorageMetrics {
+        self.interpreter.as_ref().metrics()
+    }
+
+    /// Returns the accumulated storage metrics and resets them. Call it after each
+    /// transaction to get the metrics per transaction.
+    pub fn take_storage_metrics(&mut self) -> StorageMetrics {
+        self.interpreter.as_mut().take_metrics()
+    }
+}
+
+impl<M, S, Tx, Ecal> Transactor<M, S, Tx, Ecal>
+where
+    M: Memory,
+    S: InterpreterStorage,
+{
+    /// The memory usage of the last executed transaction.
+    ///
+    /// The high-water marks are reset when the next transaction is initialized.
+    pub fn memory_stats(&self) -> MemoryStats {
+        self.interpreter.memory().stats()
     }
 }
 
@@ -311,26 +468,61 @@ where
 {
     /// Execute a transaction, and return the new state of the transactor
     pub fn transact(&mut self, tx: Checked<Tx>) -> &mut Self {
+        match self.ready_tx(tx) {
+            Ok(ready_tx) => self.transact_ready_tx(ready_tx),
+            Err(e) => self.handle_error(e),
+        }
+    }
+
+    /// Execute a transaction with the writable registers seeded before the first
+    /// instruction, and return the new state of the transactor.
+    ///
+    /// See [`Interpreter::set_writable_register`].
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn transact_with_initial_registers(
+        &mut self,
+        tx: Checked<Tx>,
+        registers: &[(RegId, Word)],
+    ) -> &mut Self {
+        let result = self.ready_tx(tx).and_then(|ready_tx| {
+            let id = ready_tx.id();
+            self.interpreter
+                .transact_with_initial_registers(ready_tx, registers)
+                .map(|state| (id, ProgramState::from(state)))
+        });
+
+        match result {
+            Ok((id, s)) => {
+                self.program_state.replace(s);
+                self.error.take();
+                self.record_history(id);
+                self
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+
+    fn ready_tx(
+        &self,
+        tx: Checked<Tx>,
+    ) -> Result<Ready<Tx>, InterpreterError<S::DataError>> {
         let gas_price = self.interpreter.gas_price();
         let gas_costs = self.interpreter.gas_costs();
         let fee_params = self.interpreter.fee_params();
         let block_height = self.interpreter.context().block_height();
 
-        match tx
-            .into_ready(gas_price, gas_costs, fee_params, block_height)
+        tx.into_ready(gas_price, gas_costs, fee_params, block_height)
             .map_err(InterpreterError::CheckError)
-        {
-            Ok(ready_tx) => self.transact_ready_tx(ready_tx),
-            Err(e) => self.handle_error(e),
-        }
     }
 
     /// Transact a `Ready` transaction directly instead of letting `Transactor` construct
     pub fn transact_ready_tx(&mut self, ready_tx: Ready<Tx>) -> &mut Self {
+        let id = ready_tx.id();
         match self.interpreter.transact(ready_tx) {
             Ok(s) => {
                 self.program_state.replace(s.into());
                 self.error.take();
+                self.record_history(id);
                 self
             }
 
@@ -338,6 +530,19 @@ where
         }
     }
 
+    fn record_history(&mut self, id: TxId) {
+        if self.history_capacity == 0 {
+            return
+        }
+
+        if let Some(transition) = self.to_owned_state_transition() {
+            if self.history.len() >= self.history_capacity {
+                self.history.pop_front();
+            }
+            self.history.push_back((id, transition));
+        }
+    }
+
     fn handle_error(&mut self, error: InterpreterError<S::DataError>) -> &mut Self {
         self.program_state.take();
         self.error.replace(error);
@@ -353,11 +558,17 @@ where
     fn from(interpreter: Interpreter<M, S, Tx, Ecal>) -> Self {
         let program_state = None;
         let error = None;
+        let history = VecDeque::new();
+        let history_capacity = DEFAULT_HISTORY_CAPACITY;
 
         Self {
             interpreter,
             program_state,
             error,
+            history,
+            history_capacity,
+            #[cfg(feature = "vm-backtrace")]
+            compact_backtrace_window: None,
         }
     }
 }
@@ -1,5 +1,35 @@
 //! FuelVM utilities
 
+#[cfg(all(feature = "std", any(test, feature = "test-helpers")))]
+pub mod alu_vectors;
+#[cfg(all(feature = "std", any(test, feature = "test-helpers")))]
+pub mod fuzz;
+pub mod loader;
+pub mod outputs;
+pub mod packed;
+#[cfg(all(feature = "std", feature = "test-helpers"))]
+pub mod parallel;
+pub mod supply;
+
+#[cfg(all(feature = "std", any(test, feature = "test-helpers")))]
+pub use alu_vectors::{
+    export_alu_vectors,
+    AluVector,
+};
+#[cfg(all(feature = "std", any(test, feature = "test-helpers")))]
+pub use fuzz::{
+    fuzz_single,
+    FuzzContract,
+    FuzzVmState,
+    SingleStepOutcome,
+};
+#[cfg(all(feature = "std", feature = "test-helpers"))]
+pub use parallel::{
+    parallel_execute,
+    ExecutionOutcome,
+    StorageChange,
+};
+
 /// A utility macro for writing scripts with the data offset included. Since the
 /// script data offset depends on the length of the script, this macro will
 /// evaluate the length and then rewrite the resultant script output with the
@@ -81,12 +111,70 @@ macro_rules! script_with_data_offset {
     }};
 }
 
+/// Builds the script that sets the register `data_reg` to the offset of the script data
+/// in the VM memory and then executes the `script`.
+///
+/// Unlike [`crate::script_with_data_offset`], the offset isn't limited to the 18-bit
+/// immediate: the prologue uses [`fuel_asm::seq::set_reg_to`], and since its length
+/// depends on the offset, the offset is recomputed until the prologue length stabilizes.
+///
+/// Returns the full script and the offset of the script data.
+#[cfg(feature = "alloc")]
+pub fn build_script_with_data<I>(
+    data_reg: fuel_asm::RegId,
+    script: I,
+    tx_offset: usize,
+) -> (alloc::vec::Vec<fuel_asm::Instruction>, fuel_types::Word)
+where
+    I: IntoIterator<Item = fuel_asm::Instruction>,
+{
+    use fuel_asm::{
+        seq::set_reg_to,
+        Instruction,
+    };
+    use fuel_tx::{
+        field::Script as ScriptField,
+        Script,
+    };
+    use fuel_types::bytes::padded_len_usize;
+
+    let script: alloc::vec::Vec<Instruction> = script.into_iter().collect();
+    let data_offset = |prologue_len: usize| {
+        let script_len = prologue_len
+            .saturating_add(script.len())
+            .saturating_mul(Instruction::SIZE);
+        tx_offset
+            .saturating_add(Script::script_offset_static())
+            .saturating_add(padded_len_usize(script_len).unwrap_or(usize::MAX))
+            as fuel_types::Word
+    };
+
+    let mut offset = data_offset(1);
+    let mut prologue = set_reg_to(data_reg, offset);
+    loop {
+        let next_offset = data_offset(prologue.len());
+        let next_prologue = set_reg_to(data_reg, next_offset);
+        let stabilized = next_prologue.len() == prologue.len();
+        offset = next_offset;
+        prologue = next_prologue;
+        if stabilized {
+            break
+        }
+    }
+
+    prologue.extend(script);
+    (prologue, offset)
+}
+
 #[allow(missing_docs)]
 #[cfg(feature = "random")]
 #[cfg(any(test, feature = "test-helpers"))]
 /// Testing utilities
 pub mod test_helpers {
+    pub use crate::memory_client::CreatedContract;
+
     use alloc::{
+        boxed::Box,
         vec,
         vec::Vec,
     };
@@ -105,10 +193,21 @@ pub mod test_helpers {
             MemoryStorage,
         },
         transactor::Transactor,
+        util::supply::SupplyTracker,
+    };
+    use alloc::collections::{
+        BTreeMap,
+        BTreeSet,
+    };
+    use anyhow::{
+        anyhow,
+        ensure,
     };
-    use anyhow::anyhow;
 
+    #[cfg(feature = "vm-backtrace")]
+    use crate::backtrace::Backtrace;
     use crate::{
+        consts::WORD_SIZE,
         interpreter::{
             CheckedMetadata,
             ExecutableTransaction,
@@ -116,8 +215,8 @@ pub mod test_helpers {
             MemoryInstance,
         },
         prelude::{
-            Backtrace,
             Call,
+            CallGasReport,
         },
     };
     use fuel_asm::{
@@ -129,11 +228,15 @@ pub mod test_helpers {
     };
     use fuel_tx::{
         field::{
+            Inputs,
+            MaxFeeLimit,
             Outputs,
             ReceiptsRoot,
+            ScriptGasLimit,
         },
         BlobBody,
         BlobIdExt,
+        Chargeable,
         ConsensusParameters,
         Contract,
         ContractParameters,
@@ -146,6 +249,7 @@ pub mod test_helpers {
         PredicateParameters,
         Receipt,
         Script,
+        ScriptExecutionResult,
         ScriptParameters,
         StorageSlot,
         Transaction,
@@ -175,12 +279,6 @@ pub mod test_helpers {
         SeedableRng,
     };
 
-    pub struct CreatedContract {
-        pub tx: Create,
-        pub contract_id: ContractId,
-        pub salt: Salt,
-    }
-
     pub struct TestBuilder {
         pub rng: StdRng,
         gas_price: Word,
@@ -190,6 +288,61 @@ pub mod test_helpers {
         storage: MemoryStorage,
         block_height: BlockHeight,
         consensus_params: ConsensusParameters,
+        initial_registers: Vec<(RegId, Word)>,
+        data_in_heap: bool,
+        check_fee_invariants: bool,
+    }
+
+    /// The register holding the address of the script data copied to the heap by
+    /// the [`data_in_heap_preamble`].
+    pub const DATA_IN_HEAP_REGISTER: u8 = 0x10;
+
+    /// Instructions copying the script data to a freshly allocated heap region and
+    /// writing its address to the [`DATA_IN_HEAP_REGISTER`]. The scratch register
+    /// `0x11` is reset to zero afterwards.
+    pub fn data_in_heap_preamble() -> Vec<Instruction> {
+        let data = DATA_IN_HEAP_REGISTER;
+        let scratch = 0x11;
+        vec![
+            op::gtf_args(data, RegId::ZERO, GTFArgs::ScriptDataLength),
+            op::aloc(data),
+            op::gtf_args(scratch, RegId::ZERO, GTFArgs::ScriptData),
+            op::mcp(RegId::HP, scratch, data),
+            op::move_(data, RegId::HP),
+            op::move_(scratch, RegId::ZERO),
+        ]
+    }
+
+    /// The offset of the payload within the call data of the
+    /// [`TestBuilder::call_contract_with_payload`].
+    pub const CALL_PAYLOAD_OFFSET: usize = Call::LEN + WORD_SIZE + AssetId::LEN;
+
+    /// The script calling the contract described by the call data of the
+    /// [`TestBuilder::call_contract`] and the offset of the call data.
+    fn call_contract_script(tx_offset: usize) -> (Vec<Instruction>, Word) {
+        let call_data = RegId::new(0x10);
+        let amount = 0x11;
+        let asset_id = 0x12;
+        super::build_script_with_data(
+            call_data,
+            [
+                op::lw(
+                    amount,
+                    call_data,
+                    Immediate12::try_from(Call::LEN / WORD_SIZE)
+                        .expect("`Call::LEN` is 48 bytes"),
+                ),
+                op::addi(
+                    asset_id,
+                    call_data,
+                    Immediate12::try_from(Call::LEN + WORD_SIZE)
+                        .expect("`Call::LEN` is 48 bytes"),
+                ),
+                op::call(call_data, amount, asset_id, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            tx_offset,
+        )
     }
 
     impl TestBuilder {
@@ -204,6 +357,9 @@ pub mod test_helpers {
                 storage: MemoryStorage::default(),
                 block_height: Default::default(),
                 consensus_params: ConsensusParameters::standard(),
+                initial_registers: vec![],
+                data_in_heap: false,
+                check_fee_invariants: false,
             }
         }
 
@@ -233,11 +389,46 @@ pub mod test_helpers {
             script: Vec<u8>,
             script_data: Vec<u8>,
         ) -> &mut Self {
+            let script = if self.data_in_heap {
+                let mut preamble: Vec<u8> = data_in_heap_preamble().into_iter().collect();
+                preamble.extend(script);
+                preamble
+            } else {
+                script
+            };
             self.builder = TransactionBuilder::script(script, script_data);
             self.builder.script_gas_limit(self.script_gas_limit);
             self
         }
 
+        /// Prepends the [`data_in_heap_preamble`] to the scripts started after this
+        /// call, so the script data is available in the heap at the address in the
+        /// [`DATA_IN_HEAP_REGISTER`].
+        ///
+        /// The preamble shifts the script, so the absolute jumps of the script must
+        /// account for its [`data_in_heap_preamble`] length.
+        pub fn with_data_in_heap(&mut self) -> &mut TestBuilder {
+            self.data_in_heap = true;
+            self
+        }
+
+        /// Enables or disables the [`assert_fee_invariants`] check of the scripts run
+        /// by [`Self::execute`]. Disabled by default.
+        pub fn fee_invariants_check(&mut self, enabled: bool) -> &mut TestBuilder {
+            self.check_fee_invariants = enabled;
+            self
+        }
+
+        /// Seeds the writable registers of the script before its first instruction.
+        /// See [`crate::interpreter::Interpreter::set_writable_register`].
+        pub fn initial_registers(
+            &mut self,
+            registers: &[(RegId, Word)],
+        ) -> &mut TestBuilder {
+            self.initial_registers = registers.to_vec();
+            self
+        }
+
         pub fn gas_price(&mut self, price: Word) -> &mut TestBuilder {
             self.gas_price = price;
             self
@@ -248,6 +439,16 @@ pub mod test_helpers {
             self
         }
 
+        pub fn tip(&mut self, tip: Word) -> &mut TestBuilder {
+            self.builder.tip(tip);
+            self
+        }
+
+        pub fn flag_defaults(&mut self, flags: Word) -> &mut TestBuilder {
+            self.builder.flag_defaults(flags);
+            self
+        }
+
         pub fn script_gas_limit(&mut self, limit: Word) -> &mut TestBuilder {
             self.builder.script_gas_limit(limit);
             self.script_gas_limit = limit;
@@ -339,6 +540,11 @@ pub mod test_helpers {
             self
         }
 
+        pub fn with_tx_params(&mut self, tx_params: TxParameters) -> &mut TestBuilder {
+            self.consensus_params.set_tx_params(tx_params);
+            self
+        }
+
         pub fn with_fee_params(&mut self, fee_params: FeeParameters) -> &mut TestBuilder {
             self.consensus_params.set_fee_params(fee_params);
             self
@@ -350,6 +556,11 @@ pub mod test_helpers {
             self
         }
 
+        pub fn with_gas_costs(&mut self, gas_costs: GasCosts) -> &mut TestBuilder {
+            self.consensus_params.set_gas_costs(gas_costs);
+            self
+        }
+
         pub fn base_asset_id(&mut self, base_asset_id: AssetId) -> &mut TestBuilder {
             self.consensus_params.set_base_asset_id(base_asset_id);
             self
@@ -480,13 +691,9 @@ pub mod test_helpers {
             let storage_slots = initial_state.unwrap_or_default();
 
             let salt: Salt = self.rng.gen();
-            let program: Witness = contract.into();
-            let storage_root = Contract::initial_state_root(storage_slots.iter());
-            let contract = Contract::from(program.as_ref());
-            let contract_root = contract.root();
-            let contract_id = contract.id(&salt, &contract_root, &storage_root);
+            let created = CreatedContract::new(&contract, &salt, &storage_slots);
 
-            let tx = TransactionBuilder::create(program, salt, storage_slots)
+            let tx = TransactionBuilder::create(contract.into(), salt, storage_slots)
                 .max_fee_limit(self.max_fee_limit)
                 .maturity(Default::default())
                 .add_fee_input()
@@ -496,22 +703,21 @@ pub mod test_helpers {
                 .expect("failed to check tx");
 
             // setup a contract in current test state
-            let state = self
-                .deploy(tx)
+            self.deploy(tx)
                 .expect("Expected vm execution to be successful");
 
             // set initial contract balance
             if let Some((asset_id, amount)) = initial_balance {
                 self.storage
-                    .contract_asset_id_balance_insert(&contract_id, &asset_id, amount)
+                    .contract_asset_id_balance_insert(
+                        &created.contract_id,
+                        &asset_id,
+                        amount,
+                    )
                     .unwrap();
             }
 
-            CreatedContract {
-                tx: state.tx().clone(),
-                contract_id,
-                salt,
-            }
+            created
         }
 
         pub fn setup_blob(&mut self, data: Vec<u8>) {
@@ -537,7 +743,7 @@ pub mod test_helpers {
                 interpreter_params,
             );
 
-            self.execute_tx_inner(&mut transactor, tx)
+            self.execute_tx_inner(&mut transactor, tx, &[])
                 .expect("Expected vm execution to be successful");
         }
 
@@ -545,6 +751,7 @@ pub mod test_helpers {
             &mut self,
             transactor: &mut Transactor<M, MemoryStorage, Tx, Ecal>,
             checked: Checked<Tx>,
+            initial_registers: &[(RegId, Word)],
         ) -> anyhow::Result<StateTransition<Tx>>
         where
             M: Memory,
@@ -554,7 +761,11 @@ pub mod test_helpers {
         {
             self.storage.set_block_height(self.block_height);
 
-            transactor.transact(checked);
+            if initial_registers.is_empty() {
+                transactor.transact(checked);
+            } else {
+                transactor.transact_with_initial_registers(checked, initial_registers);
+            }
 
             let storage = transactor.as_mut().clone();
 
@@ -604,7 +815,7 @@ pub mod test_helpers {
                 interpreter_params,
             );
 
-            self.execute_tx_inner(&mut transactor, checked)
+            self.execute_tx_inner(&mut transactor, checked, &[])
         }
 
         pub fn execute_tx(
@@ -618,10 +829,12 @@ pub mod test_helpers {
                 self.storage.clone(),
                 interpreter_params,
             );
+            let initial_registers = self.initial_registers.clone();
 
-            self.execute_tx_inner(&mut transactor, checked)
+            self.execute_tx_inner(&mut transactor, checked, &initial_registers)
         }
 
+        #[cfg(feature = "vm-backtrace")]
         pub fn execute_tx_with_backtrace(
             &mut self,
             checked: Checked<Script>,
@@ -634,19 +847,59 @@ pub mod test_helpers {
                 self.storage.clone(),
                 interpreter_params,
             );
+            let initial_registers = self.initial_registers.clone();
 
-            let state = self.execute_tx_inner(&mut transactor, checked)?;
+            let state =
+                self.execute_tx_inner(&mut transactor, checked, &initial_registers)?;
             let backtrace = transactor.backtrace();
 
             Ok((state, backtrace))
         }
 
+        pub fn execute_tx_with_call_gas_report(
+            &mut self,
+            checked: Checked<Script>,
+        ) -> anyhow::Result<(StateTransition<Script>, Vec<CallGasReport>)> {
+            let interpreter_params =
+                InterpreterParams::new(self.gas_price, &self.consensus_params);
+            let mut transactor = Transactor::<_, _, _>::new(
+                MemoryInstance::new(),
+                self.storage.clone(),
+                interpreter_params,
+            );
+            let initial_registers = self.initial_registers.clone();
+
+            let state =
+                self.execute_tx_inner(&mut transactor, checked, &initial_registers)?;
+            let report = transactor
+                .call_gas_report()
+                .expect("The script was executed above");
+
+            Ok((state, report))
+        }
+
         /// Build test tx and execute it
         pub fn execute(&mut self) -> StateTransition<Script> {
             let tx = self.build();
+            let tx_before = tx.transaction().clone();
 
-            self.execute_tx(tx)
-                .expect("expected successful vm execution")
+            let state = self
+                .execute_tx(tx)
+                .expect("expected successful vm execution");
+
+            if self.check_fee_invariants {
+                assert_fee_invariants(
+                    &tx_before,
+                    state.tx(),
+                    state.receipts(),
+                    self.get_gas_costs(),
+                    self.get_fee_params(),
+                    self.get_base_asset_id(),
+                    self.gas_price,
+                );
+            }
+
+            state
         }
 
         pub fn get_storage(&self) -> &MemoryStorage {
@@ -662,6 +915,85 @@ pub mod test_helpers {
             find_change(outputs, find_asset_id)
         }
 
+        /// Returns the offset in the VM memory of the call data used by
+        /// [`Self::call_contract`]: the [`Call`], followed by the amount of forwarded
+        /// coins, their asset id and the payload starting at
+        /// [`CALL_PAYLOAD_OFFSET`] within the call data.
+        pub fn call_data_offset(&self) -> Word {
+            call_contract_script(self.get_tx_params().tx_offset()).1
+        }
+
+        /// Calls the contract `contract_id` with the `a` and `b` arguments from a
+        /// script and returns the receipts.
+        ///
+        /// Starts a new script with the contract input and output and the fee input.
+        /// The script doesn't include the [`data_in_heap_preamble`].
+        pub fn call_contract(
+            &mut self,
+            contract_id: ContractId,
+            a: Word,
+            b: Word,
+        ) -> Vec<Receipt> {
+            self.call_contract_with_payload(contract_id, a, b, &[])
+        }
+
+        /// Same as [`Self::call_contract`], but appends the `payload` to the call
+        /// data. The payload is at [`CALL_PAYLOAD_OFFSET`] from the
+        /// [`Self::call_data_offset`].
+        pub fn call_contract_with_payload(
+            &mut self,
+            contract_id: ContractId,
+            a: Word,
+            b: Word,
+            payload: &[u8],
+        ) -> Vec<Receipt> {
+            self.start_contract_call(contract_id, a, b, 0, AssetId::zeroed(), payload)
+                .execute()
+                .receipts()
+                .to_vec()
+        }
+
+        /// Same as [`Self::call_contract`], but forwards `amount` coins of the
+        /// `asset_id` to the contract. For the non-base assets, a coin input with
+        /// the `amount` and a change output are added.
+        pub fn call_contract_with_coins(
+            &mut self,
+            contract_id: ContractId,
+            a: Word,
+            b: Word,
+            amount: Word,
+            asset_id: AssetId,
+        ) -> Vec<Receipt> {
+            self.start_contract_call(contract_id, a, b, amount, asset_id, &[]);
+            if &asset_id != self.get_base_asset_id() {
+                self.coin_input(asset_id, amount).change_output(asset_id);
+            }
+            self.execute().receipts().to_vec()
+        }
+
+        fn start_contract_call(
+            &mut self,
+            contract_id: ContractId,
+            a: Word,
+            b: Word,
+            amount: Word,
+            asset_id: AssetId,
+            payload: &[u8],
+        ) -> &mut Self {
+            let (script, _) = call_contract_script(self.get_tx_params().tx_offset());
+            let mut script_data = Call::new(contract_id, a, b).to_bytes();
+            script_data.extend(amount.to_be_bytes());
+            script_data.extend(asset_id.as_ref());
+            script_data.extend(payload);
+
+            self.builder =
+                TransactionBuilder::script(script.into_iter().collect(), script_data);
+            self.builder.script_gas_limit(self.script_gas_limit);
+            self.contract_input(contract_id)
+                .fee_input()
+                .contract_output(&contract_id)
+        }
+
         pub fn get_contract_balance(
             &mut self,
             contract_id: &ContractId,
@@ -792,6 +1124,275 @@ pub mod test_helpers {
         }
     }
 
+    /// Checks the fee charged for the executed script against its receipts and
+    /// outputs, and panics with the description of the first violated invariant.
+    ///
+    /// See [`check_fee_invariants`] for the checked invariants.
+    #[allow(clippy::too_many_arguments)]
+    pub fn assert_fee_invariants(
+        tx_before: &Script,
+        tx_after: &Script,
+        receipts: &[Receipt],
+        gas_costs: &GasCosts,
+        fee_params: &FeeParameters,
+        base_asset_id: &AssetId,
+        gas_price: Word,
+    ) {
+        if let Err(e) = check_fee_invariants(
+            tx_before,
+            tx_after,
+            receipts,
+            gas_costs,
+            fee_params,
+            base_asset_id,
+            gas_price,
+        ) {
+            panic!("Fee invariant violated: {e}\nreceipts: {receipts:?}");
+        }
+    }
+
+    /// Checks that:
+    /// - the `gas_used` of the `ScriptResult` doesn't exceed the script gas limit;
+    /// - if the script reverted, the change outputs hold the initial free balances, with
+    ///   the refund added to the base asset;
+    /// - if the script reverted, the variable outputs are empty, otherwise they hold the
+    ///   coins transferred to them;
+    /// - for every asset, the inputs are equal to the coin and change outputs, the fee,
+    ///   and the coins the script sent to contracts, variable outputs and messages. The
+    ///   retryable amounts of the data messages are spent only if the script succeeded.
+    ///   The inputs may exceed the outputs only for the assets without the change output,
+    ///   since their remaining free balance is burned.
+    ///
+    /// `tx_before` is the checked transaction before the execution, and `tx_after` is
+    /// the one returned by the VM.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_fee_invariants(
+        tx_before: &Script,
+        tx_after: &Script,
+        receipts: &[Receipt],
+        gas_costs: &GasCosts,
+        fee_params: &FeeParameters,
+        base_asset_id: &AssetId,
+        gas_price: Word,
+    ) -> anyhow::Result<()> {
+        let (result, gas_used) = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { result, gas_used } => Some((*result, *gas_used)),
+                _ => None,
+            })
+            .ok_or_else(|| anyhow!("the `ScriptResult` receipt is missing"))?;
+        let script_gas_limit = *tx_before.script_gas_limit();
+        ensure!(
+            gas_used <= script_gas_limit,
+            "gas used {gas_used} exceeds the script gas limit {script_gas_limit}"
+        );
+        let revert = result != ScriptExecutionResult::Success;
+
+        let max_fee = tx_before.max_fee_limit();
+        let refund = tx_before
+            .refund_fee(gas_costs, fee_params, gas_used, gas_price)
+            .ok_or_else(|| anyhow!("the refund overflows"))?;
+        let fee = max_fee
+            .checked_sub(refund)
+            .ok_or_else(|| anyhow!("refund {refund} exceeds the max fee {max_fee}"))?;
+
+        let mut inputs = BTreeMap::<AssetId, u128>::new();
+        let mut initial = BTreeMap::<AssetId, u128>::new();
+        let mut spent = BTreeMap::<AssetId, u128>::new();
+        let add = |balances: &mut BTreeMap<AssetId, u128>, asset_id: &AssetId, amount| {
+            let balance = balances.entry(*asset_id).or_default();
+            *balance = balance.saturating_add(u128::from(amount));
+        };
+
+        for input in tx_before.inputs() {
+            let (Some(asset_id), Some(amount)) =
+                (input.asset_id(base_asset_id), input.amount())
+            else {
+                continue
+            };
+            let retryable =
+                input.is_message_data_signed() || input.is_message_data_predicate();
+            if !retryable {
+                add(&mut initial, asset_id, amount);
+            }
+            if !retryable || !revert {
+                add(&mut inputs, asset_id, amount);
+            }
+        }
+
+        add(&mut spent, base_asset_id, fee);
+        let mut coin_outputs = BTreeMap::<AssetId, u128>::new();
+        for output in tx_before.outputs() {
+            if let Output::Coin {
+                asset_id, amount, ..
+            } = output
+            {
+                add(&mut spent, asset_id, *amount);
+                add(&mut coin_outputs, asset_id, *amount);
+            }
+        }
+        // The free balance of the reverted script: the inputs without the max fee
+        // and the coin outputs, with the unused gas refunded.
+        let reverted_change = |asset_id: &AssetId| {
+            let balance = initial
+                .get(asset_id)
+                .copied()
+                .unwrap_or_default()
+                .saturating_sub(coin_outputs.get(asset_id).copied().unwrap_or_default());
+            if asset_id == base_asset_id {
+                balance
+                    .saturating_sub(u128::from(max_fee))
+                    .saturating_add(u128::from(refund))
+            } else {
+                balance
+            }
+        };
+
+        let mut transferred_out = BTreeMap::<AssetId, u128>::new();
+        let mut variable = BTreeMap::<AssetId, u128>::new();
+        for output in tx_after.outputs() {
+            match output {
+                Output::Change {
+                    asset_id, amount, ..
+                } => {
+                    add(&mut spent, asset_id, *amount);
+                    if revert {
+                        let expected = reverted_change(asset_id);
+                        ensure!(
+                            u128::from(*amount) == expected,
+                            "reverted change of {asset_id} is {amount}, expected {expected}"
+                        );
+                    }
+                }
+                Output::Variable {
+                    asset_id, amount, ..
+                } => add(&mut variable, asset_id, *amount),
+                _ => {}
+            }
+        }
+
+        if !revert {
+            // The receipts of the script itself have the zero id, except for the
+            // messages, whose sender is read from the memory, so the call depth is
+            // tracked instead.
+            let script = ContractId::zeroed();
+            let mut depth = 0usize;
+            for receipt in receipts {
+                match receipt {
+                    Receipt::Call {
+                        id,
+                        amount,
+                        asset_id,
+                        ..
+                    } => {
+                        if id == &script {
+                            add(&mut spent, asset_id, *amount);
+                        }
+                        depth = depth.saturating_add(1);
+                    }
+                    Receipt::Return { id, .. } | Receipt::ReturnData { id, .. }
+                        if id != &script =>
+                    {
+                        depth = depth.saturating_sub(1);
+                    }
+                    Receipt::Transfer {
+                        id,
+                        amount,
+                        asset_id,
+                        ..
+                    } if id == &script => add(&mut spent, asset_id, *amount),
+                    Receipt::TransferOut {
+                        id,
+                        amount,
+                        asset_id,
+                        ..
+                    } => {
+                        add(&mut transferred_out, asset_id, *amount);
+                        if id == &script {
+                            add(&mut spent, asset_id, *amount);
+                        }
+                    }
+                    Receipt::MessageOut { amount, .. } if depth == 0 => {
+                        add(&mut spent, base_asset_id, *amount)
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        variable.retain(|_, amount| *amount != 0);
+        transferred_out.retain(|_, amount| *amount != 0);
+        ensure!(
+            variable == transferred_out,
+            "variable outputs {variable:?} don't match the transferred coins \
+             {transferred_out:?}"
+        );
+
+        // The free balance of the asset without the change output is burned
+        let with_change = tx_after
+            .outputs()
+            .iter()
+            .filter_map(|output| match output {
+                Output::Change { asset_id, .. } => Some(*asset_id),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+        for asset_id in inputs.keys().chain(spent.keys()) {
+            let input = inputs.get(asset_id).copied().unwrap_or_default();
+            let output = spent.get(asset_id).copied().unwrap_or_default();
+            ensure!(
+                input == output || (input > output && !with_change.contains(asset_id)),
+                "{asset_id} isn't conserved: {input} in the inputs, but {output} in the \
+                 outputs, the fee and the receipts"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks the balances of the contracts in the `storage` against the supply of
+    /// the minted assets tracked from the `receipts`, and panics with the
+    /// description of the first mismatch.
+    ///
+    /// See [`check_mint_burn_consistency`] for the requirements on the `receipts`.
+    pub fn assert_mint_burn_consistency(receipts: &[Receipt], storage: &MemoryStorage) {
+        if let Err(e) = check_mint_burn_consistency(receipts, storage) {
+            panic!("Mint and burn are inconsistent: {e}\nreceipts: {receipts:?}");
+        }
+    }
+
+    /// Checks that for every asset minted or burned by the `receipts`, the total
+    /// balance of the asset over all contracts in the `storage` is equal to its
+    /// [`AssetSupply::held_by_contracts`](crate::util::supply::AssetSupply::held_by_contracts).
+    ///
+    /// The `receipts` must cover all transactions that minted, burned or moved the
+    /// assets since they were created, e.g. all transactions executed on the fresh
+    /// storage.
+    pub fn check_mint_burn_consistency(
+        receipts: &[Receipt],
+        storage: &MemoryStorage,
+    ) -> anyhow::Result<()> {
+        let mut balances = BTreeMap::<AssetId, u128>::new();
+        for (key, balance) in storage.all_contract_balances() {
+            let total = balances.entry(*key.asset_id()).or_default();
+            *total = total.saturating_add(u128::from(*balance));
+        }
+
+        for (asset_id, supply) in SupplyTracker::from_receipts(receipts).iter() {
+            let held = supply.held_by_contracts();
+            let balance = balances.get(asset_id).copied().unwrap_or_default();
+            ensure!(
+                i128::try_from(balance) == Ok(held),
+                "the contracts hold {balance} of {asset_id} minted by {}, but the \
+                 receipts account for {held}",
+                supply.contract_id
+            );
+        }
+
+        Ok(())
+    }
+
     pub fn find_change(outputs: Vec<Output>, find_asset_id: AssetId) -> Word {
         let change = outputs.into_iter().find_map(|output| {
             if let Output::Change {
@@ -811,6 +1412,97 @@ pub mod test_helpers {
             panic!("no change matching asset ID {:x} was found", &find_asset_id)
         })
     }
+
+    /// The check of the receipts of the transactions of a block of the
+    /// [`BlockScenario`], one vector per transaction.
+    type BlockCheck = Box<dyn FnOnce(&MemoryStorage, &[Vec<Receipt>])>;
+
+    struct ScenarioBlock {
+        height: BlockHeight,
+        coinbase: ContractId,
+        txs: Vec<Checked<Script>>,
+        check: Option<BlockCheck>,
+    }
+
+    /// Simulates a chain by executing the transactions block by block on the same
+    /// [`MemoryClient`].
+    ///
+    /// Before the transactions of every block, the changes of the previous block
+    /// are persisted and the height and the coinbase of the block are set with
+    /// [`MemoryClient::advance_block`].
+    pub struct BlockScenario {
+        client: MemoryClient<MemoryInstance>,
+        blocks: Vec<ScenarioBlock>,
+    }
+
+    impl Default for BlockScenario {
+        fn default() -> Self {
+            Self::new(MemoryClient::default())
+        }
+    }
+
+    impl BlockScenario {
+        pub fn new(client: MemoryClient<MemoryInstance>) -> Self {
+            Self {
+                client,
+                blocks: Vec::new(),
+            }
+        }
+
+        /// Adds the block at the `height` produced by the `coinbase` with the `txs`.
+        pub fn block(
+            &mut self,
+            height: BlockHeight,
+            coinbase: ContractId,
+            txs: Vec<Checked<Script>>,
+        ) -> &mut Self {
+            self.blocks.push(ScenarioBlock {
+                height,
+                coinbase,
+                txs,
+                check: None,
+            });
+            self
+        }
+
+        /// Sets the `check` of the receipts of the last added block, called with
+        /// the storage after the execution of the block.
+        pub fn check_receipts(
+            &mut self,
+            check: impl FnOnce(&MemoryStorage, &[Vec<Receipt>]) + 'static,
+        ) -> &mut Self {
+            let block = self
+                .blocks
+                .last_mut()
+                .expect("the receipts are checked after the block is added");
+            block.check = Some(Box::new(check));
+            self
+        }
+
+        /// Executes the added blocks in order and returns the receipts of every
+        /// transaction, grouped by block.
+        pub fn execute(&mut self) -> Vec<Vec<Vec<Receipt>>> {
+            core::mem::take(&mut self.blocks)
+                .into_iter()
+                .map(|block| {
+                    self.client.advance_block(block.height, block.coinbase);
+                    let receipts = block
+                        .txs
+                        .into_iter()
+                        .map(|tx| self.client.transact(tx).to_vec())
+                        .collect::<Vec<_>>();
+                    if let Some(check) = block.check {
+                        check(self.client.as_ref(), &receipts);
+                    }
+                    receipts
+                })
+                .collect()
+        }
+
+        pub fn client(&self) -> &MemoryClient<MemoryInstance> {
+            &self.client
+        }
+    }
 }
 
 #[allow(missing_docs)]
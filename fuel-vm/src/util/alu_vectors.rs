@@ -0,0 +1,338 @@
+//! Conformance vectors of the arithmetic instructions.
+//!
+//! The alternative implementations of the VM can check their arithmetic against
+//! the [`AluVector`]s. Every vector is produced by executing the instruction in the
+//! interpreter, so the vectors describe the behavior of this implementation.
+//!
+//! The operands are placed the same way in every vector. The narrow instructions
+//! read `$r0x11` and `$r0x12` and write `$r0x10`. The wide instructions read the
+//! operands from the memory pointed to by `$r0x11`, `$r0x12` and `$r0x13`, and
+//! write the result to the memory pointed to by `$r0x10`, except for the
+//! comparisons writing `$r0x10` itself. The operands are stored one after another
+//! at the end of the heap, followed by the result.
+
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    wideint::{
+        CompareArgs,
+        CompareMode,
+        DivArgs,
+        MathArgs,
+        MathOp,
+        MulArgs,
+    },
+    Flags,
+    Instruction,
+    PanicReason,
+    RegId,
+};
+use fuel_types::Word;
+
+use super::fuzz::{
+    setup,
+    FuzzVmState,
+};
+use crate::{
+    consts::VM_MAX_RAM,
+    error::InterpreterError,
+};
+
+/// The result of the execution of an arithmetic instruction with the given inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AluVector {
+    /// The mnemonic of the instruction.
+    pub opcode: String,
+    /// The encoded instruction, including the mode of the wide instructions.
+    pub instruction: u32,
+    /// The value of `$flag` during the execution.
+    pub flags: Word,
+    /// The operands as big-endian hex: 8 bytes for the registers, and 16 or 32
+    /// bytes for the wide operands.
+    pub inputs: Vec<String>,
+    /// The result as big-endian hex, or `None` if the instruction panicked.
+    pub result: Option<String>,
+    /// The value of `$of` after the execution.
+    pub of: Word,
+    /// The value of `$err` after the execution.
+    pub err: Word,
+    /// The reason of the panic, if the instruction panicked.
+    pub panic: Option<PanicReason>,
+}
+
+/// The location of the operands and of the result of the instruction.
+#[derive(Debug, Clone, Copy)]
+enum Layout {
+    /// The operands and the result are in the registers.
+    Registers,
+    /// The operands are in the memory, and the result is in the register.
+    MemoryToRegister,
+    /// The operands and the result of the given width are in the memory.
+    Memory(usize),
+}
+
+const ALL_FLAGS: [Flags; 4] = [
+    Flags::empty(),
+    Flags::WRAPPING,
+    Flags::UNSAFEMATH,
+    Flags::WRAPPING.union(Flags::UNSAFEMATH),
+];
+
+/// The instructions ignoring `$flag` are only executed with the empty flags.
+const NO_FLAGS: [Flags; 1] = [Flags::empty()];
+
+type NarrowOp = fn(u8, u8, u8) -> Instruction;
+
+/// The fused multiply-divide, add-mod and mul-mod.
+type FusedOp = fn(u8, u8, u8, u8) -> Instruction;
+
+const NARROW_OPS: [(NarrowOp, &[Flags]); 10] = [
+    (op::add, &ALL_FLAGS),
+    (op::sub, &ALL_FLAGS),
+    (op::mul, &ALL_FLAGS),
+    (op::div, &ALL_FLAGS),
+    (op::exp, &ALL_FLAGS),
+    (op::mlog, &ALL_FLAGS),
+    (op::mroo, &ALL_FLAGS),
+    (op::mod_, &ALL_FLAGS),
+    (op::sll, &NO_FLAGS),
+    (op::srl, &NO_FLAGS),
+];
+
+/// Zero, one, two, and the values next to the overflow.
+const NARROW_INPUTS: [Word; 5] = [0, 1, 2, 1 << 63, Word::MAX];
+
+const COMPARE_MODES: [CompareMode; 7] = [
+    CompareMode::EQ,
+    CompareMode::NE,
+    CompareMode::LT,
+    CompareMode::GT,
+    CompareMode::LTE,
+    CompareMode::GTE,
+    CompareMode::LZC,
+];
+
+const MATH_OPS: [(MathOp, &[Flags]); 8] = [
+    (MathOp::ADD, &ALL_FLAGS),
+    (MathOp::SUB, &ALL_FLAGS),
+    (MathOp::NOT, &NO_FLAGS),
+    (MathOp::OR, &NO_FLAGS),
+    (MathOp::XOR, &NO_FLAGS),
+    (MathOp::AND, &NO_FLAGS),
+    (MathOp::SHL, &NO_FLAGS),
+    (MathOp::SHR, &NO_FLAGS),
+];
+
+/// The wide instructions of the same width.
+struct WideOps {
+    width: usize,
+    cmp: fn(u8, u8, u8, CompareArgs) -> Instruction,
+    op: fn(u8, u8, u8, MathArgs) -> Instruction,
+    mul: fn(u8, u8, u8, MulArgs) -> Instruction,
+    div: fn(u8, u8, u8, DivArgs) -> Instruction,
+    fused: [FusedOp; 3],
+}
+
+const WIDE_OPS: [WideOps; 2] = [
+    WideOps {
+        width: 16,
+        cmp: op::wdcm_args,
+        op: op::wdop_args,
+        mul: op::wdml_args,
+        div: op::wddv_args,
+        fused: [op::wdmd, op::wdam, op::wdmm],
+    },
+    WideOps {
+        width: 32,
+        cmp: op::wqcm_args,
+        op: op::wqop_args,
+        mul: op::wqml_args,
+        div: op::wqdv_args,
+        fused: [op::wqmd, op::wqam, op::wqmm],
+    },
+];
+
+/// Generates the conformance vectors of the narrow arithmetic instructions
+/// `ADD`, `SUB`, `MUL`, `DIV`, `EXP`, `MLOG`, `MROO`, `MOD`, `SLL` and `SRL`, and of
+/// the wide instructions.
+///
+/// Every instruction is executed for all the combinations of the edge inputs: zero,
+/// one and the values next to the overflow. The instructions reading `$flag` are
+/// executed with every combination of the [`Flags::WRAPPING`] and
+/// [`Flags::UNSAFEMATH`].
+pub fn export_alu_vectors() -> Vec<AluVector> {
+    let mut vectors = Vec::new();
+
+    let narrow_inputs = NARROW_INPUTS.map(|input| input.to_be_bytes().to_vec());
+    for (op, flags) in NARROW_OPS {
+        let instruction = op(0x10, 0x11, 0x12);
+        for inputs in combinations(&narrow_inputs, 2) {
+            push(&mut vectors, instruction, flags, &inputs, Layout::Registers);
+        }
+    }
+
+    for ops in WIDE_OPS {
+        let inputs = wide_inputs(ops.width);
+        let binary = combinations(&inputs, 2);
+        for mode in COMPARE_MODES {
+            let args = CompareArgs {
+                mode,
+                indirect_rhs: true,
+            };
+            let instruction = (ops.cmp)(0x10, 0x11, 0x12, args);
+            for inputs in &binary {
+                push(
+                    &mut vectors,
+                    instruction,
+                    &NO_FLAGS,
+                    inputs,
+                    Layout::MemoryToRegister,
+                );
+            }
+        }
+
+        let layout = Layout::Memory(ops.width);
+        let mut instructions = MATH_OPS
+            .map(|(op, flags)| {
+                let args = MathArgs {
+                    op,
+                    indirect_rhs: true,
+                };
+                ((ops.op)(0x10, 0x11, 0x12, args), flags)
+            })
+            .to_vec();
+        let args = MulArgs {
+            indirect_lhs: true,
+            indirect_rhs: true,
+        };
+        instructions.push(((ops.mul)(0x10, 0x11, 0x12, args), &ALL_FLAGS));
+        let args = DivArgs { indirect_rhs: true };
+        instructions.push(((ops.div)(0x10, 0x11, 0x12, args), &ALL_FLAGS));
+        for (instruction, flags) in instructions {
+            for inputs in &binary {
+                push(&mut vectors, instruction, flags, inputs, layout);
+            }
+        }
+
+        for fused in ops.fused {
+            let instruction = fused(0x10, 0x11, 0x12, 0x13);
+            for inputs in combinations(&inputs, 3) {
+                push(&mut vectors, instruction, &ALL_FLAGS, &inputs, layout);
+            }
+        }
+    }
+
+    vectors
+}
+
+/// Zero, one and the maximal value of the `width` bytes.
+fn wide_inputs(width: usize) -> [Vec<u8>; 3] {
+    let mut one = vec![0; width];
+    if let Some(last) = one.last_mut() {
+        *last = 1;
+    }
+    [vec![0; width], one, vec![u8::MAX; width]]
+}
+
+/// All the sequences of the `len` values taken from the `values`.
+fn combinations(values: &[Vec<u8>], len: usize) -> Vec<Vec<Vec<u8>>> {
+    (0..len).fold(vec![vec![]], |combinations, _| {
+        combinations
+            .into_iter()
+            .flat_map(|prefix| {
+                values.iter().map(move |value| {
+                    let mut combination = prefix.clone();
+                    combination.push(value.clone());
+                    combination
+                })
+            })
+            .collect()
+    })
+}
+
+fn push(
+    vectors: &mut Vec<AluVector>,
+    instruction: Instruction,
+    flags: &[Flags],
+    inputs: &[Vec<u8>],
+    layout: Layout,
+) {
+    for flags in flags {
+        vectors.push(execute(instruction, flags, inputs, layout));
+    }
+}
+
+/// Executes the `instruction` with the `inputs` placed according to the `layout`.
+fn execute(
+    instruction: Instruction,
+    flags: &Flags,
+    inputs: &[Vec<u8>],
+    layout: Layout,
+) -> AluVector {
+    let mut registers = vec![0];
+    let mut memory = Vec::new();
+    if let Layout::Registers = layout {
+        registers.extend(inputs.iter().map(|input| {
+            let input = input.as_slice().try_into().expect("the input is a word");
+            Word::from_be_bytes(input)
+        }));
+    } else {
+        memory.extend(inputs.iter().flatten());
+        if let Layout::Memory(width) = layout {
+            memory.resize(memory.len().saturating_add(width), 0);
+        }
+        let mut address = VM_MAX_RAM.saturating_sub(memory.len() as Word);
+        for input in inputs {
+            registers.push(address);
+            address = address.saturating_add(input.len() as Word);
+        }
+        if let Layout::Memory(_) = layout {
+            registers[0] = address;
+        }
+    }
+
+    let mut vm = setup(FuzzVmState {
+        registers: registers.clone(),
+        memory,
+        contracts: vec![],
+    });
+    vm.registers_mut()[RegId::FLAG] = flags.bits();
+
+    let panic = match vm.instruction(instruction) {
+        Ok(_) => None,
+        Err(InterpreterError::PanicInstruction(panic)) => Some(*panic.reason()),
+        Err(InterpreterError::Panic(reason)) => Some(reason),
+        Err(error) => panic!("the arithmetic instruction failed: {error:?}"),
+    };
+    let result = panic.is_none().then(|| match layout {
+        Layout::Registers | Layout::MemoryToRegister => {
+            hex(&vm.registers()[RegId::WRITABLE].to_be_bytes())
+        }
+        Layout::Memory(width) => hex(vm
+            .memory()
+            .read(registers[0], width)
+            .expect("the result is in the memory")),
+    });
+
+    AluVector {
+        opcode: format!("{:?}", instruction.opcode()),
+        instruction: u32::from(instruction),
+        flags: flags.bits(),
+        inputs: inputs.iter().map(|input| hex(input)).collect(),
+        result,
+        of: vm.registers()[RegId::OF],
+        err: vm.registers()[RegId::ERR],
+        panic,
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,274 @@
+//! Harness for fuzzing the single instructions.
+//!
+//! Executing the whole transactions spends most of the fuzzing cycles on the
+//! transaction setup and on the instructions preparing the state. The harness runs
+//! exactly one instruction against the interpreter state seeded by the fuzzer, and
+//! turns the panics of the interpreter into a [`SingleStepOutcome`].
+
+use alloc::{
+    collections::BTreeSet,
+    format,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use std::panic::{
+    catch_unwind,
+    AssertUnwindSafe,
+};
+
+use fuel_asm::{
+    Instruction,
+    PanicReason,
+    RegId,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Contract,
+    Finalizable,
+    Input,
+    Output,
+    Script,
+    TransactionBuilder,
+    TxPointer,
+    UtxoId,
+};
+use fuel_types::{
+    AssetId,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use crate::{
+    checked_transaction::IntoChecked,
+    constraints::reg_key::{
+        Reg,
+        RegMut,
+    },
+    error::InterpreterError,
+    interpreter::{
+        Interpreter,
+        InterpreterParams,
+        MemoryInstance,
+    },
+    state::ExecuteState,
+    storage::{
+        ContractsAssetsStorage,
+        InterpreterStorage,
+        MemoryStorage,
+    },
+};
+
+/// The maximal size of the memory image of the [`FuzzVmState`], in bytes.
+pub const FUZZ_MEMORY_LIMIT: usize = 64 * 1024;
+
+/// The maximal number of the contracts of the [`FuzzVmState`].
+pub const FUZZ_CONTRACTS_LIMIT: usize = 16;
+
+/// The contract deployed before the execution of the fuzzed instruction.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FuzzContract {
+    /// The id of the contract. The code root isn't verified, so any id works.
+    pub id: ContractId,
+    /// The bytecode of the contract.
+    pub code: Vec<u8>,
+    /// The slots of the contract state.
+    pub state: Vec<(Bytes32, Vec<u8>)>,
+    /// The balances of the contract.
+    pub balances: Vec<(AssetId, Word)>,
+}
+
+/// The interpreter state seeded by the fuzzer.
+///
+/// Only the parts of the state that can't break the invariants of the interpreter
+/// are seeded, so every state is valid.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FuzzVmState {
+    /// The values of the writable registers, starting at [`RegId::WRITABLE`]. The
+    /// extra values are ignored.
+    pub registers: Vec<Word>,
+    /// The memory image allocated on the heap, so it ends at the
+    /// [`VM_MAX_RAM`](crate::consts::VM_MAX_RAM) and its address doesn't depend on
+    /// the rest of the state. Truncated to the [`FUZZ_MEMORY_LIMIT`].
+    pub memory: Vec<u8>,
+    /// The contracts deployed to the storage and added to the inputs of the
+    /// script. The repeated ids are skipped, and the list is truncated to the
+    /// [`FUZZ_CONTRACTS_LIMIT`].
+    pub contracts: Vec<FuzzContract>,
+}
+
+/// The outcome of the execution of a single instruction by [`fuzz_single`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SingleStepOutcome {
+    /// The instruction was executed.
+    Executed {
+        /// The state after the instruction.
+        state: ExecuteState,
+        /// The gas charged by the instruction.
+        gas_used: Word,
+    },
+    /// The instruction panicked, as it would panic the script.
+    Panicked {
+        /// The reason of the panic.
+        reason: PanicReason,
+        /// The gas charged by the instruction before the panic.
+        gas_used: Word,
+    },
+    /// The interpreter failed with an error other than a panic of the
+    /// instruction, e.g. a bug.
+    Failed(String),
+    /// The gas registers are inconsistent after the instruction: the remaining
+    /// gas grew, or the context gas exceeds the global gas.
+    GasInconsistency {
+        /// The global gas before the instruction.
+        ggas_before: Word,
+        /// The global gas after the instruction.
+        ggas_after: Word,
+        /// The context gas after the instruction.
+        cgas_after: Word,
+    },
+    /// The interpreter unwound with the panic message. It is only caught if the
+    /// harness is built with `panic = "unwind"`.
+    Unwound(String),
+}
+
+/// Executes exactly one instruction against the interpreter seeded with the
+/// `state`.
+///
+/// The instruction runs in the script context with zero gas price and the half of
+/// the maximal gas of the transaction, so only the huge operations run out of gas.
+pub fn fuzz_single(instruction: Instruction, state: FuzzVmState) -> SingleStepOutcome {
+    let mut vm = setup(state);
+
+    let ggas_before = vm.registers()[RegId::GGAS];
+    let result = catch_unwind(AssertUnwindSafe(|| vm.instruction(instruction)));
+    let result = match result {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            return SingleStepOutcome::Unwound(message)
+        }
+    };
+
+    let ggas_after = vm.registers()[RegId::GGAS];
+    let cgas_after = vm.registers()[RegId::CGAS];
+    if ggas_after > ggas_before || cgas_after > ggas_after {
+        return SingleStepOutcome::GasInconsistency {
+            ggas_before,
+            ggas_after,
+            cgas_after,
+        }
+    }
+    let gas_used = ggas_before.saturating_sub(ggas_after);
+
+    match result {
+        Ok(state) => SingleStepOutcome::Executed { state, gas_used },
+        Err(InterpreterError::PanicInstruction(panic)) => SingleStepOutcome::Panicked {
+            reason: *panic.reason(),
+            gas_used,
+        },
+        Err(InterpreterError::Panic(reason)) => {
+            SingleStepOutcome::Panicked { reason, gas_used }
+        }
+        Err(error) => SingleStepOutcome::Failed(format!("{error:?}")),
+    }
+}
+
+/// Initializes the interpreter with the script and seeds the `state`.
+pub(super) fn setup(
+    state: FuzzVmState,
+) -> Interpreter<MemoryInstance, MemoryStorage, Script> {
+    let gas_price = 0;
+    let consensus_params = ConsensusParameters::standard();
+
+    let mut storage = MemoryStorage::default();
+    let mut seen = BTreeSet::new();
+    let contracts = state
+        .contracts
+        .into_iter()
+        .filter(|contract| seen.insert(contract.id))
+        .take(FUZZ_CONTRACTS_LIMIT)
+        .collect::<Vec<_>>();
+    for contract in &contracts {
+        storage
+            .storage_contract_insert(&contract.id, &Contract::from(contract.code.clone()))
+            .expect("the memory storage is infallible");
+        for (key, value) in &contract.state {
+            storage
+                .contract_state_insert(&contract.id, key, value)
+                .expect("the memory storage is infallible");
+        }
+        for (asset_id, amount) in &contract.balances {
+            storage
+                .contract_asset_id_balance_insert(&contract.id, asset_id, *amount)
+                .expect("the memory storage is infallible");
+        }
+    }
+
+    let mut builder = TransactionBuilder::script(Vec::new(), Vec::new());
+    builder
+        // Leaves the room for the gas of the transaction itself.
+        .script_gas_limit(consensus_params.tx_params().max_gas_per_tx() / 2)
+        .add_fee_input();
+    for (index, contract) in contracts.iter().enumerate() {
+        let index = u16::try_from(index).expect("the contracts are limited");
+        builder.add_input(Input::contract(
+            UtxoId::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            contract.id,
+        ));
+        builder.add_output(Output::contract(
+            index.saturating_add(1),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+        ));
+    }
+
+    let mut vm = Interpreter::with_storage(
+        MemoryInstance::new(),
+        storage,
+        InterpreterParams::new(gas_price, &consensus_params),
+    );
+    let tx = builder
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("the fuzzed script is valid")
+        .into_ready(
+            gas_price,
+            vm.gas_costs(),
+            consensus_params.fee_params(),
+            None,
+        )
+        .expect("the fuzzed script is valid");
+    vm.init_script(tx).expect("the fuzzed script is valid");
+
+    for (register, value) in vm.registers_mut()[usize::from(RegId::WRITABLE)..]
+        .iter_mut()
+        .zip(state.registers)
+    {
+        *register = value;
+    }
+
+    let memory = &state.memory[..state.memory.len().min(FUZZ_MEMORY_LIMIT)];
+    let sp = vm.registers()[RegId::SP];
+    let mut hp = vm.registers()[RegId::HP];
+    vm.memory_mut()
+        .grow_heap_by(Reg::new(&sp), RegMut::new(&mut hp), memory.len() as Word)
+        .expect("the memory image fits the heap");
+    vm.memory_mut()
+        .write_noownerchecks(hp, memory.len())
+        .expect("the memory image fits the heap")
+        .copy_from_slice(memory);
+    vm.registers_mut()[RegId::HP] = hp;
+
+    vm
+}
@@ -0,0 +1,129 @@
+//! Code generation of the loader contracts, which execute the code uploaded in
+//! blobs.
+//!
+//! A program too large for a single contract is uploaded as a sequence of blobs,
+//! and the small loader is deployed instead of it. The bytecode of the loader is
+//!
+//! ```txt
+//! loader instructions | blob ids | data section
+//! ```
+//!
+//! When called, the loader appends the code of every blob to the stack with `LDC`
+//! in the mode `1`, in order, then copies its data section after the code with `LDC`
+//! in the mode `2`, and jumps to the start of the loaded code. So the loaded program
+//! sees the same memory layout as the original program, as long as the program
+//! doesn't depend on the `$is`.
+//!
+//! `LDC` pads the loaded code to the word size, so every blob except the last one
+//! must be a multiple of the word size. The data section is copied to the word
+//! boundary after the code, where the original program keeps it as well.
+//!
+//! The loader is generated here so that the same blobs always produce the same
+//! loader bytecode, and therefore the same contract root.
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    Imm18,
+    Instruction,
+    RegId,
+};
+use fuel_tx::Contract;
+use fuel_types::{
+    BlobId,
+    Bytes32,
+    Word,
+};
+
+/// The register holding the address of the next blob id, and then of the data
+/// section.
+const ADDR: u8 = 0x10;
+/// The register holding the start of the loaded code.
+const START: u8 = 0x11;
+/// The register holding the length of the next loaded region.
+const LEN: u8 = 0x12;
+
+/// Generates the instructions of the loader executing the code of the
+/// `blob_ids`, followed by the data section of `data_section_len` bytes.
+///
+/// The loader expects the blob ids and the data section right after the
+/// instructions, see [`blob_loader_bytecode`]. It clobbers the registers `0x10`,
+/// `0x11` and `0x12` before jumping to the loaded code. The `blob_ids` must not be
+/// empty, otherwise there is no code to jump to.
+#[allow(clippy::cast_possible_truncation)]
+pub fn generate_blob_loader(
+    blob_ids: &[BlobId],
+    data_section_len: Word,
+) -> Vec<Instruction> {
+    let mut body = vec![op::add(ADDR, ADDR, RegId::IS), op::move_(START, RegId::SSP)];
+    for _ in blob_ids {
+        body.extend([
+            op::bsiz(LEN, ADDR),
+            op::ldc(ADDR, RegId::ZERO, LEN, 1),
+            op::addi(ADDR, ADDR, BlobId::LEN as u16),
+        ]);
+    }
+    if data_section_len > 0 {
+        body.extend(set_word(LEN, data_section_len));
+        body.push(op::ldc(ADDR, RegId::ZERO, LEN, 2));
+    }
+    body.extend([
+        op::sub(START, START, RegId::IS),
+        op::divi(START, START, Instruction::SIZE as u16),
+        op::jmp(START),
+    ]);
+
+    // The offset of the blob ids depends on the length of its own setup.
+    let blob_ids_offset = |setup_len: usize| {
+        let len = setup_len.saturating_add(body.len());
+        len.saturating_mul(Instruction::SIZE) as Word
+    };
+    let mut setup = set_word(ADDR, blob_ids_offset(1));
+    if setup.len() > 1 {
+        setup = set_word(ADDR, blob_ids_offset(setup.len()));
+    }
+
+    setup.extend(body);
+    setup
+}
+
+/// The bytecode of the loader contract executing the code of the `blob_ids`, with
+/// the `data_section` of the original program.
+pub fn blob_loader_bytecode(blob_ids: &[BlobId], data_section: &[u8]) -> Vec<u8> {
+    let loader = generate_blob_loader(blob_ids, data_section.len() as Word);
+    let mut bytecode: Vec<u8> = loader.into_iter().collect();
+    for blob_id in blob_ids {
+        bytecode.extend_from_slice(blob_id.as_ref());
+    }
+    bytecode.extend_from_slice(data_section);
+    bytecode
+}
+
+/// The contract root of the loader contract executing the code of the `blob_ids`,
+/// with the `data_section` of the original program.
+///
+/// The root is known before the blobs are uploaded, so the id of the loader
+/// contract can be computed ahead of the deployment.
+pub fn loader_contract_root(blob_ids: &[BlobId], data_section: &[u8]) -> Bytes32 {
+    Contract::root_from_code(blob_loader_bytecode(blob_ids, data_section))
+}
+
+/// Sets the `reg` to the `value`, with a single `MOVI` if the value fits.
+#[allow(clippy::cast_possible_truncation)]
+fn set_word(reg: u8, value: Word) -> Vec<Instruction> {
+    if let Some(imm) = u32::try_from(value).ok().and_then(Imm18::new_checked) {
+        return vec![op::movi(reg, imm.to_u32())]
+    }
+
+    // The highest 16 bits, followed by four chunks of 12 bits.
+    let mut ops = vec![op::movi(reg, (value >> 48) as u32)];
+    for shift in [36, 24, 12, 0] {
+        ops.push(op::slli(reg, reg, 12));
+        ops.push(op::ori(reg, reg, ((value >> shift) & 0xfff) as u16));
+    }
+    ops
+}
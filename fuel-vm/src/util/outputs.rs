@@ -0,0 +1,166 @@
+//! Computation of the transaction outputs produced by the VM after execution.
+//!
+//! The VM uses the same functions to finalize the outputs, so the prediction can't
+//! diverge from the actual result of the execution.
+
+use alloc::vec::Vec;
+use core::ops::Index;
+
+use fuel_tx::{
+    field,
+    Chargeable,
+    FeeParameters,
+    GasCosts,
+    Output,
+    ValidityError,
+};
+use fuel_types::{
+    AssetId,
+    Word,
+};
+
+use crate::interpreter::{
+    InitialBalances,
+    RuntimeBalances,
+};
+
+/// Returns the outputs the VM would produce for the `tx` if its execution used
+/// `gas_used` gas.
+///
+/// The free balances at the end of the execution are assumed to be equal to the
+/// initial ones, i.e. the script doesn't transfer coins to contracts or outputs.
+/// Use [`predict_outputs_with_balances`] if the final balances are known.
+#[allow(clippy::too_many_arguments)]
+pub fn predict_outputs<Tx>(
+    tx: &Tx,
+    gas_used: Word,
+    revert: bool,
+    initial_balances: &InitialBalances,
+    gas_costs: &GasCosts,
+    fee_params: &FeeParameters,
+    base_asset_id: &AssetId,
+    gas_price: Word,
+) -> Result<Vec<Output>, ValidityError>
+where
+    Tx: Chargeable + field::Outputs,
+{
+    let balances = RuntimeBalances::try_from(initial_balances.clone())?;
+    predict_outputs_with_balances(
+        tx,
+        gas_used,
+        revert,
+        initial_balances,
+        &balances,
+        gas_costs,
+        fee_params,
+        base_asset_id,
+        gas_price,
+    )
+}
+
+/// Returns the outputs the VM would produce for the `tx` if its execution used
+/// `gas_used` gas and finished with the free `balances`.
+#[allow(clippy::too_many_arguments)]
+pub fn predict_outputs_with_balances<Tx, I>(
+    tx: &Tx,
+    gas_used: Word,
+    revert: bool,
+    initial_balances: &InitialBalances,
+    balances: &I,
+    gas_costs: &GasCosts,
+    fee_params: &FeeParameters,
+    base_asset_id: &AssetId,
+    gas_price: Word,
+) -> Result<Vec<Output>, ValidityError>
+where
+    Tx: Chargeable + field::Outputs,
+    I: for<'a> Index<&'a AssetId, Output = Word>,
+{
+    let gas_refund = tx
+        .refund_fee(gas_costs, fee_params, gas_used, gas_price)
+        .ok_or(ValidityError::GasCostsCoinsOverflow)?;
+
+    let mut outputs = tx.outputs().clone();
+    // The VM resets the variable outputs before the execution, and only `TRO` can
+    // set them afterwards.
+    outputs
+        .iter_mut()
+        .filter(|output| output.is_variable())
+        .for_each(Output::prepare_init_execute);
+    finalize_outputs(
+        &mut outputs,
+        revert,
+        gas_refund,
+        initial_balances,
+        balances,
+        base_asset_id,
+    )?;
+
+    Ok(outputs)
+}
+
+/// Updates change and variable `outputs` in place.
+///
+/// `revert` will signal if the execution was reverted. It will refund the unused gas
+/// cost to the base asset and reset output changes to their `initial_balances`.
+///
+/// `gas_refund` is the amount of the base asset refunded for the unused gas.
+///
+/// `balances` will contain the current state of the free balances.
+pub(crate) fn finalize_outputs<I>(
+    outputs: &mut [Output],
+    revert: bool,
+    gas_refund: Word,
+    initial_balances: &InitialBalances,
+    balances: &I,
+    base_asset_id: &AssetId,
+) -> Result<(), ValidityError>
+where
+    I: for<'a> Index<&'a AssetId, Output = Word>,
+{
+    outputs.iter_mut().try_for_each(|o| match o {
+        // If revert, set base asset to initial balance and refund unused gas
+        //
+        // Note: the initial balance deducts the gas limit from base asset
+        Output::Change {
+            asset_id, amount, ..
+        } if revert && asset_id == base_asset_id => initial_balances.non_retryable
+            [base_asset_id]
+            .checked_add(gas_refund)
+            .map(|v| *amount = v)
+            .ok_or(ValidityError::BalanceOverflow),
+
+        // If revert, reset any non-base asset to its initial balance
+        Output::Change {
+            asset_id, amount, ..
+        } if revert => {
+            *amount = initial_balances.non_retryable[asset_id];
+            Ok(())
+        }
+
+        // The change for the base asset will be the available balance + unused gas
+        Output::Change {
+            asset_id, amount, ..
+        } if asset_id == base_asset_id => balances[asset_id]
+            .checked_add(gas_refund)
+            .map(|v| *amount = v)
+            .ok_or(ValidityError::BalanceOverflow),
+
+        // Set changes to the remainder provided balances
+        Output::Change {
+            asset_id, amount, ..
+        } => {
+            *amount = balances[asset_id];
+            Ok(())
+        }
+
+        // If revert, zeroes all variable output values
+        Output::Variable { amount, .. } if revert => {
+            *amount = 0;
+            Ok(())
+        }
+
+        // Other outputs are unaffected
+        _ => Ok(()),
+    })
+}
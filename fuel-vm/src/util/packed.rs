@@ -0,0 +1,232 @@
+//! Encoding and decoding of the packed tuples passed via the script data or logged
+//! and returned by the contracts with `LOGD` and `RETD`.
+//!
+//! Every field starts at a word boundary, as in the canonical encoding of the
+//! transaction types. Integers are big-endian, the same as in the VM memory, and
+//! zero-padded on the left to a full word. [`FieldKind::Bytes`] are prefixed with
+//! their length as a word and zero-padded on the right to the next word boundary.
+
+use alloc::vec::Vec;
+
+use fuel_types::{
+    bytes::padded_len_usize,
+    Bytes32,
+    Word,
+};
+
+use crate::consts::WORD_SIZE;
+
+/// The kind of a field of the packed tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldKind {
+    /// A byte, occupying a word.
+    U8,
+    /// A 16-bit integer, occupying a word.
+    U16,
+    /// A 32-bit integer, occupying a word.
+    U32,
+    /// A 64-bit integer.
+    U64,
+    /// A 32 bytes value, e.g. a contract id or an asset id.
+    B256,
+    /// Bytes prefixed with their length.
+    Bytes,
+}
+
+/// The value of a field of the packed tuple.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DecodedField {
+    /// A byte.
+    U8(u8),
+    /// A 16-bit integer.
+    U16(u16),
+    /// A 32-bit integer.
+    U32(u32),
+    /// A 64-bit integer.
+    U64(u64),
+    /// A 32 bytes value.
+    B256(Bytes32),
+    /// Bytes prefixed with their length.
+    Bytes(Vec<u8>),
+}
+
+impl DecodedField {
+    /// Returns the kind of the field.
+    pub const fn kind(&self) -> FieldKind {
+        match self {
+            Self::U8(_) => FieldKind::U8,
+            Self::U16(_) => FieldKind::U16,
+            Self::U32(_) => FieldKind::U32,
+            Self::U64(_) => FieldKind::U64,
+            Self::B256(_) => FieldKind::B256,
+            Self::Bytes(_) => FieldKind::Bytes,
+        }
+    }
+}
+
+/// The error returned by [`decode_packed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum DecodeError {
+    /// The data ended before the field was complete.
+    #[display(
+        fmt = "Expected {expected} bytes at offset {offset}, but only {available} are left"
+    )]
+    Truncated {
+        /// The offset of the field within the data
+        offset: usize,
+        /// The number of bytes required by the field
+        expected: usize,
+        /// The number of bytes left in the data
+        available: usize,
+    },
+    /// The padding of the field contains non-zero bytes.
+    #[display(fmt = "Non-zero padding of the field at offset {offset}")]
+    NonZeroPadding {
+        /// The offset of the field within the data
+        offset: usize,
+    },
+    /// The length prefix of the bytes doesn't fit into `usize`.
+    #[display(fmt = "Length {length} of the bytes at offset {offset} is too large")]
+    LengthOverflow {
+        /// The offset of the field within the data
+        offset: usize,
+        /// The decoded length
+        length: Word,
+    },
+    /// The data continues after the last field of the layout.
+    #[display(
+        fmt = "Unexpected {remaining} bytes after the last field at offset {offset}"
+    )]
+    TrailingBytes {
+        /// The offset of the first unexpected byte
+        offset: usize,
+        /// The number of unexpected bytes
+        remaining: usize,
+    },
+}
+
+/// Decodes the `data` as a packed tuple with the fields of the `layout`.
+///
+/// The whole `data` must be consumed by the `layout`, and the padding must be zeroed.
+pub fn decode_packed(
+    data: &[u8],
+    layout: &[FieldKind],
+) -> Result<Vec<DecodedField>, DecodeError> {
+    let mut reader = Reader { data, offset: 0 };
+    let fields = layout
+        .iter()
+        .map(|kind| reader.field(*kind))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let remaining = data.len().saturating_sub(reader.offset);
+    if remaining != 0 {
+        return Err(DecodeError::TrailingBytes {
+            offset: reader.offset,
+            remaining,
+        })
+    }
+
+    Ok(fields)
+}
+
+/// Encodes the `fields` as a packed tuple, the inverse of [`decode_packed`].
+pub fn encode_packed(fields: &[DecodedField]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for field in fields {
+        match field {
+            DecodedField::U8(value) => push_word(&mut data, &value.to_be_bytes()),
+            DecodedField::U16(value) => push_word(&mut data, &value.to_be_bytes()),
+            DecodedField::U32(value) => push_word(&mut data, &value.to_be_bytes()),
+            DecodedField::U64(value) => push_word(&mut data, &value.to_be_bytes()),
+            DecodedField::B256(value) => data.extend_from_slice(value.as_ref()),
+            DecodedField::Bytes(bytes) => {
+                push_word(&mut data, &(bytes.len() as Word).to_be_bytes());
+                data.extend_from_slice(bytes);
+                let padded =
+                    padded_len_usize(bytes.len()).expect("The bytes fit into memory");
+                data.resize(
+                    data.len()
+                        .saturating_add(padded.saturating_sub(bytes.len())),
+                    0,
+                );
+            }
+        }
+    }
+    data
+}
+
+/// Pushes the `bytes` zero-padded on the left to a full word.
+fn push_word(data: &mut Vec<u8>, bytes: &[u8]) {
+    data.resize(
+        data.len()
+            .saturating_add(WORD_SIZE.saturating_sub(bytes.len())),
+        0,
+    );
+    data.extend_from_slice(bytes);
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn field(&mut self, kind: FieldKind) -> Result<DecodedField, DecodeError> {
+        let field = match kind {
+            FieldKind::U8 => DecodedField::U8(u8::from_be_bytes(self.word()?)),
+            FieldKind::U16 => DecodedField::U16(u16::from_be_bytes(self.word()?)),
+            FieldKind::U32 => DecodedField::U32(u32::from_be_bytes(self.word()?)),
+            FieldKind::U64 => DecodedField::U64(u64::from_be_bytes(self.word()?)),
+            FieldKind::B256 => {
+                let bytes = self.take(Bytes32::LEN)?;
+                DecodedField::B256(
+                    Bytes32::try_from(bytes).expect("The length is checked above"),
+                )
+            }
+            FieldKind::Bytes => {
+                let offset = self.offset;
+                let length = u64::from_be_bytes(self.word()?);
+                let (len, padded) = usize::try_from(length)
+                    .ok()
+                    .and_then(|len| Some((len, padded_len_usize(len)?)))
+                    .ok_or(DecodeError::LengthOverflow { offset, length })?;
+                let bytes_offset = self.offset;
+                let bytes = self.take(padded)?;
+                let (bytes, padding) = bytes.split_at(len);
+                if padding.iter().any(|byte| *byte != 0) {
+                    return Err(DecodeError::NonZeroPadding {
+                        offset: bytes_offset,
+                    })
+                }
+                DecodedField::Bytes(bytes.to_vec())
+            }
+        };
+        Ok(field)
+    }
+
+    /// Reads a word and returns its last `N` bytes, checking that the rest is zeroed.
+    fn word<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let offset = self.offset;
+        let word = self.take(WORD_SIZE)?;
+        let (padding, value) = word.split_at(WORD_SIZE.saturating_sub(N));
+        if padding.iter().any(|byte| *byte != 0) {
+            return Err(DecodeError::NonZeroPadding { offset })
+        }
+        Ok(value.try_into().expect("`N` doesn't exceed the word size"))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let available = self.data.len().saturating_sub(self.offset);
+        if available < len {
+            return Err(DecodeError::Truncated {
+                offset: self.offset,
+                expected: len,
+                available,
+            })
+        }
+        let end = self.offset.saturating_add(len);
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,187 @@
+//! Execution of the same transaction against multiple storage snapshots.
+//!
+//! The state-diff analysis runs one transaction on top of several historical states
+//! of the chain. [`parallel_execute`] executes the transaction against every
+//! snapshot in its own thread, taking the memory of the interpreters from the
+//! shared [`VmMemoryPool`], and returns the outcomes in the order of the snapshots.
+
+use crate::{
+    checked_transaction::Checked,
+    error::InterpreterError,
+    interpreter::InterpreterParams,
+    pool::VmMemoryPool,
+    state::StateTransition,
+    storage::{
+        dump::{
+            StorageDump,
+            TableId,
+        },
+        MemoryStorage,
+        MemoryStorageError,
+    },
+    transactor::Transactor,
+};
+
+use alloc::{
+    collections::BTreeMap,
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    future::Future,
+    task::{
+        Context as TaskContext,
+        Poll,
+    },
+};
+use fuel_tx::{
+    Receipt,
+    Script,
+};
+
+/// The change of a storage entry, encoded as in the [`StorageDump`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageChange {
+    /// The table of the entry.
+    pub table: TableId,
+    /// The encoded key.
+    pub key: Vec<u8>,
+    /// The encoded value before the execution, or `None` if it was inserted.
+    pub before: Option<Vec<u8>>,
+    /// The encoded value after the execution, or `None` if it was removed.
+    pub after: Option<Vec<u8>>,
+}
+
+/// The outcome of the execution against one storage snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionOutcome {
+    /// The transition of the executed transaction, or the error of the execution.
+    pub result: Result<StateTransition<Script>, InterpreterError<MemoryStorageError>>,
+    /// The changes of the snapshot committed by the execution, ordered by the table
+    /// and the key. The reverted and the failed executions change nothing.
+    pub storage_delta: Vec<StorageChange>,
+}
+
+impl ExecutionOutcome {
+    /// The receipts of the execution, empty if it failed.
+    pub fn receipts(&self) -> &[Receipt] {
+        self.result
+            .as_ref()
+            .map(StateTransition::receipts)
+            .unwrap_or_default()
+    }
+}
+
+/// Executes the `tx` against every storage snapshot of the `storages` in a separate
+/// thread, and returns the outcomes in the order of the `storages`.
+///
+/// Each execution takes its memory from the `pool` and commits the changes to its
+/// own snapshot the same way as the [`MemoryClient`](crate::memory_client::MemoryClient).
+pub fn parallel_execute<P>(
+    tx: Checked<Script>,
+    storages: Vec<MemoryStorage>,
+    params: InterpreterParams,
+    pool: &P,
+) -> Vec<ExecutionOutcome>
+where
+    P: VmMemoryPool,
+{
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = storages
+            .into_iter()
+            .map(|storage| {
+                let tx = tx.clone();
+                let params = params.clone();
+                scope.spawn(move || execute(tx, storage, params, pool))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+fn execute<P>(
+    tx: Checked<Script>,
+    storage: MemoryStorage,
+    params: InterpreterParams,
+    pool: &P,
+) -> ExecutionOutcome
+where
+    P: VmMemoryPool,
+{
+    let before = storage.dump();
+    let memory = block_on(pool.get_new());
+    let mut transactor = Transactor::<_, _, Script>::new(memory, storage, params);
+    transactor.transact(tx);
+
+    let result = transactor
+        .result()
+        .map(StateTransition::from)
+        .map_err(Clone::clone);
+    match &result {
+        Ok(transition) if !transition.should_revert() => transactor.as_mut().commit(),
+        _ => transactor.as_mut().revert(),
+    }
+    let storage: &MemoryStorage = transactor.as_ref();
+    let storage_delta = storage_delta(before, storage.dump());
+
+    ExecutionOutcome {
+        result,
+        storage_delta,
+    }
+}
+
+/// The values of the entry before and after the execution.
+type EntryValues = (Option<Vec<u8>>, Option<Vec<u8>>);
+
+/// Returns the changed entries of the dumps, ordered by the table and the key.
+fn storage_delta(before: StorageDump, after: StorageDump) -> Vec<StorageChange> {
+    let mut entries: BTreeMap<_, EntryValues> = BTreeMap::new();
+    for entry in before.entries {
+        entries.entry((entry.table, entry.key)).or_default().0 = Some(entry.value);
+    }
+    for entry in after.entries {
+        entries.entry((entry.table, entry.key)).or_default().1 = Some(entry.value);
+    }
+
+    entries
+        .into_iter()
+        .filter(|(_, (before, after))| before != after)
+        .map(|((table, key), (before, after))| StorageChange {
+            table,
+            key,
+            before,
+            after,
+        })
+        .collect()
+}
+
+/// Wakes the thread blocked on the future.
+struct ThreadWaker(std::thread::Thread);
+
+impl std::task::Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drives the `future` on the current thread. The memory pools return the instances
+/// without awaiting in practice, so no runtime is required.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = core::pin::pin!(future);
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = TaskContext::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
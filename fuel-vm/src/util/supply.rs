@@ -0,0 +1,181 @@
+//! Tracking of the supply of the assets minted by the contracts.
+//!
+//! `MINT` and `BURN` change the balances of the contracts, but the storage doesn't
+//! keep the supply of the assets. [`SupplyTracker`] folds over the receipts of the
+//! executed transactions and accumulates the minted and burned amounts of every
+//! asset.
+//!
+//! The deposits and withdrawals of the assets by the contracts are tracked as
+//! well, so the supply held by the contracts can be compared to their balances.
+
+use alloc::collections::BTreeMap;
+
+use fuel_tx::{
+    ContractIdExt,
+    Receipt,
+    ScriptExecutionResult,
+};
+use fuel_types::{
+    AssetId,
+    Bytes32,
+    ContractId,
+};
+
+/// The amounts of the asset minted and burned by its contract, and moved in and out
+/// of the contracts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetSupply {
+    /// The contract minting the asset.
+    pub contract_id: ContractId,
+    /// The sub id of the asset.
+    pub sub_id: Bytes32,
+    /// The total minted amount.
+    pub minted: u128,
+    /// The total burned amount.
+    pub burned: u128,
+    /// The total amount the scripts sent to the contracts with `CALL` or `TR`.
+    pub deposited: u128,
+    /// The total amount the contracts sent to the outputs with `TRO`.
+    pub withdrawn: u128,
+}
+
+impl AssetSupply {
+    /// The current supply of the asset, i.e. the minted amount without the burned
+    /// one. Negative if the tracking started after the asset was minted.
+    pub fn supply(&self) -> i128 {
+        signed(self.minted).saturating_sub(signed(self.burned))
+    }
+
+    /// The part of the supply held by the contracts, i.e. the supply without the
+    /// amount withdrawn from the contracts and not deposited back.
+    pub fn held_by_contracts(&self) -> i128 {
+        self.supply()
+            .saturating_add(signed(self.deposited))
+            .saturating_sub(signed(self.withdrawn))
+    }
+}
+
+fn signed(amount: u128) -> i128 {
+    i128::try_from(amount).unwrap_or(i128::MAX)
+}
+
+/// Accumulates the supply of the assets from the `Mint` and `Burn` receipts.
+///
+/// The assets sent between the scripts and the contracts are tracked as well, but
+/// only for the assets minted earlier, so the base asset and the other coins are
+/// ignored. Only the receipts of the successful scripts are taken into account, since the
+/// changes of the reverted scripts are discarded.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SupplyTracker {
+    assets: BTreeMap<AssetId, AssetSupply>,
+}
+
+impl SupplyTracker {
+    /// Creates the tracker of the supply minted and burned by the `receipts`.
+    pub fn from_receipts(receipts: &[Receipt]) -> Self {
+        let mut tracker = Self::default();
+        tracker.track(receipts);
+        tracker
+    }
+
+    /// Accumulates the amounts minted and burned by the `receipts`.
+    ///
+    /// The `receipts` may belong to several transactions, each ending with the
+    /// `ScriptResult`. The transactions that didn't succeed are skipped.
+    pub fn track(&mut self, receipts: &[Receipt]) {
+        for receipts in receipts
+            .split_inclusive(|receipt| matches!(receipt, Receipt::ScriptResult { .. }))
+        {
+            let succeeded = !matches!(
+                receipts.last(),
+                Some(Receipt::ScriptResult { result, .. })
+                    if *result != ScriptExecutionResult::Success
+            );
+            if succeeded {
+                receipts.iter().for_each(|receipt| self.apply(receipt));
+            }
+        }
+    }
+
+    fn apply(&mut self, receipt: &Receipt) {
+        let script = ContractId::zeroed();
+        match receipt {
+            Receipt::Mint {
+                contract_id,
+                sub_id,
+                val,
+                ..
+            } => {
+                let asset = self.asset(contract_id, sub_id);
+                asset.minted = asset.minted.saturating_add(u128::from(*val));
+            }
+            Receipt::Burn {
+                contract_id,
+                sub_id,
+                val,
+                ..
+            } => {
+                let asset = self.asset(contract_id, sub_id);
+                asset.burned = asset.burned.saturating_add(u128::from(*val));
+            }
+            Receipt::Call {
+                id,
+                amount,
+                asset_id,
+                ..
+            }
+            | Receipt::Transfer {
+                id,
+                amount,
+                asset_id,
+                ..
+            } if *id == script => {
+                if let Some(asset) = self.assets.get_mut(asset_id) {
+                    asset.deposited = asset.deposited.saturating_add(u128::from(*amount));
+                }
+            }
+            Receipt::TransferOut {
+                id,
+                amount,
+                asset_id,
+                ..
+            } if *id != script => {
+                if let Some(asset) = self.assets.get_mut(asset_id) {
+                    asset.withdrawn = asset.withdrawn.saturating_add(u128::from(*amount));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn asset(&mut self, contract_id: &ContractId, sub_id: &Bytes32) -> &mut AssetSupply {
+        self.assets
+            .entry(contract_id.asset_id(sub_id))
+            .or_insert(AssetSupply {
+                contract_id: *contract_id,
+                sub_id: *sub_id,
+                minted: 0,
+                burned: 0,
+                deposited: 0,
+                withdrawn: 0,
+            })
+    }
+
+    /// Returns the supply of the `asset_id`, if the asset was minted or burned.
+    pub fn get(&self, asset_id: &AssetId) -> Option<&AssetSupply> {
+        self.assets.get(asset_id)
+    }
+
+    /// Returns the current supply of the `asset_id`, or zero if the asset wasn't
+    /// minted or burned.
+    pub fn supply(&self, asset_id: &AssetId) -> i128 {
+        self.get(asset_id)
+            .map(AssetSupply::supply)
+            .unwrap_or_default()
+    }
+
+    /// Iterates over the tracked assets in the order of their ids.
+    pub fn iter(&self) -> impl Iterator<Item = (&AssetId, &AssetSupply)> {
+        self.assets.iter()
+    }
+}